@@ -0,0 +1,200 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Wraps SBOMs in [in-toto](https://in-toto.io) statements for SLSA and cosign workflows.
+//!
+//! The statement uses the CycloneDX predicate type, so it can be signed and attached with
+//! `cosign attest-blob --statement`, or piped to any other signer configured on the command line.
+
+use crate::digest::{sha256_file, to_hex};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use thiserror::Error;
+
+pub const STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
+pub const CYCLONEDX_PREDICATE_TYPE: &str = "https://cyclonedx.org/bom";
+
+/// An in-toto statement about the subjects, with the SBOM as its predicate
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Statement {
+    #[serde(rename = "_type")]
+    pub statement_type: String,
+    pub subject: Vec<Subject>,
+    #[serde(rename = "predicateType")]
+    pub predicate_type: String,
+    pub predicate: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Subject {
+    pub name: String,
+    pub digest: BTreeMap<String, String>,
+}
+
+#[derive(Error, Debug)]
+pub enum AttestationError {
+    #[error("Failed to read the attestation subject {}: {error}", path.display())]
+    Subject {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+
+    #[error("Failed to run the signer `{command}`: {error}")]
+    SignerIo {
+        command: String,
+        error: std::io::Error,
+    },
+
+    #[error("The signer `{command}` failed with {status}")]
+    SignerFailed { command: String, status: ExitStatus },
+}
+
+impl Statement {
+    /// Creates a statement about the files, identified by their SHA-256 digest
+    pub fn new(bom: serde_json::Value, subjects: &[PathBuf]) -> Result<Self, AttestationError> {
+        let subject = subjects
+            .iter()
+            .map(|path| Subject::from_file(path))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            statement_type: STATEMENT_TYPE.to_owned(),
+            subject,
+            predicate_type: CYCLONEDX_PREDICATE_TYPE.to_owned(),
+            predicate: bom,
+        })
+    }
+}
+
+impl Subject {
+    fn from_file(path: &Path) -> Result<Self, AttestationError> {
+        let digest = sha256_file(path).map_err(|error| AttestationError::Subject {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+
+        Ok(Self {
+            name,
+            digest: BTreeMap::from([("sha256".to_owned(), to_hex(&digest))]),
+        })
+    }
+}
+
+/// Pipes the statement to the signer, a command run by the system shell, and returns what it prints
+pub fn sign(command: &str, statement: &[u8]) -> Result<Vec<u8>, AttestationError> {
+    let io_error = |error| AttestationError::SignerIo {
+        command: command.to_owned(),
+        error,
+    };
+
+    let mut child = shell(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(io_error)?;
+
+    // Write from another thread, the signer may print before it has read the whole statement
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let statement = statement.to_vec();
+    let writer = std::thread::spawn(move || stdin.write_all(&statement));
+
+    let mut signed = Vec::new();
+    child
+        .stdout
+        .take()
+        .expect("stdout is piped")
+        .read_to_end(&mut signed)
+        .map_err(io_error)?;
+    let status = child.wait().map_err(io_error)?;
+    if !status.success() {
+        return Err(AttestationError::SignerFailed {
+            command: command.to_owned(),
+            status,
+        });
+    }
+    writer
+        .join()
+        .expect("the writer thread does not panic")
+        .map_err(io_error)?;
+
+    Ok(signed)
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
+
+#[cfg(not(windows))]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    #[test]
+    fn it_should_create_a_statement_about_the_subjects() {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let binary = directory.child("my-tool");
+        binary.write_binary(b"abc").unwrap();
+
+        let bom = serde_json::json!({ "bomFormat": "CycloneDX" });
+        let statement = Statement::new(bom.clone(), &[binary.to_path_buf()]).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&statement).unwrap(),
+            serde_json::json!({
+                "_type": "https://in-toto.io/Statement/v1",
+                "subject": [{
+                    "name": "my-tool",
+                    "digest": {
+                        "sha256": "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+                    }
+                }],
+                "predicateType": "https://cyclonedx.org/bom",
+                "predicate": bom,
+            })
+        );
+
+        assert!(Statement::new(bom, &[directory.child("missing").to_path_buf()]).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn it_should_pipe_the_statement_to_the_signer() {
+        assert_eq!(sign("tr a-z A-Z", b"statement").unwrap(), b"STATEMENT");
+        assert!(matches!(
+            sign("cat > /dev/null; exit 3", b"statement"),
+            Err(AttestationError::SignerFailed { .. })
+        ));
+    }
+}
@@ -0,0 +1,455 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Creating SBOMs for compiled binaries, for when their source tree is not available.
+//!
+//! Binaries built with [`cargo auditable`](https://github.com/rust-secure-code/cargo-auditable)
+//! embed their dependency tree as zlib-compressed JSON in a `.dep-v0` section, which is read with
+//! `auditable-extract` and `miniz_oxide` if present. Otherwise the crates are guessed from the source paths in panic messages, which point
+//! into the registry sources in `$CARGO_HOME`. That only finds the crates with code that can panic,
+//! and not how they depend on each other.
+
+use crate::config::SbomConfig;
+use crate::digest::{sha256, to_hex};
+use crate::format::Format;
+use crate::generator::{GeneratedSbom, SbomGenerator, SbomWriterError};
+
+use cyclonedx_bom::external_models::uri::Purl;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::models::component::{Classification, Component, Components, Scope};
+use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+use cyclonedx_bom::models::metadata::{Metadata, MetadataError};
+use cyclonedx_bom::models::property::{Properties, Property};
+use miniz_oxide::inflate::{decompress_to_vec_zlib_with_limit, DecompressError};
+use once_cell::sync::Lazy;
+use regex::bytes::Regex;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::Path;
+use thiserror::Error;
+
+/// Decompressing more than this is taken as a sign of a corrupt or malicious binary
+const MAX_AUDIT_DATA_SIZE: usize = 64 * 1024 * 1024;
+
+/// How the crates in a binary were found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Detection {
+    /// Read from the dependency tree embedded by `cargo auditable`
+    Auditable,
+    /// Guessed from the registry source paths in panic messages
+    PanicStrings,
+}
+
+impl Detection {
+    fn as_str(self) -> &'static str {
+        match self {
+            Detection::Auditable => "auditable",
+            Detection::PanicStrings => "panic-strings",
+        }
+    }
+}
+
+/// A crate compiled into a binary, in the format of `cargo auditable`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AuditedPackage {
+    pub name: String,
+    pub version: String,
+    /// `crates.io`, `git`, `local`, `registry` or the name of another source
+    pub source: String,
+    #[serde(default)]
+    pub kind: AuditedKind,
+    /// Indices of the dependencies in the list of packages
+    #[serde(default)]
+    pub dependencies: Vec<usize>,
+    /// Whether this is the crate the binary was built from
+    #[serde(default)]
+    pub root: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditedKind {
+    /// Only used to build the binary, such as build scripts and proc-macros
+    Build,
+    #[default]
+    Runtime,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct AuditData {
+    packages: Vec<AuditedPackage>,
+}
+
+/// The crates found in a binary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinaryScan {
+    pub detection: Detection,
+    pub packages: Vec<AuditedPackage>,
+}
+
+#[derive(Error, Debug)]
+pub enum BinaryScanError {
+    #[error("Could not read the binary {}", .0.display())]
+    Io(std::path::PathBuf, #[source] std::io::Error),
+
+    #[error("Could not decompress the audit data")]
+    Inflate(#[source] DecompressError),
+
+    #[error("Could not parse the audit data")]
+    AuditData(#[source] serde_json::Error),
+
+    #[error("The audit data refers to dependency {0}, which does not exist")]
+    InvalidDependency(usize),
+
+    #[error("Error creating Metadata")]
+    MetadataError(#[from] MetadataError),
+}
+
+/// Finds the crates compiled into a binary, preferring the `cargo auditable` data over panic messages
+pub fn scan_binary(data: &[u8]) -> Result<BinaryScan, BinaryScanError> {
+    match auditable_extract::raw_auditable_data(data) {
+        Ok(section) => {
+            let json = decompress_to_vec_zlib_with_limit(section, MAX_AUDIT_DATA_SIZE)
+                .map_err(BinaryScanError::Inflate)?;
+            return read_audit_data(&json);
+        }
+        // Binaries without the section, or that cannot be parsed, are searched for panic messages
+        Err(error) => log::debug!("No `cargo auditable` data was read: {error}"),
+    }
+
+    Ok(BinaryScan {
+        detection: Detection::PanicStrings,
+        packages: registry_paths(data),
+    })
+}
+
+fn read_audit_data(json: &[u8]) -> Result<BinaryScan, BinaryScanError> {
+    let audit_data: AuditData = serde_json::from_slice(json).map_err(BinaryScanError::AuditData)?;
+    let count = audit_data.packages.len();
+    if let Some(&index) = audit_data
+        .packages
+        .iter()
+        .flat_map(|package| &package.dependencies)
+        .find(|&&index| index >= count)
+    {
+        return Err(BinaryScanError::InvalidDependency(index));
+    }
+    Ok(BinaryScan {
+        detection: Detection::Auditable,
+        packages: audit_data.packages,
+    })
+}
+
+/// Creates an SBOM describing the binary at `path`, with the crates compiled into it as components
+pub fn create_binary_sbom(path: &Path, config: &SbomConfig) -> Result<Bom, BinaryScanError> {
+    let data = std::fs::read(path).map_err(|error| BinaryScanError::Io(path.to_owned(), error))?;
+    let scan = scan_binary(&data)?;
+    if scan.detection == Detection::PanicStrings {
+        log::warn!(
+            "{} has no `cargo auditable` data, the crates are guessed from its panic messages and may be incomplete",
+            path.display()
+        );
+    }
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let root = scan.packages.iter().position(|package| package.root);
+    let bom_refs: Vec<String> = scan
+        .packages
+        .iter()
+        .map(|package| format!("{}@{}", package.name, package.version))
+        .collect();
+
+    let mut component = Component::new(
+        Classification::Application,
+        &file_name,
+        root.map_or("", |root| &scan.packages[root].version),
+        Some(file_name.clone()),
+    );
+    // The version is unknown without a root package, but required before 1.4
+    if root.is_none() && config.spec_version() >= SpecVersion::V1_4 {
+        component.version = None;
+    }
+    component.hashes = Some(Hashes(vec![Hash {
+        alg: HashAlgorithm::SHA_256,
+        content: HashValue(to_hex(&sha256(&data))),
+    }]));
+    component.properties = Some(Properties(vec![Property::new(
+        "cargo-cyclonedx:binary:detection",
+        scan.detection.as_str(),
+    )]));
+
+    let mut metadata = Metadata::new_with_timestamp(&config.timestamp())?;
+    metadata.component = Some(component);
+    metadata.tools = Some(SbomGenerator::create_tools(config, None));
+
+    // The same crate may be listed once per source, but it is only recorded once
+    let mut recorded = BTreeSet::new();
+    let components = scan
+        .packages
+        .iter()
+        .zip(&bom_refs)
+        .filter(|&(package, bom_ref)| !package.root && recorded.insert(bom_ref))
+        .map(|(package, bom_ref)| create_component(package, bom_ref, scan.detection))
+        .collect();
+
+    let mut dependencies: Vec<Dependency> = Vec::new();
+    if scan.detection == Detection::Auditable {
+        for (index, package) in scan.packages.iter().enumerate() {
+            let dependency_ref = match package.root {
+                true => file_name.clone(),
+                false => bom_refs[index].clone(),
+            };
+            if dependencies
+                .iter()
+                .any(|dependency| dependency.dependency_ref == dependency_ref)
+            {
+                continue;
+            }
+            let mut depends_on: Vec<String> = package
+                .dependencies
+                .iter()
+                .map(|&dependency| bom_refs[dependency].clone())
+                .collect();
+            depends_on.sort();
+            depends_on.dedup();
+            dependencies.push(Dependency {
+                dependency_ref,
+                dependencies: depends_on,
+            });
+        }
+    }
+
+    Ok(Bom {
+        metadata: Some(metadata),
+        components: Some(Components(components)),
+        dependencies: Some(Dependencies(dependencies)),
+        ..Bom::default()
+    })
+}
+
+/// Writes the SBOM of a binary to `path`, or to stdout if it is `None`
+pub fn write_binary_sbom(
+    bom: Bom,
+    path: Option<&Path>,
+    config: &SbomConfig,
+    format: Format,
+) -> Result<(), SbomWriterError> {
+    match path {
+        Some(path) => GeneratedSbom::write_to_file(bom, path, config, format),
+        None => {
+            let mut writer = std::io::stdout().lock();
+            GeneratedSbom::write_bom(bom, &mut writer, config, format)?;
+            writer.flush()?;
+            Ok(())
+        }
+    }
+}
+
+fn create_component(package: &AuditedPackage, bom_ref: &str, detection: Detection) -> Component {
+    let mut component = Component::new(
+        Classification::Library,
+        &package.name,
+        &package.version,
+        Some(bom_ref.to_owned()),
+    );
+    if package.source == "crates.io" {
+        component.purl = Purl::new("cargo", &package.name, &package.version).ok();
+    }
+    // Crates found in panic messages have code in the binary
+    component.scope = match (detection, package.kind) {
+        (Detection::Auditable, AuditedKind::Build) => Some(Scope::Excluded),
+        _ => Some(Scope::Required),
+    };
+    component
+}
+
+/// Finds the crates whose registry sources are referred to in the binary, usually by panic messages
+fn registry_paths(data: &[u8]) -> Vec<AuditedPackage> {
+    static REGISTRY_PATH: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(?-u)registry[/\\]src[/\\]([^/\\\x00]+)[/\\]([A-Za-z0-9_-]+)-([0-9]+\.[0-9]+\.[0-9]+(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?)[/\\]",
+        )
+        .unwrap()
+    });
+
+    let found: BTreeSet<(String, String, String)> = REGISTRY_PATH
+        .captures_iter(data)
+        .map(|captures| {
+            let text = |index| String::from_utf8_lossy(&captures[index]).into_owned();
+            let index = text(1);
+            let source =
+                if index.starts_with("index.crates.io-") || index.starts_with("github.com-") {
+                    "crates.io".to_owned()
+                } else {
+                    "registry".to_owned()
+                };
+            (text(2), text(3), source)
+        })
+        .collect();
+
+    found
+        .into_iter()
+        .map(|(name, version, source)| AuditedPackage {
+            name,
+            version,
+            source,
+            kind: AuditedKind::Runtime,
+            dependencies: Vec::new(),
+            root: false,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// `{"packages":[app 0.1.0 (root), serde 1.0.193, serde_derive 1.0.193 (build)]}`, compressed by zlib
+    const AUDIT_DATA: &[u8] = b"\x78\xda\x95\xcc\xbb\x0e\x02\x21\x10\x85\xe1\x77\x99\x9a\x90\x65\xad\xe4\x55\x0c\x31\xc8\x4c\xcc\x64\x11\xc8\x00\xdb\x6c\xf6\xdd\x45\x0b\xe3\xa5\xb2\x3c\x7f\x4e\xbe\x0d\x8a\x0f\x8b\xbf\x52\x05\x7b\xda\x20\xf9\x1b\x81\x05\x5f\x0a\x28\x58\x49\x2a\xe7\x34\xf6\xa4\x8d\x9e\x46\xa9\xb9\x4b\x78\x1c\x62\x0e\x3e\x8e\x80\x54\x28\x21\xa5\xc0\x4f\xc0\xa8\xd9\x29\x90\x9c\x1b\xd8\x26\x9d\x76\xf5\x22\x2b\x09\xd2\x07\x3a\x48\x6d\x8e\x87\x77\x36\x88\x6f\x54\x35\xe7\x5f\x7a\x76\xdf\xd8\x19\x49\x78\xfd\xcb\x5c\x38\xe1\x48\x97\xce\x11\x61\x77\xfb\x1d\x6a\x61\x51\x56";
+
+    /// A little-endian ELF64 file with only a section name table and the given section
+    fn elf_with_section(name: &[u8], contents: &[u8]) -> Vec<u8> {
+        let mut names = b"\0.shstrtab\0".to_vec();
+        names.extend_from_slice(name);
+        names.push(0);
+
+        let mut elf = vec![0; 64];
+        elf[..6].copy_from_slice(b"\x7fELF\x02\x01");
+        let names_offset = elf.len();
+        elf.extend_from_slice(&names);
+        let contents_offset = elf.len();
+        elf.extend_from_slice(contents);
+        let table = elf.len();
+        elf[0x28..0x30].copy_from_slice(&(table as u64).to_le_bytes());
+        elf[0x3a..0x3c].copy_from_slice(&64u16.to_le_bytes());
+        elf[0x3c..0x3e].copy_from_slice(&3u16.to_le_bytes());
+        elf[0x3e..0x40].copy_from_slice(&1u16.to_le_bytes());
+
+        let sections = [
+            (0, 0, 0),
+            (1, names_offset, names.len()),
+            (11, contents_offset, contents.len()),
+        ];
+        for (name, offset, size) in sections {
+            let mut header = vec![0; 64];
+            header[..4].copy_from_slice(&(name as u32).to_le_bytes());
+            header[0x18..0x20].copy_from_slice(&(offset as u64).to_le_bytes());
+            header[0x20..0x28].copy_from_slice(&(size as u64).to_le_bytes());
+            elf.extend_from_slice(&header);
+        }
+        elf
+    }
+
+    #[test]
+    fn it_should_read_the_audit_data_of_an_elf_binary() {
+        let scan = scan_binary(&elf_with_section(b".dep-v0", AUDIT_DATA)).unwrap();
+
+        assert_eq!(scan.detection, Detection::Auditable);
+        let packages: Vec<_> = scan
+            .packages
+            .iter()
+            .map(|package| (package.name.as_str(), package.kind, package.root))
+            .collect();
+        assert_eq!(
+            packages,
+            vec![
+                ("app", AuditedKind::Runtime, true),
+                ("serde", AuditedKind::Runtime, false),
+                ("serde_derive", AuditedKind::Build, false),
+            ]
+        );
+        assert_eq!(scan.packages[0].dependencies, vec![1, 2]);
+    }
+
+    #[test]
+    fn it_should_fail_for_corrupt_audit_data() {
+        let mut corrupt = AUDIT_DATA.to_vec();
+        let last = corrupt.len() - 1;
+        corrupt[last] ^= 1;
+
+        assert!(matches!(
+            scan_binary(&elf_with_section(b".dep-v0", &corrupt)),
+            Err(BinaryScanError::Inflate(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_guess_the_crates_from_panic_messages() {
+        let data = b"\0/home/user/.cargo/registry/src/index.crates.io-6f17d22bba15001f/serde-1.0.193/src/de/mod.rs\0\
+            C:\\Users\\user\\.cargo\\registry\\src\\index.crates.io-6f17d22bba15001f\\proc-macro2-1.0.70\\src\\lib.rs\0\
+            /cargo/registry/src/my-registry-0123456789abcdef/foo-bar-0.1.0-alpha.1/src/lib.rs\0\
+            /home/user/.cargo/registry/src/index.crates.io-6f17d22bba15001f/serde-1.0.193/src/ser/mod.rs\0";
+        let scan = scan_binary(data).unwrap();
+
+        assert_eq!(scan.detection, Detection::PanicStrings);
+        let packages: Vec<_> = scan
+            .packages
+            .iter()
+            .map(|package| {
+                (
+                    package.name.as_str(),
+                    package.version.as_str(),
+                    package.source.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            packages,
+            vec![
+                ("foo-bar", "0.1.0-alpha.1", "registry"),
+                ("proc-macro2", "1.0.70", "crates.io"),
+                ("serde", "1.0.193", "crates.io"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_describe_the_binary_and_its_crates() {
+        let tmp_dir = assert_fs::TempDir::new().unwrap();
+        let path = tmp_dir.path().join("app");
+        std::fs::write(&path, elf_with_section(b".dep-v0", AUDIT_DATA)).unwrap();
+
+        let bom = create_binary_sbom(&path, &SbomConfig::empty_config()).unwrap();
+
+        let component = bom.metadata.unwrap().component.unwrap();
+        assert_eq!(component.name.to_string(), "app");
+        assert_eq!(component.version.unwrap().to_string(), "0.1.0");
+        let components = bom.components.unwrap().0;
+        assert_eq!(components.len(), 2);
+        assert_eq!(
+            components[0].purl.as_ref().unwrap().to_string(),
+            "pkg:cargo/serde@1.0.193"
+        );
+        assert_eq!(components[1].scope, Some(Scope::Excluded));
+        let dependencies = bom.dependencies.unwrap().0;
+        assert_eq!(
+            dependencies[0],
+            Dependency {
+                dependency_ref: "app".to_owned(),
+                dependencies: vec![
+                    "serde@1.0.193".to_owned(),
+                    "serde_derive@1.0.193".to_owned()
+                ],
+            }
+        );
+    }
+}
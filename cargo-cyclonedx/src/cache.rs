@@ -0,0 +1,245 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Skips regenerating SBOMs when nothing that goes into them changed since the last run.
+//!
+//! The cache key is a digest of the lockfile, the manifests of the workspace members,
+//! the configuration and the version of cargo-cyclonedx. Inputs outside of the workspace,
+//! like the registry index or the advisory database, are not part of it.
+//!
+//! Runs that check the license policy or yanked dependencies, or upload the SBOMs, are never
+//! skipped, see [`checks_sboms`].
+
+use crate::config::SbomConfig;
+use crate::digest::to_hex;
+use crate::generator::WrittenSbom;
+use crate::vcs::git_info;
+use cargo_metadata::Metadata;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// The outcome of the last successful run, stored in the target directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerationCache {
+    path: PathBuf,
+    key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    key: String,
+    files: Vec<PathBuf>,
+}
+
+impl GenerationCache {
+    /// Computes the cache key for generating SBOMs for the workspace.
+    ///
+    /// `options` covers settings that are not part of the [`SbomConfig`], like upload options.
+    pub fn new(metadata: &Metadata, lockfile: &Path, config: &SbomConfig, options: &str) -> Self {
+        let mut hasher = Sha256::new();
+        let mut add_input = |name: &str, content: &[u8]| {
+            for part in [name.as_bytes(), content] {
+                hasher.update((part.len() as u64).to_le_bytes());
+                hasher.update(part);
+            }
+        };
+
+        add_input("version", env!("CARGO_PKG_VERSION").as_bytes());
+        // The number of jobs does not change the SBOMs
+        let config = SbomConfig {
+            jobs: None,
+            ..config.clone()
+        };
+        add_input("config", format!("{config:?}").as_bytes());
+        add_input("options", options.as_bytes());
+        add_input("lockfile", &std::fs::read(lockfile).unwrap_or_default());
+
+        let manifests: BTreeSet<&Path> = metadata
+            .packages
+            .iter()
+            .filter(|package| metadata.workspace_members.contains(&package.id))
+            .map(|package| package.manifest_path.as_std_path())
+            .collect();
+        for manifest in manifests {
+            add_input(
+                &manifest.display().to_string(),
+                &std::fs::read(manifest).unwrap_or_default(),
+            );
+        }
+
+        if config.vcs == Some(true) {
+            let vcs = git_info(metadata.workspace_root.as_std_path());
+            add_input("vcs", format!("{vcs:?}").as_bytes());
+        }
+
+        Self {
+            path: metadata
+                .target_directory
+                .as_std_path()
+                .join("cyclonedx-cache.json"),
+            key: to_hex(&hasher.finalize()),
+        }
+    }
+
+    /// Whether the last run had the same inputs and the SBOMs it wrote still exist
+    pub fn is_fresh(&self) -> bool {
+        let Ok(content) = std::fs::read(&self.path) else {
+            return false;
+        };
+        match serde_json::from_slice::<CacheEntry>(&content) {
+            Ok(entry) => entry.key == self.key && entry.files.iter().all(|file| file.is_file()),
+            Err(error) => {
+                log::debug!(
+                    "Ignoring the invalid cache {}: {}",
+                    self.path.display(),
+                    error
+                );
+                false
+            }
+        }
+    }
+
+    /// Records the SBOMs written for the current inputs
+    pub fn store(&self, sboms: &[WrittenSbom]) -> std::io::Result<()> {
+        let entry = CacheEntry {
+            key: self.key.clone(),
+            files: sboms
+                .iter()
+                .flat_map(|sbom| sbom.files.iter().cloned())
+                .collect(),
+        };
+        if let Some(directory) = self.path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        std::fs::write(&self.path, serde_json::to_vec_pretty(&entry)?)
+    }
+}
+
+/// Whether the configuration of a workspace member checks the generated SBOMs against the
+/// license policy or for yanked dependencies, which reusing the SBOMs of the last run would skip
+pub fn checks_sboms(metadata: &Metadata, config: &SbomConfig) -> bool {
+    metadata
+        .packages
+        .iter()
+        .filter(|package| metadata.workspace_members.contains(&package.id))
+        .any(|package| {
+            let package_dir = package
+                .manifest_path
+                .parent()
+                .map_or(Path::new("."), |dir| dir.as_std_path());
+            let config = match SbomConfig::from_package_metadata(&package.metadata, package_dir) {
+                Ok(Some(package_config)) => package_config.merge(config),
+                // Invalid configurations are reported by the generation
+                _ => config.clone(),
+            };
+            !config.license_policy().is_empty() || config.fail_on_yanked()
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    fn metadata(workspace: &Path) -> Metadata {
+        serde_json::from_value(serde_json::json!({
+            "packages": [{
+                "name": "pkg",
+                "version": "0.1.0",
+                "id": "pkg@0.1.0",
+                "dependencies": [],
+                "targets": [],
+                "features": {},
+                "manifest_path": workspace.join("Cargo.toml"),
+            }],
+            "workspace_members": ["pkg@0.1.0"],
+            "workspace_default_members": ["pkg@0.1.0"],
+            "resolve": null,
+            "workspace_root": workspace,
+            "target_directory": workspace.join("target"),
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_be_fresh_until_an_input_changes() {
+        let workspace = assert_fs::TempDir::new().unwrap();
+        let manifest = workspace.child("Cargo.toml");
+        manifest.write_str("[package]").unwrap();
+        let lockfile = workspace.child("Cargo.lock");
+        lockfile.write_str("version = 3").unwrap();
+        let sbom = workspace.child("pkg.cdx.xml");
+        sbom.write_str("<bom/>").unwrap();
+
+        let metadata = metadata(workspace.path());
+        let config = SbomConfig::empty_config();
+        let cache = GenerationCache::new(&metadata, lockfile.path(), &config, "");
+        assert!(!cache.is_fresh());
+
+        cache
+            .store(&[WrittenSbom {
+                name: "pkg".to_owned(),
+                version: "0.1.0".to_owned(),
+                files: vec![sbom.to_path_buf()],
+            }])
+            .unwrap();
+        assert!(cache.is_fresh());
+        assert_eq!(
+            GenerationCache::new(&metadata, lockfile.path(), &config, ""),
+            cache
+        );
+
+        let other_config = SbomConfig {
+            cpe: Some(true),
+            ..SbomConfig::empty_config()
+        };
+        assert!(!GenerationCache::new(&metadata, lockfile.path(), &other_config, "").is_fresh());
+        assert!(!GenerationCache::new(&metadata, lockfile.path(), &config, "upload").is_fresh());
+
+        manifest.write_str("[package]\nname = \"pkg\"").unwrap();
+        assert!(!GenerationCache::new(&metadata, lockfile.path(), &config, "").is_fresh());
+        manifest.write_str("[package]").unwrap();
+
+        lockfile.write_str("version = 4").unwrap();
+        assert!(!GenerationCache::new(&metadata, lockfile.path(), &config, "").is_fresh());
+        lockfile.write_str("version = 3").unwrap();
+        assert!(GenerationCache::new(&metadata, lockfile.path(), &config, "").is_fresh());
+
+        std::fs::remove_file(sbom.path()).unwrap();
+        assert!(!cache.is_fresh());
+    }
+    #[test]
+    fn it_should_detect_configurations_checking_the_sboms() {
+        let workspace = assert_fs::TempDir::new().unwrap();
+        let mut metadata = metadata(workspace.path());
+        assert!(!checks_sboms(&metadata, &SbomConfig::empty_config()));
+
+        let config = SbomConfig {
+            fail_on_yanked: Some(true),
+            ..SbomConfig::empty_config()
+        };
+        assert!(checks_sboms(&metadata, &config));
+
+        metadata.packages[0].metadata = serde_json::json!({
+            "cyclonedx": { "deny-licenses": ["GPL-3.0-only"] }
+        });
+        assert!(checks_sboms(&metadata, &SbomConfig::empty_config()));
+    }
+}
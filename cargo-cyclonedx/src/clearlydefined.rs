@@ -0,0 +1,359 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! An enricher recording the license data curated and harvested by
+//! [ClearlyDefined](https://clearlydefined.io) for crates.io packages, for `--clearlydefined`.
+//!
+//! The declared license and the licenses discovered in the sources are recorded as license
+//! evidence of the component, and the licensing score of ClearlyDefined as the
+//! `clearlydefined:licensed:score` property. The licenses of the component itself are left as
+//! declared in the manifest.
+
+use cargo_metadata::Package;
+use cyclonedx_bom::client::{CurlTransport, Method, Request, Transport};
+use cyclonedx_bom::external_models::spdx::SpdxExpression;
+use cyclonedx_bom::models::component::{Component, ComponentEvidence};
+use cyclonedx_bom::models::license::{License, LicenseChoice, Licenses};
+use cyclonedx_bom::models::property::{Properties, Property};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::enricher::ComponentEnricher;
+
+const DEFAULT_URL: &str = "https://api.clearlydefined.io";
+const SCORE_PROPERTY: &str = "clearlydefined:licensed:score";
+/// Everything but the unreserved characters of RFC 3986 is encoded in the coordinates
+const COORDINATE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+/// ClearlyDefined's placeholder for licenses it could not determine
+const NO_ASSERTION: &str = "NOASSERTION";
+
+/// The parts of a ClearlyDefined definition that are recorded
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct Definition {
+    licensed: Option<Licensed>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct Licensed {
+    declared: Option<String>,
+    facets: Option<Facets>,
+    score: Option<Score>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct Facets {
+    core: Option<Facet>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct Facet {
+    discovered: Option<Discovered>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct Discovered {
+    #[serde(default)]
+    expressions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct Score {
+    total: Option<u32>,
+}
+
+impl Definition {
+    /// The declared license followed by the discovered ones, without duplicates
+    fn licenses(&self) -> Vec<&str> {
+        let Some(licensed) = &self.licensed else {
+            return Vec::new();
+        };
+        let discovered = licensed
+            .facets
+            .as_ref()
+            .and_then(|facets| facets.core.as_ref())
+            .and_then(|core| core.discovered.as_ref())
+            .map(|discovered| discovered.expressions.as_slice())
+            .unwrap_or_default();
+
+        let mut licenses = Vec::new();
+        for license in licensed.declared.iter().chain(discovered) {
+            let license = license.trim();
+            if !license.is_empty() && license != NO_ASSERTION && !licenses.contains(&license) {
+                licenses.push(license);
+            }
+        }
+        licenses
+    }
+
+    fn score(&self) -> Option<u32> {
+        self.licensed.as_ref()?.score.as_ref()?.total
+    }
+}
+
+/// Queries ClearlyDefined for the definition of every crates.io package and records its license
+/// data in the component, see the [module](self) documentation.
+///
+/// The definitions are requested one at a time and kept for the packages of further SBOMs.
+/// Failed requests are logged as warnings and leave the component untouched.
+pub struct ClearlyDefinedEnricher<T = CurlTransport> {
+    url: String,
+    transport: T,
+    definitions: Mutex<HashMap<String, Option<Definition>>>,
+}
+
+impl ClearlyDefinedEnricher {
+    /// Queries the public ClearlyDefined API with `curl`
+    pub fn new() -> Self {
+        Self::with_transport(DEFAULT_URL, CurlTransport::default())
+    }
+}
+
+impl Default for ClearlyDefinedEnricher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Transport + Sync> ClearlyDefinedEnricher<T> {
+    /// Queries the ClearlyDefined API at the base URL, e.g. a mirror, with the transport
+    pub fn with_transport(url: impl Into<String>, transport: T) -> Self {
+        Self {
+            url: url.into().trim_end_matches('/').to_owned(),
+            transport,
+            definitions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The definition of the crate, requested only once per crate version
+    fn definition(&self, name: &str, version: &str) -> Option<Definition> {
+        let coordinates = format!(
+            "crate/cratesio/-/{}/{}",
+            utf8_percent_encode(name, COORDINATE),
+            utf8_percent_encode(version, COORDINATE)
+        );
+        if let Some(definition) = self.definitions.lock().unwrap().get(&coordinates) {
+            return definition.clone();
+        }
+
+        // Not holding the lock while waiting for the response, so other threads can go on
+        let definition = self.request(&coordinates);
+        self.definitions
+            .lock()
+            .unwrap()
+            .insert(coordinates, definition.clone());
+        definition
+    }
+
+    fn request(&self, coordinates: &str) -> Option<Definition> {
+        let request = Request {
+            method: Method::Get,
+            url: format!("{}/definitions/{coordinates}", self.url),
+            headers: vec![("Accept".to_owned(), "application/json".to_owned())],
+            body: None,
+        };
+        let response = match self.transport.send(&request) {
+            Ok(response) if response.status == 200 => response,
+            Ok(response) => {
+                log::warn!(
+                    "Failed to query ClearlyDefined for {coordinates}: HTTP {} {}",
+                    response.status,
+                    String::from_utf8_lossy(&response.body).trim()
+                );
+                return None;
+            }
+            Err(error) => {
+                log::warn!("Failed to query ClearlyDefined for {coordinates}: {error}");
+                return None;
+            }
+        };
+        match serde_json::from_slice(&response.body) {
+            Ok(definition) => Some(definition),
+            Err(error) => {
+                log::warn!("Invalid ClearlyDefined definition of {coordinates}: {error}");
+                None
+            }
+        }
+    }
+}
+
+impl<T: Transport + Sync> ComponentEnricher for ClearlyDefinedEnricher<T> {
+    fn enrich(&self, component: &mut Component, package: &Package) {
+        if !package
+            .source
+            .as_ref()
+            .is_some_and(|source| source.is_crates_io())
+        {
+            return;
+        }
+        let Some(definition) = self.definition(&package.name, &package.version.to_string()) else {
+            return;
+        };
+
+        let licenses = definition.licenses();
+        if !licenses.is_empty() {
+            let evidence = component.evidence.get_or_insert(ComponentEvidence {
+                licenses: None,
+                copyright: None,
+                occurrences: None,
+                callstack: None,
+                identity: None,
+            });
+            let evidence = &mut evidence
+                .licenses
+                .get_or_insert_with(|| Licenses(Vec::new()))
+                .0;
+            for license in licenses {
+                let license = license_choice(license);
+                if !evidence.contains(&license) {
+                    evidence.push(license);
+                }
+            }
+        }
+
+        if let Some(score) = definition.score() {
+            component
+                .properties
+                .get_or_insert_with(|| Properties(Vec::new()))
+                .0
+                .push(Property::new(SCORE_PROPERTY, &score.to_string()));
+        }
+    }
+}
+
+/// An SPDX expression if it is one, otherwise a named license
+fn license_choice(license: &str) -> LicenseChoice {
+    match SpdxExpression::parse_lax(license.to_owned()) {
+        Ok(expression) => LicenseChoice::Expression(expression),
+        Err(_) => LicenseChoice::License(License::named_license(license)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::client::Response;
+    use cyclonedx_bom::models::component::Classification;
+
+    const CRATES_IO_PACKAGE_JSON: &str = include_str!("../tests/fixtures/crates_io_package.json");
+    const ROOT_PACKAGE_JSON: &str = include_str!("../tests/fixtures/root_package.json");
+
+    /// Answers every request with the same response, counting the requests
+    struct FixedTransport {
+        response: Response,
+        urls: Mutex<Vec<String>>,
+    }
+
+    impl Transport for FixedTransport {
+        fn send(&self, request: &Request) -> std::io::Result<Response> {
+            self.urls.lock().unwrap().push(request.url.clone());
+            Ok(self.response.clone())
+        }
+    }
+
+    fn enricher(status: u16, body: &str) -> ClearlyDefinedEnricher<FixedTransport> {
+        let transport = FixedTransport {
+            response: Response {
+                status,
+                body: body.as_bytes().to_vec(),
+            },
+            urls: Mutex::new(Vec::new()),
+        };
+        ClearlyDefinedEnricher::with_transport("https://clearlydefined.example.com/", transport)
+    }
+
+    fn component_of(package: &Package) -> Component {
+        Component::new(
+            Classification::Library,
+            &package.name,
+            &package.version.to_string(),
+            Some(package.id.repr.clone()),
+        )
+    }
+
+    #[test]
+    fn it_should_record_licenses_and_score() {
+        let package: Package = serde_json::from_str(CRATES_IO_PACKAGE_JSON).unwrap();
+        let enricher = enricher(
+            200,
+            r#"{
+                "licensed": {
+                    "declared": "MIT OR Apache-2.0",
+                    "facets": {
+                        "core": {
+                            "discovered": {
+                                "expressions": ["Apache-2.0", "MIT OR Apache-2.0", "NOASSERTION", "Some License"]
+                            }
+                        }
+                    },
+                    "score": { "total": 87 }
+                }
+            }"#,
+        );
+
+        let mut component = component_of(&package);
+        enricher.enrich(&mut component, &package);
+        // The definition is requested once per crate version
+        enricher.enrich(&mut component_of(&package), &package);
+
+        assert_eq!(
+            component.evidence.unwrap().licenses,
+            Some(Licenses(vec![
+                LicenseChoice::Expression(SpdxExpression::new("MIT OR Apache-2.0")),
+                LicenseChoice::Expression(SpdxExpression::new("Apache-2.0")),
+                LicenseChoice::License(License::named_license("Some License")),
+            ]))
+        );
+        assert_eq!(
+            component.properties,
+            Some(Properties(vec![Property::new(SCORE_PROPERTY, "87")]))
+        );
+        assert_eq!(
+            *enricher.transport.urls.lock().unwrap(),
+            vec![format!(
+                "https://clearlydefined.example.com/definitions/crate/cratesio/-/{}/{}",
+                package.name, package.version
+            )]
+        );
+    }
+
+    #[test]
+    fn it_should_leave_components_without_a_definition_untouched() {
+        let package: Package = serde_json::from_str(CRATES_IO_PACKAGE_JSON).unwrap();
+        let mut component = component_of(&package);
+
+        enricher(404, "Not found").enrich(&mut component, &package);
+        enricher(200, "{}").enrich(&mut component, &package);
+
+        assert_eq!(component, component_of(&package));
+    }
+
+    #[test]
+    fn it_should_skip_packages_not_from_crates_io() {
+        let package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let enricher = enricher(200, "{}");
+
+        enricher.enrich(&mut component_of(&package), &package);
+
+        assert!(enricher.transport.urls.lock().unwrap().is_empty());
+    }
+}
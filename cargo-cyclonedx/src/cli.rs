@@ -1,15 +1,20 @@
 use cargo_cyclonedx::{
     config::{
-        Describe, Features, FilenameOverride, FilenameOverrideError, FilenamePattern,
-        IncludedDependencies, LicenseParserOptions, OutputOptions, ParseMode, PlatformSuffix,
-        SbomConfig, Target,
+        AttestationOptions, ComponentFilter, Describe, Features, FilenameOverride,
+        FilenameOverrideError, FilenamePattern, IncludedDependencies, LicenseParserOptions,
+        LicensePolicy, Organization, OutputOptions, OutputTemplate, OutputTemplateError, ParseMode,
+        PlatformSuffix, RootClassification, SbomConfig, Target, ToolsFormat, WorkspaceMembers,
     },
     format::Format,
     platform::host_platform,
+    upload::{UploadApi, UploadOptions},
 };
-use clap::{ArgAction, ArgGroup, Parser};
-use cyclonedx_bom::models::bom::SpecVersion;
-use std::collections::HashSet;
+use clap::{ArgAction, ArgGroup, Parser, Subcommand};
+use cyclonedx_bom::external_models::date_time::TimestampSource;
+use cyclonedx_bom::external_models::uri::{Uri, UriError};
+use cyclonedx_bom::models::bom::{JsonFormat, OutputOptions as JsonOutputOptions, SpecVersion};
+use cyclonedx_bom::render::RenderFormat;
+use std::collections::BTreeSet;
 use std::iter::FromIterator;
 use std::path;
 use thiserror::Error;
@@ -26,13 +31,59 @@ pub enum Opts {
 #[clap(version)]
 #[clap(group(ArgGroup::new("dependencies-group").required(false).args(&["all", "top-level"])))]
 pub struct Args {
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+
     /// Path to Cargo.toml
     #[clap(long = "manifest-path", value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
     pub manifest_path: Option<path::PathBuf>,
 
-    /// Output BOM format: json, xml
-    #[clap(long = "format", short = 'f', value_name = "FORMAT")]
-    pub format: Option<Format>,
+    /// Build the SBOM from this Cargo.lock alone, without resolving the manifests with `cargo metadata`
+    #[clap(long = "from-lockfile", value_name = "PATH", conflicts_with_all = ["manifest_path", "all_features", "no_default_features", "features"], value_hint = clap::ValueHint::FilePath)]
+    pub from_lockfile: Option<path::PathBuf>,
+
+    /// Number of threads creating the SBOMs of workspace members and their components, defaults to the number of CPUs
+    #[clap(long = "jobs", short = 'j', value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    pub jobs: Option<u16>,
+
+    /// Regenerate the SBOMs even if their inputs did not change since the last run
+    #[clap(long = "force")]
+    pub force: bool,
+
+    /// Run without accessing the network, as `cargo --offline`
+    #[clap(long = "offline")]
+    pub offline: bool,
+
+    /// Fail if Cargo.lock needs to be updated, as `cargo --locked`
+    #[clap(long = "locked")]
+    pub locked: bool,
+
+    /// Equivalent to both --locked and --offline, as `cargo --frozen`
+    #[clap(long = "frozen")]
+    pub frozen: bool,
+
+    /// Output BOM formats: json, xml. Comma separated or repeated to output several formats at once
+    #[clap(
+        long = "format",
+        short = 'f',
+        alias = "output-format",
+        value_name = "FORMAT",
+        value_delimiter = ',',
+        action = ArgAction::Append
+    )]
+    pub format: Vec<Format>,
+
+    /// Write JSON without any whitespace instead of indented
+    #[clap(long = "json-compact")]
+    pub json_compact: bool,
+
+    /// Write the keys of JSON objects in alphabetical order instead of the order of the specification
+    #[clap(long = "json-sort-keys")]
+    pub json_sort_keys: bool,
+
+    /// Write the `$schema` property with the URL of the JSON schema of the spec version
+    #[clap(long = "json-schema")]
+    pub json_schema: bool,
 
     // the ValueEnum derive provides ample help text
     #[clap(long = "describe")]
@@ -89,6 +140,23 @@ Defaults to the host target, as printed by 'rustc -vV'"
     )]
     pub filename_override: Option<String>,
 
+    /// Path to write the SBOM to, with placeholders like `{crate}_{version}.cdx.{ext}`, or `-` for stdout
+    #[clap(
+        long = "output-file",
+        short = 'o',
+        value_name = "PATTERN",
+        conflicts_with_all = ["filename_override", "target_in_filename"],
+        long_help = "Path to write the SBOM to, or '-' to write it to stdout.
+Relative paths are resolved against '--output-dir', or else the folder of each Cargo.toml.
+The path may contain the placeholders {crate}, {version}, {ext}, {target} and {kind},
+e.g. '{crate}_{version}.cdx.{ext}'"
+    )]
+    pub output_file: Option<String>,
+
+    /// Directory to write the SBOMs to instead of the folder of each Cargo.toml
+    #[clap(long = "output-dir", value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    pub output_dir: Option<path::PathBuf>,
+
     /// Reject the deprecated '/' separator for licenses, treating 'MIT/Apache-2.0' as an error
     #[clap(long = "license-strict")]
     pub license_strict: bool,
@@ -97,13 +165,191 @@ Defaults to the host target, as printed by 'rustc -vV'"
     #[clap(long = "license-accept-named", action=ArgAction::Append)]
     pub license_accept_named: Vec<String>,
 
-    /// The CycloneDX specification version to output: `1.3`, `1.4` or `1.5`. Defaults to 1.3
+    /// The CycloneDX specification version to output: `1.3`, `1.4`, `1.5` or `1.6`. Defaults to 1.3
     #[clap(long = "spec-version")]
     pub spec_version: Option<SpecVersion>,
 
     /// Do not include build-time dependencies in the SBOM
     #[clap(long = "no-build-deps")]
     pub no_build_deps: bool,
+
+    /// The timestamp to record in the SBOM: `utc`, a fixed UTC offset or an ISO 8601 timestamp
+    #[clap(
+        long = "timestamp",
+        value_name = "TIMESTAMP",
+        long_help = "The timestamp to record in the SBOM metadata.
+Use 'utc' for the current time in UTC (default), a fixed offset like '+02:00' for the current time
+in that timezone, or a complete ISO 8601 timestamp like '2024-01-01T00:00:00Z' to use it verbatim"
+    )]
+    pub timestamp: Option<TimestampSource>,
+
+    /// Classify the crate the SBOM describes as an application or a library,
+    /// instead of depending on whether it has a bin target
+    #[clap(long = "root-classification")]
+    pub root_classification: Option<RootClassification>,
+
+    // the ValueEnum derive provides ample help text
+    #[clap(long = "workspace-members")]
+    pub workspace_members: Option<WorkspaceMembers>,
+
+    // the ValueEnum derive provides ample help text
+    #[clap(long = "tools-format")]
+    pub tools_format: Option<ToolsFormat>,
+
+    /// Include a CPE 2.3 name made of the crate name and version for every component
+    #[clap(long = "cpe")]
+    pub cpe: bool,
+
+    /// Include the build artifacts of bin, cdylib and staticlib targets as components
+    #[clap(long = "artifacts")]
+    pub artifacts: bool,
+
+    /// The Cargo profile the artifacts are built with, e.g. `release`, to record in the SBOM
+    #[clap(long = "profile", value_name = "PROFILE")]
+    pub profile: Option<String>,
+
+    /// Record the git commit, tag, dirty state and remote of the checkout in the root component
+    #[clap(long = "vcs")]
+    pub vcs: bool,
+
+    /// Record a property in the SBOM metadata, e.g. `acme:build-id=1234` or a pipeline URL
+    #[clap(long = "property", value_name = "NAME=VALUE", action = ArgAction::Append, value_parser = parse_property)]
+    pub property: Vec<(String, String)>,
+
+    /// The organization supplying the described crate, recorded in the SBOM metadata
+    #[clap(long = "supplier", value_name = "NAME")]
+    pub supplier: Option<String>,
+
+    /// The URL of the supplier
+    #[clap(long = "supplier-url", value_name = "URL", requires = "supplier", value_parser = parse_url)]
+    pub supplier_url: Option<String>,
+
+    /// The organization building the described crate, recorded in the SBOM metadata
+    #[clap(long = "manufacturer", value_name = "NAME")]
+    pub manufacturer: Option<String>,
+
+    /// The URL of the manufacturer
+    #[clap(long = "manufacturer-url", value_name = "URL", requires = "manufacturer", value_parser = parse_url)]
+    pub manufacturer_url: Option<String>,
+
+    /// TOML file mapping `name@version` or `name` of dependencies to SPDX expressions replacing their licenses
+    #[clap(long = "license-overrides", value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    pub license_overrides: Option<path::PathBuf>,
+
+    /// Leave this crate out of the SBOM, given as `name` or `name@version`
+    #[clap(long = "exclude", value_name = "CRATE", action = ArgAction::Append)]
+    pub exclude: Vec<String>,
+
+    /// Leave the local crates in directories matching this glob, relative to the workspace root, out of the SBOM
+    #[clap(long = "exclude-path", value_name = "GLOB", action = ArgAction::Append, value_parser = glob::Pattern::new)]
+    pub exclude_path: Vec<glob::Pattern>,
+
+    /// Leave all crates except this one, given as `name` or `name@version`, out of the SBOM
+    #[clap(long = "include-only", value_name = "CRATE", action = ArgAction::Append)]
+    pub include_only: Vec<String>,
+
+    /// Record which dependencies are yanked, as found in the crates.io index or with --offline in its local cache
+    #[clap(long = "check-yanked")]
+    pub check_yanked: bool,
+
+    /// Fail after writing the SBOMs if a dependency is yanked, implies --check-yanked
+    #[clap(long = "fail-on-yanked")]
+    pub fail_on_yanked: bool,
+
+    /// Record unmaintained dependencies, as found in a local checkout of the RustSec advisory database
+    #[clap(long = "advisory-db", value_name = "PATH", value_hint = clap::ValueHint::DirPath)]
+    pub advisory_db: Option<path::PathBuf>,
+
+    /// Also write an in-toto statement with the JSON SBOM as its CycloneDX predicate, to `<name>.intoto.json`
+    #[clap(long = "attestation")]
+    pub attestation: bool,
+
+    /// A file the attestation is about, usually a binary described by the SBOM, identified by its SHA-256 digest
+    #[clap(long = "attestation-subject", value_name = "FILE", action = ArgAction::Append, requires = "attestation", value_hint = clap::ValueHint::FilePath)]
+    pub attestation_subject: Vec<path::PathBuf>,
+
+    /// Shell command to pipe the statement to, e.g. a cosign invocation; its output is written to `<name>.intoto.jsonl`
+    #[clap(
+        long = "attestation-signer",
+        value_name = "COMMAND",
+        requires = "attestation"
+    )]
+    pub attestation_signer: Option<String>,
+
+    /// Record the licenses and licensing score ClearlyDefined has for each crates.io dependency as license evidence
+    #[clap(long = "clearlydefined", conflicts_with_all = ["offline", "frozen"])]
+    pub clearlydefined: bool,
+
+    /// Write a JSON report of skipped crates, license decisions, missing hashes and warnings to this file
+    #[clap(long = "report", value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    pub report: Option<path::PathBuf>,
+
+    /// Write the dependency graph of the SBOM to this file, as mermaid for `.mmd` files and as GraphViz DOT otherwise
+    #[clap(long = "graph", value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    pub graph: Option<path::PathBuf>,
+
+    /// Upload the SBOMs to Dependency-Track, or to a BOM repository server with --upload-api, after writing them, using curl
+    #[clap(long = "upload", requires = "dtrack_url")]
+    pub upload: bool,
+
+    /// Base URL of the Dependency-Track server, or another server implementing its BOM API
+    #[clap(
+        long = "dtrack-url",
+        alias = "upload-url",
+        value_name = "URL",
+        requires = "upload"
+    )]
+    pub dtrack_url: Option<String>,
+
+    /// The API to upload with, Dependency-Track's BOM API (default) or the BOM Exchange API of a BOM repository server
+    #[clap(long = "upload-api", value_name = "API", requires = "upload")]
+    pub upload_api: Option<UploadApi>,
+
+    /// API key of a team with the BOM_UPLOAD and PROJECT_CREATION_UPLOAD permissions
+    #[clap(
+        long = "api-key",
+        value_name = "KEY",
+        env = "DTRACK_API_KEY",
+        hide_env_values = true
+    )]
+    pub api_key: Option<String>,
+
+    /// Project to upload to, defaults to the name of the described crate or binary
+    #[clap(long = "project-name", value_name = "NAME", requires = "upload")]
+    pub project_name: Option<String>,
+
+    /// Project version to upload to, defaults to the version of the described crate
+    #[clap(long = "project-version", value_name = "VERSION", requires = "upload")]
+    pub project_version: Option<String>,
+
+    /// How often to retry an upload after a connection error or a server error
+    #[clap(long = "upload-retries", value_name = "N", default_value_t = 3)]
+    pub upload_retries: u32,
+
+    /// Accept invalid TLS certificates when uploading
+    #[clap(long = "upload-insecure", requires = "upload")]
+    pub upload_insecure: bool,
+
+    /// PEM file with the certificates to trust when uploading
+    #[clap(long = "upload-ca-cert", value_name = "FILE", requires = "upload", value_hint = clap::ValueHint::FilePath)]
+    pub upload_ca_cert: Option<path::PathBuf>,
+
+    /// Fail if a dependency can only be used under this license, an SPDX identifier or expression
+    #[clap(long = "deny-license", value_name = "LICENSE", action = ArgAction::Append)]
+    pub deny_license: Vec<String>,
+
+    /// Fail if a dependency cannot be used under the allowed licenses, given as SPDX identifiers
+    #[clap(long = "allow-license", value_name = "LICENSE", action = ArgAction::Append)]
+    pub allow_license: Vec<String>,
+
+    /// Print a summary of the components, licenses and vulnerabilities of each SBOM, as markdown (default) or text
+    #[clap(
+        long = "summary",
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "markdown"
+    )]
+    pub summary: Option<RenderFormat>,
 }
 
 impl Args {
@@ -151,33 +397,96 @@ impl Args {
             false => PlatformSuffix::NotIncluded,
         };
 
-        let filename_pattern = match &self.filename_override {
-            Some(string) => {
+        let filename_pattern = match (&self.filename_override, self.output_file.as_deref()) {
+            (_, Some("-")) => FilenamePattern::Stdout,
+            (_, Some(template)) => FilenamePattern::Template(OutputTemplate::new(template)?),
+            (Some(string), None) => {
                 let name_override = FilenameOverride::new(string)?;
                 FilenamePattern::Custom(name_override)
             }
-            None => FilenamePattern::CrateName,
+            (None, None) => FilenamePattern::CrateName,
+        };
+
+        let mut formats = Vec::new();
+        for format in &self.format {
+            if !formats.contains(format) {
+                formats.push(*format);
+            }
+        }
+        let formats = (!formats.is_empty()).then_some(formats);
+
+        match (&filename_pattern, &formats) {
+            (FilenamePattern::Stdout, Some(formats)) if formats.len() > 1 => {
+                return Err(ArgsError::MultipleFormatsToStdout)
+            }
+            (FilenamePattern::Template(template), Some(formats))
+                if formats.len() > 1 && !template.contains("ext") =>
+            {
+                return Err(ArgsError::MultipleFormatsToFile)
+            }
+            _ => (),
+        }
+
+        let attestation = match self.attestation {
+            true if self.attestation_subject.is_empty() => {
+                return Err(ArgsError::AttestationWithoutSubject)
+            }
+            true => Some(AttestationOptions {
+                subjects: self.attestation_subject.clone(),
+                signer: self.attestation_signer.clone(),
+            }),
+            false => None,
         };
 
-        let output_options = Some(OutputOptions {
+        let output_options = OutputOptions {
             filename: filename_pattern,
             platform_suffix,
-        });
+            directory: self.output_dir.clone(),
+        };
+        // Leave the defaults to the `[package.metadata.cyclonedx]` configuration
+        let output_options = (output_options != OutputOptions::default()).then_some(output_options);
 
         let license_parser = Some(LicenseParserOptions {
             mode: match self.license_strict {
                 true => ParseMode::Strict,
                 false => ParseMode::Lax,
             },
-            accept_named: HashSet::from_iter(self.license_accept_named.clone()),
+            accept_named: BTreeSet::from_iter(self.license_accept_named.clone()),
         });
 
         let describe = self.describe;
         let spec_version = self.spec_version;
-        let only_normal_deps = Some(self.no_build_deps);
+        let only_normal_deps = self.no_build_deps.then_some(true);
+        let timestamp = self.timestamp.clone();
+        let tools_format = self.tools_format;
+        let json_output = (self.json_compact || self.json_sort_keys || self.json_schema).then_some(
+            JsonOutputOptions {
+                format: match self.json_compact {
+                    true => JsonFormat::Compact,
+                    false => JsonFormat::Pretty,
+                },
+                sort_keys: self.json_sort_keys,
+                schema: self.json_schema,
+            },
+        );
+        let cpe = Some(self.cpe);
+        let artifacts = Some(self.artifacts);
+        let profile = self.profile.clone();
+        let vcs = Some(self.vcs);
+        let license_policy = LicensePolicy {
+            allow_licenses: self.allow_license.clone(),
+            deny_licenses: self.deny_license.clone(),
+        };
+        let license_policy = (!license_policy.is_empty()).then_some(license_policy);
+        let component_filter = ComponentFilter {
+            exclude: self.exclude.clone(),
+            exclude_paths: self.exclude_path.clone(),
+            include_only: self.include_only.clone(),
+        };
+        let component_filter = (!component_filter.is_empty()).then_some(component_filter);
 
         Ok(SbomConfig {
-            format: self.format,
+            formats,
             included_dependencies,
             output_options,
             features,
@@ -186,14 +495,175 @@ impl Args {
             describe,
             spec_version,
             only_normal_deps,
+            timestamp,
+            tools_format,
+            json_output,
+            cpe,
+            license_policy,
+            artifacts,
+            profile,
+            vcs,
+            // Read from the `--license-overrides` file when generating the SBOMs
+            license_overrides: None,
+            properties: (!self.property.is_empty())
+                .then(|| self.property.iter().cloned().collect()),
+            supplier: self.supplier.clone().map(|name| Organization {
+                name,
+                url: self.supplier_url.clone(),
+            }),
+            manufacturer: self.manufacturer.clone().map(|name| Organization {
+                name,
+                url: self.manufacturer_url.clone(),
+            }),
+            root_classification: self.root_classification,
+            workspace_members: self.workspace_members,
+            component_filter,
+            check_yanked: self.check_yanked.then_some(true),
+            fail_on_yanked: self.fail_on_yanked.then_some(true),
+            advisory_db: self.advisory_db.clone(),
+            attestation,
+            jobs: self.jobs.map(usize::from),
+            offline: (self.offline || self.frozen).then_some(true),
+        })
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Creates SBOMs for compiled binaries, from their `cargo auditable` data or else their panic messages
+    Binary(BinaryArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct BinaryArgs {
+    /// The binaries to describe, e.g. in `target/release`
+    #[clap(required = true, value_name = "BINARY", value_hint = clap::ValueHint::FilePath)]
+    pub binaries: Vec<path::PathBuf>,
+
+    /// Output BOM formats: json, xml. Comma separated or repeated to output several formats at once
+    #[clap(
+        long = "format",
+        short = 'f',
+        value_name = "FORMAT",
+        value_delimiter = ',',
+        action = ArgAction::Append
+    )]
+    pub format: Vec<Format>,
+
+    /// Path to write the SBOM to, or '-' to write it to stdout. Defaults to `<binary>.cdx.<ext>` next to each binary
+    #[clap(
+        long = "output-file",
+        short = 'o',
+        value_name = "PATH",
+        conflicts_with = "output_dir"
+    )]
+    pub output_file: Option<path::PathBuf>,
+
+    /// Directory to write the SBOMs to instead of the folder of each binary
+    #[clap(long = "output-dir", value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    pub output_dir: Option<path::PathBuf>,
+
+    /// The CycloneDX specification version to output: `1.3`, `1.4`, `1.5` or `1.6`. Defaults to 1.3
+    #[clap(long = "spec-version")]
+    pub spec_version: Option<SpecVersion>,
+}
+
+impl BinaryArgs {
+    pub fn as_config(&self) -> Result<SbomConfig, ArgsError> {
+        if self.output_file.is_some() && self.binaries.len() * self.format.len().max(1) > 1 {
+            return Err(ArgsError::MultipleSbomsToOutputFile);
+        }
+        Ok(SbomConfig {
+            formats: (!self.format.is_empty()).then(|| self.format.clone()),
+            spec_version: self.spec_version,
+            ..SbomConfig::empty_config()
         })
     }
+
+    /// Returns the path to write the SBOM of `binary` to, or `None` to write it to stdout
+    pub fn output_path(&self, binary: &path::Path, format: Format) -> Option<path::PathBuf> {
+        match &self.output_file {
+            Some(path) if path.as_os_str() == "-" => None,
+            Some(path) => Some(path.clone()),
+            None => {
+                let mut file_name = binary.file_name().unwrap_or_default().to_owned();
+                file_name.push(format!(".cdx.{format}"));
+                let directory = match &self.output_dir {
+                    Some(directory) => directory.as_path(),
+                    None => binary.parent().unwrap_or(path::Path::new("")),
+                };
+                Some(directory.join(file_name))
+            }
+        }
+    }
+}
+
+impl Args {
+    /// The options to upload the SBOMs with, if `--upload` is given
+    pub fn upload_options(&self) -> Result<Option<UploadOptions>, ArgsError> {
+        if !self.upload {
+            return Ok(None);
+        }
+        if self.output_file.as_deref() == Some("-") {
+            return Err(ArgsError::UploadFromStdout);
+        }
+        let api = self.upload_api.unwrap_or_default();
+        // BOM repository servers don't need authentication
+        let api_key = match (self.api_key.clone(), api) {
+            (Some(api_key), _) => api_key,
+            (None, UploadApi::BomExchange) => String::new(),
+            (None, UploadApi::DependencyTrack) => return Err(ArgsError::UploadWithoutApiKey),
+        };
+
+        Ok(Some(UploadOptions {
+            api,
+            url: self.dtrack_url.clone().unwrap_or_default(),
+            api_key,
+            project_name: self.project_name.clone(),
+            project_version: self.project_version.clone(),
+            retries: self.upload_retries,
+            insecure: self.upload_insecure,
+            ca_cert: self.upload_ca_cert.clone(),
+        }))
+    }
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum ArgsError {
     #[error("Invalid filename")]
     FilenameOverrideError(#[from] FilenameOverrideError),
+
+    #[error("Invalid output file")]
+    OutputTemplateError(#[from] OutputTemplateError),
+
+    #[error("Only a single format can be written to stdout")]
+    MultipleFormatsToStdout,
+
+    #[error("The output file must contain {{ext}} to write multiple formats")]
+    MultipleFormatsToFile,
+
+    #[error("An attestation needs at least one --attestation-subject")]
+    AttestationWithoutSubject,
+
+    #[error("Uploading needs an --api-key or the DTRACK_API_KEY environment variable")]
+    UploadWithoutApiKey,
+
+    #[error("SBOMs written to stdout cannot be uploaded")]
+    UploadFromStdout,
+
+    #[error("Only a single binary and format can be written to --output-file")]
+    MultipleSbomsToOutputFile,
+}
+
+fn parse_property(property: &str) -> Result<(String, String), String> {
+    match property.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_owned(), value.to_owned())),
+        _ => Err(format!("Expected NAME=VALUE, got `{property}`")),
+    }
+}
+
+fn parse_url(url: &str) -> Result<String, UriError> {
+    Uri::try_from(url.to_owned()).map(|uri| uri.to_string())
 }
 
 #[cfg(test)]
@@ -233,6 +703,349 @@ mod tests {
         assert!(!contains_feature(&config, ""));
     }
 
+    #[test]
+    fn parse_timestamp() {
+        let args = vec!["cyclonedx"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.timestamp(), TimestampSource::Utc);
+
+        let args = vec!["cyclonedx", "--timestamp=+01:00"];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.timestamp(),
+            TimestampSource::FixedOffset {
+                hours: 1,
+                minutes: 0
+            }
+        );
+
+        let args = vec!["cyclonedx", "--timestamp=2024-01-01T00:00:00Z"];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.timestamp().timestamp().unwrap().to_string(),
+            "2024-01-01T00:00:00Z"
+        );
+
+        let args = ["cyclonedx", "--timestamp=yesterday"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_summary() {
+        let args = ["cyclonedx"];
+        assert_eq!(Args::parse_from(args.iter()).summary, None);
+
+        let args = ["cyclonedx", "--summary"];
+        assert_eq!(
+            Args::parse_from(args.iter()).summary,
+            Some(RenderFormat::Markdown)
+        );
+
+        let args = ["cyclonedx", "--summary=text"];
+        assert_eq!(
+            Args::parse_from(args.iter()).summary,
+            Some(RenderFormat::Text)
+        );
+
+        let args = ["cyclonedx", "--summary=html"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_formats() {
+        let args = vec!["cyclonedx"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.formats(), vec![Format::Xml]);
+
+        let args = vec!["cyclonedx", "--format=json"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.formats(), vec![Format::Json]);
+
+        let args = vec!["cyclonedx", "--output-format=json,xml", "-f", "json"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.formats(), vec![Format::Json, Format::Xml]);
+
+        let args = ["cyclonedx", "--format=yaml"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_output_file() {
+        let args = vec!["cyclonedx"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.output_options().filename, FilenamePattern::CrateName);
+        assert_eq!(config.output_options().directory, None);
+
+        let args = vec!["cyclonedx", "--output-file=-"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.output_options().filename, FilenamePattern::Stdout);
+
+        let args = vec![
+            "cyclonedx",
+            "--output-file={crate}_{version}.cdx.{ext}",
+            "--output-dir=sboms",
+        ];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.output_options().filename,
+            FilenamePattern::Template(OutputTemplate::new("{crate}_{version}.cdx.{ext}").unwrap())
+        );
+        assert_eq!(
+            config.output_options().directory,
+            Some(path::PathBuf::from("sboms"))
+        );
+
+        let args = ["cyclonedx", "--output-file=-", "--format=json,xml"];
+        assert_eq!(
+            Args::parse_from(args.iter()).as_config(),
+            Err(ArgsError::MultipleFormatsToStdout)
+        );
+
+        let args = ["cyclonedx", "--output-file=bom.json", "--format=json,xml"];
+        assert_eq!(
+            Args::parse_from(args.iter()).as_config(),
+            Err(ArgsError::MultipleFormatsToFile)
+        );
+
+        let args = [
+            "cyclonedx",
+            "--output-file=bom.json",
+            "--override-filename=bom",
+        ];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_json_output() {
+        let args = vec!["cyclonedx"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.json_output, None);
+        assert_eq!(config.json_output(), JsonOutputOptions::default());
+
+        let args = vec!["cyclonedx", "--json-compact", "--json-schema"];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.json_output(),
+            JsonOutputOptions {
+                format: JsonFormat::Compact,
+                sort_keys: false,
+                schema: true,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_tools_format() {
+        let args = vec!["cyclonedx"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.tools_format(), ToolsFormat::List);
+
+        let args = vec!["cyclonedx", "--tools-format=object"];
+        let config = parse_to_config(&args);
+        assert_eq!(config.tools_format(), ToolsFormat::Object);
+
+        let args = ["cyclonedx", "--tools-format=array"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_license_policy() {
+        let args = vec!["cyclonedx"];
+        let config = parse_to_config(&args);
+        assert!(config.license_policy.is_none());
+
+        let args = vec![
+            "cyclonedx",
+            "--allow-license=MIT",
+            "--allow-license=Apache-2.0",
+            "--deny-license=GPL-3.0-only OR AGPL-3.0-only",
+        ];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.license_policy(),
+            LicensePolicy {
+                allow_licenses: vec!["MIT".into(), "Apache-2.0".into()],
+                deny_licenses: vec!["GPL-3.0-only OR AGPL-3.0-only".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_maintenance_checks() {
+        let args = vec!["cyclonedx"];
+        let config = parse_to_config(&args);
+        assert!(!config.check_yanked());
+        assert!(!config.fail_on_yanked());
+        assert!(config.advisory_db.is_none());
+
+        let args = vec!["cyclonedx", "--fail-on-yanked", "--advisory-db=advisory-db"];
+        let config = parse_to_config(&args);
+        assert!(config.check_yanked());
+        assert!(config.fail_on_yanked());
+        assert_eq!(config.advisory_db, Some(path::PathBuf::from("advisory-db")));
+        assert!(!config.offline());
+
+        assert!(parse_to_config(&["cyclonedx", "--offline"]).offline());
+        assert!(parse_to_config(&["cyclonedx", "--frozen"]).offline());
+    }
+
+    #[test]
+    fn parse_attestation() {
+        let args = vec!["cyclonedx"];
+        let config = parse_to_config(&args);
+        assert!(config.attestation.is_none());
+
+        let args = vec![
+            "cyclonedx",
+            "--attestation",
+            "--attestation-subject=target/release/my-tool",
+            "--attestation-signer=cosign sign-blob -",
+        ];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.attestation,
+            Some(AttestationOptions {
+                subjects: vec![path::PathBuf::from("target/release/my-tool")],
+                signer: Some("cosign sign-blob -".to_owned()),
+            })
+        );
+
+        let args = ["cyclonedx", "--attestation"];
+        let args = Args::try_parse_from(args.iter()).unwrap();
+        assert_eq!(args.as_config(), Err(ArgsError::AttestationWithoutSubject));
+
+        let args = ["cyclonedx", "--attestation-subject=my-tool"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_upload_options() {
+        let args = Args::parse_from(["cyclonedx"]);
+        assert_eq!(args.upload_options(), Ok(None));
+
+        let args = Args::parse_from([
+            "cyclonedx",
+            "--upload",
+            "--dtrack-url=https://dtrack.example.com",
+            "--api-key=odt_secret",
+            "--project-name=my-project",
+            "--upload-retries=1",
+            "--upload-ca-cert=ca.pem",
+        ]);
+        assert_eq!(
+            args.upload_options(),
+            Ok(Some(UploadOptions {
+                api: UploadApi::DependencyTrack,
+                url: "https://dtrack.example.com".to_owned(),
+                api_key: "odt_secret".to_owned(),
+                project_name: Some("my-project".to_owned()),
+                project_version: None,
+                retries: 1,
+                insecure: false,
+                ca_cert: Some(path::PathBuf::from("ca.pem")),
+            }))
+        );
+
+        let args = Args::parse_from([
+            "cyclonedx",
+            "--upload",
+            "--dtrack-url=https://dtrack.example.com",
+            "--api-key=odt_secret",
+            "--output-file=-",
+        ]);
+        assert_eq!(args.upload_options(), Err(ArgsError::UploadFromStdout));
+
+        let args = Args::parse_from([
+            "cyclonedx",
+            "--upload",
+            "--upload-api=bom-exchange",
+            "--upload-url=https://bom-repository.example.com",
+        ]);
+        let options = args.upload_options().unwrap().unwrap();
+        assert_eq!(options.api, UploadApi::BomExchange);
+        assert_eq!(options.url, "https://bom-repository.example.com");
+        assert_eq!(options.api_key, "");
+
+        let args = ["cyclonedx", "--upload"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_clearlydefined() {
+        let args = Args::parse_from(["cyclonedx", "--clearlydefined"]);
+        assert!(args.clearlydefined);
+
+        let args = ["cyclonedx", "--clearlydefined", "--offline"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_from_lockfile() {
+        let args = Args::parse_from(["cyclonedx", "--from-lockfile=Cargo.lock"]);
+        assert_eq!(args.from_lockfile, Some(path::PathBuf::from("Cargo.lock")));
+
+        let args = ["cyclonedx", "--from-lockfile=Cargo.lock", "--all-features"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_jobs() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert!(config.jobs.is_none());
+        assert!(config.jobs() >= 1);
+
+        let config = parse_to_config(&["cyclonedx", "--jobs=4"]);
+        assert_eq!(config.jobs(), 4);
+
+        let args = ["cyclonedx", "--jobs=0"];
+        assert!(Args::try_parse_from(args.iter()).is_err());
+    }
+
+    #[test]
+    fn parse_metadata_options() {
+        let config = parse_to_config(&[
+            "cyclonedx",
+            "--property=acme:build-id=1234",
+            "--property=acme:pipeline=https://ci.example/1234",
+            "--supplier=Acme",
+            "--supplier-url=https://acme.example",
+            "--manufacturer=Acme Build",
+        ]);
+        assert_eq!(
+            config.properties,
+            Some(std::collections::BTreeMap::from([
+                ("acme:build-id".to_owned(), "1234".to_owned()),
+                (
+                    "acme:pipeline".to_owned(),
+                    "https://ci.example/1234".to_owned()
+                ),
+            ]))
+        );
+        assert_eq!(
+            config.supplier,
+            Some(Organization {
+                name: "Acme".to_owned(),
+                url: Some("https://acme.example".to_owned()),
+            })
+        );
+        assert_eq!(
+            config.manufacturer,
+            Some(Organization {
+                name: "Acme Build".to_owned(),
+                url: None,
+            })
+        );
+
+        for args in [
+            &["cyclonedx", "--property=no-value"][..],
+            &["cyclonedx", "--property==value"],
+            &["cyclonedx", "--supplier-url=https://acme.example"],
+            &["cyclonedx", "--supplier=Acme", "--supplier-url=not a url"],
+        ] {
+            assert!(Args::try_parse_from(args.iter()).is_err());
+        }
+    }
+
     fn contains_feature(config: &SbomConfig, feature: &str) -> bool {
         config
             .features
@@ -1,6 +1,9 @@
-use cyclonedx_bom::models::bom::SpecVersion;
+use cyclonedx_bom::external_models::date_time::TimestampSource;
+use cyclonedx_bom::models::bom::{OutputOptions as JsonOutputOptions, SpecVersion};
+use serde::de::Error as _;
 use serde::Deserialize;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -22,10 +25,14 @@ use thiserror::Error;
  * SPDX-License-Identifier: Apache-2.0
  */
 use crate::format::Format;
+use crate::license_override::{
+    read_license_overrides, to_license_overrides, LicenseOverride, LicenseOverrideEntry,
+    LicenseOverrides, PACKAGE_METADATA_SOURCE,
+};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct SbomConfig {
-    pub format: Option<Format>,
+    pub formats: Option<Vec<Format>>,
     pub included_dependencies: Option<IncludedDependencies>,
     pub output_options: Option<OutputOptions>,
     pub features: Option<Features>,
@@ -34,6 +41,36 @@ pub struct SbomConfig {
     pub describe: Option<Describe>,
     pub spec_version: Option<SpecVersion>,
     pub only_normal_deps: Option<bool>,
+    pub timestamp: Option<TimestampSource>,
+    pub tools_format: Option<ToolsFormat>,
+    pub json_output: Option<JsonOutputOptions>,
+    pub cpe: Option<bool>,
+    pub license_policy: Option<LicensePolicy>,
+    pub artifacts: Option<bool>,
+    pub profile: Option<String>,
+    pub vcs: Option<bool>,
+    /// Licenses replacing the ones declared by packages, keyed by `name@version` or `name`
+    pub license_overrides: Option<LicenseOverrides>,
+    /// Additional properties to record in the SBOM metadata
+    pub properties: Option<BTreeMap<String, String>>,
+    /// The organization supplying the described crate, recorded in the SBOM metadata
+    pub supplier: Option<Organization>,
+    /// The organization building the described crate, recorded in the SBOM metadata
+    pub manufacturer: Option<Organization>,
+    pub root_classification: Option<RootClassification>,
+    pub workspace_members: Option<WorkspaceMembers>,
+    pub component_filter: Option<ComponentFilter>,
+    /// Look up whether dependencies are yanked in the crates.io index
+    pub check_yanked: Option<bool>,
+    /// Fail after writing the SBOMs if a dependency is yanked
+    pub fail_on_yanked: Option<bool>,
+    /// A local checkout of the RustSec advisory database to find unmaintained dependencies
+    pub advisory_db: Option<PathBuf>,
+    pub attestation: Option<AttestationOptions>,
+    /// How many workspace members to create SBOMs for in parallel
+    pub jobs: Option<usize>,
+    /// Never access the network, e.g. to only look up yanked versions in the local registry index cache
+    pub offline: Option<bool>,
 }
 
 impl SbomConfig {
@@ -41,13 +78,94 @@ impl SbomConfig {
         Default::default()
     }
 
+    /// Reads the configuration from the `cyclonedx` table of the `[package.metadata]` of a package.
+    ///
+    /// Relative output directories are resolved against `package_dir`, the folder of its `Cargo.toml`.
+    pub fn from_package_metadata(
+        metadata: &serde_json::Value,
+        package_dir: &Path,
+    ) -> Result<Option<Self>, serde_json::Error> {
+        let Some(config) = metadata.get("cyclonedx") else {
+            return Ok(None);
+        };
+        let config = PackageMetadataConfig::deserialize(config)?;
+
+        let formats = config.format.map(|format| match format {
+            OneOrMany::One(format) => vec![format],
+            OneOrMany::Many(formats) => formats,
+        });
+
+        let filename = match config.output_file.as_deref() {
+            Some("-") => FilenamePattern::Stdout,
+            Some(template) => {
+                let template = OutputTemplate::new(template).map_err(serde_json::Error::custom)?;
+                if formats.as_ref().is_some_and(|formats| formats.len() > 1)
+                    && !template.contains("ext")
+                {
+                    return Err(serde_json::Error::custom(
+                        "Multiple formats can only be written to an output-file containing {ext}",
+                    ));
+                }
+                FilenamePattern::Template(template)
+            }
+            None => FilenamePattern::CrateName,
+        };
+        let directory = config.output_dir.map(|dir| package_dir.join(dir));
+        let output_options = (filename != FilenamePattern::CrateName || directory.is_some())
+            .then_some(OutputOptions {
+                filename,
+                platform_suffix: PlatformSuffix::NotIncluded,
+                directory,
+            });
+
+        let mut license_overrides = match config.license_overrides_file {
+            Some(path) => read_license_overrides(&package_dir.join(path))
+                .map_err(serde_json::Error::custom)?,
+            None => LicenseOverrides::new(),
+        };
+        // Overrides in the package metadata take precedence over the file
+        license_overrides.extend(
+            to_license_overrides(config.license_overrides, PACKAGE_METADATA_SOURCE)
+                .map_err(serde_json::Error::custom)?,
+        );
+
+        let component_filter = ComponentFilter {
+            exclude: config.exclude,
+            exclude_paths: config
+                .exclude_paths
+                .iter()
+                .map(|path| glob::Pattern::new(path))
+                .collect::<Result<_, _>>()
+                .map_err(serde_json::Error::custom)?,
+            include_only: config.include_only,
+        };
+
+        Ok(Some(SbomConfig {
+            formats,
+            output_options,
+            included_dependencies: config.included_dependencies,
+            only_normal_deps: config.no_build_deps,
+            spec_version: config.spec_version,
+            license_policy: (!config.license_policy.is_empty()).then_some(config.license_policy),
+            license_overrides: (!license_overrides.is_empty()).then_some(license_overrides),
+            properties: (!config.properties.is_empty()).then_some(config.properties),
+            supplier: config.supplier,
+            manufacturer: config.manufacturer,
+            root_classification: config.root_classification,
+            workspace_members: config.workspace_members,
+            component_filter: (!component_filter.is_empty()).then_some(component_filter),
+            ..SbomConfig::empty_config()
+        }))
+    }
+
     pub fn merge(&self, other: &SbomConfig) -> SbomConfig {
         SbomConfig {
-            format: other.format.or(self.format),
+            formats: other.formats.clone().or_else(|| self.formats.clone()),
             included_dependencies: other.included_dependencies.or(self.included_dependencies),
             output_options: other
                 .output_options
                 .clone()
+                .map(|other| self.output_options.clone().unwrap_or_default().merge(other))
                 .or_else(|| self.output_options.clone()),
             features: other.features.clone().or_else(|| self.features.clone()),
             target: other.target.clone().or_else(|| self.target.clone()),
@@ -59,11 +177,69 @@ impl SbomConfig {
             describe: other.describe.or(self.describe),
             spec_version: other.spec_version.or(self.spec_version),
             only_normal_deps: other.only_normal_deps.or(self.only_normal_deps),
+            timestamp: other.timestamp.clone().or_else(|| self.timestamp.clone()),
+            tools_format: other.tools_format.or(self.tools_format),
+            json_output: other.json_output.or(self.json_output),
+            cpe: other.cpe.or(self.cpe),
+            artifacts: other.artifacts.or(self.artifacts),
+            profile: other.profile.clone().or_else(|| self.profile.clone()),
+            vcs: other.vcs.or(self.vcs),
+            license_overrides: merge_maps(&self.license_overrides, &other.license_overrides),
+            properties: merge_maps(&self.properties, &other.properties),
+            supplier: other.supplier.clone().or_else(|| self.supplier.clone()),
+            manufacturer: other
+                .manufacturer
+                .clone()
+                .or_else(|| self.manufacturer.clone()),
+            root_classification: other.root_classification.or(self.root_classification),
+            workspace_members: other.workspace_members.or(self.workspace_members),
+            component_filter: other
+                .component_filter
+                .clone()
+                .map(|other| {
+                    self.component_filter
+                        .clone()
+                        .unwrap_or_default()
+                        .merge(other)
+                })
+                .or_else(|| self.component_filter.clone()),
+            check_yanked: other.check_yanked.or(self.check_yanked),
+            fail_on_yanked: other.fail_on_yanked.or(self.fail_on_yanked),
+            advisory_db: other
+                .advisory_db
+                .clone()
+                .or_else(|| self.advisory_db.clone()),
+            attestation: other
+                .attestation
+                .clone()
+                .or_else(|| self.attestation.clone()),
+            jobs: other.jobs.or(self.jobs),
+            offline: other.offline.or(self.offline),
+            license_policy: other
+                .license_policy
+                .clone()
+                .map(|other| self.license_policy.clone().unwrap_or_default().merge(other))
+                .or_else(|| self.license_policy.clone()),
         }
     }
 
-    pub fn format(&self) -> Format {
-        self.format.unwrap_or_default()
+    /// The formats to output, each SBOM is written once per format
+    pub fn formats(&self) -> Vec<Format> {
+        self.formats
+            .clone()
+            .unwrap_or_else(|| vec![Format::default()])
+    }
+
+    /// The license configured to replace the declared one of a package, if any
+    pub fn license_override(&self, name: &str, version: &str) -> Option<&LicenseOverride> {
+        let overrides = self.license_overrides.as_ref()?;
+        overrides
+            .get(&format!("{name}@{version}"))
+            .or_else(|| overrides.get(name))
+    }
+
+    pub fn spec_version(&self) -> SpecVersion {
+        self.spec_version.unwrap_or_default()
     }
 
     pub fn included_dependencies(&self) -> IncludedDependencies {
@@ -77,12 +253,141 @@ impl SbomConfig {
     pub fn license_parser(&self) -> LicenseParserOptions {
         self.license_parser.clone().unwrap_or_default()
     }
+
+    pub fn license_policy(&self) -> LicensePolicy {
+        self.license_policy.clone().unwrap_or_default()
+    }
+
+    pub fn timestamp(&self) -> TimestampSource {
+        self.timestamp.clone().unwrap_or_default()
+    }
+
+    pub fn component_filter(&self) -> ComponentFilter {
+        self.component_filter.clone().unwrap_or_default()
+    }
+
+    /// The number of threads to create SBOMs with, defaults to the available parallelism
+    pub fn jobs(&self) -> usize {
+        self.jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(usize::from)
+                .unwrap_or(1)
+        })
+    }
+
+    /// Whether to look up yanked versions, which failing on them implies
+    pub fn check_yanked(&self) -> bool {
+        self.check_yanked.unwrap_or(false) || self.fail_on_yanked()
+    }
+
+    pub fn fail_on_yanked(&self) -> bool {
+        self.fail_on_yanked.unwrap_or(false)
+    }
+
+    pub fn offline(&self) -> bool {
+        self.offline.unwrap_or(false)
+    }
+
+    pub fn workspace_members(&self) -> WorkspaceMembers {
+        self.workspace_members.unwrap_or_default()
+    }
+
+    pub fn tools_format(&self) -> ToolsFormat {
+        self.tools_format.unwrap_or_default()
+    }
+
+    /// The layout, key order and `$schema` property of JSON output
+    pub fn json_output(&self) -> JsonOutputOptions {
+        self.json_output.unwrap_or_default()
+    }
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// Combines two maps, the entries of `other` take precedence
+fn merge_maps<V: Clone>(
+    base: &Option<BTreeMap<String, V>>,
+    other: &Option<BTreeMap<String, V>>,
+) -> Option<BTreeMap<String, V>> {
+    match (base, other) {
+        (Some(base), Some(other)) => {
+            let mut merged = base.clone();
+            merged.extend(other.clone());
+            Some(merged)
+        }
+        (base, other) => other.clone().or_else(|| base.clone()),
+    }
+}
+
+/// The options which can be configured in `[package.metadata.cyclonedx]`
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all(deserialize = "kebab-case"))]
+struct PackageMetadataConfig {
+    #[serde(default)]
+    spec_version: Option<SpecVersion>,
+
+    #[serde(default)]
+    format: Option<OneOrMany<Format>>,
+
+    #[serde(default)]
+    output_file: Option<String>,
+
+    #[serde(default)]
+    output_dir: Option<PathBuf>,
+
+    #[serde(default)]
+    included_dependencies: Option<IncludedDependencies>,
+
+    #[serde(default)]
+    no_build_deps: Option<bool>,
+
+    /// Maps `name@version` or `name` to an SPDX expression
+    #[serde(default)]
+    license_overrides: BTreeMap<String, LicenseOverrideEntry>,
+
+    /// A `license-overrides.toml` file, relative to the `Cargo.toml`
+    #[serde(default)]
+    license_overrides_file: Option<PathBuf>,
+
+    #[serde(default)]
+    properties: BTreeMap<String, String>,
+
+    #[serde(default)]
+    supplier: Option<Organization>,
+
+    #[serde(default)]
+    manufacturer: Option<Organization>,
+
+    #[serde(default)]
+    root_classification: Option<RootClassification>,
+
+    #[serde(default)]
+    workspace_members: Option<WorkspaceMembers>,
+
+    #[serde(default)]
+    exclude: Vec<String>,
+
+    #[serde(default)]
+    exclude_paths: Vec<String>,
+
+    #[serde(default)]
+    include_only: Vec<String>,
+
+    #[serde(flatten)]
+    license_policy: LicensePolicy,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum IncludedDependencies {
+    #[serde(rename = "top-level")]
     TopLevelDependencies,
     #[default]
+    #[serde(rename = "all")]
     AllDependencies,
 }
 
@@ -98,10 +403,38 @@ impl FromStr for IncludedDependencies {
     }
 }
 
+/// Wraps each SBOM in an in-toto statement, see [`crate::attestation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttestationOptions {
+    /// The files the statement is about, usually the binaries described by the SBOM
+    pub subjects: Vec<PathBuf>,
+    /// A shell command the statement is piped to, whose output is written instead of the statement
+    pub signer: Option<String>,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct OutputOptions {
     pub filename: FilenamePattern,
     pub platform_suffix: PlatformSuffix,
+    /// Write the SBOMs to this directory instead of next to the `Cargo.toml` manifest
+    pub directory: Option<PathBuf>,
+}
+
+impl OutputOptions {
+    /// Combines the options, those set in `other` take precedence
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            filename: match other.filename {
+                FilenamePattern::CrateName => self.filename,
+                filename => filename,
+            },
+            platform_suffix: match other.platform_suffix {
+                PlatformSuffix::NotIncluded => self.platform_suffix,
+                platform_suffix => platform_suffix,
+            },
+            directory: other.directory.or(self.directory),
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -127,16 +460,13 @@ impl Target {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub enum FilenamePattern {
+    #[default]
     CrateName,
     Custom(FilenameOverride),
-}
-
-impl Default for FilenamePattern {
-    fn default() -> Self {
-        Self::CrateName
-    }
+    Template(OutputTemplate),
+    Stdout,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -193,6 +523,76 @@ pub enum FilenameOverrideError {
     TheOne(String),
 }
 
+/// A path for the output with placeholders, e.g. `{crate}_{version}.cdx.{ext}`
+///
+/// The supported placeholders are:
+/// * `{crate}` - The name of the crate, or of the binary or Cargo target it describes
+/// * `{version}` - The version of the crate
+/// * `{ext}` - The extension of the output format, `json` or `xml`
+/// * `{target}` - The target platform, or `all`
+/// * `{kind}` - The kinds of the described Cargo target, e.g. `bin`, empty for a crate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputTemplate(String);
+
+impl OutputTemplate {
+    const PLACEHOLDERS: [&'static str; 5] = ["crate", "version", "ext", "target", "kind"];
+
+    pub fn new(template: impl Into<String>) -> Result<Self, OutputTemplateError> {
+        let template = template.into();
+
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find(['{', '}']) {
+            if rest[start..].starts_with('}') {
+                return Err(OutputTemplateError::UnmatchedBrace(template.clone()));
+            }
+            let end = rest[start..]
+                .find('}')
+                .ok_or_else(|| OutputTemplateError::UnmatchedBrace(template.clone()))?;
+            let placeholder = &rest[start + 1..start + end];
+            if !Self::PLACEHOLDERS.contains(&placeholder) {
+                return Err(OutputTemplateError::UnknownPlaceholder(
+                    placeholder.to_owned(),
+                ));
+            }
+            rest = &rest[start + end + 1..];
+        }
+
+        Ok(Self(template))
+    }
+
+    pub fn contains(&self, placeholder: &str) -> bool {
+        self.0.contains(&format!("{{{placeholder}}}"))
+    }
+
+    pub fn render(&self, values: &TemplateValues) -> String {
+        self.0
+            .replace("{crate}", values.crate_name)
+            .replace("{version}", values.version)
+            .replace("{ext}", values.ext)
+            .replace("{target}", values.target)
+            .replace("{kind}", values.kind)
+    }
+}
+
+/// The values for the placeholders of an [`OutputTemplate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemplateValues<'a> {
+    pub crate_name: &'a str,
+    pub version: &'a str,
+    pub ext: &'a str,
+    pub target: &'a str,
+    pub kind: &'a str,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum OutputTemplateError {
+    #[error("Unknown placeholder in output file pattern: {{{0}}}")]
+    UnknownPlaceholder(String),
+
+    #[error("Unmatched brace in output file pattern: {0}")]
+    UnmatchedBrace(String),
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub enum PlatformSuffix {
     Included,
@@ -208,7 +608,7 @@ pub struct LicenseParserOptions {
 
     /// Silently accept the named licenses
     #[serde(default)]
-    pub accept_named: HashSet<String>,
+    pub accept_named: BTreeSet<String>,
 }
 
 impl LicenseParserOptions {
@@ -223,6 +623,96 @@ impl LicenseParserOptions {
     }
 }
 
+/// An organization recorded as the supplier or manufacturer in the SBOM metadata
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Organization {
+    pub name: String,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// The crates which are left out of the SBOM
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentFilter {
+    /// Leave out these crates, given as `name` or `name@version`
+    pub exclude: Vec<String>,
+
+    /// Leave out the local crates in directories matching these globs,
+    /// relative to the workspace root
+    pub exclude_paths: Vec<glob::Pattern>,
+
+    /// Leave out all crates except these, if not empty
+    pub include_only: Vec<String>,
+}
+
+impl ComponentFilter {
+    pub fn is_empty(&self) -> bool {
+        self.exclude.is_empty() && self.exclude_paths.is_empty() && self.include_only.is_empty()
+    }
+
+    pub fn merge(mut self, other: Self) -> Self {
+        self.exclude.extend(other.exclude);
+        self.exclude_paths.extend(other.exclude_paths);
+        self.include_only.extend(other.include_only);
+        self
+    }
+
+    /// Whether the crate is kept in the SBOM, `path` is the directory of a local crate
+    pub fn includes(&self, name: &str, version: &str, path: Option<&Path>) -> bool {
+        let name_and_version = format!("{name}@{version}");
+        let matches = |spec: &String| spec == name || *spec == name_and_version;
+
+        if !self.include_only.is_empty() && !self.include_only.iter().any(matches) {
+            return false;
+        }
+        if self.exclude.iter().any(matches) {
+            return false;
+        }
+        match path {
+            Some(path) => !self
+                .exclude_paths
+                .iter()
+                .any(|pattern| pattern.matches_path(path)),
+            None => true,
+        }
+    }
+}
+
+/// The licenses which are allowed or denied in the dependencies
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all(deserialize = "kebab-case"))]
+pub struct LicensePolicy {
+    /// Only allow dependencies which can be used under these licenses, if not empty
+    #[serde(default)]
+    pub allow_licenses: Vec<String>,
+
+    /// Deny dependencies which can only be used under one of these licenses
+    #[serde(default)]
+    pub deny_licenses: Vec<String>,
+}
+
+impl LicensePolicy {
+    /// Reads the policy from the `cyclonedx` table of the `[package.metadata]` of a package
+    pub fn from_package_metadata(
+        metadata: &serde_json::Value,
+    ) -> Result<Option<Self>, serde_json::Error> {
+        match metadata.get("cyclonedx") {
+            Some(config) => Ok(Some(Self::deserialize(config)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.allow_licenses.is_empty() && self.deny_licenses.is_empty()
+    }
+
+    pub fn merge(mut self, other: Self) -> Self {
+        self.allow_licenses.extend(other.allow_licenses);
+        self.deny_licenses.extend(other.deny_licenses);
+        self
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub enum ParseMode {
@@ -245,9 +735,41 @@ pub enum Describe {
     AllCargoTargets,
 }
 
+/// How is the component the SBOM describes classified?
+#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum, Deserialize)]
+#[serde(rename_all(deserialize = "kebab-case"))]
+pub enum RootClassification {
+    /// An application, the default for crates with a bin target
+    Application,
+    /// A library, the default for crates without a bin target
+    Library,
+}
+
+/// How are the workspace members a workspace member depends on recorded?
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, clap::ValueEnum, Deserialize)]
+#[serde(rename_all(deserialize = "kebab-case"))]
+pub enum WorkspaceMembers {
+    /// As top-level components, like all other dependencies. (default)
+    #[default]
+    Components,
+    /// As subcomponents of the component the SBOM describes
+    Subcomponents,
+}
+
+/// How are the tools that created the SBOM recorded?
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ToolsFormat {
+    /// The legacy list of tools, supported by all spec versions. (default)
+    #[default]
+    List,
+    /// Tools as components and services, supported since spec version 1.5
+    Object,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use cyclonedx_bom::external_models::spdx::SpdxExpression;
 
     #[test]
     fn it_should_error_for_a_filename_with_a_path_separator() {
@@ -273,6 +795,347 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn it_should_render_an_output_template() {
+        let template = OutputTemplate::new("sboms/{crate}_{version}{kind}.cdx.{ext}")
+            .expect("Should have been able to create the template");
+
+        let actual = template.render(&TemplateValues {
+            crate_name: "foo",
+            version: "1.0.0",
+            ext: "json",
+            target: "all",
+            kind: "",
+        });
+
+        assert_eq!(actual, "sboms/foo_1.0.0.cdx.json");
+        assert!(template.contains("ext"));
+        assert!(!template.contains("target"));
+    }
+
+    #[test]
+    fn it_should_error_for_an_invalid_output_template() {
+        assert_eq!(
+            OutputTemplate::new("{crate}.{extension}"),
+            Err(OutputTemplateError::UnknownPlaceholder("extension".into()))
+        );
+        assert_eq!(
+            OutputTemplate::new("{crate.json"),
+            Err(OutputTemplateError::UnmatchedBrace("{crate.json".into()))
+        );
+        assert_eq!(
+            OutputTemplate::new("crate}.json"),
+            Err(OutputTemplateError::UnmatchedBrace("crate}.json".into()))
+        );
+    }
+
+    #[test]
+    fn it_should_read_the_license_policy_from_package_metadata() {
+        let metadata = serde_json::json!({
+            "cyclonedx": {
+                "allow-licenses": ["MIT", "Apache-2.0"],
+                "deny-licenses": ["GPL-3.0-only"]
+            }
+        });
+
+        let actual = LicensePolicy::from_package_metadata(&metadata)
+            .expect("Failed to read the license policy");
+
+        assert_eq!(
+            actual,
+            Some(LicensePolicy {
+                allow_licenses: vec!["MIT".into(), "Apache-2.0".into()],
+                deny_licenses: vec!["GPL-3.0-only".into()],
+            })
+        );
+        assert!(
+            LicensePolicy::from_package_metadata(&serde_json::Value::Null)
+                .expect("Failed to read the license policy")
+                .is_none()
+        );
+        assert!(LicensePolicy::from_package_metadata(
+            &serde_json::json!({ "cyclonedx": { "deny-licenses": "GPL-3.0-only" } })
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn it_should_read_the_config_from_package_metadata() {
+        let metadata = serde_json::json!({
+            "cyclonedx": {
+                "spec-version": "1.6",
+                "deny-licenses": ["GPL-3.0-only"]
+            }
+        });
+
+        let actual = SbomConfig::from_package_metadata(&metadata, Path::new("/project"))
+            .expect("Failed to read the configuration");
+
+        assert_eq!(
+            actual,
+            Some(SbomConfig {
+                spec_version: Some(SpecVersion::V1_6),
+                license_policy: Some(LicensePolicy {
+                    allow_licenses: vec![],
+                    deny_licenses: vec!["GPL-3.0-only".into()],
+                }),
+                ..Default::default()
+            })
+        );
+        assert!(
+            SbomConfig::from_package_metadata(&serde_json::Value::Null, Path::new("/project"))
+                .expect("Failed to read the configuration")
+                .is_none()
+        );
+        assert!(SbomConfig::from_package_metadata(
+            &serde_json::json!({ "cyclonedx": { "spec-version": "1.2" } }),
+            Path::new("/project")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn it_should_read_the_output_and_dependency_options_from_package_metadata() {
+        let metadata = serde_json::json!({
+            "cyclonedx": {
+                "format": ["json", "xml"],
+                "output-file": "{crate}.cdx.{ext}",
+                "output-dir": "sboms",
+                "included-dependencies": "top-level",
+                "no-build-deps": true,
+                "license-overrides": {
+                    "foo@1.0.0": "MIT",
+                    "bar": "Apache-2.0 OR MIT"
+                },
+                "properties": { "acme:team": "platform" },
+                "supplier": { "name": "Acme", "url": "https://acme.example" },
+                "manufacturer": { "name": "Acme Build" }
+            }
+        });
+
+        let actual = SbomConfig::from_package_metadata(&metadata, Path::new("/project"))
+            .expect("Failed to read the configuration")
+            .expect("Expected a configuration");
+
+        assert_eq!(actual.formats(), vec![Format::Json, Format::Xml]);
+        assert_eq!(
+            actual.output_options(),
+            OutputOptions {
+                filename: FilenamePattern::Template(
+                    OutputTemplate::new("{crate}.cdx.{ext}").unwrap()
+                ),
+                platform_suffix: PlatformSuffix::NotIncluded,
+                directory: Some(PathBuf::from("/project/sboms")),
+            }
+        );
+        assert_eq!(
+            actual.included_dependencies(),
+            IncludedDependencies::TopLevelDependencies
+        );
+        assert_eq!(actual.only_normal_deps, Some(true));
+        assert_eq!(
+            actual
+                .license_override("foo", "1.0.0")
+                .map(|license_override| &license_override.expression),
+            Some(&SpdxExpression::new("MIT"))
+        );
+        assert_eq!(actual.license_override("foo", "2.0.0"), None);
+        assert_eq!(
+            actual.license_override("bar", "0.1.0"),
+            Some(&LicenseOverride {
+                expression: SpdxExpression::new("Apache-2.0 OR MIT"),
+                source: PACKAGE_METADATA_SOURCE.to_string(),
+                reason: None,
+            })
+        );
+        assert_eq!(
+            actual.properties,
+            Some(BTreeMap::from([("acme:team".into(), "platform".into())]))
+        );
+        assert_eq!(
+            actual.supplier,
+            Some(Organization {
+                name: "Acme".to_owned(),
+                url: Some("https://acme.example".to_owned()),
+            })
+        );
+        assert_eq!(
+            actual.manufacturer.map(|manufacturer| manufacturer.name),
+            Some("Acme Build".to_owned())
+        );
+
+        let single_format = serde_json::json!({ "cyclonedx": { "format": "json" } });
+        let actual = SbomConfig::from_package_metadata(&single_format, Path::new("/project"))
+            .expect("Failed to read the configuration")
+            .expect("Expected a configuration");
+        assert_eq!(actual.formats(), vec![Format::Json]);
+        assert_eq!(actual.output_options, None);
+
+        for invalid in [
+            serde_json::json!({ "cyclonedx": { "license-overrides": { "foo": "not a license" } } }),
+            serde_json::json!({ "cyclonedx": { "output-file": "{name}.json" } }),
+            serde_json::json!({ "cyclonedx": { "format": ["json", "xml"], "output-file": "bom" } }),
+            serde_json::json!({ "cyclonedx": { "included-dependencies": "some" } }),
+        ] {
+            assert!(
+                SbomConfig::from_package_metadata(&invalid, Path::new("/project")).is_err(),
+                "Expected {invalid} to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_read_a_license_overrides_file_from_package_metadata() {
+        let package_dir = assert_fs::TempDir::new().unwrap();
+        std::fs::write(
+            package_dir.path().join("license-overrides.toml"),
+            r#"
+            "foo" = { license = "MIT", reason = "Relicensed upstream" }
+            "bar" = "MIT"
+            "#,
+        )
+        .unwrap();
+        let metadata = serde_json::json!({
+            "cyclonedx": {
+                "license-overrides-file": "license-overrides.toml",
+                "license-overrides": { "bar": "Apache-2.0" }
+            }
+        });
+
+        let actual = SbomConfig::from_package_metadata(&metadata, package_dir.path())
+            .expect("Failed to read the configuration")
+            .expect("Expected a configuration");
+
+        let foo = actual.license_override("foo", "1.0.0").unwrap();
+        assert_eq!(foo.expression, SpdxExpression::new("MIT"));
+        assert_eq!(foo.reason.as_deref(), Some("Relicensed upstream"));
+        assert!(foo.source.ends_with("license-overrides.toml"));
+        let bar = actual.license_override("bar", "1.0.0").unwrap();
+        assert_eq!(bar.expression, SpdxExpression::new("Apache-2.0"));
+        assert_eq!(bar.source, PACKAGE_METADATA_SOURCE);
+
+        let missing = serde_json::json!({
+            "cyclonedx": { "license-overrides-file": "missing.toml" }
+        });
+        assert!(SbomConfig::from_package_metadata(&missing, package_dir.path()).is_err());
+    }
+
+    #[test]
+    fn it_should_filter_components() {
+        let filter = ComponentFilter {
+            exclude: vec!["secret".into(), "old@0.1.0".into()],
+            exclude_paths: vec![glob::Pattern::new("internal/*").unwrap()],
+            include_only: vec![],
+        };
+
+        assert!(filter.includes("serde", "1.0.0", None));
+        assert!(!filter.includes("secret", "1.0.0", None));
+        assert!(!filter.includes("old", "0.1.0", None));
+        assert!(filter.includes("old", "0.2.0", None));
+        assert!(!filter.includes("tool", "1.0.0", Some(Path::new("internal/tool"))));
+        assert!(filter.includes("tool", "1.0.0", Some(Path::new("crates/tool"))));
+
+        let filter = ComponentFilter {
+            include_only: vec!["serde".into()],
+            ..Default::default()
+        };
+        assert!(filter.includes("serde", "1.0.0", None));
+        assert!(!filter.includes("tokio", "1.0.0", None));
+    }
+
+    #[test]
+    fn it_should_prefer_the_output_options_from_the_command_line() {
+        let package_config = SbomConfig {
+            output_options: Some(OutputOptions {
+                filename: FilenamePattern::Stdout,
+                platform_suffix: PlatformSuffix::NotIncluded,
+                directory: Some(PathBuf::from("/project/sboms")),
+            }),
+            properties: Some(BTreeMap::from([
+                ("acme:team".into(), "platform".into()),
+                ("acme:tier".into(), "1".into()),
+            ])),
+            ..Default::default()
+        };
+        let cli_config = SbomConfig {
+            output_options: Some(OutputOptions {
+                filename: FilenamePattern::CrateName,
+                platform_suffix: PlatformSuffix::Included,
+                directory: Some(PathBuf::from("out")),
+            }),
+            properties: Some(BTreeMap::from([("acme:tier".into(), "2".into())])),
+            ..Default::default()
+        };
+
+        let config = package_config.merge(&cli_config);
+
+        assert_eq!(
+            config.output_options(),
+            OutputOptions {
+                filename: FilenamePattern::Stdout,
+                platform_suffix: PlatformSuffix::Included,
+                directory: Some(PathBuf::from("out")),
+            }
+        );
+        assert_eq!(
+            config.properties,
+            Some(BTreeMap::from([
+                ("acme:team".into(), "platform".into()),
+                ("acme:tier".into(), "2".into()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_prefer_the_spec_version_from_the_command_line() {
+        let package_config = SbomConfig {
+            spec_version: Some(SpecVersion::V1_6),
+            ..Default::default()
+        };
+        let cli_config = SbomConfig {
+            spec_version: Some(SpecVersion::V1_4),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            package_config.merge(&cli_config).spec_version(),
+            SpecVersion::V1_4
+        );
+        assert_eq!(
+            package_config.merge(&SbomConfig::default()).spec_version(),
+            SpecVersion::V1_6
+        );
+        assert_eq!(SbomConfig::default().spec_version(), SpecVersion::V1_3);
+    }
+
+    #[test]
+    fn it_should_merge_license_policies() {
+        let config_1 = SbomConfig {
+            license_policy: Some(LicensePolicy {
+                allow_licenses: vec!["MIT".into()],
+                deny_licenses: vec![],
+            }),
+            ..Default::default()
+        };
+        let config_2 = SbomConfig {
+            license_policy: Some(LicensePolicy {
+                allow_licenses: vec![],
+                deny_licenses: vec!["GPL-3.0-only".into()],
+            }),
+            ..Default::default()
+        };
+
+        let config = config_1.merge(&config_2);
+
+        assert_eq!(
+            config.license_policy(),
+            LicensePolicy {
+                allow_licenses: vec!["MIT".into()],
+                deny_licenses: vec!["GPL-3.0-only".into()],
+            }
+        );
+    }
+
     #[test]
     fn it_should_merge_license_names() {
         let config_1 = SbomConfig {
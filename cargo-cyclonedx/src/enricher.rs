@@ -0,0 +1,167 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Hooks for adding organization-specific metadata to the components of the SBOMs,
+//! such as the team owning a crate or its identifier in an internal inventory.
+
+use cargo_metadata::Package;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::{Component, Components};
+use std::collections::HashMap;
+
+/// Adds metadata to the components created from Cargo packages.
+///
+/// Enrichers run after the component is complete, so they may also change or remove what the
+/// generator recorded. They are shared between the threads creating the SBOMs of workspace
+/// members, hence `Sync`. Closures taking the same arguments are enrichers as well.
+pub trait ComponentEnricher: Sync {
+    /// Called for the component of every package in an SBOM, including the described one
+    fn enrich(&self, component: &mut Component, package: &Package);
+}
+
+impl<F> ComponentEnricher for F
+where
+    F: Fn(&mut Component, &Package) + Sync,
+{
+    fn enrich(&self, component: &mut Component, package: &Package) {
+        self(component, package)
+    }
+}
+
+/// Runs the enrichers on the components of the BOM created from `packages`, found by their `bom-ref`
+pub(crate) fn enrich_bom<'a>(
+    bom: &mut Bom,
+    packages: impl IntoIterator<Item = &'a Package>,
+    enrichers: &[&dyn ComponentEnricher],
+) {
+    if enrichers.is_empty() {
+        return;
+    }
+    let packages: HashMap<&str, &Package> = packages
+        .into_iter()
+        .map(|package| (package.id.repr.as_str(), package))
+        .collect();
+
+    if let Some(metadata) = bom.metadata.as_mut() {
+        if let Some(component) = metadata.component.as_mut() {
+            if let Some(package) = component
+                .bom_ref
+                .as_deref()
+                .and_then(|bom_ref| packages.get(bom_ref))
+            {
+                for enricher in enrichers {
+                    enricher.enrich(component, package);
+                }
+            }
+            if let Some(components) = component.components.as_mut() {
+                enrich_components(components, &packages, enrichers);
+            }
+        }
+    }
+    if let Some(components) = bom.components.as_mut() {
+        enrich_components(components, &packages, enrichers);
+    }
+}
+
+fn enrich_components(
+    components: &mut Components,
+    packages: &HashMap<&str, &Package>,
+    enrichers: &[&dyn ComponentEnricher],
+) {
+    for component in &mut components.0 {
+        if let Some(package) = component
+            .bom_ref
+            .as_deref()
+            .and_then(|bom_ref| packages.get(bom_ref))
+        {
+            for enricher in enrichers {
+                enricher.enrich(component, package);
+            }
+        }
+        if let Some(components) = component.components.as_mut() {
+            enrich_components(components, packages, enrichers);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::models::component::Classification;
+    use cyclonedx_bom::models::metadata::Metadata;
+    use cyclonedx_bom::models::property::{Properties, Property};
+
+    const ROOT_PACKAGE_JSON: &str = include_str!("../tests/fixtures/root_package.json");
+    const CRATES_IO_PACKAGE_JSON: &str = include_str!("../tests/fixtures/crates_io_package.json");
+
+    fn component_of(package: &Package) -> Component {
+        Component::new(
+            Classification::Library,
+            &package.name,
+            &package.version.to_string(),
+            Some(package.id.repr.clone()),
+        )
+    }
+
+    #[test]
+    fn it_should_enrich_the_components_of_packages() {
+        let root: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let dependency: Package = serde_json::from_str(CRATES_IO_PACKAGE_JSON).unwrap();
+
+        let mut root_component = component_of(&root);
+        // Cargo targets are not packages
+        root_component.components = Some(Components(vec![Component::new(
+            Classification::Application,
+            "cargo-cyclonedx",
+            "0.3.8",
+            Some(format!("{} bin-target-0", root.id)),
+        )]));
+        let mut bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(root_component),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![component_of(&dependency)])),
+            ..Bom::default()
+        };
+
+        let owner = |component: &mut Component, package: &Package| {
+            component.properties = Some(Properties(vec![Property::new(
+                "acme:owner",
+                &format!("team-{}", package.name),
+            )]));
+        };
+        enrich_bom(&mut bom, [&root, &dependency], &[&owner]);
+
+        let owner_of = |component: &Component| {
+            component
+                .properties
+                .as_ref()
+                .map(|properties| properties.0[0].value.to_string())
+        };
+        let metadata_component = bom.metadata.unwrap().component.unwrap();
+        assert_eq!(
+            owner_of(&metadata_component).as_deref(),
+            Some("team-cargo-cyclonedx")
+        );
+        assert_eq!(owner_of(&metadata_component.components.unwrap().0[0]), None);
+        assert_eq!(
+            owner_of(&bom.components.unwrap().0[0]),
+            Some(format!("team-{}", dependency.name))
+        );
+    }
+}
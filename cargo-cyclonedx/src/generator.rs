@@ -1,3 +1,4 @@
+use crate::attestation::{sign, AttestationError, Statement};
 use crate::config::Describe;
 use std::cmp::min;
 use std::collections::HashSet;
@@ -18,16 +19,30 @@ use std::collections::HashSet;
  *
  * SPDX-License-Identifier: Apache-2.0
  */
+use crate::config::AttestationOptions;
 use crate::config::FilenamePattern;
 use crate::config::PlatformSuffix;
 use crate::config::SbomConfig;
-use crate::config::{IncludedDependencies, ParseMode};
+use crate::config::{
+    ComponentFilter, IncludedDependencies, Organization, ParseMode, RootClassification, Target,
+    TemplateValues, ToolsFormat, WorkspaceMembers,
+};
+use crate::enricher::{enrich_bom, ComponentEnricher};
 use crate::format::Format;
+use crate::maintenance::{cargo_home, AdvisoryDatabase, AdvisoryDatabaseError, RegistryIndex};
+use crate::platform::{host_platform, rustc_location, rustc_version, RustcVersion};
+use crate::policy::{check_licenses, LicenseViolation};
 use crate::purl::get_purl;
+use crate::report::{
+    LicenseDecision, LicenseDecisionKind, PackageReport, SkipReason, SkippedCrate,
+};
+use crate::vcs::{git_info, GitInfo};
 
 use cargo_metadata;
+use cargo_metadata::CargoOpt;
 use cargo_metadata::DependencyKind;
 use cargo_metadata::Metadata as CargoMetadata;
+use cargo_metadata::MetadataCommand;
 use cargo_metadata::Node;
 use cargo_metadata::NodeDep;
 use cargo_metadata::Package;
@@ -35,13 +50,16 @@ use cargo_metadata::PackageId;
 
 use cargo_lock::package::Checksum;
 use cargo_lock::Lockfile;
-use cargo_metadata::camino::Utf8PathBuf;
+use cargo_metadata::camino::{Utf8Path, Utf8PathBuf};
 use cyclonedx_bom::external_models::normalized_string::NormalizedString;
 use cyclonedx_bom::external_models::spdx::SpdxExpression;
 use cyclonedx_bom::external_models::uri::Uri;
 use cyclonedx_bom::models::attached_text::AttachedText;
-use cyclonedx_bom::models::bom::Bom;
-use cyclonedx_bom::models::component::{Classification, Component, Components, Scope};
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::models::code::{Commit, Commits};
+use cyclonedx_bom::models::component::{
+    Classification, Component, Components, Cpe, CpePart, Pedigree, Scope,
+};
 use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
 use cyclonedx_bom::models::external_reference::{
     ExternalReference, ExternalReferenceType, ExternalReferences,
@@ -49,15 +67,18 @@ use cyclonedx_bom::models::external_reference::{
 use cyclonedx_bom::models::license::{License, LicenseChoice, Licenses};
 use cyclonedx_bom::models::metadata::Metadata;
 use cyclonedx_bom::models::metadata::MetadataError;
-use cyclonedx_bom::models::organization::OrganizationalContact;
+use cyclonedx_bom::models::organization::{OrganizationalContact, OrganizationalEntity};
 use cyclonedx_bom::models::property::{Properties, Property};
 use cyclonedx_bom::models::tool::{Tool, Tools};
+use cyclonedx_bom::progress::{NoProgress, Phase, Progress, ProgressHandler};
 use cyclonedx_bom::validation::Validate;
 use once_cell::sync::Lazy;
+use rayon::prelude::*;
 use regex::Regex;
 
 use log::Level;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fs::File;
@@ -65,6 +86,8 @@ use std::io::BufWriter;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 use thiserror::Error;
 use validator::validate_email;
 
@@ -104,7 +127,166 @@ impl From<&DependencyKind> for PrivateDepKind {
 pub struct SbomGenerator {
     config: SbomConfig,
     workspace_root: Utf8PathBuf,
-    crate_hashes: HashMap<cargo_metadata::PackageId, Checksum>,
+    crate_hashes: Arc<HashMap<cargo_metadata::PackageId, Checksum>>,
+    rustc: Option<RustcVersion>,
+    vcs: Option<GitInfo>,
+    workspace_members: Vec<PackageId>,
+    registry_index: Option<Arc<RegistryIndex>>,
+    advisory_db: Option<AdvisoryDatabase>,
+    report: Mutex<PackageReport>,
+}
+
+/// The packages of a workspace, shared by the threads creating the SBOMs of its members
+struct Workspace<'a> {
+    members: Vec<PackageId>,
+    packages: PackageMap,
+    resolve: ResolveMap,
+    workspace_root: Utf8PathBuf,
+    /// The checksums of the packages from the `Cargo.lock` of the workspace
+    crate_hashes: Arc<HashMap<cargo_metadata::PackageId, Checksum>>,
+    rustc: Option<RustcVersion>,
+    registry_index: OnceLock<Option<Arc<RegistryIndex>>>,
+    advisory_dbs: Mutex<HashMap<PathBuf, AdvisoryDatabase>>,
+    enrichers: &'a [&'a dyn ComponentEnricher],
+}
+
+impl Workspace<'_> {
+    fn create_sbom(
+        &self,
+        member: &PackageId,
+        config: &SbomConfig,
+    ) -> Result<GeneratedSbom, GeneratorError> {
+        log::trace!("Processing the package {}", member);
+
+        let dep_kinds = index_dep_kinds(member, &self.resolve);
+
+        let manifest_path = self.packages[member]
+            .manifest_path
+            .clone()
+            .into_std_path_buf();
+
+        let config = match SbomConfig::from_package_metadata(
+            &self.packages[member].metadata,
+            manifest_path.parent().unwrap_or(Path::new(".")),
+        ) {
+            Ok(Some(package_config)) => package_config.merge(config),
+            Ok(None) => config.clone(),
+            Err(error) => {
+                return Err(GeneratorError::PackageError {
+                    package_id: member.clone(),
+                    error: anyhow::Error::new(error)
+                        .context("Invalid `[package.metadata.cyclonedx]` configuration"),
+                })
+            }
+        };
+
+        let (dependencies, pruned_resolve) =
+            if config.included_dependencies() == IncludedDependencies::AllDependencies {
+                all_dependencies(member, &self.packages, &self.resolve, &config)
+            } else {
+                top_level_dependencies(member, &self.packages, &self.resolve, &config)
+            };
+        let selected: Vec<PackageId> = dependencies.keys().cloned().collect();
+        let (dependencies, pruned_resolve) = filter_components(
+            member,
+            dependencies,
+            pruned_resolve,
+            &config.component_filter(),
+            &self.workspace_root,
+        );
+        let skipped = selected
+            .iter()
+            .filter(|id| !dependencies.contains_key(id))
+            .map(|id| SkippedCrate {
+                name: self.packages[id].name.clone(),
+                version: self.packages[id].version.to_string(),
+                reason: SkipReason::Excluded,
+            })
+            .collect();
+
+        let vcs = match config.vcs {
+            Some(true) => {
+                let vcs = manifest_path.parent().and_then(git_info);
+                if vcs.is_none() {
+                    log::warn!(
+                        "Package {} is not in a git checkout, no VCS information will be included in the SBOM",
+                        self.packages[member].name
+                    );
+                }
+                vcs
+            }
+            _ => None,
+        };
+
+        let registry_index = match config.check_yanked() {
+            true => self
+                .registry_index
+                .get_or_init(|| {
+                    let index = cargo_home()
+                        .ok_or_else(|| "the Cargo home is not set".to_owned())
+                        .and_then(|cargo_home| {
+                            RegistryIndex::new(&cargo_home, config.offline())
+                                .map_err(|error| error.to_string())
+                        });
+                    match index {
+                        Ok(index) => Some(Arc::new(index)),
+                        Err(error) => {
+                            log::warn!(
+                                "Failed to open the crates.io index, yanked dependencies will not be detected: {}",
+                                error
+                            );
+                            None
+                        }
+                    }
+                })
+                .clone(),
+            false => None,
+        };
+
+        let advisory_db = match &config.advisory_db {
+            Some(path) => {
+                // Loaded once and shared between the members, other threads wait for it meanwhile
+                let mut advisory_dbs = self.advisory_dbs.lock().unwrap();
+                Some(match advisory_dbs.get(path) {
+                    Some(database) => database.clone(),
+                    None => {
+                        let database = AdvisoryDatabase::load(path)?;
+                        advisory_dbs.insert(path.clone(), database.clone());
+                        database
+                    }
+                })
+            }
+            None => None,
+        };
+
+        let generator = SbomGenerator {
+            config,
+            workspace_root: self.workspace_root.clone(),
+            crate_hashes: self.crate_hashes.clone(),
+            rustc: self.rustc.clone(),
+            vcs,
+            workspace_members: self.members.clone(),
+            registry_index,
+            advisory_db,
+            report: Mutex::new(PackageReport {
+                package: self.packages[member].name.clone(),
+                skipped,
+                ..PackageReport::default()
+            }),
+        };
+        let (mut bom, target_kinds) =
+            generator.create_bom(member, &dependencies, &pruned_resolve, &dep_kinds)?;
+        enrich_bom(&mut bom, dependencies.values(), self.enrichers);
+
+        Ok(GeneratedSbom {
+            bom,
+            manifest_path,
+            package_name: self.packages[member].name.clone(),
+            report: generator.take_report(),
+            sbom_config: generator.config,
+            target_kinds,
+        })
+    }
 }
 
 /// Contains a map from `bom_ref` of a subcomponent to the kinds of Cargo targets it has,
@@ -117,65 +299,111 @@ pub struct TargetKinds(
 );
 
 impl SbomGenerator {
+    /// Runs `cargo metadata` on the manifest and creates the SBOMs of its workspace members,
+    /// as `cargo cyclonedx --manifest-path` does
+    pub fn create_sboms_for_manifest(
+        manifest_path: &Path,
+        config: &SbomConfig,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        let config = with_default_target(config);
+        let metadata = metadata_command(manifest_path, &config, Vec::new()).exec()?;
+        Self::create_sboms(metadata, &config)
+    }
+
     pub fn create_sboms(
         meta: CargoMetadata,
         config: &SbomConfig,
     ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
-        log::trace!("Processing the workspace {}", meta.workspace_root);
-        let members: Vec<PackageId> = meta.workspace_members;
-        let packages = index_packages(meta.packages);
-        let resolve = index_resolve(meta.resolve.unwrap().nodes);
-
-        let mut result = Vec::with_capacity(members.len());
-        for member in members.iter() {
-            log::trace!("Processing the package {}", member);
+        Self::create_sboms_with_progress(meta, config, &NoProgress)
+    }
 
-            let dep_kinds = index_dep_kinds(member, &resolve);
+    /// Creates the SBOMs like [`create_sboms`](Self::create_sboms), while reporting the
+    /// processed workspace members in the [`Phase::Generation`] phase.
+    pub fn create_sboms_with_progress(
+        meta: CargoMetadata,
+        config: &SbomConfig,
+        progress: &dyn ProgressHandler,
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        Self::create_sboms_with_enrichers(meta, config, progress, &[])
+    }
 
-            let (dependencies, pruned_resolve) =
-                if config.included_dependencies() == IncludedDependencies::AllDependencies {
-                    all_dependencies(member, &packages, &resolve, config)
-                } else {
-                    top_level_dependencies(member, &packages, &resolve, config)
-                };
+    /// Creates the SBOMs like [`create_sboms_with_progress`](Self::create_sboms_with_progress),
+    /// passing the component of every package to the `enrichers`, in order.
+    pub fn create_sboms_with_enrichers(
+        meta: CargoMetadata,
+        config: &SbomConfig,
+        progress: &dyn ProgressHandler,
+        enrichers: &[&dyn ComponentEnricher],
+    ) -> Result<Vec<GeneratedSbom>, GeneratorError> {
+        log::trace!("Processing the workspace {}", meta.workspace_root);
+        let config = &with_default_target(config);
+        let rustc = rustc_version(&rustc_location());
+        if rustc.is_none() {
+            log::warn!(
+                "Failed to determine the rustc version, it will not be included in the SBOM"
+            );
+        }
 
-            let manifest_path = packages[member].manifest_path.clone().into_std_path_buf();
-
-            let mut crate_hashes = HashMap::new();
-            match locate_cargo_lock(&manifest_path) {
-                Ok(path) => match Lockfile::load(path) {
-                    Ok(lockfile_contents) => crate_hashes = package_hashes(&lockfile_contents),
-                    Err(err) => log::warn!(
-                        "Failed to parse `Cargo.lock`: {err}\n\
-                        Hashes will not be included in the SBOM."
-                    ),
-                },
-                Err(err) => log::warn!(
-                    "Failed to locate `Cargo.lock`: {err}\n\
-                    Hashes will not be included in the SBOM."
-                ),
+        let workspace = Workspace {
+            members: meta.workspace_members,
+            packages: index_packages(meta.packages),
+            resolve: index_resolve(meta.resolve.unwrap().nodes),
+            crate_hashes: Arc::new(load_crate_hashes(&meta.workspace_root)),
+            workspace_root: meta.workspace_root,
+            rustc,
+            registry_index: OnceLock::new(),
+            advisory_dbs: Mutex::new(HashMap::new()),
+            enrichers,
+        };
+        let members = &workspace.members;
+
+        let total = Some(members.len() as u64);
+        progress.on_progress(Progress::new(Phase::Generation, 0, total));
+
+        // The members and the packages of each SBOM are processed by the threads of the pool,
+        // the progress is reported from this one
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(config.jobs())
+            .build()?;
+        let failed = AtomicBool::new(false);
+        let mut results: Vec<Option<GeneratedSbom>> = members.iter().map(|_| None).collect();
+        let mut errors = Vec::new();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        pool.in_place_scope(|scope| {
+            for (index, member) in members.iter().enumerate() {
+                let (sender, workspace, failed) = (sender.clone(), &workspace, &failed);
+                scope.spawn(move |_| {
+                    if failed.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    let result = workspace.create_sbom(member, config);
+                    if result.is_err() {
+                        failed.store(true, Ordering::Relaxed);
+                    }
+                    // The results are received until all members are processed
+                    let _ = sender.send((index, result));
+                });
             }
+            drop(sender);
+
+            let mut processed = 0;
+            for (index, result) in receiver {
+                match result {
+                    Ok(sbom) => {
+                        results[index] = Some(sbom);
+                        processed += 1;
+                        progress.on_progress(Progress::new(Phase::Generation, processed, total));
+                    }
+                    Err(error) => errors.push((index, error)),
+                }
+            }
+        });
 
-            let generator = SbomGenerator {
-                config: config.clone(),
-                workspace_root: meta.workspace_root.to_owned(),
-                crate_hashes,
-            };
-            let (bom, target_kinds) =
-                generator.create_bom(member, &dependencies, &pruned_resolve, &dep_kinds)?;
-
-            let generated = GeneratedSbom {
-                bom,
-                manifest_path,
-                package_name: packages[member].name.clone(),
-                sbom_config: generator.config,
-                target_kinds,
-            };
-
-            result.push(generated);
+        // Report the error of the first failing member, like a serial run would
+        if let Some((_, error)) = errors.into_iter().min_by_key(|(index, _)| *index) {
+            return Err(error);
         }
-
-        Ok(result)
+        Ok(results.into_iter().flatten().collect())
     }
 
     fn create_bom(
@@ -188,19 +416,66 @@ impl SbomGenerator {
         let mut bom = Bom::default();
         let root_package = &packages[package];
 
-        let components: Vec<_> = packages
-            .values()
-            .filter(|p| &p.id != package)
-            .map(|component| self.create_component(component, root_package, dep_kinds))
+        // Reading license files and looking up hashes is spread over the threads of the pool
+        let mut components: Vec<_> = packages
+            .par_iter()
+            .filter(|(id, _)| *id != package)
+            .map(|(_, component)| {
+                let mut component_bom = self.create_component(component, root_package, dep_kinds);
+                if let Some(node) = resolve.get(&component.id) {
+                    add_properties(
+                        &mut component_bom,
+                        create_features_properties(&node.features),
+                    );
+                }
+                component_bom
+            })
             .collect();
 
+        let mut dependencies = create_dependencies(resolve);
+
+        if let Some(true) = self.config.artifacts {
+            for artifact in self.create_artifacts(root_package) {
+                // The artifact is built from the crate, so it depends on it
+                dependencies.0.push(Dependency {
+                    dependency_ref: artifact.bom_ref.clone().unwrap(),
+                    dependencies: vec![root_package.id.to_string()],
+                });
+                components.push(artifact);
+            }
+        }
+
+        let mut nested_members = Vec::new();
+        if self.config.workspace_members() == WorkspaceMembers::Subcomponents {
+            let (members, others) = components.into_iter().partition(|component| {
+                self.workspace_members
+                    .iter()
+                    .any(|member| component.bom_ref.as_deref() == Some(&member.repr))
+            });
+            nested_members = members;
+            components = others;
+        }
+
         bom.components = Some(Components(components));
 
-        let (metadata, target_kinds) = self.create_metadata(&packages[package])?;
+        let (mut metadata, target_kinds) = self.create_metadata(&packages[package])?;
+
+        if let Some(component) = metadata.component.as_mut() {
+            if let Some(node) = resolve.get(package) {
+                add_properties(component, create_features_properties(&node.features));
+            }
+            if !nested_members.is_empty() {
+                component
+                    .components
+                    .get_or_insert_with(|| Components(Vec::new()))
+                    .0
+                    .extend(nested_members);
+            }
+        }
 
         bom.metadata = Some(metadata);
 
-        bom.dependencies = Some(create_dependencies(resolve));
+        bom.dependencies = Some(dependencies);
 
         Ok((bom, target_kinds))
     }
@@ -230,6 +505,9 @@ impl SbomGenerator {
         );
 
         component.purl = purl;
+        if let Some(true) = self.config.cpe {
+            component.cpe = Some(Self::create_cpe(&name, &version));
+        }
         component.scope = match dep_kinds
             .get(&package.id)
             .unwrap_or(&DependencyKind::Normal)
@@ -239,6 +517,8 @@ impl SbomGenerator {
         };
         component.external_references = Self::get_external_references(package);
         component.licenses = self.get_licenses(package);
+        component.properties = self.create_license_override_properties(package);
+        add_properties(&mut component, self.create_maintenance_properties(package));
         component.hashes = self.get_hashes(package);
 
         component.description = package
@@ -326,6 +606,38 @@ impl SbomGenerator {
         (top_component, TargetKinds(target_kinds))
     }
 
+    /// Creates a component for each file built from the bin, cdylib and staticlib targets
+    fn create_artifacts(&self, package: &Package) -> Vec<Component> {
+        let platform = match self.config.target.as_ref() {
+            Some(crate::config::Target::SingleTarget(target)) => target.clone(),
+            _ => crate::platform::host_platform(),
+        };
+
+        let mut artifacts = Vec::new();
+        for tgt in filter_targets(&package.targets) {
+            for kind in &tgt.kind {
+                let Some(file_name) = artifact_file_name(&tgt.name, kind, &platform) else {
+                    continue;
+                };
+                let cdx_type = match kind.as_str() {
+                    "bin" => Classification::Application,
+                    _ => Classification::Library,
+                };
+                let bom_ref = format!("{} artifact-{}", package.id, file_name);
+
+                let mut artifact = Component::new(
+                    cdx_type,
+                    &file_name,
+                    &package.version.to_string(),
+                    Some(bom_ref),
+                );
+                artifact.scope = Some(Scope::Required);
+                artifacts.push(artifact);
+            }
+        }
+        artifacts
+    }
+
     fn get_classification(pkg: &Package) -> Classification {
         // Transitive dependencies that contain both libraries and binaries
         // get surfaces only as a library by `cargo metadata`.
@@ -407,7 +719,91 @@ impl SbomGenerator {
         None
     }
 
+    /// Records where an overridden license comes from, and the license declared by the package
+    fn create_license_override_properties(&self, package: &Package) -> Option<Properties> {
+        let license_override = self
+            .config
+            .license_override(&package.name, &package.version.to_string())?;
+
+        let mut properties = vec![Property::new(
+            "cargo-cyclonedx:license_override:source",
+            &license_override.source,
+        )];
+        if let Some(license) = &package.license {
+            properties.push(Property::new(
+                "cargo-cyclonedx:license_override:declared",
+                license,
+            ));
+        }
+        if let Some(reason) = &license_override.reason {
+            properties.push(Property::new(
+                "cargo-cyclonedx:license_override:reason",
+                reason,
+            ));
+        }
+        Some(Properties(properties))
+    }
+
+    /// Records whether the package is yanked and the advisories about it being unmaintained
+    fn create_maintenance_properties(&self, package: &Package) -> Option<Properties> {
+        let mut properties = Vec::new();
+
+        let from_crates_io = package
+            .source
+            .as_ref()
+            .is_some_and(|source| source.is_crates_io());
+        if let Some(registry_index) = self.registry_index.as_ref().filter(|_| from_crates_io) {
+            match registry_index.is_yanked(&package.name, &package.version.to_string()) {
+                Some(true) => {
+                    log::warn!("Package {}@{} is yanked", package.name, package.version);
+                    properties.push(Property::new(YANKED_PROPERTY, "true"));
+                }
+                Some(false) => (),
+                None => log::warn!(
+                    "Package {}@{} is not in the crates.io index, cannot check whether it is yanked",
+                    package.name,
+                    package.version
+                ),
+            }
+        }
+
+        if let Some(advisory_db) = &self.advisory_db {
+            for id in advisory_db.unmaintained(&package.name, &package.version) {
+                log::warn!(
+                    "Package {}@{} is unmaintained: {}",
+                    package.name,
+                    package.version,
+                    id
+                );
+                properties.push(Property::new("cargo-cyclonedx:advisory:unmaintained", id));
+            }
+        }
+
+        (!properties.is_empty()).then_some(Properties(properties))
+    }
+
     fn get_licenses(&self, package: &Package) -> Option<Licenses> {
+        if let Some(license_override) = self
+            .config
+            .license_override(&package.name, &package.version.to_string())
+        {
+            log::debug!(
+                "Overriding the license of package {}@{} with {} from {}",
+                package.name,
+                package.version,
+                license_override.expression,
+                license_override.source
+            );
+            self.report_license(
+                package,
+                Some(license_override.expression.to_string()),
+                LicenseDecisionKind::Override,
+            );
+            return Some(Licenses(vec![LicenseChoice::Expression(
+                license_override.expression.clone(),
+            )]));
+        }
+
         let mut licenses = vec![];
 
         if let Some(license) = &package.license {
@@ -431,7 +827,16 @@ impl SbomGenerator {
             };
 
             match result {
-                Ok(expression) => licenses.push(LicenseChoice::Expression(expression)),
+                Ok(expression) => {
+                    if expression.to_string() != *license {
+                        self.report_license(
+                            package,
+                            Some(expression.to_string()),
+                            LicenseDecisionKind::Normalized,
+                        );
+                    }
+                    licenses.push(LicenseChoice::Expression(expression))
+                }
                 Err(err) => {
                     let level = match &self.config.license_parser {
                         Some(opts) if opts.accept_named.contains(license) => Level::Info,
@@ -444,6 +849,7 @@ impl SbomGenerator {
                         license,
                         err,
                     );
+                    self.report_license(package, Some(license.clone()), LicenseDecisionKind::Named);
                     licenses.push(LicenseChoice::License(License::named_license(license)))
                 }
             }
@@ -476,12 +882,40 @@ impl SbomGenerator {
                 "Package {} has no licenses or license file specified",
                 package.name
             );
+            self.report_license(package, None, LicenseDecisionKind::Missing);
             return None;
         }
 
         Some(Licenses(licenses))
     }
 
+    /// The report of the package, with the entries of the components in a deterministic order
+    /// regardless of the threads creating them
+    fn take_report(&self) -> PackageReport {
+        let mut report = std::mem::take(&mut *self.report.lock().unwrap());
+        // Stable sorts, which keep the order of the entries of each package
+        report
+            .licenses
+            .sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+        report.missing_hashes.sort();
+        report
+    }
+
+    fn report_license(
+        &self,
+        package: &Package,
+        recorded: Option<String>,
+        decision: LicenseDecisionKind,
+    ) {
+        self.report.lock().unwrap().licenses.push(LicenseDecision {
+            name: package.name.clone(),
+            version: package.version.to_string(),
+            declared: package.license.clone(),
+            recorded,
+            decision,
+        });
+    }
+
     fn get_hashes(&self, package: &Package) -> Option<cyclonedx_bom::models::hash::Hashes> {
         match self.crate_hashes.get(&package.id) {
             Some(hash) => Some(cyclonedx_bom::models::hash::Hashes(vec![to_bom_hash(hash)])),
@@ -495,6 +929,16 @@ impl SbomGenerator {
                     "Hash for package ID {} not found in Cargo.lock",
                     &package.id
                 );
+                let from_registry = package.source.as_ref().is_some_and(|source| {
+                    source.repr.starts_with("registry+") || source.repr.starts_with("sparse+")
+                });
+                if from_registry {
+                    self.report
+                        .lock()
+                        .unwrap()
+                        .missing_hashes
+                        .push(package.id.to_string());
+                }
                 None
             }
         }
@@ -506,31 +950,167 @@ impl SbomGenerator {
     ) -> Result<(Metadata, TargetKinds), GeneratorError> {
         let authors = Self::create_authors(package);
 
-        let mut metadata = Metadata::new()?;
+        let mut metadata = Metadata::new_with_timestamp(&self.config.timestamp())?;
+
+        let (mut component, target_kinds) = self.create_toplevel_component(package);
+
+        component.component_type = match self.config.root_classification {
+            Some(RootClassification::Application) => Classification::Application,
+            Some(RootClassification::Library) => Classification::Library,
+            None => Self::get_classification(package),
+        };
+
         if !authors.is_empty() {
+            // Components only have authors as contacts since 1.6, before that only the string
+            if self.config.spec_version() >= SpecVersion::V1_6 {
+                component.authors = Some(authors.clone());
+            }
             metadata.authors = Some(authors);
         }
 
-        let (mut component, target_kinds) = self.create_toplevel_component(package);
-
-        component.component_type = Self::get_classification(package);
+        if let Some(vcs) = &self.vcs {
+            Self::add_vcs_provenance(&mut component, vcs);
+        }
 
         metadata.component = Some(component);
 
-        let tool = Tool::new("CycloneDX", "cargo-cyclonedx", env!("CARGO_PKG_VERSION"));
-
-        metadata.tools = Some(Tools::List(vec![tool]));
+        metadata.tools = Some(Self::create_tools(&self.config, self.rustc.as_ref()));
 
         use crate::config::Target::*;
-        let properties = match self.config.target.as_ref().unwrap() {
+        let mut properties = match self.config.target.as_ref().unwrap() {
             SingleTarget(target) => vec![Property::new("cdx:rustc:sbom:target:triple", target)],
             AllTargets => vec![Property::new("cdx:rustc:sbom:target:all_targets", "true")],
         };
+        if let Some(rustc) = &self.rustc {
+            properties.push(Property::new("cargo-cyclonedx:host:triple", &rustc.host));
+        }
+        if let Some(profile) = &self.config.profile {
+            properties.push(Property::new("cargo-cyclonedx:profile", profile));
+        }
+        for (name, value) in self.config.properties.iter().flatten() {
+            properties.push(Property::new(name, value));
+        }
         metadata.properties = Some(Properties(properties));
 
+        metadata.supplier = self.config.supplier.as_ref().map(create_organization);
+        if let Some(manufacturer) = &self.config.manufacturer {
+            // `manufacture` was renamed to `manufacturer` in 1.6
+            match self.config.spec_version() >= SpecVersion::V1_6 {
+                true => metadata.manufacturer = Some(create_organization(manufacturer)),
+                false => metadata.manufacture = Some(create_organization(manufacturer)),
+            }
+        }
+
         Ok((metadata, target_kinds))
     }
 
+    /// Records the checked out commit in the pedigree, the remote as a VCS reference,
+    /// and the tag and dirty state as properties of the component
+    fn add_vcs_provenance(component: &mut Component, vcs: &GitInfo) {
+        let commit = Commit {
+            uid: Some(NormalizedString::new(&vcs.commit)),
+            url: None,
+            author: None,
+            committer: None,
+            message: None,
+        };
+        component.pedigree = Some(Pedigree {
+            ancestors: None,
+            descendants: None,
+            variants: None,
+            commits: Some(Commits(vec![commit])),
+            patches: None,
+            notes: None,
+        });
+
+        if let Some(remote_url) = &vcs.remote_url {
+            match Uri::try_from(remote_url.clone()) {
+                Ok(uri) => {
+                    let references = component
+                        .external_references
+                        .get_or_insert_with(|| ExternalReferences(Vec::new()));
+                    let known = references.0.iter().any(|reference| {
+                        reference.external_reference_type == ExternalReferenceType::Vcs
+                            && reference.url.to_string() == *remote_url
+                    });
+                    if !known {
+                        references
+                            .0
+                            .push(ExternalReference::new(ExternalReferenceType::Vcs, uri));
+                    }
+                }
+                Err(e) => log::warn!("Invalid git remote URI ({}): {}", remote_url, e),
+            }
+        }
+
+        let properties = component
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()));
+        properties.0.push(Property::new(
+            "cargo-cyclonedx:vcs:dirty",
+            &vcs.dirty.to_string(),
+        ));
+        if let Some(tag) = &vcs.tag {
+            properties
+                .0
+                .push(Property::new("cargo-cyclonedx:vcs:tag", tag));
+        }
+    }
+
+    /// Crates have no vendor, so the CPE matches any vendor of a product with the crate name.
+    fn create_cpe(name: &str, version: &str) -> Cpe {
+        Cpe::from_parts(CpePart::Application, "", name, version)
+    }
+
+    /// Records cargo-cyclonedx and, if known, the rustc toolchain as tools
+    pub(crate) fn create_tools(config: &SbomConfig, rustc: Option<&RustcVersion>) -> Tools {
+        let version = env!("CARGO_PKG_VERSION");
+        let spec_version = config.spec_version();
+
+        match config.tools_format() {
+            ToolsFormat::Object if spec_version >= SpecVersion::V1_5 => {
+                let mut tool = Component::new(
+                    Classification::Application,
+                    "cargo-cyclonedx",
+                    version,
+                    None,
+                );
+                tool.supplier = Some(OrganizationalEntity::new("CycloneDX"));
+                let mut tools = vec![tool];
+
+                if let Some(rustc) = rustc {
+                    let mut tool =
+                        Component::new(Classification::Application, "rustc", &rustc.release, None);
+                    tool.supplier = Some(OrganizationalEntity::new("The Rust Project"));
+                    if let Some(commit_hash) = &rustc.commit_hash {
+                        tool.properties = Some(Properties(vec![Property::new(
+                            "cargo-cyclonedx:rustc:commit_hash",
+                            commit_hash,
+                        )]));
+                    }
+                    tools.push(tool);
+                }
+
+                Tools::Object {
+                    services: None,
+                    components: Some(Components(tools)),
+                }
+            }
+            tools_format => {
+                if tools_format == ToolsFormat::Object {
+                    log::warn!(
+                        "Tools can only be recorded as an object since spec version 1.5, recording them as a list instead"
+                    );
+                }
+                let mut tools = vec![Tool::new("CycloneDX", "cargo-cyclonedx", version)];
+                if let Some(rustc) = rustc {
+                    tools.push(Tool::new("The Rust Project", "rustc", &rustc.release));
+                }
+                Tools::List(tools)
+            }
+        }
+    }
+
     fn create_authors(package: &Package) -> Vec<OrganizationalContact> {
         let mut authors = vec![];
         let mut invalid_authors = vec![];
@@ -575,6 +1155,57 @@ impl SbomGenerator {
     }
 }
 
+/// Records the features enabled for a package in the resolved dependency graph,
+/// since they change which code is compiled
+/// Appends the properties to those already recorded for the component
+const YANKED_PROPERTY: &str = "cargo-cyclonedx:yanked";
+
+fn add_properties(component: &mut Component, properties: Option<Properties>) {
+    if let Some(properties) = properties {
+        component
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .0
+            .extend(properties.0);
+    }
+}
+
+fn create_features_properties(features: &[String]) -> Option<Properties> {
+    if features.is_empty() {
+        return None;
+    }
+
+    let mut features = features.to_vec();
+    features.sort();
+    Some(Properties(vec![Property::new(
+        "cargo-cyclonedx:features",
+        &features.join(","),
+    )]))
+}
+
+/// Returns the name of the file built for a target of the given kind on the platform,
+/// or `None` if the kind does not produce a shipped artifact, like an `rlib`.
+fn artifact_file_name(name: &str, kind: &str, platform: &str) -> Option<String> {
+    let windows = platform.contains("windows");
+    let wasm = platform.starts_with("wasm");
+    // Cargo replaces dashes in library names, but not in binary names
+    let lib_name = name.replace('-', "_");
+
+    let file_name = match kind {
+        "bin" if windows => format!("{name}.exe"),
+        "bin" if wasm => format!("{name}.wasm"),
+        "bin" => name.to_owned(),
+        "cdylib" if windows => format!("{lib_name}.dll"),
+        "cdylib" if wasm => format!("{lib_name}.wasm"),
+        "cdylib" if platform.contains("apple") => format!("lib{lib_name}.dylib"),
+        "cdylib" => format!("lib{lib_name}.so"),
+        "staticlib" if windows && platform.ends_with("msvc") => format!("{lib_name}.lib"),
+        "staticlib" => format!("lib{lib_name}.a"),
+        _ => return None,
+    };
+    Some(file_name)
+}
+
 /// Ignore tests, benches, examples and build scripts.
 /// They are not part of the final build artifacts, which is what we are after.
 fn filter_targets(
@@ -674,6 +1305,15 @@ pub enum GeneratorError {
 
     #[error("Could not parse author string: {}", .0)]
     AuthorParseError(String),
+
+    #[error("Could not load the advisory database")]
+    AdvisoryDatabaseError(#[from] AdvisoryDatabaseError),
+
+    #[error("Error running `cargo metadata`")]
+    CargoMetadataError(#[from] cargo_metadata::Error),
+
+    #[error("Failed to start the threads creating the SBOMs")]
+    ThreadPoolError(#[from] rayon::ThreadPoolBuildError),
 }
 
 /// Generates the `Dependencies` field in the final SBOM
@@ -766,6 +1406,74 @@ fn all_dependencies(
     (out_packages, out_resolve)
 }
 
+/// Leaves out the packages rejected by the filter. The packages depending on a left out package
+/// depend on its dependencies instead, so that the dependency graph stays connected.
+fn filter_components(
+    root: &PackageId,
+    packages: PackageMap,
+    resolve: ResolveMap,
+    filter: &ComponentFilter,
+    workspace_root: &Utf8Path,
+) -> (PackageMap, ResolveMap) {
+    if filter.is_empty() {
+        return (packages, resolve);
+    }
+
+    let excluded: HashSet<PackageId> = packages
+        .values()
+        .filter(|package| &package.id != root)
+        .filter(|package| {
+            // Only local crates are matched by their path, not those in the Cargo cache
+            let path = package.source.is_none().then(|| {
+                let dir = package
+                    .manifest_path
+                    .parent()
+                    .unwrap_or(&package.manifest_path);
+                dir.strip_prefix(workspace_root)
+                    .unwrap_or(dir)
+                    .as_std_path()
+            });
+            !filter.includes(&package.name, &package.version.to_string(), path)
+        })
+        .map(|package| package.id.clone())
+        .collect();
+    for id in &excluded {
+        log::debug!("Leaving the package {} out of the SBOM", id);
+    }
+
+    let out_resolve = resolve
+        .iter()
+        .filter(|(id, _)| !excluded.contains(id))
+        .map(|(id, node)| {
+            let mut dependencies = BTreeSet::new();
+            let mut visited = HashSet::new();
+            let mut queue: Vec<&PackageId> = node.dependencies.iter().collect();
+            while let Some(dependency) = queue.pop() {
+                if !excluded.contains(dependency) {
+                    dependencies.insert(dependency.clone());
+                } else if visited.insert(dependency) {
+                    // Depend on what the left out package depends on
+                    if let Some(excluded_node) = resolve.get(dependency) {
+                        queue.extend(&excluded_node.dependencies);
+                    }
+                }
+            }
+
+            let mut node = node.clone();
+            node.deps.retain(|dep| !excluded.contains(&dep.pkg));
+            node.dependencies = dependencies.into_iter().collect();
+            (id.clone(), node)
+        })
+        .collect();
+
+    let out_packages = packages
+        .into_iter()
+        .filter(|(id, _)| !excluded.contains(id))
+        .collect();
+
+    (out_packages, out_resolve)
+}
+
 fn add_filtered_dependencies(node: &Node, config: &SbomConfig) -> Node {
     let mut node = node.clone();
     node.deps = filtered_dependencies(&node.deps, config).cloned().collect();
@@ -804,67 +1512,257 @@ pub struct GeneratedSbom {
     pub package_name: String,
     pub sbom_config: SbomConfig,
     pub target_kinds: TargetKinds,
+    pub report: PackageReport,
+}
+
+/// An SBOM written by [`GeneratedSbom::write_to_files`], once per configured format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrittenSbom {
+    /// The name of the described component, the crate or one of its binaries
+    pub name: String,
+    pub version: String,
+    /// The files written, in the order of the configured formats, empty when writing to stdout
+    pub files: Vec<PathBuf>,
 }
 
 impl GeneratedSbom {
-    /// Writes SBOM to either a JSON or XML file in the same folder as `Cargo.toml` manifest
-    pub fn write_to_files(self) -> Result<(), SbomWriterError> {
-        match self.sbom_config.describe.unwrap_or_default() {
-            Describe::Crate => {
-                let path = self.manifest_path.with_file_name(self.filename(None, &[]));
-                Self::write_to_file(self.bom, &path, &self.sbom_config)
-            }
-            pattern @ (Describe::Binaries | Describe::AllCargoTargets) => {
-                for (sbom, target_kind) in
+    /// Checks the licenses of the dependencies against the configured [`LicensePolicy`](crate::config::LicensePolicy)
+    pub fn license_violations(&self) -> Vec<LicenseViolation> {
+        check_licenses(&self.bom, &self.sbom_config.license_policy())
+    }
+
+    /// The `bom-ref`s of the dependencies found to be yanked, see [`SbomConfig::check_yanked`]
+    pub fn yanked_dependencies(&self) -> Vec<String> {
+        self.bom
+            .components
+            .iter()
+            .flat_map(|components| &components.0)
+            .filter(|component| {
+                component.properties.as_ref().is_some_and(|properties| {
+                    properties
+                        .0
+                        .iter()
+                        .any(|property| property.name == YANKED_PROPERTY)
+                })
+            })
+            .filter_map(|component| component.bom_ref.clone())
+            .collect()
+    }
+
+    /// Writes SBOM to JSON and/or XML files, one file for each configured format.
+    ///
+    /// The files are written to the same folder as `Cargo.toml` manifest, unless a different
+    /// output directory or file is configured. [`FilenamePattern::Stdout`] writes to stdout.
+    pub fn write_to_files(self) -> Result<Vec<WrittenSbom>, SbomWriterError> {
+        let formats = self.sbom_config.formats();
+        let sboms: Vec<(Bom, Option<String>, Vec<String>)> =
+            match self.sbom_config.describe.unwrap_or_default() {
+                Describe::Crate => vec![(self.bom.clone(), None, Vec::new())],
+                pattern @ (Describe::Binaries | Describe::AllCargoTargets) => {
                     Self::per_artifact_sboms(&self.bom, &self.target_kinds, pattern)
-                {
-                    let meta = sbom.metadata.as_ref().unwrap();
-                    let name = meta.component.as_ref().unwrap().name.as_ref();
-                    let path = self
-                        .manifest_path
-                        .with_file_name(self.filename(Some(name), &target_kind));
-                    Self::write_to_file(sbom, &path, &self.sbom_config)?;
+                        .map(|(sbom, target_kind)| {
+                            let meta = sbom.metadata.as_ref().unwrap();
+                            let name = meta.component.as_ref().unwrap().name.to_string();
+                            (sbom, Some(name), target_kind)
+                        })
+                        .collect()
+                }
+            };
+
+        if self.sbom_config.output_options().filename == FilenamePattern::Stdout
+            && sboms.len() * formats.len() > 1
+        {
+            return Err(SbomWriterError::MultipleSbomsToStdout);
+        }
+
+        let mut written = Vec::with_capacity(sboms.len());
+        for (sbom, name, target_kind) in sboms {
+            let component = sbom
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.component.as_ref());
+            let mut written_sbom = WrittenSbom {
+                name: component
+                    .map(|component| component.name.to_string())
+                    .unwrap_or_default(),
+                version: component
+                    .and_then(|component| component.version.as_ref())
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+                files: Vec::new(),
+            };
+
+            if let Some(attestation) = &self.sbom_config.attestation {
+                let path = self.output_path(name.as_deref(), &target_kind, Format::Json);
+                Self::write_attestation(
+                    sbom.clone(),
+                    path.as_deref(),
+                    &self.sbom_config,
+                    attestation,
+                )?;
+                if path.is_none() {
+                    // The statement is written to stdout instead of the SBOM
+                    written.push(written_sbom);
+                    continue;
+                }
+            }
+            for &format in &formats {
+                match self.output_path(name.as_deref(), &target_kind, format) {
+                    Some(path) => {
+                        Self::write_to_file(sbom.clone(), &path, &self.sbom_config, format)?;
+                        written_sbom.files.push(path);
+                    }
+                    None => {
+                        let mut writer = std::io::stdout().lock();
+                        Self::write_bom(sbom.clone(), &mut writer, &self.sbom_config, format)?;
+                        writer.flush()?;
+                    }
+                }
+            }
+            written.push(written_sbom);
+        }
+        Ok(written)
+    }
+
+    pub(crate) fn write_to_file(
+        bom: Bom,
+        path: &Path,
+        config: &SbomConfig,
+        format: Format,
+    ) -> Result<(), SbomWriterError> {
+        log::info!("Outputting {}", path.display());
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        Self::write_bom(bom, &mut writer, config, format)?;
+
+        // Flush the writer explicitly to catch and report any I/O errors
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes an in-toto statement wrapping the JSON SBOM, or what the signer makes of it,
+    /// next to the SBOM at `bom_path`, or to stdout if it is `None`
+    fn write_attestation(
+        bom: Bom,
+        bom_path: Option<&Path>,
+        config: &SbomConfig,
+        attestation: &AttestationOptions,
+    ) -> Result<(), SbomWriterError> {
+        let mut predicate = Vec::new();
+        Self::write_bom(bom, &mut predicate, config, Format::Json)?;
+        let predicate = serde_json::from_slice(&predicate).map_err(std::io::Error::from)?;
+
+        let statement = Statement::new(predicate, &attestation.subjects)?;
+        let mut statement = serde_json::to_vec_pretty(&statement).map_err(std::io::Error::from)?;
+        let extension = match &attestation.signer {
+            Some(signer) => {
+                statement = sign(signer, &statement)?;
+                "intoto.jsonl"
+            }
+            None => "intoto.json",
+        };
+
+        match bom_path {
+            Some(bom_path) => {
+                let path = bom_path.with_extension(extension);
+                log::info!("Outputting {}", path.display());
+                if let Some(directory) = path.parent() {
+                    std::fs::create_dir_all(directory)?;
                 }
-                Ok(())
+                std::fs::write(path, statement)?;
+            }
+            None => {
+                let mut writer = std::io::stdout().lock();
+                writer.write_all(&statement)?;
+                writer.flush()?;
             }
         }
+
+        Ok(())
     }
 
-    fn write_to_file(bom: Bom, path: &Path, config: &SbomConfig) -> Result<(), SbomWriterError> {
+    pub(crate) fn write_bom<W: Write>(
+        bom: Bom,
+        writer: &mut W,
+        config: &SbomConfig,
+        format: Format,
+    ) -> Result<(), SbomWriterError> {
+        let spec_version = config.spec_version();
+
         // If running in debug mode, validate that the SBOM is self-consistent and well-formed
         if cfg!(debug_assertions) {
-            let result = bom.validate();
+            let result = bom.validate_version(spec_version);
             if result.has_errors() {
-                panic!(
-                    "The generated SBOM failed validation: {:?}",
-                    result.errors()
-                );
+                panic!("The generated SBOM failed validation:\n{result}");
             }
         }
 
-        use cyclonedx_bom::models::bom::SpecVersion::*;
-        let spec_version = config.spec_version.unwrap_or(V1_3);
-
-        log::info!("Outputting {}", path.display());
-        let file = File::create(path)?;
-        let mut writer = BufWriter::new(file);
-        match config.format() {
+        match format {
             Format::Json => {
-                bom.output_as_json(&mut writer, spec_version)
+                bom.output_as_json_with_options(writer, spec_version, &config.json_output())
                     .map_err(SbomWriterError::JsonWriteError)?;
             }
             Format::Xml => {
-                bom.output_as_xml(&mut writer, spec_version)
+                bom.output_as_xml(writer, spec_version)
                     .map_err(SbomWriterError::XmlWriteError)?;
             }
         }
 
-        // Flush the writer explicitly to catch and report any I/O errors
-        writer.flush()?;
-
         Ok(())
     }
 
+    /// Returns the path to write an SBOM to, or `None` to write it to stdout
+    fn output_path(
+        &self,
+        binary_name: Option<&str>,
+        target_kind: &[String],
+        format: Format,
+    ) -> Option<PathBuf> {
+        let output_options = self.sbom_config.output_options();
+        let directory = match output_options.directory {
+            Some(directory) => directory,
+            None => self
+                .manifest_path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+        };
+
+        let filename = match output_options.filename {
+            FilenamePattern::Stdout => return None,
+            FilenamePattern::Template(template) => {
+                let version = self
+                    .bom
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.component.as_ref())
+                    .and_then(|component| component.version.as_ref())
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                template.render(&TemplateValues {
+                    crate_name: binary_name.unwrap_or(&self.package_name),
+                    version: &version,
+                    ext: &format.to_string(),
+                    target: self
+                        .sbom_config
+                        .target
+                        .as_ref()
+                        .map_or("all", Target::as_str),
+                    kind: &target_kind.join("-"),
+                })
+            }
+            FilenamePattern::CrateName | FilenamePattern::Custom(_) => {
+                self.filename(binary_name, target_kind, format)
+            }
+        };
+
+        Some(directory.join(filename))
+    }
+
     /// Returns an iterator over SBOMs and their associated target kinds
     fn per_artifact_sboms<'a>(
         bom: &'a Bom,
@@ -878,9 +1776,10 @@ impl GeneratedSbom {
         components
             .0
             .iter()
-            .filter(move |component| {
-                let target_kind = &target_kinds.0[component.bom_ref.as_ref().unwrap()];
-                match describe {
+            .filter_map(move |component| {
+                // Workspace members nested as subcomponents are not Cargo targets
+                let target_kind = target_kinds.0.get(component.bom_ref.as_ref()?)?;
+                let selected = match describe {
                     Describe::Binaries => {
                         // only record binary artifacts
                         // TODO: refactor this to use an enum, coming Soon(tm) to cargo-metadata:
@@ -890,10 +1789,10 @@ impl GeneratedSbom {
                     }
                     Describe::AllCargoTargets => true, // pass everything through
                     Describe::Crate => unreachable!(),
-                }
+                };
+                selected.then_some((component, target_kind))
             })
-            .map(|component| {
-                let target_kind = &target_kinds.0[component.bom_ref.as_ref().unwrap()];
+            .map(|(component, target_kind)| {
                 // In the original SBOM the toplevel component describes a crate.
                 // We need to change it to describe a specific binary.
                 // Most properties apply to the entire package and should be kept;
@@ -909,7 +1808,12 @@ impl GeneratedSbom {
             })
     }
 
-    fn filename(&self, binary_name: Option<&str>, target_kind: &[String]) -> String {
+    fn filename(
+        &self,
+        binary_name: Option<&str>,
+        target_kind: &[String],
+        format: Format,
+    ) -> String {
         let output_options = self.sbom_config.output_options();
         let describe = self.sbom_config.describe.unwrap_or_default();
 
@@ -927,6 +1831,8 @@ impl GeneratedSbom {
                 prefix = name_override.to_string();
                 extension = ""; // do not append the extension to allow writing to literally "bom.xml" as per spec
             }
+            // handled by the caller
+            FilenamePattern::Template(_) | FilenamePattern::Stdout => unreachable!(),
         }
 
         let target_kind_suffix = if !target_kind.is_empty() {
@@ -950,11 +1856,7 @@ impl GeneratedSbom {
 
         format!(
             "{}{}{}{}.{}",
-            prefix,
-            target_kind_suffix,
-            platform_suffix,
-            extension,
-            self.sbom_config.format()
+            prefix, target_kind_suffix, platform_suffix, extension, format
         )
     }
 }
@@ -963,8 +1865,12 @@ impl GeneratedSbom {
 /// This must be run **after** `cargo metadata` which will generate the `Cargo.lock` file
 /// and make sure it's up to date.
 fn locate_cargo_lock(manifest_path: &Path) -> Result<PathBuf, std::io::Error> {
-    let manifest_path = manifest_path.canonicalize()?;
-    let ancestors = manifest_path.as_path().ancestors();
+    // The manifest itself may not exist for metadata read from a lockfile
+    let manifest_dir = manifest_path
+        .parent()
+        .unwrap_or(Path::new("."))
+        .canonicalize()?;
+    let ancestors = manifest_dir.as_path().ancestors();
 
     for path in ancestors {
         let potential_lockfile = path.join("Cargo.lock");
@@ -978,8 +1884,78 @@ fn locate_cargo_lock(manifest_path: &Path) -> Result<PathBuf, std::io::Error> {
     ))
 }
 
+/// Loads the hashes of the packages from the `Cargo.lock` of the workspace, which all members share
+fn load_crate_hashes(workspace_root: &Utf8Path) -> HashMap<cargo_metadata::PackageId, Checksum> {
+    let lockfile = locate_cargo_lock(workspace_root.join("Cargo.toml").as_std_path())
+        .map_err(|err| format!("Failed to locate `Cargo.lock`: {err}"))
+        .and_then(|path| {
+            Lockfile::load(path).map_err(|err| format!("Failed to parse `Cargo.lock`: {err}"))
+        });
+    match lockfile {
+        Ok(lockfile) => package_hashes(&lockfile),
+        Err(message) => {
+            log::warn!("{message}\nHashes will not be included in the SBOM.");
+            HashMap::new()
+        }
+    }
+}
+
 /// Extracts all available package hashes from the provided `Cargo.lock` file
 /// and collects them into a HashMap for fast and reasy lookup
+fn create_organization(organization: &Organization) -> OrganizationalEntity {
+    let mut entity = OrganizationalEntity::new(&organization.name);
+    if let Some(url) = &organization.url {
+        match Uri::try_from(url.clone()) {
+            Ok(url) => entity.url = Some(vec![url]),
+            Err(error) => log::warn!("Ignoring the URL of {}: {error}", organization.name),
+        }
+    }
+    entity
+}
+
+/// Defaults the target to the host platform, as the CLI does
+fn with_default_target(config: &SbomConfig) -> SbomConfig {
+    let mut config = config.clone();
+    config
+        .target
+        .get_or_insert_with(|| Target::SingleTarget(host_platform()));
+    config
+}
+
+/// Prepares the `cargo metadata` invocation for a manifest, with the features and target platform of `config`.
+///
+/// `other_options` are passed to cargo as well, e.g. `--offline`.
+pub fn metadata_command(
+    manifest_path: &Path,
+    config: &SbomConfig,
+    mut other_options: Vec<String>,
+) -> MetadataCommand {
+    let mut cmd = MetadataCommand::new();
+    cmd.manifest_path(manifest_path);
+
+    if let Some(feature_configuration) = config.features.as_ref() {
+        if feature_configuration.all_features {
+            cmd.features(CargoOpt::AllFeatures);
+        }
+        if feature_configuration.no_default_features {
+            cmd.features(CargoOpt::NoDefaultFeatures);
+        }
+        if !feature_configuration.features.is_empty() {
+            cmd.features(CargoOpt::SomeFeatures(
+                feature_configuration.features.clone(),
+            ));
+        }
+    }
+
+    if let Some(Target::SingleTarget(target)) = config.target.as_ref() {
+        other_options.push("--filter-platform".to_owned());
+        other_options.push(target.to_owned());
+    }
+    cmd.other_options(other_options);
+
+    cmd
+}
+
 fn package_hashes(lockfile: &Lockfile) -> HashMap<cargo_metadata::PackageId, Checksum> {
     let mut result = HashMap::new();
     for pkg in &lockfile.packages {
@@ -1028,6 +2004,12 @@ pub enum SbomWriterError {
 
     #[error("Error serializing to XML")]
     SerializeXmlError(#[source] std::io::Error),
+
+    #[error("Only a single SBOM can be written to stdout")]
+    MultipleSbomsToStdout,
+
+    #[error("Error writing the attestation")]
+    AttestationError(#[from] AttestationError),
 }
 
 impl From<std::io::Error> for SbomWriterError {
@@ -1039,6 +2021,557 @@ impl From<std::io::Error> for SbomWriterError {
 #[cfg(test)]
 mod test {
     use super::*;
+    use cyclonedx_bom::client::CurlTransport;
+
+    const ROOT_PACKAGE_JSON: &str = include_str!("../tests/fixtures/root_package.json");
+
+    fn generator(config: SbomConfig) -> SbomGenerator {
+        SbomGenerator {
+            config: SbomConfig {
+                target: Some(crate::config::Target::AllTargets),
+                ..config
+            },
+            workspace_root: Utf8PathBuf::from("/home/shnatsel/Code/cargo-cyclonedx/"),
+            crate_hashes: Arc::default(),
+            rustc: None,
+            vcs: None,
+            workspace_members: Vec::new(),
+            registry_index: None,
+            advisory_db: None,
+            report: Mutex::default(),
+        }
+    }
+
+    #[test]
+    fn it_should_record_yanked_and_unmaintained_dependencies() {
+        use assert_fs::prelude::*;
+
+        let index = assert_fs::TempDir::new().unwrap();
+        index
+            .child(".cache/ca/rg/cargo-cyclonedx")
+            .write_binary(
+                b"\x03\x02\x00\x00\x00Unknown\x000.3.8\x00{\"name\":\"cargo-cyclonedx\",\"vers\":\"0.3.8\",\"deps\":[],\"cksum\":\"0000000000000000000000000000000000000000000000000000000000000000\",\"features\":{},\"yanked\":true}\x00",
+            )
+            .unwrap();
+        let advisory_db = assert_fs::TempDir::new().unwrap();
+        advisory_db
+            .child("crates/cargo-cyclonedx/RUSTSEC-2020-0001.md")
+            .write_str(
+                "```toml\n\
+                [advisory]\n\
+                id = \"RUSTSEC-2020-0001\"\n\
+                package = \"cargo-cyclonedx\"\n\
+                informational = \"unmaintained\"\n\
+                [versions]\n\
+                patched = []\n\
+                ```\n",
+            )
+            .unwrap();
+
+        let mut package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let mut generator = generator(SbomConfig::empty_config());
+        // Offline, so only the index cache is read
+        generator.registry_index = Some(Arc::new(RegistryIndex::with_index(
+            crates_index::SparseIndex::at_path(
+                index.path().to_path_buf(),
+                crates_index::sparse::URL.to_owned(),
+            ),
+            CurlTransport::default(),
+            true,
+        )));
+        generator.advisory_db = Some(AdvisoryDatabase::load(advisory_db.path()).unwrap());
+
+        // Only packages from crates.io are looked up in its index
+        assert_eq!(
+            generator.create_maintenance_properties(&package),
+            Some(Properties(vec![Property::new(
+                "cargo-cyclonedx:advisory:unmaintained",
+                "RUSTSEC-2020-0001"
+            )]))
+        );
+
+        package.source = Some(cargo_metadata::Source {
+            repr: "registry+https://github.com/rust-lang/crates.io-index".to_owned(),
+        });
+        assert_eq!(
+            generator.create_maintenance_properties(&package),
+            Some(Properties(vec![
+                Property::new("cargo-cyclonedx:yanked", "true"),
+                Property::new("cargo-cyclonedx:advisory:unmaintained", "RUSTSEC-2020-0001"),
+            ]))
+        );
+
+        assert_eq!(
+            self::generator(SbomConfig::empty_config()).create_maintenance_properties(&package),
+            None
+        );
+    }
+
+    #[test]
+    fn it_should_report_license_decisions_and_missing_hashes() {
+        let mut package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let generator = generator(SbomConfig::empty_config());
+
+        generator.get_licenses(&package);
+        package.license = Some("MIT/Apache-2.0".to_owned());
+        generator.get_licenses(&package);
+        package.license = Some("Not a license!".to_owned());
+        generator.get_licenses(&package);
+        package.license = None;
+        generator.get_licenses(&package);
+
+        // Hashes are only expected of registry packages
+        generator.get_hashes(&package);
+        package.source = Some(cargo_metadata::Source {
+            repr: "registry+https://github.com/rust-lang/crates.io-index".to_owned(),
+        });
+        generator.get_hashes(&package);
+
+        let report = generator.take_report();
+        let decisions: Vec<_> = report
+            .licenses
+            .iter()
+            .map(|license| (license.declared.as_deref(), license.decision))
+            .collect();
+        assert_eq!(
+            decisions,
+            vec![
+                (Some("MIT/Apache-2.0"), LicenseDecisionKind::Normalized),
+                (Some("Not a license!"), LicenseDecisionKind::Named),
+                (None, LicenseDecisionKind::Missing),
+            ]
+        );
+        assert_eq!(report.missing_hashes, vec![package.id.to_string()]);
+    }
+
+    #[test]
+    fn it_should_prepare_cargo_metadata_with_the_config() {
+        let config = SbomConfig {
+            features: Some(crate::config::Features {
+                all_features: false,
+                no_default_features: true,
+                features: vec!["std".to_owned()],
+            }),
+            target: Some(Target::SingleTarget("x86_64-unknown-linux-gnu".to_owned())),
+            ..SbomConfig::empty_config()
+        };
+        let cmd = metadata_command(
+            Path::new("Cargo.toml"),
+            &config,
+            vec!["--offline".to_owned()],
+        );
+
+        let args: Vec<_> = cmd
+            .cargo_command()
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        assert!(args.ends_with(&[
+            "--offline".to_owned(),
+            "--filter-platform".to_owned(),
+            "x86_64-unknown-linux-gnu".to_owned(),
+        ]));
+        assert!(args.contains(&"--no-default-features".to_owned()));
+        assert!(args.contains(&"std".to_owned()));
+    }
+
+    #[test]
+    fn it_should_create_the_sboms_of_a_manifest() {
+        let manifest_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/build_then_runtime_dep/Cargo.toml");
+
+        let sboms =
+            SbomGenerator::create_sboms_for_manifest(&manifest_path, &SbomConfig::empty_config())
+                .unwrap();
+
+        let top_level_crate = sboms
+            .iter()
+            .find(|sbom| sbom.package_name == "top_level_crate")
+            .unwrap();
+        assert!(top_level_crate.bom.components.is_some());
+        assert!(top_level_crate.bom.dependencies.is_some());
+    }
+
+    #[test]
+    fn it_should_pass_the_components_to_the_enrichers() {
+        let manifest_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/build_then_runtime_dep/Cargo.toml");
+        let config = with_default_target(&SbomConfig::empty_config());
+        let metadata = metadata_command(&manifest_path, &config, Vec::new())
+            .exec()
+            .unwrap();
+
+        let internal_id = |component: &mut Component, package: &Package| {
+            add_properties(
+                component,
+                Some(Properties(vec![Property::new(
+                    "acme:internal-id",
+                    &package.name,
+                )])),
+            );
+        };
+        let sboms = SbomGenerator::create_sboms_with_enrichers(
+            metadata,
+            &config,
+            &NoProgress,
+            &[&internal_id],
+        )
+        .unwrap();
+
+        for sbom in sboms {
+            let metadata = sbom.bom.metadata.as_ref().unwrap();
+            let components = sbom.bom.components.as_ref().unwrap();
+            for component in metadata.component.iter().chain(&components.0) {
+                let properties = component.properties.as_ref().unwrap();
+                assert!(properties
+                    .0
+                    .contains(&Property::new("acme:internal-id", component.name.as_ref())));
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_record_the_enabled_features() {
+        assert_eq!(create_features_properties(&[]), None);
+        assert_eq!(
+            create_features_properties(&["std".into(), "derive".into()]),
+            Some(Properties(vec![Property::new(
+                "cargo-cyclonedx:features",
+                "derive,std"
+            )]))
+        );
+    }
+
+    #[test]
+    fn it_should_name_artifacts_per_platform() {
+        let linux = "x86_64-unknown-linux-gnu";
+        let macos = "aarch64-apple-darwin";
+        let windows = "x86_64-pc-windows-msvc";
+
+        assert_eq!(
+            artifact_file_name("my-tool", "bin", linux),
+            Some("my-tool".into())
+        );
+        assert_eq!(
+            artifact_file_name("my-tool", "bin", windows),
+            Some("my-tool.exe".into())
+        );
+        assert_eq!(
+            artifact_file_name("my-lib", "cdylib", linux),
+            Some("libmy_lib.so".into())
+        );
+        assert_eq!(
+            artifact_file_name("my-lib", "cdylib", macos),
+            Some("libmy_lib.dylib".into())
+        );
+        assert_eq!(
+            artifact_file_name("my-lib", "cdylib", windows),
+            Some("my_lib.dll".into())
+        );
+        assert_eq!(
+            artifact_file_name("my-lib", "staticlib", windows),
+            Some("my_lib.lib".into())
+        );
+        assert_eq!(
+            artifact_file_name("my-lib", "staticlib", "x86_64-pc-windows-gnu"),
+            Some("libmy_lib.a".into())
+        );
+        assert_eq!(artifact_file_name("my-lib", "lib", linux), None);
+        assert_eq!(artifact_file_name("my-lib", "rlib", linux), None);
+    }
+
+    #[test]
+    fn it_should_create_artifacts_of_the_root_package() {
+        let package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let config = SbomConfig {
+            artifacts: Some(true),
+            ..Default::default()
+        };
+        let mut generator = generator(config);
+        generator.config.target = Some(crate::config::Target::SingleTarget(
+            "x86_64-pc-windows-msvc".into(),
+        ));
+
+        let artifacts = generator.create_artifacts(&package);
+
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(artifacts[0].name.to_string(), "cargo-cyclonedx.exe");
+        assert_eq!(artifacts[0].component_type, Classification::Application);
+        assert_eq!(
+            artifacts[0].bom_ref.as_deref(),
+            Some("cargo-cyclonedx 0.3.8 (path+file:///home/shnatsel/Code/cargo-cyclonedx/cargo-cyclonedx) artifact-cargo-cyclonedx.exe")
+        );
+    }
+
+    #[test]
+    fn it_should_describe_the_root_package_in_the_metadata() {
+        let package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+
+        let (metadata, target_kinds) = generator(SbomConfig::empty_config())
+            .create_metadata(&package)
+            .unwrap();
+        let component = metadata.component.unwrap();
+
+        assert_eq!(component.name.to_string(), "cargo-cyclonedx");
+        assert_eq!(component.version.unwrap().to_string(), "0.3.8");
+        assert_eq!(
+            component.description.unwrap().to_string(),
+            "CycloneDX Software Bill of Materials (SBOM) for Rust Crates"
+        );
+        assert!(component.licenses.is_some());
+        assert!(component.purl.is_some());
+        assert_eq!(
+            component.author.unwrap().to_string(),
+            "Steve Springett <steve.springett@owasp.org>"
+        );
+        assert_eq!(component.authors, None);
+
+        let references = component.external_references.unwrap().0;
+        assert!(references
+            .iter()
+            .any(|r| r.external_reference_type == ExternalReferenceType::Vcs));
+        assert!(references
+            .iter()
+            .any(|r| r.external_reference_type == ExternalReferenceType::Website));
+
+        let targets = component.components.unwrap().0;
+        assert!(!targets.is_empty());
+        assert!(targets.iter().all(|target| target_kinds
+            .0
+            .contains_key(target.bom_ref.as_ref().unwrap())));
+
+        assert_eq!(
+            metadata.authors,
+            Some(vec![OrganizationalContact::new(
+                "Steve Springett",
+                Some("steve.springett@owasp.org")
+            )])
+        );
+    }
+
+    #[test]
+    fn it_should_record_the_root_authors_as_contacts_since_1_6() {
+        let package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let config = SbomConfig {
+            spec_version: Some(SpecVersion::V1_6),
+            ..Default::default()
+        };
+
+        let (metadata, _) = generator(config).create_metadata(&package).unwrap();
+        let component = metadata.component.unwrap();
+
+        assert_eq!(component.authors, metadata.authors);
+        assert!(component.authors.is_some());
+    }
+
+    #[test]
+    fn it_should_create_a_cpe_for_a_crate() {
+        let cpe = SbomGenerator::create_cpe("cargo-cyclonedx", "0.5.6");
+
+        assert_eq!(
+            cpe.to_string(),
+            "cpe:2.3:a:*:cargo-cyclonedx:0.5.6:*:*:*:*:*:*:*"
+        );
+        assert!(cyclonedx_bom::models::component::validate_cpe(&cpe).is_ok());
+    }
+
+    #[test]
+    fn it_should_create_tools_in_the_configured_format() {
+        let config = SbomConfig::empty_config();
+        assert!(matches!(
+            SbomGenerator::create_tools(&config, None),
+            Tools::List(tools) if tools.len() == 1
+        ));
+
+        let config = SbomConfig {
+            spec_version: Some(SpecVersion::V1_5),
+            tools_format: Some(ToolsFormat::Object),
+            ..SbomConfig::empty_config()
+        };
+        match SbomGenerator::create_tools(&config, None) {
+            Tools::Object {
+                services: None,
+                components: Some(components),
+            } => {
+                assert_eq!(components.0.len(), 1);
+                assert_eq!(components.0[0].name.to_string(), "cargo-cyclonedx");
+            }
+            tools => panic!("Expected tools object, got: {:?}", tools),
+        }
+
+        // The tools object is not supported before 1.5
+        let config = SbomConfig {
+            spec_version: Some(SpecVersion::V1_4),
+            tools_format: Some(ToolsFormat::Object),
+            ..SbomConfig::empty_config()
+        };
+        assert!(matches!(
+            SbomGenerator::create_tools(&config, None),
+            Tools::List(_)
+        ));
+    }
+
+    #[test]
+    fn it_should_record_rustc_as_a_tool() {
+        let rustc = RustcVersion {
+            release: "1.80.0".to_string(),
+            commit_hash: Some("051478957371ee0084a7c0913941d2a8c4757bb9".to_string()),
+            host: "x86_64-unknown-linux-gnu".to_string(),
+        };
+
+        let config = SbomConfig::empty_config();
+        match SbomGenerator::create_tools(&config, Some(&rustc)) {
+            Tools::List(tools) => {
+                assert_eq!(tools.len(), 2);
+                assert_eq!(tools[1], Tool::new("The Rust Project", "rustc", "1.80.0"));
+            }
+            tools => panic!("Expected a list of tools, got {tools:?}"),
+        }
+
+        let config = SbomConfig {
+            spec_version: Some(SpecVersion::V1_5),
+            tools_format: Some(ToolsFormat::Object),
+            ..Default::default()
+        };
+        match SbomGenerator::create_tools(&config, Some(&rustc)) {
+            Tools::Object {
+                components: Some(components),
+                ..
+            } => {
+                assert_eq!(components.0.len(), 2);
+                assert_eq!(components.0[1].name.to_string(), "rustc");
+                assert_eq!(
+                    components.0[1].version.as_ref().unwrap().to_string(),
+                    "1.80.0"
+                );
+            }
+            tools => panic!("Expected tools as components, got {tools:?}"),
+        }
+    }
+
+    #[test]
+    fn it_should_classify_the_root_component_as_configured() {
+        let package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+
+        let (metadata, _) = generator(SbomConfig::empty_config())
+            .create_metadata(&package)
+            .unwrap();
+        assert_eq!(
+            metadata.component.unwrap().component_type,
+            Classification::Application
+        );
+
+        let (metadata, _) = generator(SbomConfig {
+            root_classification: Some(RootClassification::Library),
+            ..Default::default()
+        })
+        .create_metadata(&package)
+        .unwrap();
+        assert_eq!(
+            metadata.component.unwrap().component_type,
+            Classification::Library
+        );
+    }
+
+    #[test]
+    fn it_should_record_the_build_environment_in_the_metadata() {
+        let package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let mut generator = generator(SbomConfig {
+            profile: Some("release".to_string()),
+            ..Default::default()
+        });
+        generator.rustc = Some(RustcVersion {
+            release: "1.80.0".to_string(),
+            commit_hash: None,
+            host: "aarch64-apple-darwin".to_string(),
+        });
+
+        let (metadata, _) = generator.create_metadata(&package).unwrap();
+
+        assert_eq!(
+            metadata.properties,
+            Some(Properties(vec![
+                Property::new("cdx:rustc:sbom:target:all_targets", "true"),
+                Property::new("cargo-cyclonedx:host:triple", "aarch64-apple-darwin"),
+                Property::new("cargo-cyclonedx:profile", "release"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_record_the_supplier_and_manufacturer_in_the_metadata() {
+        let package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let config = SbomConfig {
+            supplier: Some(Organization {
+                name: "Acme".to_string(),
+                url: Some("https://acme.example".to_string()),
+            }),
+            manufacturer: Some(Organization {
+                name: "Acme Build".to_string(),
+                url: None,
+            }),
+            ..Default::default()
+        };
+
+        let (metadata, _) = generator(config.clone()).create_metadata(&package).unwrap();
+        let mut supplier = OrganizationalEntity::new("Acme");
+        supplier.url = Some(vec![Uri::new("https://acme.example")]);
+        assert_eq!(metadata.supplier, Some(supplier));
+        assert_eq!(
+            metadata.manufacture,
+            Some(OrganizationalEntity::new("Acme Build"))
+        );
+        assert_eq!(metadata.manufacturer, None);
+
+        let (metadata, _) = generator(SbomConfig {
+            spec_version: Some(SpecVersion::V1_6),
+            ..config
+        })
+        .create_metadata(&package)
+        .unwrap();
+        assert_eq!(metadata.manufacture, None);
+        assert_eq!(
+            metadata.manufacturer,
+            Some(OrganizationalEntity::new("Acme Build"))
+        );
+    }
+
+    #[test]
+    fn it_should_record_the_git_provenance_of_the_root_component() {
+        let package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let mut generator = generator(SbomConfig::empty_config());
+        generator.vcs = Some(GitInfo {
+            commit: "0123456789abcdef0123456789abcdef01234567".to_string(),
+            dirty: true,
+            tag: Some("v1.0.0".to_string()),
+            remote_url: Some("https://example.com/acme/root.git".to_string()),
+        });
+
+        let (metadata, _) = generator.create_metadata(&package).unwrap();
+        let component = metadata.component.unwrap();
+
+        let commits = component.pedigree.unwrap().commits.unwrap();
+        assert_eq!(
+            commits.0[0].uid,
+            Some(NormalizedString::new(
+                "0123456789abcdef0123456789abcdef01234567"
+            ))
+        );
+        assert!(component
+            .external_references
+            .unwrap()
+            .0
+            .iter()
+            .any(|r| r.external_reference_type == ExternalReferenceType::Vcs
+                && r.url.to_string() == "https://example.com/acme/root.git"));
+        let properties = component.properties.unwrap();
+        assert!(properties
+            .0
+            .contains(&Property::new("cargo-cyclonedx:vcs:dirty", "true")));
+        assert!(properties
+            .0
+            .contains(&Property::new("cargo-cyclonedx:vcs:tag", "v1.0.0")));
+    }
 
     #[test]
     fn it_should_parse_author_and_email() {
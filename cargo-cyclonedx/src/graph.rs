@@ -0,0 +1,135 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Renders the dependency graph recorded in an SBOM, for `--graph`.
+
+use cyclonedx_bom::models::bom::Bom;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// The DOT language of GraphViz
+    Dot,
+    /// A mermaid flowchart
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// Picks the format from the file extension, `.mmd` and `.mermaid` for mermaid and DOT for
+    /// everything else.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("mmd" | "mermaid") => Self::Mermaid,
+            _ => Self::Dot,
+        }
+    }
+}
+
+/// Renders the dependencies of the SBOM, labelling the nodes with the names and versions of the
+/// components they refer to.
+pub fn render_graph(bom: &Bom, format: GraphFormat) -> String {
+    let graph = bom.dependency_graph();
+    let label = |bom_ref: &str| {
+        let component = bom.component_by_ref(bom_ref)?;
+        Some(match &component.version {
+            Some(version) => format!("{} {}", component.name, version),
+            None => component.name.to_string(),
+        })
+    };
+    match format {
+        GraphFormat::Dot => graph.to_dot_with_labels(label),
+        GraphFormat::Mermaid => graph.to_mermaid_with_labels(label),
+    }
+}
+
+/// The file to write the graph of a package to, the path itself for a single package and
+/// `<stem>-<package>.<extension>` if graphs of several packages are written.
+pub fn graph_path(path: &Path, package_name: &str, several_packages: bool) -> PathBuf {
+    if !several_packages {
+        return path.to_path_buf();
+    }
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{stem}-{package_name}.{}", extension.to_string_lossy()),
+        None => format!("{stem}-{package_name}"),
+    };
+    path.with_file_name(file_name)
+}
+
+/// Writes the graph of the SBOM to the file, creating its directory if necessary.
+pub fn write_graph(bom: &Bom, path: &Path) -> std::io::Result<()> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+    std::fs::write(path, render_graph(bom, GraphFormat::from_path(path)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::models::component::{Classification, Component, Components};
+    use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
+
+    #[test]
+    fn it_should_label_nodes_with_component_names() {
+        let bom = Bom {
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "serde",
+                "1.0.0",
+                Some("registry+https://github.com/rust-lang/crates.io-index#serde@1.0.0".into()),
+            )])),
+            dependencies: Some(Dependencies(vec![Dependency {
+                dependency_ref: "app".to_string(),
+                dependencies: vec![
+                    "registry+https://github.com/rust-lang/crates.io-index#serde@1.0.0".to_string(),
+                ],
+            }])),
+            ..Bom::default()
+        };
+
+        let dot = render_graph(&bom, GraphFormat::Dot);
+        assert!(dot.contains(r#"[label="serde 1.0.0"];"#), "{dot}");
+        assert!(dot.contains("  \"app\";\n"), "{dot}");
+
+        let mermaid = render_graph(&bom, GraphFormat::Mermaid);
+        assert!(mermaid.contains("  n1[\"serde 1.0.0\"]\n"), "{mermaid}");
+    }
+
+    #[test]
+    fn it_should_name_graph_files() {
+        assert_eq!(
+            GraphFormat::from_path(Path::new("deps.mmd")),
+            GraphFormat::Mermaid
+        );
+        assert_eq!(
+            GraphFormat::from_path(Path::new("deps.gv")),
+            GraphFormat::Dot
+        );
+        assert_eq!(
+            graph_path(Path::new("out/deps.dot"), "app", false),
+            PathBuf::from("out/deps.dot")
+        );
+        assert_eq!(
+            graph_path(Path::new("out/deps.dot"), "app", true),
+            PathBuf::from("out/deps-app.dot")
+        );
+    }
+}
@@ -15,11 +15,52 @@
  *
  * SPDX-License-Identifier: Apache-2.0
  */
+//! The SBOM generation of `cargo cyclonedx`, for build tools and IDE plugins to create SBOMs
+//! in-process instead of running the command.
+//!
+//! [`SbomGenerator`] maps the packages and dependency graph of `cargo metadata` to CycloneDX
+//! components and dependencies, creating one SBOM per workspace member:
+//!
+//! ```no_run
+//! use cargo_cyclonedx::{config::SbomConfig, format::Format, SbomGenerator};
+//! use std::path::Path;
+//!
+//! let config = SbomConfig {
+//!     formats: Some(vec![Format::Json]),
+//!     ..SbomConfig::empty_config()
+//! };
+//! let sboms = SbomGenerator::create_sboms_for_manifest(Path::new("Cargo.toml"), &config)?;
+//! for sbom in sboms {
+//!     // Either inspect the `cyclonedx_bom::models::bom::Bom`, or write it next to `Cargo.toml`
+//!     println!("{} has {:?} components", sbom.package_name, sbom.bom.components.map(|c| c.0.len()));
+//! }
+//! # Ok::<(), cargo_cyclonedx::GeneratorError>(())
+//! ```
+//!
+//! To run `cargo metadata` with further options, prepare it with [`metadata_command`] and pass its
+//! output to [`SbomGenerator::create_sboms`], or
+//! [`create_sboms_with_progress`](SbomGenerator::create_sboms_with_progress) to report progress.
+//! [`create_sboms_with_enrichers`](SbomGenerator::create_sboms_with_enrichers) also hands every
+//! component to [`ComponentEnricher`](enricher::ComponentEnricher)s, to add internal metadata.
 
+pub mod attestation;
+pub mod binary;
+pub mod cache;
+pub mod clearlydefined;
 pub mod config;
+mod digest;
+pub mod enricher;
 pub mod format;
 pub mod generator;
+pub mod graph;
+pub mod license_override;
+pub mod lockfile;
+pub mod maintenance;
 pub mod platform;
+pub mod policy;
 pub mod purl;
+pub mod report;
+pub mod upload;
+pub mod vcs;
 
 pub use crate::generator::*;
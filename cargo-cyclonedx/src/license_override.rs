@@ -0,0 +1,183 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cyclonedx_bom::external_models::spdx::{SpdxExpression, SpdxExpressionError};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The source of the overrides configured in `[package.metadata.cyclonedx]`
+pub const PACKAGE_METADATA_SOURCE: &str = "package.metadata.cyclonedx";
+
+/// A license replacing the one declared by a package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseOverride {
+    pub expression: SpdxExpression,
+    /// Where the override was configured, a file path or [`PACKAGE_METADATA_SOURCE`]
+    pub source: String,
+    /// Why the declared license is replaced
+    pub reason: Option<String>,
+}
+
+/// License overrides keyed by `name@version`, or by `name` for all versions of a package
+pub type LicenseOverrides = BTreeMap<String, LicenseOverride>;
+
+/// An override as written in a `license-overrides.toml` file or in the package metadata,
+/// either just the SPDX expression or a table with the license and a reason:
+///
+/// ```toml
+/// "foo@1.0.0" = "MIT"
+/// "bar" = { license = "Apache-2.0 OR MIT", reason = "The crate is dual licensed since 0.2" }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum LicenseOverrideEntry {
+    License(String),
+    Detailed {
+        license: String,
+        #[serde(default)]
+        reason: Option<String>,
+    },
+}
+
+#[derive(Error, Debug)]
+pub enum LicenseOverridesError {
+    #[error("Failed to read the license overrides from {}: {error}", path.display())]
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+
+    #[error("Failed to parse the license overrides in {source_name}: {error}")]
+    Parse {
+        source_name: String,
+        error: toml::de::Error,
+    },
+
+    #[error("Invalid license override for {package} in {source_name}: {error}")]
+    InvalidLicense {
+        package: String,
+        source_name: String,
+        error: SpdxExpressionError,
+    },
+}
+
+/// Reads the license overrides from a TOML file mapping `name@version` or `name`
+/// to an SPDX expression
+pub fn read_license_overrides(path: &Path) -> Result<LicenseOverrides, LicenseOverridesError> {
+    let content = std::fs::read_to_string(path).map_err(|error| LicenseOverridesError::Io {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    parse_license_overrides(&content, &path.display().to_string())
+}
+
+/// Parses the content of a license overrides file, `source` is recorded as its provenance
+pub fn parse_license_overrides(
+    content: &str,
+    source: &str,
+) -> Result<LicenseOverrides, LicenseOverridesError> {
+    let entries: BTreeMap<String, LicenseOverrideEntry> =
+        toml::from_str(content).map_err(|error| LicenseOverridesError::Parse {
+            source_name: source.to_owned(),
+            error,
+        })?;
+    to_license_overrides(entries, source)
+}
+
+pub(crate) fn to_license_overrides(
+    entries: BTreeMap<String, LicenseOverrideEntry>,
+    source: &str,
+) -> Result<LicenseOverrides, LicenseOverridesError> {
+    entries
+        .into_iter()
+        .map(|(package, entry)| {
+            let (license, reason) = match entry {
+                LicenseOverrideEntry::License(license) => (license, None),
+                LicenseOverrideEntry::Detailed { license, reason } => (license, reason),
+            };
+            let expression = SpdxExpression::try_from(license).map_err(|error| {
+                LicenseOverridesError::InvalidLicense {
+                    package: package.clone(),
+                    source_name: source.to_owned(),
+                    error,
+                }
+            })?;
+            let license_override = LicenseOverride {
+                expression,
+                source: source.to_owned(),
+                reason,
+            };
+            Ok((package, license_override))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_license_overrides() {
+        let content = r#"
+            "foo@1.0.0" = "MIT"
+            "bar" = { license = "Apache-2.0 OR MIT", reason = "Dual licensed upstream" }
+        "#;
+
+        let actual = parse_license_overrides(content, "license-overrides.toml")
+            .expect("Failed to parse the license overrides");
+
+        assert_eq!(
+            actual,
+            BTreeMap::from([
+                (
+                    "foo@1.0.0".to_string(),
+                    LicenseOverride {
+                        expression: SpdxExpression::new("MIT"),
+                        source: "license-overrides.toml".to_string(),
+                        reason: None,
+                    }
+                ),
+                (
+                    "bar".to_string(),
+                    LicenseOverride {
+                        expression: SpdxExpression::new("Apache-2.0 OR MIT"),
+                        source: "license-overrides.toml".to_string(),
+                        reason: Some("Dual licensed upstream".to_string()),
+                    }
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_license_overrides() {
+        assert!(matches!(
+            parse_license_overrides(r#""foo" = "not a license""#, "overrides.toml"),
+            Err(LicenseOverridesError::InvalidLicense { package, .. }) if package == "foo"
+        ));
+        assert!(matches!(
+            parse_license_overrides(r#""foo" = 1"#, "overrides.toml"),
+            Err(LicenseOverridesError::Parse { .. })
+        ));
+        assert!(matches!(
+            read_license_overrides(Path::new("/nonexistent/overrides.toml")),
+            Err(LicenseOverridesError::Io { .. })
+        ));
+    }
+}
@@ -0,0 +1,456 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Builds the `cargo metadata` output from a `Cargo.lock` alone.
+//!
+//! This skips manifest resolution and never touches the network, at the cost of information
+//! only the manifests have: all dependencies are normal dependencies for all platforms, and
+//! licenses, authors, descriptions, features and Cargo targets are unknown.
+//!
+//! The workspace members are the root package and the `[workspace] members` of the `Cargo.toml`
+//! next to the lockfile. The other packages without a source are path dependencies, found by
+//! following the `path` keys of the manifests. Without a `Cargo.toml`, every package without a
+//! source is treated as a workspace member.
+
+use cargo_lock::Lockfile;
+use cargo_metadata::Metadata;
+use serde_json::json;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LockfileError {
+    #[error("Failed to read the lockfile {}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Failed to parse the lockfile {}", path.display())]
+    Parse {
+        path: PathBuf,
+        #[source]
+        error: cargo_lock::Error,
+    },
+
+    #[error("Failed to parse the manifest {}", path.display())]
+    Manifest {
+        path: PathBuf,
+        #[source]
+        error: toml::de::Error,
+    },
+
+    #[error("The dependency {dependency} of {package} is missing from the lockfile")]
+    MissingDependency { package: String, dependency: String },
+
+    #[error("Failed to convert the lockfile to cargo metadata")]
+    Metadata(#[source] serde_json::Error),
+}
+
+/// Reads the lockfile and returns the metadata `cargo metadata` would report for it
+pub fn metadata_from_lockfile(path: &Path) -> Result<Metadata, LockfileError> {
+    let path = path.canonicalize().map_err(|error| LockfileError::Io {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let lockfile = Lockfile::load(&path).map_err(|error| LockfileError::Parse {
+        path: path.clone(),
+        error,
+    })?;
+    let workspace_root = path.parent().unwrap_or(Path::new("/"));
+    let manifests = read_manifests(workspace_root)?;
+    to_metadata(&lockfile, workspace_root, manifests.as_ref())
+}
+
+/// The local packages declared by the manifests of a workspace
+#[derive(Debug, Default)]
+struct Manifests {
+    /// The names of the workspace members
+    members: HashSet<String>,
+    /// The manifest paths of the members and path dependencies, by package name
+    paths: HashMap<String, PathBuf>,
+}
+
+/// Reads the root manifest, the manifests of its workspace members and those of the path
+/// dependencies they lead to, or returns `None` if there is no root manifest
+fn read_manifests(workspace_root: &Path) -> Result<Option<Manifests>, LockfileError> {
+    let root_manifest = workspace_root.join("Cargo.toml");
+    if !root_manifest.is_file() {
+        return Ok(None);
+    }
+    let root = read_manifest(&root_manifest)?;
+
+    let mut member_manifests = Vec::new();
+    if root.get("package").is_some() {
+        member_manifests.push(root_manifest.clone());
+    }
+    let workspace = root.get("workspace");
+    let paths = |key: &str| -> Vec<String> {
+        workspace
+            .and_then(|workspace| workspace.get(key))
+            .and_then(toml::Value::as_array)
+            .map(|paths| {
+                paths
+                    .iter()
+                    .filter_map(|path| path.as_str().map(str::to_owned))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let excluded: Vec<PathBuf> = paths("exclude")
+        .iter()
+        .map(|path| workspace_root.join(path))
+        .collect();
+    for pattern in paths("members") {
+        let pattern = workspace_root.join(pattern);
+        // Members that are not globs are matched literally, like directories with brackets
+        let dirs: Vec<PathBuf> = match glob::glob(&pattern.to_string_lossy()) {
+            Ok(dirs) => dirs.filter_map(Result::ok).collect(),
+            Err(_) => vec![pattern],
+        };
+        for dir in dirs {
+            let manifest = dir.join("Cargo.toml");
+            if manifest.is_file() && !excluded.iter().any(|excluded| dir.starts_with(excluded)) {
+                member_manifests.push(manifest);
+            }
+        }
+    }
+
+    let mut manifests = Manifests::default();
+    let mut queue: VecDeque<(PathBuf, bool)> = member_manifests
+        .into_iter()
+        .map(|manifest| (manifest, true))
+        .collect();
+    // The root manifest may declare path dependencies in `[workspace.dependencies]` or `[patch]`
+    // without being a package itself
+    queue.push_back((root_manifest, false));
+    let mut visited = HashSet::new();
+    while let Some((path, is_member)) = queue.pop_front() {
+        if !visited.insert((path.clone(), is_member)) {
+            continue;
+        }
+        let manifest = read_manifest(&path)?;
+        if let Some(name) = manifest
+            .get("package")
+            .and_then(|package| package.get("name"))
+            .and_then(toml::Value::as_str)
+        {
+            if is_member {
+                manifests.members.insert(name.to_owned());
+            }
+            manifests
+                .paths
+                .entry(name.to_owned())
+                .or_insert_with(|| path.clone());
+        }
+        let dir = path.parent().unwrap_or(Path::new("/"));
+        for dependency_path in dependency_paths(&manifest) {
+            // Canonical paths, so manifests reached through different paths are read once
+            if let Ok(manifest) = dir.join(dependency_path).join("Cargo.toml").canonicalize() {
+                queue.push_back((manifest, false));
+            }
+        }
+    }
+    Ok(Some(manifests))
+}
+
+fn read_manifest(path: &Path) -> Result<toml::Table, LockfileError> {
+    let content = std::fs::read_to_string(path).map_err(|error| LockfileError::Io {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    content.parse().map_err(|error| LockfileError::Manifest {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// The `path` keys of the dependencies of all kinds and platforms, of the workspace
+/// dependencies and of the patches
+fn dependency_paths(manifest: &toml::Table) -> Vec<&str> {
+    const KINDS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+    let mut tables: Vec<&toml::Value> = KINDS
+        .iter()
+        .filter_map(|kind| manifest.get(*kind))
+        .collect();
+    if let Some(targets) = manifest.get("target").and_then(toml::Value::as_table) {
+        for target in targets.values() {
+            tables.extend(KINDS.iter().filter_map(|kind| target.get(*kind)));
+        }
+    }
+    if let Some(dependencies) = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("dependencies"))
+    {
+        tables.push(dependencies);
+    }
+    if let Some(patches) = manifest.get("patch").and_then(toml::Value::as_table) {
+        tables.extend(patches.values());
+    }
+
+    tables
+        .into_iter()
+        .filter_map(toml::Value::as_table)
+        .flat_map(|dependencies| dependencies.values())
+        .filter_map(|dependency| dependency.get("path").and_then(toml::Value::as_str))
+        .collect()
+}
+
+fn to_metadata(
+    lockfile: &Lockfile,
+    workspace_root: &Path,
+    manifests: Option<&Manifests>,
+) -> Result<Metadata, LockfileError> {
+    // The same package ID format as `cargo metadata`, so the hashes from the lockfile match
+    let package_id = |package: &cargo_lock::Package| match &package.source {
+        Some(source) => format!("{}#{}@{}", source, package.name, package.version),
+        None => format!("{}@{}", package.name, package.version),
+    };
+    let ids: HashMap<(&str, String), Vec<(&cargo_lock::Package, String)>> = lockfile
+        .packages
+        .iter()
+        .fold(HashMap::new(), |mut ids, package| {
+            ids.entry((package.name.as_str(), package.version.to_string()))
+                .or_default()
+                .push((package, package_id(package)));
+            ids
+        });
+
+    let root_manifest = workspace_root.join("Cargo.toml");
+    let mut packages = Vec::with_capacity(lockfile.packages.len());
+    let mut nodes = Vec::with_capacity(lockfile.packages.len());
+    let mut workspace_members = Vec::new();
+    for package in &lockfile.packages {
+        let id = package_id(package);
+        let name = package.name.as_str();
+        let is_member = manifests.map_or(true, |manifests| manifests.members.contains(name));
+        if package.source.is_none() && is_member {
+            workspace_members.push(id.clone());
+        }
+        let manifest_path = manifests
+            .filter(|_| package.source.is_none())
+            .and_then(|manifests| manifests.paths.get(name))
+            .unwrap_or(&root_manifest);
+
+        let mut deps = Vec::with_capacity(package.dependencies.len());
+        for dependency in &package.dependencies {
+            let candidates = ids
+                .get(&(dependency.name.as_str(), dependency.version.to_string()))
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let dependency_id = candidates
+                .iter()
+                .find(|(candidate, _)| {
+                    candidates.len() == 1 || candidate.source == dependency.source
+                })
+                .map(|(_, id)| id.clone())
+                .ok_or_else(|| LockfileError::MissingDependency {
+                    package: id.clone(),
+                    dependency: dependency.to_string(),
+                })?;
+            deps.push(json!({
+                "name": dependency.name.as_str().replace('-', "_"),
+                "pkg": dependency_id,
+                "dep_kinds": [{ "kind": null, "target": null }],
+            }));
+        }
+
+        packages.push(json!({
+            "name": package.name.as_str(),
+            "version": package.version.to_string(),
+            "id": id,
+            "source": package.source.as_ref().map(ToString::to_string),
+            "dependencies": [],
+            "targets": [],
+            "features": {},
+            "manifest_path": manifest_path,
+        }));
+        nodes.push(json!({
+            "id": id,
+            "dependencies": deps.iter().map(|dep| dep["pkg"].clone()).collect::<Vec<_>>(),
+            "deps": deps,
+            "features": [],
+        }));
+    }
+
+    let metadata = json!({
+        "packages": packages,
+        "workspace_members": workspace_members,
+        "workspace_default_members": workspace_members,
+        "resolve": { "nodes": nodes, "root": null },
+        "workspace_root": workspace_root,
+        "target_directory": workspace_root.join("target"),
+        "version": 1,
+    });
+    serde_json::from_value(metadata).map_err(LockfileError::Metadata)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    const LOCKFILE: &str = r#"
+version = 3
+
+[[package]]
+name = "my-app"
+version = "0.1.0"
+dependencies = [
+ "serde",
+ "my-lib",
+]
+
+[[package]]
+name = "my-lib"
+version = "0.2.0"
+dependencies = [
+ "serde",
+]
+
+[[package]]
+name = "serde"
+version = "1.0.193"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "25dd9975e68d0cb5aa1120c288333fc98731bd1dd12f561e468ea4728c042b89"
+"#;
+
+    #[test]
+    fn it_should_convert_the_lockfile_to_metadata() {
+        let lockfile = Lockfile::from_str(LOCKFILE).unwrap();
+        let metadata = to_metadata(&lockfile, Path::new("/workspace"), None).unwrap();
+
+        assert_eq!(
+            metadata
+                .workspace_members
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["my-app@0.1.0", "my-lib@0.2.0"]
+        );
+
+        let serde_id = "registry+https://github.com/rust-lang/crates.io-index#serde@1.0.193";
+        let serde = &metadata.packages[2];
+        assert_eq!(serde.id.repr, serde_id);
+        assert!(serde.source.as_ref().unwrap().is_crates_io());
+        assert_eq!(serde.manifest_path, "/workspace/Cargo.toml");
+
+        let resolve = metadata.resolve.unwrap();
+        let app = &resolve.nodes[0];
+        assert_eq!(
+            app.dependencies
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [serde_id, "my-lib@0.2.0"]
+        );
+        assert_eq!(app.deps[1].name, "my_lib");
+        assert_eq!(
+            app.deps[1].dep_kinds[0].kind,
+            cargo_metadata::DependencyKind::Normal
+        );
+    }
+
+    #[test]
+    fn it_should_read_the_members_from_the_manifests() {
+        use assert_fs::prelude::*;
+
+        let workspace = assert_fs::TempDir::new().unwrap();
+        workspace
+            .child("Cargo.toml")
+            .write_str(
+                r#"
+[workspace]
+members = ["apps/*"]
+exclude = ["apps/old"]
+"#,
+            )
+            .unwrap();
+        workspace
+            .child("apps/my-app/Cargo.toml")
+            .write_str(
+                r#"
+[package]
+name = "my-app"
+
+[dependencies]
+my-lib = { path = "../../libs/my-lib" }
+"#,
+            )
+            .unwrap();
+        workspace
+            .child("apps/old/Cargo.toml")
+            .write_str("[package]\nname = \"old\"\n")
+            .unwrap();
+        workspace
+            .child("libs/my-lib/Cargo.toml")
+            .write_str("[package]\nname = \"my-lib\"\n")
+            .unwrap();
+
+        let lockfile = Lockfile::from_str(LOCKFILE).unwrap();
+        let manifests = read_manifests(workspace.path()).unwrap();
+        let metadata = to_metadata(&lockfile, workspace.path(), manifests.as_ref()).unwrap();
+
+        assert_eq!(
+            metadata
+                .workspace_members
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["my-app@0.1.0"]
+        );
+        assert_eq!(
+            metadata.packages[0].manifest_path,
+            workspace.child("apps/my-app/Cargo.toml").path()
+        );
+        assert_eq!(
+            metadata.packages[1].manifest_path,
+            workspace
+                .child("libs/my-lib/Cargo.toml")
+                .path()
+                .canonicalize()
+                .unwrap()
+        );
+        assert_eq!(
+            metadata.packages[2].manifest_path,
+            workspace.child("Cargo.toml").path()
+        );
+    }
+
+    #[test]
+    fn it_should_fail_for_missing_dependencies() {
+        let lockfile = Lockfile::from_str(
+            r#"
+version = 3
+
+[[package]]
+name = "my-app"
+version = "0.1.0"
+dependencies = [
+ "serde 1.0.193",
+]
+"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            to_metadata(&lockfile, Path::new("/workspace"), None),
+            Err(LockfileError::MissingDependency { .. })
+        ));
+    }
+}
@@ -46,54 +46,219 @@
 * SOFTWARE.
 */
 use cargo_cyclonedx::{
-    config::{SbomConfig, Target},
-    generator::SbomGenerator,
-    GeneratedSbom,
+    binary::{create_binary_sbom, write_binary_sbom},
+    cache::{checks_sboms, GenerationCache},
+    clearlydefined::ClearlyDefinedEnricher,
+    config::SbomConfig,
+    generator::{metadata_command, SbomGenerator},
+    graph::{graph_path, write_graph},
+    license_override::read_license_overrides,
+    lockfile::metadata_from_lockfile,
+    report::GenerationReport,
+    upload::upload_bom,
 };
+use cyclonedx_bom::progress::NoProgress;
+use cyclonedx_bom::render::summary;
 
 use std::{
     io::{self},
     path::{Path, PathBuf},
+    sync::Mutex,
 };
 
-use cargo_metadata::{self, CargoOpt, Metadata};
+use cargo_metadata::{self, Metadata};
 
 use anyhow::Result;
 use clap::Parser;
 use env_logger::Builder;
-use log::LevelFilter;
+use log::{Level, LevelFilter, Log, Metadata as LogMetadata, Record};
 
 mod cli;
-use cli::{Args, Opts};
+use cli::{Args, BinaryArgs, Command, Opts};
 
-fn generate_sboms(args: &Args) -> Result<Vec<GeneratedSbom>> {
-    let cli_config = args.as_config()?;
-    let manifest_path = locate_manifest(args)?;
-    log::debug!("Found the Cargo.toml file at {}", manifest_path.display());
-
-    log::trace!("Running `cargo metadata` started");
-    let metadata = get_metadata(args, &manifest_path, &cli_config)?;
-    log::trace!("Running `cargo metadata` finished");
-
-    log::trace!("SBOM generation started");
-    let boms = SbomGenerator::create_sboms(metadata, &cli_config)?;
-    log::trace!("SBOM generation finished");
+fn load_config(args: &Args) -> Result<SbomConfig> {
+    let mut cli_config = args.as_config()?;
+    if let Some(path) = &args.license_overrides {
+        cli_config.license_overrides = Some(read_license_overrides(path)?);
+    }
+    Ok(cli_config)
+}
 
-    Ok(boms)
+fn load_metadata(args: &Args, cli_config: &SbomConfig) -> Result<Metadata> {
+    match &args.from_lockfile {
+        Some(path) => {
+            log::debug!("Reading the dependencies from {}", path.display());
+            Ok(metadata_from_lockfile(path)?)
+        }
+        None => {
+            let manifest_path = locate_manifest(args)?;
+            log::debug!("Found the Cargo.toml file at {}", manifest_path.display());
+
+            log::trace!("Running `cargo metadata` started");
+            let metadata = get_metadata(args, &manifest_path, cli_config)?;
+            log::trace!("Running `cargo metadata` finished");
+            Ok(metadata)
+        }
+    }
 }
 
 fn main() -> anyhow::Result<()> {
     let Opts::Bom(args) = Opts::parse();
     setup_logging(&args)?;
+    if let Some(Command::Binary(binary_args)) = &args.command {
+        return scan_binaries(binary_args);
+    }
+    let upload_options = args.upload_options()?;
+
+    let cli_config = load_config(&args)?;
+    let metadata = load_metadata(&args, &cli_config)?;
+
+    // SBOMs written to stdout are not cached, there is nothing to reuse
+    let cache = (args.output_file.as_deref() != Some("-")).then(|| {
+        let lockfile = match &args.from_lockfile {
+            Some(path) => path.clone(),
+            None => metadata
+                .workspace_root
+                .join("Cargo.lock")
+                .into_std_path_buf(),
+        };
+        GenerationCache::new(
+            &metadata,
+            &lockfile,
+            &cli_config,
+            &format!("{upload_options:?} clearlydefined={}", args.clearlydefined),
+        )
+    });
+    // A report, a summary and a graph describe this run, so they are always regenerated along with the SBOMs.
+    // The license and yanked checks and the upload run on the generated SBOMs, so they are not skipped either.
+    let reuse = !args.force
+        && args.report.is_none()
+        && args.summary.is_none()
+        && args.graph.is_none()
+        && upload_options.is_none()
+        && !checks_sboms(&metadata, &cli_config);
+    if reuse && cache.as_ref().is_some_and(GenerationCache::is_fresh) {
+        log::info!("The SBOMs are up to date, pass --force to regenerate them");
+        return Ok(());
+    }
+
+    log::trace!("SBOM generation started");
+    let boms = if args.clearlydefined {
+        let enricher = ClearlyDefinedEnricher::new();
+        SbomGenerator::create_sboms_with_enrichers(
+            metadata,
+            &cli_config,
+            &NoProgress,
+            &[&enricher],
+        )?
+    } else {
+        SbomGenerator::create_sboms(metadata, &cli_config)?
+    };
+    log::trace!("SBOM generation finished");
+
+    let mut license_violations = 0;
+    for bom in &boms {
+        for violation in bom.license_violations() {
+            log::error!("{}: {}", bom.package_name, violation);
+            license_violations += 1;
+        }
+    }
+
+    let mut yanked_dependencies = 0;
+    for bom in boms.iter().filter(|bom| bom.sbom_config.fail_on_yanked()) {
+        for dependency in bom.yanked_dependencies() {
+            log::error!("{}: {} is yanked", bom.package_name, dependency);
+            yanked_dependencies += 1;
+        }
+    }
+
+    if boms.len() > 1 && args.output_file.as_deref() == Some("-") {
+        anyhow::bail!(
+            "Only a single SBOM can be written to stdout, but {} packages were found",
+            boms.len()
+        );
+    }
 
-    let boms = generate_sboms(&args)?;
+    if args.summary.is_some() && args.output_file.as_deref() == Some("-") {
+        anyhow::bail!("The summary cannot be printed when the SBOM is written to stdout");
+    }
+
+    let package_reports: Vec<_> = boms.iter().map(|bom| bom.report.clone()).collect();
+    let summaries: Vec<_> = match args.summary {
+        Some(format) => boms.iter().map(|bom| summary(&bom.bom, format)).collect(),
+        None => Vec::new(),
+    };
+
+    if let Some(path) = &args.graph {
+        for bom in &boms {
+            write_graph(
+                &bom.bom,
+                &graph_path(path, &bom.package_name, boms.len() > 1),
+            )?;
+        }
+    }
 
     log::trace!("SBOM output started");
+    let mut written_sboms = Vec::new();
     for bom in boms {
-        bom.write_to_files()?;
+        written_sboms.extend(bom.write_to_files()?);
     }
     log::trace!("SBOM output finished");
 
+    if !summaries.is_empty() {
+        print!("{}", summaries.join("\n"));
+    }
+
+    if let Some(path) = &args.report {
+        let report = GenerationReport {
+            packages: package_reports,
+            warnings: WARNINGS.lock().map(|w| w.clone()).unwrap_or_default(),
+        };
+        report.write(path)?;
+    }
+
+    // Checked before the upload, so SBOMs that fail the policies are not published
+    if license_violations > 0 {
+        anyhow::bail!("Found {license_violations} violations of the license policy");
+    }
+    if yanked_dependencies > 0 {
+        anyhow::bail!("Found {yanked_dependencies} yanked dependencies");
+    }
+
+    if let Some(options) = &upload_options {
+        log::trace!("SBOM upload started");
+        for sbom in &written_sboms {
+            if let Some(path) = sbom.files.first() {
+                upload_bom(
+                    path,
+                    options.project_name.as_deref().unwrap_or(&sbom.name),
+                    options.project_version.as_deref().unwrap_or(&sbom.version),
+                    options,
+                )?;
+            }
+        }
+        log::trace!("SBOM upload finished");
+    }
+
+    if let Some(cache) = &cache {
+        if let Err(error) = cache.store(&written_sboms) {
+            log::warn!("Failed to store the generation cache: {error}");
+        }
+    }
+
+    Ok(())
+}
+
+fn scan_binaries(args: &BinaryArgs) -> Result<()> {
+    let config = args.as_config()?;
+    for binary in &args.binaries {
+        log::info!("Scanning {}", binary.display());
+        let bom = create_binary_sbom(binary, &config)?;
+        for format in config.formats() {
+            let path = args.output_path(binary, format);
+            write_binary_sbom(bom.clone(), path.as_deref(), &config, format)?;
+        }
+    }
     Ok(())
 }
 
@@ -112,11 +277,53 @@ fn setup_logging(args: &Args) -> anyhow::Result<()> {
     };
     builder.filter_level(level_filter);
     builder.parse_default_env(); // allow overriding CLI arguments
-    builder.try_init()?;
+    let logger = builder.build();
+
+    let mut max_level = logger.filter();
+    let record_warnings = args.report.is_some();
+    if record_warnings {
+        // The warnings belong in the report even if they are not printed
+        max_level = max_level.max(LevelFilter::Warn);
+    }
+    log::set_boxed_logger(Box::new(RecordingLogger {
+        inner: logger,
+        record_warnings,
+    }))?;
+    log::set_max_level(max_level);
 
     Ok(())
 }
 
+/// The warnings and errors logged so far, for `--report`
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Forwards to `env_logger`, keeping a copy of the warnings for the report
+struct RecordingLogger {
+    inner: env_logger::Logger,
+    record_warnings: bool,
+}
+
+impl Log for RecordingLogger {
+    fn enabled(&self, metadata: &LogMetadata) -> bool {
+        (self.record_warnings && metadata.level() <= Level::Warn) || self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.record_warnings && record.level() <= Level::Warn {
+            if let Ok(mut warnings) = WARNINGS.lock() {
+                warnings.push(record.args().to_string());
+            }
+        }
+        if self.inner.matches(record) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
 fn locate_manifest(args: &Args) -> Result<PathBuf, io::Error> {
     if let Some(manifest_path) = &args.manifest_path {
         let manifest_path = manifest_path.canonicalize()?;
@@ -140,22 +347,7 @@ fn get_metadata(
     manifest_path: &Path,
     config: &SbomConfig,
 ) -> anyhow::Result<Metadata> {
-    let mut cmd = cargo_metadata::MetadataCommand::new();
-    cmd.manifest_path(manifest_path);
-
-    if let Some(feature_configuration) = config.features.as_ref() {
-        if feature_configuration.all_features {
-            cmd.features(CargoOpt::AllFeatures);
-        }
-        if feature_configuration.no_default_features {
-            cmd.features(CargoOpt::NoDefaultFeatures);
-        }
-        if !feature_configuration.features.is_empty() {
-            cmd.features(CargoOpt::SomeFeatures(
-                feature_configuration.features.clone(),
-            ));
-        }
-    }
+    let mut cmd = metadata_command(manifest_path, config, cargo_options(args));
 
     if args.quiet == 0 {
         // Contrary to the name, this does not enable verbose output.
@@ -164,21 +356,56 @@ fn get_metadata(
         cmd.verbose(true);
     }
 
-    if let Some(Target::SingleTarget(target)) = config.target.as_ref() {
-        cmd.other_options(vec!["--filter-platform".to_owned(), target.to_owned()]);
+    Ok(cmd.exec()?)
+}
+
+/// Returns the options passed through to `cargo metadata`
+fn cargo_options(args: &Args) -> Vec<String> {
+    let mut options = Vec::new();
+
+    // Hashes are read from Cargo.lock, so these are enough to never touch the network
+    // and to fail instead of updating Cargo.lock
+    if args.offline {
+        options.push("--offline".to_owned());
+    }
+    if args.locked {
+        options.push("--locked".to_owned());
+    }
+    if args.frozen {
+        options.push("--frozen".to_owned());
     }
 
-    Ok(cmd.exec()?)
+    options
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::{load_config, load_metadata};
+    use cargo_cyclonedx::{generator::SbomGenerator, GeneratedSbom};
     use cyclonedx_bom::prelude::NormalizedString;
 
+    fn generate_sboms(args: &crate::Args) -> anyhow::Result<Vec<GeneratedSbom>> {
+        let cli_config = load_config(args)?;
+        let metadata = load_metadata(args, &cli_config)?;
+        Ok(SbomGenerator::create_sboms(metadata, &cli_config)?)
+    }
+
+    #[test]
+    fn pass_options_through_to_cargo() {
+        use crate::cargo_options;
+        use crate::cli;
+        use clap::Parser;
+
+        let args = cli::Args::parse_from(["cyclonedx"]);
+        assert!(cargo_options(&args).is_empty());
+
+        let args = cli::Args::parse_from(["cyclonedx", "--offline", "--locked", "--frozen"]);
+        assert_eq!(cargo_options(&args), ["--offline", "--locked", "--frozen"]);
+    }
+
     #[test]
     fn parse_toml_only_normal() {
         use crate::cli;
-        use crate::generate_sboms;
         use clap::Parser;
         use cyclonedx_bom::models::component::Scope;
         use std::path::PathBuf;
@@ -202,7 +429,6 @@ mod tests {
     #[test]
     fn parse_toml_with_excluded() {
         use crate::cli;
-        use crate::generate_sboms;
         use clap::Parser;
         use cyclonedx_bom::models::component::Scope;
         use std::path::PathBuf;
@@ -0,0 +1,467 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Evidence of yanked and unmaintained dependencies.
+//!
+//! Yanked versions are looked up in the crates.io sparse index, falling back to the index
+//! cache Cargo keeps in `$CARGO_HOME` when offline or when the index cannot be reached.
+//! Unmaintained crates are looked up in a checkout of the
+//! [RustSec advisory database](https://github.com/rustsec/advisory-db), which never touches the network.
+
+use cargo_metadata::semver::{Version, VersionReq};
+use crates_index::{Crate, SparseIndex};
+use cyclonedx_bom::client::{CurlTransport, Method, Request, Transport};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use thiserror::Error;
+
+/// Returns `$CARGO_HOME`, or `~/.cargo` if it is not set
+pub fn cargo_home() -> Option<PathBuf> {
+    if let Some(cargo_home) = std::env::var_os("CARGO_HOME") {
+        return Some(PathBuf::from(cargo_home));
+    }
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".cargo"))
+}
+
+/// The crates.io sparse index, queried with a [`Transport`]
+///
+/// Each crate is looked up once. The responses are not written to Cargo's index cache, which is only read.
+#[derive(Debug)]
+pub struct RegistryIndex<T = CurlTransport> {
+    index: SparseIndex,
+    transport: T,
+    offline: bool,
+    /// The yanked flag of each version, by crate name, or `None` for crates the index does not know
+    yanked: Mutex<HashMap<String, Option<HashMap<String, bool>>>>,
+}
+
+impl RegistryIndex {
+    /// The crates.io index with its cache in `cargo_home`, queried with `curl` unless `offline`
+    pub fn new(cargo_home: &Path, offline: bool) -> Result<Self, crates_index::Error> {
+        let index = SparseIndex::with_path(cargo_home, crates_index::sparse::URL)?;
+        Ok(Self::with_index(index, CurlTransport::default(), offline))
+    }
+}
+
+impl<T: Transport> RegistryIndex<T> {
+    pub fn with_index(index: SparseIndex, transport: T, offline: bool) -> Self {
+        Self {
+            index,
+            transport,
+            offline,
+            yanked: Mutex::default(),
+        }
+    }
+
+    /// Whether the version of the crate is yanked, or `None` if neither the index nor its cache know it
+    pub fn is_yanked(&self, name: &str, version: &str) -> Option<bool> {
+        if let Some(versions) = self.yanked.lock().unwrap().get(name) {
+            return versions.as_ref()?.get(version).copied();
+        }
+
+        let versions = match self.offline {
+            true => None,
+            false => self.fetch(name),
+        }
+        .or_else(|| self.index.crate_from_cache(name).ok())
+        .map(|krate| {
+            krate
+                .versions()
+                .iter()
+                .map(|entry| (entry.version().to_owned(), entry.is_yanked()))
+                .collect::<HashMap<_, _>>()
+        });
+        let yanked = versions
+            .as_ref()
+            .and_then(|versions| versions.get(version).copied());
+        self.yanked
+            .lock()
+            .unwrap()
+            .insert(name.to_owned(), versions);
+        yanked
+    }
+
+    /// Requests the index entry of the crate, or `None` if the request failed
+    fn fetch(&self, name: &str) -> Option<Crate> {
+        let request = match self.index.make_cache_request(name).map(|r| r.body(())) {
+            Ok(Ok(request)) => request,
+            _ => return None,
+        };
+        let request = Request {
+            method: Method::Get,
+            url: request.uri().to_string(),
+            // curl does not decompress responses unless asked to, so only identity is accepted
+            headers: request
+                .headers()
+                .iter()
+                .filter(|(name, _)| *name != crates_index::http::header::ACCEPT_ENCODING)
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_owned()))
+                })
+                .collect(),
+            body: None,
+        };
+
+        let response = match self.transport.send(&request) {
+            Ok(response) => response,
+            Err(error) => {
+                log::warn!("Failed to query the crates.io index for {name}: {error}");
+                return None;
+            }
+        };
+        let response = crates_index::http::Response::builder()
+            .status(response.status)
+            .body(response.body)
+            .ok()?;
+        match self.index.parse_cache_response(name, response, false) {
+            Ok(krate) => krate,
+            Err(error) => {
+                log::warn!("Failed to read the crates.io index entry of {name}: {error}");
+                None
+            }
+        }
+    }
+}
+
+/// An advisory of the RustSec database about a crate being unmaintained
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Advisory {
+    /// The identifier, e.g. `RUSTSEC-2020-0016`
+    pub id: String,
+    pub package: String,
+    patched: Vec<VersionReq>,
+    unaffected: Vec<VersionReq>,
+}
+
+impl Advisory {
+    pub fn affects(&self, name: &str, version: &Version) -> bool {
+        self.package == name
+            && !self
+                .patched
+                .iter()
+                .chain(&self.unaffected)
+                .any(|requirement| requirement.matches(version))
+    }
+}
+
+/// The unmaintained advisories of a local checkout of the RustSec advisory database
+#[derive(Debug, Clone, Default)]
+pub struct AdvisoryDatabase {
+    advisories: Vec<Advisory>,
+}
+
+#[derive(Error, Debug)]
+pub enum AdvisoryDatabaseError {
+    #[error("Failed to read the advisory database in {}: {error}", path.display())]
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct AdvisoryFile {
+    advisory: AdvisoryMetadata,
+    #[serde(default)]
+    versions: AdvisoryVersions,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdvisoryMetadata {
+    id: String,
+    package: String,
+    #[serde(default)]
+    informational: Option<String>,
+    #[serde(default)]
+    withdrawn: Option<toml::Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AdvisoryVersions {
+    #[serde(default)]
+    patched: Vec<String>,
+    #[serde(default)]
+    unaffected: Vec<String>,
+}
+
+impl AdvisoryDatabase {
+    /// Reads the advisories in the `crates` directory of the database
+    pub fn load(path: &Path) -> Result<Self, AdvisoryDatabaseError> {
+        let io_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |error| AdvisoryDatabaseError::Io { path, error }
+        };
+
+        let crates = path.join("crates");
+        let mut advisories = Vec::new();
+        for package in std::fs::read_dir(&crates).map_err(io_error(&crates))? {
+            let package = package.map_err(io_error(&crates))?.path();
+            if !package.is_dir() {
+                continue;
+            }
+            for file in std::fs::read_dir(&package).map_err(io_error(&package))? {
+                let file = file.map_err(io_error(&package))?.path();
+                if file.extension().map_or(true, |extension| extension != "md") {
+                    continue;
+                }
+                let content = std::fs::read_to_string(&file).map_err(io_error(&file))?;
+                match parse_advisory(&content) {
+                    Ok(Some(advisory)) => advisories.push(advisory),
+                    Ok(None) => (),
+                    Err(error) => log::warn!(
+                        "Skipping the invalid advisory {}: {}",
+                        file.display(),
+                        error
+                    ),
+                }
+            }
+        }
+        advisories.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(Self { advisories })
+    }
+
+    /// The identifiers of the unmaintained advisories affecting the version of the crate
+    pub fn unmaintained(&self, name: &str, version: &Version) -> Vec<&str> {
+        self.advisories
+            .iter()
+            .filter(|advisory| advisory.affects(name, version))
+            .map(|advisory| advisory.id.as_str())
+            .collect()
+    }
+}
+
+/// Parses the TOML front matter of an advisory, returns `None` for all but active
+/// advisories about unmaintained crates
+fn parse_advisory(content: &str) -> Result<Option<Advisory>, String> {
+    let front_matter = content
+        .trim_start()
+        .strip_prefix("```toml")
+        .and_then(|rest| rest.split_once("```"))
+        .map(|(front_matter, _)| front_matter)
+        .ok_or("Missing the TOML front matter")?;
+    let file: AdvisoryFile = toml::from_str(front_matter).map_err(|error| error.to_string())?;
+
+    if file.advisory.informational.as_deref() != Some("unmaintained")
+        || file.advisory.withdrawn.is_some()
+    {
+        return Ok(None);
+    }
+
+    let parse_requirements = |requirements: &[String]| {
+        requirements
+            .iter()
+            .map(|requirement| VersionReq::parse(requirement).map_err(|error| error.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    Ok(Some(Advisory {
+        id: file.advisory.id,
+        package: file.advisory.package,
+        patched: parse_requirements(&file.versions.patched)?,
+        unaffected: parse_requirements(&file.versions.unaffected)?,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    #[derive(Default)]
+    struct FixedTransport {
+        status: u16,
+        body: String,
+        requests: std::sync::atomic::AtomicUsize,
+    }
+
+    impl Transport for FixedTransport {
+        fn send(&self, request: &Request) -> std::io::Result<cyclonedx_bom::client::Response> {
+            self.requests
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            assert_eq!(request.url, "https://index.crates.io/3/f/foo");
+            match self.status {
+                0 => Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "Could not resolve host",
+                )),
+                status => Ok(cyclonedx_bom::client::Response {
+                    status,
+                    body: self.body.clone().into_bytes(),
+                }),
+            }
+        }
+    }
+
+    fn entry(version: &str, yanked: bool) -> String {
+        format!(
+            r#"{{"name":"foo","vers":"{version}","deps":[],"cksum":"{}","features":{{}},"yanked":{yanked}}}"#,
+            "0".repeat(64)
+        )
+    }
+
+    /// The lines of the index file of `foo`
+    fn index_entry(versions: &[(&str, bool)]) -> String {
+        versions
+            .iter()
+            .map(|(version, yanked)| entry(version, *yanked))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The file of `foo` in Cargo's index cache
+    fn cache_entry(versions: &[(&str, bool)]) -> Vec<u8> {
+        let mut content = vec![3, 2, 0, 0, 0];
+        content.extend_from_slice(b"Unknown\0");
+        for (version, yanked) in versions {
+            content.extend_from_slice(version.as_bytes());
+            content.push(0);
+            content.extend_from_slice(entry(version, *yanked).as_bytes());
+            content.push(0);
+        }
+        content
+    }
+
+    /// An index with the cache entry of `foo` in a temporary directory
+    fn registry_index(
+        cached: &[(&str, bool)],
+        transport: FixedTransport,
+        offline: bool,
+    ) -> (assert_fs::TempDir, RegistryIndex<FixedTransport>) {
+        let directory = assert_fs::TempDir::new().unwrap();
+        let index = SparseIndex::at_path(
+            directory.path().to_path_buf(),
+            crates_index::sparse::URL.to_owned(),
+        );
+        directory
+            .child(".cache/3/f/foo")
+            .write_binary(&cache_entry(cached))
+            .unwrap();
+        (
+            directory,
+            RegistryIndex::with_index(index, transport, offline),
+        )
+    }
+
+    #[test]
+    fn it_should_find_yanked_versions_in_the_index() {
+        let transport = FixedTransport {
+            status: 200,
+            body: index_entry(&[("0.1.0", true), ("0.2.0", true)]),
+            ..FixedTransport::default()
+        };
+        let (_directory, index) = registry_index(&[("0.1.0", false)], transport, false);
+
+        assert_eq!(index.is_yanked("foo", "0.1.0"), Some(true));
+        assert_eq!(index.is_yanked("foo", "0.2.0"), Some(true));
+        assert_eq!(index.is_yanked("foo", "0.3.0"), None);
+        assert_eq!(
+            index
+                .transport
+                .requests
+                .load(std::sync::atomic::Ordering::Relaxed),
+            1
+        );
+    }
+
+    #[test]
+    fn it_should_fall_back_to_the_index_cache() {
+        let cached = [("0.1.0", true), ("0.2.0", false)];
+
+        let unreachable = FixedTransport::default();
+        let (_directory, index) = registry_index(&cached, unreachable, false);
+        assert_eq!(index.is_yanked("foo", "0.1.0"), Some(true));
+        assert_eq!(index.is_yanked("foo", "0.2.0"), Some(false));
+        assert_eq!(index.is_yanked("foo", "0.3.0"), None);
+
+        let failing = FixedTransport {
+            status: 503,
+            ..FixedTransport::default()
+        };
+        let (_directory, index) = registry_index(&cached, failing, false);
+        assert_eq!(index.is_yanked("foo", "0.1.0"), Some(true));
+    }
+
+    #[test]
+    fn it_should_only_read_the_index_cache_offline() {
+        let transport = FixedTransport {
+            status: 200,
+            body: index_entry(&[("0.1.0", false)]),
+            ..FixedTransport::default()
+        };
+        let (_directory, index) = registry_index(&[("0.1.0", true)], transport, true);
+
+        assert_eq!(index.is_yanked("foo", "0.1.0"), Some(true));
+        assert_eq!(index.is_yanked("bar", "0.1.0"), None);
+    }
+
+    #[test]
+    fn it_should_read_unmaintained_advisories() {
+        let database = assert_fs::TempDir::new().unwrap();
+        database
+            .child("crates/foo/RUSTSEC-2020-0001.md")
+            .write_str(
+                r#"```toml
+[advisory]
+id = "RUSTSEC-2020-0001"
+package = "foo"
+date = "2020-01-01"
+informational = "unmaintained"
+
+[versions]
+patched = [">= 2.0.0"]
+```
+
+# foo is unmaintained
+"#,
+            )
+            .unwrap();
+        database
+            .child("crates/foo/RUSTSEC-2021-0001.md")
+            .write_str(
+                r#"```toml
+[advisory]
+id = "RUSTSEC-2021-0001"
+package = "foo"
+date = "2021-01-01"
+
+[versions]
+patched = []
+```
+"#,
+            )
+            .unwrap();
+
+        let database = AdvisoryDatabase::load(database.path()).unwrap();
+
+        assert_eq!(
+            database.unmaintained("foo", &Version::new(1, 0, 0)),
+            ["RUSTSEC-2020-0001"]
+        );
+        assert!(database
+            .unmaintained("foo", &Version::new(2, 0, 0))
+            .is_empty());
+        assert!(database
+            .unmaintained("bar", &Version::new(1, 0, 0))
+            .is_empty());
+        assert!(AdvisoryDatabase::load(Path::new("/nonexistent/advisory-db")).is_err());
+    }
+}
@@ -29,3 +29,68 @@ pub fn rustc_host_target_triple(rustc_path: &OsStr) -> String {
         .map(|l| l[6..].to_string())
         .expect("Failed to parse rustc output to determine the current platform. Please report this bug!")
 }
+
+/// The toolchain information printed by `rustc -vV`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustcVersion {
+    /// The release, e.g. `1.80.0` or `1.81.0-nightly`
+    pub release: String,
+    pub commit_hash: Option<String>,
+    /// The host target triple, e.g. `x86_64-unknown-linux-gnu`
+    pub host: String,
+}
+
+impl RustcVersion {
+    /// Parses the output of `rustc -vV`
+    pub fn parse(output: &str) -> Option<Self> {
+        let field = |name: &str| {
+            output
+                .lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix(": "))
+                .map(|value| value.trim().to_string())
+        };
+
+        Some(Self {
+            release: field("release")?,
+            commit_hash: field("commit-hash").filter(|hash| hash != "unknown"),
+            host: field("host")?,
+        })
+    }
+}
+
+/// Returns the toolchain information of the `rustc` at the given path,
+/// or `None` if it could not be determined.
+pub fn rustc_version(rustc_path: &OsStr) -> Option<RustcVersion> {
+    let output = Command::new(rustc_path).arg("-vV").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    RustcVersion::parse(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_the_rustc_version() {
+        let output = "rustc 1.80.0 (051478957 2024-07-21)
+binary: rustc
+commit-hash: 051478957371ee0084a7c0913941d2a8c4757bb9
+commit-date: 2024-07-21
+host: x86_64-unknown-linux-gnu
+release: 1.80.0
+LLVM version: 18.1.7
+";
+
+        assert_eq!(
+            RustcVersion::parse(output),
+            Some(RustcVersion {
+                release: "1.80.0".to_string(),
+                commit_hash: Some("051478957371ee0084a7c0913941d2a8c4757bb9".to_string()),
+                host: "x86_64-unknown-linux-gnu".to_string(),
+            })
+        );
+        assert_eq!(RustcVersion::parse("rustc 1.80.0"), None);
+    }
+}
@@ -0,0 +1,321 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cyclonedx_bom::external_models::spdx::SpdxExpression;
+use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::license::{LicenseChoice, LicenseIdentifier};
+
+use crate::config::LicensePolicy;
+
+/// A dependency with a license that violates the [`LicensePolicy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseViolation {
+    /// The name and version of the dependency
+    pub component: String,
+    pub kind: LicenseViolationKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LicenseViolationKind {
+    /// The license requires one of the denied licenses
+    Denied(String),
+    /// The license cannot be satisfied with the allowed licenses
+    NotAllowed(String),
+    /// The dependency has no license, while only allowed licenses are accepted
+    Missing,
+    /// The license expression cannot be parsed, so it cannot be checked against the policy
+    Unparseable(String),
+}
+
+impl std::fmt::Display for LicenseViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            LicenseViolationKind::Denied(license) => {
+                write!(f, "{}: the license `{}` is denied", self.component, license)
+            }
+            LicenseViolationKind::NotAllowed(license) => {
+                write!(
+                    f,
+                    "{}: the license `{}` is not allowed",
+                    self.component, license
+                )
+            }
+            LicenseViolationKind::Missing => write!(f, "{}: no license found", self.component),
+            LicenseViolationKind::Unparseable(license) => {
+                write!(
+                    f,
+                    "{}: the license `{}` cannot be parsed",
+                    self.component, license
+                )
+            }
+        }
+    }
+}
+
+/// Checks the licenses of all dependencies of the BOM, including nested components, against the policy
+///
+/// The licenses of a dependency with several licenses all have to conform to the policy.
+/// The metadata component and its nested components describe the package itself and are not checked.
+pub fn check_licenses(bom: &Bom, policy: &LicensePolicy) -> Vec<LicenseViolation> {
+    let deny_licenses = expand_licenses(&policy.deny_licenses);
+    let root_components = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref())
+        .map_or(0, count_components);
+
+    bom.iter_components_recursive()
+        .skip(root_components)
+        .flat_map(|component| check_component(component, &policy.allow_licenses, &deny_licenses))
+        .collect()
+}
+
+/// Counts the component and all of its nested components
+fn count_components(component: &Component) -> usize {
+    1 + component
+        .components
+        .iter()
+        .flat_map(|components| components.0.iter())
+        .map(count_components)
+        .sum::<usize>()
+}
+
+fn check_component(
+    component: &Component,
+    allow_licenses: &[String],
+    deny_licenses: &[String],
+) -> Vec<LicenseViolation> {
+    let name = match &component.version {
+        Some(version) => format!("{} {}", component.name, version),
+        None => component.name.to_string(),
+    };
+    let violation = |kind| LicenseViolation {
+        component: name.clone(),
+        kind,
+    };
+
+    let licenses = component
+        .licenses
+        .iter()
+        .flat_map(|licenses| licenses.0.iter())
+        .collect::<Vec<_>>();
+    if licenses.is_empty() {
+        return match allow_licenses.is_empty() {
+            true => vec![],
+            false => vec![violation(LicenseViolationKind::Missing)],
+        };
+    }
+
+    let mut violations = vec![];
+    for license in licenses {
+        let (license, denied, allowed) = match license {
+            LicenseChoice::Expression(expression) => match (
+                expression.requires_any(deny_licenses),
+                expression.satisfies(allow_licenses),
+            ) {
+                (Ok(denied), Ok(allowed)) => (expression.to_string(), denied, allowed),
+                _ => {
+                    if !allow_licenses.is_empty() || !deny_licenses.is_empty() {
+                        violations.push(violation(LicenseViolationKind::Unparseable(
+                            expression.to_string(),
+                        )));
+                    }
+                    continue;
+                }
+            },
+            LicenseChoice::License(license) => {
+                let name = match &license.license_identifier {
+                    LicenseIdentifier::SpdxId(id) => id.to_string(),
+                    LicenseIdentifier::Name(name) => name.to_string(),
+                };
+                let expression = SpdxExpression::new(&name);
+                (
+                    name.clone(),
+                    expression
+                        .requires_any(deny_licenses)
+                        .unwrap_or_else(|_| deny_licenses.contains(&name)),
+                    expression
+                        .satisfies(allow_licenses)
+                        .unwrap_or_else(|_| allow_licenses.contains(&name)),
+                )
+            }
+        };
+
+        if denied {
+            violations.push(violation(LicenseViolationKind::Denied(license)));
+        } else if !allow_licenses.is_empty() && !allowed {
+            violations.push(violation(LicenseViolationKind::NotAllowed(license)));
+        }
+    }
+    violations
+}
+
+/// Replaces the denied expressions with the licenses they reference, so that each of them is denied.
+fn expand_licenses(licenses: &[String]) -> Vec<String> {
+    licenses
+        .iter()
+        .flat_map(|license| match SpdxExpression::new(license).license_ids() {
+            Ok(license_ids) if license_ids.len() > 1 => license_ids,
+            _ => vec![license.clone()],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use cyclonedx_bom::models::component::{Classification, Components};
+    use cyclonedx_bom::models::license::{License, Licenses};
+    use cyclonedx_bom::models::metadata::Metadata;
+
+    fn component(name: &str, licenses: Vec<LicenseChoice>) -> Component {
+        let mut component = Component::new(Classification::Library, name, "1.0.0", None);
+        if !licenses.is_empty() {
+            component.licenses = Some(Licenses(licenses));
+        }
+        component
+    }
+
+    fn example_bom() -> Bom {
+        Bom {
+            components: Some(Components(vec![
+                component(
+                    "dual",
+                    vec![LicenseChoice::Expression(SpdxExpression::new(
+                        "MIT OR Apache-2.0",
+                    ))],
+                ),
+                component(
+                    "copyleft",
+                    vec![LicenseChoice::Expression(SpdxExpression::new(
+                        "GPL-3.0-only",
+                    ))],
+                ),
+                component(
+                    "named",
+                    vec![LicenseChoice::License(License::named_license(
+                        "Proprietary License",
+                    ))],
+                ),
+                component("unlicensed", vec![]),
+            ])),
+            ..Bom::default()
+        }
+    }
+
+    fn check_bom(bom: &Bom, allow_licenses: &[&str], deny_licenses: &[&str]) -> Vec<String> {
+        let policy = LicensePolicy {
+            allow_licenses: allow_licenses.iter().map(|l| l.to_string()).collect(),
+            deny_licenses: deny_licenses.iter().map(|l| l.to_string()).collect(),
+        };
+        check_licenses(bom, &policy)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn check(allow_licenses: &[&str], deny_licenses: &[&str]) -> Vec<String> {
+        check_bom(&example_bom(), allow_licenses, deny_licenses)
+    }
+
+    #[test]
+    fn it_should_accept_all_licenses_without_a_policy() {
+        assert!(check(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn it_should_report_denied_licenses() {
+        assert_eq!(
+            check(&[], &["GPL-3.0-only OR Apache-2.0", "Proprietary License"]),
+            vec![
+                "copyleft 1.0.0: the license `GPL-3.0-only` is denied",
+                "named 1.0.0: the license `Proprietary License` is denied",
+            ]
+        );
+        assert_eq!(
+            check(&[], &["MIT", "Apache-2.0"]),
+            vec!["dual 1.0.0: the license `MIT OR Apache-2.0` is denied"]
+        );
+    }
+
+    #[test]
+    fn it_should_report_licenses_which_are_not_allowed() {
+        assert_eq!(
+            check(&["Apache-2.0", "Proprietary License"], &[]),
+            vec![
+                "copyleft 1.0.0: the license `GPL-3.0-only` is not allowed",
+                "unlicensed 1.0.0: no license found",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_report_unparseable_license_expressions() {
+        let bom = Bom {
+            components: Some(Components(vec![component(
+                "broken",
+                vec![LicenseChoice::Expression(SpdxExpression::new("MIT OR"))],
+            )])),
+            ..Bom::default()
+        };
+
+        assert!(check_bom(&bom, &[], &[]).is_empty());
+        assert_eq!(
+            check_bom(&bom, &[], &["GPL-3.0-only"]),
+            vec!["broken 1.0.0: the license `MIT OR` cannot be parsed"]
+        );
+        assert_eq!(
+            check_bom(&bom, &["MIT"], &[]),
+            vec!["broken 1.0.0: the license `MIT OR` cannot be parsed"]
+        );
+    }
+
+    #[test]
+    fn it_should_check_nested_components() {
+        let mut parent = component("parent", vec![]);
+        parent.components = Some(Components(vec![component(
+            "nested",
+            vec![LicenseChoice::Expression(SpdxExpression::new(
+                "GPL-3.0-only",
+            ))],
+        )]));
+        let mut root = component("root", vec![]);
+        root.components = Some(Components(vec![component("target", vec![])]));
+        let bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(root),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![parent])),
+            ..Bom::default()
+        };
+
+        assert_eq!(
+            check_bom(&bom, &[], &["GPL-3.0-only"]),
+            vec!["nested 1.0.0: the license `GPL-3.0-only` is denied"]
+        );
+        assert_eq!(
+            check_bom(&bom, &["MIT"], &[]),
+            vec![
+                "parent 1.0.0: no license found",
+                "nested 1.0.0: the license `GPL-3.0-only` is not allowed",
+            ]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! A machine-readable report of what the SBOMs left out or had to guess,
+//! for pipelines to enforce quality gates on their completeness.
+
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct GenerationReport {
+    pub packages: Vec<PackageReport>,
+    /// The warnings logged while generating the SBOMs
+    pub warnings: Vec<String>,
+}
+
+/// What happened while creating the SBOM of a workspace member
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PackageReport {
+    pub package: String,
+    /// Dependencies left out of the SBOM
+    pub skipped: Vec<SkippedCrate>,
+    /// Licenses not recorded as declared
+    pub licenses: Vec<LicenseDecision>,
+    /// The `bom-ref`s of registry packages without a hash in `Cargo.lock`
+    pub missing_hashes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedCrate {
+    pub name: String,
+    pub version: String,
+    pub reason: SkipReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SkipReason {
+    /// Left out by `--exclude`, `--exclude-path` or `--include-only`
+    Excluded,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LicenseDecision {
+    pub name: String,
+    pub version: String,
+    /// The `license` field of the manifest
+    pub declared: Option<String>,
+    /// The license recorded in the SBOM
+    pub recorded: Option<String>,
+    pub decision: LicenseDecisionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LicenseDecisionKind {
+    /// The declared expression was rewritten into a valid SPDX expression, e.g. `MIT/Apache-2.0`
+    Normalized,
+    /// The declared expression is not valid SPDX and was recorded as a named license
+    Named,
+    /// A license override replaced the declared license
+    Override,
+    /// Neither a license nor a license file is declared
+    Missing,
+}
+
+impl GenerationReport {
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)
+    }
+}
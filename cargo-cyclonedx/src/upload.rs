@@ -0,0 +1,417 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Uploads SBOMs to [Dependency-Track](https://dependencytrack.org) or a server implementing its BOM API,
+//! or publishes them to a BOM repository server with the [`BomExchangeClient`].
+//!
+//! The requests are sent by the [`CurlTransport`] of `cyclonedx-bom`, which runs `curl` to handle
+//! TLS with the system's certificate store.
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use cyclonedx_bom::client::{BomExchangeClient, CurlTransport, Method, Request, Transport};
+use cyclonedx_bom::errors::{ClientError, ParseError};
+use cyclonedx_bom::models::bom::Bom;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+/// The API of the server the SBOMs are uploaded to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum UploadApi {
+    /// The BOM API of Dependency-Track, submitting to `<url>/api/v1/bom` (default)
+    #[default]
+    DependencyTrack,
+    /// The BOM Exchange API of a BOM repository server, publishing to `<url>/v1/bom`
+    BomExchange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadOptions {
+    pub api: UploadApi,
+    /// The base URL of the server
+    pub url: String,
+    /// Sent as the `X-Api-Key` header, left out if empty
+    pub api_key: String,
+    /// The project to upload to, defaults to the name of the described component
+    pub project_name: Option<String>,
+    /// The project version, defaults to the version of the described component
+    pub project_version: Option<String>,
+    /// How often to retry after a connection error or a server error response
+    pub retries: u32,
+    /// Accept invalid TLS certificates
+    pub insecure: bool,
+    /// A PEM file with the certificates to trust instead of the system's
+    pub ca_cert: Option<PathBuf>,
+}
+
+#[derive(Error, Debug)]
+pub enum UploadError {
+    #[error("Failed to read {} to upload it", path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("Failed to upload {}", path.display())]
+    Upload {
+        path: PathBuf,
+        #[source]
+        error: ClientError,
+    },
+
+    #[error("Failed to parse {} to publish it", path.display())]
+    Parse {
+        path: PathBuf,
+        #[source]
+        error: ParseError,
+    },
+
+    #[error("Failed to publish {}", path.display())]
+    Publish {
+        path: PathBuf,
+        #[source]
+        error: ClientError,
+    },
+}
+
+/// Uploads the SBOM file to the project, creating the project if it does not exist yet
+///
+/// BOM repository servers identify BOMs by their serial number and version, so the project is
+/// only used by Dependency-Track.
+pub fn upload_bom(
+    path: &Path,
+    project_name: &str,
+    project_version: &str,
+    options: &UploadOptions,
+) -> Result<(), UploadError> {
+    let transport = CurlTransport {
+        insecure: options.insecure,
+        ca_cert: options.ca_cert.clone(),
+    };
+    match options.api {
+        UploadApi::DependencyTrack => {
+            submit_bom(&transport, path, project_name, project_version, options)
+        }
+        UploadApi::BomExchange => publish_bom(&transport, path, options),
+    }
+}
+
+fn submit_bom(
+    transport: &impl Transport,
+    path: &Path,
+    project_name: &str,
+    project_version: &str,
+    options: &UploadOptions,
+) -> Result<(), UploadError> {
+    let input = read(path)?;
+    let request = bom_request(&input, project_name, project_version, options);
+    with_retries(path, options.retries, || send(transport, &request)).map_err(|error| {
+        UploadError::Upload {
+            path: path.to_path_buf(),
+            error,
+        }
+    })?;
+    log::info!(
+        "Uploaded {} to the project {} {}",
+        path.display(),
+        project_name,
+        project_version
+    );
+    Ok(())
+}
+
+fn publish_bom(
+    transport: &impl Transport,
+    path: &Path,
+    options: &UploadOptions,
+) -> Result<(), UploadError> {
+    let input = read(path)?;
+    let (bom, version) = Bom::parse_auto(input).map_err(|error| UploadError::Parse {
+        path: path.to_path_buf(),
+        error,
+    })?;
+
+    let mut client = BomExchangeClient::with_transport(&options.url, transport);
+    if !options.api_key.is_empty() {
+        client = client.with_header("X-Api-Key", &options.api_key);
+    }
+
+    with_retries(path, options.retries, || client.publish(&bom, version)).map_err(|error| {
+        UploadError::Publish {
+            path: path.to_path_buf(),
+            error,
+        }
+    })?;
+    log::info!("Published {} to {}", path.display(), options.url);
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, UploadError> {
+    std::fs::read(path).map_err(|error| UploadError::Read {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Repeats the request after connection errors and server errors, waiting longer each time
+fn with_retries(
+    path: &Path,
+    retries: u32,
+    mut request: impl FnMut() -> Result<(), ClientError>,
+) -> Result<(), ClientError> {
+    let mut attempt = 0;
+    loop {
+        let error = match request() {
+            Ok(()) => return Ok(()),
+            Err(error) => error,
+        };
+
+        if !error.is_retryable() || attempt >= retries {
+            return Err(error);
+        }
+        attempt += 1;
+        let delay = Duration::from_secs(1 << attempt.min(5));
+        log::warn!(
+            "Uploading {} failed: {}, retrying in {}s ({}/{})",
+            path.display(),
+            error,
+            delay.as_secs(),
+            attempt,
+            retries
+        );
+        std::thread::sleep(delay);
+    }
+}
+
+/// The `PUT <url>/api/v1/bom` request of Dependency-Track, with the SBOM encoded as Base64
+fn bom_request(
+    bom: &[u8],
+    project_name: &str,
+    project_version: &str,
+    options: &UploadOptions,
+) -> Request {
+    let mut headers = vec![("Content-Type".to_owned(), "application/json".to_owned())];
+    if !options.api_key.is_empty() {
+        headers.push(("X-Api-Key".to_owned(), options.api_key.clone()));
+    }
+    let body = serde_json::json!({
+        "projectName": project_name,
+        "projectVersion": project_version,
+        "autoCreate": true,
+        "bom": BASE64_STANDARD.encode(bom),
+    });
+    Request {
+        method: Method::Put,
+        url: format!("{}/api/v1/bom", options.url.trim_end_matches('/')),
+        headers,
+        body: Some(body.to_string()),
+    }
+}
+
+fn send(transport: &impl Transport, request: &Request) -> Result<(), ClientError> {
+    let response = transport
+        .send(request)
+        .map_err(|error| ClientError::Transport {
+            url: request.url.clone(),
+            error,
+        })?;
+    if (200..300).contains(&response.status) {
+        Ok(())
+    } else {
+        Err(ClientError::Status {
+            url: request.url.clone(),
+            status: response.status,
+            body: String::from_utf8_lossy(&response.body).trim().to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+    use cyclonedx_bom::client::Response;
+    use std::cell::RefCell;
+
+    /// Records the requests and answers them with the queued responses
+    #[derive(Default)]
+    struct RecordingTransport {
+        requests: RefCell<Vec<Request>>,
+        responses: RefCell<Vec<std::io::Result<Response>>>,
+    }
+
+    impl Transport for RecordingTransport {
+        fn send(&self, request: &Request) -> std::io::Result<Response> {
+            self.requests.borrow_mut().push(request.clone());
+            self.responses.borrow_mut().remove(0)
+        }
+    }
+
+    fn transport(responses: Vec<std::io::Result<Response>>) -> RecordingTransport {
+        RecordingTransport {
+            responses: RefCell::new(responses),
+            ..RecordingTransport::default()
+        }
+    }
+
+    fn response(status: u16, body: &str) -> std::io::Result<Response> {
+        Ok(Response {
+            status,
+            body: body.as_bytes().to_vec(),
+        })
+    }
+
+    fn options() -> UploadOptions {
+        UploadOptions {
+            api: UploadApi::DependencyTrack,
+            url: "https://dtrack.example.com/".to_owned(),
+            api_key: "odt_secret".to_owned(),
+            project_name: None,
+            project_version: None,
+            retries: 0,
+            insecure: false,
+            ca_cert: None,
+        }
+    }
+
+    #[test]
+    fn it_should_submit_the_bom_to_dependency_track() {
+        let file = assert_fs::NamedTempFile::new("my-crate.cdx.json").unwrap();
+        file.write_str(r#"{"bomFormat": "CycloneDX"}"#).unwrap();
+        let transport = transport(vec![response(200, r#"{"token": "abc"}"#)]);
+
+        submit_bom(&transport, file.path(), "my-crate", "1.0.0", &options())
+            .expect("Failed to upload");
+
+        let request = transport.requests.borrow()[0].clone();
+        assert_eq!(request.method, Method::Put);
+        assert_eq!(request.url, "https://dtrack.example.com/api/v1/bom");
+        assert_eq!(
+            request.headers,
+            vec![
+                ("Content-Type".to_owned(), "application/json".to_owned()),
+                ("X-Api-Key".to_owned(), "odt_secret".to_owned()),
+            ]
+        );
+        let body: serde_json::Value = serde_json::from_str(&request.body.unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "projectName": "my-crate",
+                "projectVersion": "1.0.0",
+                "autoCreate": true,
+                "bom": BASE64_STANDARD.encode(r#"{"bomFormat": "CycloneDX"}"#),
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_escape_line_breaks_in_the_project() {
+        let file = assert_fs::NamedTempFile::new("my-crate.cdx.json").unwrap();
+        file.write_str("{}").unwrap();
+        let transport = transport(vec![response(200, "")]);
+
+        submit_bom(
+            &transport,
+            file.path(),
+            "my-crate\nX-Api-Key: other",
+            "1.0.0\r\n",
+            &options(),
+        )
+        .expect("Failed to upload");
+
+        let request = transport.requests.borrow()[0].clone();
+        let body = request.body.unwrap();
+        assert!(!body.contains('\n') && !body.contains('\r'));
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["projectName"], "my-crate\nX-Api-Key: other");
+        assert_eq!(body["projectVersion"], "1.0.0\r\n");
+        assert_eq!(request.headers.len(), 2);
+    }
+
+    #[test]
+    fn it_should_report_failed_uploads() {
+        let file = assert_fs::NamedTempFile::new("my-crate.cdx.json").unwrap();
+        file.write_str("{}").unwrap();
+        let transport = transport(vec![response(401, "Unauthorized\n")]);
+
+        let error = submit_bom(&transport, file.path(), "my-crate", "1.0.0", &options())
+            .expect_err("Should have failed");
+
+        assert!(matches!(
+            error,
+            UploadError::Upload {
+                error: ClientError::Status { status: 401, ref body, .. },
+                ..
+            } if body == "Unauthorized"
+        ));
+    }
+
+    #[test]
+    fn it_should_publish_the_bom_to_a_bom_repository() {
+        let file = assert_fs::NamedTempFile::new("my-crate.cdx.xml").unwrap();
+        file.write_str(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="1" />"#,
+        )
+        .unwrap();
+        let transport = transport(vec![response(201, "")]);
+        let options = UploadOptions {
+            api: UploadApi::BomExchange,
+            url: "https://bom-repository.example.com".to_owned(),
+            api_key: String::new(),
+            ..options()
+        };
+
+        publish_bom(&transport, file.path(), &options).expect("Failed to publish");
+
+        let request = transport.requests.borrow()[0].clone();
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "https://bom-repository.example.com/v1/bom");
+        assert_eq!(
+            request.headers,
+            vec![(
+                "Content-Type".to_owned(),
+                "application/vnd.cyclonedx+json; version=1.4".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn it_should_retry_only_transient_failures() {
+        let failures = RefCell::new(vec![
+            ClientError::Status {
+                url: String::new(),
+                status: 401,
+                body: String::new(),
+            },
+            ClientError::Transport {
+                url: String::new(),
+                error: std::io::Error::new(std::io::ErrorKind::Other, "Connection refused"),
+            },
+        ]);
+
+        let error = with_retries(Path::new("my-crate.cdx.json"), 1, || {
+            Err(failures.borrow_mut().pop().unwrap())
+        })
+        .expect_err("Should have failed");
+
+        assert!(matches!(error, ClientError::Status { status: 401, .. }));
+        assert!(failures.borrow().is_empty());
+    }
+}
@@ -75,6 +75,86 @@ fn find_content_in_bom_files() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn write_multiple_formats() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--top-level")
+        .arg("--output-format=json,xml");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::str::contains("<vendor>CycloneDX</vendor>"));
+    tmp_dir
+        .child("pkg.cdx.json")
+        .assert(predicate::str::contains(r#""vendor": "CycloneDX"#));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_to_output_file_pattern() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    let out_dir = assert_fs::TempDir::new()?;
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--top-level")
+        .arg("--format=json,xml")
+        .arg("--output-dir")
+        .arg(out_dir.path())
+        .arg("--output-file=sboms/{crate}_{version}.cdx.{ext}");
+
+    cmd.assert().success().stdout("");
+
+    out_dir
+        .child("sboms/pkg_0.0.0.cdx.json")
+        .assert(predicate::str::contains(r#""vendor": "CycloneDX"#));
+    out_dir
+        .child("sboms/pkg_0.0.0.cdx.xml")
+        .assert(predicate::str::contains("<vendor>CycloneDX</vendor>"));
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::path::missing());
+
+    out_dir.close()?;
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_to_stdout() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--top-level")
+        .arg("--format=json")
+        .arg("--output-file=-");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(r#""vendor": "CycloneDX"#));
+
+    tmp_dir
+        .child("pkg.cdx.json")
+        .assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 #[test]
 fn find_content_in_stderr() -> Result<(), Box<dyn std::error::Error>> {
     let tmp_dir = make_temp_rust_project()?;
@@ -133,6 +213,678 @@ fn find_content_in_stderr() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn fail_for_licenses_violating_the_license_policy() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let pkg_name = "nested-pkg";
+    let root_manifest = format!(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies.{0}]
+        path = "{0}"
+        "#,
+        pkg_name,
+    );
+    tmp_dir.child("Cargo.toml").write_str(&root_manifest)?;
+
+    let pkg_dir = tmp_dir.child(pkg_name);
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir.child("Cargo.toml").write_str(&format!(
+        r#"
+        [package]
+        name = "{}"
+        version = "0.0.0"
+        license = "MIT AND GPL-3.0-only"
+        "#,
+        pkg_name,
+    ))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--deny-license=GPL-3.0-only");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "test: {} 0.0.0: the license `MIT AND GPL-3.0-only` is denied",
+            pkg_name
+        )));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--deny-license=AGPL-3.0-only");
+
+    cmd.assert().success();
+
+    tmp_dir.child("Cargo.toml").write_str(&format!(
+        r#"{}
+        [package.metadata.cyclonedx]
+        allow-licenses = ["MIT"]
+        "#,
+        root_manifest,
+    ))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "test: {} 0.0.0: the license `MIT AND GPL-3.0-only` is not allowed",
+            pkg_name
+        )));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn read_the_configuration_from_package_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+        license = "GPL-3.0-only"
+
+        [dependencies]
+        direct = { path = "direct" }
+
+        [build-dependencies]
+        build-helper = { path = "build-helper" }
+
+        [package.metadata.cyclonedx]
+        format = "json"
+        output-dir = "sboms"
+        output-file = "{crate}_{version}.cdx.{ext}"
+        deny-licenses = ["GPL-3.0-only"]
+        license-overrides = { "pkg@0.0.0" = "MIT" }
+        properties = { "acme:team" = "platform" }
+        included-dependencies = "top-level"
+        no-build-deps = true
+        "#,
+    )?;
+    tmp_dir.child("direct/src/lib.rs").touch()?;
+    tmp_dir.child("direct/Cargo.toml").write_str(
+        r#"
+        package = { name = "direct", version = "0.0.0" }
+        dependencies = { indirect = { path = "../indirect" } }
+        "#,
+    )?;
+    for name in ["indirect", "build-helper"] {
+        tmp_dir.child(format!("{name}/src/lib.rs")).touch()?;
+        tmp_dir
+            .child(format!("{name}/Cargo.toml"))
+            .write_str(&format!(
+                r#"package = {{ name = "{name}", version = "0.0.0" }}"#
+            ))?;
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("sboms/pkg_0.0.0.cdx.json")
+        .assert(predicate::str::contains(r#""name": "acme:team""#))
+        .assert(predicate::str::contains(r#""expression": "MIT""#))
+        .assert(predicate::str::contains(r#""name": "direct""#))
+        .assert(predicate::str::contains("indirect").not())
+        .assert(predicate::str::contains("build-helper").not());
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn override_licenses_from_a_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let pkg_name = "nested-pkg";
+    tmp_dir.child("Cargo.toml").write_str(&format!(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies.{0}]
+        path = "{0}"
+        "#,
+        pkg_name,
+    ))?;
+    let pkg_dir = tmp_dir.child(pkg_name);
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir.child("Cargo.toml").write_str(&format!(
+        r#"
+        [package]
+        name = "{}"
+        version = "0.0.0"
+        license = "GPL-3.0-only"
+        "#,
+        pkg_name,
+    ))?;
+    tmp_dir.child("license-overrides.toml").write_str(&format!(
+        r#""{}@0.0.0" = {{ license = "MIT", reason = "Relicensed in the repository" }}"#,
+        pkg_name,
+    ))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--deny-license=GPL-3.0-only")
+        .arg("--license-overrides=license-overrides.toml");
+
+    cmd.assert().success();
+
+    tmp_dir
+        .child("test.cdx.json")
+        .assert(predicate::str::contains(r#""expression": "MIT""#))
+        .assert(predicate::str::contains(
+            "cargo-cyclonedx:license_override:declared",
+        ))
+        .assert(predicate::str::contains(r#""value": "GPL-3.0-only""#))
+        .assert(predicate::str::contains("Relicensed in the repository"));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--license-overrides=missing.toml");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Failed to read the license overrides from missing.toml",
+    ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn nest_workspace_members_as_subcomponents() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+    tmp_dir
+        .child("Cargo.toml")
+        .write_str(r#"workspace = { members = ["app", "core"] }"#)?;
+    tmp_dir.child("app/src/main.rs").touch()?;
+    tmp_dir.child("app/Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "app"
+        version = "0.0.0"
+
+        [dependencies]
+        core = { path = "../core" }
+        "#,
+    )?;
+    tmp_dir.child("core/src/lib.rs").touch()?;
+    tmp_dir
+        .child("core/Cargo.toml")
+        .write_str(r#"package = { name = "core", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--workspace-members=subcomponents")
+        .arg("--root-classification=library");
+
+    cmd.assert().success();
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("app/app.cdx.json"))?)?;
+    let root = &bom["metadata"]["component"];
+    assert_eq!(root["type"], "library");
+    let subcomponents: Vec<_> = root["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|component| component["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(subcomponents, ["app", "core"]);
+    assert!(bom["components"]
+        .as_array()
+        .map_or(true, |components| components.is_empty()));
+
+    // Only the Cargo targets get their own SBOM
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--workspace-members=subcomponents")
+        .arg("--describe=binaries");
+
+    cmd.assert().success();
+
+    tmp_dir
+        .child("app/app_bin.cdx.json")
+        .assert(predicate::path::exists());
+    tmp_dir
+        .child("app/core_lib.cdx.json")
+        .assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn exclude_components_and_rewrite_their_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [dependencies]
+        secret = { path = "internal/secret" }
+        "#,
+    )?;
+    tmp_dir.child("internal/secret/src/lib.rs").touch()?;
+    tmp_dir.child("internal/secret/Cargo.toml").write_str(
+        r#"
+        package = { name = "secret", version = "0.0.0" }
+        dependencies = { public = { path = "../../public" } }
+        "#,
+    )?;
+    tmp_dir.child("public/src/lib.rs").touch()?;
+    tmp_dir
+        .child("public/Cargo.toml")
+        .write_str(r#"package = { name = "public", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--exclude-path=internal/*");
+
+    cmd.assert().success();
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("pkg.cdx.json"))?)?;
+    let components: Vec<_> = bom["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|component| component["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(components, ["public"]);
+    let dependencies = bom["dependencies"].as_array().unwrap();
+    let root_dependencies = dependencies
+        .iter()
+        .find(|dependency| dependency["ref"].as_str().unwrap().contains("pkg"))
+        .unwrap();
+    assert_eq!(root_dependencies["dependsOn"].as_array().unwrap().len(), 1);
+    assert!(root_dependencies["dependsOn"][0]
+        .as_str()
+        .unwrap()
+        .contains("public"));
+    assert!(!dependencies
+        .iter()
+        .any(|dependency| dependency.to_string().contains("secret")));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--include-only=secret");
+
+    cmd.assert().success();
+
+    tmp_dir
+        .child("pkg.cdx.json")
+        .assert(predicate::str::contains(r#""name": "secret""#))
+        .assert(predicate::str::contains(r#""name": "public""#).not());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn fail_for_an_outdated_lockfile_when_locked() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--offline")
+        .arg("--locked");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--locked"));
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::path::missing());
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--offline");
+
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--frozen");
+
+    cmd.assert().success();
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_an_attestation_wrapping_the_sbom() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--attestation")
+        .arg("--attestation-subject=Cargo.toml");
+
+    cmd.assert().success();
+
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::path::exists());
+    let statement = std::fs::read_to_string(tmp_dir.child("pkg.cdx.intoto.json").path())?;
+    let statement: serde_json::Value = serde_json::from_str(&statement)?;
+    assert_eq!(statement["_type"], "https://in-toto.io/Statement/v1");
+    assert_eq!(statement["predicateType"], "https://cyclonedx.org/bom");
+    assert_eq!(statement["subject"][0]["name"], "Cargo.toml");
+    assert_eq!(statement["predicate"]["bomFormat"], "CycloneDX");
+    assert_eq!(
+        statement["predicate"]["metadata"]["component"]["name"],
+        "pkg"
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn pipe_the_attestation_to_the_signer() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--attestation")
+        .arg("--attestation-subject=Cargo.toml")
+        .arg("--attestation-signer=grep -c predicateType");
+
+    cmd.assert().success();
+
+    tmp_dir.child("pkg.cdx.intoto.jsonl").assert("1\n");
+    tmp_dir
+        .child("pkg.cdx.intoto.json")
+        .assert(predicate::path::missing());
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--attestation")
+        .arg("--attestation-subject=Cargo.toml")
+        .arg("--attestation-signer=cat > /dev/null; exit 1");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("attestation"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn upload_the_sbom_with_curl() -> Result<(), Box<dyn std::error::Error>> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp_dir = make_temp_rust_project()?;
+    // A stand-in for curl that records its config and responds with the given status
+    let bin = tmp_dir.child("bin");
+    let curl = bin.child("curl");
+    curl.write_str(&format!(
+        "#!/bin/sh\ncat > {}\nprintf 'body\\n%s' \"$STATUS\"\n",
+        tmp_dir.child("curl-config").path().display()
+    ))?;
+    std::fs::set_permissions(curl.path(), std::fs::Permissions::from_mode(0o755))?;
+    let path = format!(
+        "{}:{}",
+        bin.path().display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env("PATH", &path)
+        .env("STATUS", "200")
+        .env("DTRACK_API_KEY", "odt_secret")
+        .arg("cyclonedx")
+        .arg("--upload")
+        .arg("--dtrack-url=https://dtrack.example.com/");
+
+    cmd.assert().success();
+
+    let config = std::fs::read_to_string(tmp_dir.child("curl-config").path())?;
+    assert!(config.contains(r#"url = "https://dtrack.example.com/api/v1/bom""#));
+    assert!(config.contains(r#"header = "X-Api-Key: odt_secret""#));
+    assert!(config.contains(r#"request = "PUT""#));
+    assert!(config.contains(r#"\"projectName\":\"pkg\""#));
+    assert!(config.contains(r#"\"projectVersion\":\"0.0.0\""#));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env("PATH", &path)
+        .env("STATUS", "401")
+        .env("DTRACK_API_KEY", "odt_secret")
+        .arg("cyclonedx")
+        .arg("--force")
+        .arg("--upload")
+        .arg("--dtrack-url=https://dtrack.example.com/");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("HTTP 401 body"));
+
+    std::fs::remove_file(tmp_dir.child("curl-config").path())?;
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        package = { name = "pkg", version = "0.0.0" }
+        dependencies = { dep = { path = "dep" } }
+        "#,
+    )?;
+    tmp_dir.child("dep/src/lib.rs").touch()?;
+    tmp_dir
+        .child("dep/Cargo.toml")
+        .write_str(r#"package = { name = "dep", version = "0.0.0", license = "GPL-3.0-only" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .env("PATH", &path)
+        .env("STATUS", "200")
+        .env("DTRACK_API_KEY", "odt_secret")
+        .arg("cyclonedx")
+        .arg("--deny-license=GPL-3.0-only")
+        .arg("--report=report.json")
+        .arg("--upload")
+        .arg("--dtrack-url=https://dtrack.example.com/");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("violations of the license policy"));
+    tmp_dir
+        .child("report.json")
+        .assert(predicate::path::exists());
+    tmp_dir
+        .child("curl-config")
+        .assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn generate_the_sbom_from_a_lockfile() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+    tmp_dir.child("Cargo.lock").write_str(
+        r#"version = 3
+
+[[package]]
+name = "my-app"
+version = "0.1.0"
+dependencies = [
+ "serde",
+]
+
+[[package]]
+name = "serde"
+version = "1.0.193"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "25dd9975e68d0cb5aa1120c288333fc98731bd1dd12f561e468ea4728c042b89"
+"#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--from-lockfile=Cargo.lock")
+        .arg("--format=json");
+
+    cmd.assert().success();
+
+    let bom = std::fs::read_to_string(tmp_dir.child("my-app.cdx.json").path())?;
+    let bom: serde_json::Value = serde_json::from_str(&bom)?;
+    assert_eq!(bom["metadata"]["component"]["name"], "my-app");
+    let serde = &bom["components"][0];
+    assert_eq!(serde["name"], "serde");
+    assert_eq!(serde["purl"], "pkg:cargo/serde@1.0.193");
+    assert_eq!(
+        serde["hashes"][0]["content"],
+        "25dd9975e68d0cb5aa1120c288333fc98731bd1dd12f561e468ea4728c042b89"
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn skip_the_generation_when_nothing_changed() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    let sbom = tmp_dir.child("pkg.cdx.xml");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+    cmd.assert().success();
+    sbom.assert(predicate::str::contains("<bom"));
+
+    // A stand-in to tell whether the SBOM was written again
+    sbom.write_str("stale")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+    cmd.assert().success();
+    sbom.assert("stale");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--force");
+    cmd.assert().success();
+    sbom.assert(predicate::str::contains("<bom"));
+
+    // Changing the configuration regenerates the SBOM
+    sbom.write_str("stale")?;
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--cpe");
+    cmd.assert().success();
+    sbom.assert(predicate::str::contains("<bom"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_a_report_of_the_generation() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--report")
+        .arg("report.json");
+    cmd.assert().success();
+
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("report.json"))?)?;
+    assert_eq!(report["packages"][0]["package"], "pkg");
+    assert_eq!(report["packages"][0]["licenses"][0]["decision"], "missing");
+    assert!(report["warnings"].is_array());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn describe_a_binary_without_its_sources() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+    // No `cargo auditable` data, so the crates are found in the panic messages
+    tmp_dir.child("app").write_binary(
+        b"\0/home/user/.cargo/registry/src/index.crates.io-6f17d22bba15001f/serde-1.0.193/src/de/mod.rs\0",
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("binary")
+        .arg("app")
+        .arg("--format")
+        .arg("json");
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("no `cargo auditable` data"));
+
+    tmp_dir
+        .child("app.cdx.json")
+        .assert(predicate::str::contains(
+            r#""purl": "pkg:cargo/serde@1.0.193""#,
+        ))
+        .assert(predicate::str::contains("panic-strings"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 fn make_temp_rust_project() -> Result<assert_fs::TempDir, assert_fs::fixture::FixtureError> {
     let tmp_dir = assert_fs::TempDir::new()?;
     tmp_dir.child("src/main.rs").touch()?;
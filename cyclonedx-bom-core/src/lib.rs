@@ -0,0 +1,350 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! A read-only core of the CycloneDX models with JSON parsing that only needs `alloc`, for
+//! environments without `std` such as embedded attestation verifiers that inspect BOM payloads.
+//!
+//! The models cover what identifies a BOM and its contents: the spec version, serial number and
+//! version of the BOM, the timestamp and component of its metadata, the components with their
+//! hashes and nested components, the dependencies and the vulnerabilities with the bom-refs they
+//! affect. Everything else in the document is skipped. Use the `cyclonedx-bom` crate for the
+//! complete models, XML, validation and output.
+//!
+//! ```
+//! let input = br#"{
+//!   "bomFormat": "CycloneDX",
+//!   "specVersion": "1.5",
+//!   "version": 1,
+//!   "components": [
+//!     {
+//!       "type": "library",
+//!       "bom-ref": "serde",
+//!       "name": "serde",
+//!       "version": "1.0.193",
+//!       "hashes": [{"alg": "SHA-256", "content": "25dd9975e68d0cb5aa1120c288333fc98731bd1dd12f561e468ea4728c042b89"}]
+//!     }
+//!   ]
+//! }"#;
+//!
+//! let bom = cyclonedx_bom_core::parse_json(input)?;
+//! assert_eq!(bom.spec_version, cyclonedx_bom_core::SpecVersion::V1_5);
+//! let serde = bom.component_by_ref("serde").expect("serde is a component");
+//! assert!(serde.hash("SHA-256").is_some());
+//! # Ok::<(), cyclonedx_bom_core::Error>(())
+//! ```
+
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use serde::Deserialize;
+
+/// Parses a JSON document of any supported spec version.
+///
+/// Fails if the document is not valid JSON, its `bomFormat` is not `CycloneDX` or its
+/// `specVersion` is not supported.
+pub fn parse_json(input: &[u8]) -> Result<Bom, Error> {
+    let document: Document = serde_json::from_slice(input).map_err(Error::Json)?;
+    if document.bom_format != "CycloneDX" {
+        return Err(Error::NotCycloneDx(document.bom_format));
+    }
+
+    Ok(Bom {
+        spec_version: document.spec_version.parse()?,
+        serial_number: document.serial_number,
+        version: document.version,
+        metadata: document.metadata,
+        components: document.components,
+        dependencies: document.dependencies,
+        vulnerabilities: document.vulnerabilities,
+    })
+}
+
+/// The versions of the CycloneDX specification that can be parsed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpecVersion {
+    V1_3,
+    V1_4,
+    V1_5,
+    V1_6,
+}
+
+impl FromStr for SpecVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1.3" => Ok(Self::V1_3),
+            "1.4" => Ok(Self::V1_4),
+            "1.5" => Ok(Self::V1_5),
+            "1.6" => Ok(Self::V1_6),
+            _ => Err(Error::UnsupportedSpecVersion(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for SpecVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let version = match self {
+            Self::V1_3 => "1.3",
+            Self::V1_4 => "1.4",
+            Self::V1_5 => "1.5",
+            Self::V1_6 => "1.6",
+        };
+        f.write_str(version)
+    }
+}
+
+/// The error of [`parse_json`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input is not a JSON document of the expected structure
+    Json(serde_json::Error),
+    /// The `bomFormat` of the document is not `CycloneDX`
+    NotCycloneDx(String),
+    /// The `specVersion` of the document is not supported
+    UnsupportedSpecVersion(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => write!(f, "Failed to deserialize JSON: {error}"),
+            Self::NotCycloneDx(format) => write!(f, "Unsupported BOM format '{format}'"),
+            Self::UnsupportedSpecVersion(version) => {
+                write!(f, "Unsupported Spec Version '{version}'")
+            }
+        }
+    }
+}
+
+/// The top level fields of the document, before the format and spec version are checked
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Document {
+    bom_format: String,
+    spec_version: String,
+    #[serde(default)]
+    serial_number: Option<String>,
+    #[serde(default = "default_version")]
+    version: u32,
+    #[serde(default)]
+    metadata: Option<Metadata>,
+    #[serde(default)]
+    components: Vec<Component>,
+    #[serde(default)]
+    dependencies: Vec<Dependency>,
+    #[serde(default)]
+    vulnerabilities: Vec<Vulnerability>,
+}
+
+fn default_version() -> u32 {
+    1
+}
+
+/// The identifying parts of a BOM
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bom {
+    pub spec_version: SpecVersion,
+    pub serial_number: Option<String>,
+    pub version: u32,
+    pub metadata: Option<Metadata>,
+    pub components: Vec<Component>,
+    pub dependencies: Vec<Dependency>,
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+impl Bom {
+    /// Iterates over the component of the metadata, the components and all their nested
+    /// components, depth first.
+    pub fn iter_components(&self) -> impl Iterator<Item = &Component> {
+        let mut stack: Vec<&Component> = self.components.iter().rev().collect();
+        if let Some(component) = self.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+            stack.push(component);
+        }
+
+        core::iter::from_fn(move || {
+            let component = stack.pop()?;
+            stack.extend(component.components.iter().rev());
+            Some(component)
+        })
+    }
+
+    /// Looks up a component anywhere in the BOM by its bom-ref
+    pub fn component_by_ref(&self, bom_ref: &str) -> Option<&Component> {
+        self.iter_components()
+            .find(|component| component.bom_ref.as_deref() == Some(bom_ref))
+    }
+
+    /// Returns the bom-refs the given bom-ref depends on directly
+    pub fn dependencies_of(&self, bom_ref: &str) -> &[String] {
+        self.dependencies
+            .iter()
+            .find(|dependency| dependency.dependency_ref == bom_ref)
+            .map(|dependency| dependency.depends_on.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct Metadata {
+    #[serde(default)]
+    pub timestamp: Option<String>,
+    #[serde(default)]
+    pub component: Option<Component>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Component {
+    #[serde(rename = "type")]
+    pub component_type: String,
+    #[serde(rename = "bom-ref", default)]
+    pub bom_ref: Option<String>,
+    #[serde(default)]
+    pub group: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub purl: Option<String>,
+    #[serde(default)]
+    pub hashes: Vec<Hash>,
+    #[serde(default)]
+    pub components: Vec<Component>,
+}
+
+impl Component {
+    /// Returns the content of the hash with the given algorithm, e.g. `SHA-256`
+    pub fn hash(&self, alg: &str) -> Option<&str> {
+        self.hashes
+            .iter()
+            .find(|hash| hash.alg == alg)
+            .map(|hash| hash.content.as_str())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Hash {
+    pub alg: String,
+    pub content: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Dependency {
+    #[serde(rename = "ref")]
+    pub dependency_ref: String,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Vulnerability {
+    #[serde(rename = "bom-ref", default)]
+    pub bom_ref: Option<String>,
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub affects: Vec<VulnerabilityTarget>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct VulnerabilityTarget {
+    #[serde(rename = "ref")]
+    pub bom_ref: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const INPUT: &str = r#"{
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.4",
+      "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+      "version": 2,
+      "metadata": {
+        "timestamp": "2024-01-01T00:00:00Z",
+        "component": {"type": "application", "bom-ref": "app", "name": "app"},
+        "tools": [{"name": "cargo-cyclonedx"}]
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "outer",
+          "name": "outer",
+          "licenses": [{"expression": "MIT"}],
+          "components": [{"type": "library", "bom-ref": "inner", "name": "inner"}]
+        }
+      ],
+      "dependencies": [{"ref": "app", "dependsOn": ["outer"]}, {"ref": "outer"}],
+      "vulnerabilities": [{"id": "RUSTSEC-2024-0001", "affects": [{"ref": "inner"}]}]
+    }"#;
+
+    #[test]
+    fn it_should_parse_the_identifying_parts() {
+        let bom = parse_json(INPUT.as_bytes()).expect("Failed to parse BOM");
+
+        assert_eq!(bom.spec_version, SpecVersion::V1_4);
+        assert_eq!(
+            bom.serial_number.as_deref(),
+            Some("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79")
+        );
+        assert_eq!(bom.version, 2);
+        assert_eq!(bom.dependencies_of("app"), ["outer".to_string()]);
+        assert!(bom.dependencies_of("outer").is_empty());
+        assert_eq!(
+            bom.vulnerabilities[0].affects,
+            vec![VulnerabilityTarget {
+                bom_ref: "inner".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_iterate_over_nested_components() {
+        let bom = parse_json(INPUT.as_bytes()).expect("Failed to parse BOM");
+
+        let names: Vec<_> = bom.iter_components().map(|c| c.name.as_str()).collect();
+
+        assert_eq!(names, vec!["app", "outer", "inner"]);
+        assert_eq!(bom.component_by_ref("inner").unwrap().name, "inner");
+    }
+
+    #[test]
+    fn it_should_reject_other_formats_and_versions() {
+        let other_format = r#"{"bomFormat": "SPDX", "specVersion": "1.5"}"#;
+        let other_version = r#"{"bomFormat": "CycloneDX", "specVersion": "1.2"}"#;
+
+        assert!(matches!(
+            parse_json(other_format.as_bytes()),
+            Err(Error::NotCycloneDx(_))
+        ));
+        assert!(matches!(
+            parse_json(other_version.as_bytes()),
+            Err(Error::UnsupportedSpecVersion(_))
+        ));
+    }
+}
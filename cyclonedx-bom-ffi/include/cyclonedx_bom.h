@@ -0,0 +1,93 @@
+#ifndef CYCLONEDX_BOM_H
+#define CYCLONEDX_BOM_H
+
+/* Generated with cbindgen from src/lib.rs, do not edit by hand */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The format to serialize a BOM to, passed to [`cdx_bom_serialize`] as a `c_int`
+typedef enum CdxFormat {
+  CDX_FORMAT_JSON = 0,
+  CDX_FORMAT_XML = 1,
+} CdxFormat;
+
+// A parsed BOM with the spec version of its document
+typedef struct CdxBom CdxBom;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parses a JSON or XML BOM of any supported spec version from the `length` bytes at `input`.
+//
+// Returns `NULL` and sets `error` if the input is not a BOM.
+//
+// # Safety
+//
+// `input` must point to `length` readable bytes and `error` must be `NULL` or point to a
+// writable `char *`.
+struct CdxBom *cdx_bom_parse(const uint8_t *input, size_t length, char **error);
+
+// Releases a BOM returned by [`cdx_bom_parse`], `NULL` is ignored.
+//
+// # Safety
+//
+// `bom` must be `NULL` or a BOM returned by [`cdx_bom_parse`] which has not been freed yet.
+void cdx_bom_free(struct CdxBom *bom);
+
+// Returns the spec version of the parsed document, e.g. `1.5`.
+//
+// # Safety
+//
+// `bom` must be a BOM returned by [`cdx_bom_parse`].
+char *cdx_bom_spec_version(const struct CdxBom *bom);
+
+// Validates the BOM against a spec version such as `1.5`, or the version of its document if
+// `spec_version` is `NULL`.
+//
+// Returns `1` if the BOM is valid and `0` if it is not, with `issues` set to the issues found,
+// one per line. Returns `-1` and sets `error` if the spec version is unknown.
+//
+// # Safety
+//
+// `bom` must be a BOM returned by [`cdx_bom_parse`], `spec_version` must be `NULL` or a
+// NUL-terminated string, and `issues` and `error` must be `NULL` or point to a writable
+// `char *`.
+int32_t cdx_bom_validate(const struct CdxBom *bom,
+                         const char *spec_version,
+                         char **issues,
+                         char **error);
+
+// Serializes the BOM as JSON or XML of a spec version such as `1.5`, or the version of its
+// document if `spec_version` is `NULL`.
+//
+// `format` is one of the [`CdxFormat`] values.
+//
+// Returns `NULL` and sets `error` if the format or spec version is unknown or the BOM cannot
+// be represented in it.
+//
+// # Safety
+//
+// `bom` must be a BOM returned by [`cdx_bom_parse`], `spec_version` must be `NULL` or a
+// NUL-terminated string and `error` must be `NULL` or point to a writable `char *`.
+char *cdx_bom_serialize(const struct CdxBom *bom,
+                        int format,
+                        const char *spec_version,
+                        char **error);
+
+// Releases a string returned by one of the functions, `NULL` is ignored.
+//
+// # Safety
+//
+// `string` must be `NULL` or a string returned by this library which has not been freed yet.
+void cdx_string_free(char *string);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* CYCLONEDX_BOM_H */
@@ -0,0 +1,371 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! C bindings to parse, validate and serialize CycloneDX SBOMs with the `cyclonedx-bom` crate,
+//! so tooling in other languages can share one implementation.
+//!
+//! The header `include/cyclonedx_bom.h` is generated with `cbindgen` from this file. A BOM is
+//! an opaque `CdxBom` pointer owned by the caller, strings returned by the functions are owned
+//! by the caller as well and released with `cdx_string_free`.
+//!
+//! ```c
+//! char *error = NULL;
+//! CdxBom *bom = cdx_bom_parse(input, length, &error);
+//! if (bom == NULL) {
+//!     fprintf(stderr, "%s\n", error);
+//!     cdx_string_free(error);
+//!     return 1;
+//! }
+//! char *xml = cdx_bom_serialize(bom, CDX_FORMAT_XML, "1.5", &error);
+//! cdx_bom_free(bom);
+//! ```
+//!
+//! Errors are reported through the optional `error` out parameter, which is set to a message
+//! when a function fails and left untouched otherwise.
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::validation::Validate;
+
+/// A parsed BOM with the spec version of its document
+pub struct CdxBom {
+    bom: Bom,
+    spec_version: SpecVersion,
+}
+
+/// The format to serialize a BOM to, passed to [`cdx_bom_serialize`] as a `c_int`
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CdxFormat {
+    Json = 0,
+    Xml = 1,
+}
+
+impl TryFrom<c_int> for CdxFormat {
+    type Error = String;
+
+    fn try_from(format: c_int) -> Result<Self, Self::Error> {
+        match format {
+            0 => Ok(Self::Json),
+            1 => Ok(Self::Xml),
+            unknown => Err(format!("Unknown format {unknown}")),
+        }
+    }
+}
+
+/// Parses a JSON or XML BOM of any supported spec version from the `length` bytes at `input`.
+///
+/// Returns `NULL` and sets `error` if the input is not a BOM.
+///
+/// # Safety
+///
+/// `input` must point to `length` readable bytes and `error` must be `NULL` or point to a
+/// writable `char *`.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_parse(
+    input: *const u8,
+    length: usize,
+    error: *mut *mut c_char,
+) -> *mut CdxBom {
+    ffi_boundary(error, ptr::null_mut(), || {
+        if input.is_null() {
+            return Err("The input is NULL".to_string());
+        }
+        let input = std::slice::from_raw_parts(input, length);
+        let (bom, spec_version) = Bom::parse_auto(input).map_err(|e| e.to_string())?;
+        Ok(Box::into_raw(Box::new(CdxBom { bom, spec_version })))
+    })
+}
+
+/// Releases a BOM returned by [`cdx_bom_parse`], `NULL` is ignored.
+///
+/// # Safety
+///
+/// `bom` must be `NULL` or a BOM returned by [`cdx_bom_parse`] which has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_free(bom: *mut CdxBom) {
+    if !bom.is_null() {
+        drop(Box::from_raw(bom));
+    }
+}
+
+/// Returns the spec version of the parsed document, e.g. `1.5`.
+///
+/// # Safety
+///
+/// `bom` must be a BOM returned by [`cdx_bom_parse`].
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_spec_version(bom: *const CdxBom) -> *mut c_char {
+    ffi_boundary(ptr::null_mut(), ptr::null_mut(), || {
+        let bom = bom_ref(bom)?;
+        into_c_string(bom.spec_version.to_string())
+    })
+}
+
+/// Validates the BOM against a spec version such as `1.5`, or the version of its document if
+/// `spec_version` is `NULL`.
+///
+/// Returns `1` if the BOM is valid and `0` if it is not, with `issues` set to the issues found,
+/// one per line. Returns `-1` and sets `error` if the spec version is unknown.
+///
+/// # Safety
+///
+/// `bom` must be a BOM returned by [`cdx_bom_parse`], `spec_version` must be `NULL` or a
+/// NUL-terminated string, and `issues` and `error` must be `NULL` or point to a writable
+/// `char *`.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_validate(
+    bom: *const CdxBom,
+    spec_version: *const c_char,
+    issues: *mut *mut c_char,
+    error: *mut *mut c_char,
+) -> i32 {
+    ffi_boundary(error, -1, || {
+        let bom = bom_ref(bom)?;
+        let spec_version = parse_spec_version(spec_version, bom.spec_version)?;
+        let result = bom.bom.validate_version(spec_version);
+        if result.passed() {
+            return Ok(1);
+        }
+        if !issues.is_null() {
+            *issues = into_c_string(result.to_string())?;
+        }
+        Ok(0)
+    })
+}
+
+/// Serializes the BOM as JSON or XML of a spec version such as `1.5`, or the version of its
+/// document if `spec_version` is `NULL`.
+///
+/// `format` is one of the [`CdxFormat`] values.
+///
+/// Returns `NULL` and sets `error` if the format or spec version is unknown or the BOM cannot
+/// be represented in it.
+///
+/// # Safety
+///
+/// `bom` must be a BOM returned by [`cdx_bom_parse`], `spec_version` must be `NULL` or a
+/// NUL-terminated string and `error` must be `NULL` or point to a writable `char *`.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_serialize(
+    bom: *const CdxBom,
+    format: c_int,
+    spec_version: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut c_char {
+    ffi_boundary(error, ptr::null_mut(), || {
+        let bom = bom_ref(bom)?;
+        let format = CdxFormat::try_from(format)?;
+        let spec_version = parse_spec_version(spec_version, bom.spec_version)?;
+
+        let mut output = Vec::new();
+        let bom = bom.bom.clone();
+        match format {
+            CdxFormat::Json => bom
+                .output_as_json(&mut output, spec_version)
+                .map_err(|e| e.to_string())?,
+            CdxFormat::Xml => bom
+                .output_as_xml(&mut output, spec_version)
+                .map_err(|e| e.to_string())?,
+        }
+        into_c_string(output)
+    })
+}
+
+/// Releases a string returned by one of the functions, `NULL` is ignored.
+///
+/// # Safety
+///
+/// `string` must be `NULL` or a string returned by this library which has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Runs the body of a function, reporting its error or panic in `error` instead of unwinding
+/// into the caller.
+unsafe fn ffi_boundary<T>(
+    error: *mut *mut c_char,
+    on_error: T,
+    body: impl FnOnce() -> Result<T, String>,
+) -> T {
+    let message = match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(value)) => return value,
+        Ok(Err(message)) => message,
+        Err(_) => "Unexpected panic in cyclonedx-bom".to_string(),
+    };
+    if !error.is_null() {
+        *error = into_c_string(message).unwrap_or(ptr::null_mut());
+    }
+    on_error
+}
+
+unsafe fn bom_ref<'a>(bom: *const CdxBom) -> Result<&'a CdxBom, String> {
+    bom.as_ref().ok_or_else(|| "The BOM is NULL".to_string())
+}
+
+unsafe fn parse_spec_version(
+    spec_version: *const c_char,
+    default: SpecVersion,
+) -> Result<SpecVersion, String> {
+    if spec_version.is_null() {
+        return Ok(default);
+    }
+    let spec_version = CStr::from_ptr(spec_version)
+        .to_str()
+        .map_err(|e| e.to_string())?;
+    spec_version
+        .parse()
+        .map_err(|e: cyclonedx_bom::errors::BomError| e.to_string())
+}
+
+fn into_c_string(value: impl Into<Vec<u8>>) -> Result<*mut c_char, String> {
+    CString::new(value)
+        .map(CString::into_raw)
+        .map_err(|e| format!("The string contains a NUL byte: {e}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const INPUT: &str = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+  "version": 1,
+  "components": [{"type": "library", "name": "serde", "version": "1.0.193"}]
+}"#;
+
+    unsafe fn take_string(string: *mut c_char) -> String {
+        assert!(!string.is_null());
+        let value = CStr::from_ptr(string).to_str().unwrap().to_string();
+        cdx_string_free(string);
+        value
+    }
+
+    fn parse(input: &str) -> Result<*mut CdxBom, String> {
+        let mut error = ptr::null_mut();
+        let bom = unsafe { cdx_bom_parse(input.as_ptr(), input.len(), &mut error) };
+        match bom.is_null() {
+            true => Err(unsafe { take_string(error) }),
+            false => Ok(bom),
+        }
+    }
+
+    #[test]
+    fn it_should_parse_validate_and_serialize() {
+        let bom = parse(INPUT).expect("Failed to parse BOM");
+        unsafe {
+            assert_eq!(take_string(cdx_bom_spec_version(bom)), "1.5");
+
+            let mut issues = ptr::null_mut();
+            let mut error = ptr::null_mut();
+            assert_eq!(
+                cdx_bom_validate(bom, ptr::null(), &mut issues, &mut error),
+                1
+            );
+            assert!(issues.is_null() && error.is_null());
+
+            let xml = cdx_bom_serialize(
+                bom,
+                CdxFormat::Xml as c_int,
+                b"1.4\0".as_ptr().cast(),
+                &mut error,
+            );
+            let xml = take_string(xml);
+            assert!(xml.contains(r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.4""#));
+            assert!(xml.contains("<name>serde</name>"));
+
+            let json = take_string(cdx_bom_serialize(
+                bom,
+                CdxFormat::Json as c_int,
+                ptr::null(),
+                &mut error,
+            ));
+            assert!(json.contains(r#""specVersion": "1.5""#));
+
+            cdx_bom_free(bom);
+        }
+    }
+
+    #[test]
+    fn it_should_report_the_issues_of_an_invalid_bom() {
+        let bom = parse(&INPUT.replace("\"library\"", "\"firmwares\"")).expect("Failed to parse");
+        unsafe {
+            let mut issues = ptr::null_mut();
+            assert_eq!(
+                cdx_bom_validate(bom, b"1.5\0".as_ptr().cast(), &mut issues, ptr::null_mut()),
+                0
+            );
+            assert!(take_string(issues).contains("Unknown classification"));
+            cdx_bom_free(bom);
+        }
+    }
+
+    #[test]
+    fn it_should_report_errors() {
+        assert_eq!(
+            parse("not a BOM").unwrap_err(),
+            "Input is neither a JSON nor an XML document"
+        );
+
+        let bom = parse(INPUT).expect("Failed to parse BOM");
+        unsafe {
+            let mut error = ptr::null_mut();
+            let output = cdx_bom_serialize(
+                bom,
+                CdxFormat::Json as c_int,
+                b"2.0\0".as_ptr().cast(),
+                &mut error,
+            );
+            assert!(output.is_null());
+            assert_eq!(take_string(error), "Unsupported Spec Version '2.0'");
+
+            let output = cdx_bom_serialize(bom, 2, ptr::null(), &mut error);
+            assert!(output.is_null());
+            assert_eq!(take_string(error), "Unknown format 2");
+            cdx_bom_free(bom);
+        }
+    }
+
+    #[test]
+    fn it_should_match_the_generated_header() {
+        let crate_dir = env!("CARGO_MANIFEST_DIR");
+        let config = cbindgen::Config::from_file(format!("{crate_dir}/cbindgen.toml"))
+            .expect("Failed to read cbindgen.toml");
+
+        let mut header = Vec::new();
+        cbindgen::generate_with_config(crate_dir, config)
+            .expect("Failed to generate the header")
+            .write(&mut header);
+
+        let expected = std::fs::read_to_string(format!("{crate_dir}/include/cyclonedx_bom.h"))
+            .expect("Failed to read include/cyclonedx_bom.h");
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            expected,
+            "include/cyclonedx_bom.h is out of date, regenerate it with cbindgen"
+        );
+    }
+}
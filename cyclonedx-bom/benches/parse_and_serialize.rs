@@ -0,0 +1,118 @@
+//! Benchmarks for parsing and serializing large BOMs in JSON and XML.
+//!
+//! The BOM is generated with a configurable number of components, each with a purl, a license,
+//! a hash, some properties and a dependency on its predecessor, which roughly resembles the BOM
+//! of a larger Rust workspace. Run with `cargo bench -p cyclonedx-bom`, and with
+//! `--features quick-xml` to parse the XML documents with `quick-xml` instead of `xml-rs`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use cyclonedx_bom::external_models::{normalized_string::NormalizedString, uri::Purl};
+use cyclonedx_bom::models::{
+    bom::Bom,
+    component::{Classification, Component, Components},
+    dependency::{Dependencies, Dependency},
+    hash::{Hash, HashAlgorithm, HashValue, Hashes},
+    license::{LicenseChoice, Licenses},
+    metadata::Metadata,
+    property::{Properties, Property},
+};
+use cyclonedx_bom::prelude::SpdxExpression;
+
+const COMPONENT_COUNTS: [usize; 2] = [100, 2_000];
+
+fn generate_bom(count: usize) -> Bom {
+    let components = (0..count)
+        .map(|i| {
+            let name = format!("crate-{i}");
+            let mut component = Component::new(
+                Classification::Library,
+                &name,
+                "1.0.0",
+                Some(format!("{name}@1.0.0")),
+            );
+            component.description = Some(NormalizedString::new("A generated component"));
+            component.purl = Purl::new("cargo", &name, "1.0.0").ok();
+            component.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+                SpdxExpression::new("MIT OR Apache-2.0"),
+            )]));
+            component.hashes = Some(Hashes(vec![Hash {
+                alg: HashAlgorithm::SHA_256,
+                content: HashValue(format!("{i:064x}")),
+            }]));
+            component.properties = Some(Properties(vec![
+                Property::new("cdx:rustc:sbom:target:all_targets", "true"),
+                Property::new("acme:index", &i.to_string()),
+            ]));
+            component
+        })
+        .collect();
+
+    let dependencies = (1..count)
+        .map(|i| Dependency {
+            dependency_ref: format!("crate-{i}@1.0.0"),
+            dependencies: vec![format!("crate-{}@1.0.0", i - 1)],
+        })
+        .collect();
+
+    Bom {
+        metadata: Some(Metadata::default()),
+        components: Some(Components(components)),
+        dependencies: Some(Dependencies(dependencies)),
+        ..Bom::default()
+    }
+}
+
+fn bench_json(c: &mut Criterion) {
+    let mut group = c.benchmark_group("json");
+    for count in COMPONENT_COUNTS {
+        let bom = generate_bom(count);
+        let mut output = Vec::new();
+        bom.clone()
+            .output_as_json_v1_5(&mut output)
+            .expect("Failed to write BOM");
+        group.throughput(Throughput::Bytes(output.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("parse", count), &output, |b, input| {
+            b.iter(|| Bom::parse_from_json_v1_5(input.as_slice()).expect("Failed to parse BOM"))
+        });
+        group.bench_with_input(BenchmarkId::new("serialize", count), &bom, |b, bom| {
+            b.iter(|| {
+                let mut output = Vec::with_capacity(output.len());
+                bom.clone()
+                    .output_as_json_v1_5(&mut output)
+                    .expect("Failed to write BOM");
+                output
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_xml(c: &mut Criterion) {
+    let mut group = c.benchmark_group("xml");
+    for count in COMPONENT_COUNTS {
+        let bom = generate_bom(count);
+        let mut output = Vec::new();
+        bom.clone()
+            .output_as_xml_v1_5(&mut output)
+            .expect("Failed to write BOM");
+        group.throughput(Throughput::Bytes(output.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("parse", count), &output, |b, input| {
+            b.iter(|| Bom::parse_from_xml_v1_5(input.as_slice()).expect("Failed to parse BOM"))
+        });
+        group.bench_with_input(BenchmarkId::new("serialize", count), &bom, |b, bom| {
+            b.iter(|| {
+                let mut output = Vec::with_capacity(output.len());
+                bom.clone()
+                    .output_as_xml_v1_5(&mut output)
+                    .expect("Failed to write BOM");
+                output
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_json, bench_xml);
+criterion_main!(benches);
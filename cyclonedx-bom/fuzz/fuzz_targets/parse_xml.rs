@@ -0,0 +1,20 @@
+//! Parses the input as an XML BOM of every spec version, which must fail with an error instead
+//! of panicking on malformed documents, e.g. in the readers of lists.
+
+#![no_main]
+
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use libfuzzer_sys::fuzz_target;
+
+const VERSIONS: [SpecVersion; 4] = [
+    SpecVersion::V1_3,
+    SpecVersion::V1_4,
+    SpecVersion::V1_5,
+    SpecVersion::V1_6,
+];
+
+fuzz_target!(|data: &[u8]| {
+    for version in VERSIONS {
+        let _ = Bom::parse_from_xml_with_version(data, version);
+    }
+});
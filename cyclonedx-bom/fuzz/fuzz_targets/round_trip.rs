@@ -0,0 +1,38 @@
+//! Parses the input as a BOM of the detected format and spec version and checks that writing
+//! it is idempotent: the output of a parsed output is the same output again.
+
+#![no_main]
+
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use libfuzzer_sys::fuzz_target;
+
+fn output(bom: Bom, version: SpecVersion, json: bool) -> Option<Vec<u8>> {
+    let mut output = Vec::new();
+    let written = match json {
+        true => bom.output_as_json(&mut output, version).is_ok(),
+        false => bom.output_as_xml(&mut output, version).is_ok(),
+    };
+    written.then_some(output)
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok((bom, version)) = Bom::parse_auto(data) else {
+        return;
+    };
+    let json = data.iter().find(|byte| !byte.is_ascii_whitespace()) == Some(&b'{');
+    // Not every parsed BOM can be written, e.g. with values unsupported by its spec version
+    let Some(first) = output(bom, version, json) else {
+        return;
+    };
+
+    let (reparsed, reparsed_version) =
+        Bom::parse_auto(&first).expect("Failed to parse the written BOM");
+    assert_eq!(reparsed_version, version);
+    let second = output(reparsed, version, json).expect("Failed to write the parsed BOM");
+    assert!(
+        first == second,
+        "The output changed after a round trip:\n{}\n{}",
+        String::from_utf8_lossy(&first),
+        String::from_utf8_lossy(&second)
+    );
+});
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+# Seeds the corpora of the fuzz targets with the examples of the specification in tests/spec,
+# run from the fuzz directory before the first `cargo fuzz run`.
+set -euo pipefail
+
+spec_dir="$(dirname "$0")/../tests/spec"
+corpus_dir="$(dirname "$0")/corpus"
+
+mkdir -p "$corpus_dir/parse_json" "$corpus_dir/parse_xml" "$corpus_dir/round_trip"
+for file in "$spec_dir"/*/*.json; do
+    name="$(basename "$(dirname "$file")")-$(basename "$file")"
+    cp "$file" "$corpus_dir/parse_json/$name"
+    cp "$file" "$corpus_dir/round_trip/$name"
+done
+for file in "$spec_dir"/*/*.xml; do
+    name="$(basename "$(dirname "$file")")-$(basename "$file")"
+    cp "$file" "$corpus_dir/parse_xml/$name"
+    cp "$file" "$corpus_dir/round_trip/$name"
+done
@@ -0,0 +1,193 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Parsing from [`AsyncRead`] and output to [`AsyncWrite`] for `tokio` applications, e.g. web
+//! services that ingest uploaded BOMs.
+//!
+//! The parsers themselves are synchronous. The async variants run them on the blocking thread
+//! pool of the runtime and feed them from the async reader as they consume the input, so neither
+//! a worker thread is blocked nor the whole request body is buffered before parsing. Output is
+//! serialized on the blocking thread pool as well and then written to the async writer.
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+//!
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! let input = r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1}"#;
+//! let bom = Bom::parse_from_json_async(input.as_bytes()).await?;
+//!
+//! let mut output = Vec::new();
+//! bom.output_as_json_async(&mut output, SpecVersion::V1_4).await?;
+//! assert!(output.starts_with(b"{"));
+//! # Ok::<(), cyclonedx_bom::errors::AsyncIoError>(())
+//! # }).unwrap();
+//! ```
+
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::SyncIoBridge;
+
+use crate::errors::AsyncIoError;
+use crate::models::bom::{Bom, SpecVersion};
+
+impl Bom {
+    /// Parses a JSON document of any supported spec version from an async reader, see
+    /// [`parse_from_json`](Self::parse_from_json).
+    ///
+    /// Has to be called from within a `tokio` runtime.
+    pub async fn parse_from_json_async<R>(reader: R) -> Result<Self, AsyncIoError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let reader = SyncIoBridge::new(reader);
+        let bom = tokio::task::spawn_blocking(move || Self::parse_from_json(reader)).await??;
+        Ok(bom)
+    }
+
+    /// Parses a JSON document conforming to the spec version that you provide from an async
+    /// reader, see [`parse_from_json_with_version`](Self::parse_from_json_with_version).
+    ///
+    /// Has to be called from within a `tokio` runtime.
+    pub async fn parse_from_json_async_with_version<R>(
+        reader: R,
+        version: SpecVersion,
+    ) -> Result<Self, AsyncIoError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let reader = SyncIoBridge::new(reader);
+        let bom = tokio::task::spawn_blocking(move || {
+            Self::parse_from_json_with_version(reader, version)
+        })
+        .await??;
+        Ok(bom)
+    }
+
+    /// Parses an XML document conforming to the spec version that you provide from an async
+    /// reader, see [`parse_from_xml_with_version`](Self::parse_from_xml_with_version).
+    ///
+    /// Has to be called from within a `tokio` runtime.
+    pub async fn parse_from_xml_async_with_version<R>(
+        reader: R,
+        version: SpecVersion,
+    ) -> Result<Self, AsyncIoError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let reader = SyncIoBridge::new(reader);
+        let bom =
+            tokio::task::spawn_blocking(move || Self::parse_from_xml_with_version(reader, version))
+                .await??;
+        Ok(bom)
+    }
+
+    /// Output as a JSON document conforming to the spec version that you provide to an async
+    /// writer, see [`output_as_json`](Self::output_as_json).
+    ///
+    /// Has to be called from within a `tokio` runtime. The writer is flushed but not shut down.
+    pub async fn output_as_json_async<W>(
+        self,
+        writer: &mut W,
+        version: SpecVersion,
+    ) -> Result<(), AsyncIoError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let output = tokio::task::spawn_blocking(move || {
+            let mut output = Vec::new();
+            self.output_as_json(&mut output, version).map(|_| output)
+        })
+        .await??;
+        write_all(writer, &output).await
+    }
+
+    /// Output as an XML document conforming to the spec version that you provide to an async
+    /// writer, see [`output_as_xml`](Self::output_as_xml).
+    ///
+    /// Has to be called from within a `tokio` runtime. The writer is flushed but not shut down.
+    pub async fn output_as_xml_async<W>(
+        self,
+        writer: &mut W,
+        version: SpecVersion,
+    ) -> Result<(), AsyncIoError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let output = tokio::task::spawn_blocking(move || {
+            let mut output = Vec::new();
+            self.output_as_xml(&mut output, version).map(|_| output)
+        })
+        .await??;
+        write_all(writer, &output).await
+    }
+}
+
+async fn write_all<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    output: &[u8],
+) -> Result<(), AsyncIoError> {
+    writer.write_all(output).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn it_should_round_trip_json() {
+        let bom = Bom::default();
+        let serial_number = bom.serial_number.clone();
+
+        let mut output = Vec::new();
+        bom.output_as_json_async(&mut output, SpecVersion::V1_5)
+            .await
+            .expect("Failed to write BOM");
+        let bom = Bom::parse_from_json_async(std::io::Cursor::new(output))
+            .await
+            .expect("Failed to parse BOM");
+
+        assert_eq!(bom.serial_number, serial_number);
+    }
+
+    #[tokio::test]
+    async fn it_should_round_trip_xml() {
+        let bom = Bom::default();
+        let serial_number = bom.serial_number.clone();
+
+        let mut output = Vec::new();
+        bom.output_as_xml_async(&mut output, SpecVersion::V1_6)
+            .await
+            .expect("Failed to write BOM");
+        let bom =
+            Bom::parse_from_xml_async_with_version(std::io::Cursor::new(output), SpecVersion::V1_6)
+                .await
+                .expect("Failed to parse BOM");
+
+        assert_eq!(bom.serial_number, serial_number);
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_to_parse_invalid_json() {
+        let input = r#"{"bomFormat": "CycloneDX", "#;
+
+        let result = Bom::parse_from_json_async(input.as_bytes()).await;
+
+        assert!(matches!(result, Err(AsyncIoError::JsonReadError(_))));
+    }
+}
@@ -0,0 +1,472 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Reading and writing bundles, tar archives with several related BOMs, e.g. the BOM of a
+//! product, the SBOMs of its services and VEX documents, and an `index.json` listing them.
+//!
+//! The archive is an uncompressed ustar archive, compress it on top if needed. The index lists
+//! the path, role, serial number and version of each BOM:
+//!
+//! ```json
+//! {
+//!   "boms": [
+//!     {
+//!       "path": "product.cdx.json",
+//!       "role": "product",
+//!       "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+//!       "version": 1
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! BOMs with a path ending in `.xml` are written as XML, all others as JSON, in the spec version
+//! of the BOM. BOM-Links between the BOMs of a bundle resolve with [`Bundle::resolve`].
+//!
+//! ```
+//! use cyclonedx_bom::bundle::{Bundle, BundleRole};
+//! use cyclonedx_bom::models::bom::Bom;
+//!
+//! let mut bundle = Bundle::default();
+//! bundle.push("product.cdx.json", BundleRole::Product, Bom::default());
+//!
+//! let mut archive = Vec::new();
+//! bundle.write_tar(&mut archive)?;
+//! let bundle = Bundle::read_tar(archive.as_slice())?;
+//! assert!(bundle.product().is_some());
+//! # Ok::<(), cyclonedx_bom::errors::BundleError>(())
+//! ```
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::BundleError;
+use crate::models::bom::{Bom, UrnUuid};
+use crate::models::bom_link::{BomLinkError, BomLinkResolver, BomLinkTarget, BomLinkUrn};
+
+/// The path of the index in the archive
+pub const INDEX_PATH: &str = "index.json";
+
+const BLOCK_SIZE: usize = 512;
+
+/// What a BOM of a bundle describes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, strum::Display, Serialize, Deserialize)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum BundleRole {
+    /// The BOM of the product the bundle is about
+    Product,
+    /// The SBOM of a service of the product
+    Service,
+    /// A VEX document about the vulnerabilities of the product or its services
+    Vex,
+    Other,
+}
+
+/// A BOM of a bundle with its path in the archive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BundleEntry {
+    pub path: String,
+    pub role: BundleRole,
+    pub bom: Bom,
+}
+
+/// Related BOMs which are read and written together, see the [module documentation](self).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bundle {
+    pub entries: Vec<BundleEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Index {
+    boms: Vec<IndexEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IndexEntry {
+    path: String,
+    role: BundleRole,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    serial_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
+}
+
+impl Bundle {
+    /// Adds a BOM at the path of the archive.
+    pub fn push(&mut self, path: impl Into<String>, role: BundleRole, bom: Bom) {
+        self.entries.push(BundleEntry {
+            path: path.into(),
+            role,
+            bom,
+        });
+    }
+
+    /// Returns the BOM at the path of the archive.
+    pub fn get(&self, path: &str) -> Option<&Bom> {
+        self.entries
+            .iter()
+            .find(|entry| entry.path == path)
+            .map(|entry| &entry.bom)
+    }
+
+    /// Returns the first BOM with the [`BundleRole::Product`] role.
+    pub fn product(&self) -> Option<&Bom> {
+        self.with_role(BundleRole::Product).next()
+    }
+
+    /// Returns the BOMs with the role, in the order of the index.
+    pub fn with_role(&self, role: BundleRole) -> impl Iterator<Item = &Bom> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.role == role)
+            .map(|entry| &entry.bom)
+    }
+
+    /// Resolves a BOM-Link such as `urn:cdx:<serial number>/1#<bom-ref>` to a BOM of the bundle
+    /// or an element of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the BOM-Link is invalid or the bundle has no BOM or element it links to.
+    pub fn resolve(&self, bom_link: &str) -> Result<BomLinkTarget<'_>, BomLinkError> {
+        bom_link.parse::<BomLinkUrn>()?.resolve(self)
+    }
+
+    /// Reads a bundle from a tar archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive is invalid, has no index, misses a BOM of the index or
+    /// one of the BOMs fails to parse.
+    pub fn read_tar<R: Read>(reader: R) -> Result<Self, BundleError> {
+        let mut files = read_tar_files(reader)?;
+        let index = files.remove(INDEX_PATH).ok_or(BundleError::MissingIndex)?;
+        let index: Index = serde_json::from_slice(&index)?;
+
+        let entries = index
+            .boms
+            .into_iter()
+            .map(|entry| {
+                let content = files
+                    .get(&entry.path)
+                    .ok_or_else(|| BundleError::MissingEntry(entry.path.clone()))?;
+                let (bom, _) = Bom::parse_auto(content).map_err(|error| BundleError::Parse {
+                    path: entry.path.clone(),
+                    error,
+                })?;
+                Ok(BundleEntry {
+                    path: entry.path,
+                    role: entry.role,
+                    bom,
+                })
+            })
+            .collect::<Result<_, BundleError>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// Writes the bundle as a tar archive, the index first and then the BOMs in their order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a path is too long for a tar archive, a BOM fails to serialize or
+    /// writing fails.
+    pub fn write_tar<W: Write>(&self, mut writer: W) -> Result<(), BundleError> {
+        let index = Index {
+            boms: self
+                .entries
+                .iter()
+                .map(|entry| IndexEntry {
+                    path: entry.path.clone(),
+                    role: entry.role,
+                    serial_number: entry.bom.serial_number.as_ref().map(UrnUuid::to_string),
+                    version: Some(entry.bom.version),
+                })
+                .collect(),
+        };
+        let index = serde_json::to_vec_pretty(&index)?;
+        write_tar_file(&mut writer, INDEX_PATH, &index)?;
+
+        for entry in &self.entries {
+            let mut content = Vec::new();
+            let bom = entry.bom.clone();
+            let version = bom.spec_version;
+            if entry.path.ends_with(".xml") {
+                bom.output_as_xml(&mut content, version)
+                    .map_err(|error| BundleError::Write {
+                        path: entry.path.clone(),
+                        error: error.to_string(),
+                    })?;
+            } else {
+                bom.output_as_json(&mut content, version)
+                    .map_err(|error| BundleError::Write {
+                        path: entry.path.clone(),
+                        error: error.to_string(),
+                    })?;
+            }
+            write_tar_file(&mut writer, &entry.path, &content)?;
+        }
+
+        // The end of the archive are two blocks of zeros.
+        writer.write_all(&[0; 2 * BLOCK_SIZE])?;
+        Ok(())
+    }
+}
+
+impl BomLinkResolver for Bundle {
+    fn resolve_bom(&self, serial_number: &UrnUuid, version: u32) -> Option<&Bom> {
+        self.entries
+            .iter()
+            .find_map(|entry| entry.bom.resolve_bom(serial_number, version))
+    }
+}
+
+/// Reads the regular files of a tar archive by their path, other entries are skipped.
+fn read_tar_files<R: Read>(mut reader: R) -> Result<HashMap<String, Vec<u8>>, BundleError> {
+    let mut files = HashMap::new();
+    let mut header = [0; BLOCK_SIZE];
+
+    loop {
+        if !read_block(&mut reader, &mut header)? || header.iter().all(|byte| *byte == 0) {
+            return Ok(files);
+        }
+
+        let expected_checksum = parse_octal(&header[148..156])?;
+        if checksum(&header) != expected_checksum {
+            return Err(BundleError::InvalidArchive(
+                "header checksum mismatch".to_string(),
+            ));
+        }
+
+        let size = usize::try_from(parse_octal(&header[124..136])?)
+            .map_err(|_| BundleError::InvalidArchive("entry too large".to_string()))?;
+        let padded = (size + BLOCK_SIZE - 1) / BLOCK_SIZE * BLOCK_SIZE;
+        let mut content = vec![0; padded];
+        reader.read_exact(&mut content)?;
+        content.truncate(size);
+
+        // Regular files only, directories, links and extended headers are skipped.
+        if matches!(header[156], b'0' | 0) {
+            let mut path = null_terminated(&header[0..100])?;
+            if &header[257..262] == b"ustar" {
+                let prefix = null_terminated(&header[345..500])?;
+                if !prefix.is_empty() {
+                    path = format!("{prefix}/{path}");
+                }
+            }
+            let path = path.strip_prefix("./").unwrap_or(&path).to_string();
+            files.insert(path, content);
+        }
+    }
+}
+
+/// Reads a whole block, returns `false` at the end of the input.
+fn read_block<R: Read>(reader: &mut R, block: &mut [u8; BLOCK_SIZE]) -> Result<bool, BundleError> {
+    let mut read = 0;
+    while read < BLOCK_SIZE {
+        match reader.read(&mut block[read..])? {
+            0 if read == 0 => return Ok(false),
+            0 => return Err(BundleError::InvalidArchive("truncated header".to_string())),
+            n => read += n,
+        }
+    }
+    Ok(true)
+}
+
+fn write_tar_file<W: Write>(writer: &mut W, path: &str, content: &[u8]) -> Result<(), BundleError> {
+    if path.len() > 100 {
+        return Err(BundleError::PathTooLong(path.to_string()));
+    }
+
+    let mut header = [0; BLOCK_SIZE];
+    header[..path.len()].copy_from_slice(path.as_bytes());
+    write_octal(&mut header[100..108], 0o644);
+    write_octal(&mut header[108..116], 0);
+    write_octal(&mut header[116..124], 0);
+    write_octal(&mut header[124..136], content.len() as u64);
+    write_octal(&mut header[136..148], 0);
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    let checksum = checksum(&header);
+    write_octal(&mut header[148..155], checksum);
+    header[155] = b' ';
+
+    writer.write_all(&header)?;
+    writer.write_all(content)?;
+    let padding = (BLOCK_SIZE - content.len() % BLOCK_SIZE) % BLOCK_SIZE;
+    writer.write_all(&vec![0; padding])?;
+    Ok(())
+}
+
+/// The sum of the header bytes with the checksum field counted as spaces.
+fn checksum(header: &[u8; BLOCK_SIZE]) -> u64 {
+    header
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| {
+            if (148..156).contains(&i) {
+                u64::from(b' ')
+            } else {
+                u64::from(*byte)
+            }
+        })
+        .sum()
+}
+
+/// Writes the value as zero padded octal digits followed by a null byte.
+fn write_octal(field: &mut [u8], value: u64) {
+    let digits = format!("{value:0width$o}", width = field.len() - 1);
+    field[..digits.len()].copy_from_slice(digits.as_bytes());
+    field[digits.len()] = 0;
+}
+
+fn parse_octal(field: &[u8]) -> Result<u64, BundleError> {
+    let digits = null_terminated(field)?;
+    let digits = digits.trim_matches(|c: char| c == ' ' || c == '\0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(digits, 8)
+        .map_err(|_| BundleError::InvalidArchive(format!("invalid number '{digits}'")))
+}
+
+fn null_terminated(field: &[u8]) -> Result<String, BundleError> {
+    let end = field
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(field.len());
+    String::from_utf8(field[..end].to_vec())
+        .map_err(|_| BundleError::InvalidArchive("path is not UTF-8".to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::models::bom::SpecVersion;
+    use crate::models::component::{Classification, Component, Components};
+    use crate::models::metadata::Metadata;
+
+    fn bom(serial_number: &str, component: Option<&str>) -> Bom {
+        Bom {
+            serial_number: Some(UrnUuid(format!("urn:uuid:{serial_number}"))),
+            spec_version: SpecVersion::V1_5,
+            metadata: Some(Metadata::default()),
+            components: component.map(|name| {
+                Components(vec![Component::new(
+                    Classification::Library,
+                    name,
+                    "1.0.0",
+                    Some(name.to_string()),
+                )])
+            }),
+            ..Bom::default()
+        }
+    }
+
+    fn bundle() -> Bundle {
+        let mut bundle = Bundle::default();
+        bundle.push(
+            "product.cdx.json",
+            BundleRole::Product,
+            bom("3e671687-395b-41f5-a30f-a58921a69b79", Some("product")),
+        );
+        bundle.push(
+            "services/api.cdx.xml",
+            BundleRole::Service,
+            bom("a1b2c3d4-395b-41f5-a30f-a58921a69b79", Some("api")),
+        );
+        bundle.push(
+            "vex.cdx.json",
+            BundleRole::Vex,
+            bom("f0e1d2c3-395b-41f5-a30f-a58921a69b79", None),
+        );
+        bundle
+    }
+
+    #[test]
+    fn it_should_round_trip_a_bundle() {
+        let bundle = bundle();
+
+        let mut archive = Vec::new();
+        bundle
+            .write_tar(&mut archive)
+            .expect("Failed to write bundle");
+        assert_eq!(archive.len() % BLOCK_SIZE, 0);
+
+        let actual = Bundle::read_tar(archive.as_slice()).expect("Failed to read bundle");
+        assert_eq!(actual, bundle);
+        assert_eq!(actual.with_role(BundleRole::Service).count(), 1);
+    }
+
+    #[test]
+    fn it_should_resolve_bom_links_within_the_bundle() {
+        let bundle = bundle();
+
+        let target = bundle
+            .resolve("urn:cdx:a1b2c3d4-395b-41f5-a30f-a58921a69b79/1#api")
+            .expect("Failed to resolve BOM-Link");
+        assert!(
+            matches!(target, BomLinkTarget::Component(component) if component.name.to_string() == "api")
+        );
+
+        let target = bundle
+            .resolve("urn:cdx:f0e1d2c3-395b-41f5-a30f-a58921a69b79/1")
+            .expect("Failed to resolve BOM-Link");
+        assert_eq!(target, BomLinkTarget::Bom(&bundle.entries[2].bom));
+
+        assert_eq!(
+            bundle.resolve("urn:cdx:f0e1d2c3-395b-41f5-a30f-a58921a69b79/2"),
+            Err(BomLinkError::UnresolvedBom(
+                "urn:cdx:f0e1d2c3-395b-41f5-a30f-a58921a69b79/2".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_read_a_bundle_without_index() {
+        let mut archive = Vec::new();
+        write_tar_file(&mut archive, "product.cdx.json", b"{}").unwrap();
+        archive.extend_from_slice(&[0; 2 * BLOCK_SIZE]);
+
+        let actual = Bundle::read_tar(archive.as_slice());
+        assert!(matches!(actual, Err(BundleError::MissingIndex)));
+    }
+
+    #[test]
+    fn it_should_fail_to_read_a_bundle_with_a_missing_bom() {
+        let mut archive = Vec::new();
+        write_tar_file(
+            &mut archive,
+            INDEX_PATH,
+            br#"{"boms": [{"path": "product.cdx.json", "role": "product"}]}"#,
+        )
+        .unwrap();
+        archive.extend_from_slice(&[0; 2 * BLOCK_SIZE]);
+
+        let actual = Bundle::read_tar(archive.as_slice());
+        assert!(
+            matches!(actual, Err(BundleError::MissingEntry(path)) if path == "product.cdx.json")
+        );
+    }
+}
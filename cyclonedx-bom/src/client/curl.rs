@@ -0,0 +1,182 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use super::{Request, Response, Transport};
+
+/// Sends the requests with `curl`, available with the `curl-cli` feature.
+///
+/// The options are passed on stdin, so headers like API keys never show up in the process list.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CurlTransport {
+    /// Accept invalid TLS certificates
+    pub insecure: bool,
+    /// A PEM file with the certificates to trust instead of the system's
+    pub ca_cert: Option<PathBuf>,
+}
+
+/// The response status, separated from the body by the last line break
+const STATUS_FORMAT: &str = "\\n%{http_code}";
+
+impl CurlTransport {
+    /// Renders the request as a curl config file, see `curl --config`
+    fn config(&self, request: &Request) -> String {
+        let mut config = vec![
+            format!("url = {}", quote(&request.url)),
+            format!("request = {}", quote(request.method.as_str())),
+        ];
+        for (name, value) in &request.headers {
+            config.push(format!("header = {}", quote(&format!("{name}: {value}"))));
+        }
+        if let Some(body) = &request.body {
+            config.push(format!("data-binary = {}", quote(body)));
+        }
+        config.extend([
+            "silent".to_string(),
+            "show-error".to_string(),
+            format!("write-out = {}", quote(STATUS_FORMAT)),
+        ]);
+        if self.insecure {
+            config.push("insecure".to_string());
+        }
+        if let Some(ca_cert) = &self.ca_cert {
+            config.push(format!(
+                "cacert = {}",
+                quote(&ca_cert.display().to_string())
+            ));
+        }
+        config.push(String::new());
+        config.join("\n")
+    }
+}
+
+impl Transport for CurlTransport {
+    fn send(&self, request: &Request) -> std::io::Result<Response> {
+        let mut child = Command::new("curl")
+            .args(["--config", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(self.config(request).as_bytes())?;
+        let output = child.wait_with_output()?;
+
+        parse_output(output.stdout).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            )
+        })
+    }
+}
+
+/// Splits the output of curl into the body and the status written after it, `None` if no
+/// response was received
+fn parse_output(mut stdout: Vec<u8>) -> Option<Response> {
+    let position = stdout.iter().rposition(|&byte| byte == b'\n').unwrap_or(0);
+    let status = std::str::from_utf8(&stdout[position..])
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    if status == 0 {
+        return None;
+    }
+    stdout.truncate(position);
+    Some(Response {
+        status,
+        body: stdout,
+    })
+}
+
+/// Quotes a value for a curl config file, in which backslashes, quotes and line breaks are
+/// escaped
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client::Method;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_keep_binary_response_bodies() {
+        let mut stdout = vec![0x1f, 0x8b, 0xff, b'\n', 0x00];
+        stdout.extend_from_slice(b"\n200");
+
+        assert_eq!(
+            parse_output(stdout),
+            Some(Response {
+                status: 200,
+                body: vec![0x1f, 0x8b, 0xff, b'\n', 0x00],
+            })
+        );
+        assert_eq!(parse_output(b"\n000".to_vec()), None);
+        assert_eq!(parse_output(Vec::new()), None);
+    }
+
+    #[test]
+    fn it_should_render_the_curl_config() {
+        let transport = CurlTransport {
+            insecure: true,
+            ca_cert: Some(PathBuf::from("ca.pem")),
+        };
+        let request = Request {
+            method: Method::Post,
+            url: "https://bom-repository.example.com/v1/bom".to_string(),
+            headers: vec![("X-Api-Key".to_string(), "secret".to_string())],
+            body: Some("{\n  \"name\": \"a\\b\"\n}".to_string()),
+        };
+
+        assert_eq!(
+            transport.config(&request),
+            r#"url = "https://bom-repository.example.com/v1/bom"
+request = "POST"
+header = "X-Api-Key: secret"
+data-binary = "{\n  \"name\": \"a\\b\"\n}"
+silent
+show-error
+write-out = "\\n%{http_code}"
+insecure
+cacert = "ca.pem"
+"#
+        );
+    }
+}
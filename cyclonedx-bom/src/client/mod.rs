@@ -0,0 +1,369 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! A client for the CycloneDX BOM Exchange API, as served by the CycloneDX BOM repository
+//! server, to publish BOMs, retrieve them by serial number and version, and search them by
+//! package URL.
+//!
+//! | Operation | Request |
+//! |-----------|---------|
+//! | [`publish`](BomExchangeClient::publish) | `POST <url>/v1/bom` |
+//! | [`retrieve`](BomExchangeClient::retrieve) | `GET <url>/v1/bom?serialNumber=<serial>&version=<version>` |
+//! | [`search_by_purl`](BomExchangeClient::search_by_purl) | `GET <url>/v1/search?purl=<purl>` |
+//!
+//! BOMs are exchanged as JSON, with the spec version in the media type, e.g.
+//! `application/vnd.cyclonedx+json; version=1.5`.
+//!
+//! The requests are sent by a [`Transport`], e.g. implemented with an HTTP client the application
+//! already uses. With the `curl-cli` feature the client can also send them with `CurlTransport`,
+//! which runs `curl` and so handles TLS with the system's certificate store without any HTTP
+//! dependencies.
+//!
+//! ```no_run
+//! use cyclonedx_bom::client::{BomExchangeClient, Request, Response, Transport};
+//! use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+//!
+//! struct HttpTransport;
+//!
+//! impl Transport for HttpTransport {
+//!     fn send(&self, _request: &Request) -> std::io::Result<Response> {
+//!         // Send the request with the HTTP client of the application
+//! #       unimplemented!()
+//!     }
+//! }
+//!
+//! let client = BomExchangeClient::with_transport("https://bom-repository.example.com", HttpTransport)
+//!     .with_header("Authorization", "Bearer secret");
+//! let bom = Bom::default();
+//! let serial_number = bom.serial_number.clone().expect("a default BOM has a serial number");
+//! client.publish(&bom, SpecVersion::V1_5)?;
+//!
+//! let bom = client.retrieve(&serial_number.to_string(), Some(1))?;
+//! for found in client.search_by_purl("pkg:cargo/serde@1.0.193")? {
+//!     println!("{} version {}", found.serial_number, found.version);
+//! }
+//! # Ok::<(), cyclonedx_bom::errors::ClientError>(())
+//! ```
+
+use serde::Deserialize;
+
+use crate::errors::ClientError;
+use crate::models::bom::{Bom, SpecVersion};
+
+#[cfg(feature = "curl-cli")]
+mod curl;
+#[cfg(feature = "curl-cli")]
+pub use curl::CurlTransport;
+
+/// The HTTP method of a [`Request`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+}
+
+impl Method {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+        }
+    }
+}
+
+/// A request of the client, sent by a [`Transport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    /// The header names and values
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+/// The response to a [`Request`], of any status.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// Sends the requests of a [`BomExchangeClient`].
+pub trait Transport {
+    /// Sends the request and returns the response of any status, failing only if no response
+    /// was received, e.g. after a connection or TLS error.
+    fn send(&self, request: &Request) -> std::io::Result<Response>;
+}
+
+impl<T: Transport + ?Sized> Transport for &T {
+    fn send(&self, request: &Request) -> std::io::Result<Response> {
+        (**self).send(request)
+    }
+}
+
+/// A BOM found by [`BomExchangeClient::search_by_purl`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BomIdentifier {
+    pub serial_number: String,
+    pub version: u32,
+}
+
+/// A client for a server implementing the BOM Exchange API, see the [module](self) documentation.
+#[derive(Clone, Debug)]
+pub struct BomExchangeClient<T> {
+    url: String,
+    headers: Vec<(String, String)>,
+    transport: T,
+}
+
+#[cfg(feature = "curl-cli")]
+impl BomExchangeClient<CurlTransport> {
+    /// Creates a client for the server at the base URL, sending its requests with `curl`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::with_transport(url, CurlTransport::default())
+    }
+}
+
+impl<T: Transport> BomExchangeClient<T> {
+    /// Creates a client for the server at the base URL, sending its requests with the transport.
+    pub fn with_transport(url: impl Into<String>, transport: T) -> Self {
+        Self {
+            url: url.into().trim_end_matches('/').to_string(),
+            headers: Vec::new(),
+            transport,
+        }
+    }
+
+    /// Adds a header sent with every request, e.g. to authenticate with an API key.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Publishes the BOM as a JSON document of the spec version.
+    pub fn publish(&self, bom: &Bom, version: SpecVersion) -> Result<(), ClientError> {
+        let mut body = Vec::new();
+        bom.clone().output_as_json(&mut body, version)?;
+        let body = String::from_utf8(body).expect("JSON output is valid UTF-8");
+
+        let mut request = self.request(Method::Post, format!("{}/v1/bom", self.url));
+        request
+            .headers
+            .push(("Content-Type".to_string(), media_type(version)));
+        request.body = Some(body);
+        self.send(request).map(|_| ())
+    }
+
+    /// Retrieves the BOM with the serial number, in the given or else in its latest version.
+    pub fn retrieve(&self, serial_number: &str, version: Option<u32>) -> Result<Bom, ClientError> {
+        let mut url = format!(
+            "{}/v1/bom?serialNumber={}",
+            self.url,
+            encode_query_value(serial_number)
+        );
+        if let Some(version) = version {
+            url.push_str(&format!("&version={version}"));
+        }
+        let mut request = self.request(Method::Get, url);
+        request.headers.push((
+            "Accept".to_string(),
+            "application/vnd.cyclonedx+json".to_string(),
+        ));
+        let response = self.send(request)?;
+        Ok(Bom::parse_from_json(response.body.as_slice())?)
+    }
+
+    /// Returns the serial numbers and versions of the BOMs describing the package URL.
+    pub fn search_by_purl(&self, purl: &str) -> Result<Vec<BomIdentifier>, ClientError> {
+        let url = format!("{}/v1/search?purl={}", self.url, encode_query_value(purl));
+        let mut request = self.request(Method::Get, url.clone());
+        request
+            .headers
+            .push(("Accept".to_string(), "application/json".to_string()));
+        let response = self.send(request)?;
+        serde_json::from_slice(&response.body)
+            .map_err(|error| ClientError::InvalidResponse { url, error })
+    }
+
+    fn request(&self, method: Method, url: String) -> Request {
+        Request {
+            method,
+            url,
+            headers: self.headers.clone(),
+            body: None,
+        }
+    }
+
+    fn send(&self, request: Request) -> Result<Response, ClientError> {
+        let response = self
+            .transport
+            .send(&request)
+            .map_err(|error| ClientError::Transport {
+                url: request.url.clone(),
+                error,
+            })?;
+        if (200..300).contains(&response.status) {
+            Ok(response)
+        } else {
+            Err(ClientError::Status {
+                url: request.url,
+                status: response.status,
+                body: String::from_utf8_lossy(&response.body).trim().to_string(),
+            })
+        }
+    }
+}
+
+fn media_type(version: SpecVersion) -> String {
+    format!("application/vnd.cyclonedx+json; version={version}")
+}
+
+/// Percent-encodes everything but the unreserved characters of RFC 3986
+fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            byte => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::cell::RefCell;
+
+    /// Records the requests and answers them with the queued responses
+    #[derive(Default)]
+    struct RecordingTransport {
+        requests: RefCell<Vec<Request>>,
+        responses: RefCell<Vec<Response>>,
+    }
+
+    impl Transport for RecordingTransport {
+        fn send(&self, request: &Request) -> std::io::Result<Response> {
+            self.requests.borrow_mut().push(request.clone());
+            Ok(self.responses.borrow_mut().remove(0))
+        }
+    }
+
+    fn client(responses: Vec<Response>) -> BomExchangeClient<RecordingTransport> {
+        let transport = RecordingTransport {
+            responses: RefCell::new(responses),
+            ..RecordingTransport::default()
+        };
+        BomExchangeClient::with_transport("https://bom-repository.example.com/", transport)
+            .with_header("X-Api-Key", "secret")
+    }
+
+    fn response(status: u16, body: &str) -> Response {
+        Response {
+            status,
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn it_should_publish_a_bom() {
+        let client = client(vec![response(201, "")]);
+
+        client
+            .publish(&Bom::default(), SpecVersion::V1_5)
+            .expect("Failed to publish");
+
+        let request = client.transport.requests.borrow()[0].clone();
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(request.url, "https://bom-repository.example.com/v1/bom");
+        assert_eq!(
+            request.headers,
+            vec![
+                ("X-Api-Key".to_string(), "secret".to_string()),
+                (
+                    "Content-Type".to_string(),
+                    "application/vnd.cyclonedx+json; version=1.5".to_string()
+                ),
+            ]
+        );
+        assert!(request.body.unwrap().contains(r#""specVersion": "1.5""#));
+    }
+
+    #[test]
+    fn it_should_retrieve_a_bom() {
+        let mut document = Vec::new();
+        Bom::default()
+            .output_as_json(&mut document, SpecVersion::V1_4)
+            .unwrap();
+        let client = client(vec![response(200, std::str::from_utf8(&document).unwrap())]);
+
+        let bom = client
+            .retrieve("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79", Some(2))
+            .expect("Failed to retrieve");
+
+        assert_eq!(bom.version, 1);
+        assert_eq!(
+            client.transport.requests.borrow()[0].url,
+            "https://bom-repository.example.com/v1/bom?serialNumber=urn%3Auuid%3A3e671687-395b-41f5-a30f-a58921a69b79&version=2"
+        );
+    }
+
+    #[test]
+    fn it_should_search_by_purl() {
+        let client = client(vec![response(
+            200,
+            r#"[{"serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79", "version": 3}]"#,
+        )]);
+
+        let found = client
+            .search_by_purl("pkg:cargo/serde@1.0.193")
+            .expect("Failed to search");
+
+        assert_eq!(
+            found,
+            vec![BomIdentifier {
+                serial_number: "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string(),
+                version: 3,
+            }]
+        );
+        assert_eq!(
+            client.transport.requests.borrow()[0].url,
+            "https://bom-repository.example.com/v1/search?purl=pkg%3Acargo%2Fserde%401.0.193"
+        );
+    }
+
+    #[test]
+    fn it_should_fail_for_error_responses() {
+        let client = client(vec![response(404, "Not found\n")]);
+
+        let error = client
+            .retrieve("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79", None)
+            .expect_err("Should have failed");
+
+        assert!(matches!(
+            &error,
+            ClientError::Status { status: 404, body, .. } if body == "Not found"
+        ));
+        assert!(!error.is_retryable());
+    }
+}
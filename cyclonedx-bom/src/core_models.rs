@@ -0,0 +1,186 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! The read-only models of [`cyclonedx-bom-core`](cyclonedx_bom_core), re-exported for code that
+//! inspects BOMs both with and without `std`, and the conversion of the complete models to them.
+//!
+//! ```
+//! use cyclonedx_bom::core_models;
+//! use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+//!
+//! let bom = Bom::default();
+//! let core = bom.to_core(SpecVersion::V1_5);
+//! assert_eq!(core.spec_version, core_models::SpecVersion::V1_5);
+//! ```
+
+pub use cyclonedx_bom_core::*;
+
+use crate::models;
+
+impl models::bom::Bom {
+    /// Converts the identifying parts of the BOM to the models of `cyclonedx-bom-core`, as
+    /// [`parse_json`] would read them from the JSON document of the spec version.
+    pub fn to_core(&self, spec_version: models::bom::SpecVersion) -> Bom {
+        Bom {
+            spec_version: spec_version.into(),
+            serial_number: self.serial_number.as_ref().map(ToString::to_string),
+            version: self.version,
+            metadata: self.metadata.as_ref().map(|metadata| Metadata {
+                timestamp: metadata.timestamp.as_ref().map(ToString::to_string),
+                component: metadata.component.as_ref().map(to_core_component),
+            }),
+            components: to_core_components(self.components.as_ref()),
+            dependencies: self
+                .dependencies
+                .iter()
+                .flat_map(|dependencies| &dependencies.0)
+                .map(|dependency| Dependency {
+                    dependency_ref: dependency.dependency_ref.clone(),
+                    depends_on: dependency.dependencies.clone(),
+                })
+                .collect(),
+            vulnerabilities: self
+                .vulnerabilities
+                .iter()
+                .flat_map(|vulnerabilities| &vulnerabilities.0)
+                .map(|vulnerability| Vulnerability {
+                    bom_ref: vulnerability.bom_ref.clone(),
+                    id: vulnerability.id.as_ref().map(ToString::to_string),
+                    affects: vulnerability
+                        .vulnerability_targets
+                        .iter()
+                        .flat_map(|targets| &targets.0)
+                        .map(|target| VulnerabilityTarget {
+                            bom_ref: target.bom_ref.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<models::bom::SpecVersion> for SpecVersion {
+    fn from(version: models::bom::SpecVersion) -> Self {
+        match version {
+            models::bom::SpecVersion::V1_3 => Self::V1_3,
+            models::bom::SpecVersion::V1_4 => Self::V1_4,
+            models::bom::SpecVersion::V1_5 => Self::V1_5,
+            models::bom::SpecVersion::V1_6 => Self::V1_6,
+        }
+    }
+}
+
+impl From<SpecVersion> for models::bom::SpecVersion {
+    fn from(version: SpecVersion) -> Self {
+        match version {
+            SpecVersion::V1_3 => Self::V1_3,
+            SpecVersion::V1_4 => Self::V1_4,
+            SpecVersion::V1_5 => Self::V1_5,
+            SpecVersion::V1_6 => Self::V1_6,
+        }
+    }
+}
+
+fn to_core_components(components: Option<&models::component::Components>) -> Vec<Component> {
+    components
+        .iter()
+        .flat_map(|components| &components.0)
+        .map(to_core_component)
+        .collect()
+}
+
+fn to_core_component(component: &models::component::Component) -> Component {
+    Component {
+        component_type: component.component_type.to_string(),
+        bom_ref: component.bom_ref.clone(),
+        group: component.group.as_ref().map(ToString::to_string),
+        name: component.name.to_string(),
+        version: component.version.as_ref().map(ToString::to_string),
+        purl: component.purl.as_ref().map(ToString::to_string),
+        hashes: component
+            .hashes
+            .iter()
+            .flat_map(|hashes| &hashes.0)
+            .map(|hash| Hash {
+                alg: hash.alg.to_string(),
+                content: hash.content.0.clone(),
+            })
+            .collect(),
+        components: to_core_components(component.components.as_ref()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::bom::SpecVersion as ModelSpecVersion;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_convert_like_the_core_parser_reads_the_json_output() {
+        let input = r#"{
+          "bomFormat": "CycloneDX",
+          "specVersion": "1.5",
+          "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+          "version": 2,
+          "metadata": {
+            "timestamp": "2024-01-01T00:00:00Z",
+            "component": {"type": "application", "bom-ref": "app", "name": "app", "version": "0.1.0"}
+          },
+          "components": [
+            {
+              "type": "library",
+              "bom-ref": "outer",
+              "group": "rust",
+              "name": "outer",
+              "purl": "pkg:cargo/outer@1.0.0",
+              "hashes": [{"alg": "SHA-256", "content": "25dd9975e68d0cb5aa1120c288333fc98731bd1dd12f561e468ea4728c042b89"}],
+              "components": [{"type": "library", "bom-ref": "inner", "name": "inner"}]
+            }
+          ],
+          "dependencies": [{"ref": "app", "dependsOn": ["outer"]}, {"ref": "outer", "dependsOn": []}],
+          "vulnerabilities": [{"bom-ref": "vuln", "id": "RUSTSEC-2024-0001", "affects": [{"ref": "inner"}]}]
+        }"#;
+        let bom = crate::models::bom::Bom::parse_from_json_v1_5(input.as_bytes())
+            .expect("Failed to parse BOM");
+
+        let mut output = Vec::new();
+        bom.clone()
+            .output_as_json(&mut output, ModelSpecVersion::V1_5)
+            .expect("Failed to write BOM");
+
+        assert_eq!(
+            bom.to_core(ModelSpecVersion::V1_5),
+            parse_json(&output).expect("Failed to parse the output")
+        );
+    }
+
+    #[test]
+    fn it_should_convert_spec_versions() {
+        for version in [
+            ModelSpecVersion::V1_3,
+            ModelSpecVersion::V1_4,
+            ModelSpecVersion::V1_5,
+            ModelSpecVersion::V1_6,
+        ] {
+            let core = SpecVersion::from(version);
+            assert_eq!(core.to_string(), version.to_string());
+            assert_eq!(ModelSpecVersion::from(core), version);
+        }
+    }
+}
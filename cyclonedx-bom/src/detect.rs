@@ -0,0 +1,353 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Detection of the format and spec version of a BOM without parsing the whole document.
+
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+use xml::reader::XmlEvent;
+use xml::EventReader;
+
+use crate::errors::{BomError, ParseError, XmlReadError};
+use crate::models::bom::SpecVersion;
+
+/// The serialization format of a BOM.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum Format {
+    Json,
+    Xml,
+}
+
+/// Detects the format and the spec version of a BOM, reading only as much of the document as
+/// necessary.
+///
+/// For XML this is the prologue up to the root element, whose namespace determines the version.
+/// For JSON the top level fields are scanned until `specVersion` is found, nested values are
+/// skipped without being parsed. If there is no `specVersion`, the version is taken from the
+/// `$schema` url.
+/// ```
+/// use cyclonedx_bom::{detect_version, Format};
+/// use cyclonedx_bom::models::bom::SpecVersion;
+///
+/// let input = r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "components": []}"#;
+/// let detected = detect_version(input.as_bytes())?;
+/// assert_eq!(detected, (Format::Json, SpecVersion::V1_5));
+/// # Ok::<(), cyclonedx_bom::errors::ParseError>(())
+/// ```
+pub fn detect_version<R: Read>(reader: R) -> Result<(Format, SpecVersion), ParseError> {
+    let mut reader = BufReader::new(reader);
+    match skip_whitespace(&mut reader)? {
+        Some(b'{') => Ok((Format::Json, JsonScanner::new(reader).spec_version()?)),
+        Some(b'<') => Ok((Format::Xml, xml_spec_version(reader)?)),
+        _ => Err(ParseError::UnknownFormat),
+    }
+}
+
+/// Skips a UTF-8 byte order mark and whitespace, returns the next byte without consuming it.
+fn skip_whitespace<R: BufRead>(reader: &mut R) -> Result<Option<u8>, ParseError> {
+    let mut at_start = true;
+    loop {
+        let buffer = reader.fill_buf().map_err(read_error)?;
+        if at_start && buffer.starts_with(b"\xef\xbb\xbf") {
+            reader.consume(3);
+            at_start = false;
+            continue;
+        }
+        at_start = false;
+
+        let Some(&byte) = buffer.first() else {
+            return Ok(None);
+        };
+        if !byte.is_ascii_whitespace() {
+            return Ok(Some(byte));
+        }
+        reader.consume(1);
+    }
+}
+
+fn read_error(error: std::io::Error) -> ParseError {
+    ParseError::JsonReadError(serde_json::Error::io(error).into())
+}
+
+/// Returns the spec version from the `specVersion` or, if missing, the `$schema` of a JSON BOM.
+pub(crate) fn json_spec_version(json: &Value) -> Result<SpecVersion, ParseError> {
+    if let Some(version) = json.get("specVersion") {
+        let version = version
+            .as_str()
+            .ok_or_else(|| BomError::UnsupportedSpecVersion(version.to_string()))?;
+        return Ok(SpecVersion::from_str(version)?);
+    }
+
+    let schema = json.get("$schema").and_then(Value::as_str);
+    schema_spec_version(schema)
+}
+
+fn schema_spec_version(schema: Option<&str>) -> Result<SpecVersion, ParseError> {
+    static SCHEMA_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"bom-(\d+\.\d+)\.schema\.json$").expect("Failed to compile regex.")
+    });
+
+    let schema = schema.ok_or_else(|| {
+        ParseError::UnknownSpecVersion("No field 'specVersion' or '$schema' found".to_string())
+    })?;
+    let version = SCHEMA_REGEX
+        .captures(schema)
+        .and_then(|captures| captures.get(1))
+        .ok_or_else(|| ParseError::UnknownSpecVersion(format!("Unknown schema '{schema}'")))?;
+    Ok(SpecVersion::from_str(version.as_str())?)
+}
+
+/// Returns the spec version from the namespace of the root element of an XML BOM.
+pub(crate) fn xml_spec_version<R: Read>(reader: R) -> Result<SpecVersion, ParseError> {
+    const NAMESPACE_PREFIX: &str = "http://cyclonedx.org/schema/bom/";
+
+    for event in EventReader::new(reader) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                let namespace = name.namespace.unwrap_or_default();
+                let version = namespace.strip_prefix(NAMESPACE_PREFIX).ok_or_else(|| {
+                    ParseError::UnknownSpecVersion(format!("Unknown namespace '{namespace}'"))
+                })?;
+                return Ok(SpecVersion::from_str(version)?);
+            }
+            Ok(_) => (),
+            Err(error) => {
+                return Err(XmlReadError::ElementReadError {
+                    error,
+                    element: "bom".to_string(),
+                }
+                .into())
+            }
+        }
+    }
+
+    Err(ParseError::UnknownSpecVersion(
+        "No root element found".to_string(),
+    ))
+}
+
+/// A minimal scanner over the top level fields of a JSON object.
+struct JsonScanner<R: BufRead> {
+    reader: R,
+}
+
+impl<R: BufRead> JsonScanner<R> {
+    fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    fn spec_version(mut self) -> Result<SpecVersion, ParseError> {
+        let mut schema = None;
+        self.expect(b'{')?;
+        if self.peek_token()? == b'}' {
+            return schema_spec_version(None);
+        }
+
+        loop {
+            self.peek_token()?;
+            self.expect(b'"')?;
+            let key = self.string()?;
+            self.peek_token()?;
+            self.expect(b':')?;
+
+            match key.as_str() {
+                "specVersion" | "$schema" => {
+                    let value = match self.peek_token()? {
+                        b'"' => {
+                            self.expect(b'"')?;
+                            self.string()?
+                        }
+                        _ => {
+                            return Err(BomError::UnsupportedSpecVersion(format!(
+                                "Field '{key}' is not a string"
+                            ))
+                            .into())
+                        }
+                    };
+                    if key == "specVersion" {
+                        return Ok(SpecVersion::from_str(&value)?);
+                    }
+                    schema = Some(value);
+                }
+                _ => self.skip_value()?,
+            }
+
+            match self.peek_token()? {
+                b',' => self.expect(b',')?,
+                b'}' => return schema_spec_version(schema.as_deref()),
+                other => return Err(self.syntax_error(other)),
+            }
+        }
+    }
+
+    /// Skips whitespace and returns the next byte without consuming it.
+    fn peek_token(&mut self) -> Result<u8, ParseError> {
+        skip_whitespace(&mut self.reader)?.ok_or_else(|| {
+            ParseError::UnknownSpecVersion("Unexpected end of the document".to_string())
+        })
+    }
+
+    fn next_byte(&mut self) -> Result<u8, ParseError> {
+        let byte = self.peek_byte()?;
+        self.reader.consume(1);
+        Ok(byte)
+    }
+
+    fn peek_byte(&mut self) -> Result<u8, ParseError> {
+        let buffer = self.reader.fill_buf().map_err(read_error)?;
+        buffer.first().copied().ok_or_else(|| {
+            ParseError::UnknownSpecVersion("Unexpected end of the document".to_string())
+        })
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), ParseError> {
+        match self.next_byte()? {
+            byte if byte == expected => Ok(()),
+            other => Err(self.syntax_error(other)),
+        }
+    }
+
+    fn syntax_error(&self, byte: u8) -> ParseError {
+        ParseError::UnknownSpecVersion(format!("Unexpected character '{}'", byte as char))
+    }
+
+    /// Reads the rest of a string whose opening quote was consumed.
+    fn string(&mut self) -> Result<String, ParseError> {
+        let mut bytes = vec![b'"'];
+        loop {
+            let byte = self.next_byte()?;
+            bytes.push(byte);
+            match byte {
+                b'\\' => bytes.push(self.next_byte()?),
+                b'"' => break,
+                _ => (),
+            }
+        }
+        serde_json::from_slice(&bytes).map_err(|error| ParseError::JsonReadError(error.into()))
+    }
+
+    /// Skips a complete value of any type.
+    fn skip_value(&mut self) -> Result<(), ParseError> {
+        let mut depth = 0usize;
+        loop {
+            match self.peek_token()? {
+                b'"' => {
+                    self.expect(b'"')?;
+                    self.string()?;
+                }
+                b'{' | b'[' => {
+                    self.next_byte()?;
+                    depth += 1;
+                    continue;
+                }
+                b'}' | b']' if depth > 0 => {
+                    self.next_byte()?;
+                    depth -= 1;
+                }
+                b',' | b':' if depth > 0 => {
+                    self.next_byte()?;
+                    continue;
+                }
+                _ => {
+                    // numbers, booleans and null
+                    while !matches!(self.peek_byte()?, b',' | b'}' | b']')
+                        && !self.peek_byte()?.is_ascii_whitespace()
+                    {
+                        self.next_byte()?;
+                    }
+                }
+            }
+
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_detect_json_versions() {
+        let input = r#"
+            {
+                "$schema": "http://cyclonedx.org/schema/bom-1.4.schema.json",
+                "bomFormat": "CycloneDX",
+                "metadata": {"tools": [{"name": "a \"quoted\" name"}], "timestamp": null},
+                "version": 1,
+                "specVersion": "1.4"
+            }"#;
+        assert_eq!(
+            detect_version(input.as_bytes()).unwrap(),
+            (Format::Json, SpecVersion::V1_4)
+        );
+
+        let input =
+            r#"{"$schema": "http://cyclonedx.org/schema/bom-1.5.schema.json", "x": [1, true]}"#;
+        assert_eq!(
+            detect_version(input.as_bytes()).unwrap(),
+            (Format::Json, SpecVersion::V1_5)
+        );
+    }
+
+    #[test]
+    fn it_should_stop_reading_after_the_spec_version() {
+        let input = r#"{"specVersion": "1.6", "components": [ this is not valid JSON"#;
+        assert_eq!(
+            detect_version(input.as_bytes()).unwrap(),
+            (Format::Json, SpecVersion::V1_6)
+        );
+    }
+
+    #[test]
+    fn it_should_detect_xml_versions() {
+        let input = "\u{feff}<?xml version=\"1.0\"?>\n<bom xmlns=\"http://cyclonedx.org/schema/bom/1.3\" version=\"1\"><components>";
+        assert_eq!(
+            detect_version(input.as_bytes()).unwrap(),
+            (Format::Xml, SpecVersion::V1_3)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_unsupported_versions() {
+        assert!(matches!(
+            detect_version(r#"{"specVersion": "1.2"}"#.as_bytes()),
+            Err(ParseError::BomError(BomError::UnsupportedSpecVersion(_)))
+        ));
+        assert!(matches!(
+            detect_version(r#"{"bomFormat": "CycloneDX"}"#.as_bytes()),
+            Err(ParseError::UnknownSpecVersion(_))
+        ));
+        assert!(matches!(
+            detect_version(r#"<bom xmlns="http://example.com"/>"#.as_bytes()),
+            Err(ParseError::UnknownSpecVersion(_))
+        ));
+        assert!(matches!(
+            detect_version("bom".as_bytes()),
+            Err(ParseError::UnknownFormat)
+        ));
+    }
+}
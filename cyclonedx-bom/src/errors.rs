@@ -129,6 +129,129 @@ pub enum XmlReadError {
     },
 }
 
+/// Error of parsing a BOM whose format and spec version are detected from the input.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseError {
+    #[error("Failed to parse JSON: {0}")]
+    JsonReadError(#[from] JsonReadError),
+
+    #[error("Failed to parse XML: {0}")]
+    XmlReadError(#[from] XmlReadError),
+
+    #[error("Input is neither a JSON nor an XML document")]
+    UnknownFormat,
+
+    #[error("Failed to detect the spec version: {0}")]
+    UnknownSpecVersion(String),
+
+    #[error("{0}")]
+    BomError(#[from] BomError),
+}
+
+/// Error of reading or writing a [`Bundle`](crate::bundle::Bundle).
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BundleError {
+    #[error("Failed to read or write the archive: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Invalid tar archive: {0}")]
+    InvalidArchive(String),
+
+    #[error("The archive has no {}", crate::bundle::INDEX_PATH)]
+    MissingIndex,
+
+    #[error("Invalid bundle index: {0}")]
+    InvalidIndex(#[from] serde_json::Error),
+
+    #[error("The index lists {0} which is missing from the archive")]
+    MissingEntry(String),
+
+    #[error("Failed to parse {path}: {error}")]
+    Parse {
+        path: String,
+        #[source]
+        error: ParseError,
+    },
+
+    #[error("Failed to write {path}: {error}")]
+    Write { path: String, error: String },
+
+    #[error("The path {0} is longer than the 100 bytes of a tar header")]
+    PathTooLong(String),
+}
+
+/// Error of a request of the [`BomExchangeClient`](crate::client::BomExchangeClient).
+#[cfg(feature = "client")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ClientError {
+    #[error("Failed to send the request to {url}: {error}")]
+    Transport {
+        url: String,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("The request to {url} failed with HTTP {status} {body}")]
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+
+    #[error("Invalid response from {url}: {error}")]
+    InvalidResponse {
+        url: String,
+        #[source]
+        error: serde_json::Error,
+    },
+
+    #[error("Failed to write the BOM: {0}")]
+    JsonWriteError(#[from] JsonWriteError),
+
+    #[error("Failed to read the BOM: {0}")]
+    JsonReadError(#[from] JsonReadError),
+}
+
+#[cfg(feature = "client")]
+impl ClientError {
+    /// Returns `true` for failures that may pass when the request is repeated, i.e. when no
+    /// response was received, the server was overloaded or it failed with a server error.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Transport { .. } => true,
+            Self::Status { status, .. } => *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// Error of parsing or writing a BOM with the async variants of [`async_io`](crate::async_io).
+#[cfg(feature = "tokio")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum AsyncIoError {
+    #[error("Failed to parse JSON: {0}")]
+    JsonReadError(#[from] JsonReadError),
+
+    #[error("Failed to parse XML: {0}")]
+    XmlReadError(#[from] XmlReadError),
+
+    #[error("Failed to write JSON: {0}")]
+    JsonWriteError(#[from] JsonWriteError),
+
+    #[error("Failed to write XML: {0}")]
+    XmlWriteError(#[from] XmlWriteError),
+
+    #[error("Failed to write the output: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("The blocking task failed: {0}")]
+    TaskError(#[from] tokio::task::JoinError),
+}
+
 impl XmlReadError {
     pub fn required_data_missing(required_field: &str, element: &OwnedName) -> Self {
         Self::RequiredDataMissing {
@@ -137,3 +260,31 @@ impl XmlReadError {
         }
     }
 }
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum OsvReadError {
+    #[error("Failed to parse the OSV advisory: {0}")]
+    JsonError(#[from] serde_json::Error),
+}
+
+#[cfg(feature = "spdx-interop")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpdxInteropError {
+    #[error("Failed to convert the SPDX document: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("Unsupported SPDX version {0}, only SPDX 2.x documents can be converted")]
+    UnsupportedSpdxVersion(String),
+}
+
+/// The error of parsing an enum of the models from a string it has no variant for, e.g. with
+/// [`HashAlgorithm::from_str`](crate::models::hash::HashAlgorithm).
+#[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
+#[error("Unknown {kind} '{value}'")]
+pub struct UnknownValueError {
+    /// The kind of value, e.g. `hash algorithm`
+    pub kind: &'static str,
+    pub value: String,
+}
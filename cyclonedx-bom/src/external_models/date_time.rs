@@ -17,11 +17,15 @@
  */
 
 use std::convert::TryFrom;
+use std::str::FromStr;
 
 use thiserror::Error;
-use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use time::{
+    format_description::well_known::{Iso8601, Rfc3339},
+    OffsetDateTime, UtcOffset,
+};
 
-use crate::validation::ValidationError;
+use crate::validation::{code, ValidationError};
 
 /// For the purposes of CycloneDX SBOM documents, `DateTime` is a ISO8601 formatted timestamp
 ///
@@ -38,22 +42,101 @@ use crate::validation::ValidationError;
 ///
 /// assert_eq!(date_time.to_string(), timestamp);
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateTime(pub(crate) String);
 
 pub fn validate_date_time(date_time: &DateTime) -> Result<(), ValidationError> {
     if OffsetDateTime::parse(&date_time.0, &Iso8601::DEFAULT).is_err() {
-        return Err("DateTime does not conform to ISO 8601".into());
+        return Err(
+            ValidationError::new("DateTime does not conform to ISO 8601")
+                .with_code(code::INVALID_DATE_TIME),
+        );
     }
     Ok(())
 }
 
 impl DateTime {
+    /// Returns the current time in UTC
     pub fn now() -> Result<Self, DateTimeError> {
-        let now = OffsetDateTime::now_utc()
-            .format(&Iso8601::DEFAULT)
-            .map_err(|_| DateTimeError::FailedCurrentTime)?;
-        Ok(Self(now))
+        TimestampSource::Utc.timestamp()
+    }
+}
+
+/// Controls how the timestamp of a newly generated document is created.
+///
+/// A source can be parsed from a string, which is either `utc`, a fixed offset like `+02:00` or
+/// `-05:30`, or a complete ISO 8601 timestamp that is used as is.
+///
+/// ```
+/// use cyclonedx_bom::external_models::date_time::TimestampSource;
+///
+/// let source: TimestampSource = "1970-01-01T00:00:00Z".parse().expect("Failed to parse source");
+/// let timestamp = source.timestamp().expect("Failed to create timestamp");
+///
+/// assert_eq!(timestamp.to_string(), "1970-01-01T00:00:00Z");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimestampSource {
+    /// The current time in UTC
+    #[default]
+    Utc,
+    /// The current time with a fixed offset from UTC
+    FixedOffset { hours: i8, minutes: i8 },
+    /// A fixed timestamp, e.g. to make generated documents reproducible
+    Fixed(DateTime),
+}
+
+impl TimestampSource {
+    /// Creates a timestamp according to the source
+    ///
+    /// # Errors
+    ///
+    /// Returns an error variant if the offset is out of range or the current time cannot be
+    /// formatted
+    pub fn timestamp(&self) -> Result<DateTime, DateTimeError> {
+        let now = match self {
+            Self::Utc => OffsetDateTime::now_utc(),
+            Self::FixedOffset { hours, minutes } => {
+                let offset = UtcOffset::from_hms(*hours, *minutes, 0)
+                    .map_err(|e| DateTimeError::InvalidOffset(e.to_string()))?;
+                OffsetDateTime::now_utc().to_offset(offset)
+            }
+            Self::Fixed(date_time) => return Ok(date_time.clone()),
+        };
+
+        DateTime::try_from(now).map_err(|_| DateTimeError::FailedCurrentTime)
+    }
+}
+
+impl FromStr for TimestampSource {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("utc") || s == "Z" {
+            return Ok(Self::Utc);
+        }
+
+        if let Some((sign, offset)) = s
+            .strip_prefix('+')
+            .map(|offset| (1, offset))
+            .or_else(|| s.strip_prefix('-').map(|offset| (-1, offset)))
+        {
+            let invalid_offset =
+                || DateTimeError::InvalidOffset(format!("Expected +HH:MM, got {s}"));
+            let (hours, minutes) = offset.split_once(':').ok_or_else(invalid_offset)?;
+            let hours: i8 = hours.parse().map_err(|_| invalid_offset())?;
+            let minutes: i8 = minutes.parse().map_err(|_| invalid_offset())?;
+            UtcOffset::from_hms(sign * hours, sign * minutes, 0)
+                .map_err(|e| DateTimeError::InvalidOffset(e.to_string()))?;
+
+            return Ok(Self::FixedOffset {
+                hours: sign * hours,
+                minutes: sign * minutes,
+            });
+        }
+
+        DateTime::try_from(s.to_string()).map(Self::Fixed)
     }
 }
 
@@ -71,6 +154,64 @@ impl TryFrom<String> for DateTime {
     }
 }
 
+/// Formats the timestamp as RFC 3339, which is the format used for all generated timestamps.
+///
+/// ```
+/// use cyclonedx_bom::external_models::date_time::DateTime;
+/// use std::convert::TryFrom;
+/// use time::{OffsetDateTime, UtcOffset};
+///
+/// let offset = UtcOffset::from_hms(2, 0, 0).expect("Failed to create offset");
+/// let date_time = DateTime::try_from(OffsetDateTime::UNIX_EPOCH.to_offset(offset))
+///     .expect("Failed to format");
+///
+/// assert_eq!(date_time.to_string(), "1970-01-01T02:00:00+02:00");
+/// ```
+impl TryFrom<OffsetDateTime> for DateTime {
+    type Error = DateTimeError;
+
+    fn try_from(value: OffsetDateTime) -> Result<Self, Self::Error> {
+        value
+            .format(&Rfc3339)
+            .map(Self)
+            .map_err(|e| DateTimeError::InvalidDateTime(e.to_string()))
+    }
+}
+
+impl TryFrom<&DateTime> for OffsetDateTime {
+    type Error = DateTimeError;
+
+    fn try_from(value: &DateTime) -> Result<Self, Self::Error> {
+        OffsetDateTime::parse(&value.0, &Iso8601::DEFAULT).map_err(|e| {
+            DateTimeError::InvalidDateTime(format!("DateTime does not conform to ISO 8601: {}", e))
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for DateTime
+where
+    Tz::Offset: std::fmt::Display,
+{
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        Self(value.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<&DateTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = DateTimeError;
+
+    fn try_from(value: &DateTime) -> Result<Self, Self::Error> {
+        let date_time = OffsetDateTime::try_from(value)?;
+        let offset = chrono::FixedOffset::east_opt(date_time.offset().whole_seconds())
+            .ok_or_else(|| DateTimeError::InvalidOffset(date_time.offset().to_string()))?;
+        chrono::DateTime::from_timestamp(date_time.unix_timestamp(), date_time.nanosecond())
+            .map(|utc| utc.with_timezone(&offset))
+            .ok_or_else(|| DateTimeError::InvalidDateTime(value.0.clone()))
+    }
+}
+
 impl std::fmt::Display for DateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.0)
@@ -90,12 +231,16 @@ pub enum DateTimeError {
 
     #[error("Failed to get current time")]
     FailedCurrentTime,
+
+    #[error("Invalid UTC offset: {}", .0)]
+    InvalidOffset(String),
 }
 
 #[cfg(test)]
 mod test {
     use pretty_assertions::assert_eq;
 
+    use super::*;
     use crate::{external_models::validate_date_time, prelude::DateTime};
 
     #[test]
@@ -106,6 +251,34 @@ mod test {
         assert!(validation_result.is_ok());
     }
 
+    #[test]
+    fn it_should_convert_from_and_to_offset_date_time() {
+        let date_time = DateTime::try_from(OffsetDateTime::UNIX_EPOCH).expect("Failed to format");
+        assert_eq!(date_time, DateTime("1970-01-01T00:00:00Z".to_string()));
+
+        let actual =
+            OffsetDateTime::try_from(&DateTime("1969-06-28T01:20:00.00-04:00".to_string()))
+                .expect("Failed to parse");
+        assert_eq!(actual.unix_timestamp(), -16_137_600);
+        assert_eq!(actual.offset().whole_hours(), -4);
+
+        assert!(OffsetDateTime::try_from(&DateTime("invalid date".to_string())).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn it_should_convert_from_and_to_chrono() {
+        let chrono_date_time = chrono::DateTime::parse_from_rfc3339("1969-06-28T01:20:00-04:00")
+            .expect("Failed to parse");
+
+        let date_time = DateTime::from(chrono_date_time);
+        assert_eq!(date_time, DateTime("1969-06-28T01:20:00-04:00".to_string()));
+
+        let actual = chrono::DateTime::<chrono::FixedOffset>::try_from(&date_time)
+            .expect("Failed to convert");
+        assert_eq!(actual, chrono_date_time);
+    }
+
     #[test]
     fn invalid_datetimes_should_fail_validation() {
         let validation_result = validate_date_time(&DateTime("invalid date".to_string()));
@@ -115,4 +288,47 @@ mod test {
             Err("DateTime does not conform to ISO 8601".into()),
         );
     }
+
+    #[test]
+    fn it_should_parse_timestamp_sources() {
+        assert_eq!("utc".parse(), Ok(TimestampSource::Utc));
+        assert_eq!(
+            "+02:00".parse(),
+            Ok(TimestampSource::FixedOffset {
+                hours: 2,
+                minutes: 0
+            })
+        );
+        assert_eq!(
+            "-05:30".parse(),
+            Ok(TimestampSource::FixedOffset {
+                hours: -5,
+                minutes: -30
+            })
+        );
+        assert_eq!(
+            "2024-01-01T12:00:00Z".parse(),
+            Ok(TimestampSource::Fixed(DateTime(
+                "2024-01-01T12:00:00Z".to_string()
+            )))
+        );
+        assert!("+26:00".parse::<TimestampSource>().is_err());
+        assert!("+0200".parse::<TimestampSource>().is_err());
+        assert!("tomorrow".parse::<TimestampSource>().is_err());
+    }
+
+    #[test]
+    fn it_should_create_timestamps_with_offset() {
+        let utc = TimestampSource::Utc.timestamp().unwrap();
+        assert!(utc.to_string().ends_with('Z'));
+
+        let offset = TimestampSource::FixedOffset {
+            hours: 2,
+            minutes: 0,
+        }
+        .timestamp()
+        .unwrap();
+        assert!(offset.to_string().ends_with("+02:00"));
+        assert!(validate_date_time(&offset).is_ok());
+    }
 }
@@ -16,14 +16,42 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use crate::validation::ValidationError;
+use crate::validation::{code, ValidationError};
 use std::fmt::Display;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+use thiserror::Error;
+
+static NORMALIZE_ON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables normalizing every [`NormalizedString`] when a BOM is written.
+///
+/// When enabled, invalid characters are replaced with spaces on output and
+/// [`validate_normalized_string`] no longer reports them, because the written
+/// BOM will not contain them. This is a process-wide setting and is disabled by default.
+/// ```
+/// use cyclonedx_bom::external_models::normalized_string::{
+///     normalize_on_output, set_normalize_on_output,
+/// };
+///
+/// set_normalize_on_output(true);
+/// assert!(normalize_on_output());
+/// # set_normalize_on_output(false);
+/// ```
+pub fn set_normalize_on_output(enabled: bool) {
+    NORMALIZE_ON_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether [`NormalizedString`]s are normalized when a BOM is written.
+pub fn normalize_on_output() -> bool {
+    NORMALIZE_ON_OUTPUT.load(Ordering::Relaxed)
+}
 
 /// A string that does not contain carriage return, line feed, or tab characters
 ///
 /// Defined via the [XML schema](https://www.w3.org/TR/xmlschema-2/#normalizedString)
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NormalizedString(pub(crate) String);
 
 impl NormalizedString {
@@ -35,8 +63,32 @@ impl NormalizedString {
     /// assert_eq!(normalized_string.to_string(), "A string with invalid characters".to_string());
     /// ```
     pub fn new(value: &str) -> Self {
-        let value = value.replace("\r\n", " ").replace(['\r', '\n', '\t'], " ");
-        NormalizedString(value)
+        Self::normalize(value)
+    }
+
+    /// Construct a `NormalizedString` by replacing all of the invalid characters with spaces
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let normalized_string = NormalizedString::normalize("Multi-line\ndescription");
+    /// assert_eq!(normalized_string.to_string(), "Multi-line description".to_string());
+    /// ```
+    pub fn normalize(value: &str) -> Self {
+        NormalizedString(replace_invalid_characters(value))
+    }
+
+    /// Construct a `NormalizedString`, failing if the value contains invalid characters
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// assert!(NormalizedString::new_strict("A valid string").is_ok());
+    /// assert!(NormalizedString::new_strict("An\ninvalid string").is_err());
+    /// ```
+    pub fn new_strict(value: &str) -> Result<Self, NormalizedStringError> {
+        if contains_invalid_characters(value) {
+            return Err(NormalizedStringError::InvalidCharacters(value.to_string()));
+        }
+        Ok(NormalizedString(value.to_string()))
     }
 
     /// Allow for the existence of invalid inputs from other data sources
@@ -53,7 +105,11 @@ impl From<&str> for NormalizedString {
 
 impl From<NormalizedString> for String {
     fn from(value: NormalizedString) -> Self {
-        value.0
+        if normalize_on_output() {
+            replace_invalid_characters(&value.0)
+        } else {
+            value.0
+        }
     }
 }
 
@@ -79,22 +135,39 @@ impl AsRef<str> for NormalizedString {
 
 impl Display for NormalizedString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        if normalize_on_output() {
+            write!(f, "{}", replace_invalid_characters(&self.0))
+        } else {
+            write!(f, "{}", self.0)
+        }
     }
 }
 
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NormalizedStringError {
+    #[error("NormalizedString contains invalid characters \\r \\n \\t or \\r\\n: {}", .0)]
+    InvalidCharacters(String),
+}
+
+fn replace_invalid_characters(value: &str) -> String {
+    value.replace("\r\n", " ").replace(['\r', '\n', '\t'], " ")
+}
+
+fn contains_invalid_characters(value: &str) -> bool {
+    value.contains(['\r', '\n', '\t'])
+}
+
 /// Validates a [`NormalizedString`].
+///
+/// Passes for any value while [`normalize_on_output`] is enabled.
 pub fn validate_normalized_string(
     normalized_string: &NormalizedString,
 ) -> Result<(), ValidationError> {
-    if normalized_string.contains("\r\n")
-        || normalized_string.contains('\r')
-        || normalized_string.contains('\n')
-        || normalized_string.contains('\t')
-    {
+    if !normalize_on_output() && contains_invalid_characters(normalized_string) {
         return Err(ValidationError::new(
             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
-        ));
+        )
+        .with_code(code::INVALID_NORMALIZED_STRING));
     }
 
     Ok(())
@@ -119,6 +192,24 @@ mod test {
             NormalizedString("carriage returns and linefeeds".to_string()),
             NormalizedString::new("carriage\r\nreturns\rand\nlinefeeds")
         );
+        assert_eq!(
+            NormalizedString::new("carriage\r\nreturns"),
+            NormalizedString::normalize("carriage\r\nreturns")
+        );
+    }
+
+    #[test]
+    fn it_should_construct_strict_strings() {
+        assert_eq!(
+            NormalizedString::new_strict("no_whitespace"),
+            Ok(NormalizedString("no_whitespace".to_string()))
+        );
+        assert_eq!(
+            NormalizedString::new_strict("line\nfeed"),
+            Err(NormalizedStringError::InvalidCharacters(
+                "line\nfeed".to_string()
+            ))
+        );
     }
 
     #[test]
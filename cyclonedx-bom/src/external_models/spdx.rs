@@ -18,10 +18,16 @@
 
 use std::convert::TryFrom;
 
-use spdx::{Expression, ParseMode};
+use spdx::{
+    expression::{ExprNode, Operator},
+    Expression, LicenseItem, LicenseReq, ParseMode,
+};
 use thiserror::Error;
 
-use crate::{models::bom::BomReference, validation::ValidationError};
+use crate::{
+    models::bom::BomReference,
+    validation::{code, ValidationError},
+};
 
 /// An identifier for a single, specific license
 ///
@@ -36,7 +42,8 @@ use crate::{models::bom::BomReference, validation::ValidationError};
 /// assert_eq!(spdx_identifier.to_string(), identifier);
 /// # Ok::<(), SpdxIdentifierError>(())
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpdxIdentifier(pub(crate) String);
 
 impl SpdxIdentifier {
@@ -82,7 +89,8 @@ impl std::fmt::Display for SpdxIdentifier {
 
 pub fn validate_spdx_identifier(identifier: &SpdxIdentifier) -> Result<(), ValidationError> {
     match SpdxIdentifier::try_from(identifier.0.to_string()) {
-        Err(_error) => Err(ValidationError::new("SPDX identifier is not valid")),
+        Err(_error) => Err(ValidationError::new("SPDX identifier is not valid")
+            .with_code(code::INVALID_SPDX_IDENTIFIER)),
         _ => Ok(()),
     }
 }
@@ -110,7 +118,8 @@ pub enum SpdxIdentifierError {
 /// assert_eq!(spdx_expression.to_string(), expression);
 /// # Ok::<(), SpdxExpressionError>(())
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpdxExpression {
     pub(crate) bom_ref: Option<BomReference>,
     pub(crate) expression: String,
@@ -149,6 +158,167 @@ impl SpdxExpression {
         }
     }
 
+    /// Returns the licenses referenced in the expression in the order of their first occurrence,
+    /// either as SPDX license identifiers or as `LicenseRef-` references
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
+    ///
+    /// let spdx_expression = SpdxExpression::new("MIT OR (Apache-2.0 WITH LLVM-exception AND MIT)");
+    /// assert_eq!(spdx_expression.license_ids()?, vec!["MIT", "Apache-2.0"]);
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn license_ids(&self) -> Result<Vec<String>, SpdxExpressionError> {
+        let mut license_ids: Vec<String> = Vec::new();
+        for requirement in self.parse()?.requirements() {
+            let license_id = match &requirement.req.license {
+                LicenseItem::Spdx { id, .. } => id.name.to_string(),
+                other => other.to_string(),
+            };
+            if !license_ids.contains(&license_id) {
+                license_ids.push(license_id);
+            }
+        }
+        Ok(license_ids)
+    }
+
+    /// Returns `true` if the expression can be satisfied with OSI approved licenses only
+    pub fn is_osi_approved(&self) -> Result<bool, SpdxExpressionError> {
+        Ok(self
+            .parse()?
+            .evaluate(|req| req.license.id().is_some_and(|id| id.is_osi_approved())))
+    }
+
+    /// Returns `true` if the expression can be satisfied with licenses the FSF considers free only
+    pub fn is_fsf_free_libre(&self) -> Result<bool, SpdxExpressionError> {
+        Ok(self
+            .parse()?
+            .evaluate(|req| req.license.id().is_some_and(|id| id.is_fsf_free_libre())))
+    }
+
+    /// Returns `true` if the expression can be satisfied with the allowed licenses only
+    ///
+    /// An allowed license is either a license identifier, which allows the license with any
+    /// exception, or a license with a specific exception, e.g. `Apache-2.0 WITH LLVM-exception`.
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
+    ///
+    /// let spdx_expression = SpdxExpression::new("MIT OR GPL-3.0-only");
+    /// assert!(spdx_expression.satisfies(&["MIT", "Apache-2.0"])?);
+    /// assert!(!spdx_expression.satisfies(&["Apache-2.0"])?);
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn satisfies<A: AsRef<str>>(&self, allowed: &[A]) -> Result<bool, SpdxExpressionError> {
+        let allowed = Self::normalize_requirements(allowed);
+
+        Ok(self
+            .parse()?
+            .evaluate(|req| Self::matches_any(req, &allowed)))
+    }
+
+    /// Returns `true` if the expression cannot be satisfied without one of the denied licenses
+    ///
+    /// The denied licenses are matched like the allowed licenses of [`Self::satisfies`].
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
+    ///
+    /// assert!(!SpdxExpression::new("MIT OR GPL-3.0-only").requires_any(&["GPL-3.0-only"])?);
+    /// assert!(SpdxExpression::new("MIT AND GPL-3.0-only").requires_any(&["GPL-3.0-only"])?);
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn requires_any<A: AsRef<str>>(&self, denied: &[A]) -> Result<bool, SpdxExpressionError> {
+        let denied = Self::normalize_requirements(denied);
+
+        Ok(!self
+            .parse()?
+            .evaluate(|req| !Self::matches_any(req, &denied)))
+    }
+
+    fn matches_any(req: &LicenseReq, licenses: &[String]) -> bool {
+        let license = req.license.to_string();
+        licenses
+            .iter()
+            .any(|candidate| *candidate == license || *candidate == req.to_string())
+    }
+
+    /// Returns the expression in its canonical form
+    ///
+    /// Operators and `WITH` exceptions are written in upper case, a `/` is replaced by `OR`
+    /// and redundant parentheses are removed.
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
+    ///
+    /// let spdx_expression = SpdxExpression::new("(MIT or Apache-2.0 with LLVM-exception)");
+    /// assert_eq!(
+    ///     spdx_expression.normalize()?.to_string(),
+    ///     "MIT OR Apache-2.0 WITH LLVM-exception"
+    /// );
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn normalize(&self) -> Result<Self, SpdxExpressionError> {
+        let expression = Expression::parse_mode(&self.expression, ParseMode::LAX)
+            .map_err(|e| SpdxExpressionError::InvalidLaxSpdxExpression(format!("{}", e.reason)))?;
+
+        // The expression is stored in postfix order, each entry holds the operator joining it.
+        let mut stack: Vec<(String, Option<Operator>)> = Vec::new();
+        for node in expression.iter() {
+            match node {
+                ExprNode::Req(req) => stack.push((req.req.to_string(), None)),
+                ExprNode::Op(operator) => {
+                    let (right, right_operator) = stack.pop().unwrap_or_default();
+                    let (left, left_operator) = stack.pop().unwrap_or_default();
+                    let wrap = |term: String, term_operator: Option<Operator>| match term_operator {
+                        Some(Operator::Or) if *operator == Operator::And => format!("({term})"),
+                        _ => term,
+                    };
+                    let joined = match operator {
+                        Operator::And => "AND",
+                        Operator::Or => "OR",
+                    };
+                    stack.push((
+                        format!(
+                            "{} {joined} {}",
+                            wrap(left, left_operator),
+                            wrap(right, right_operator)
+                        ),
+                        Some(*operator),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self {
+            bom_ref: self.bom_ref.clone(),
+            expression: stack.pop().map(|(term, _)| term).unwrap_or_default(),
+        })
+    }
+
+    fn parse(&self) -> Result<Expression, SpdxExpressionError> {
+        Expression::parse(&self.expression)
+            .map_err(|e| SpdxExpressionError::InvalidSpdxExpression(format!("{}", e.reason)))
+    }
+
+    /// Normalizes the single licenses of an allow- or deny-list, so that they can be compared
+    /// with the requirements of a parsed expression.
+    fn normalize_requirements<A: AsRef<str>>(licenses: &[A]) -> Vec<String> {
+        licenses
+            .iter()
+            .map(|license| {
+                let license = license.as_ref();
+                match Expression::parse_mode(license, ParseMode::LAX) {
+                    Ok(expression) => match expression.iter().collect::<Vec<_>>().as_slice() {
+                        [ExprNode::Req(req)] => req.req.to_string(),
+                        _ => license.to_string(),
+                    },
+                    Err(_) => license.to_string(),
+                }
+            })
+            .collect()
+    }
+
     fn convert_lax(self) -> Result<Self, SpdxExpressionError> {
         let converted = self.expression.replace('/', " OR ");
 
@@ -187,7 +357,8 @@ impl std::fmt::Display for SpdxExpression {
 
 pub fn validate_spdx_expression(expression: &SpdxExpression) -> Result<(), ValidationError> {
     if Expression::parse(&expression.expression).is_err() {
-        return Err(ValidationError::new("SPDX expression is not valid"));
+        return Err(ValidationError::new("SPDX expression is not valid")
+            .with_code(code::INVALID_SPDX_EXPRESSION));
     }
     Ok(())
 }
@@ -290,6 +461,85 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_list_the_license_ids_of_an_spdx_expression() {
+        let actual = SpdxExpression::new(
+            "(MIT OR Apache-2.0 WITH LLVM-exception) AND LicenseRef-Proprietary AND MIT",
+        )
+        .license_ids()
+        .expect("Failed to list the license ids");
+
+        assert_eq!(actual, vec!["MIT", "Apache-2.0", "LicenseRef-Proprietary"]);
+    }
+
+    #[test]
+    fn it_should_check_the_free_status_of_an_spdx_expression() {
+        let expression = SpdxExpression::new("MIT OR LicenseRef-Proprietary");
+        assert_eq!(expression.is_osi_approved(), Ok(true));
+        assert_eq!(expression.is_fsf_free_libre(), Ok(true));
+
+        let expression = SpdxExpression::new("MIT AND LicenseRef-Proprietary");
+        assert_eq!(expression.is_osi_approved(), Ok(false));
+        assert_eq!(expression.is_fsf_free_libre(), Ok(false));
+
+        let expression = SpdxExpression::new("CC0-1.0");
+        assert_eq!(expression.is_osi_approved(), Ok(false));
+        assert_eq!(expression.is_fsf_free_libre(), Ok(true));
+
+        assert_eq!(
+            SpdxExpression::new("not a real license").is_osi_approved(),
+            Err(SpdxExpressionError::InvalidSpdxExpression(
+                "unknown term".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_check_an_spdx_expression_against_an_allow_list() {
+        let expression = SpdxExpression::new("(MIT OR Apache-2.0) AND Unicode-DFS-2016");
+        assert_eq!(expression.satisfies(&["MIT", "Unicode-DFS-2016"]), Ok(true));
+        assert_eq!(expression.satisfies(&["Apache-2.0", "MIT"]), Ok(false));
+
+        let expression = SpdxExpression::new("Apache-2.0 WITH LLVM-exception");
+        assert_eq!(expression.satisfies(&["Apache-2.0"]), Ok(true));
+        assert_eq!(
+            expression.satisfies(&["Apache-2.0 with LLVM-exception"]),
+            Ok(true)
+        );
+        assert_eq!(
+            SpdxExpression::new("Apache-2.0").satisfies(&["Apache-2.0 WITH LLVM-exception"]),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn it_should_check_an_spdx_expression_against_a_deny_list() {
+        let expression = SpdxExpression::new("(MIT OR GPL-3.0-only) AND Unicode-DFS-2016");
+        assert_eq!(expression.requires_any(&["GPL-3.0-only"]), Ok(false));
+        assert_eq!(expression.requires_any(&["Unicode-DFS-2016"]), Ok(true));
+        assert_eq!(expression.requires_any(&["MIT", "GPL-3.0-only"]), Ok(true));
+        assert_eq!(
+            SpdxExpression::new("Apache-2.0 WITH LLVM-exception").requires_any(&["Apache-2.0"]),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn it_should_normalize_an_spdx_expression() {
+        let actual = SpdxExpression::new("MIT and (Apache-2.0 with LLVM-exception or MIT/0BSD)")
+            .normalize()
+            .expect("Failed to normalize the expression");
+        assert_eq!(
+            actual,
+            SpdxExpression::new("MIT AND (Apache-2.0 WITH LLVM-exception OR MIT OR 0BSD)")
+        );
+
+        let actual = SpdxExpression::new("(MIT AND Apache-2.0) OR ISC")
+            .normalize()
+            .expect("Failed to normalize the expression");
+        assert_eq!(actual, SpdxExpression::new("MIT AND Apache-2.0 OR ISC"));
+    }
+
     #[test]
     fn valid_spdx_expressions_should_pass_validation() {
         let validation_result = validate_spdx_expression(&SpdxExpression::new("MIT OR Apache-2.0"));
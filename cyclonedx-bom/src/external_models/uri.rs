@@ -16,22 +16,103 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use std::{convert::TryFrom, str::FromStr};
+use std::{collections::BTreeMap, convert::TryFrom, str::FromStr};
 
 use fluent_uri::Uri as Url;
 use purl::{GenericPurl, GenericPurlBuilder};
 use thiserror::Error;
 
-use crate::validation::ValidationError;
+use crate::validation::{code, UriValidation, ValidationError, ValidationOptions};
 
 pub fn validate_purl(purl: &Purl) -> Result<(), ValidationError> {
     match GenericPurl::<String>::from_str(&purl.0) {
         Ok(_) => Ok(()),
-        Err(e) => Err(format!("Purl does not conform to Package URL spec: {e}").into()),
+        Err(e) => Err(ValidationError::new(format!(
+            "Purl does not conform to Package URL spec: {e}"
+        ))
+        .with_code(code::INVALID_PURL)),
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A [`Purl`] parsed into its parts.
+///
+/// For more details see the [Package URL spec](https://github.com/package-url/purl-spec).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackageUrl {
+    package_type: String,
+    namespace: Option<String>,
+    name: String,
+    version: Option<String>,
+    qualifiers: BTreeMap<String, String>,
+    subpath: Option<String>,
+    canonical: String,
+}
+
+impl PackageUrl {
+    /// The package type, e.g. `cargo` or `npm`
+    pub fn package_type(&self) -> &str {
+        &self.package_type
+    }
+
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// The qualifiers sorted by their key
+    pub fn qualifiers(&self) -> &BTreeMap<String, String> {
+        &self.qualifiers
+    }
+
+    pub fn qualifier(&self, key: &str) -> Option<&str> {
+        self.qualifiers.get(key).map(String::as_str)
+    }
+
+    pub fn subpath(&self) -> Option<&str> {
+        self.subpath.as_deref()
+    }
+}
+
+impl FromStr for PackageUrl {
+    type Err = UriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let purl =
+            GenericPurl::<String>::from_str(s).map_err(|e| UriError::InvalidPurl(e.to_string()))?;
+
+        Ok(Self {
+            package_type: purl.package_type().clone(),
+            namespace: purl.namespace().map(str::to_string),
+            name: purl.name().to_string(),
+            version: purl.version().map(str::to_string),
+            qualifiers: purl
+                .qualifiers()
+                .iter()
+                .map(|(key, value)| (key.as_str().to_string(), value.to_string()))
+                .collect(),
+            subpath: purl.subpath().map(str::to_string),
+            canonical: purl.to_string(),
+        })
+    }
+}
+
+/// Formats the purl in its canonical form, e.g. with sorted qualifiers.
+impl std::fmt::Display for PackageUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.canonical)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Purl(pub(crate) String);
 
 impl Purl {
@@ -43,6 +124,25 @@ impl Purl {
             Err(e) => Err(UriError::InvalidPurl(e.to_string())),
         }
     }
+
+    /// Parses the purl into its parts.
+    /// ```
+    /// use cyclonedx_bom::prelude::Purl;
+    /// use std::str::FromStr;
+    ///
+    /// let purl = Purl::from_str("pkg:cargo/cyclonedx-bom@0.8.0").unwrap();
+    /// let package_url = purl.parse()?;
+    /// assert_eq!(package_url.package_type(), "cargo");
+    /// assert_eq!(package_url.name(), "cyclonedx-bom");
+    /// assert_eq!(package_url.version(), Some("0.8.0"));
+    /// # Ok::<(), cyclonedx_bom::external_models::uri::UriError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if the purl does not conform to the Package URL spec
+    pub fn parse(&self) -> Result<PackageUrl, UriError> {
+        PackageUrl::from_str(&self.0)
+    }
 }
 
 impl std::fmt::Display for Purl {
@@ -64,14 +164,44 @@ impl AsRef<str> for Purl {
     }
 }
 
+/// Validates a URI as configured by the [`UriValidation`] of the current [`ValidationOptions`]
 pub fn validate_uri(uri: &Uri) -> Result<(), ValidationError> {
-    if Url::parse(uri.0.as_str()).is_err() {
-        return Err(ValidationError::new("Uri does not conform to RFC 3986"));
+    match ValidationOptions::current().uri {
+        UriValidation::Strict => {
+            if Url::parse(uri.0.as_str()).is_err() {
+                return Err(ValidationError::new("Uri does not conform to RFC 3986")
+                    .with_code(code::INVALID_URI));
+            }
+        }
+        UriValidation::IriPermissive => {
+            if Url::parse(&iri_to_uri(&uri.0)).is_err() {
+                return Err(ValidationError::new("Uri does not conform to RFC 3987")
+                    .with_code(code::INVALID_URI));
+            }
+        }
+        UriValidation::Off => {}
     }
     Ok(())
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Maps an IRI to a URI by percent-encoding its non-ASCII characters, as of RFC 3987, section 3.1
+fn iri_to_uri(iri: &str) -> String {
+    let mut uri = String::with_capacity(iri.len());
+    for c in iri.chars() {
+        if c.is_ascii() {
+            uri.push(c);
+        } else {
+            let mut buffer = [0; 4];
+            for byte in c.encode_utf8(&mut buffer).bytes() {
+                uri.push_str(&format!("%{byte:02X}"));
+            }
+        }
+    }
+    uri
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Uri(pub(crate) String);
 
 impl Uri {
@@ -122,9 +252,12 @@ pub enum UriError {
 mod test {
     use pretty_assertions::assert_eq;
 
+    use std::collections::BTreeMap;
+
     use crate::{
-        external_models::uri::{validate_purl, validate_uri},
+        external_models::uri::{iri_to_uri, validate_purl, validate_uri, UriError},
         prelude::{Purl, Uri},
+        validation::{UriValidation, ValidationOptions},
     };
 
     #[test]
@@ -143,6 +276,44 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_parse_a_purl_into_its_parts() {
+        let purl = Purl(
+            "pkg:maven/org.apache.xmlgraphics/batik-anim@1.9.1?type=pom&classifier=sources#src/main"
+                .to_string(),
+        );
+
+        let package_url = purl.parse().expect("Failed to parse purl");
+
+        assert_eq!(package_url.package_type(), "maven");
+        assert_eq!(package_url.namespace(), Some("org.apache.xmlgraphics"));
+        assert_eq!(package_url.name(), "batik-anim");
+        assert_eq!(package_url.version(), Some("1.9.1"));
+        assert_eq!(
+            package_url.qualifiers(),
+            &BTreeMap::from([
+                ("classifier".to_string(), "sources".to_string()),
+                ("type".to_string(), "pom".to_string()),
+            ])
+        );
+        assert_eq!(package_url.qualifier("type"), Some("pom"));
+        assert_eq!(package_url.subpath(), Some("src/main"));
+        assert_eq!(
+            package_url.to_string(),
+            "pkg:maven/org.apache.xmlgraphics/batik-anim@1.9.1?classifier=sources&type=pom#src/main"
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_an_invalid_purl() {
+        let actual = Purl("invalid purl".to_string()).parse();
+
+        assert_eq!(
+            actual,
+            Err(UriError::InvalidPurl("URL scheme must be pkg".to_string()))
+        );
+    }
+
     #[test]
     fn valid_uris_should_pass_validation() {
         let validation_result = validate_uri(&Uri("https://example.com".to_string()));
@@ -158,4 +329,42 @@ mod test {
             Err("Uri does not conform to RFC 3986".into()),
         );
     }
+
+    #[test]
+    fn iris_should_pass_permissive_validation_only() {
+        let iri = Uri("https://例え.jp/パス?q=値#片".to_string());
+
+        assert_eq!(
+            validate_uri(&iri),
+            Err("Uri does not conform to RFC 3986".into())
+        );
+        let options = ValidationOptions {
+            uri: UriValidation::IriPermissive,
+        };
+        assert_eq!(options.apply(|| validate_uri(&iri)), Ok(()));
+        assert_eq!(
+            options.apply(|| validate_uri(&Uri("invalid uri".to_string()))),
+            Err("Uri does not conform to RFC 3987".into())
+        );
+    }
+
+    #[test]
+    fn any_uri_should_pass_when_validation_is_off() {
+        let options = ValidationOptions {
+            uri: UriValidation::Off,
+        };
+
+        assert_eq!(
+            options.apply(|| validate_uri(&Uri("invalid uri".to_string()))),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn iris_should_be_mapped_to_uris() {
+        assert_eq!(
+            iri_to_uri("https://example.com/ä?x=y"),
+            "https://example.com/%C3%A4?x=y"
+        );
+    }
 }
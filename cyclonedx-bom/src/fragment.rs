@@ -0,0 +1,288 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Parsing of fragments of a BOM, standalone components, services or vulnerabilities as allowed
+//! by the specification for partial documents, e.g. to stitch the output of several tools
+//! together with [`Vulnerabilities::merge`].
+//!
+//! A JSON fragment is a single object, an array of objects or an object with the list under its
+//! usual key, e.g. `{"components": [...]}`. An XML fragment is a single element such as
+//! `<component>` or the list element such as `<components>`.
+//!
+//! ```
+//! use cyclonedx_bom::fragment::parse_components;
+//! use cyclonedx_bom::models::bom::SpecVersion;
+//! use cyclonedx_bom::Format;
+//!
+//! let input = r#"{"type": "library", "name": "serde", "version": "1.0.193"}"#;
+//! let components = parse_components(input.as_bytes(), Format::Json, SpecVersion::V1_5)?;
+//! assert_eq!(components.0[0].name.to_string(), "serde");
+//! # Ok::<(), cyclonedx_bom::errors::ParseError>(())
+//! ```
+
+use std::io::Read;
+
+use serde_json::{json, Value};
+use xml::reader::XmlEvent;
+use xml::EventReader;
+
+use crate::detect::Format;
+use crate::errors::{ParseError, XmlReadError};
+use crate::models::{
+    bom::{Bom, SpecVersion},
+    component::Components,
+    service::Services,
+    vulnerability::Vulnerabilities,
+};
+
+/// The names of a list of a BOM and its elements.
+struct FragmentKind {
+    element: &'static str,
+    list: &'static str,
+}
+
+const COMPONENTS: FragmentKind = FragmentKind {
+    element: "component",
+    list: "components",
+};
+const SERVICES: FragmentKind = FragmentKind {
+    element: "service",
+    list: "services",
+};
+const VULNERABILITIES: FragmentKind = FragmentKind {
+    element: "vulnerability",
+    list: "vulnerabilities",
+};
+
+/// Parses a fragment with one or more components.
+pub fn parse_components<R: Read>(
+    reader: R,
+    format: Format,
+    version: SpecVersion,
+) -> Result<Components, ParseError> {
+    let bom = parse_fragment(reader, format, version, &COMPONENTS)?;
+    Ok(bom.components.unwrap_or_else(|| Components(Vec::new())))
+}
+
+/// Parses a fragment with one or more services.
+pub fn parse_services<R: Read>(
+    reader: R,
+    format: Format,
+    version: SpecVersion,
+) -> Result<Services, ParseError> {
+    let bom = parse_fragment(reader, format, version, &SERVICES)?;
+    Ok(bom.services.unwrap_or_else(|| Services(Vec::new())))
+}
+
+/// Parses a fragment with one or more vulnerabilities, which are supported since spec version 1.4.
+pub fn parse_vulnerabilities<R: Read>(
+    reader: R,
+    format: Format,
+    version: SpecVersion,
+) -> Result<Vulnerabilities, ParseError> {
+    let bom = parse_fragment(reader, format, version, &VULNERABILITIES)?;
+    Ok(bom
+        .vulnerabilities
+        .unwrap_or_else(|| Vulnerabilities(Vec::new())))
+}
+
+/// Parses the fragment as a BOM that only has the list of the fragment.
+fn parse_fragment<R: Read>(
+    mut reader: R,
+    format: Format,
+    version: SpecVersion,
+    kind: &FragmentKind,
+) -> Result<Bom, ParseError> {
+    match format {
+        Format::Json => {
+            let fragment: Value =
+                serde_json::from_reader(reader).map_err(crate::errors::JsonReadError::from)?;
+            let list = match fragment {
+                Value::Array(elements) => Value::Array(elements),
+                Value::Object(mut object)
+                    if object.contains_key("bomFormat")
+                        || (object.len() == 1 && object.contains_key(kind.list)) =>
+                {
+                    object.remove(kind.list).unwrap_or_else(|| json!([]))
+                }
+                element => Value::Array(vec![element]),
+            };
+            let bom = json!({
+                "bomFormat": "CycloneDX",
+                "specVersion": version.to_string(),
+                "version": 1,
+                kind.list: list,
+            });
+            Ok(Bom::parse_json_value_with_version(bom, version)?)
+        }
+        Format::Xml => {
+            let mut input = Vec::new();
+            reader.read_to_end(&mut input).map_err(|error| {
+                crate::errors::JsonReadError::from(serde_json::Error::io(error))
+            })?;
+            let fragment = xml_fragment(&input)?;
+            let (open, close) = match root_element(fragment)? {
+                name if name == kind.list => (String::new(), String::new()),
+                name if name == kind.element => {
+                    (format!("<{}>", kind.list), format!("</{}>", kind.list))
+                }
+                name => {
+                    return Err(XmlReadError::UnexpectedElementReadError {
+                        error: format!(
+                            "Expected a {} or {} element, got {name}",
+                            kind.element, kind.list
+                        ),
+                        element: "fragment".to_string(),
+                    }
+                    .into())
+                }
+            };
+            let bom = format!(
+                r#"<bom xmlns="http://cyclonedx.org/schema/bom/{version}" version="1">{open}{}{close}</bom>"#,
+                String::from_utf8_lossy(fragment)
+            );
+            Ok(Bom::parse_from_xml_with_version(bom.as_bytes(), version)?)
+        }
+    }
+}
+
+/// Strips the byte order mark and the XML declaration, which cannot be nested into a document.
+fn xml_fragment(input: &[u8]) -> Result<&[u8], XmlReadError> {
+    let input = input.strip_prefix(b"\xef\xbb\xbf").unwrap_or(input);
+    let start = input
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    let input = &input[start..];
+    if !input.starts_with(b"<?xml") {
+        return Ok(input);
+    }
+    match input.windows(2).position(|window| window == b"?>") {
+        Some(end) => Ok(&input[end + 2..]),
+        None => Err(XmlReadError::UnexpectedElementReadError {
+            error: "Unterminated XML declaration".to_string(),
+            element: "fragment".to_string(),
+        }),
+    }
+}
+
+/// Returns the local name of the root element.
+fn root_element(fragment: &[u8]) -> Result<String, XmlReadError> {
+    for event in EventReader::new(fragment) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => return Ok(name.local_name),
+            Ok(_) => (),
+            Err(error) => {
+                return Err(XmlReadError::ElementReadError {
+                    error,
+                    element: "fragment".to_string(),
+                })
+            }
+        }
+    }
+    Err(XmlReadError::UnexpectedElementReadError {
+        error: "No root element found".to_string(),
+        element: "fragment".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn names(components: &Components) -> Vec<String> {
+        components.0.iter().map(|c| c.name.to_string()).collect()
+    }
+
+    #[test]
+    fn it_should_parse_json_fragments_of_components() {
+        let single = r#"{"type": "library", "name": "a", "components": [{"type": "library", "name": "nested"}]}"#;
+        let list = r#"[{"type": "library", "name": "a"}, {"type": "library", "name": "b"}]"#;
+        let wrapped = r#"{"components": [{"type": "library", "name": "a"}]}"#;
+        let parse = |input: &str| {
+            parse_components(input.as_bytes(), Format::Json, SpecVersion::V1_5).unwrap()
+        };
+
+        let components = parse(single);
+        assert_eq!(names(&components), ["a"]);
+        assert_eq!(
+            names(components.0[0].components.as_ref().unwrap()),
+            ["nested"]
+        );
+        assert_eq!(names(&parse(list)), ["a", "b"]);
+        assert_eq!(names(&parse(wrapped)), ["a"]);
+    }
+
+    #[test]
+    fn it_should_parse_xml_fragments_of_components() {
+        let single = r#"<?xml version="1.0" encoding="utf-8"?>
+<component xmlns="http://cyclonedx.org/schema/bom/1.5" type="library"><name>a</name></component>"#;
+        let list = r#"<components><component type="library"><name>a</name></component><component type="library"><name>b</name></component></components>"#;
+        let parse = |input: &str| {
+            parse_components(input.as_bytes(), Format::Xml, SpecVersion::V1_5).unwrap()
+        };
+
+        assert_eq!(names(&parse(single)), ["a"]);
+        assert_eq!(names(&parse(list)), ["a", "b"]);
+    }
+
+    #[test]
+    fn it_should_parse_fragments_of_services_and_vulnerabilities() {
+        let services = parse_services(
+            r#"<service><name>api</name></service>"#.as_bytes(),
+            Format::Xml,
+            SpecVersion::V1_4,
+        )
+        .unwrap();
+        assert_eq!(services.0[0].name.to_string(), "api");
+
+        let vulnerabilities = parse_vulnerabilities(
+            r#"{"vulnerabilities": [{"id": "CVE-2024-0001"}, {"id": "CVE-2024-0002"}]}"#.as_bytes(),
+            Format::Json,
+            SpecVersion::V1_5,
+        )
+        .unwrap();
+        let ids: Vec<_> = vulnerabilities
+            .0
+            .iter()
+            .map(|v| v.id.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(ids, ["CVE-2024-0001", "CVE-2024-0002"]);
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_fragments_of_another_kind() {
+        let error = parse_components(
+            r#"<service><name>api</name></service>"#.as_bytes(),
+            Format::Xml,
+            SpecVersion::V1_5,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse XML: Got unexpected XML element when reading fragment: Expected a component or components element, got service"
+        );
+
+        assert!(parse_components(
+            r#"{"name": "missing type"}"#.as_bytes(),
+            Format::Json,
+            SpecVersion::V1_5
+        )
+        .is_err());
+    }
+}
@@ -122,12 +122,28 @@
 //! use cyclonedx_bom::prelude::*;
 //! ```
 
+#[cfg(feature = "tokio")]
+pub mod async_io;
+pub mod bundle;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod core_models;
+pub mod detect;
 pub mod errors;
 pub mod external_models;
+pub mod fragment;
 pub mod models;
 pub mod prelude;
+pub mod progress;
+pub mod render;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 pub mod validation;
 
+#[cfg(feature = "quick-xml")]
+mod quick_xml_reader;
 mod specs;
 mod utilities;
 mod xml;
+
+pub use detect::{detect_version, Format};
@@ -30,12 +30,20 @@ use crate::{
 
 use super::bom::SpecVersion;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Annotations(pub Vec<Annotation>);
 
 impl Validate for Annotations {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
-        ValidationContext::new()
+        let mut context = ValidationContext::new();
+        if version <= SpecVersion::V1_4 {
+            context.add_custom(
+                "annotations",
+                "Annotations are not supported in this format version",
+            );
+        }
+        context
             .add_list("inner", &self.0, |annotation| {
                 annotation.validate_version(version)
             })
@@ -43,7 +51,8 @@ impl Validate for Annotations {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Annotation {
     pub bom_ref: Option<String>,
     pub subjects: Vec<String>,
@@ -63,7 +72,9 @@ impl Validate for Annotation {
 }
 
 /// Represents an Annotator: organization, individual, component or service.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::large_enum_variant)]
 pub enum Annotator {
     Organization(OrganizationalEntity),
     Individual(OrganizationalContact),
@@ -93,4 +104,88 @@ impl Validate for Annotator {
 }
 
 #[cfg(test)]
-mod test {}
+mod test {
+    use super::*;
+    use crate::{external_models::normalized_string::NormalizedString, validation};
+    use pretty_assertions::assert_eq;
+
+    fn annotation(annotator: Annotator, timestamp: &str) -> Annotation {
+        Annotation {
+            bom_ref: Some("annotation-1".to_string()),
+            subjects: vec!["component-a".to_string()],
+            annotator,
+            timestamp: DateTime(timestamp.to_string()),
+            text: "This is an annotation".to_string(),
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = Annotations(vec![
+            annotation(
+                Annotator::Organization(OrganizationalEntity::new("Acme, Inc.")),
+                "2020-04-13T20:20:39+00:00",
+            ),
+            annotation(
+                Annotator::Service(Service::new("service", None)),
+                "2020-04-13T20:20:39+00:00",
+            ),
+        ])
+        .validate_version(SpecVersion::V1_5);
+
+        assert!(validation_result.passed());
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let mut organization = OrganizationalEntity::new("Acme, Inc.");
+        organization.name = Some(NormalizedString("invalid\tname".to_string()));
+
+        let validation_result = Annotations(vec![annotation(
+            Annotator::Organization(organization),
+            "invalid date",
+        )])
+        .validate_version(SpecVersion::V1_5);
+
+        assert_eq!(
+            validation_result,
+            validation::list(
+                "inner",
+                [(
+                    0,
+                    vec![
+                        validation::field("timestamp", "DateTime does not conform to ISO 8601"),
+                        validation::r#struct(
+                            "annotator",
+                            validation::r#struct(
+                                "organization",
+                                validation::field(
+                                    "name",
+                                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                )
+                            )
+                        )
+                    ]
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_fail_validation_in_older_versions() {
+        let validation_result = Annotations(vec![annotation(
+            Annotator::Organization(OrganizationalEntity::new("Acme, Inc.")),
+            "2020-04-13T20:20:39+00:00",
+        )])
+        .validate_version(SpecVersion::V1_4);
+
+        assert_eq!(
+            validation_result,
+            validation::custom(
+                "annotations",
+                ["Annotations are not supported in this format version"]
+            )
+        );
+    }
+}
@@ -25,7 +25,8 @@ use crate::{
 
 use super::bom::SpecVersion;
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttachedText {
     pub content_type: Option<NormalizedString>,
     pub encoding: Option<Encoding>,
@@ -81,7 +82,8 @@ pub fn validate_encoding(encoding: &Encoding) -> Result<(), ValidationError> {
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Encoding {
     Base64,
@@ -99,6 +101,8 @@ impl Encoding {
     }
 }
 
+from_str_via_new_unchecked!(Encoding, UnknownEncoding, "encoding");
+
 #[cfg(test)]
 mod test {
     use crate::{
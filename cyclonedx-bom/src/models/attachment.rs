@@ -16,6 +16,9 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use base64::{engine::general_purpose::STANDARD, Engine};
+use thiserror::Error;
+
 use crate::{
     prelude::{Validate, ValidationResult},
     validation::{ValidationContext, ValidationError},
@@ -23,24 +26,178 @@ use crate::{
 
 use super::bom::SpecVersion;
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+const BASE64_ENCODING: &str = "base64";
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Attachment {
     pub content: String,
     pub content_type: Option<String>,
     pub encoding: Option<String>,
 }
 
+impl Attachment {
+    /// Construct a new base64 encoded `Attachment` from raw bytes.
+    ///
+    /// - `content_type` - Content type of the attachment, if `None` it is derived from the content
+    /// - `content` - Raw content, which will be base64 encoded when added to the BOM
+    pub fn from_bytes<T: AsRef<[u8]>>(content_type: Option<String>, content: T) -> Self {
+        let content = content.as_ref();
+        Self {
+            content_type: content_type.or_else(|| sniff_content_type(content).map(String::from)),
+            encoding: Some(BASE64_ENCODING.to_string()),
+            content: STANDARD.encode(content),
+        }
+    }
+
+    /// Returns the raw content of the attachment, decoding it according to the declared encoding.
+    pub fn decoded_bytes(&self) -> Result<Vec<u8>, AttachmentError> {
+        match self.encoding.as_deref() {
+            None => Ok(self.content.as_bytes().to_vec()),
+            Some(BASE64_ENCODING) => STANDARD
+                .decode(&self.content)
+                .map_err(|e| AttachmentError::InvalidBase64(e.to_string())),
+            Some(encoding) => Err(AttachmentError::UnsupportedEncoding(encoding.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AttachmentError {
+    #[error("Unsupported encoding: {}", .0)]
+    UnsupportedEncoding(String),
+
+    #[error("Content is not Base64 encoded: {}", .0)]
+    InvalidBase64(String),
+}
+
+/// Guesses the MIME type of the given content from well-known file signatures.
+pub fn sniff_content_type(content: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"<?xml", "application/xml"),
+    ];
+
+    if let Some((_, mime)) = SIGNATURES
+        .iter()
+        .find(|(signature, _)| content.starts_with(signature))
+    {
+        return Some(mime);
+    }
+
+    let text = std::str::from_utf8(content).ok()?;
+    match text.trim_start().chars().next() {
+        Some('{') | Some('[') if serde_json::from_str::<serde_json::Value>(text).is_ok() => {
+            Some("application/json")
+        }
+        _ => Some("text/plain"),
+    }
+}
+
 impl Validate for Attachment {
     fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
-        ValidationContext::new()
-            .add_field_option("encoding", self.encoding.as_ref(), validate_encoding)
-            .into()
+        let mut context = ValidationContext::new();
+        context.add_field_option("encoding", self.encoding.as_ref(), validate_encoding);
+
+        if self.encoding.as_deref() == Some(BASE64_ENCODING)
+            && STANDARD.decode(&self.content).is_err()
+        {
+            context.add_field("content", &self.content, |_| {
+                Err("Content is not Base64 encoded".into())
+            });
+        }
+
+        context.into()
     }
 }
 
 fn validate_encoding(encoding: &String) -> Result<(), ValidationError> {
-    if encoding != "base64" {
+    if encoding != BASE64_ENCODING {
         return Err("Unsupported encoding found.".into());
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_construct_attachment_from_bytes() {
+        let actual = Attachment::from_bytes(Some("text/plain".to_string()), b"this text is plain");
+
+        assert_eq!(
+            actual,
+            Attachment {
+                content: "dGhpcyB0ZXh0IGlzIHBsYWlu".to_string(),
+                content_type: Some("text/plain".to_string()),
+                encoding: Some("base64".to_string()),
+            }
+        );
+        assert_eq!(actual.decoded_bytes(), Ok(b"this text is plain".to_vec()));
+    }
+
+    #[test]
+    fn it_should_sniff_content_types() {
+        let png = Attachment::from_bytes(None, b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR");
+        assert_eq!(png.content_type.as_deref(), Some("image/png"));
+
+        let json = Attachment::from_bytes(None, br#"{"name": "value"}"#);
+        assert_eq!(json.content_type.as_deref(), Some("application/json"));
+
+        let text = Attachment::from_bytes(None, "plain text");
+        assert_eq!(text.content_type.as_deref(), Some("text/plain"));
+
+        assert_eq!(sniff_content_type(&[0x00, 0xff, 0xfe]), None);
+    }
+
+    #[test]
+    fn it_should_fail_to_decode_with_unsupported_encoding() {
+        let attachment = Attachment {
+            content: "content".to_string(),
+            content_type: None,
+            encoding: Some("base32".to_string()),
+        };
+
+        assert_eq!(
+            attachment.decoded_bytes(),
+            Err(AttachmentError::UnsupportedEncoding("base32".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_return_unencoded_content() {
+        let attachment = Attachment {
+            content: "content".to_string(),
+            content_type: None,
+            encoding: None,
+        };
+
+        assert_eq!(attachment.decoded_bytes(), Ok(b"content".to_vec()));
+    }
+
+    #[test]
+    fn invalid_attachment_should_fail_validation() {
+        let validation_result = Attachment {
+            content: "not base64 encoded".to_string(),
+            content_type: None,
+            encoding: Some("base64".to_string()),
+        }
+        .validate();
+
+        assert!(Attachment::from_bytes(None, "content").validate().passed());
+        assert_eq!(
+            validation_result,
+            validation::field("content", "Content is not Base64 encoded")
+        );
+    }
+}
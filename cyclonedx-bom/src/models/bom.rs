@@ -25,28 +25,49 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use xml::{EmitterConfig, EventReader, EventWriter, ParserConfig};
+use thiserror::Error;
+use xml::{EmitterConfig, EventReader, EventWriter};
 
-use crate::errors::BomError;
+use crate::detect::{json_spec_version, xml_spec_version};
+use crate::errors::{BomError, ParseError};
+use crate::external_models::uri::Purl;
 use crate::models::annotation::Annotations;
+use crate::models::bom_index::{all_components, purl_matches, BomIndex};
 use crate::models::component::{Component, Components};
-use crate::models::composition::Compositions;
-use crate::models::dependency::Dependencies;
+use crate::models::composition::{AggregateType, Composition, Compositions};
+use crate::models::declarations::Declarations;
+use crate::models::definitions::Definitions;
+use crate::models::dependency::{Dependencies, DependencyGraph};
 use crate::models::external_reference::ExternalReferences;
 use crate::models::formulation::Formula;
-use crate::models::metadata::Metadata;
+use crate::models::metadata::{Metadata, MetadataError};
 use crate::models::property::Properties;
 use crate::models::service::{Service, Services};
 use crate::models::signature::Signature;
 use crate::models::vulnerability::Vulnerabilities;
-use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
-use crate::xml::{FromXmlDocument, ToXml};
+use crate::progress::{Phase, ProgressHandler, ProgressTracker};
+use crate::validation::{code, Validate, ValidationContext, ValidationError, ValidationResult};
+use crate::xml::{to_xml_write_error, FromXmlDocument, ToXml, XmlReader};
 
 use super::vulnerability::Vulnerability;
 
 /// Represents the spec version of a BOM.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, PartialOrd, strum::Display)]
+#[derive(
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    PartialOrd,
+    Ord,
+    Hash,
+    strum::Display,
+)]
 pub enum SpecVersion {
+    #[default]
     #[strum(to_string = "1.3")]
     #[serde(rename = "1.3")]
     V1_3 = 1,
@@ -56,12 +77,9 @@ pub enum SpecVersion {
     #[strum(to_string = "1.5")]
     #[serde(rename = "1.5")]
     V1_5 = 3,
-}
-
-impl Default for SpecVersion {
-    fn default() -> Self {
-        Self::V1_3
-    }
+    #[strum(to_string = "1.6")]
+    #[serde(rename = "1.6")]
+    V1_6 = 4,
 }
 
 impl FromStr for SpecVersion {
@@ -72,6 +90,7 @@ impl FromStr for SpecVersion {
             "1.3" => Ok(SpecVersion::V1_3),
             "1.4" => Ok(SpecVersion::V1_4),
             "1.5" => Ok(SpecVersion::V1_5),
+            "1.6" => Ok(SpecVersion::V1_6),
             s => Err(BomError::UnsupportedSpecVersion(s.to_string())),
         }
     }
@@ -88,7 +107,8 @@ pub fn validate_bom_ref(
 }
 
 /// A reference to a Bom element
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BomReference(pub String);
 
 impl BomReference {
@@ -100,7 +120,8 @@ impl BomReference {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bom {
     pub version: u32,
     pub serial_number: Option<UrnUuid>,
@@ -119,10 +140,33 @@ pub struct Bom {
     pub annotations: Option<Annotations>,
     /// Added in version 1.5
     pub formulation: Option<Vec<Formula>>,
+    /// Added in version 1.6
+    pub declarations: Option<Declarations>,
+    /// Added in version 1.6
+    pub definitions: Option<Definitions>,
     pub spec_version: SpecVersion,
 }
 
 impl Bom {
+    /// Constructs a BOM with a random serial number and metadata from [`Metadata::now`], i.e.
+    /// the current time and this library as the tool that created it
+    /// ```
+    /// use cyclonedx_bom::models::{bom::Bom, metadata::MetadataError};
+    ///
+    /// let bom = Bom::now()?;
+    /// assert!(bom.serial_number.is_some());
+    /// # Ok::<(), MetadataError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error variant if unable to generate a valid timestamp
+    pub fn now() -> Result<Self, MetadataError> {
+        Ok(Self {
+            metadata: Some(Metadata::now()?),
+            ..Default::default()
+        })
+    }
+
     /// General function to parse a JSON file, fetches the `specVersion` field first then applies the right conversion.
     pub fn parse_from_json<R: std::io::Read>(
         mut reader: R,
@@ -138,16 +182,58 @@ impl Bom {
                 .as_str()
                 .ok_or_else(|| BomError::UnsupportedSpecVersion(version.to_string()))?;
 
-            match SpecVersion::from_str(version)? {
-                SpecVersion::V1_3 => Ok(crate::specs::v1_3::bom::Bom::deserialize(json)?.into()),
-                SpecVersion::V1_4 => Ok(crate::specs::v1_4::bom::Bom::deserialize(json)?.into()),
-                SpecVersion::V1_5 => Ok(crate::specs::v1_5::bom::Bom::deserialize(json)?.into()),
-            }
+            let version = SpecVersion::from_str(version)?;
+            Self::parse_json_value_with_version(json, version)
         } else {
             Err(BomError::UnsupportedSpecVersion("No field 'specVersion' found".to_string()).into())
         }
     }
 
+    pub(crate) fn parse_json_value_with_version(
+        json: Value,
+        version: SpecVersion,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        match version {
+            SpecVersion::V1_3 => Ok(crate::specs::v1_3::bom::Bom::deserialize(json)?.into()),
+            SpecVersion::V1_4 => Ok(crate::specs::v1_4::bom::Bom::deserialize(json)?.into()),
+            SpecVersion::V1_5 => Ok(crate::specs::v1_5::bom::Bom::deserialize(json)?.into()),
+            SpecVersion::V1_6 => Ok(crate::specs::v1_6::bom::Bom::deserialize(json)?.into()),
+        }
+    }
+
+    /// Parses a JSON or XML document of any supported spec version and returns the BOM together
+    /// with the detected version.
+    ///
+    /// The format is detected from the first character of the document. The spec version of a
+    /// JSON document is taken from `specVersion`, or from the `$schema` url if it is missing, the
+    /// one of an XML document from the namespace of the root element.
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    ///
+    /// let input = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="1"/>"#;
+    /// let (bom, version) = Bom::parse_auto(input)?;
+    /// assert_eq!(version, SpecVersion::V1_4);
+    /// # Ok::<(), cyclonedx_bom::errors::ParseError>(())
+    /// ```
+    pub fn parse_auto(input: impl AsRef<[u8]>) -> Result<(Self, SpecVersion), ParseError> {
+        let input = input.as_ref();
+        let input = input.strip_prefix(b"\xef\xbb\xbf").unwrap_or(input);
+
+        match input.iter().find(|byte| !byte.is_ascii_whitespace()) {
+            Some(b'{') => {
+                let json: Value =
+                    serde_json::from_slice(input).map_err(crate::errors::JsonReadError::from)?;
+                let version = json_spec_version(&json)?;
+                Ok((Self::parse_json_value_with_version(json, version)?, version))
+            }
+            Some(b'<') => {
+                let version = xml_spec_version(input)?;
+                Ok((Self::parse_from_xml_with_version(input, version)?, version))
+            }
+            _ => Err(ParseError::UnknownFormat),
+        }
+    }
+
     /// Parse the input as a JSON document conforming to the version of the specification that you provide.
     /// Use [`parse_from_json`](Self::parse_from_json) if you want to support multiple versions instead.
     pub fn parse_from_json_with_version<R: std::io::Read>(
@@ -158,6 +244,7 @@ impl Bom {
             SpecVersion::V1_3 => Self::parse_from_json_v1_3(reader),
             SpecVersion::V1_4 => Self::parse_from_json_v1_4(reader),
             SpecVersion::V1_5 => Self::parse_from_json_v1_5(reader),
+            SpecVersion::V1_6 => Self::parse_from_json_v1_6(reader),
         }
     }
 
@@ -171,6 +258,70 @@ impl Bom {
             SpecVersion::V1_3 => self.output_as_json_v1_3(writer),
             SpecVersion::V1_4 => self.output_as_json_v1_4(writer),
             SpecVersion::V1_5 => self.output_as_json_v1_5(writer),
+            SpecVersion::V1_6 => self.output_as_json_v1_6(writer),
+        }
+    }
+
+    /// Output as a JSON document conforming to the specification version that you provide, in the
+    /// given format.
+    ///
+    /// The document is serialized directly into the writer, so a response can be streamed without
+    /// building an intermediate `String` first.
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, JsonFormat, SpecVersion};
+    ///
+    /// let mut output = Vec::new();
+    /// Bom::default().output_as_json_to_writer(&mut output, SpecVersion::V1_5, JsonFormat::Compact)?;
+    /// assert!(!output.contains(&b'\n'));
+    /// # Ok::<(), cyclonedx_bom::errors::JsonWriteError>(())
+    /// ```
+    pub fn output_as_json_to_writer<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        version: SpecVersion,
+        format: JsonFormat,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let options = OutputOptions {
+            format,
+            ..OutputOptions::default()
+        };
+        self.output_as_json_with_options(writer, version, &options)
+    }
+
+    /// Output as a JSON document conforming to the specification version that you provide, with
+    /// the given options for its layout, the order of its keys and the `$schema` property.
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, JsonFormat, OutputOptions, SpecVersion};
+    ///
+    /// let options = OutputOptions {
+    ///     format: JsonFormat::Compact,
+    ///     sort_keys: true,
+    ///     schema: true,
+    /// };
+    /// let mut output = Vec::new();
+    /// Bom::default().output_as_json_with_options(&mut output, SpecVersion::V1_5, &options)?;
+    /// assert!(output.starts_with(br#"{"$schema":"http://cyclonedx.org/schema/bom-1.5.schema.json","bomFormat""#));
+    /// # Ok::<(), cyclonedx_bom::errors::JsonWriteError>(())
+    /// ```
+    pub fn output_as_json_with_options<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        version: SpecVersion,
+        options: &OutputOptions,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        match version {
+            SpecVersion::V1_3 => {
+                write_json::<crate::specs::v1_3::bom::Bom, _>(self, writer, version, options)
+            }
+            SpecVersion::V1_4 => {
+                write_json::<crate::specs::v1_4::bom::Bom, _>(self, writer, version, options)
+            }
+            SpecVersion::V1_5 => {
+                write_json::<crate::specs::v1_5::bom::Bom, _>(self, writer, version, options)
+            }
+            SpecVersion::V1_6 => {
+                write_json::<crate::specs::v1_6::bom::Bom, _>(self, writer, version, options)
+            }
         }
     }
 
@@ -183,6 +334,49 @@ impl Bom {
             SpecVersion::V1_3 => Self::parse_from_xml_v1_3(reader),
             SpecVersion::V1_4 => Self::parse_from_xml_v1_4(reader),
             SpecVersion::V1_5 => Self::parse_from_xml_v1_5(reader),
+            SpecVersion::V1_6 => Self::parse_from_xml_v1_6(reader),
+        }
+    }
+
+    /// Parse the input as an XML document conforming to the version of the specification that you
+    /// provide, returning warnings about the content that was skipped.
+    ///
+    /// Elements and text that are unexpected in their place are skipped with a warning, as the
+    /// other XML parse functions skip them silently, unless [`XmlReadOptions::strict`] is turned
+    /// on to fail the parse instead.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion, XmlReadOptions};
+    ///
+    /// let input = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+    ///   <dependencies>
+    ///     <dependency ref="a" />
+    ///     <note>Added by hand</note>
+    ///   </dependencies>
+    /// </bom>"#;
+    ///
+    /// let (bom, warnings) = Bom::parse_from_xml_with_options(
+    ///     input.as_bytes(),
+    ///     SpecVersion::V1_5,
+    ///     XmlReadOptions::default(),
+    /// )?;
+    /// assert_eq!(bom.dependencies.map(|dependencies| dependencies.0.len()), Some(1));
+    /// assert_eq!(warnings[0].to_string(), "Skipped unexpected <note> in dependencies");
+    ///
+    /// let strict = XmlReadOptions { strict: true };
+    /// assert!(Bom::parse_from_xml_with_options(input.as_bytes(), SpecVersion::V1_5, strict).is_err());
+    /// # Ok::<(), cyclonedx_bom::errors::XmlReadError>(())
+    /// ```
+    pub fn parse_from_xml_with_options<R: std::io::Read>(
+        reader: R,
+        version: SpecVersion,
+        options: XmlReadOptions,
+    ) -> Result<(Self, Vec<XmlReadWarning>), crate::errors::XmlReadError> {
+        match version {
+            SpecVersion::V1_3 => read_xml::<crate::specs::v1_3::bom::Bom, _>(reader, options),
+            SpecVersion::V1_4 => read_xml::<crate::specs::v1_4::bom::Bom, _>(reader, options),
+            SpecVersion::V1_5 => read_xml::<crate::specs::v1_5::bom::Bom, _>(reader, options),
+            SpecVersion::V1_6 => read_xml::<crate::specs::v1_6::bom::Bom, _>(reader, options),
         }
     }
 
@@ -196,6 +390,39 @@ impl Bom {
             SpecVersion::V1_3 => self.output_as_xml_v1_3(writer),
             SpecVersion::V1_4 => self.output_as_xml_v1_4(writer),
             SpecVersion::V1_5 => self.output_as_xml_v1_5(writer),
+            SpecVersion::V1_6 => self.output_as_xml_v1_6(writer),
+        }
+    }
+
+    /// Output as an XML document conforming to the specification version that you provide, in the
+    /// given format.
+    ///
+    /// The format controls the indentation, the XML declaration, how empty elements are written
+    /// and the order of attributes, so the output can match existing documents.
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion, XmlFormat};
+    ///
+    /// let format = XmlFormat {
+    ///     indent: Some("\t".to_string()),
+    ///     declaration: false,
+    ///     ..XmlFormat::default()
+    /// };
+    /// let mut output = Vec::new();
+    /// Bom::default().output_as_xml_to_writer(&mut output, SpecVersion::V1_5, &format)?;
+    /// assert!(output.starts_with(b"<bom"));
+    /// # Ok::<(), cyclonedx_bom::errors::XmlWriteError>(())
+    /// ```
+    pub fn output_as_xml_to_writer<W: std::io::Write>(
+        self,
+        writer: &mut W,
+        version: SpecVersion,
+        format: &XmlFormat,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        match version {
+            SpecVersion::V1_3 => write_xml::<crate::specs::v1_3::bom::Bom, _>(self, writer, format),
+            SpecVersion::V1_4 => write_xml::<crate::specs::v1_4::bom::Bom, _>(self, writer, format),
+            SpecVersion::V1_5 => write_xml::<crate::specs::v1_5::bom::Bom, _>(self, writer, format),
+            SpecVersion::V1_6 => write_xml::<crate::specs::v1_6::bom::Bom, _>(self, writer, format),
         }
     }
 
@@ -218,10 +445,8 @@ impl Bom {
     pub fn parse_from_xml_v1_3<R: std::io::Read>(
         reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_3::bom::Bom::read_xml_document(&mut event_reader)?;
-        Ok(bom.into())
+        read_xml::<crate::specs::v1_3::bom::Bom, _>(reader, XmlReadOptions::default())
+            .map(|(bom, _)| bom)
     }
 
     /// Output as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
@@ -265,10 +490,8 @@ impl Bom {
     pub fn parse_from_xml_v1_4<R: std::io::Read>(
         reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_4::bom::Bom::read_xml_document(&mut event_reader)?;
-        Ok(bom.into())
+        read_xml::<crate::specs::v1_4::bom::Bom, _>(reader, XmlReadOptions::default())
+            .map(|(bom, _)| bom)
     }
 
     /// Output as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
@@ -305,10 +528,8 @@ impl Bom {
     pub fn parse_from_xml_v1_5<R: std::io::Read>(
         reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_5::bom::Bom::read_xml_document(&mut event_reader)?;
-        Ok(bom.into())
+        read_xml::<crate::specs::v1_5::bom::Bom, _>(reader, XmlReadOptions::default())
+            .map(|(bom, _)| bom)
     }
 
     /// Output as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
@@ -332,6 +553,443 @@ impl Bom {
         let bom: crate::specs::v1_5::bom::Bom = self.try_into()?;
         bom.write_xml_element(&mut event_writer)
     }
+
+    /// Parse the input as a JSON document conforming to [version 1.6 of the specification](https://cyclonedx.org/docs/1.6/json/)
+    pub fn parse_from_json_v1_6<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_6::bom::Bom = serde_json::from_reader(&mut reader)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.6 of the specification](https://cyclonedx.org/docs/1.6/xml/)
+    pub fn parse_from_xml_v1_6<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        read_xml::<crate::specs::v1_6::bom::Bom, _>(reader, XmlReadOptions::default())
+            .map(|(bom, _)| bom)
+    }
+
+    /// Output as a JSON document conforming to [version 1.6 of the specification](https://cyclonedx.org/docs/1.6/json/)
+    pub fn output_as_json_v1_6<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let bom: crate::specs::v1_6::bom::Bom = self.try_into()?;
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.6 of the specification](https://cyclonedx.org/docs/1.6/xml/)
+    pub fn output_as_xml_v1_6<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = EmitterConfig::default().perform_indent(true);
+        let mut event_writer = EventWriter::new_with_config(writer, config);
+
+        let bom: crate::specs::v1_6::bom::Bom = self.try_into()?;
+        bom.write_xml_element(&mut event_writer)
+    }
+
+    /// Returns the component with the given bom-ref, including the metadata component and nested
+    /// components.
+    ///
+    /// For repeated lookups, build a [`BomIndex`] once instead.
+    pub fn component_by_ref(&self, bom_ref: &str) -> Option<&Component> {
+        all_components(self)
+            .into_iter()
+            .find(|component| component.bom_ref.as_deref() == Some(bom_ref))
+    }
+
+    /// Returns all components matching the given purl, see [`purl_matches`] for the rules.
+    ///
+    /// A purl without version, e.g. `pkg:cargo/serde`, matches all versions of the package.
+    pub fn components_matching_purl(&self, purl: &Purl) -> Vec<&Component> {
+        all_components(self)
+            .into_iter()
+            .filter(|component| {
+                component
+                    .purl
+                    .as_ref()
+                    .is_some_and(|candidate| purl_matches(candidate, purl))
+            })
+            .collect()
+    }
+
+    /// Builds a [`BomIndex`] for fast lookups of components.
+    pub fn index(&self) -> BomIndex<'_> {
+        BomIndex::new(self)
+    }
+
+    /// Removes identical external references and sorts the remaining ones, see
+    /// [`ExternalReferences::normalize`].
+    ///
+    /// This applies to the external references of the BOM and of all components and services,
+    /// including nested ones.
+    pub fn normalize_external_references(&mut self) {
+        fn normalize_component(component: &mut Component) {
+            if let Some(references) = &mut component.external_references {
+                references.normalize();
+            }
+            for component in component.components.iter_mut().flat_map(|c| &mut c.0) {
+                normalize_component(component);
+            }
+        }
+
+        fn normalize_service(service: &mut Service) {
+            if let Some(references) = &mut service.external_references {
+                references.normalize();
+            }
+            for service in service.services.iter_mut().flat_map(|s| &mut s.0) {
+                normalize_service(service);
+            }
+        }
+
+        if let Some(references) = &mut self.external_references {
+            references.normalize();
+        }
+        if let Some(component) = self.metadata.as_mut().and_then(|m| m.component.as_mut()) {
+            normalize_component(component);
+        }
+        for component in self.components.iter_mut().flat_map(|c| &mut c.0) {
+            normalize_component(component);
+        }
+        for service in self.services.iter_mut().flat_map(|s| &mut s.0) {
+            normalize_service(service);
+        }
+    }
+
+    /// Keeps only the components, including nested ones, for which the predicate returns `true`.
+    ///
+    /// Removing a component also removes its nested components. The dependencies and
+    /// compositions are rewritten so that they no longer reference removed bom-refs, and the
+    /// dependencies of a removed component are passed on to its dependents. The metadata
+    /// component is never removed.
+    pub fn filter_components<P>(&mut self, predicate: P)
+    where
+        P: FnMut(&Component) -> bool,
+    {
+        crate::models::redaction::filter_components(self, predicate);
+    }
+
+    /// Returns a copy of the BOM in a canonical form, in which the order of elements no longer
+    /// matters.
+    ///
+    /// All unordered collections, like components, services, dependencies, hashes, licenses and
+    /// properties, are sorted at every nesting level, leading and trailing whitespace is trimmed
+    /// from names, versions and descriptions, and hash values are lowercased.
+    pub fn normalized(&self) -> Bom {
+        let mut bom = self.clone();
+        crate::models::normalization::normalize(&mut bom);
+        bom
+    }
+
+    /// Compares two BOMs regardless of element ordering and insignificant whitespace, by
+    /// comparing their [`Bom::normalized`] forms.
+    pub fn semantically_equals(&self, other: &Bom) -> bool {
+        self == other || self.normalized() == other.normalized()
+    }
+
+    /// Builds a [`DependencyGraph`] over the dependencies of the BOM.
+    ///
+    /// A BOM without dependencies results in an empty graph.
+    pub fn dependency_graph(&self) -> DependencyGraph<'_> {
+        self.dependencies
+            .as_ref()
+            .map(DependencyGraph::new)
+            .unwrap_or_default()
+    }
+
+    /// Returns all components in document order, starting with the metadata component and
+    /// followed by the top level components, each directly followed by its nested components.
+    pub fn iter_components_recursive(&self) -> impl Iterator<Item = &Component> {
+        all_components(self).into_iter()
+    }
+
+    /// Moves all nested components of the top level components to the top level.
+    ///
+    /// The nested components directly follow their former parent. To keep the information
+    /// which components formed an assembly, a composition with aggregate `not_specified` is
+    /// added for every former parent with a bom-ref, listing the parent followed by the
+    /// bom-refs of its former nested components. The metadata component is left untouched.
+    pub fn flatten(&mut self) {
+        fn hoist(
+            mut component: Component,
+            result: &mut Vec<Component>,
+            compositions: &mut Vec<Composition>,
+        ) {
+            let nested = component.components.take().map(|c| c.0).unwrap_or_default();
+            let assembly = component.bom_ref.clone().filter(|_| !nested.is_empty());
+            result.push(component);
+
+            let mut assemblies = Vec::new();
+            for component in nested {
+                if let Some(bom_ref) = &component.bom_ref {
+                    assemblies.push(BomReference::new(bom_ref));
+                }
+                hoist(component, result, compositions);
+            }
+
+            if let Some(bom_ref) = assembly {
+                assemblies.insert(0, BomReference::new(bom_ref));
+                compositions.push(Composition {
+                    bom_ref: None,
+                    aggregate: AggregateType::NotSpecified,
+                    assemblies: Some(assemblies),
+                    dependencies: None,
+                    vulnerabilities: None,
+                    signature: None,
+                });
+            }
+        }
+
+        let Some(components) = self.components.take() else {
+            return;
+        };
+
+        let mut result = Vec::with_capacity(components.0.len());
+        let mut compositions = Vec::new();
+        for component in components.0 {
+            hoist(component, &mut result, &mut compositions);
+        }
+
+        self.components = Some(Components(result));
+        if !compositions.is_empty() {
+            self.compositions
+                .get_or_insert_with(|| Compositions(Vec::new()))
+                .0
+                .extend(compositions);
+        }
+    }
+}
+
+/// The layout of JSON output, see [`Bom::output_as_json_to_writer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonFormat {
+    /// Indented with two spaces, one field per line
+    #[default]
+    Pretty,
+    /// Without any whitespace
+    Compact,
+}
+
+/// The options of JSON output, see [`Bom::output_as_json_with_options`].
+///
+/// The default matches the output of [`Bom::output_as_json`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    pub format: JsonFormat,
+    /// Whether to write the keys of all objects in alphabetical order instead of the order of
+    /// the specification
+    pub sort_keys: bool,
+    /// Whether to write the `$schema` property with the URL of the JSON schema of the spec version
+    pub schema: bool,
+}
+
+/// A BOM with the optional `$schema` property in front of its fields
+#[derive(Serialize)]
+struct JsonDocument<'a, B> {
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    schema: Option<String>,
+    #[serde(flatten)]
+    bom: &'a B,
+}
+
+fn write_json<B, W>(
+    bom: Bom,
+    writer: &mut W,
+    version: SpecVersion,
+    options: &OutputOptions,
+) -> Result<(), crate::errors::JsonWriteError>
+where
+    B: TryFrom<Bom, Error = BomError> + Serialize,
+    W: std::io::Write,
+{
+    let bom = B::try_from(bom)?;
+    let document = JsonDocument {
+        schema: options
+            .schema
+            .then(|| format!("http://cyclonedx.org/schema/bom-{version}.schema.json")),
+        bom: &bom,
+    };
+
+    if options.sort_keys {
+        let document = sort_keys(serde_json::to_value(&document)?);
+        write_json_value(&document, writer, options.format)
+    } else {
+        write_json_value(&document, writer, options.format)
+    }
+}
+
+fn write_json_value<T, W>(
+    value: &T,
+    writer: &mut W,
+    format: JsonFormat,
+) -> Result<(), crate::errors::JsonWriteError>
+where
+    T: Serialize,
+    W: std::io::Write,
+{
+    match format {
+        JsonFormat::Pretty => serde_json::to_writer_pretty(writer, value)?,
+        JsonFormat::Compact => serde_json::to_writer(writer, value)?,
+    }
+    Ok(())
+}
+
+/// Sorts the keys of all objects, regardless of whether `serde_json` preserves their order.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+/// The layout of XML output, see [`Bom::output_as_xml_to_writer`].
+///
+/// The default matches the output of [`Bom::output_as_xml`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XmlFormat {
+    /// The string of one level of indentation, `None` writes the document on a single line
+    pub indent: Option<String>,
+    /// Whether to start with an XML declaration such as `<?xml version="1.0" encoding="utf-8"?>`
+    pub declaration: bool,
+    /// The encoding named in the XML declaration, the output is always UTF-8 so this only
+    /// chooses its spelling, e.g. `UTF-8`
+    pub encoding: String,
+    /// Whether to write empty elements as `<a />` instead of `<a></a>`
+    pub self_closing: bool,
+    /// Whether to write a space before the `/>` of self-closing elements
+    pub pad_self_closing: bool,
+    /// Whether to write the attributes of each element in alphabetical order instead of the
+    /// order of the specification
+    pub sort_attributes: bool,
+}
+
+impl Default for XmlFormat {
+    fn default() -> Self {
+        Self {
+            indent: Some("  ".to_string()),
+            declaration: true,
+            encoding: "utf-8".to_string(),
+            self_closing: true,
+            pad_self_closing: true,
+            sort_attributes: false,
+        }
+    }
+}
+
+impl XmlFormat {
+    fn emitter_config(&self) -> EmitterConfig {
+        let config = EmitterConfig::default()
+            .perform_indent(self.indent.is_some())
+            .write_document_declaration(false)
+            .normalize_empty_elements(self.self_closing)
+            .pad_self_closing(self.pad_self_closing);
+        match &self.indent {
+            Some(indent) => config.indent_string(indent.clone()),
+            None => config,
+        }
+    }
+}
+
+/// Options for parsing XML documents, see [`Bom::parse_from_xml_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct XmlReadOptions {
+    /// Whether elements and text that the specification does not allow in their place fail the
+    /// parse, instead of being skipped with an [`XmlReadWarning`] as by default
+    pub strict: bool,
+}
+
+/// Content of an XML document that was skipped because it is not expected in its place, e.g. an
+/// element added by another tool
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XmlReadWarning {
+    /// The name of the element containing the skipped content
+    pub element: String,
+    /// The skipped content, either the tag of an element such as `<note>` or `text`
+    pub skipped: String,
+}
+
+impl fmt::Display for XmlReadWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Skipped unexpected {} in {}", self.skipped, self.element)
+    }
+}
+
+fn read_xml<B, R>(
+    reader: R,
+    options: XmlReadOptions,
+) -> Result<(Bom, Vec<XmlReadWarning>), crate::errors::XmlReadError>
+where
+    B: FromXmlDocument + Into<Bom>,
+    R: std::io::Read,
+{
+    let mut event_reader = XmlReader::from_reader(reader, options);
+    let bom = B::read_xml_document(&mut event_reader)?;
+    Ok((bom.into(), event_reader.into_warnings()))
+}
+
+fn write_xml<B, W>(
+    bom: Bom,
+    writer: &mut W,
+    format: &XmlFormat,
+) -> Result<(), crate::errors::XmlWriteError>
+where
+    B: TryFrom<Bom, Error = BomError> + ToXml,
+    W: std::io::Write,
+{
+    let bom = B::try_from(bom)?;
+    let mut event_writer = EventWriter::new_with_config(writer, format.emitter_config());
+    if format.declaration {
+        event_writer
+            .write(xml::writer::XmlEvent::StartDocument {
+                version: xml::common::XmlVersion::Version10,
+                encoding: Some(&format.encoding),
+                standalone: None,
+            })
+            .map_err(to_xml_write_error("bom"))?;
+    }
+
+    if !format.sort_attributes {
+        return bom.write_xml_element(&mut event_writer);
+    }
+
+    // The attributes are written in the order of the specification by the elements, so the
+    // document is written once without formatting and its events are sorted and written again.
+    let mut unsorted = Vec::new();
+    let config = EmitterConfig::default().write_document_declaration(false);
+    bom.write_xml_element(&mut EventWriter::new_with_config(&mut unsorted, config))?;
+
+    let config = xml::ParserConfig::default().ignore_comments(false);
+    for event in EventReader::new_with_config(unsorted.as_slice(), config) {
+        let mut event =
+            event.map_err(|error| BomError::XmlSerializationError(error.to_string()))?;
+        match &mut event {
+            xml::reader::XmlEvent::StartDocument { .. } => continue,
+            xml::reader::XmlEvent::StartElement { attributes, .. } => {
+                attributes.sort_by_key(|attribute| attribute.name.to_string());
+            }
+            _ => (),
+        }
+        if let Some(event) = event.as_writer_event() {
+            event_writer
+                .write(event)
+                .map_err(to_xml_write_error("bom"))?;
+        }
+    }
+    Ok(())
 }
 
 impl Default for Bom {
@@ -351,6 +1009,8 @@ impl Default for Bom {
             signature: None,
             annotations: None,
             formulation: None,
+            declarations: None,
+            definitions: None,
             spec_version: SpecVersion::V1_3,
         }
     }
@@ -358,6 +1018,30 @@ impl Default for Bom {
 
 impl Validate for Bom {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        self.validate_version_with_tracker(version, None)
+    }
+
+    fn validate(&self) -> ValidationResult {
+        self.validate_version(self.spec_version)
+    }
+}
+
+impl Bom {
+    /// Validates the BOM against its spec version like [`validate`](Validate::validate), while
+    /// reporting the validated top level components to the handler.
+    pub fn validate_with_progress(&self, handler: &dyn ProgressHandler) -> ValidationResult {
+        let total = self.components.as_ref().map_or(0, |c| c.0.len()) as u64;
+        let tracker = ProgressTracker::start(handler, Phase::Validation, Some(total));
+        let result = self.validate_version_with_tracker(self.spec_version, Some(&tracker));
+        tracker.finish();
+        result
+    }
+
+    fn validate_version_with_tracker(
+        &self,
+        version: SpecVersion,
+        tracker: Option<&ProgressTracker>,
+    ) -> ValidationResult {
         let mut context = ValidationContext::new();
         context.add_field_option(
             "serial_number",
@@ -365,7 +1049,17 @@ impl Validate for Bom {
             validate_urn_uuid,
         );
         context.add_struct_option("metadata", self.metadata.as_ref(), version);
-        context.add_struct_option("components", self.components.as_ref(), version);
+        context.add_struct_option(
+            "components",
+            self.components
+                .as_ref()
+                .map(|components| TrackedComponents {
+                    components,
+                    tracker,
+                })
+                .as_ref(),
+            version,
+        );
         context.add_struct_option("services", self.services.as_ref(), version);
         context.add_struct_option(
             "external_references",
@@ -375,6 +1069,12 @@ impl Validate for Bom {
         context.add_struct_option("compositions", self.compositions.as_ref(), version);
         context.add_struct_option("properties", self.properties.as_ref(), version);
         context.add_struct_option("vulnerabilities", self.vulnerabilities.as_ref(), version);
+        context.add_struct_option("annotations", self.annotations.as_ref(), version);
+        context.add_list_option("formulation", self.formulation.as_ref(), |formula| {
+            formula.validate_version(version)
+        });
+        context.add_struct_option("declarations", self.declarations.as_ref(), version);
+        context.add_struct_option("definitions", self.definitions.as_ref(), version);
 
         // To keep track of all Bom references inside.
         let mut bom_refs = BomReferencesContext::default();
@@ -397,16 +1097,28 @@ impl Validate for Bom {
             validate_vulnerabilities(&mut context, &mut bom_refs, vulnerabilities);
         }
 
+        if let Some(formulation) = &self.formulation {
+            validate_formulation(&mut context, &mut bom_refs, formulation);
+        }
+
+        if let Some(declarations) = &self.declarations {
+            validate_declarations(&mut context, &mut bom_refs, declarations);
+        }
+
+        if let Some(definitions) = &self.definitions {
+            validate_definitions(&mut context, &mut bom_refs, definitions);
+        }
+
         // Check dependencies & sub dependencies
         if let Some(dependencies) = &self.dependencies {
             for dependency in &dependencies.0 {
                 if !bom_refs.contains(&dependency.dependency_ref) {
                     context.add_custom(
                         "dependency_ref",
-                        format!(
+                        unknown_reference(format!(
                             "Dependency ref '{}' does not exist in the BOM",
                             dependency.dependency_ref
-                        ),
+                        )),
                     );
                 }
 
@@ -414,52 +1126,47 @@ impl Validate for Bom {
                     if !bom_refs.contains(sub_dependency) {
                         context.add_custom(
                             "sub dependency_ref",
-                            format!(
+                            unknown_reference(format!(
                                 "Dependency ref '{}' does not exist in the BOM",
                                 sub_dependency
-                            ),
+                            )),
                         );
                     }
                 }
             }
         }
 
-        // Check compositions, its dependencies & assemblies
+        // Check compositions, its assemblies, dependencies & vulnerabilities
         if let Some(compositions) = &self.compositions {
-            for composition in &compositions.0 {
-                if let Some(assemblies) = &composition.assemblies {
-                    for BomReference(assembly) in assemblies {
-                        if !bom_refs.contains(assembly) {
-                            context.add_custom(
-                                "composition ref",
-                                format!(
-                                    "Composition reference '{assembly}' does not exist in the BOM"
-                                ),
-                            );
-                        }
-                    }
-                }
+            validate_compositions(&mut context, &mut bom_refs, compositions);
+        }
 
-                if let Some(dependencies) = &composition.dependencies {
-                    for BomReference(dependency) in dependencies {
-                        if !bom_refs.contains(dependency) {
-                            context.add_custom(
-                                "composition ref",
-                                format!(
-                                    "Composition reference '{dependency}' does not exist in the BOM"
-                                ),
-                            );
-                        }
-                    }
-                }
-            }
+        // Check annotations & their subjects
+        if let Some(annotations) = &self.annotations {
+            validate_annotations(&mut context, &mut bom_refs, annotations);
         }
 
         context.into()
     }
+}
 
-    fn validate(&self) -> ValidationResult {
-        return self.validate_version(self.spec_version);
+/// Validates the components exactly like [`Components`] does, while counting them.
+struct TrackedComponents<'a> {
+    components: &'a Components,
+    tracker: Option<&'a ProgressTracker<'a>>,
+}
+
+impl Validate for TrackedComponents<'_> {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list("inner", &self.components.0, |component| {
+                let result = component.validate_version(version);
+                if let Some(tracker) = self.tracker {
+                    tracker.advance(1);
+                }
+                result
+            })
+            .into()
     }
 }
 
@@ -468,6 +1175,11 @@ struct BomReferencesContext {
     component_bom_refs: HashSet<String>,
     service_bom_refs: HashSet<String>,
     vulnerabilities_bom_refs: HashSet<String>,
+    composition_bom_refs: HashSet<String>,
+    annotation_bom_refs: HashSet<String>,
+    formulation_bom_refs: HashSet<String>,
+    declaration_bom_refs: HashSet<String>,
+    definition_bom_refs: HashSet<String>,
 }
 
 impl BomReferencesContext {
@@ -475,6 +1187,11 @@ impl BomReferencesContext {
         self.component_bom_refs.contains(bom_ref)
             || self.service_bom_refs.contains(bom_ref)
             || self.vulnerabilities_bom_refs.contains(bom_ref)
+            || self.composition_bom_refs.contains(bom_ref)
+            || self.annotation_bom_refs.contains(bom_ref)
+            || self.formulation_bom_refs.contains(bom_ref)
+            || self.declaration_bom_refs.contains(bom_ref)
+            || self.definition_bom_refs.contains(bom_ref)
     }
 
     fn add_component_bom_ref(&mut self, bom_ref: impl ToString) {
@@ -488,6 +1205,26 @@ impl BomReferencesContext {
     fn add_vulnerability_bom_ref(&mut self, bom_ref: impl ToString) {
         self.vulnerabilities_bom_refs.insert(bom_ref.to_string());
     }
+
+    fn add_composition_bom_ref(&mut self, bom_ref: impl ToString) {
+        self.composition_bom_refs.insert(bom_ref.to_string());
+    }
+
+    fn add_annotation_bom_ref(&mut self, bom_ref: impl ToString) {
+        self.annotation_bom_refs.insert(bom_ref.to_string());
+    }
+
+    fn add_formulation_bom_ref(&mut self, bom_ref: impl ToString) {
+        self.formulation_bom_refs.insert(bom_ref.to_string());
+    }
+
+    fn add_declaration_bom_ref(&mut self, bom_ref: impl ToString) {
+        self.declaration_bom_refs.insert(bom_ref.to_string());
+    }
+
+    fn add_definition_bom_ref(&mut self, bom_ref: impl ToString) {
+        self.definition_bom_refs.insert(bom_ref.to_string());
+    }
 }
 
 /// Validates the Bom references.
@@ -498,7 +1235,7 @@ fn validate_component_bom_refs(
 ) {
     if let Some(bom_ref) = &component.bom_ref {
         if bom_refs.contains(bom_ref) {
-            context.add_custom("bom_ref", format!(r#"Bom ref "{bom_ref}" is not unique"#));
+            context.add_custom("bom_ref", duplicate_bom_ref(bom_ref));
         }
         bom_refs.add_component_bom_ref(bom_ref);
     }
@@ -506,6 +1243,17 @@ fn validate_component_bom_refs(
     if let Some(components) = &component.components {
         validate_components(context, bom_refs, components);
     }
+
+    if let Some(pedigree) = &component.pedigree {
+        let pedigree_components = [
+            &pedigree.ancestors,
+            &pedigree.descendants,
+            &pedigree.variants,
+        ];
+        for components in pedigree_components.into_iter().flatten() {
+            validate_components(context, bom_refs, components);
+        }
+    }
 }
 
 fn validate_components(
@@ -535,7 +1283,7 @@ fn validate_service_bom_refs(
 ) {
     if let Some(bom_ref) = &service.bom_ref {
         if bom_refs.contains(bom_ref) {
-            context.add_custom("bom_ref", format!(r#"Bom ref "{bom_ref}" is not unique"#));
+            context.add_custom("bom_ref", duplicate_bom_ref(bom_ref));
         }
         bom_refs.add_service_bom_ref(bom_ref);
     }
@@ -562,52 +1310,295 @@ fn validate_vulnerabilities_bom_refs(
 ) {
     if let Some(bom_ref) = &vulnerability.bom_ref {
         if bom_refs.contains(bom_ref) {
-            context.add_custom("bom_ref", format!(r#"Bom ref "{bom_ref}" is not unique"#));
+            context.add_custom("bom_ref", duplicate_bom_ref(bom_ref));
         }
         bom_refs.add_vulnerability_bom_ref(bom_ref);
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct UrnUuid(pub String);
+fn validate_formulation(
+    context: &mut ValidationContext,
+    bom_refs: &mut BomReferencesContext,
+    formulation: &[Formula],
+) {
+    for formula in formulation {
+        let workflows = formula.workflows.iter().flatten();
+        let tasks = workflows
+            .clone()
+            .flat_map(|workflow| workflow.tasks.iter().flatten().map(|task| &task.bom_ref));
+        let formulation_bom_refs = formula
+            .bom_ref
+            .iter()
+            .chain(workflows.map(|workflow| &workflow.bom_ref))
+            .chain(tasks);
+
+        for BomReference(bom_ref) in formulation_bom_refs {
+            if bom_refs.contains(bom_ref) {
+                context.add_custom("bom_ref", duplicate_bom_ref(bom_ref));
+            }
+            bom_refs.add_formulation_bom_ref(bom_ref);
+        }
 
-impl UrnUuid {
-    pub fn new(value: String) -> Result<Self, UrnUuidError> {
-        match matches_urn_uuid_regex(&value) {
-            true => Ok(Self(value)),
-            false => Err(UrnUuidError::InvalidUrnUuid(
-                "UrnUuid does not match regular expression".to_string(),
-            )),
+        if let Some(components) = &formula.components {
+            validate_components(context, bom_refs, components);
         }
-    }
 
-    pub fn generate() -> Self {
-        Self::from(uuid::Uuid::new_v4())
+        if let Some(services) = &formula.services {
+            validate_services(context, bom_refs, services);
+        }
     }
 }
 
-impl fmt::Display for UrnUuid {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+fn validate_declarations(
+    context: &mut ValidationContext,
+    bom_refs: &mut BomReferencesContext,
+    declarations: &Declarations,
+) {
+    let assessors = declarations.assessors.iter().flatten();
+    let claims = declarations.claims.iter().flatten();
+    let evidence = declarations.evidence.iter().flatten();
+    let declaration_bom_refs = assessors
+        .filter_map(|assessor| assessor.bom_ref.as_ref())
+        .chain(claims.filter_map(|claim| claim.bom_ref.as_ref()))
+        .chain(evidence.filter_map(|evidence| evidence.bom_ref.as_ref()));
+
+    for BomReference(bom_ref) in declaration_bom_refs {
+        if bom_refs.contains(bom_ref) {
+            context.add_custom("bom_ref", duplicate_bom_ref(bom_ref));
+        }
+        bom_refs.add_declaration_bom_ref(bom_ref);
     }
-}
 
-impl From<uuid::Uuid> for UrnUuid {
-    fn from(uuid: uuid::Uuid) -> Self {
-        Self(format!("urn:uuid:{}", uuid))
+    if let Some(targets) = &declarations.targets {
+        if let Some(components) = &targets.components {
+            validate_components(context, bom_refs, components);
+        }
+
+        if let Some(services) = &targets.services {
+            validate_services(context, bom_refs, services);
+        }
+    }
+}
+
+fn validate_definitions(
+    context: &mut ValidationContext,
+    bom_refs: &mut BomReferencesContext,
+    definitions: &Definitions,
+) {
+    for standard in definitions.standards.iter().flatten() {
+        let requirements = standard.requirements.iter().flatten();
+        let levels = standard.levels.iter().flatten();
+        let definition_bom_refs = standard
+            .bom_ref
+            .iter()
+            .chain(requirements.filter_map(|requirement| requirement.bom_ref.as_ref()))
+            .chain(levels.filter_map(|level| level.bom_ref.as_ref()));
+
+        for BomReference(bom_ref) in definition_bom_refs {
+            if bom_refs.contains(bom_ref) {
+                context.add_custom("bom_ref", duplicate_bom_ref(bom_ref));
+            }
+            bom_refs.add_definition_bom_ref(bom_ref);
+        }
+
+        let requirement_bom_refs: HashSet<&String> = standard
+            .requirements
+            .iter()
+            .flatten()
+            .filter_map(|requirement| requirement.bom_ref.as_ref())
+            .map(|BomReference(bom_ref)| bom_ref)
+            .collect();
+
+        let parents = standard
+            .requirements
+            .iter()
+            .flatten()
+            .filter_map(|requirement| requirement.parent.as_ref());
+        let level_requirements = standard
+            .levels
+            .iter()
+            .flatten()
+            .flat_map(|level| level.requirements.iter().flatten());
+
+        for reference in parents.chain(level_requirements) {
+            if !requirement_bom_refs.contains(reference) {
+                context.add_custom(
+                    "requirement ref",
+                    unknown_reference(format!(
+                        "Requirement reference '{reference}' does not exist in the standard"
+                    )),
+                );
+            }
+        }
+    }
+}
+
+fn validate_compositions(
+    context: &mut ValidationContext,
+    bom_refs: &mut BomReferencesContext,
+    compositions: &Compositions,
+) {
+    for composition in &compositions.0 {
+        if let Some(BomReference(bom_ref)) = &composition.bom_ref {
+            if bom_refs.contains(bom_ref) {
+                context.add_custom("bom_ref", duplicate_bom_ref(bom_ref));
+            }
+            bom_refs.add_composition_bom_ref(bom_ref);
+        }
+    }
+
+    for composition in &compositions.0 {
+        let assemblies = composition.assemblies.iter().flatten();
+        let dependencies = composition.dependencies.iter().flatten();
+        for BomReference(reference) in assemblies.chain(dependencies) {
+            if !bom_refs.contains(reference) {
+                context.add_custom(
+                    "composition ref",
+                    unknown_reference(format!(
+                        "Composition reference '{reference}' does not exist in the BOM"
+                    )),
+                );
+            }
+        }
+
+        for BomReference(reference) in composition.vulnerabilities.iter().flatten() {
+            if !bom_refs.vulnerabilities_bom_refs.contains(reference) {
+                context.add_custom(
+                    "composition vulnerability ref",
+                    unknown_reference(format!(
+                        "Composition vulnerability reference '{reference}' does not exist in the BOM"
+                    )),
+                );
+            }
+        }
+    }
+}
+
+fn validate_annotations(
+    context: &mut ValidationContext,
+    bom_refs: &mut BomReferencesContext,
+    annotations: &Annotations,
+) {
+    for annotation in &annotations.0 {
+        if let Some(bom_ref) = &annotation.bom_ref {
+            if bom_refs.contains(bom_ref) {
+                context.add_custom("bom_ref", duplicate_bom_ref(bom_ref));
+            }
+            bom_refs.add_annotation_bom_ref(bom_ref);
+        }
+    }
+
+    for annotation in &annotations.0 {
+        // Subjects in other BOMs are referenced by BOM-Link and cannot be checked here.
+        for subject in annotation
+            .subjects
+            .iter()
+            .filter(|s| !s.starts_with("urn:cdx:"))
+        {
+            if !bom_refs.contains(subject) {
+                context.add_custom(
+                    "annotation subject",
+                    unknown_reference(format!(
+                        "Annotation subject '{subject}' does not exist in the BOM"
+                    )),
+                );
+            }
+        }
+    }
+}
+
+/// A serial number in the `urn:uuid:` format of [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122)
+///
+/// ```
+/// use cyclonedx_bom::models::bom::UrnUuid;
+///
+/// let serial_number: UrnUuid = "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79"
+///     .parse()
+///     .expect("Failed to parse serial number");
+/// assert_eq!(serial_number.to_string(), "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UrnUuid(pub String);
+
+impl UrnUuid {
+    /// Constructs a `UrnUuid`, failing if the value does not match the `urn:uuid:` format
+    pub fn new(value: String) -> Result<Self, UrnUuidError> {
+        match matches_urn_uuid_regex(&value) {
+            true => Ok(Self(value)),
+            false => Err(UrnUuidError::InvalidUrnUuid(
+                "UrnUuid does not match regular expression".to_string(),
+            )),
+        }
+    }
+
+    /// Generates a random (version 4) serial number
+    pub fn generate() -> Self {
+        Self::from(uuid::Uuid::new_v4())
+    }
+
+    /// Generates a name-based (version 5) serial number derived from the given content
+    ///
+    /// The same content always results in the same serial number, which keeps the serial
+    /// numbers of reproducible builds stable.
+    /// ```
+    /// use cyclonedx_bom::models::bom::UrnUuid;
+    ///
+    /// assert_eq!(
+    ///     UrnUuid::generate_from_content("my-crate 1.0.0"),
+    ///     UrnUuid::generate_from_content("my-crate 1.0.0"),
+    /// );
+    /// ```
+    pub fn generate_from_content<C: AsRef<[u8]>>(content: C) -> Self {
+        static NAMESPACE: Lazy<uuid::Uuid> =
+            Lazy::new(|| uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_URL, b"https://cyclonedx.org/"));
+        Self::from(uuid::Uuid::new_v5(&NAMESPACE, content.as_ref()))
     }
 }
 
+impl FromStr for UrnUuid {
+    type Err = UrnUuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_string())
+    }
+}
+
+impl fmt::Display for UrnUuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<uuid::Uuid> for UrnUuid {
+    fn from(uuid: uuid::Uuid) -> Self {
+        Self(format!("urn:uuid:{}", uuid))
+    }
+}
+
+fn duplicate_bom_ref(bom_ref: &str) -> ValidationError {
+    ValidationError::new(format!(r#"Bom ref "{bom_ref}" is not unique"#))
+        .with_code(code::DUPLICATE_BOM_REF)
+}
+
+fn unknown_reference(message: String) -> ValidationError {
+    ValidationError::new(message).with_code(code::UNKNOWN_REFERENCE)
+}
+
 /// Validates a given [`UrnUuid`].
 pub fn validate_urn_uuid(urn_uuid: &UrnUuid) -> Result<(), ValidationError> {
     if !matches_urn_uuid_regex(&urn_uuid.0) {
-        return Err("UrnUuid does not match regular expression".into());
+        return Err(
+            ValidationError::new("UrnUuid does not match regular expression")
+                .with_code(code::INVALID_URN_UUID),
+        );
     }
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
 pub enum UrnUuidError {
+    #[error("Invalid UrnUuid: {}", .0)]
     InvalidUrnUuid(String),
 }
 
@@ -626,10 +1617,13 @@ mod test {
             date_time::DateTime, normalized_string::NormalizedString, uri::Uri as Url,
         },
         models::{
-            component::{Classification, Component},
+            annotation::{Annotation, Annotator},
+            component::{Classification, Component, Pedigree},
             composition::{AggregateType, Composition},
+            definitions::{Level, Requirement, Standard},
             dependency::Dependency,
             external_reference::{ExternalReference, ExternalReferenceType, Uri},
+            organization::OrganizationalEntity,
             property::Property,
             service::Service,
             vulnerability::Vulnerability,
@@ -640,6 +1634,352 @@ mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn it_should_parse_boms_of_any_format_and_version() {
+        let json = r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1}"#;
+        let (_, version) = Bom::parse_auto(json).expect("Failed to parse JSON");
+        assert_eq!(version, SpecVersion::V1_5);
+
+        let schema = br#"
+            {
+                "$schema": "http://cyclonedx.org/schema/bom-1.4.schema.json",
+                "bomFormat": "CycloneDX",
+                "specVersion": "1.4",
+                "version": 1
+            }"#;
+        let (_, version) = Bom::parse_auto(schema.as_slice()).expect("Failed to parse JSON");
+        assert_eq!(version, SpecVersion::V1_4);
+
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="2"/>"#;
+        let (bom, version) = Bom::parse_auto(xml).expect("Failed to parse XML");
+        assert_eq!(version, SpecVersion::V1_3);
+        assert_eq!(bom.version, 2);
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_unknown_formats_and_versions() {
+        assert!(matches!(
+            Bom::parse_auto("bom"),
+            Err(ParseError::UnknownFormat)
+        ));
+        assert!(matches!(
+            Bom::parse_auto(r#"{"bomFormat": "CycloneDX", "version": 1}"#),
+            Err(ParseError::UnknownSpecVersion(_))
+        ));
+        assert!(matches!(
+            Bom::parse_auto(r#"<bom xmlns="http://cyclonedx.org/schema/bom/2.0"/>"#),
+            Err(ParseError::BomError(BomError::UnsupportedSpecVersion(_)))
+        ));
+    }
+
+    #[test]
+    fn it_should_output_json_in_the_given_format() {
+        let bom = Bom {
+            serial_number: None,
+            ..Bom::default()
+        };
+
+        let mut pretty = Vec::new();
+        bom.clone()
+            .output_as_json_to_writer(&mut pretty, SpecVersion::V1_4, JsonFormat::Pretty)
+            .expect("Failed to write BOM");
+        let mut compact = Vec::new();
+        bom.clone()
+            .output_as_json_to_writer(&mut compact, SpecVersion::V1_4, JsonFormat::Compact)
+            .expect("Failed to write BOM");
+        let mut default = Vec::new();
+        bom.output_as_json_v1_4(&mut default)
+            .expect("Failed to write BOM");
+
+        assert_eq!(pretty, default);
+        assert!(!compact.contains(&b' ') && !compact.contains(&b'\n'));
+        assert_eq!(
+            serde_json::from_slice::<Value>(&compact).unwrap(),
+            serde_json::from_slice::<Value>(&pretty).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_output_json_with_sorted_keys_and_schema() {
+        let bom = Bom {
+            serial_number: Some(UrnUuid(
+                "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string(),
+            )),
+            metadata: Some(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "1.0.0",
+                    Some("app".to_string()),
+                )),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+        let options = OutputOptions {
+            format: JsonFormat::Pretty,
+            sort_keys: true,
+            schema: true,
+        };
+
+        let mut output = Vec::new();
+        bom.clone()
+            .output_as_json_with_options(&mut output, SpecVersion::V1_4, &options)
+            .expect("Failed to write BOM");
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!(
+            output,
+            r#"{
+  "$schema": "http://cyclonedx.org/schema/bom-1.4.schema.json",
+  "bomFormat": "CycloneDX",
+  "metadata": {
+    "component": {
+      "bom-ref": "app",
+      "name": "app",
+      "type": "application",
+      "version": "1.0.0"
+    }
+  },
+  "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+  "specVersion": "1.4",
+  "version": 1
+}"#
+        );
+        let parsed = Bom::parse_from_json_v1_4(output.as_bytes()).expect("Failed to parse BOM");
+        assert_eq!(parsed.metadata, bom.metadata);
+    }
+
+    #[test]
+    fn it_should_output_xml_in_the_default_format() {
+        let bom = Bom {
+            serial_number: None,
+            ..Bom::default()
+        };
+
+        let mut formatted = Vec::new();
+        bom.clone()
+            .output_as_xml_to_writer(&mut formatted, SpecVersion::V1_5, &XmlFormat::default())
+            .expect("Failed to write BOM");
+        let mut default = Vec::new();
+        bom.output_as_xml_v1_5(&mut default)
+            .expect("Failed to write BOM");
+
+        assert_eq!(
+            String::from_utf8(formatted).unwrap(),
+            String::from_utf8(default).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_output_xml_in_the_given_format() {
+        let bom = Bom {
+            serial_number: None,
+            metadata: Some(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "1.0.0",
+                    Some("app".to_string()),
+                )),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+        let format = XmlFormat {
+            indent: Some("\t".to_string()),
+            encoding: "UTF-8".to_string(),
+            pad_self_closing: false,
+            sort_attributes: true,
+            ..XmlFormat::default()
+        };
+
+        let mut output = Vec::new();
+        bom.clone()
+            .output_as_xml_to_writer(&mut output, SpecVersion::V1_5, &format)
+            .expect("Failed to write BOM");
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!(
+            output,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <bom xmlns=\"http://cyclonedx.org/schema/bom/1.5\" version=\"1\">\n\
+             \t<metadata>\n\
+             \t\t<component bom-ref=\"app\" type=\"application\">\n\
+             \t\t\t<name>app</name>\n\
+             \t\t\t<version>1.0.0</version>\n\
+             \t\t</component>\n\
+             \t</metadata>\n\
+             </bom>"
+        );
+        let parsed = Bom::parse_from_xml_v1_5(output.as_bytes()).unwrap();
+        assert_eq!(parsed.metadata, bom.metadata);
+    }
+
+    #[test]
+    fn it_should_output_xml_without_indentation_and_declaration() {
+        let bom = Bom {
+            serial_number: None,
+            components: Some(Components(Vec::new())),
+            ..Bom::default()
+        };
+        let format = XmlFormat {
+            indent: None,
+            declaration: false,
+            self_closing: false,
+            ..XmlFormat::default()
+        };
+
+        let mut output = Vec::new();
+        bom.output_as_xml_to_writer(&mut output, SpecVersion::V1_4, &format)
+            .expect("Failed to write BOM");
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="1"><components></components></bom>"#
+        );
+    }
+
+    #[test]
+    fn it_should_iterate_nested_components() {
+        let mut component = Component::new(Classification::Library, "a", "1.0.0", None);
+        let mut nested = Component::new(Classification::Library, "b", "1.0.0", None);
+        nested.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "c",
+            "1.0.0",
+            None,
+        )]));
+        component.components = Some(Components(vec![nested]));
+        let bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "1.0.0",
+                    None,
+                )),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![
+                component,
+                Component::new(Classification::Library, "d", "1.0.0", None),
+            ])),
+            ..Bom::default()
+        };
+
+        let names: Vec<_> = bom
+            .iter_components_recursive()
+            .map(|component| component.name.to_string())
+            .collect();
+
+        assert_eq!(names, vec!["app", "a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn it_should_flatten_nested_components() {
+        let mut nested =
+            Component::new(Classification::Library, "b", "1.0.0", Some("b".to_string()));
+        nested.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "c",
+            "1.0.0",
+            Some("c".to_string()),
+        )]));
+        let mut component =
+            Component::new(Classification::Library, "a", "1.0.0", Some("a".to_string()));
+        component.components = Some(Components(vec![
+            nested,
+            Component::new(Classification::Library, "unreferenced", "1.0.0", None),
+        ]));
+        let mut bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        bom.flatten();
+
+        let components = bom.components.as_ref().unwrap();
+        assert_eq!(
+            components
+                .0
+                .iter()
+                .map(|component| component.name.to_string())
+                .collect::<Vec<_>>(),
+            vec!["a", "b", "c", "unreferenced"]
+        );
+        assert!(components.0.iter().all(|c| c.components.is_none()));
+        assert_eq!(
+            bom.compositions
+                .unwrap()
+                .0
+                .into_iter()
+                .map(|composition| composition.assemblies.unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![BomReference::new("b"), BomReference::new("c")],
+                vec![BomReference::new("a"), BomReference::new("b")],
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_normalize_nested_external_references() {
+        let reference = ExternalReference::new(
+            ExternalReferenceType::Website,
+            Url::new("https://example.com"),
+        );
+        let mut nested = Component::new(Classification::Library, "nested", "1.0.0", None);
+        nested.external_references = Some(ExternalReferences(vec![
+            reference.clone(),
+            reference.clone(),
+        ]));
+        let mut component = Component::new(Classification::Library, "component", "1.0.0", None);
+        component.components = Some(Components(vec![nested]));
+        let mut bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        bom.normalize_external_references();
+
+        let nested = &bom.components.unwrap().0[0].components.clone().unwrap().0[0];
+        assert_eq!(
+            nested.external_references,
+            Some(ExternalReferences(vec![reference]))
+        );
+    }
+
+    #[test]
+    fn it_should_report_validation_progress() {
+        let reports = std::cell::RefCell::new(Vec::new());
+        let handler = |progress: crate::progress::Progress| reports.borrow_mut().push(progress);
+        let bom = Bom {
+            components: Some(Components(vec![
+                Component::new(Classification::Library, "a", "1.0.0", None),
+                Component::new(Classification::Library, "b", "1.0.0", None),
+            ])),
+            ..Bom::default()
+        };
+
+        let result = bom.validate_with_progress(&handler);
+
+        assert_eq!(result, bom.validate());
+        assert_eq!(
+            reports
+                .into_inner()
+                .into_iter()
+                .map(|progress| (progress.phase, progress.processed, progress.total))
+                .collect::<Vec<_>>(),
+            vec![
+                (Phase::Validation, 0, Some(2)),
+                (Phase::Validation, 1, Some(2)),
+                (Phase::Validation, 2, Some(2)),
+            ]
+        );
+    }
+
     #[test]
     fn it_should_parse_json_using_function_without_suffix() {
         let input = r#"{
@@ -670,6 +2010,8 @@ mod test {
             annotations: None,
             properties: None,
             formulation: None,
+            declarations: None,
+            definitions: None,
         };
 
         let actual = bom.validate();
@@ -677,6 +2019,26 @@ mod test {
         assert!(actual.passed());
     }
 
+    #[test]
+    fn it_should_validate_iris_with_the_configured_uri_validation() {
+        let mut component = Component::new(Classification::Library, "component", "1.0.0", None);
+        component.external_references = Some(ExternalReferences(vec![ExternalReference::new(
+            ExternalReferenceType::Website,
+            Url::new("https://例え.jp/パス"),
+        )]));
+        let bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        assert!(bom.validate().has_errors());
+        let permissive = validation::ValidationOptions {
+            uri: validation::UriValidation::IriPermissive,
+        };
+        assert!(bom.validate_with_options(permissive).passed());
+        assert!(bom.validate().has_errors());
+    }
+
     #[test]
     fn it_should_validate_broken_dependency_refs_as_failed() {
         let bom = Bom {
@@ -697,6 +2059,8 @@ mod test {
             signature: None,
             annotations: None,
             formulation: None,
+            declarations: None,
+            definitions: None,
         };
 
         let actual = bom.validate();
@@ -715,6 +2079,31 @@ mod test {
             ]
             .into()
         );
+        assert!(actual
+            .issues()
+            .iter()
+            .all(|issue| issue.code == validation::code::UNKNOWN_REFERENCE));
+    }
+
+    #[test]
+    fn it_should_locate_issues_of_components_by_bom_ref() {
+        let mut component = Component::new(Classification::Library, "name", "1.0.0", None);
+        component.bom_ref = Some("library".to_string());
+        component.purl = Some(Purl("invalid".to_string()));
+        let bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        let issues = bom.validate().issues();
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, validation::code::INVALID_PURL);
+        assert_eq!(issues[0].path.bom_ref(), Some("library"));
+        assert_eq!(
+            issues[0].path.to_string(),
+            "components.inner[0][bom-ref=library].purl"
+        );
     }
 
     #[test]
@@ -741,6 +2130,8 @@ mod test {
             signature: None,
             annotations: None,
             formulation: None,
+            declarations: None,
+            definitions: None,
         };
 
         let actual = bom.validate_version(SpecVersion::V1_3);
@@ -757,6 +2148,256 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_validate_composition_vulnerability_refs() {
+        let bom = Bom {
+            spec_version: SpecVersion::V1_5,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "library",
+                "1.0.0",
+                Some("library".to_string()),
+            )])),
+            compositions: Some(Compositions(vec![Composition {
+                bom_ref: Some(BomReference::new("composition")),
+                aggregate: AggregateType::Complete,
+                assemblies: Some(vec![BomReference::new("library")]),
+                dependencies: Some(vec![BomReference::new("missing")]),
+                vulnerabilities: Some(vec![
+                    BomReference::new("vulnerability"),
+                    BomReference::new("library"),
+                ]),
+                signature: None,
+            }])),
+            vulnerabilities: Some(Vulnerabilities(vec![Vulnerability::new(Some(
+                "vulnerability".to_string(),
+            ))])),
+            ..Bom::default()
+        };
+
+        let actual = bom.validate();
+
+        assert_eq!(
+            actual,
+            vec![
+                validation::custom(
+                    "composition ref",
+                    ["Composition reference 'missing' does not exist in the BOM"]
+                ),
+                validation::custom(
+                    "composition vulnerability ref",
+                    ["Composition vulnerability reference 'library' does not exist in the BOM"]
+                ),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn it_should_validate_duplicate_composition_bom_refs_as_failed() {
+        let composition = Composition {
+            bom_ref: Some(BomReference::new("library")),
+            aggregate: AggregateType::Complete,
+            assemblies: None,
+            dependencies: None,
+            vulnerabilities: None,
+            signature: None,
+        };
+        let bom = Bom {
+            spec_version: SpecVersion::V1_5,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "library",
+                "1.0.0",
+                Some("library".to_string()),
+            )])),
+            compositions: Some(Compositions(vec![composition])),
+            ..Bom::default()
+        };
+
+        let actual = bom.validate();
+
+        assert_eq!(
+            actual,
+            validation::custom("bom_ref", [r#"Bom ref "library" is not unique"#])
+        );
+    }
+
+    #[test]
+    fn it_should_validate_broken_annotation_subjects_as_failed() {
+        let annotation = |bom_ref: &str, subjects: &[&str]| Annotation {
+            bom_ref: Some(bom_ref.to_string()),
+            subjects: subjects.iter().map(|s| s.to_string()).collect(),
+            annotator: Annotator::Organization(OrganizationalEntity::new("Acme, Inc.")),
+            timestamp: DateTime("2020-04-13T20:20:39+00:00".to_string()),
+            text: "This is an annotation".to_string(),
+            signature: None,
+        };
+        let bom = Bom {
+            spec_version: SpecVersion::V1_5,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "library",
+                "1.0.0",
+                Some("library".to_string()),
+            )])),
+            annotations: Some(Annotations(vec![
+                annotation("annotation-1", &["library", "annotation-2"]),
+                annotation(
+                    "annotation-2",
+                    &[
+                        "missing",
+                        "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#library",
+                    ],
+                ),
+                annotation("library", &[]),
+            ])),
+            ..Bom::default()
+        };
+
+        let actual = bom.validate();
+
+        assert_eq!(
+            actual,
+            vec![
+                validation::custom("bom_ref", [r#"Bom ref "library" is not unique"#]),
+                validation::custom(
+                    "annotation subject",
+                    ["Annotation subject 'missing' does not exist in the BOM"]
+                ),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn it_should_validate_formulation() {
+        let bom = Bom {
+            spec_version: SpecVersion::V1_4,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "library",
+                "1.0.0",
+                Some("library".to_string()),
+            )])),
+            formulation: Some(vec![Formula {
+                bom_ref: Some(BomReference::new("library")),
+                components: None,
+                services: None,
+                workflows: None,
+                properties: None,
+            }]),
+            ..Bom::default()
+        };
+
+        let actual = bom.validate();
+
+        assert_eq!(
+            actual,
+            vec![
+                validation::list(
+                    "formulation",
+                    [(
+                        0,
+                        ValidationResult::from(Err(ValidationError::new(
+                            "Formula is not defined for version 1.4"
+                        )))
+                    )]
+                ),
+                validation::custom("bom_ref", [r#"Bom ref "library" is not unique"#]),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn it_should_validate_definitions() {
+        let bom = Bom {
+            spec_version: SpecVersion::V1_6,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "library",
+                "1.0.0",
+                Some("library".to_string()),
+            )])),
+            definitions: Some(Definitions {
+                standards: Some(vec![Standard {
+                    bom_ref: Some(BomReference::new("library")),
+                    requirements: Some(vec![Requirement {
+                        bom_ref: Some(BomReference::new("requirement-1")),
+                        ..Requirement::default()
+                    }]),
+                    levels: Some(vec![Level {
+                        bom_ref: Some(BomReference::new("level-1")),
+                        requirements: Some(vec![
+                            "requirement-1".to_string(),
+                            "requirement-2".to_string(),
+                        ]),
+                        ..Level::default()
+                    }]),
+                    ..Standard::default()
+                }]),
+            }),
+            ..Bom::default()
+        };
+
+        let actual = bom.validate_version(SpecVersion::V1_6);
+
+        assert_eq!(
+            actual,
+            vec![
+                validation::custom("bom_ref", [r#"Bom ref "library" is not unique"#]),
+                validation::custom(
+                    "requirement ref",
+                    ["Requirement reference 'requirement-2' does not exist in the standard"]
+                ),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn it_should_validate_that_pedigree_bom_references_are_unique() {
+        let component = |bom_ref: &str| {
+            Component::new(
+                Classification::Library,
+                "library",
+                "1.0.0",
+                Some(bom_ref.to_string()),
+            )
+        };
+        let mut patched = component("library");
+        patched.pedigree = Some(Pedigree {
+            ancestors: Some(Components(vec![component("upstream")])),
+            descendants: None,
+            variants: Some(Components(vec![
+                component("library"),
+                component("upstream"),
+            ])),
+            commits: None,
+            patches: None,
+            notes: None,
+        });
+        let bom = Bom {
+            spec_version: SpecVersion::V1_5,
+            components: Some(Components(vec![patched])),
+            ..Bom::default()
+        };
+
+        let actual = bom.validate();
+
+        assert_eq!(
+            actual,
+            validation::custom(
+                "bom_ref",
+                [
+                    r#"Bom ref "library" is not unique"#,
+                    r#"Bom ref "upstream" is not unique"#,
+                ]
+            )
+        );
+    }
+
     #[test]
     fn it_should_validate_a_bom_with_multiple_validation_issues_as_failed() {
         let bom = Bom {
@@ -769,6 +2410,7 @@ mod test {
                 authors: None,
                 component: None,
                 manufacture: None,
+                manufacturer: None,
                 supplier: None,
                 licenses: None,
                 properties: None,
@@ -779,6 +2421,7 @@ mod test {
                 mime_type: None,
                 bom_ref: Some("dependency".to_string()),
                 supplier: None,
+                authors: None,
                 author: None,
                 publisher: None,
                 group: None,
@@ -798,9 +2441,11 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
                 signature: None,
                 model_card: None,
                 data: None,
+                crypto_properties: None,
             }])),
             services: Some(Services(vec![Service::new("invalid\tname", None)])),
             external_references: Some(ExternalReferences(vec![ExternalReference {
@@ -816,7 +2461,7 @@ mod test {
                 dependencies: vec![],
             }])),
             compositions: Some(Compositions(vec![Composition {
-                bom_ref: Some(BomReference::new("composition-1")),
+                bom_ref: None,
                 aggregate: AggregateType::UnknownAggregateType("unknown".to_string()),
                 assemblies: None,
                 dependencies: None,
@@ -853,6 +2498,8 @@ mod test {
             signature: None,
             annotations: None,
             formulation: None,
+            declarations: None,
+            definitions: None,
         };
 
         let actual = bom.validate();
@@ -959,6 +2606,7 @@ mod test {
                 authors: None,
                 component: Some(component_builder("metadata-component")),
                 manufacture: None,
+                manufacturer: None,
                 supplier: None,
                 licenses: None,
                 properties: None,
@@ -985,6 +2633,8 @@ mod test {
             signature: None,
             annotations: None,
             formulation: None,
+            declarations: None,
+            definitions: None,
         }
         .validate();
 
@@ -1011,6 +2661,35 @@ mod test {
         assert!(validation_result.is_ok());
     }
 
+    #[test]
+    fn it_should_generate_uuids_from_content() {
+        let serial_number = UrnUuid::generate_from_content("content");
+
+        assert!(validate_urn_uuid(&serial_number).is_ok());
+        assert_eq!(serial_number, UrnUuid::generate_from_content(b"content"));
+        assert_ne!(
+            serial_number,
+            UrnUuid::generate_from_content("other content")
+        );
+        assert_eq!(serial_number.0.chars().nth(23), Some('5'));
+    }
+
+    #[test]
+    fn it_should_parse_uuids() {
+        assert_eq!(
+            "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".parse(),
+            Ok(UrnUuid(
+                "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()
+            ))
+        );
+        assert_eq!(
+            "3e671687-395b-41f5-a30f-a58921a69b79".parse::<UrnUuid>(),
+            Err(UrnUuidError::InvalidUrnUuid(
+                "UrnUuid does not match regular expression".to_string()
+            ))
+        );
+    }
+
     #[test]
     fn invalid_uuids_should_fail_validation() {
         let validation_result = validate_urn_uuid(&UrnUuid("invalid uuid".to_string()));
@@ -0,0 +1,283 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::HashMap;
+
+use crate::external_models::uri::Purl;
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Cpe};
+
+/// A lookup table over all components of a [`Bom`], built once for repeated queries.
+///
+/// The index covers the metadata component, the top level components and all nested
+/// components. Looking up a component by bom-ref, purl, name or CPE is a single hash map access.
+#[derive(Debug, Default)]
+pub struct BomIndex<'a> {
+    by_ref: HashMap<&'a str, &'a Component>,
+    by_purl: HashMap<String, Vec<&'a Component>>,
+    by_name: HashMap<&'a str, Vec<&'a Component>>,
+    by_cpe: HashMap<&'a str, Vec<&'a Component>>,
+}
+
+impl<'a> BomIndex<'a> {
+    pub fn new(bom: &'a Bom) -> Self {
+        let mut index = Self::default();
+        for component in all_components(bom) {
+            index.insert(component);
+        }
+        index
+    }
+
+    fn insert(&mut self, component: &'a Component) {
+        if let Some(bom_ref) = &component.bom_ref {
+            // The first occurrence wins, duplicate bom-refs are reported by validation.
+            self.by_ref.entry(bom_ref.as_str()).or_insert(component);
+        }
+        if let Some(purl) = &component.purl {
+            self.by_purl
+                .entry(canonical_purl(purl))
+                .or_default()
+                .push(component);
+        }
+        self.by_name
+            .entry(component.name.as_ref())
+            .or_default()
+            .push(component);
+        if let Some(cpe) = &component.cpe {
+            self.by_cpe
+                .entry(cpe.0.as_str())
+                .or_default()
+                .push(component);
+        }
+    }
+
+    /// Returns the component with the given bom-ref.
+    pub fn component_by_ref(&self, bom_ref: &str) -> Option<&'a Component> {
+        self.by_ref.get(bom_ref).copied()
+    }
+
+    /// Returns all components whose purl is equal to the given one.
+    ///
+    /// Both purls are compared in their canonical form, e.g. the order of qualifiers is ignored.
+    pub fn components_by_purl(&self, purl: &Purl) -> &[&'a Component] {
+        Self::lookup(self.by_purl.get(&canonical_purl(purl)))
+    }
+
+    /// Returns all components with the given name.
+    pub fn components_by_name(&self, name: &str) -> &[&'a Component] {
+        Self::lookup(self.by_name.get(name))
+    }
+
+    /// Returns all components with the given CPE.
+    pub fn components_by_cpe(&self, cpe: &Cpe) -> &[&'a Component] {
+        Self::lookup(self.by_cpe.get(cpe.0.as_str()))
+    }
+
+    /// Returns the number of indexed components.
+    pub fn len(&self) -> usize {
+        self.by_name.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_name.is_empty()
+    }
+
+    fn lookup<'b>(components: Option<&'b Vec<&'a Component>>) -> &'b [&'a Component] {
+        components.map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+/// Returns all components of the BOM in document order, starting with the metadata component.
+pub(crate) fn all_components(bom: &Bom) -> Vec<&Component> {
+    let mut result = Vec::new();
+    if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+        collect_components(component, &mut result);
+    }
+    if let Some(components) = &bom.components {
+        for component in &components.0 {
+            collect_components(component, &mut result);
+        }
+    }
+    result
+}
+
+fn collect_components<'a>(component: &'a Component, result: &mut Vec<&'a Component>) {
+    result.push(component);
+    if let Some(components) = &component.components {
+        for component in &components.0 {
+            collect_components(component, result);
+        }
+    }
+}
+
+/// Returns `true` if the `candidate` purl matches the `query` purl.
+///
+/// Type, namespace and name have to be equal. The version, the qualifiers and the subpath are
+/// only compared if they are present in the `query`, so `pkg:cargo/serde` matches every version
+/// of `serde`. Purls that cannot be parsed only match if they are identical.
+pub fn purl_matches(candidate: &Purl, query: &Purl) -> bool {
+    let (Ok(candidate_purl), Ok(query_purl)) = (candidate.parse(), query.parse()) else {
+        return candidate == query;
+    };
+
+    candidate_purl.package_type() == query_purl.package_type()
+        && candidate_purl.namespace() == query_purl.namespace()
+        && candidate_purl.name() == query_purl.name()
+        && query_purl
+            .version()
+            .map_or(true, |version| candidate_purl.version() == Some(version))
+        && query_purl
+            .qualifiers()
+            .iter()
+            .all(|(key, value)| candidate_purl.qualifier(key) == Some(value.as_str()))
+        && query_purl
+            .subpath()
+            .map_or(true, |subpath| candidate_purl.subpath() == Some(subpath))
+}
+
+fn canonical_purl(purl: &Purl) -> String {
+    purl.parse()
+        .map(|purl| purl.to_string())
+        .unwrap_or_else(|_| purl.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::component::{Classification, Components};
+    use crate::models::metadata::Metadata;
+    use pretty_assertions::assert_eq;
+
+    fn component(name: &str, version: &str, purl: Option<&str>) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            version,
+            Some(format!("{name}@{version}")),
+        );
+        component.purl = purl.map(|purl| Purl(purl.to_string()));
+        component
+    }
+
+    fn example_bom() -> Bom {
+        let mut parent = component("parent", "1.0.0", Some("pkg:cargo/parent@1.0.0"));
+        let mut nested = component("nested", "0.1.0", Some("pkg:cargo/nested@0.1.0"));
+        nested.cpe = Some(Cpe::new("cpe:2.3:a:example:nested:0.1.0:*:*:*:*:*:*:*"));
+        parent.components = Some(Components(vec![nested]));
+
+        Bom {
+            metadata: Some(Metadata {
+                component: Some(component("root", "2.0.0", Some("pkg:cargo/root@2.0.0"))),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![
+                parent,
+                component("serde", "1.0.0", Some("pkg:cargo/serde@1.0.0")),
+                component(
+                    "serde",
+                    "1.0.1",
+                    Some("pkg:cargo/serde@1.0.1?repository_url=https://example.com&arch=x86_64"),
+                ),
+                component("unpackaged", "0.0.1", None),
+            ])),
+            ..Bom::default()
+        }
+    }
+
+    fn names(components: &[&Component]) -> Vec<String> {
+        components
+            .iter()
+            .map(|c| format!("{}@{}", c.name, c.version.as_ref().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_index_all_components() {
+        let bom = example_bom();
+        let index = BomIndex::new(&bom);
+
+        assert_eq!(index.len(), 6);
+        assert_eq!(
+            index
+                .component_by_ref("root@2.0.0")
+                .map(|c| c.name.to_string()),
+            Some("root".to_string())
+        );
+        assert_eq!(
+            index
+                .component_by_ref("nested@0.1.0")
+                .map(|c| c.name.to_string()),
+            Some("nested".to_string())
+        );
+        assert!(index.component_by_ref("missing").is_none());
+        assert_eq!(
+            names(index.components_by_name("serde")),
+            vec!["serde@1.0.0", "serde@1.0.1"]
+        );
+        assert_eq!(
+            names(
+                index.components_by_cpe(&Cpe::new("cpe:2.3:a:example:nested:0.1.0:*:*:*:*:*:*:*"))
+            ),
+            vec!["nested@0.1.0"]
+        );
+        assert!(index.components_by_name("missing").is_empty());
+    }
+
+    #[test]
+    fn it_should_look_up_purls_in_canonical_form() {
+        let bom = example_bom();
+        let index = BomIndex::new(&bom);
+
+        assert_eq!(
+            names(index.components_by_purl(&Purl(
+                "pkg:cargo/serde@1.0.1?arch=x86_64&repository_url=https://example.com".to_string()
+            ))),
+            vec!["serde@1.0.1"]
+        );
+        assert!(index
+            .components_by_purl(&Purl("pkg:cargo/serde".to_string()))
+            .is_empty());
+    }
+
+    #[test]
+    fn it_should_find_components_in_bom() {
+        let bom = example_bom();
+
+        assert_eq!(
+            bom.component_by_ref("nested@0.1.0")
+                .map(|c| c.name.to_string()),
+            Some("nested".to_string())
+        );
+        assert!(bom.component_by_ref("missing").is_none());
+        assert_eq!(
+            names(&bom.components_matching_purl(&Purl("pkg:cargo/serde".to_string()))),
+            vec!["serde@1.0.0", "serde@1.0.1"]
+        );
+        assert_eq!(
+            names(&bom.components_matching_purl(&Purl("pkg:cargo/serde@1.0.0".to_string()))),
+            vec!["serde@1.0.0"]
+        );
+        assert_eq!(
+            names(&bom.components_matching_purl(&Purl("pkg:cargo/serde?arch=x86_64".to_string()))),
+            vec!["serde@1.0.1"]
+        );
+        assert!(bom
+            .components_matching_purl(&Purl("pkg:npm/serde".to_string()))
+            .is_empty());
+    }
+}
@@ -0,0 +1,481 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! [BOM-Links](https://cyclonedx.org/capabilities/bomlink/) reference a BOM or an element of a
+//! BOM by the serial number and version of the document, e.g.
+//! `urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1#componentA`.
+//!
+//! Documents are looked up with a [`BomLinkResolver`], which lets applications decide where
+//! linked BOMs come from, e.g. a directory of files or a BOM repository.
+
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use thiserror::Error;
+
+use crate::models::bom::{Bom, SpecVersion, UrnUuid};
+use crate::models::component::Component;
+use crate::models::service::{Service, Services};
+use crate::models::vulnerability::Vulnerability;
+use crate::validation::ValidationError;
+
+static BOM_LINK_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^urn:cdx:([0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12})/([1-9][0-9]*)(?:#(.+))?$")
+        .expect("Failed to compile regex.")
+});
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BomLink(pub String);
+
+impl BomLink {
+    /// Parses the BOM-Link into its parts.
+    /// ```
+    /// use cyclonedx_bom::models::bom_link::BomLink;
+    ///
+    /// let link = BomLink("urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1#componentA".to_string());
+    /// let urn = link.parse()?;
+    /// assert_eq!(urn.serial_number(), "f08a6ccd-4dce-4759-bd84-c626675d60a7");
+    /// assert_eq!(urn.version(), 1);
+    /// assert_eq!(urn.bom_ref(), Some("componentA"));
+    /// # Ok::<(), cyclonedx_bom::models::bom_link::BomLinkError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if the BOM-Link does not conform to the BOM-Link spec
+    pub fn parse(&self) -> Result<BomLinkUrn, BomLinkError> {
+        BomLinkUrn::from_str(&self.0)
+    }
+}
+
+impl From<BomLinkUrn> for BomLink {
+    fn from(urn: BomLinkUrn) -> Self {
+        Self(urn.to_string())
+    }
+}
+
+impl std::fmt::Display for BomLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for BomLink {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A [`BomLink`] parsed into its parts.
+///
+/// The `bom-ref` is stored decoded and percent-encoded again when formatted.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BomLinkUrn {
+    serial_number: String,
+    version: u32,
+    bom_ref: Option<String>,
+}
+
+impl BomLinkUrn {
+    /// Creates a link to the BOM with the given serial number and version.
+    /// ```
+    /// use cyclonedx_bom::models::bom::UrnUuid;
+    /// use cyclonedx_bom::models::bom_link::BomLinkUrn;
+    ///
+    /// let serial_number = UrnUuid("urn:uuid:f08a6ccd-4dce-4759-bd84-c626675d60a7".to_string());
+    /// let urn = BomLinkUrn::new(&serial_number, 1)?.with_bom_ref("pkg:cargo/a@1.0.0");
+    /// assert_eq!(
+    ///     urn.to_string(),
+    ///     "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1#pkg:cargo/a@1.0.0"
+    /// );
+    /// # Ok::<(), cyclonedx_bom::models::bom_link::BomLinkError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if the serial number is not a `urn:uuid` or the version is 0
+    pub fn new(serial_number: &UrnUuid, version: u32) -> Result<Self, BomLinkError> {
+        let uuid = serial_number
+            .0
+            .strip_prefix("urn:uuid:")
+            .ok_or_else(|| BomLinkError::InvalidSerialNumber(serial_number.0.clone()))?;
+        if version == 0 {
+            return Err(BomLinkError::InvalidBomLink(
+                "The version has to be at least 1".to_string(),
+            ));
+        }
+        Self::from_str(&format!("urn:cdx:{uuid}/{version}"))
+            .map_err(|_| BomLinkError::InvalidSerialNumber(serial_number.0.clone()))
+    }
+
+    /// Creates a link to the given BOM, which needs a serial number.
+    pub fn for_bom(bom: &Bom) -> Result<Self, BomLinkError> {
+        let serial_number = bom
+            .serial_number
+            .as_ref()
+            .ok_or(BomLinkError::MissingSerialNumber)?;
+        Self::new(serial_number, bom.version)
+    }
+
+    /// Returns a link to the element with the `bom-ref` inside the same BOM.
+    pub fn with_bom_ref(mut self, bom_ref: &str) -> Self {
+        self.bom_ref = Some(bom_ref.to_string());
+        self
+    }
+
+    /// Returns a link to the linked BOM itself, without a `bom-ref`.
+    pub fn document(&self) -> Self {
+        Self {
+            bom_ref: None,
+            ..self.clone()
+        }
+    }
+
+    /// The UUID of the serial number, without the `urn:uuid:` prefix
+    pub fn serial_number(&self) -> &str {
+        &self.serial_number
+    }
+
+    pub fn urn_uuid(&self) -> UrnUuid {
+        UrnUuid(format!("urn:uuid:{}", self.serial_number))
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The decoded `bom-ref` of the linked element, `None` if the link references the whole BOM
+    pub fn bom_ref(&self) -> Option<&str> {
+        self.bom_ref.as_deref()
+    }
+
+    /// Returns `true` if the link references the given BOM, ignoring its version.
+    pub fn links_to_serial_number(&self, serial_number: &UrnUuid) -> bool {
+        serial_number.0.strip_prefix("urn:uuid:") == Some(self.serial_number.as_str())
+    }
+
+    /// Looks up the linked BOM and element with the given resolver.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resolver does not know the linked BOM or if the BOM has no
+    /// component, service or vulnerability with the `bom-ref`.
+    pub fn resolve<'a, R>(&self, resolver: &'a R) -> Result<BomLinkTarget<'a>, BomLinkError>
+    where
+        R: BomLinkResolver + ?Sized,
+    {
+        let bom = resolver
+            .resolve_bom(&self.urn_uuid(), self.version)
+            .ok_or_else(|| BomLinkError::UnresolvedBom(self.document().to_string()))?;
+
+        let Some(bom_ref) = &self.bom_ref else {
+            return Ok(BomLinkTarget::Bom(bom));
+        };
+
+        if let Some(component) = bom.component_by_ref(bom_ref) {
+            return Ok(BomLinkTarget::Component(component));
+        }
+        if let Some(service) = bom
+            .services
+            .as_ref()
+            .and_then(|services| service_by_ref(services, bom_ref))
+        {
+            return Ok(BomLinkTarget::Service(service));
+        }
+        if let Some(vulnerability) = bom.vulnerabilities.as_ref().and_then(|vulnerabilities| {
+            vulnerabilities
+                .0
+                .iter()
+                .find(|vulnerability| vulnerability.bom_ref.as_deref() == Some(bom_ref))
+        }) {
+            return Ok(BomLinkTarget::Vulnerability(vulnerability));
+        }
+
+        Err(BomLinkError::UnresolvedBomRef(self.to_string()))
+    }
+}
+
+impl FromStr for BomLinkUrn {
+    type Err = BomLinkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BomLinkError::InvalidBomLink(s.to_string());
+        let captures = BOM_LINK_REGEX.captures(s).ok_or_else(invalid)?;
+
+        Ok(Self {
+            serial_number: captures[1].to_string(),
+            version: captures[2].parse().map_err(|_| invalid())?,
+            bom_ref: captures
+                .get(3)
+                .map(|bom_ref| decode_fragment(bom_ref.as_str())),
+        })
+    }
+}
+
+impl std::fmt::Display for BomLinkUrn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "urn:cdx:{}/{}", self.serial_number, self.version)?;
+        if let Some(bom_ref) = &self.bom_ref {
+            write!(f, "#{}", encode_fragment(bom_ref))?;
+        }
+        Ok(())
+    }
+}
+
+/// Looks up the BOMs referenced by BOM-Links.
+///
+/// A [`Bom`] resolves links to itself and a slice of BOMs resolves links to any of them.
+/// Applications implement this trait to load linked BOMs from other sources.
+pub trait BomLinkResolver {
+    /// Returns the BOM with the serial number and version, `None` if it is unknown
+    fn resolve_bom(&self, serial_number: &UrnUuid, version: u32) -> Option<&Bom>;
+}
+
+impl BomLinkResolver for Bom {
+    fn resolve_bom(&self, serial_number: &UrnUuid, version: u32) -> Option<&Bom> {
+        (self.serial_number.as_ref() == Some(serial_number) && self.version == version)
+            .then_some(self)
+    }
+}
+
+impl BomLinkResolver for [Bom] {
+    fn resolve_bom(&self, serial_number: &UrnUuid, version: u32) -> Option<&Bom> {
+        self.iter()
+            .find_map(|bom| bom.resolve_bom(serial_number, version))
+    }
+}
+
+/// The element a [`BomLinkUrn`] resolves to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BomLinkTarget<'a> {
+    Bom(&'a Bom),
+    Component(&'a Component),
+    Service(&'a Service),
+    Vulnerability(&'a Vulnerability),
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BomLinkError {
+    #[error("Invalid BOM-Link: {0}")]
+    InvalidBomLink(String),
+
+    #[error("The serial number {0} is not a urn:uuid")]
+    InvalidSerialNumber(String),
+
+    #[error("The BOM has no serial number which can be linked to")]
+    MissingSerialNumber,
+
+    #[error("The linked BOM {0} is unknown")]
+    UnresolvedBom(String),
+
+    #[error("The linked BOM has no element with the bom-ref of {0}")]
+    UnresolvedBomRef(String),
+}
+
+/// Validates a [`BomLink`], BOM-Links were added in 1.5 only.
+pub(crate) fn validate_bom_link(
+    bom_link: &BomLink,
+    version: SpecVersion,
+) -> Result<(), ValidationError> {
+    if version < SpecVersion::V1_5 {
+        return Err("BOM-Link not supported before version 1.5".into());
+    }
+
+    if bom_link.parse().is_err() {
+        return Err(ValidationError::new("Invalid BOM-Link"));
+    }
+
+    Ok(())
+}
+
+fn service_by_ref<'a>(services: &'a Services, bom_ref: &str) -> Option<&'a Service> {
+    services.0.iter().find_map(|service| {
+        if service.bom_ref.as_deref() == Some(bom_ref) {
+            return Some(service);
+        }
+        service
+            .services
+            .as_ref()
+            .and_then(|services| service_by_ref(services, bom_ref))
+    })
+}
+
+/// Percent-encodes all characters which are not allowed in the fragment of an URI.
+fn encode_fragment(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => encoded.push(byte as char),
+            b'-' | b'.' | b'_' | b'~' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+'
+            | b',' | b';' | b'=' | b':' | b'@' | b'/' | b'?' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn decode_fragment(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::models::component::{Classification, Components};
+    use crate::models::service::Services;
+    use pretty_assertions::assert_eq;
+
+    fn example_bom() -> Bom {
+        Bom {
+            serial_number: Some(UrnUuid(
+                "urn:uuid:f08a6ccd-4dce-4759-bd84-c626675d60a7".to_string(),
+            )),
+            version: 2,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "a",
+                "1.0.0",
+                Some("pkg:cargo/a@1.0.0?x=a b".to_string()),
+            )])),
+            services: Some(Services(vec![Service {
+                services: Some(Services(vec![Service::new(
+                    "inner",
+                    Some("service-inner".to_string()),
+                )])),
+                ..Service::new("outer", Some("service-outer".to_string()))
+            }])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_parse_and_format_bom_links() {
+        let urn = BomLinkUrn::from_str(
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/12#pkg:cargo/a@1.0.0?x=a%20b",
+        )
+        .unwrap();
+
+        assert_eq!(urn.serial_number(), "f08a6ccd-4dce-4759-bd84-c626675d60a7");
+        assert_eq!(urn.version(), 12);
+        assert_eq!(urn.bom_ref(), Some("pkg:cargo/a@1.0.0?x=a b"));
+        assert_eq!(
+            urn.to_string(),
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/12#pkg:cargo/a@1.0.0?x=a%20b"
+        );
+        assert_eq!(
+            urn.document().to_string(),
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/12"
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_bom_links() {
+        for link in [
+            "urn:cdx:not-a-serial-number/1",
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/0",
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7",
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/99999999999",
+            "urn:uuid:f08a6ccd-4dce-4759-bd84-c626675d60a7/1",
+        ] {
+            assert_eq!(
+                BomLink(link.to_string()).parse(),
+                Err(BomLinkError::InvalidBomLink(link.to_string())),
+                "{link}"
+            );
+        }
+
+        assert_eq!(
+            BomLinkUrn::new(&UrnUuid("not-a-urn".to_string()), 1),
+            Err(BomLinkError::InvalidSerialNumber("not-a-urn".to_string()))
+        );
+        assert_eq!(
+            BomLinkUrn::for_bom(&Bom {
+                serial_number: None,
+                ..Bom::default()
+            }),
+            Err(BomLinkError::MissingSerialNumber)
+        );
+    }
+
+    #[test]
+    fn it_should_resolve_bom_links() {
+        let bom = example_bom();
+        let urn = BomLinkUrn::for_bom(&bom).unwrap();
+
+        assert_eq!(urn.resolve(&bom), Ok(BomLinkTarget::Bom(&bom)));
+        assert_eq!(
+            urn.clone()
+                .with_bom_ref("pkg:cargo/a@1.0.0?x=a b")
+                .resolve(&bom),
+            Ok(BomLinkTarget::Component(
+                &bom.components.as_ref().unwrap().0[0]
+            ))
+        );
+        assert!(matches!(
+            urn.clone().with_bom_ref("service-inner").resolve(&bom),
+            Ok(BomLinkTarget::Service(service)) if service.name.to_string() == "inner"
+        ));
+        assert_eq!(
+            urn.clone().with_bom_ref("missing").resolve(&bom),
+            Err(BomLinkError::UnresolvedBomRef(
+                "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/2#missing".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_resolve_bom_links_across_documents() {
+        let boms = vec![Bom::default(), example_bom()];
+        let link = BomLink("urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/2".to_string());
+
+        assert_eq!(
+            link.parse().unwrap().resolve(boms.as_slice()),
+            Ok(BomLinkTarget::Bom(&boms[1]))
+        );
+
+        let outdated =
+            BomLink("urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1#service-outer".to_string());
+        assert_eq!(
+            outdated.parse().unwrap().resolve(boms.as_slice()),
+            Err(BomLinkError::UnresolvedBom(
+                "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,339 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use serde_json::Value;
+
+use crate::errors::JsonReadError;
+use crate::models::bom::Bom;
+use crate::models::component::Components;
+use crate::models::metadata::Metadata;
+use crate::models::property::Properties;
+use crate::progress::{Phase, ProgressHandler, ProgressTracker};
+
+/// A partial BOM that serves as the skeleton for generated BOMs.
+///
+/// A template typically contains organizational data like the supplier, the manufacturer,
+/// authors, licenses, lifecycles and standard properties. Generated content is layered on top of
+/// it with [`apply`](Self::apply):
+///
+/// - fields set in the generated BOM take precedence over the ones of the template
+/// - fields only set in the template are kept
+/// - properties are combined, a template property is dropped if the generated BOM contains a
+///   property with the same name
+/// - components, services, external references and dependencies of the template are kept in
+///   front of the generated ones
+///
+/// ```
+/// use cyclonedx_bom::models::bom_template::BomTemplate;
+/// use cyclonedx_bom::prelude::*;
+///
+/// let template = BomTemplate::parse_from_json(
+///     r#"{ "metadata": { "supplier": { "name": "ACME Corp" } } }"#.as_bytes(),
+/// )
+/// .expect("Failed to parse template");
+///
+/// let bom = template.apply(Bom::default());
+/// let supplier = bom.metadata.and_then(|m| m.supplier).and_then(|s| s.name);
+/// assert_eq!(supplier, Some(NormalizedString::new("ACME Corp")));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BomTemplate {
+    pub bom: Bom,
+}
+
+impl BomTemplate {
+    pub fn new(bom: Bom) -> Self {
+        Self { bom }
+    }
+
+    /// Parses a template from a JSON document.
+    ///
+    /// Unlike a complete BOM, the template may omit `bomFormat`, `specVersion` and `version`;
+    /// a missing `specVersion` is read as the latest supported version.
+    pub fn parse_from_json<R: std::io::Read>(mut reader: R) -> Result<Self, JsonReadError> {
+        Self::parse_json_value(serde_json::from_reader(&mut reader)?)
+    }
+
+    /// Parses a template from a pre-parsed JSON document, see
+    /// [`parse_from_json`](Self::parse_from_json).
+    pub fn parse_json_value(mut json: Value) -> Result<Self, JsonReadError> {
+        if let Value::Object(object) = &mut json {
+            object
+                .entry("bomFormat")
+                .or_insert_with(|| Value::from("CycloneDX"));
+            object
+                .entry("specVersion")
+                .or_insert_with(|| Value::from("1.5"));
+            object.entry("version").or_insert_with(|| Value::from(1));
+        }
+        Bom::parse_json_value(json).map(Self::new)
+    }
+
+    /// Layers the generated BOM on top of the template like [`apply`](Self::apply), while
+    /// reporting every merged component in the [`Phase::Merge`] phase.
+    pub fn apply_with_progress(&self, generated: Bom, handler: &dyn ProgressHandler) -> Bom {
+        let count = |bom: &Bom| bom.components.as_ref().map_or(0, |c| c.0.len()) as u64;
+        let tracker = ProgressTracker::start(
+            handler,
+            Phase::Merge,
+            Some(count(&self.bom) + count(&generated)),
+        );
+        let bom = self.layer(generated, &|| tracker.advance(1));
+        tracker.finish();
+        bom
+    }
+
+    /// Layers the generated BOM on top of the template.
+    ///
+    /// The version, serial number and spec version are always taken from the generated BOM.
+    pub fn apply(&self, generated: Bom) -> Bom {
+        self.layer(generated, &|| {})
+    }
+
+    /// Layers the generated BOM on top of the template, calling `on_component` for every
+    /// merged component.
+    fn layer(&self, generated: Bom, on_component: &dyn Fn()) -> Bom {
+        let template = self.bom.clone();
+
+        Bom {
+            version: generated.version,
+            serial_number: generated.serial_number,
+            metadata: layer_metadata(template.metadata, generated.metadata),
+            components: concat_components(template.components, generated.components, on_component),
+            services: concat(template.services, generated.services, |s| &mut s.0),
+            external_references: concat(
+                template.external_references,
+                generated.external_references,
+                |r| &mut r.0,
+            ),
+            dependencies: concat(template.dependencies, generated.dependencies, |d| &mut d.0),
+            compositions: concat(template.compositions, generated.compositions, |c| &mut c.0),
+            properties: layer_properties(template.properties, generated.properties),
+            vulnerabilities: concat(template.vulnerabilities, generated.vulnerabilities, |v| {
+                &mut v.0
+            }),
+            signature: generated.signature.or(template.signature),
+            annotations: concat(template.annotations, generated.annotations, |a| &mut a.0),
+            formulation: concat(template.formulation, generated.formulation, |f| f),
+            declarations: generated.declarations.or(template.declarations),
+            definitions: generated.definitions.or(template.definitions),
+            spec_version: generated.spec_version,
+        }
+    }
+}
+
+fn layer_metadata(template: Option<Metadata>, generated: Option<Metadata>) -> Option<Metadata> {
+    let (template, generated) = match (template, generated) {
+        (Some(template), Some(generated)) => (template, generated),
+        (template, generated) => return generated.or(template),
+    };
+
+    Some(Metadata {
+        timestamp: generated.timestamp.or(template.timestamp),
+        tools: generated.tools.or(template.tools),
+        authors: generated.authors.or(template.authors),
+        component: generated.component.or(template.component),
+        manufacture: generated.manufacture.or(template.manufacture),
+        manufacturer: generated.manufacturer.or(template.manufacturer),
+        supplier: generated.supplier.or(template.supplier),
+        licenses: generated.licenses.or(template.licenses),
+        properties: layer_properties(template.properties, generated.properties),
+        lifecycles: generated.lifecycles.or(template.lifecycles),
+    })
+}
+
+fn layer_properties(
+    template: Option<Properties>,
+    generated: Option<Properties>,
+) -> Option<Properties> {
+    let (template, generated) = match (template, generated) {
+        (Some(template), Some(generated)) => (template, generated),
+        (template, generated) => return generated.or(template),
+    };
+
+    let mut properties: Vec<_> = template
+        .0
+        .into_iter()
+        .filter(|property| !generated.0.iter().any(|p| p.name == property.name))
+        .collect();
+    properties.extend(generated.0);
+    Some(Properties(properties))
+}
+
+fn concat_components(
+    template: Option<Components>,
+    generated: Option<Components>,
+    on_component: &dyn Fn(),
+) -> Option<Components> {
+    if template.is_none() && generated.is_none() {
+        return None;
+    }
+
+    let mut components = Vec::new();
+    for component in template.into_iter().chain(generated).flat_map(|c| c.0) {
+        components.push(component);
+        on_component();
+    }
+    Some(Components(components))
+}
+
+fn concat<T, U>(
+    template: Option<T>,
+    generated: Option<T>,
+    inner: fn(&mut T) -> &mut Vec<U>,
+) -> Option<T> {
+    match (template, generated) {
+        (Some(mut template), Some(mut generated)) => {
+            inner(&mut template).append(inner(&mut generated));
+            Some(template)
+        }
+        (template, generated) => template.or(generated),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::normalized_string::NormalizedString;
+    use crate::models::component::{Classification, Component};
+    use crate::models::lifecycle::{Lifecycle, Lifecycles, Phase};
+    use crate::models::organization::OrganizationalEntity;
+    use crate::models::property::Property;
+    use crate::progress::Progress;
+    use pretty_assertions::assert_eq;
+
+    fn template() -> BomTemplate {
+        BomTemplate::parse_from_json(
+            r#"{
+  "metadata": {
+    "supplier": { "name": "ACME Corp" },
+    "lifecycles": [ { "phase": "build" } ],
+    "properties": [
+      { "name": "acme:team", "value": "platform" },
+      { "name": "acme:classification", "value": "internal" }
+    ]
+  },
+  "components": [
+    { "type": "library", "name": "approved-base", "version": "1.0.0" }
+  ]
+}"#
+            .as_bytes(),
+        )
+        .expect("Failed to parse template")
+    }
+
+    #[test]
+    fn it_should_report_merge_progress() {
+        let reports = std::cell::RefCell::new(Vec::new());
+        let handler = |progress: Progress| reports.borrow_mut().push(progress);
+
+        let generated = Bom {
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "dependency",
+                "1.0.0",
+                None,
+            )])),
+            ..Bom::default()
+        };
+
+        template().apply_with_progress(generated, &handler);
+
+        assert_eq!(
+            reports.into_inner(),
+            vec![
+                Progress::new(crate::progress::Phase::Merge, 0, Some(2)),
+                Progress::new(crate::progress::Phase::Merge, 1, Some(2)),
+                Progress::new(crate::progress::Phase::Merge, 2, Some(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_a_partial_template() {
+        let template = template();
+        let metadata = template.bom.metadata.expect("Metadata is missing");
+
+        assert_eq!(
+            metadata.supplier.and_then(|s| s.name),
+            Some(NormalizedString::new("ACME Corp"))
+        );
+        assert_eq!(
+            metadata.lifecycles,
+            Some(Lifecycles(vec![Lifecycle::Phase(Phase::Build)]))
+        );
+    }
+
+    #[test]
+    fn it_should_layer_generated_content_into_the_template() {
+        let generated = Bom {
+            metadata: Some(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "0.1.0",
+                    None,
+                )),
+                properties: Some(Properties(vec![Property::new(
+                    "acme:classification",
+                    "public",
+                )])),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "dependency",
+                "2.0.0",
+                None,
+            )])),
+            ..Bom::default()
+        };
+        let serial_number = generated.serial_number.clone();
+
+        let bom = template().apply(generated);
+        let metadata = bom.metadata.expect("Metadata is missing");
+
+        assert_eq!(bom.serial_number, serial_number);
+        assert_eq!(
+            metadata.supplier,
+            Some(OrganizationalEntity::new("ACME Corp"))
+        );
+        assert_eq!(
+            metadata.component.map(|c| c.name),
+            Some(NormalizedString::new("app"))
+        );
+        assert_eq!(
+            metadata.properties,
+            Some(Properties(vec![
+                Property::new("acme:team", "platform"),
+                Property::new("acme:classification", "public"),
+            ]))
+        );
+        assert!(metadata.lifecycles.is_some());
+        assert_eq!(
+            bom.components
+                .expect("Components are missing")
+                .0
+                .into_iter()
+                .map(|c| c.name.to_string())
+                .collect::<Vec<_>>(),
+            vec!["approved-base", "dependency"]
+        );
+    }
+}
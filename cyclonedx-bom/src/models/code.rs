@@ -28,7 +28,8 @@ use crate::{
 
 use super::{attached_text::AttachedText, bom::SpecVersion};
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Commit {
     pub uid: Option<NormalizedString>,
     pub url: Option<Uri>,
@@ -49,7 +50,8 @@ impl Validate for Commit {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Commits(pub Vec<Commit>);
 
 impl Validate for Commits {
@@ -60,7 +62,8 @@ impl Validate for Commits {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Diff {
     pub text: Option<AttachedText>,
     pub url: Option<Uri>,
@@ -75,7 +78,8 @@ impl Validate for Diff {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IdentifiableAction {
     pub timestamp: Option<DateTime>,
     pub name: Option<NormalizedString>,
@@ -92,7 +96,8 @@ impl Validate for IdentifiableAction {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Issue {
     pub issue_type: IssueClassification,
     pub id: Option<NormalizedString>,
@@ -135,7 +140,8 @@ pub fn validate_issue_classification(
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "snake_case")]
 pub enum IssueClassification {
     Defect,
@@ -157,7 +163,14 @@ impl IssueClassification {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+from_str_via_new_unchecked!(
+    IssueClassification,
+    UnknownIssueClassification,
+    "issue classification"
+);
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Patch {
     pub patch_type: PatchClassification,
     pub diff: Option<Diff>,
@@ -180,7 +193,8 @@ impl Validate for Patch {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Patches(pub Vec<Patch>);
 
 impl Validate for Patches {
@@ -203,7 +217,8 @@ pub fn validate_patch_classification(
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum PatchClassification {
     Unofficial,
@@ -227,7 +242,14 @@ impl PatchClassification {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+from_str_via_new_unchecked!(
+    PatchClassification,
+    UnknownPatchClassification,
+    "patch classification"
+);
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Source {
     pub name: Option<NormalizedString>,
     pub url: Option<Uri>,
@@ -29,8 +29,9 @@ use crate::models::code::{Commits, Patches};
 use crate::models::external_reference::ExternalReferences;
 use crate::models::hash::Hashes;
 use crate::models::license::Licenses;
-use crate::models::organization::OrganizationalEntity;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::models::property::Properties;
+use crate::models::release_notes::ReleaseNotes;
 use crate::validation::ValidationError;
 use crate::{
     external_models::{
@@ -42,15 +43,25 @@ use crate::{
 
 use super::bom::{validate_bom_ref, SpecVersion};
 use super::component_data::ComponentData;
+use super::crypto_properties::CryptoProperties;
 use super::modelcard::ModelCard;
 use super::signature::Signature;
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// A component of a BOM.
+///
+/// Equality, ordering and hashing are structural: two components are only equal if all of their
+/// fields, including the `bom_ref` and nested components, are equal. Use [`Component::matches`]
+/// or [`Component::identity`] to compare components by the package they describe instead.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Component {
     pub component_type: Classification,
     pub mime_type: Option<MimeType>,
     pub bom_ref: Option<String>,
     pub supplier: Option<OrganizationalEntity>,
+    /// Added in version 1.6
+    pub authors: Option<Vec<OrganizationalContact>>,
+    /// Deprecated in version 1.6 in favor of `authors`
     pub author: Option<NormalizedString>,
     pub publisher: Option<NormalizedString>,
     pub group: Option<NormalizedString>,
@@ -71,11 +82,15 @@ pub struct Component {
     pub components: Option<Components>,
     pub evidence: Option<ComponentEvidence>,
     /// Added in version 1.4
+    pub release_notes: Option<ReleaseNotes>,
+    /// Added in version 1.4
     pub signature: Option<Signature>,
     /// Added in version 1.5
     pub model_card: Option<ModelCard>,
     /// Added in version 1.5
-    pub data: Option<ComponentData>,
+    pub data: Option<Vec<ComponentData>>,
+    /// Added in version 1.6
+    pub crypto_properties: Option<CryptoProperties>,
 }
 
 impl Component {
@@ -92,6 +107,7 @@ impl Component {
             bom_ref,
             mime_type: None,
             supplier: None,
+            authors: None,
             author: None,
             publisher: None,
             group: None,
@@ -109,21 +125,146 @@ impl Component {
             properties: None,
             components: None,
             evidence: None,
+            release_notes: None,
             signature: None,
             model_card: None,
             data: None,
+            crypto_properties: None,
+        }
+    }
+
+    /// Returns the identity of the package the component describes, e.g. to put components of
+    /// different BOMs in a set.
+    ///
+    /// The identity is taken from the first identifier the component has, in the order of
+    /// purl, CPE, SWID tag id and finally group, name and version. The purl is identified by
+    /// its canonical form, so the order of the qualifiers does not matter.
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component, ComponentIdentity};
+    /// use cyclonedx_bom::prelude::Purl;
+    /// use std::str::FromStr;
+    ///
+    /// let mut component = Component::new(Classification::Library, "serde", "1.0.193", None);
+    /// component.purl = Purl::from_str("pkg:cargo/serde@1.0.193?b=2&a=1").ok();
+    /// let mut other = Component::new(Classification::Library, "serde", "1.0.193", Some("serde".into()));
+    /// other.purl = Purl::from_str("pkg:cargo/serde@1.0.193?a=1&b=2").ok();
+    ///
+    /// assert_ne!(component, other);
+    /// assert_eq!(component.identity(), other.identity());
+    /// assert_eq!(
+    ///     component.identity(),
+    ///     ComponentIdentity::Purl("pkg:cargo/serde@1.0.193?a=1&b=2".to_string())
+    /// );
+    /// ```
+    pub fn identity(&self) -> ComponentIdentity {
+        self.identities().remove(0)
+    }
+
+    /// Returns `true` if both components describe the same package, which is the notion of
+    /// identity to use when merging, diffing or deduplicating components.
+    ///
+    /// The components are compared by the first identifier in the order of [`Component::identity`]
+    /// both of them have, so a component with a purl and a CPE matches a component that only has
+    /// the same CPE.
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component, Cpe};
+    /// use cyclonedx_bom::prelude::Purl;
+    /// use std::str::FromStr;
+    ///
+    /// let cpe = Cpe::new("cpe:2.3:a:openssl:openssl:3.0.0:*:*:*:*:*:*:*");
+    /// let mut component = Component::new(Classification::Library, "openssl", "3.0.0", None);
+    /// component.purl = Purl::from_str("pkg:generic/openssl@3.0.0").ok();
+    /// component.cpe = Some(cpe.clone());
+    /// let mut other = Component::new(Classification::Library, "libssl", "3.0.0", None);
+    /// other.cpe = Some(cpe);
+    ///
+    /// assert!(component.matches(&other));
+    /// assert_ne!(component.identity(), other.identity());
+    /// ```
+    pub fn matches(&self, other: &Component) -> bool {
+        let others = other.identities();
+        self.identities()
+            .into_iter()
+            .find_map(|identity| {
+                others
+                    .iter()
+                    .find(|other| identity.same_kind(other))
+                    .map(|other| identity == *other)
+            })
+            .unwrap_or(false)
+    }
+
+    /// All identities of the component in the order of their precedence, the last one being
+    /// its coordinates.
+    fn identities(&self) -> Vec<ComponentIdentity> {
+        let mut identities = Vec::new();
+        if let Some(purl) = &self.purl {
+            identities.push(ComponentIdentity::Purl(
+                purl.parse()
+                    .map(|package_url| package_url.to_string())
+                    .unwrap_or_else(|_| purl.to_string()),
+            ));
         }
+        if let Some(cpe) = &self.cpe {
+            identities.push(ComponentIdentity::Cpe(cpe.to_string()));
+        }
+        if let Some(swid) = &self.swid {
+            identities.push(ComponentIdentity::Swid(swid.tag_id.clone()));
+        }
+        identities.push(ComponentIdentity::Coordinates {
+            group: self.group.as_ref().map(ToString::to_string),
+            name: self.name.to_string(),
+            version: self.version.as_ref().map(ToString::to_string),
+        });
+        identities
+    }
+}
+
+/// The identity of the package a [`Component`] describes, see [`Component::identity`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComponentIdentity {
+    /// The canonical form of the purl
+    Purl(String),
+    /// The CPE of a component without a purl
+    Cpe(String),
+    /// The SWID tag id of a component without a purl or CPE
+    Swid(String),
+    /// The group, name and version of a component without any of the other identifiers
+    Coordinates {
+        group: Option<String>,
+        name: String,
+        version: Option<String>,
+    },
+}
+
+impl ComponentIdentity {
+    fn same_kind(&self, other: &ComponentIdentity) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
     }
 }
 
 impl Validate for Component {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         let mut ctx = ValidationContext::new();
+        ctx.set_bom_ref(self.bom_ref.as_deref());
         ctx.add_field("component_type", &self.component_type, |ct| {
             validate_classification(ct, version)
         });
         ctx.add_field_option("mime_type", self.mime_type.as_ref(), validate_mime_type);
         ctx.add_struct_option("supplier", self.supplier.as_ref(), version);
+        if version <= SpecVersion::V1_5 {
+            if self.authors.is_some() {
+                ctx.add_custom(
+                    "authors",
+                    "Field 'authors' not supported in this format version",
+                );
+            }
+        } else {
+            ctx.add_list_option("authors", self.authors.as_ref(), |author| {
+                author.validate_version(version)
+            });
+        }
         ctx.add_field_option("author", self.author.as_ref(), validate_normalized_string);
         ctx.add_field_option(
             "publisher",
@@ -158,11 +299,43 @@ impl Validate for Component {
         ctx.add_struct_option("properties", self.properties.as_ref(), version);
         ctx.add_struct_option("components", self.components.as_ref(), version);
         ctx.add_struct_option("evidence", self.evidence.as_ref(), version);
+        if version <= SpecVersion::V1_3 {
+            if self.release_notes.is_some() {
+                ctx.add_custom(
+                    "release_notes",
+                    "Field 'release_notes' not supported in this format version",
+                );
+            }
+        } else {
+            ctx.add_struct_option("release_notes", self.release_notes.as_ref(), version);
+        }
+        if version <= SpecVersion::V1_4 {
+            if self.model_card.is_some() {
+                ctx.add_custom(
+                    "model_card",
+                    "Field 'model_card' not supported in this format version",
+                );
+            }
+            if self.data.is_some() {
+                ctx.add_custom("data", "Field 'data' not supported in this format version");
+            }
+        } else {
+            ctx.add_struct_option("model_card", self.model_card.as_ref(), version);
+            ctx.add_list_option("data", self.data.as_ref(), |data| {
+                data.validate_version(version)
+            });
+        }
+        ctx.add_struct_option(
+            "crypto_properties",
+            self.crypto_properties.as_ref(),
+            version,
+        );
         ctx.into()
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Components(pub Vec<Component>);
 
 impl Validate for Components {
@@ -184,7 +357,11 @@ pub fn validate_classification(
         if Classification::File < *classification {
             return Err(ValidationError::new("Unknown classification"));
         }
-    } else if SpecVersion::V1_5 <= version
+    } else if version == SpecVersion::V1_5 {
+        if Classification::Data < *classification {
+            return Err(ValidationError::new("Unknown classification"));
+        }
+    } else if SpecVersion::V1_6 <= version
         && matches!(classification, Classification::UnknownClassification(_))
     {
         return Err(ValidationError::new("Unknown classification"));
@@ -192,7 +369,8 @@ pub fn validate_classification(
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 #[repr(u16)]
 pub enum Classification {
@@ -212,6 +390,8 @@ pub enum Classification {
     MachineLearningModel = 11,
     /// Added in 1.5
     Data = 12,
+    /// Added in 1.6
+    CryptographicAsset = 13,
     #[doc(hidden)]
     #[strum(default)]
     UnknownClassification(String),
@@ -232,11 +412,14 @@ impl Classification {
             "device-driver" => Self::DeviceDriver,
             "machine-learning-model" => Self::MachineLearningModel,
             "data" => Self::Data,
+            "cryptographic-asset" => Self::CryptographicAsset,
             unknown => Self::UnknownClassification(unknown.to_string()),
         }
     }
 }
 
+from_str_via_new_unchecked!(Classification, UnknownClassification, "classification");
+
 pub fn validate_scope(scope: &Scope) -> Result<(), ValidationError> {
     if matches!(scope, Scope::UnknownScope(_)) {
         return Err(ValidationError::new("Unknown scope"));
@@ -244,7 +427,8 @@ pub fn validate_scope(scope: &Scope) -> Result<(), ValidationError> {
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Scope {
     Required,
@@ -266,6 +450,8 @@ impl Scope {
     }
 }
 
+from_str_via_new_unchecked!(Scope, UnknownScope, "scope");
+
 /// Checks if given [`MimeType`] is valid / supported.
 pub fn validate_mime_type(mime_type: &MimeType) -> Result<(), ValidationError> {
     static UUID_REGEX: Lazy<Regex> =
@@ -280,10 +466,12 @@ pub fn validate_mime_type(mime_type: &MimeType) -> Result<(), ValidationError> {
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MimeType(pub String);
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Swid {
     pub tag_id: String,
     pub name: String,
@@ -306,7 +494,7 @@ impl Validate for Swid {
 pub fn validate_cpe(cpe: &Cpe) -> Result<(), ValidationError> {
     static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
         Regex::new(
-            r##"([c][pP][eE]:/[AHOaho]?(:[A-Za-z0-9\._\-~%]*){0,6})|(cpe:2\.3:[aho\*\-](:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){5}(:(([a-zA-Z]{2,3}(-([a-zA-Z]{2}|[0-9]{3}))?)|[\*\-]))(:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){4})"##,
+            r##"^(?:([c][pP][eE]:/[AHOaho]?(:[A-Za-z0-9\._\-~%]*){0,6})|(cpe:2\.3:[aho\*\-](:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){5}(:(([a-zA-Z]{2,3}(-([a-zA-Z]{2}|[0-9]{3}))?)|[\*\-]))(:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){4}))$"##,
         ).expect("Failed to compile regex.")
     });
 
@@ -319,13 +507,106 @@ pub fn validate_cpe(cpe: &Cpe) -> Result<(), ValidationError> {
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cpe(pub(crate) String);
 
 impl Cpe {
     pub fn new(inner: &str) -> Self {
         Self(inner.to_string())
     }
+
+    /// Constructs a CPE 2.3 formatted string from the given attributes, all other attributes
+    /// match any value. Characters which have a special meaning in CPE 2.3 are escaped
+    /// and empty attributes are replaced with `*`.
+    /// ```
+    /// use cyclonedx_bom::models::component::{Cpe, CpePart};
+    ///
+    /// let cpe = Cpe::from_parts(CpePart::Application, "rust-lang", "cargo", "1.0.0");
+    /// assert_eq!(cpe.to_string(), "cpe:2.3:a:rust-lang:cargo:1.0.0:*:*:*:*:*:*:*");
+    /// ```
+    pub fn from_parts(part: CpePart, vendor: &str, product: &str, version: &str) -> Self {
+        Self(format!(
+            "cpe:2.3:{}:{}:{}:{}:*:*:*:*:*:*:*",
+            part,
+            escape_cpe_attribute(vendor),
+            escape_cpe_attribute(product),
+            escape_cpe_attribute(version),
+        ))
+    }
+
+    /// Returns the part of a CPE 2.3 formatted string, `None` for other CPE formats.
+    pub fn part(&self) -> Option<CpePart> {
+        match self.attribute(0)? {
+            "a" => Some(CpePart::Application),
+            "o" => Some(CpePart::OperatingSystem),
+            "h" => Some(CpePart::Hardware),
+            _ => None,
+        }
+    }
+
+    /// Returns the still escaped vendor of a CPE 2.3 formatted string, `None` for other CPE formats.
+    pub fn vendor(&self) -> Option<&str> {
+        self.attribute(1)
+    }
+
+    /// Returns the still escaped product of a CPE 2.3 formatted string, `None` for other CPE formats.
+    pub fn product(&self) -> Option<&str> {
+        self.attribute(2)
+    }
+
+    /// Returns the still escaped version of a CPE 2.3 formatted string, `None` for other CPE formats.
+    pub fn version(&self) -> Option<&str> {
+        self.attribute(3)
+    }
+
+    fn attribute(&self, index: usize) -> Option<&str> {
+        let attributes = self.0.strip_prefix("cpe:2.3:")?;
+        let mut start = 0;
+        let mut escaped = false;
+        let mut current = 0;
+        for (position, c) in attributes.char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                ':' => {
+                    if current == index {
+                        return Some(&attributes[start..position]);
+                    }
+                    current += 1;
+                    start = position + 1;
+                }
+                _ => {}
+            }
+        }
+        (current == index).then(|| &attributes[start..])
+    }
+}
+
+fn escape_cpe_attribute(value: &str) -> String {
+    if value.is_empty() {
+        return "*".to_string();
+    }
+    value
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' | '_' => c.to_string(),
+            ' ' => "_".to_string(),
+            _ => format!("\\{c}"),
+        })
+        .collect()
+}
+
+/// The class of product a CPE describes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CpePart {
+    #[strum(serialize = "a")]
+    Application,
+    #[strum(serialize = "o")]
+    OperatingSystem,
+    #[strum(serialize = "h")]
+    Hardware,
 }
 
 impl From<String> for Cpe {
@@ -358,7 +639,8 @@ impl std::fmt::Display for Cpe {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComponentEvidence {
     pub licenses: Option<Licenses>,
     pub copyright: Option<CopyrightTexts>,
@@ -372,20 +654,45 @@ pub struct ComponentEvidence {
 
 impl Validate for ComponentEvidence {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
-        ValidationContext::new()
+        let mut context = ValidationContext::new();
+        context
             .add_struct_option("licenses", self.licenses.as_ref(), version)
-            .add_struct_option("copyright", self.copyright.as_ref(), version)
-            .add_struct_option("occurrences", self.occurrences.as_ref(), version)
-            .add_struct_option("callstack", self.callstack.as_ref(), version)
-            .add_struct_option("identity", self.identity.as_ref(), version)
-            .into()
+            .add_struct_option("copyright", self.copyright.as_ref(), version);
+
+        if version <= SpecVersion::V1_4 {
+            if self.occurrences.is_some() {
+                context.add_custom(
+                    "occurrences",
+                    "Field 'occurrences' not supported in this format version",
+                );
+            }
+            if self.callstack.is_some() {
+                context.add_custom(
+                    "callstack",
+                    "Field 'callstack' not supported in this format version",
+                );
+            }
+            if self.identity.is_some() {
+                context.add_custom(
+                    "identity",
+                    "Field 'identity' not supported in this format version",
+                );
+            }
+        } else {
+            context
+                .add_struct_option("occurrences", self.occurrences.as_ref(), version)
+                .add_struct_option("callstack", self.callstack.as_ref(), version)
+                .add_struct_option("identity", self.identity.as_ref(), version);
+        }
+        context.into()
     }
 }
 
 /// For more details see
 /// https://cyclonedx.org/docs/1.5/json/#components_items_evidence_occurrences
 /// Added in version 1.5
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Occurrences(pub Vec<Occurrence>);
 
 impl Validate for Occurrences {
@@ -398,7 +705,8 @@ impl Validate for Occurrences {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Occurrence {
     pub bom_ref: Option<BomReference>,
     pub location: String,
@@ -423,7 +731,8 @@ impl Validate for Occurrence {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Callstack {
     pub frames: Frames,
 }
@@ -440,7 +749,8 @@ impl Validate for Callstack {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Frames(pub Vec<Frame>);
 
 impl Validate for Frames {
@@ -454,7 +764,8 @@ impl Validate for Frames {
 /// For more information see
 /// https://cyclonedx.org/docs/1.5/json/#components_items_evidence_callstack
 /// Added in version 1.5
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Frame {
     pub package: Option<NormalizedString>,
     pub module: NormalizedString,
@@ -490,13 +801,14 @@ impl Validate for Frame {
 }
 
 pub fn validate_confidence(confidence: &ConfidenceScore) -> Result<(), ValidationError> {
-    if confidence.get() < 0.0 && 1.0 > confidence.get() {
+    if !(0.0..=1.0).contains(&confidence.get()) {
         return Err("Confidence score outside range 0.0 - 1.0".into());
     }
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfidenceScore(pub OrderedFloat<f32>);
 
 impl ConfidenceScore {
@@ -516,7 +828,8 @@ pub fn validate_identity_field(field: &IdentityField) -> Result<(), ValidationEr
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 #[repr(u16)]
 pub enum IdentityField {
@@ -545,10 +858,13 @@ impl IdentityField {
     }
 }
 
+from_str_via_new_unchecked!(IdentityField, Unknown, "identity field");
+
 /// For more information see
 /// https://cyclonedx.org/docs/1.5/json/#components_items_evidence_identity
 /// Added in version 1.5
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Identity {
     pub field: IdentityField,
     /// Level between 0.0-1.0 (where 1.0 is highest confidence)
@@ -558,30 +874,52 @@ pub struct Identity {
 }
 
 impl Validate for Identity {
-    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
             .add_field("field", &self.field, validate_identity_field)
             .add_field_option("confidence", self.confidence.as_ref(), validate_confidence)
+            .add_struct_option("methods", self.methods.as_ref(), version)
             .into()
     }
 }
 
 /// For more information see
 /// https://cyclonedx.org/docs/1.5/json/#components_items_evidence_identity_methods
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Methods(pub Vec<Method>);
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+impl Validate for Methods {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list("inner", &self.0, |method| method.validate_version(version))
+            .into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Method {
     pub technique: String,
+    /// Level between 0.0-1.0 (where 1.0 is highest confidence)
     pub confidence: ConfidenceScore,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+impl Validate for Method {
+    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_field("confidence", &self.confidence, validate_confidence)
+            .into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToolsReferences(pub Vec<String>);
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pedigree {
     pub ancestors: Option<Components>,
     pub descendants: Option<Components>,
@@ -607,10 +945,12 @@ pub fn validate_copyright(_copyright: &Copyright) -> Result<(), ValidationError>
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Copyright(pub String);
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CopyrightTexts(pub Vec<Copyright>);
 
 impl Validate for CopyrightTexts {
@@ -664,6 +1004,7 @@ mod test {
                 url: None,
                 contact: None,
             }),
+            authors: None,
             author: Some(NormalizedString::new("author")),
             publisher: Some(NormalizedString::new("publisher")),
             group: Some(NormalizedString::new("group")),
@@ -753,6 +1094,7 @@ mod test {
                     tools: None,
                 }),
             }),
+            release_notes: None,
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
             model_card: Some(ModelCard {
                 bom_ref: None,
@@ -769,7 +1111,7 @@ mod test {
                         name: Some("dataset".to_string()),
                         contents: Some(DataContents {
                             attachment: Some(Attachment {
-                                content: "data content".to_string(),
+                                content: "ZGF0YSBjb250ZW50".to_string(),
                                 content_type: Some("text/plain".to_string()),
                                 encoding: Some("base64".to_string()),
                             }),
@@ -785,7 +1127,7 @@ mod test {
                                 image: Some(Attachment {
                                     content_type: Some("image/jpeg".to_string()),
                                     encoding: Some("base64".to_string()),
-                                    content: "imagebytes".to_string(),
+                                    content: "aW1hZ2VieXRlcw==".to_string(),
                                 }),
                             }]),
                         }),
@@ -821,19 +1163,19 @@ mod test {
                         collection: None,
                     }),
                 }),
-                considerations: Some(Considerations {}),
+                considerations: Some(Considerations::default()),
                 properties: Some(Properties(vec![Property {
                     name: "property".to_string(),
                     value: NormalizedString("value".to_string()),
                 }])),
             }),
-            data: Some(ComponentData {
+            data: Some(vec![ComponentData {
                 bom_ref: None,
                 data_type: ComponentDataType::SourceCode,
                 name: Some("github".into()),
                 contents: Some(DataContents {
                     attachment: Some(Attachment {
-                        content: "some pic".into(),
+                        content: "c29tZSBwaWM=".into(),
                         content_type: None,
                         encoding: Some("base64".into()),
                     }),
@@ -845,9 +1187,10 @@ mod test {
                 graphics: None,
                 description: None,
                 governance: None,
-            }),
+            }]),
+            crypto_properties: None,
         }];
-        let validation_result = Components(vec).validate();
+        let validation_result = Components(vec).validate_version(SpecVersion::V1_5);
 
         assert!(validation_result.passed());
     }
@@ -864,6 +1207,7 @@ mod test {
                 url: None,
                 contact: None,
             }),
+            authors: None,
             author: Some(NormalizedString("invalid\tauthor".to_string())),
             publisher: Some(NormalizedString("invalid\tpublisher".to_string())),
             group: Some(NormalizedString("invalid\tgroup".to_string())),
@@ -937,9 +1281,11 @@ mod test {
                 callstack: None,
                 identity: None,
             }),
+            release_notes: None,
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
             model_card: None,
             data: None,
+            crypto_properties: None,
         }])
         .validate();
 
@@ -1163,6 +1509,7 @@ mod test {
             mime_type: None,
             bom_ref: None,
             supplier: None,
+            authors: None,
             author: None,
             publisher: None,
             group: None,
@@ -1182,12 +1529,29 @@ mod test {
             properties: None,
             components: None,
             evidence: None,
+            release_notes: None,
             signature: None,
             model_card: None,
             data: None,
+            crypto_properties: None,
         }
     }
 
+    #[test]
+    fn authors_should_fail_validation_in_older_versions() {
+        let mut component = Component::new(Classification::Library, "name", "1.0.0", None);
+        component.authors = Some(vec![OrganizationalContact::new("name", None)]);
+
+        assert!(component.validate_version(SpecVersion::V1_6).passed());
+        assert_eq!(
+            component.validate_version(SpecVersion::V1_5),
+            validation::custom(
+                "authors",
+                ["Field 'authors' not supported in this format version"]
+            )
+        );
+    }
+
     #[test]
     fn test_validate_classification() {
         assert!(validate_classification(&Classification::Library, SpecVersion::V1_4).is_ok());
@@ -1206,4 +1570,297 @@ mod test {
         )
         .is_err());
     }
+
+    #[test]
+    fn it_should_validate_cpes() {
+        assert!(validate_cpe(&Cpe::new("cpe:/a:example:mylibrary:1.0.0")).is_ok());
+        assert!(validate_cpe(&Cpe::new(
+            r"cpe:2.3:a:example:my\:library:1.0.0:*:*:*:*:*:*:*"
+        ))
+        .is_ok());
+
+        assert!(validate_cpe(&Cpe::new("invalid cpe")).is_err());
+        assert!(validate_cpe(&Cpe::new("cpe:2.3:a:example:mylibrary")).is_err());
+        assert!(validate_cpe(&Cpe::new(
+            "prefix cpe:2.3:a:example:mylibrary:1.0.0:*:*:*:*:*:*:*"
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn it_should_create_cpes_from_parts() {
+        let cpe = Cpe::from_parts(CpePart::Application, "Example Inc", "my:library", "");
+
+        assert_eq!(
+            cpe.to_string(),
+            r"cpe:2.3:a:Example_Inc:my\:library:*:*:*:*:*:*:*:*"
+        );
+        assert!(validate_cpe(&cpe).is_ok());
+        assert_eq!(cpe.part(), Some(CpePart::Application));
+        assert_eq!(cpe.vendor(), Some("Example_Inc"));
+        assert_eq!(cpe.product(), Some(r"my\:library"));
+        assert_eq!(cpe.version(), Some("*"));
+
+        let cpe = Cpe::new("cpe:/a:example:mylibrary:1.0.0");
+        assert_eq!(cpe.part(), None);
+        assert_eq!(cpe.vendor(), None);
+    }
+
+    fn example_evidence() -> ComponentEvidence {
+        ComponentEvidence {
+            licenses: None,
+            copyright: None,
+            occurrences: Some(Occurrences(vec![Occurrence::new("src/main.rs")])),
+            callstack: Some(Callstack::new(Frames(vec![Frame {
+                package: None,
+                module: "module".into(),
+                function: None,
+                parameters: None,
+                line: None,
+                column: None,
+                full_filename: None,
+            }]))),
+            identity: Some(Identity {
+                field: IdentityField::Purl,
+                confidence: Some(ConfidenceScore::new(1.0)),
+                methods: Some(Methods(vec![Method {
+                    technique: "manifest-analysis".to_string(),
+                    confidence: ConfidenceScore::new(0.0),
+                    value: Some("Cargo.toml".to_string()),
+                }])),
+                tools: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn valid_evidence_should_pass_validation() {
+        let validation_result = example_evidence().validate_version(SpecVersion::V1_5);
+
+        assert!(validation_result.passed());
+    }
+
+    #[test]
+    fn evidence_with_out_of_range_confidence_should_fail_validation() {
+        let mut evidence = example_evidence();
+        let identity = evidence.identity.as_mut().unwrap();
+        identity.confidence = Some(ConfidenceScore::new(1.5));
+        identity.methods.as_mut().unwrap().0[0].confidence = ConfidenceScore::new(-0.1);
+
+        let validation_result = evidence.validate_version(SpecVersion::V1_5);
+
+        assert_eq!(
+            validation_result,
+            validation::r#struct(
+                "identity",
+                vec![
+                    validation::field("confidence", "Confidence score outside range 0.0 - 1.0"),
+                    validation::r#struct(
+                        "methods",
+                        validation::list(
+                            "inner",
+                            [(
+                                0,
+                                validation::field(
+                                    "confidence",
+                                    "Confidence score outside range 0.0 - 1.0"
+                                )
+                            )]
+                        )
+                    )
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn evidence_should_fail_validation_in_older_versions() {
+        let validation_result = example_evidence().validate_version(SpecVersion::V1_4);
+
+        assert_eq!(
+            validation_result,
+            vec![
+                validation::custom(
+                    "occurrences",
+                    ["Field 'occurrences' not supported in this format version"]
+                ),
+                validation::custom(
+                    "callstack",
+                    ["Field 'callstack' not supported in this format version"]
+                ),
+                validation::custom(
+                    "identity",
+                    ["Field 'identity' not supported in this format version"]
+                ),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn data_component_with_unknown_data_type_should_fail_validation() {
+        let mut component = Component::new(Classification::Data, "config", "1.0", None);
+        component.data = Some(vec![ComponentData {
+            bom_ref: None,
+            data_type: ComponentDataType::new_unchecked("spreadsheet"),
+            name: Some("settings".to_string()),
+            contents: None,
+            classification: None,
+            sensitive_data: None,
+            graphics: None,
+            description: None,
+            governance: None,
+        }]);
+
+        let validation_result = component.validate_version(SpecVersion::V1_5);
+
+        assert_eq!(
+            validation_result,
+            validation::list(
+                "data",
+                [(
+                    0,
+                    validation::field("type", "Unknown component data type found")
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn release_notes_should_fail_validation_in_older_versions() {
+        let mut component = Component::new(Classification::Library, "library", "1.0", None);
+        component.release_notes = Some(ReleaseNotes::new("major"));
+
+        assert!(component.validate_version(SpecVersion::V1_4).passed());
+        assert_eq!(
+            component.validate_version(SpecVersion::V1_3),
+            validation::custom(
+                "release_notes",
+                ["Field 'release_notes' not supported in this format version"]
+            )
+        );
+    }
+
+    #[test]
+    fn modelcard_and_data_should_fail_validation_in_older_versions() {
+        let mut component = Component::new(Classification::Library, "model", "1.0", None);
+        component.model_card = Some(ModelCard {
+            bom_ref: None,
+            model_parameters: None,
+            quantitative_analysis: None,
+            considerations: None,
+            properties: None,
+        });
+        component.data = Some(vec![]);
+
+        let validation_result = component.validate_version(SpecVersion::V1_4);
+
+        assert_eq!(
+            validation_result,
+            vec![
+                validation::custom(
+                    "model_card",
+                    ["Field 'model_card' not supported in this format version"]
+                ),
+                validation::custom(
+                    "data",
+                    ["Field 'data' not supported in this format version"]
+                ),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn it_should_identify_components_by_purl_or_coordinates() {
+        use std::collections::{BTreeSet, HashSet};
+        use std::str::FromStr;
+
+        let component = |name: &str, purl: Option<&str>| {
+            let mut component = Component::new(
+                Classification::Library,
+                name,
+                "1.0.0",
+                Some(name.to_string()),
+            );
+            component.purl = purl.map(|purl| Purl::from_str(purl).unwrap());
+            component
+        };
+        let components = [
+            component("b", Some("pkg:cargo/b@1.0.0?x=1&y=2")),
+            component("b-renamed", Some("pkg:cargo/b@1.0.0?y=2&x=1")),
+            component("a", None),
+            component("a", None),
+            component("invalid", Some("not a purl")),
+        ];
+
+        let identities: HashSet<_> = components.iter().map(Component::identity).collect();
+        assert_eq!(identities.len(), 3);
+        assert!(identities.contains(&ComponentIdentity::Coordinates {
+            group: None,
+            name: "a".to_string(),
+            version: Some("1.0.0".to_string()),
+        }));
+        assert!(identities.contains(&ComponentIdentity::Purl("not a purl".to_string())));
+
+        let sorted: BTreeSet<_> = components.iter().collect();
+        assert_eq!(sorted.len(), 4);
+        assert_eq!(
+            sorted.first().map(|c| c.name.to_string()),
+            Some("a".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_parse_classifications_and_scopes_from_their_display_form() {
+        use std::str::FromStr;
+
+        let classification = Classification::MachineLearningModel;
+        assert_eq!(classification.to_string(), "machine-learning-model");
+        assert_eq!(
+            Classification::from_str(&classification.to_string()),
+            Ok(classification)
+        );
+        assert_eq!(Scope::from_str("excluded"), Ok(Scope::Excluded));
+        assert_eq!(
+            Scope::from_str("dev").unwrap_err(),
+            crate::errors::UnknownValueError {
+                kind: "scope",
+                value: "dev".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_match_components_by_their_first_shared_identifier() {
+        use std::str::FromStr;
+
+        let swid = |tag_id: &str| Swid {
+            tag_id: tag_id.to_string(),
+            name: "app".to_string(),
+            version: None,
+            tag_version: None,
+            patch: None,
+            text: None,
+            url: None,
+        };
+        let mut purl_and_swid = Component::new(Classification::Application, "app", "1.0.0", None);
+        purl_and_swid.purl = Purl::from_str("pkg:generic/app@1.0.0").ok();
+        purl_and_swid.swid = Some(swid("app-tag"));
+        let mut other_purl = purl_and_swid.clone();
+        other_purl.purl = Purl::from_str("pkg:generic/other@1.0.0").ok();
+        let mut only_swid = Component::new(Classification::Application, "renamed", "2.0.0", None);
+        only_swid.swid = Some(swid("app-tag"));
+        let coordinates = Component::new(Classification::Application, "app", "1.0.0", None);
+
+        assert_eq!(
+            only_swid.identity(),
+            ComponentIdentity::Swid("app-tag".to_string())
+        );
+        assert!(purl_and_swid.matches(&only_swid));
+        assert!(only_swid.matches(&purl_and_swid));
+        assert!(!purl_and_swid.matches(&other_purl));
+        assert!(purl_and_swid.matches(&coordinates));
+        assert!(!only_swid.matches(&coordinates));
+    }
 }
@@ -29,7 +29,8 @@ use super::{
 };
 
 /// Inline Component Data
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComponentData {
     pub bom_ref: Option<BomReference>,
     /// 'type' field
@@ -54,7 +55,8 @@ impl Validate for ComponentData {
     }
 }
 
-fn validate_datatype(datatype: &ComponentDataType) -> Result<(), ValidationError> {
+/// Checks the given [`ComponentDataType`] is valid.
+pub fn validate_datatype(datatype: &ComponentDataType) -> Result<(), ValidationError> {
     if matches!(datatype, ComponentDataType::Unknown(_)) {
         return Err("Unknown component data type found".into());
     }
@@ -62,7 +64,8 @@ fn validate_datatype(datatype: &ComponentDataType) -> Result<(), ValidationError
 }
 
 /// Type of data
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display, strum::EnumString, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, strum::EnumString, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum ComponentDataType {
     SourceCode,
@@ -75,13 +78,20 @@ pub enum ComponentDataType {
     Unknown(String),
 }
 
+impl ComponentDataType {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        std::str::FromStr::from_str(value.as_ref()).expect("infallible")
+    }
+}
+
 impl From<String> for ComponentDataType {
     fn from(value: String) -> Self {
         std::str::FromStr::from_str(&value).expect("infallible")
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataContents {
     pub attachment: Option<Attachment>,
     pub url: Option<Uri>,
@@ -99,7 +109,8 @@ impl Validate for DataContents {
 }
 
 /// bom-1.5.schema.json #definitions/graphicsCollection
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphicsCollection {
     pub description: Option<String>,
     pub collection: Option<Vec<Graphic>>,
@@ -116,7 +127,8 @@ impl Validate for GraphicsCollection {
 }
 
 /// bom-1.5.schema.json #definitions/graphic
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Graphic {
     pub name: Option<String>,
     pub image: Option<Attachment>,
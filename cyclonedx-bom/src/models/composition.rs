@@ -16,14 +16,17 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::collections::HashMap;
+
 use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
 
 use super::{
-    bom::{BomReference, SpecVersion},
+    bom::{validate_bom_ref, Bom, BomReference, SpecVersion},
     signature::Signature,
 };
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Composition {
     pub bom_ref: Option<BomReference>,
     pub aggregate: AggregateType,
@@ -36,15 +39,24 @@ pub struct Composition {
 impl Validate for Composition {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
+            .add_field_option("bom_ref", self.bom_ref.as_ref(), |bom_ref| {
+                validate_bom_ref(bom_ref, version)
+            })
             .add_field("aggregate", &self.aggregate, |at| {
                 validate_aggregate_type(at, version)
             })
+            .add_field_option(
+                "vulnerabilities",
+                self.vulnerabilities.as_ref(),
+                |vulnerabilities| validate_vulnerabilities(vulnerabilities, version),
+            )
             .add_struct_option("signature", self.signature.as_ref(), version)
             .into()
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Compositions(pub Vec<Composition>);
 
 impl Validate for Compositions {
@@ -66,15 +78,25 @@ pub fn validate_aggregate_type(
         if AggregateType::IncompleteFirstPartyProprietaryOnly < *aggregate_type {
             return Err("Unknown aggregate type".into());
         }
-    } else if version <= SpecVersion::V1_5
-        && matches!(aggregate_type, AggregateType::UnknownAggregateType(_))
-    {
+    } else if matches!(aggregate_type, AggregateType::UnknownAggregateType(_)) {
         return Err(ValidationError::new("Unknown aggregate type"));
     }
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display)]
+/// Validates the list of vulnerabilities of a composition, which were added in 1.5.
+fn validate_vulnerabilities(
+    _vulnerabilities: &[BomReference],
+    version: SpecVersion,
+) -> Result<(), ValidationError> {
+    if version <= SpecVersion::V1_4 {
+        return Err("Field 'vulnerabilities' not supported in this format version".into());
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "snake_case")]
 #[repr(u16)]
 pub enum AggregateType {
@@ -103,6 +125,8 @@ impl AggregateType {
             "complete" => Self::Complete,
             "incomplete" => Self::Incomplete,
             "incomplete_first_party_only" => Self::IncompleteFirstPartyOnly,
+            "incomplete_first_party_proprietary_only" => Self::IncompleteFirstPartyProprietaryOnly,
+            // Misspelling accepted by earlier versions of this crate
             "incomplete_first_party_propprietary_only" => Self::IncompleteFirstPartyProprietaryOnly,
             "incomplete_first_party_opensource_only" => Self::IncompleteFirstPartyOpensourceOnly,
             "incomplete_third_party_only" => Self::IncompleteThirdPartyOnly,
@@ -115,6 +139,125 @@ impl AggregateType {
     }
 }
 
+from_str_via_new_unchecked!(AggregateType, UnknownAggregateType, "aggregate type");
+
+/// Which parts of a BOM the compositions assert to be complete, see [`Bom::completeness_report`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompletenessReport {
+    /// The aggregate of the assembly of the metadata component, i.e. of the whole inventory
+    pub inventory: Option<AggregateType>,
+    /// The components in the order of [`Bom::iter_components_recursive`]
+    pub components: Vec<ComponentCompleteness>,
+}
+
+/// The asserted completeness of a component, `None` if no composition mentions the component.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComponentCompleteness {
+    pub bom_ref: Option<String>,
+    pub name: String,
+    /// The aggregate of the nested components
+    pub assembly: Option<AggregateType>,
+    /// The aggregate of the dependencies of the component
+    pub dependencies: Option<AggregateType>,
+}
+
+impl ComponentCompleteness {
+    /// Returns `true` if all nested components are listed, so a missing one is not part of it.
+    pub fn is_assembly_complete(&self) -> bool {
+        self.assembly == Some(AggregateType::Complete)
+    }
+
+    /// Returns `true` if all dependencies are listed, so a missing one is not a dependency.
+    pub fn are_dependencies_complete(&self) -> bool {
+        self.dependencies == Some(AggregateType::Complete)
+    }
+}
+
+impl CompletenessReport {
+    /// Returns `true` if the BOM lists all components, so a missing one is not part of it.
+    pub fn is_inventory_complete(&self) -> bool {
+        self.inventory == Some(AggregateType::Complete)
+    }
+
+    /// Returns the completeness of the component with the bom-ref.
+    pub fn component(&self, bom_ref: &str) -> Option<&ComponentCompleteness> {
+        self.components
+            .iter()
+            .find(|component| component.bom_ref.as_deref() == Some(bom_ref))
+    }
+
+    /// Returns the components with neither a complete assembly nor complete dependencies.
+    pub fn unasserted(&self) -> impl Iterator<Item = &ComponentCompleteness> {
+        self.components.iter().filter(|component| {
+            !component.is_assembly_complete() && !component.are_dependencies_complete()
+        })
+    }
+}
+
+impl Bom {
+    /// Interprets the compositions to report which assemblies and dependency lists of the
+    /// components are asserted complete, incomplete or unknown.
+    ///
+    /// If several compositions mention a component, any aggregate other than `complete` wins.
+    /// ```
+    /// use cyclonedx_bom::models::bom::Bom;
+    ///
+    /// let report = Bom::default().completeness_report();
+    /// assert!(!report.is_inventory_complete());
+    /// ```
+    pub fn completeness_report(&self) -> CompletenessReport {
+        fn record<'a>(
+            aggregates: &mut HashMap<&'a str, &'a AggregateType>,
+            references: Option<&'a Vec<BomReference>>,
+            aggregate: &'a AggregateType,
+        ) {
+            for BomReference(reference) in references.into_iter().flatten() {
+                let known = aggregates.entry(reference).or_insert(aggregate);
+                if **known == AggregateType::Complete {
+                    *known = aggregate;
+                }
+            }
+        }
+
+        let mut assemblies = HashMap::new();
+        let mut dependencies = HashMap::new();
+        for composition in self.compositions.iter().flat_map(|c| &c.0) {
+            let aggregate = &composition.aggregate;
+            record(&mut assemblies, composition.assemblies.as_ref(), aggregate);
+            record(
+                &mut dependencies,
+                composition.dependencies.as_ref(),
+                aggregate,
+            );
+        }
+
+        let aggregate = |aggregates: &HashMap<&str, &AggregateType>, bom_ref: Option<&str>| {
+            bom_ref
+                .and_then(|bom_ref| aggregates.get(bom_ref))
+                .map(|aggregate| (*aggregate).clone())
+        };
+        let inventory = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+            .and_then(|component| aggregate(&assemblies, component.bom_ref.as_deref()));
+        let components = self
+            .iter_components_recursive()
+            .map(|component| ComponentCompleteness {
+                bom_ref: component.bom_ref.clone(),
+                name: component.name.to_string(),
+                assembly: aggregate(&assemblies, component.bom_ref.as_deref()),
+                dependencies: aggregate(&dependencies, component.bom_ref.as_deref()),
+            })
+            .collect();
+
+        CompletenessReport {
+            inventory,
+            components,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{models::signature::Algorithm, validation};
@@ -132,7 +275,7 @@ mod test {
             vulnerabilities: Some(vec![BomReference::new("vulnerability-ref")]),
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
         }])
-        .validate();
+        .validate_version(SpecVersion::V1_5);
 
         assert!(validation_result.passed());
     }
@@ -147,7 +290,7 @@ mod test {
             vulnerabilities: Some(vec![BomReference::new("vulnerability-ref")]),
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
         }])
-        .validate();
+        .validate_version(SpecVersion::V1_5);
 
         assert_eq!(
             validation_result,
@@ -160,4 +303,133 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn it_should_fail_validation_of_1_5_fields_in_older_versions() {
+        let validation_result = Compositions(vec![Composition {
+            bom_ref: Some(BomReference::new("composition-1")),
+            aggregate: AggregateType::Complete,
+            assemblies: Some(vec![BomReference::new("assembly-ref")]),
+            dependencies: None,
+            vulnerabilities: Some(vec![BomReference::new("vulnerability-ref")]),
+            signature: None,
+        }])
+        .validate_version(SpecVersion::V1_4);
+
+        assert_eq!(
+            validation_result,
+            validation::list(
+                "composition",
+                [(
+                    0,
+                    vec![
+                        validation::field(
+                            "bom_ref",
+                            "Attribute 'bom-ref' not supported in this format version"
+                        ),
+                        validation::field(
+                            "vulnerabilities",
+                            "Field 'vulnerabilities' not supported in this format version"
+                        )
+                    ]
+                )]
+            )
+        );
+    }
+
+    fn composition(
+        aggregate: AggregateType,
+        assemblies: &[&str],
+        dependencies: &[&str],
+    ) -> Composition {
+        let references =
+            |refs: &[&str]| Some(refs.iter().map(BomReference::new).collect::<Vec<_>>());
+        Composition {
+            bom_ref: None,
+            aggregate,
+            assemblies: references(assemblies),
+            dependencies: references(dependencies),
+            vulnerabilities: None,
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn it_should_report_the_asserted_completeness_of_components() {
+        use crate::models::{
+            component::{Classification, Component, Components},
+            metadata::Metadata,
+        };
+
+        let component = |name: &str| {
+            Component::new(
+                Classification::Library,
+                name,
+                "1.0.0",
+                Some(name.to_string()),
+            )
+        };
+        let bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(component("root")),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![
+                component("library"),
+                component("other"),
+                Component::new(Classification::Library, "anonymous", "1.0.0", None),
+            ])),
+            compositions: Some(Compositions(vec![
+                composition(AggregateType::Complete, &["root"], &["root", "library"]),
+                composition(AggregateType::Incomplete, &[], &["library"]),
+                composition(AggregateType::Unknown, &["other"], &[]),
+                composition(AggregateType::Complete, &["other"], &[]),
+            ])),
+            ..Bom::default()
+        };
+
+        let report = bom.completeness_report();
+
+        assert!(report.is_inventory_complete());
+        assert_eq!(
+            report.component("root"),
+            Some(&ComponentCompleteness {
+                bom_ref: Some("root".to_string()),
+                name: "root".to_string(),
+                assembly: Some(AggregateType::Complete),
+                dependencies: Some(AggregateType::Complete),
+            })
+        );
+        assert_eq!(
+            report.component("library").unwrap().dependencies,
+            Some(AggregateType::Incomplete)
+        );
+        assert_eq!(
+            report.component("other").unwrap().assembly,
+            Some(AggregateType::Unknown)
+        );
+        assert_eq!(
+            report
+                .unasserted()
+                .map(|component| component.name.as_str())
+                .collect::<Vec<_>>(),
+            ["library", "other", "anonymous"]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_aggregate_types_from_their_display_form() {
+        use std::str::FromStr;
+
+        let aggregate = AggregateType::IncompleteFirstPartyProprietaryOnly;
+        assert_eq!(
+            AggregateType::from_str(&aggregate.to_string()),
+            Ok(aggregate)
+        );
+        assert_eq!(
+            AggregateType::from_str("incomplete_first_party_propprietary_only"),
+            Ok(AggregateType::IncompleteFirstPartyProprietaryOnly)
+        );
+        assert!(AggregateType::from_str("partial").is_err());
+    }
 }
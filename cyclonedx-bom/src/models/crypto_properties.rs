@@ -0,0 +1,1045 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! The [cryptographic properties](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties)
+//! of components of type `cryptographic-asset`, added in version 1.6, which describe the
+//! algorithms, certificates, protocols and related material of a Cryptography BOM (CBOM).
+
+use crate::{
+    external_models::date_time::{validate_date_time, DateTime},
+    models::bom::SpecVersion,
+    prelude::{Validate, ValidationResult},
+    validation::{ValidationContext, ValidationError},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CryptoProperties {
+    pub asset_type: CryptoAssetType,
+    pub algorithm_properties: Option<AlgorithmProperties>,
+    pub certificate_properties: Option<CertificateProperties>,
+    pub related_crypto_material_properties: Option<RelatedCryptoMaterialProperties>,
+    pub protocol_properties: Option<ProtocolProperties>,
+    /// The object identifier (OID) of the cryptographic asset
+    pub oid: Option<String>,
+}
+
+impl CryptoProperties {
+    pub fn new(asset_type: CryptoAssetType) -> Self {
+        Self {
+            asset_type,
+            algorithm_properties: None,
+            certificate_properties: None,
+            related_crypto_material_properties: None,
+            protocol_properties: None,
+            oid: None,
+        }
+    }
+}
+
+impl Validate for CryptoProperties {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        let mut context = ValidationContext::new();
+        if version < SpecVersion::V1_6 {
+            context.add_custom(
+                "crypto_properties",
+                "Crypto properties are not supported in this format version",
+            );
+        }
+        context
+            .add_enum("asset_type", &self.asset_type, validate_crypto_asset_type)
+            .add_struct_option(
+                "algorithm_properties",
+                self.algorithm_properties.as_ref(),
+                version,
+            )
+            .add_struct_option(
+                "certificate_properties",
+                self.certificate_properties.as_ref(),
+                version,
+            )
+            .add_struct_option(
+                "related_crypto_material_properties",
+                self.related_crypto_material_properties.as_ref(),
+                version,
+            )
+            .add_struct_option(
+                "protocol_properties",
+                self.protocol_properties.as_ref(),
+                version,
+            )
+            .into()
+    }
+}
+
+/// The properties of an asset of type `algorithm`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlgorithmProperties {
+    pub primitive: Option<CryptoPrimitive>,
+    /// An identifier for the parameter set of the algorithm, e.g. the key size `128` of AES-128
+    pub parameter_set_identifier: Option<String>,
+    /// The elliptic curve of an algorithm, e.g. `secp256r1`
+    pub curve: Option<String>,
+    pub execution_environment: Option<ExecutionEnvironment>,
+    pub implementation_platform: Option<ImplementationPlatform>,
+    pub certification_level: Option<Vec<CertificationLevel>>,
+    pub mode: Option<CryptoMode>,
+    pub padding: Option<CryptoPadding>,
+    pub crypto_functions: Option<Vec<CryptoFunction>>,
+    /// The classical security level in bits
+    pub classical_security_level: Option<u32>,
+    /// The NIST security strength category between 0 and 6 against quantum computers
+    pub nist_quantum_security_level: Option<u32>,
+}
+
+impl Validate for AlgorithmProperties {
+    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_enum_option(
+                "primitive",
+                self.primitive.as_ref(),
+                validate_crypto_primitive,
+            )
+            .add_enum_option(
+                "execution_environment",
+                self.execution_environment.as_ref(),
+                validate_execution_environment,
+            )
+            .add_enum_option(
+                "implementation_platform",
+                self.implementation_platform.as_ref(),
+                validate_implementation_platform,
+            )
+            .add_list_option(
+                "certification_level",
+                self.certification_level.as_ref(),
+                validate_certification_level,
+            )
+            .add_enum_option("mode", self.mode.as_ref(), validate_crypto_mode)
+            .add_enum_option("padding", self.padding.as_ref(), validate_crypto_padding)
+            .add_list_option(
+                "crypto_functions",
+                self.crypto_functions.as_ref(),
+                validate_crypto_function,
+            )
+            .add_field_option(
+                "nist_quantum_security_level",
+                self.nist_quantum_security_level,
+                validate_nist_quantum_security_level,
+            )
+            .into()
+    }
+}
+
+fn validate_nist_quantum_security_level(level: u32) -> Result<(), ValidationError> {
+    if level > 6 {
+        return Err("NIST quantum security level must be between 0 and 6".into());
+    }
+    Ok(())
+}
+
+/// The properties of an asset of type `certificate`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CertificateProperties {
+    pub subject_name: Option<String>,
+    pub issuer_name: Option<String>,
+    pub not_valid_before: Option<DateTime>,
+    pub not_valid_after: Option<DateTime>,
+    /// The `bom-ref` of the signature algorithm
+    pub signature_algorithm_ref: Option<String>,
+    /// The `bom-ref` of the public key of the subject
+    pub subject_public_key_ref: Option<String>,
+    /// The format of the certificate, e.g. `X.509`
+    pub certificate_format: Option<String>,
+    /// The file extension of the certificate, e.g. `crt`
+    pub certificate_extension: Option<String>,
+}
+
+impl Validate for CertificateProperties {
+    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_field_option(
+                "not_valid_before",
+                self.not_valid_before.as_ref(),
+                validate_date_time,
+            )
+            .add_field_option(
+                "not_valid_after",
+                self.not_valid_after.as_ref(),
+                validate_date_time,
+            )
+            .into()
+    }
+}
+
+/// The properties of an asset of type `related-crypto-material`, e.g. a key.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelatedCryptoMaterialProperties {
+    pub material_type: Option<RelatedCryptoMaterialType>,
+    pub id: Option<String>,
+    pub state: Option<RelatedCryptoMaterialState>,
+    /// The `bom-ref` of the algorithm the material is used with
+    pub algorithm_ref: Option<String>,
+    pub creation_date: Option<DateTime>,
+    pub activation_date: Option<DateTime>,
+    pub update_date: Option<DateTime>,
+    pub expiration_date: Option<DateTime>,
+    pub value: Option<String>,
+    /// The size of the material in bits or bytes, depending on its type
+    pub size: Option<u32>,
+    pub format: Option<String>,
+    pub secured_by: Option<SecuredBy>,
+}
+
+impl Validate for RelatedCryptoMaterialProperties {
+    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_enum_option(
+                "material_type",
+                self.material_type.as_ref(),
+                validate_related_crypto_material_type,
+            )
+            .add_enum_option(
+                "state",
+                self.state.as_ref(),
+                validate_related_crypto_material_state,
+            )
+            .add_field_option(
+                "creation_date",
+                self.creation_date.as_ref(),
+                validate_date_time,
+            )
+            .add_field_option(
+                "activation_date",
+                self.activation_date.as_ref(),
+                validate_date_time,
+            )
+            .add_field_option("update_date", self.update_date.as_ref(), validate_date_time)
+            .add_field_option(
+                "expiration_date",
+                self.expiration_date.as_ref(),
+                validate_date_time,
+            )
+            .into()
+    }
+}
+
+/// The mechanism and algorithm which secure the related crypto material.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecuredBy {
+    /// The mechanism, e.g. `Software` or `HSM`
+    pub mechanism: Option<String>,
+    /// The `bom-ref` of the algorithm
+    pub algorithm_ref: Option<String>,
+}
+
+/// The properties of an asset of type `protocol`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolProperties {
+    pub protocol_type: Option<ProtocolType>,
+    pub version: Option<String>,
+    pub cipher_suites: Option<Vec<CipherSuite>>,
+    pub ikev2_transform_types: Option<Ikev2TransformTypes>,
+    /// The `bom-ref`s of the cryptographic assets used by the protocol
+    pub crypto_ref_array: Option<Vec<String>>,
+}
+
+impl Validate for ProtocolProperties {
+    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_enum_option(
+                "protocol_type",
+                self.protocol_type.as_ref(),
+                validate_protocol_type,
+            )
+            .into()
+    }
+}
+
+/// A cipher suite of a protocol.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CipherSuite {
+    pub name: Option<String>,
+    /// The `bom-ref`s of the algorithms of the cipher suite
+    pub algorithms: Option<Vec<String>>,
+    /// The identifiers of the cipher suite, e.g. `0xC0` and `0x30`
+    pub identifiers: Option<Vec<String>>,
+}
+
+/// The IKEv2 transform types of a protocol, each a list of `bom-ref`s of algorithms.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ikev2TransformTypes {
+    /// Encryption algorithms
+    pub encr: Option<Vec<String>>,
+    /// Pseudorandom functions
+    pub prf: Option<Vec<String>>,
+    /// Integrity algorithms
+    pub integ: Option<Vec<String>>,
+    /// Key exchange methods
+    pub ke: Option<Vec<String>>,
+    /// Whether extended sequence numbers are used
+    pub esn: Option<bool>,
+    /// Authentication methods
+    pub auth: Option<Vec<String>>,
+}
+
+/// The type of a cryptographic asset.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_assetType)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum CryptoAssetType {
+    Algorithm,
+    Certificate,
+    Protocol,
+    RelatedCryptoMaterial,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedCryptoAssetType(String),
+}
+
+impl CryptoAssetType {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "algorithm" => Self::Algorithm,
+            "certificate" => Self::Certificate,
+            "protocol" => Self::Protocol,
+            "related-crypto-material" => Self::RelatedCryptoMaterial,
+            undefined => Self::UndefinedCryptoAssetType(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(
+    CryptoAssetType,
+    UndefinedCryptoAssetType,
+    "crypto asset type"
+);
+
+pub fn validate_crypto_asset_type(value: &CryptoAssetType) -> Result<(), ValidationError> {
+    if let CryptoAssetType::UndefinedCryptoAssetType(undefined) = value {
+        return Err(format!("Undefined crypto asset type '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The cryptographic building block of an algorithm.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_algorithmProperties_primitive)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum CryptoPrimitive {
+    Drbg,
+    Mac,
+    BlockCipher,
+    StreamCipher,
+    Signature,
+    Hash,
+    Pke,
+    Xof,
+    Kdf,
+    KeyAgree,
+    Kem,
+    Ae,
+    Combiner,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedCryptoPrimitive(String),
+}
+
+impl CryptoPrimitive {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "drbg" => Self::Drbg,
+            "mac" => Self::Mac,
+            "block-cipher" => Self::BlockCipher,
+            "stream-cipher" => Self::StreamCipher,
+            "signature" => Self::Signature,
+            "hash" => Self::Hash,
+            "pke" => Self::Pke,
+            "xof" => Self::Xof,
+            "kdf" => Self::Kdf,
+            "key-agree" => Self::KeyAgree,
+            "kem" => Self::Kem,
+            "ae" => Self::Ae,
+            "combiner" => Self::Combiner,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedCryptoPrimitive(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(
+    CryptoPrimitive,
+    UndefinedCryptoPrimitive,
+    "crypto primitive"
+);
+
+pub fn validate_crypto_primitive(value: &CryptoPrimitive) -> Result<(), ValidationError> {
+    if let CryptoPrimitive::UndefinedCryptoPrimitive(undefined) = value {
+        return Err(format!("Undefined crypto primitive '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The environment an algorithm is executed in.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_algorithmProperties_executionEnvironment)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum ExecutionEnvironment {
+    SoftwarePlainRam,
+    SoftwareEncryptedRam,
+    SoftwareTee,
+    Hardware,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedExecutionEnvironment(String),
+}
+
+impl ExecutionEnvironment {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "software-plain-ram" => Self::SoftwarePlainRam,
+            "software-encrypted-ram" => Self::SoftwareEncryptedRam,
+            "software-tee" => Self::SoftwareTee,
+            "hardware" => Self::Hardware,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedExecutionEnvironment(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(
+    ExecutionEnvironment,
+    UndefinedExecutionEnvironment,
+    "execution environment"
+);
+
+pub fn validate_execution_environment(value: &ExecutionEnvironment) -> Result<(), ValidationError> {
+    if let ExecutionEnvironment::UndefinedExecutionEnvironment(undefined) = value {
+        return Err(format!("Undefined execution environment '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The platform an algorithm is implemented for.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_algorithmProperties_implementationPlatform)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum ImplementationPlatform {
+    Generic,
+    #[strum(serialize = "x86_32")]
+    X86_32,
+    #[strum(serialize = "x86_64")]
+    X86_64,
+    #[strum(serialize = "armv7-a")]
+    Armv7A,
+    #[strum(serialize = "armv7-m")]
+    Armv7M,
+    #[strum(serialize = "armv8-a")]
+    Armv8A,
+    #[strum(serialize = "armv8-m")]
+    Armv8M,
+    #[strum(serialize = "armv9-a")]
+    Armv9A,
+    #[strum(serialize = "armv9-m")]
+    Armv9M,
+    #[strum(serialize = "s390x")]
+    S390x,
+    #[strum(serialize = "ppc64")]
+    Ppc64,
+    #[strum(serialize = "ppc64le")]
+    Ppc64le,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedImplementationPlatform(String),
+}
+
+impl ImplementationPlatform {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "generic" => Self::Generic,
+            "x86_32" => Self::X86_32,
+            "x86_64" => Self::X86_64,
+            "armv7-a" => Self::Armv7A,
+            "armv7-m" => Self::Armv7M,
+            "armv8-a" => Self::Armv8A,
+            "armv8-m" => Self::Armv8M,
+            "armv9-a" => Self::Armv9A,
+            "armv9-m" => Self::Armv9M,
+            "s390x" => Self::S390x,
+            "ppc64" => Self::Ppc64,
+            "ppc64le" => Self::Ppc64le,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedImplementationPlatform(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(
+    ImplementationPlatform,
+    UndefinedImplementationPlatform,
+    "implementation platform"
+);
+
+pub fn validate_implementation_platform(
+    value: &ImplementationPlatform,
+) -> Result<(), ValidationError> {
+    if let ImplementationPlatform::UndefinedImplementationPlatform(undefined) = value {
+        return Err(format!("Undefined implementation platform '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The certification an implementation of an algorithm has received.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_algorithmProperties_certificationLevel)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum CertificationLevel {
+    None,
+    #[strum(serialize = "fips140-1-l1")]
+    Fips140_1L1,
+    #[strum(serialize = "fips140-1-l2")]
+    Fips140_1L2,
+    #[strum(serialize = "fips140-1-l3")]
+    Fips140_1L3,
+    #[strum(serialize = "fips140-1-l4")]
+    Fips140_1L4,
+    #[strum(serialize = "fips140-2-l1")]
+    Fips140_2L1,
+    #[strum(serialize = "fips140-2-l2")]
+    Fips140_2L2,
+    #[strum(serialize = "fips140-2-l3")]
+    Fips140_2L3,
+    #[strum(serialize = "fips140-2-l4")]
+    Fips140_2L4,
+    #[strum(serialize = "fips140-3-l1")]
+    Fips140_3L1,
+    #[strum(serialize = "fips140-3-l2")]
+    Fips140_3L2,
+    #[strum(serialize = "fips140-3-l3")]
+    Fips140_3L3,
+    #[strum(serialize = "fips140-3-l4")]
+    Fips140_3L4,
+    #[strum(serialize = "cc-eal1")]
+    CcEal1,
+    #[strum(serialize = "cc-eal2")]
+    CcEal2,
+    #[strum(serialize = "cc-eal3")]
+    CcEal3,
+    #[strum(serialize = "cc-eal4")]
+    CcEal4,
+    #[strum(serialize = "cc-eal5")]
+    CcEal5,
+    #[strum(serialize = "cc-eal6")]
+    CcEal6,
+    #[strum(serialize = "cc-eal7")]
+    CcEal7,
+    #[strum(serialize = "cc-eal1+")]
+    CcEal1Plus,
+    #[strum(serialize = "cc-eal2+")]
+    CcEal2Plus,
+    #[strum(serialize = "cc-eal3+")]
+    CcEal3Plus,
+    #[strum(serialize = "cc-eal4+")]
+    CcEal4Plus,
+    #[strum(serialize = "cc-eal5+")]
+    CcEal5Plus,
+    #[strum(serialize = "cc-eal6+")]
+    CcEal6Plus,
+    #[strum(serialize = "cc-eal7+")]
+    CcEal7Plus,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedCertificationLevel(String),
+}
+
+impl CertificationLevel {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "none" => Self::None,
+            "fips140-1-l1" => Self::Fips140_1L1,
+            "fips140-1-l2" => Self::Fips140_1L2,
+            "fips140-1-l3" => Self::Fips140_1L3,
+            "fips140-1-l4" => Self::Fips140_1L4,
+            "fips140-2-l1" => Self::Fips140_2L1,
+            "fips140-2-l2" => Self::Fips140_2L2,
+            "fips140-2-l3" => Self::Fips140_2L3,
+            "fips140-2-l4" => Self::Fips140_2L4,
+            "fips140-3-l1" => Self::Fips140_3L1,
+            "fips140-3-l2" => Self::Fips140_3L2,
+            "fips140-3-l3" => Self::Fips140_3L3,
+            "fips140-3-l4" => Self::Fips140_3L4,
+            "cc-eal1" => Self::CcEal1,
+            "cc-eal2" => Self::CcEal2,
+            "cc-eal3" => Self::CcEal3,
+            "cc-eal4" => Self::CcEal4,
+            "cc-eal5" => Self::CcEal5,
+            "cc-eal6" => Self::CcEal6,
+            "cc-eal7" => Self::CcEal7,
+            "cc-eal1+" => Self::CcEal1Plus,
+            "cc-eal2+" => Self::CcEal2Plus,
+            "cc-eal3+" => Self::CcEal3Plus,
+            "cc-eal4+" => Self::CcEal4Plus,
+            "cc-eal5+" => Self::CcEal5Plus,
+            "cc-eal6+" => Self::CcEal6Plus,
+            "cc-eal7+" => Self::CcEal7Plus,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedCertificationLevel(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(
+    CertificationLevel,
+    UndefinedCertificationLevel,
+    "certification level"
+);
+
+pub fn validate_certification_level(value: &CertificationLevel) -> Result<(), ValidationError> {
+    if let CertificationLevel::UndefinedCertificationLevel(undefined) = value {
+        return Err(format!("Undefined certification level '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The mode of operation of a block cipher.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_algorithmProperties_mode)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum CryptoMode {
+    Cbc,
+    Ecb,
+    Ccm,
+    Gcm,
+    Cfb,
+    Ofb,
+    Ctr,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedCryptoMode(String),
+}
+
+impl CryptoMode {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "cbc" => Self::Cbc,
+            "ecb" => Self::Ecb,
+            "ccm" => Self::Ccm,
+            "gcm" => Self::Gcm,
+            "cfb" => Self::Cfb,
+            "ofb" => Self::Ofb,
+            "ctr" => Self::Ctr,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedCryptoMode(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(CryptoMode, UndefinedCryptoMode, "crypto mode");
+
+pub fn validate_crypto_mode(value: &CryptoMode) -> Result<(), ValidationError> {
+    if let CryptoMode::UndefinedCryptoMode(undefined) = value {
+        return Err(format!("Undefined crypto mode '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The padding scheme of an algorithm.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_algorithmProperties_padding)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum CryptoPadding {
+    #[strum(serialize = "pkcs5")]
+    Pkcs5,
+    #[strum(serialize = "pkcs7")]
+    Pkcs7,
+    #[strum(serialize = "pkcs1v15")]
+    Pkcs1v15,
+    Oaep,
+    Raw,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedCryptoPadding(String),
+}
+
+impl CryptoPadding {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "pkcs5" => Self::Pkcs5,
+            "pkcs7" => Self::Pkcs7,
+            "pkcs1v15" => Self::Pkcs1v15,
+            "oaep" => Self::Oaep,
+            "raw" => Self::Raw,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedCryptoPadding(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(CryptoPadding, UndefinedCryptoPadding, "crypto padding");
+
+pub fn validate_crypto_padding(value: &CryptoPadding) -> Result<(), ValidationError> {
+    if let CryptoPadding::UndefinedCryptoPadding(undefined) = value {
+        return Err(format!("Undefined crypto padding '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// A function an algorithm implements.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_algorithmProperties_cryptoFunctions)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum CryptoFunction {
+    Generate,
+    Keygen,
+    Encrypt,
+    Decrypt,
+    Digest,
+    Tag,
+    Keyderive,
+    Sign,
+    Verify,
+    Encapsulate,
+    Decapsulate,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedCryptoFunction(String),
+}
+
+impl CryptoFunction {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "generate" => Self::Generate,
+            "keygen" => Self::Keygen,
+            "encrypt" => Self::Encrypt,
+            "decrypt" => Self::Decrypt,
+            "digest" => Self::Digest,
+            "tag" => Self::Tag,
+            "keyderive" => Self::Keyderive,
+            "sign" => Self::Sign,
+            "verify" => Self::Verify,
+            "encapsulate" => Self::Encapsulate,
+            "decapsulate" => Self::Decapsulate,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedCryptoFunction(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(CryptoFunction, UndefinedCryptoFunction, "crypto function");
+
+pub fn validate_crypto_function(value: &CryptoFunction) -> Result<(), ValidationError> {
+    if let CryptoFunction::UndefinedCryptoFunction(undefined) = value {
+        return Err(format!("Undefined crypto function '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The type of related cryptographic material, e.g. a key or a token.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_relatedCryptoMaterialProperties_type)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum RelatedCryptoMaterialType {
+    PrivateKey,
+    PublicKey,
+    SecretKey,
+    Key,
+    Ciphertext,
+    Signature,
+    Digest,
+    InitializationVector,
+    Nonce,
+    Seed,
+    Salt,
+    SharedSecret,
+    Tag,
+    AdditionalData,
+    Password,
+    Credential,
+    Token,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedRelatedCryptoMaterialType(String),
+}
+
+impl RelatedCryptoMaterialType {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "private-key" => Self::PrivateKey,
+            "public-key" => Self::PublicKey,
+            "secret-key" => Self::SecretKey,
+            "key" => Self::Key,
+            "ciphertext" => Self::Ciphertext,
+            "signature" => Self::Signature,
+            "digest" => Self::Digest,
+            "initialization-vector" => Self::InitializationVector,
+            "nonce" => Self::Nonce,
+            "seed" => Self::Seed,
+            "salt" => Self::Salt,
+            "shared-secret" => Self::SharedSecret,
+            "tag" => Self::Tag,
+            "additional-data" => Self::AdditionalData,
+            "password" => Self::Password,
+            "credential" => Self::Credential,
+            "token" => Self::Token,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedRelatedCryptoMaterialType(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(
+    RelatedCryptoMaterialType,
+    UndefinedRelatedCryptoMaterialType,
+    "related crypto material type"
+);
+
+pub fn validate_related_crypto_material_type(
+    value: &RelatedCryptoMaterialType,
+) -> Result<(), ValidationError> {
+    if let RelatedCryptoMaterialType::UndefinedRelatedCryptoMaterialType(undefined) = value {
+        return Err(format!("Undefined related crypto material type '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The key state as defined by NIST SP 800-57.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_relatedCryptoMaterialProperties_state)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum RelatedCryptoMaterialState {
+    PreActivation,
+    Active,
+    Suspended,
+    Deactivated,
+    Compromised,
+    Destroyed,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedRelatedCryptoMaterialState(String),
+}
+
+impl RelatedCryptoMaterialState {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "pre-activation" => Self::PreActivation,
+            "active" => Self::Active,
+            "suspended" => Self::Suspended,
+            "deactivated" => Self::Deactivated,
+            "compromised" => Self::Compromised,
+            "destroyed" => Self::Destroyed,
+            undefined => Self::UndefinedRelatedCryptoMaterialState(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(
+    RelatedCryptoMaterialState,
+    UndefinedRelatedCryptoMaterialState,
+    "related crypto material state"
+);
+
+pub fn validate_related_crypto_material_state(
+    value: &RelatedCryptoMaterialState,
+) -> Result<(), ValidationError> {
+    if let RelatedCryptoMaterialState::UndefinedRelatedCryptoMaterialState(undefined) = value {
+        return Err(format!("Undefined related crypto material state '{undefined}'").into());
+    }
+    Ok(())
+}
+
+/// The type of a cryptographic protocol.
+///
+/// Defined via the [JSON schema](https://cyclonedx.org/docs/1.6/json/#components_items_cryptoProperties_protocolProperties_type)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum ProtocolType {
+    Tls,
+    Ssh,
+    Ipsec,
+    Ike,
+    Sstp,
+    Wpa,
+    Other,
+    Unknown,
+    #[doc(hidden)]
+    #[strum(default)]
+    UndefinedProtocolType(String),
+}
+
+impl ProtocolType {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "tls" => Self::Tls,
+            "ssh" => Self::Ssh,
+            "ipsec" => Self::Ipsec,
+            "ike" => Self::Ike,
+            "sstp" => Self::Sstp,
+            "wpa" => Self::Wpa,
+            "other" => Self::Other,
+            "unknown" => Self::Unknown,
+            undefined => Self::UndefinedProtocolType(undefined.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(ProtocolType, UndefinedProtocolType, "protocol type");
+
+pub fn validate_protocol_type(value: &ProtocolType) -> Result<(), ValidationError> {
+    if let ProtocolType::UndefinedProtocolType(undefined) = value {
+        return Err(format!("Undefined protocol type '{undefined}'").into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_display_the_schema_values() {
+        assert_eq!(ImplementationPlatform::X86_64.to_string(), "x86_64");
+        assert_eq!(CertificationLevel::Fips140_3L1.to_string(), "fips140-3-l1");
+        assert_eq!(CertificationLevel::CcEal4Plus.to_string(), "cc-eal4+");
+        assert_eq!(
+            CryptoAssetType::RelatedCryptoMaterial.to_string(),
+            "related-crypto-material"
+        );
+        assert_eq!(
+            CryptoPrimitive::new_unchecked("block-cipher"),
+            CryptoPrimitive::BlockCipher
+        );
+    }
+
+    #[test]
+    fn it_should_pass_validation() {
+        let crypto_properties = CryptoProperties {
+            algorithm_properties: Some(AlgorithmProperties {
+                primitive: Some(CryptoPrimitive::Ae),
+                parameter_set_identifier: Some("128".to_string()),
+                mode: Some(CryptoMode::Gcm),
+                crypto_functions: Some(vec![CryptoFunction::Encrypt, CryptoFunction::Decrypt]),
+                classical_security_level: Some(128),
+                nist_quantum_security_level: Some(1),
+                ..AlgorithmProperties::default()
+            }),
+            oid: Some("2.16.840.1.101.3.4.1.6".to_string()),
+            ..CryptoProperties::new(CryptoAssetType::Algorithm)
+        };
+
+        assert!(crypto_properties
+            .validate_version(SpecVersion::V1_6)
+            .passed());
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let crypto_properties = CryptoProperties {
+            algorithm_properties: Some(AlgorithmProperties {
+                primitive: Some(CryptoPrimitive::new_unchecked("quantum")),
+                nist_quantum_security_level: Some(7),
+                ..AlgorithmProperties::default()
+            }),
+            ..CryptoProperties::new(CryptoAssetType::new_unchecked("key"))
+        };
+
+        assert_eq!(
+            crypto_properties.validate_version(SpecVersion::V1_6),
+            vec![
+                validation::r#enum("asset_type", "Undefined crypto asset type 'key'"),
+                validation::r#struct(
+                    "algorithm_properties",
+                    vec![
+                        validation::r#enum("primitive", "Undefined crypto primitive 'quantum'"),
+                        validation::field(
+                            "nist_quantum_security_level",
+                            "NIST quantum security level must be between 0 and 6"
+                        ),
+                    ]
+                ),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn it_should_fail_validation_in_older_versions() {
+        let crypto_properties = CryptoProperties::new(CryptoAssetType::Certificate);
+
+        assert_eq!(
+            crypto_properties.validate_version(SpecVersion::V1_5),
+            validation::custom(
+                "crypto_properties",
+                ["Crypto properties are not supported in this format version"]
+            )
+        );
+    }
+}
@@ -26,7 +26,8 @@ use super::{
     organization::{OrganizationalContact, OrganizationalEntity},
 };
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataGovernance {
     pub custodians: Option<Vec<DataGovernanceResponsibleParty>>,
     pub stewards: Option<Vec<DataGovernanceResponsibleParty>>,
@@ -49,7 +50,8 @@ impl Validate for DataGovernance {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataGovernanceResponsibleParty {
     Organization(OrganizationalEntity),
     Contact(OrganizationalContact),
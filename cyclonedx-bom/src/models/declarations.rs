@@ -0,0 +1,459 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! The [declarations](https://cyclonedx.org/docs/1.6/json/#declarations) of a BOM, which
+//! describe the conformance of its subjects to standards, added in version 1.6.
+
+use crate::{
+    external_models::{
+        date_time::{validate_date_time, DateTime},
+        uri::{validate_uri, Uri},
+    },
+    models::{
+        attachment::Attachment,
+        bom::{BomReference, SpecVersion},
+        component::Components,
+        data_governance::DataGovernance,
+        external_reference::{ExternalReference, ExternalReferences},
+        organization::{OrganizationalContact, OrganizationalEntity},
+        service::Services,
+        signature::Signature,
+        vulnerability_rating::Score,
+    },
+    prelude::{Validate, ValidationResult},
+    validation::{ValidationContext, ValidationError},
+};
+
+/// The list of assessors evaluating claims and determining conformance to requirements and
+/// confidence in that assessment.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Declarations {
+    pub assessors: Option<Vec<Assessor>>,
+    pub attestations: Option<Vec<Attestation>>,
+    pub claims: Option<Vec<Claim>>,
+    pub evidence: Option<Vec<DeclarationEvidence>>,
+    pub targets: Option<Targets>,
+    pub affirmation: Option<Affirmation>,
+    pub signature: Option<Signature>,
+}
+
+impl Validate for Declarations {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        let mut context = ValidationContext::new();
+        if version < SpecVersion::V1_6 {
+            context.add_custom(
+                "declarations",
+                "Declarations are not supported in this format version",
+            );
+        }
+        context
+            .add_list_option("assessors", self.assessors.as_ref(), |assessor| {
+                assessor.validate_version(version)
+            })
+            .add_list_option("attestations", self.attestations.as_ref(), |attestation| {
+                attestation.validate_version(version)
+            })
+            .add_list_option("claims", self.claims.as_ref(), |claim| {
+                claim.validate_version(version)
+            })
+            .add_list_option("evidence", self.evidence.as_ref(), |evidence| {
+                evidence.validate_version(version)
+            })
+            .add_struct_option("targets", self.targets.as_ref(), version)
+            .add_struct_option("affirmation", self.affirmation.as_ref(), version)
+            .add_struct_option("signature", self.signature.as_ref(), version)
+            .into()
+    }
+}
+
+/// A third or second party which assesses the claims of the attestations.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Assessor {
+    pub bom_ref: Option<BomReference>,
+    pub third_party: Option<bool>,
+    pub organization: Option<OrganizationalEntity>,
+}
+
+impl Validate for Assessor {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_struct_option("organization", self.organization.as_ref(), version)
+            .into()
+    }
+}
+
+/// Maps the requirements of a standard to the claims of the declarations.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Attestation {
+    pub summary: Option<String>,
+    /// The `bom-ref` of the assessor
+    pub assessor: Option<String>,
+    pub map: Option<Vec<AttestationMap>>,
+    pub signature: Option<Signature>,
+}
+
+impl Validate for Attestation {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list_option("map", self.map.as_ref(), |map| {
+                map.validate_version(version)
+            })
+            .add_struct_option("signature", self.signature.as_ref(), version)
+            .into()
+    }
+}
+
+/// The claims and counter claims attested to a requirement of a standard.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttestationMap {
+    /// The `bom-ref` of the requirement
+    pub requirement: Option<String>,
+    /// The `bom-ref`s of the claims
+    pub claims: Option<Vec<String>>,
+    /// The `bom-ref`s of the counter claims
+    pub counter_claims: Option<Vec<String>>,
+    pub conformance: Option<Conformance>,
+    pub confidence: Option<Confidence>,
+}
+
+impl Validate for AttestationMap {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_struct_option("conformance", self.conformance.as_ref(), version)
+            .add_struct_option("confidence", self.confidence.as_ref(), version)
+            .into()
+    }
+}
+
+/// The conformance of the claims to a requirement.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Conformance {
+    /// The conformance between zero (no conformance) and one (full conformance)
+    pub score: Option<Score>,
+    pub rationale: Option<String>,
+    /// The `bom-ref`s of the mitigation strategies
+    pub mitigation_strategies: Option<Vec<String>>,
+}
+
+impl Validate for Conformance {
+    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_field_option("score", self.score.as_ref(), validate_score)
+            .into()
+    }
+}
+
+/// The confidence in the claim meeting a requirement.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Confidence {
+    /// The confidence between zero (no confidence) and one (full confidence)
+    pub score: Option<Score>,
+    pub rationale: Option<String>,
+}
+
+impl Validate for Confidence {
+    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_field_option("score", self.score.as_ref(), validate_score)
+            .into()
+    }
+}
+
+/// A statement about a target, supported by evidence.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Claim {
+    pub bom_ref: Option<BomReference>,
+    /// The `bom-ref` of the target the claim applies to
+    pub target: Option<String>,
+    pub predicate: Option<String>,
+    /// The `bom-ref`s of the mitigation strategies
+    pub mitigation_strategies: Option<Vec<String>>,
+    pub reasoning: Option<String>,
+    /// The `bom-ref`s of the evidence supporting the claim
+    pub evidence: Option<Vec<String>>,
+    /// The `bom-ref`s of the evidence refuting the claim
+    pub counter_evidence: Option<Vec<String>>,
+    pub external_references: Option<ExternalReferences>,
+    pub signature: Option<Signature>,
+}
+
+impl Validate for Claim {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_struct_option(
+                "external_references",
+                self.external_references.as_ref(),
+                version,
+            )
+            .add_struct_option("signature", self.signature.as_ref(), version)
+            .into()
+    }
+}
+
+/// The evidence a claim is based on.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeclarationEvidence {
+    pub bom_ref: Option<BomReference>,
+    pub property_name: Option<String>,
+    pub description: Option<String>,
+    pub data: Option<Vec<EvidenceData>>,
+    pub created: Option<DateTime>,
+    pub expires: Option<DateTime>,
+    pub author: Option<OrganizationalContact>,
+    pub reviewer: Option<OrganizationalContact>,
+    pub signature: Option<Signature>,
+}
+
+impl Validate for DeclarationEvidence {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list_option("data", self.data.as_ref(), |data| {
+                data.validate_version(version)
+            })
+            .add_field_option("created", self.created.as_ref(), validate_date_time)
+            .add_field_option("expires", self.expires.as_ref(), validate_date_time)
+            .add_struct_option("author", self.author.as_ref(), version)
+            .add_struct_option("reviewer", self.reviewer.as_ref(), version)
+            .add_struct_option("signature", self.signature.as_ref(), version)
+            .into()
+    }
+}
+
+/// The output or analysis that supports the evidence.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvidenceData {
+    pub name: Option<String>,
+    pub contents: Option<EvidenceContents>,
+    pub classification: Option<String>,
+    pub sensitive_data: Option<Vec<String>>,
+    pub governance: Option<DataGovernance>,
+}
+
+impl Validate for EvidenceData {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_struct_option("contents", self.contents.as_ref(), version)
+            .add_struct_option("governance", self.governance.as_ref(), version)
+            .into()
+    }
+}
+
+/// The contents of the evidence data, either inline or at an URL.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EvidenceContents {
+    pub attachment: Option<Attachment>,
+    pub url: Option<Uri>,
+}
+
+impl Validate for EvidenceContents {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_struct_option("attachment", self.attachment.as_ref(), version)
+            .add_field_option("url", self.url.as_ref(), validate_uri)
+            .into()
+    }
+}
+
+/// The organizations, components and services the claims can be made about.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Targets {
+    pub organizations: Option<Vec<OrganizationalEntity>>,
+    pub components: Option<Components>,
+    pub services: Option<Services>,
+}
+
+impl Validate for Targets {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list_option(
+                "organizations",
+                self.organizations.as_ref(),
+                |organization| organization.validate_version(version),
+            )
+            .add_struct_option("components", self.components.as_ref(), version)
+            .add_struct_option("services", self.services.as_ref(), version)
+            .into()
+    }
+}
+
+/// The affirmation of the declarations by their signatories.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affirmation {
+    pub statement: Option<String>,
+    pub signatories: Option<Vec<Signatory>>,
+    pub signature: Option<Signature>,
+}
+
+impl Validate for Affirmation {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list_option("signatories", self.signatories.as_ref(), |signatory| {
+                signatory.validate_version(version)
+            })
+            .add_struct_option("signature", self.signature.as_ref(), version)
+            .into()
+    }
+}
+
+/// A person which affirms the declarations, either with a digital signature or
+/// with a reference to an external signature of the organization.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Signatory {
+    pub name: Option<String>,
+    pub role: Option<String>,
+    pub signature: Option<Signature>,
+    pub organization: Option<OrganizationalEntity>,
+    pub external_reference: Option<ExternalReference>,
+}
+
+impl Validate for Signatory {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_struct_option("signature", self.signature.as_ref(), version)
+            .add_struct_option("organization", self.organization.as_ref(), version)
+            .add_struct_option(
+                "external_reference",
+                self.external_reference.as_ref(),
+                version,
+            )
+            .into()
+    }
+}
+
+fn validate_score(score: &Score) -> Result<(), ValidationError> {
+    if !(0.0..=1.0).contains(&score.to_f32()) {
+        return Err("Score must be between 0 and 1".into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation;
+    use pretty_assertions::assert_eq;
+
+    fn declarations(score: f32) -> Declarations {
+        Declarations {
+            assessors: Some(vec![Assessor {
+                bom_ref: Some(BomReference::new("assessor-1")),
+                third_party: Some(true),
+                organization: Some(OrganizationalEntity::new("Assessors Inc")),
+            }]),
+            attestations: Some(vec![Attestation {
+                summary: Some("Attestation summary".to_string()),
+                assessor: Some("assessor-1".to_string()),
+                map: Some(vec![AttestationMap {
+                    requirement: Some("requirement-1".to_string()),
+                    claims: Some(vec!["claim-1".to_string()]),
+                    counter_claims: None,
+                    conformance: Some(Conformance {
+                        score: Some(Score::from(score)),
+                        rationale: Some("Conformance rationale".to_string()),
+                        mitigation_strategies: None,
+                    }),
+                    confidence: None,
+                }]),
+                signature: None,
+            }]),
+            claims: Some(vec![Claim {
+                bom_ref: Some(BomReference::new("claim-1")),
+                target: Some("acme-inc".to_string()),
+                predicate: Some("The predicate".to_string()),
+                mitigation_strategies: None,
+                reasoning: None,
+                evidence: Some(vec!["evidence-1".to_string()]),
+                counter_evidence: None,
+                external_references: None,
+                signature: None,
+            }]),
+            evidence: Some(vec![DeclarationEvidence {
+                bom_ref: Some(BomReference::new("evidence-1")),
+                property_name: Some("internal.com:property".to_string()),
+                description: None,
+                data: None,
+                created: Some(DateTime("2022-01-01T00:00:00Z".to_string())),
+                expires: None,
+                author: None,
+                reviewer: None,
+                signature: None,
+            }]),
+            targets: None,
+            affirmation: None,
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = declarations(0.8).validate_version(SpecVersion::V1_6);
+
+        assert!(validation_result.passed());
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = declarations(1.5).validate_version(SpecVersion::V1_6);
+
+        assert_eq!(
+            validation_result,
+            validation::list(
+                "attestations",
+                [(
+                    0,
+                    validation::list(
+                        "map",
+                        [(
+                            0,
+                            validation::r#struct(
+                                "conformance",
+                                validation::field("score", "Score must be between 0 and 1")
+                            )
+                        )]
+                    )
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_fail_validation_in_older_versions() {
+        let validation_result = declarations(0.8).validate_version(SpecVersion::V1_5);
+
+        assert_eq!(
+            validation_result,
+            validation::custom(
+                "declarations",
+                ["Declarations are not supported in this format version"]
+            )
+        );
+    }
+}
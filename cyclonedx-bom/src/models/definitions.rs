@@ -0,0 +1,232 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! The [definitions](https://cyclonedx.org/docs/1.6/json/#definitions) of a BOM, which embed
+//! standards and their requirements so that declarations can refer to them, added in version 1.6.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    models::{
+        bom::{BomReference, SpecVersion},
+        external_reference::ExternalReferences,
+        property::Properties,
+        signature::Signature,
+    },
+    prelude::{Validate, ValidationResult},
+    validation::{ValidationContext, ValidationError},
+};
+
+/// A collection of reusable objects that are defined and may be used elsewhere in the BOM.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Definitions {
+    pub standards: Option<Vec<Standard>>,
+}
+
+impl Validate for Definitions {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        let mut context = ValidationContext::new();
+        if version < SpecVersion::V1_6 {
+            context.add_custom(
+                "definitions",
+                "Definitions are not supported in this format version",
+            );
+        }
+        context
+            .add_list_option("standards", self.standards.as_ref(), |standard| {
+                standard.validate_version(version)
+            })
+            .into()
+    }
+}
+
+/// A standard which may consist of regulations, industry or organizational-specific standards,
+/// maturity models, best practices, or any other requirements which can be evaluated against or
+/// attested to.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Standard {
+    pub bom_ref: Option<BomReference>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub owner: Option<String>,
+    pub requirements: Option<Vec<Requirement>>,
+    pub levels: Option<Vec<Level>>,
+    pub external_references: Option<ExternalReferences>,
+    pub signature: Option<Signature>,
+}
+
+impl Validate for Standard {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list_option("requirements", self.requirements.as_ref(), |requirement| {
+                requirement.validate_version(version)
+            })
+            .add_struct_option(
+                "external_references",
+                self.external_references.as_ref(),
+                version,
+            )
+            .add_struct_option("signature", self.signature.as_ref(), version)
+            .into()
+    }
+}
+
+/// A requirement of a standard, e.g. a control or a single statement of a regulation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Requirement {
+    pub bom_ref: Option<BomReference>,
+    /// The unique identifier used in the standard, e.g. `1.1.1`
+    pub identifier: Option<String>,
+    pub title: Option<String>,
+    pub text: Option<String>,
+    pub descriptions: Option<Vec<String>>,
+    /// The Common Requirements Enumeration (CRE) identifiers, e.g. `CRE:764-507`
+    pub open_cre: Option<Vec<String>>,
+    /// The `bom-ref` of the parent requirement
+    pub parent: Option<String>,
+    pub properties: Option<Properties>,
+    pub external_references: Option<ExternalReferences>,
+}
+
+impl Validate for Requirement {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list_option("open_cre", self.open_cre.as_ref(), |open_cre| {
+                validate_open_cre(open_cre)
+            })
+            .add_struct_option("properties", self.properties.as_ref(), version)
+            .add_struct_option(
+                "external_references",
+                self.external_references.as_ref(),
+                version,
+            )
+            .into()
+    }
+}
+
+/// A level of a standard, e.g. a maturity level, which groups a subset of its requirements.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Level {
+    pub bom_ref: Option<BomReference>,
+    pub identifier: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// The `bom-ref`s of the requirements of the level
+    pub requirements: Option<Vec<String>>,
+}
+
+/// Checks if the given Common Requirements Enumeration identifier is valid.
+fn validate_open_cre(open_cre: &str) -> Result<(), ValidationError> {
+    static OPEN_CRE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^CRE:[0-9]+-[0-9]+$").expect("Failed to compile regex."));
+
+    if !OPEN_CRE_REGEX.is_match(open_cre) {
+        return Err(ValidationError::new(
+            "OpenCRE identifier does not match regular expression",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation;
+    use pretty_assertions::assert_eq;
+
+    fn definitions(open_cre: &str) -> Definitions {
+        Definitions {
+            standards: Some(vec![Standard {
+                bom_ref: Some(BomReference::new("standard-1")),
+                name: Some("Application Security Verification Standard".to_string()),
+                version: Some("4.0.3".to_string()),
+                requirements: Some(vec![Requirement {
+                    bom_ref: Some(BomReference::new("requirement-1")),
+                    identifier: Some("V1.1.1".to_string()),
+                    open_cre: Some(vec![open_cre.to_string()]),
+                    ..Requirement::default()
+                }]),
+                levels: Some(vec![Level {
+                    bom_ref: Some(BomReference::new("level-1")),
+                    identifier: Some("L1".to_string()),
+                    requirements: Some(vec!["requirement-1".to_string()]),
+                    ..Level::default()
+                }]),
+                ..Standard::default()
+            }]),
+        }
+    }
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = definitions("CRE:764-507").validate_version(SpecVersion::V1_6);
+
+        assert!(validation_result.passed());
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = definitions("764-507").validate_version(SpecVersion::V1_6);
+
+        assert_eq!(
+            validation_result,
+            validation::list(
+                "standards",
+                [(
+                    0,
+                    validation::list(
+                        "requirements",
+                        [(
+                            0,
+                            validation::list(
+                                "open_cre",
+                                [(
+                                    0,
+                                    validation::custom(
+                                        "",
+                                        ["OpenCRE identifier does not match regular expression"]
+                                    )
+                                )]
+                            )
+                        )]
+                    )
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_fail_validation_in_older_versions() {
+        let validation_result = definitions("CRE:764-507").validate_version(SpecVersion::V1_5);
+
+        assert_eq!(
+            validation_result,
+            validation::custom(
+                "definitions",
+                ["Definitions are not supported in this format version"]
+            )
+        );
+    }
+}
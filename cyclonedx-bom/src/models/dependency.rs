@@ -16,11 +16,369 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use indexmap::{IndexMap, IndexSet};
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dependencies(pub Vec<Dependency>);
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dependency {
     pub dependency_ref: String,
     pub dependencies: Vec<String>,
 }
+
+/// A graph view over [`Dependencies`], backed by adjacency maps in both directions.
+///
+/// Every bom-ref that appears in the dependencies, either as the `ref` of a dependency or as one
+/// of its `dependsOn` entries, is a node of the graph. Nodes and edges keep their document order.
+///
+/// ```
+/// use cyclonedx_bom::models::dependency::{Dependencies, Dependency, DependencyGraph};
+///
+/// let dependencies = Dependencies(vec![
+///     Dependency {
+///         dependency_ref: "app".to_string(),
+///         dependencies: vec!["lib".to_string()],
+///     },
+///     Dependency {
+///         dependency_ref: "lib".to_string(),
+///         dependencies: vec!["core".to_string()],
+///     },
+/// ]);
+/// let graph = DependencyGraph::new(&dependencies);
+///
+/// assert_eq!(graph.roots(), vec!["app"]);
+/// assert_eq!(graph.transitive_closure("app"), vec!["lib", "core"]);
+/// assert_eq!(graph.reverse_dependencies("core"), &["lib"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DependencyGraph<'a> {
+    dependencies: IndexMap<&'a str, Vec<&'a str>>,
+    dependents: IndexMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> DependencyGraph<'a> {
+    pub fn new(dependencies: &'a Dependencies) -> Self {
+        let mut graph = Self::default();
+        for dependency in &dependencies.0 {
+            let from = dependency.dependency_ref.as_str();
+            graph.add_node(from);
+            for to in &dependency.dependencies {
+                let to = to.as_str();
+                graph.add_node(to);
+                if !graph.dependencies[from].contains(&to) {
+                    graph.dependencies[from].push(to);
+                    graph.dependents[to].push(from);
+                }
+            }
+        }
+        graph
+    }
+
+    fn add_node(&mut self, node: &'a str) {
+        self.dependencies.entry(node).or_default();
+        self.dependents.entry(node).or_default();
+    }
+
+    /// Returns all nodes of the graph.
+    pub fn nodes(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.dependencies.keys().copied()
+    }
+
+    /// Returns `true` if the bom-ref is a node of the graph.
+    pub fn contains(&self, bom_ref: &str) -> bool {
+        self.dependencies.contains_key(bom_ref)
+    }
+
+    /// Returns the bom-refs that the given bom-ref directly depends on.
+    pub fn direct_dependencies(&self, bom_ref: &str) -> &[&'a str] {
+        self.dependencies
+            .get(bom_ref)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the bom-refs that directly depend on the given bom-ref.
+    pub fn reverse_dependencies(&self, bom_ref: &str) -> &[&'a str] {
+        self.dependents
+            .get(bom_ref)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns all bom-refs that the given bom-ref depends on, directly or indirectly, in
+    /// breadth-first order.
+    ///
+    /// The bom-ref itself is only part of the result if it is part of a cycle.
+    pub fn transitive_closure(&self, bom_ref: &str) -> Vec<&'a str> {
+        let mut visited = IndexSet::new();
+        let mut queue = VecDeque::from_iter(self.direct_dependencies(bom_ref).iter().copied());
+        while let Some(node) = queue.pop_front() {
+            if visited.insert(node) {
+                queue.extend(self.direct_dependencies(node));
+            }
+        }
+        visited.into_iter().collect()
+    }
+
+    /// Returns the bom-refs that no other bom-ref depends on.
+    pub fn roots(&self) -> Vec<&'a str> {
+        self.dependents
+            .iter()
+            .filter(|(_, dependents)| dependents.is_empty())
+            .map(|(node, _)| *node)
+            .collect()
+    }
+
+    /// Returns `true` if the graph contains at least one cycle.
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Returns the first cycle found in the graph.
+    ///
+    /// The cycle is returned as the list of bom-refs along the path, e.g. `["a", "b"]` for
+    /// `a -> b -> a`. A bom-ref that depends on itself is returned as a cycle of one element.
+    pub fn find_cycle(&self) -> Option<Vec<&'a str>> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum State {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+
+        let mut states = vec![State::Unvisited; self.dependencies.len()];
+
+        for start in 0..self.dependencies.len() {
+            if states[start] != State::Unvisited {
+                continue;
+            }
+
+            // An iterative depth-first search, so that deep graphs don't overflow the stack.
+            let mut path: Vec<(usize, usize)> = vec![(start, 0)];
+            states[start] = State::InProgress;
+
+            while let Some((node, next_child)) = path.last_mut() {
+                let children = &self.dependencies[*node];
+                let Some(child) = children.get(*next_child) else {
+                    states[*node] = State::Done;
+                    path.pop();
+                    continue;
+                };
+                *next_child += 1;
+
+                let child = self
+                    .dependencies
+                    .get_index_of(child)
+                    .expect("every dependency is a node");
+                match states[child] {
+                    State::Unvisited => {
+                        states[child] = State::InProgress;
+                        path.push((child, 0));
+                    }
+                    State::InProgress => {
+                        let cycle_start = path
+                            .iter()
+                            .position(|(node, _)| *node == child)
+                            .expect("nodes in progress are on the path");
+                        return Some(
+                            path[cycle_start..]
+                                .iter()
+                                .map(|(node, _)| {
+                                    *self.dependencies.get_index(*node).expect("valid index").0
+                                })
+                                .collect(),
+                        );
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Renders the graph in the DOT language of GraphViz, labelling the nodes with their bom-refs.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::dependency::{Dependencies, Dependency, DependencyGraph};
+    ///
+    /// let dependencies = Dependencies(vec![Dependency {
+    ///     dependency_ref: "app".to_string(),
+    ///     dependencies: vec!["lib".to_string()],
+    /// }]);
+    /// let graph = DependencyGraph::new(&dependencies);
+    ///
+    /// assert_eq!(
+    ///     graph.to_dot(),
+    ///     "digraph dependencies {\n  \"app\";\n  \"lib\";\n  \"app\" -> \"lib\";\n}\n"
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with_labels(|_| None)
+    }
+
+    /// Renders the graph in the DOT language of GraphViz, labelling the nodes with the labels
+    /// returned by the given function, e.g. the names of the components, and with their bom-refs
+    /// where it returns `None`.
+    pub fn to_dot_with_labels<F>(&self, labels: F) -> String
+    where
+        F: Fn(&'a str) -> Option<String>,
+    {
+        fn quote(id: &str) -> String {
+            format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+
+        let mut output = String::from("digraph dependencies {\n");
+        for node in self.nodes() {
+            match labels(node) {
+                Some(label) => {
+                    let _ = writeln!(output, "  {} [label={}];", quote(node), quote(&label));
+                }
+                None => {
+                    let _ = writeln!(output, "  {};", quote(node));
+                }
+            }
+        }
+        for (from, dependencies) in &self.dependencies {
+            for to in dependencies {
+                let _ = writeln!(output, "  {} -> {};", quote(from), quote(to));
+            }
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    /// Renders the graph as a mermaid flowchart, labelling the nodes with their bom-refs.
+    ///
+    /// bom-refs are not valid mermaid node ids, so the nodes are numbered in document order.
+    pub fn to_mermaid(&self) -> String {
+        self.to_mermaid_with_labels(|_| None)
+    }
+
+    /// Renders the graph as a mermaid flowchart, labelling the nodes with the labels returned by
+    /// the given function, and with their bom-refs where it returns `None`.
+    pub fn to_mermaid_with_labels<F>(&self, labels: F) -> String
+    where
+        F: Fn(&'a str) -> Option<String>,
+    {
+        let mut output = String::from("flowchart TD\n");
+        for (index, node) in self.nodes().enumerate() {
+            let label = labels(node).unwrap_or_else(|| node.to_string());
+            let _ = writeln!(output, "  n{index}[\"{}\"]", label.replace('"', "#quot;"));
+        }
+        for (from, dependencies) in self.dependencies.values().enumerate() {
+            for to in dependencies {
+                let to = self
+                    .dependencies
+                    .get_index_of(to)
+                    .expect("every dependency is a node");
+                let _ = writeln!(output, "  n{from} --> n{to}");
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn create_dependencies(edges: &[(&str, &[&str])]) -> Dependencies {
+        Dependencies(
+            edges
+                .iter()
+                .map(|(from, to)| Dependency {
+                    dependency_ref: from.to_string(),
+                    dependencies: to.iter().map(|to| to.to_string()).collect(),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn it_should_traverse_the_dependency_graph() {
+        let dependencies = create_dependencies(&[
+            ("app", &["cli", "log"]),
+            ("cli", &["log", "util"]),
+            ("util", &[]),
+            ("tests", &["util"]),
+        ]);
+        let graph = DependencyGraph::new(&dependencies);
+
+        assert_eq!(
+            graph.nodes().collect::<Vec<_>>(),
+            vec!["app", "cli", "log", "util", "tests"]
+        );
+        assert_eq!(graph.direct_dependencies("app"), &["cli", "log"]);
+        assert_eq!(graph.direct_dependencies("log"), &[] as &[&str]);
+        assert_eq!(graph.direct_dependencies("missing"), &[] as &[&str]);
+        assert_eq!(graph.transitive_closure("app"), vec!["cli", "log", "util"]);
+        assert_eq!(graph.reverse_dependencies("util"), &["cli", "tests"]);
+        assert_eq!(graph.roots(), vec!["app", "tests"]);
+        assert!(graph.contains("log"));
+        assert!(!graph.contains("missing"));
+        assert_eq!(graph.find_cycle(), None);
+    }
+
+    #[test]
+    fn it_should_merge_duplicate_dependency_entries() {
+        let dependencies = create_dependencies(&[("a", &["b"]), ("a", &["b", "c"])]);
+        let graph = DependencyGraph::new(&dependencies);
+
+        assert_eq!(graph.direct_dependencies("a"), &["b", "c"]);
+        assert_eq!(graph.reverse_dependencies("b"), &["a"]);
+    }
+
+    #[test]
+    fn it_should_detect_cycles() {
+        let dependencies = create_dependencies(&[
+            ("root", &["a"]),
+            ("a", &["b"]),
+            ("b", &["c"]),
+            ("c", &["a"]),
+        ]);
+        let graph = DependencyGraph::new(&dependencies);
+
+        assert!(graph.has_cycle());
+        assert_eq!(graph.find_cycle(), Some(vec!["a", "b", "c"]));
+        assert_eq!(graph.transitive_closure("a"), vec!["b", "c", "a"]);
+        assert_eq!(graph.roots(), vec!["root"]);
+
+        let self_dependency = create_dependencies(&[("self", &["self"])]);
+        let graph = DependencyGraph::new(&self_dependency);
+        assert_eq!(graph.find_cycle(), Some(vec!["self"]));
+    }
+
+    #[test]
+    fn it_should_render_the_graph_as_dot() {
+        let dependencies = create_dependencies(&[("app", &["lib", "log"]), ("lib", &["log"])]);
+        let graph = DependencyGraph::new(&dependencies);
+
+        insta::assert_snapshot!(graph.to_dot());
+
+        let labelled = graph.to_dot_with_labels(|bom_ref| {
+            (bom_ref == "lib").then(|| "lib \"core\" 1.0\\".to_string())
+        });
+        assert!(labelled.contains(r#"  "lib" [label="lib \"core\" 1.0\\"];"#));
+        assert!(labelled.contains("  \"app\";\n"));
+    }
+
+    #[test]
+    fn it_should_render_the_graph_as_mermaid() {
+        let dependencies =
+            create_dependencies(&[("app", &["lib", "log"]), ("lib", &["log"]), ("tests", &[])]);
+        let graph = DependencyGraph::new(&dependencies);
+
+        insta::assert_snapshot!(graph.to_mermaid());
+
+        let labelled = graph.to_mermaid_with_labels(|bom_ref| Some(format!("\"{bom_ref}\"")));
+        assert!(labelled.contains("  n0[\"#quot;app#quot;\"]\n"));
+    }
+}
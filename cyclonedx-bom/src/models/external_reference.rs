@@ -16,19 +16,20 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use once_cell::sync::Lazy;
-use regex::Regex;
-
 use crate::external_models::uri::{validate_uri as validate_url, Uri as Url};
+use crate::models::bom_link::validate_bom_link;
 use crate::models::hash::Hashes;
 use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
 
 use super::bom::SpecVersion;
 
+pub use crate::models::bom_link::BomLink;
+
 /// Represents a way to document systems, sites, and information that may be relevant but which are not included with the BOM.
 ///
 /// Please see the [CycloneDX use case](https://cyclonedx.org/use-cases/#external-references) for more information and examples.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExternalReference {
     pub external_reference_type: ExternalReferenceType,
     pub url: Uri,
@@ -61,9 +62,11 @@ impl Validate for ExternalReference {
             .add_field(
                 "external_reference_type",
                 &self.external_reference_type,
-                validate_external_reference_type,
+                |reference_type| validate_external_reference_type(reference_type, version),
             )
-            .add_field("url", &self.url, |uri| validate_reference_uri(uri, version))
+            .add_field("url", &self.url, |uri| {
+                validate_reference_uri(uri, &self.external_reference_type, version)
+            })
             .add_list("hashes", &self.hashes, |hash| {
                 hash.validate_version(version)
             })
@@ -71,9 +74,27 @@ impl Validate for ExternalReference {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExternalReferences(pub Vec<ExternalReference>);
 
+impl ExternalReferences {
+    /// Removes identical external references and sorts the remaining ones.
+    ///
+    /// The references are ordered by their type, following the order of the types in the
+    /// specification, and then by their url. The first occurrence of a duplicate is kept.
+    pub fn normalize(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        self.0.retain(|reference| seen.insert(reference.clone()));
+        self.0.sort_by(|a, b| {
+            a.external_reference_type
+                .spec_order()
+                .cmp(&b.external_reference_type.spec_order())
+                .then_with(|| a.url.to_string().cmp(&b.url.to_string()))
+        });
+    }
+}
+
 impl Validate for ExternalReferences {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -84,20 +105,21 @@ impl Validate for ExternalReferences {
     }
 }
 
+/// Validates an [`ExternalReferenceType`], reference types are only known from the version
+/// of the specification that introduced them.
 pub fn validate_external_reference_type(
     reference_type: &ExternalReferenceType,
+    version: SpecVersion,
 ) -> Result<(), ValidationError> {
-    if matches!(
-        reference_type,
-        ExternalReferenceType::UnknownExternalReferenceType(_)
-    ) {
-        return Err("Unknown external reference type".into());
+    match reference_type.introduced_in() {
+        Some(introduced_in) if introduced_in <= version => Ok(()),
+        _ => Err("Unknown external reference type".into()),
     }
-    Ok(())
 }
 
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_externalReferenceType).
-#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum ExternalReferenceType {
     Vcs,
@@ -139,12 +161,118 @@ pub enum ExternalReferenceType {
     ComponentAnalysisReport,
     MaturityReport,
     CertificationReport,
+    #[strum(serialize = "codified-infrastructure")]
     CondifiedInfrastructure,
     QualityMetrics,
     Poam,
+    SourceDistribution,
+    ElectronicSignature,
+    DigitalSignature,
+    #[strum(serialize = "rfc-9116")]
+    Rfc9116,
 }
 
 impl ExternalReferenceType {
+    /// The position of the type in the specification, unknown types are sorted last.
+    fn spec_order(&self) -> usize {
+        match self {
+            Self::Vcs => 0,
+            Self::IssueTracker => 1,
+            Self::Website => 2,
+            Self::Advisories => 3,
+            Self::Bom => 4,
+            Self::MailingList => 5,
+            Self::Social => 6,
+            Self::Chat => 7,
+            Self::Documentation => 8,
+            Self::Support => 9,
+            Self::SourceDistribution => 10,
+            Self::Distribution => 11,
+            Self::DistributionIntake => 12,
+            Self::License => 13,
+            Self::BuildMeta => 14,
+            Self::BuildSystem => 15,
+            Self::ReleaseNotes => 16,
+            Self::SecurityContact => 17,
+            Self::ModelCard => 18,
+            Self::Log => 19,
+            Self::Configuration => 20,
+            Self::Evidence => 21,
+            Self::Formulation => 22,
+            Self::Attestation => 23,
+            Self::ThreatModel => 24,
+            Self::AdversaryModel => 25,
+            Self::RiskAssessment => 26,
+            Self::VulnerabilityAssertion => 27,
+            Self::ExploitabilityStatement => 28,
+            Self::PentestReport => 29,
+            Self::StaticAnalysisReport => 30,
+            Self::DynamicAnalysisReport => 31,
+            Self::RuntimeAnalysisReport => 32,
+            Self::ComponentAnalysisReport => 33,
+            Self::MaturityReport => 34,
+            Self::CertificationReport => 35,
+            Self::CondifiedInfrastructure => 36,
+            Self::QualityMetrics => 37,
+            Self::Poam => 38,
+            Self::ElectronicSignature => 39,
+            Self::DigitalSignature => 40,
+            Self::Rfc9116 => 41,
+            Self::Other => 42,
+            Self::UnknownExternalReferenceType(_) => 43,
+        }
+    }
+
+    /// The version of the specification that introduced the type, `None` for unknown types.
+    fn introduced_in(&self) -> Option<SpecVersion> {
+        match self {
+            Self::Vcs
+            | Self::IssueTracker
+            | Self::Website
+            | Self::Advisories
+            | Self::Bom
+            | Self::MailingList
+            | Self::Social
+            | Self::Chat
+            | Self::Documentation
+            | Self::Support
+            | Self::Distribution
+            | Self::License
+            | Self::BuildMeta
+            | Self::BuildSystem
+            | Self::Other => Some(SpecVersion::V1_3),
+            Self::ReleaseNotes => Some(SpecVersion::V1_4),
+            Self::DistributionIntake
+            | Self::SecurityContact
+            | Self::ModelCard
+            | Self::Log
+            | Self::Configuration
+            | Self::Evidence
+            | Self::Formulation
+            | Self::Attestation
+            | Self::ThreatModel
+            | Self::AdversaryModel
+            | Self::RiskAssessment
+            | Self::VulnerabilityAssertion
+            | Self::ExploitabilityStatement
+            | Self::PentestReport
+            | Self::StaticAnalysisReport
+            | Self::DynamicAnalysisReport
+            | Self::RuntimeAnalysisReport
+            | Self::ComponentAnalysisReport
+            | Self::MaturityReport
+            | Self::CertificationReport
+            | Self::CondifiedInfrastructure
+            | Self::QualityMetrics
+            | Self::Poam => Some(SpecVersion::V1_5),
+            Self::SourceDistribution
+            | Self::ElectronicSignature
+            | Self::DigitalSignature
+            | Self::Rfc9116 => Some(SpecVersion::V1_6),
+            Self::UnknownExternalReferenceType(_) => None,
+        }
+    }
+
     pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
         match value.as_ref() {
             "vcs" => Self::Vcs,
@@ -157,6 +285,7 @@ impl ExternalReferenceType {
             "chat" => Self::Chat,
             "documentation" => Self::Documentation,
             "support" => Self::Support,
+            "source-distribution" => Self::SourceDistribution,
             "distribution" => Self::Distribution,
             "distribution-intake" => Self::DistributionIntake,
             "license" => Self::License,
@@ -185,21 +314,41 @@ impl ExternalReferenceType {
             "codified-infrastructure" => Self::CondifiedInfrastructure,
             "quality-metrics" => Self::QualityMetrics,
             "poam" => Self::Poam,
+            "electronic-signature" => Self::ElectronicSignature,
+            "digital-signature" => Self::DigitalSignature,
+            "rfc-9116" => Self::Rfc9116,
             "other" => Self::Other,
             unknown => Self::UnknownExternalReferenceType(unknown.to_string()),
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+from_str_via_new_unchecked!(
+    ExternalReferenceType,
+    UnknownExternalReferenceType,
+    "external reference type"
+);
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Uri {
     Url(Url),
     BomLink(BomLink),
 }
 
 /// Validates an [`Uri`], the [`Uri::BomLink`] variant was added in 1.5 only.
-fn validate_reference_uri(uri: &Uri, version: SpecVersion) -> Result<(), ValidationError> {
+///
+/// A reference of type `bom` whose url looks like a BOM-Link is validated as BOM-Link, even if it
+/// was not constructed as [`Uri::BomLink`].
+fn validate_reference_uri(
+    uri: &Uri,
+    reference_type: &ExternalReferenceType,
+    version: SpecVersion,
+) -> Result<(), ValidationError> {
     match uri {
+        Uri::Url(url) if *reference_type == ExternalReferenceType::Bom && url.is_bomlink() => {
+            validate_bom_link(&BomLink(url.to_string()), version)
+        }
         Uri::Url(url) => validate_url(url),
         Uri::BomLink(bom_link) => validate_bom_link(bom_link, version),
     }
@@ -225,25 +374,6 @@ impl std::fmt::Display for Uri {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct BomLink(pub String);
-
-fn validate_bom_link(bom_link: &BomLink, version: SpecVersion) -> Result<(), ValidationError> {
-    if version < SpecVersion::V1_5 {
-        return Err("BOM-Link not supported before version 1.5".into());
-    }
-
-    static BOM_LINK_REGEX: Lazy<Regex> = Lazy::new(|| {
-        Regex::new(r"^urn:cdx:[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}/[1-9][0-9]*(#.+)?$").unwrap()
-    });
-
-    if !BOM_LINK_REGEX.is_match(&bom_link.0) {
-        return Err(ValidationError::new("Invalid BOM-Link"));
-    }
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod test {
     use crate::{
@@ -295,6 +425,99 @@ mod test {
             .has_errors());
     }
 
+    #[test]
+    fn it_should_validate_bom_references_with_bomlink_urls() {
+        let external_reference = ExternalReference::new(
+            ExternalReferenceType::Bom,
+            Uri::Url(Url("urn:cdx:not-a-serial-number/1".to_string())),
+        );
+
+        assert_eq!(
+            external_reference.validate_version(SpecVersion::V1_5),
+            validation::field("url", "Invalid BOM-Link")
+        );
+    }
+
+    #[test]
+    fn it_should_validate_external_reference_types_by_version() {
+        let release_notes = ExternalReference::new(
+            ExternalReferenceType::ReleaseNotes,
+            Url("https://example.com/CHANGELOG.md".to_string()),
+        );
+        let model_card = ExternalReference::new(
+            ExternalReferenceType::new_unchecked("model-card"),
+            Url("https://example.com/model-card".to_string()),
+        );
+        let electronic_signature = ExternalReference::new(
+            ExternalReferenceType::new_unchecked("electronic-signature"),
+            Url("https://example.com/signature".to_string()),
+        );
+        let unknown = ExternalReference::new(
+            ExternalReferenceType::new_unchecked("unknown"),
+            Url("https://example.com".to_string()),
+        );
+
+        assert!(release_notes.validate_version(SpecVersion::V1_4).passed());
+        assert_eq!(
+            release_notes.validate_version(SpecVersion::V1_3),
+            validation::field("external_reference_type", "Unknown external reference type")
+        );
+        assert!(model_card.validate_version(SpecVersion::V1_5).passed());
+        assert_eq!(
+            model_card.validate_version(SpecVersion::V1_4),
+            validation::field("external_reference_type", "Unknown external reference type")
+        );
+        assert!(electronic_signature
+            .validate_version(SpecVersion::V1_6)
+            .passed());
+        assert_eq!(
+            electronic_signature.validate_version(SpecVersion::V1_5),
+            validation::field("external_reference_type", "Unknown external reference type")
+        );
+        assert_eq!(
+            unknown.validate_version(SpecVersion::V1_5),
+            validation::field("external_reference_type", "Unknown external reference type")
+        );
+    }
+
+    #[test]
+    fn it_should_normalize_external_references() {
+        let website = ExternalReference::new(
+            ExternalReferenceType::Website,
+            Url("https://example.com".to_string()),
+        );
+        let vcs_b = ExternalReference::new(
+            ExternalReferenceType::Vcs,
+            Url("https://example.com/b.git".to_string()),
+        );
+        let vcs_a = ExternalReference::new(
+            ExternalReferenceType::Vcs,
+            Url("https://example.com/a.git".to_string()),
+        );
+        let mut commented_website = website.clone();
+        commented_website.comment = Some("Comment".to_string());
+        let unknown = ExternalReference::new(
+            ExternalReferenceType::new_unchecked("unknown"),
+            Url("https://example.com".to_string()),
+        );
+
+        let mut references = ExternalReferences(vec![
+            unknown.clone(),
+            website.clone(),
+            vcs_b.clone(),
+            commented_website.clone(),
+            website.clone(),
+            vcs_a.clone(),
+            vcs_b.clone(),
+        ]);
+        references.normalize();
+
+        assert_eq!(
+            references,
+            ExternalReferences(vec![vcs_a, vcs_b, website, commented_website, unknown])
+        );
+    }
+
     #[test]
     fn it_should_pass_validation() {
         let validation_result = ExternalReferences(vec![
@@ -415,4 +638,27 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn it_should_parse_external_reference_types_from_their_display_form() {
+        use std::str::FromStr;
+
+        for reference_type in [
+            ExternalReferenceType::Vcs,
+            ExternalReferenceType::BuildMeta,
+            ExternalReferenceType::CondifiedInfrastructure,
+            ExternalReferenceType::Poam,
+            ExternalReferenceType::Rfc9116,
+        ] {
+            assert_eq!(
+                ExternalReferenceType::from_str(&reference_type.to_string()),
+                Ok(reference_type)
+            );
+        }
+        assert_eq!(
+            ExternalReferenceType::CondifiedInfrastructure.to_string(),
+            "codified-infrastructure"
+        );
+        assert!(ExternalReferenceType::from_str("git").is_err());
+    }
 }
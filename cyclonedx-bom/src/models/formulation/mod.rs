@@ -9,7 +9,8 @@ use self::workflow::Workflow;
 
 use super::{bom::BomReference, component::Components, property::Properties, service::Services};
 
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Formula {
     pub bom_ref: Option<BomReference>,
     pub components: Option<Components>,
@@ -25,7 +26,7 @@ impl Validate for Formula {
                 "Formula is not defined for version {version}"
             )))
             .into(),
-            SpecVersion::V1_5 => ValidationContext::new()
+            SpecVersion::V1_5 | SpecVersion::V1_6 => ValidationContext::new()
                 .add_unique_list_option(
                     "components", // components is uniqueItems: true
                     self.components.as_ref().map(|wrapper| wrapper.0.iter()),
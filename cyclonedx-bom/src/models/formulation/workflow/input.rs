@@ -6,7 +6,8 @@ use crate::{
 
 use super::{resource_reference::ResourceReference, EnvironmentVar};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Input {
     pub required: RequiredInputField,
     pub source: Option<ResourceReference>,
@@ -38,7 +39,8 @@ impl Validate for Input {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RequiredInputField {
     Resource(ResourceReference),
     Parameters(Vec<Parameter>),
@@ -46,7 +48,8 @@ pub enum RequiredInputField {
     Data(Attachment),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Parameter {
     pub name: Option<String>,
     pub value: Option<String>,
@@ -17,7 +17,8 @@ use self::{
     trigger::Trigger, workspace::Workspace,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Workflow {
     pub bom_ref: BomReference,
     pub uid: String,
@@ -80,7 +81,8 @@ impl Validate for Workflow {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Task {
     pub bom_ref: BomReference,
     pub uid: String,
@@ -135,7 +137,8 @@ impl Validate for Task {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, strum::Display)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum TaskType {
     Copy,
@@ -174,6 +177,8 @@ impl TaskType {
     }
 }
 
+from_str_via_new_unchecked!(TaskType, Unknown, "task type");
+
 impl Validate for TaskType {
     fn validate_version(
         &self,
@@ -187,7 +192,8 @@ impl Validate for TaskType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnvironmentVar {
     Property { name: String, value: String },
     Value(String),
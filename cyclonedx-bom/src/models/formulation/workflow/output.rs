@@ -6,7 +6,8 @@ use crate::{
 
 use super::{resource_reference::ResourceReference, EnvironmentVar};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Output {
     pub required: RequiredOutputField,
     pub r#type: Option<Type>,
@@ -40,14 +41,16 @@ impl Validate for Output {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RequiredOutputField {
     Resource(ResourceReference),
     EnvironmentVars(Vec<EnvironmentVar>),
     Data(Attachment),
 }
 
-#[derive(Debug, Clone, strum::Display, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, strum::Display, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Type {
     Artifact,
@@ -75,6 +78,8 @@ impl Type {
     }
 }
 
+from_str_via_new_unchecked!(Type, Unknown, "output type");
+
 impl Validate for Type {
     fn validate_version(
         &self,
@@ -2,7 +2,8 @@ use crate::{
     models::external_reference::ExternalReference, prelude::Validate, validation::ValidationContext,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ResourceReference {
     Ref(String),
     ExternalReference(ExternalReference),
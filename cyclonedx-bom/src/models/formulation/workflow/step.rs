@@ -1,6 +1,7 @@
 use crate::{models::property::Properties, prelude::Validate, validation::ValidationContext};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Step {
     pub commands: Option<Vec<Command>>,
     pub description: Option<String>,
@@ -22,7 +23,8 @@ impl Validate for Step {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Command {
     pub executed: Option<String>,
     pub properties: Option<Properties>,
@@ -11,7 +11,8 @@ use crate::{
 
 use super::{input::Input, output::Output, resource_reference::ResourceReference};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Trigger {
     pub bom_ref: BomReference,
     pub uid: String,
@@ -62,7 +63,8 @@ impl Validate for Trigger {
     }
 }
 
-#[derive(Debug, Clone, strum::Display, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, strum::Display, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Type {
     Manual,
@@ -86,6 +88,8 @@ impl Type {
     }
 }
 
+from_str_via_new_unchecked!(Type, UnknownType, "trigger type");
+
 impl Validate for Type {
     fn validate_version(
         &self,
@@ -99,7 +103,8 @@ impl Validate for Type {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Event {
     pub uid: Option<String>,
     pub description: Option<String>,
@@ -129,7 +134,8 @@ impl Validate for Event {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Condition {
     pub description: Option<String>,
     pub expression: Option<String>,
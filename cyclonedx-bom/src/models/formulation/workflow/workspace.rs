@@ -6,7 +6,8 @@ use crate::{
 
 use super::resource_reference::ResourceReference;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Workspace {
     pub bom_ref: BomReference,
     pub uid: String,
@@ -39,7 +40,8 @@ impl Validate for Workspace {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, strum::Display)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum AccessMode {
     ReadOnly,
@@ -65,6 +67,8 @@ impl AccessMode {
     }
 }
 
+from_str_via_new_unchecked!(AccessMode, UnknownAccessMode, "access mode");
+
 pub fn validate_access_mode(access_mode: &AccessMode) -> Result<(), ValidationError> {
     match access_mode {
         AccessMode::UnknownAccessMode(_) => Err(ValidationError::new("Unknown access mode")),
@@ -72,7 +76,8 @@ pub fn validate_access_mode(access_mode: &AccessMode) -> Result<(), ValidationEr
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Volume {
     pub uid: Option<String>,
     pub name: Option<String>,
@@ -92,7 +97,8 @@ impl Validate for Volume {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, strum::Display)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Mode {
     #[default]
@@ -113,6 +119,8 @@ impl Mode {
     }
 }
 
+from_str_via_new_unchecked!(Mode, UnknownMode, "volume mode");
+
 pub fn validate_mode(mode: &Mode) -> Result<(), ValidationError> {
     match mode {
         Mode::UnknownMode(_) => Err(ValidationError::new("Unknown mode")),
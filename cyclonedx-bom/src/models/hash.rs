@@ -26,7 +26,8 @@ use super::bom::SpecVersion;
 /// Represents the hash of the component
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashType)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hash {
     pub alg: HashAlgorithm,
     pub content: HashValue,
@@ -41,7 +42,8 @@ impl Validate for Hash {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hashes(pub Vec<Hash>);
 
 impl Validate for Hashes {
@@ -63,7 +65,8 @@ pub fn validate_hash_algorithm(algorithm: &HashAlgorithm) -> Result<(), Validati
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashAlg)
 #[allow(non_camel_case_types)]
-#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "SCREAMING-KEBAB-CASE")]
 pub enum HashAlgorithm {
     MD5,
@@ -106,6 +109,8 @@ impl HashAlgorithm {
     }
 }
 
+from_str_via_new_unchecked!(HashAlgorithm, UnknownHashAlgorithm, "hash algorithm");
+
 pub fn validate_hash_value(value: &HashValue) -> Result<(), ValidationError> {
     static HASH_VALUE_REGEX: Lazy<Regex> = Lazy::new(|| {
         Regex::new(
@@ -123,7 +128,8 @@ pub fn validate_hash_value(value: &HashValue) -> Result<(), ValidationError> {
 }
 
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashValue)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HashValue(pub String);
 
 #[cfg(test)]
@@ -166,4 +172,24 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn it_should_parse_hash_algorithms_from_their_display_form() {
+        use std::str::FromStr;
+
+        for algorithm in [
+            HashAlgorithm::SHA1,
+            HashAlgorithm::SHA3_256,
+            HashAlgorithm::BLAKE2b_512,
+        ] {
+            assert_eq!(
+                HashAlgorithm::from_str(&algorithm.to_string()),
+                Ok(algorithm)
+            );
+        }
+        assert_eq!(
+            HashAlgorithm::from_str("SHA-2").unwrap_err().to_string(),
+            "Unknown hash algorithm 'SHA-2'"
+        );
+    }
 }
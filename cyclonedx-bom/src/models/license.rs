@@ -39,7 +39,8 @@ use super::property::Properties;
 ///
 /// As defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_licenseChoiceType)
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LicenseChoice {
     License(License),
     Expression(SpdxExpression),
@@ -81,7 +82,8 @@ impl Validate for LicenseChoice {
 /// Represents a license with identifier, text, and url
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_licenseType)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct License {
     pub bom_ref: Option<BomReference>,
     pub license_identifier: LicenseIdentifier,
@@ -140,7 +142,8 @@ impl Validate for License {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Licenses(pub Vec<LicenseChoice>);
 
 impl Validate for Licenses {
@@ -179,7 +182,8 @@ pub fn validate_license_identifier(identifier: &LicenseIdentifier) -> Result<(),
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LicenseIdentifier {
     /// An SPDX license identifier from the list on the [SPDX website](https://spdx.org/licenses/).
     SpdxId(SpdxIdentifier),
@@ -203,7 +207,8 @@ impl Validate for LicenseIdentifier {
 /// Represents Licensing information, added in spec version 1.5.
 ///
 /// For more details see: https://cyclonedx.org/docs/1.5/json/#metadata_licenses_oneOf_i0_items_license_licensing
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Licensing {
     pub alt_ids: Option<Vec<NormalizedString>>,
     pub licensor: Option<LicenseContact>,
@@ -237,7 +242,8 @@ impl Validate for Licensing {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LicenseContact {
     Organization(OrganizationalEntity),
     Contact(OrganizationalContact),
@@ -260,7 +266,8 @@ fn validate_license_type(license_type: &LicenseType) -> Result<(), ValidationErr
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 #[repr(u16)]
 pub enum LicenseType {
@@ -309,6 +316,8 @@ impl LicenseType {
     }
 }
 
+from_str_via_new_unchecked!(LicenseType, Unknown, "license type");
+
 #[cfg(test)]
 mod test {
     use crate::validation;
@@ -16,18 +16,69 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::external_models::normalized_string::validate_normalized_string;
+use crate::models::bom::SpecVersion;
 use crate::prelude::NormalizedString;
+use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// The product lifecycles a BOM describes, added in version 1.5.
+///
+/// For more details see
+/// https://cyclonedx.org/docs/1.5/json/#metadata_lifecycles
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lifecycles(pub Vec<Lifecycle>);
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+impl Validate for Lifecycles {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        let mut context = ValidationContext::new();
+        if version <= SpecVersion::V1_4 {
+            context.add_custom(
+                "lifecycles",
+                "Lifecycles are not supported in this format version",
+            );
+        }
+        context
+            .add_list("inner", &self.0, |lifecycle| {
+                lifecycle.validate_version(version)
+            })
+            .into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Lifecycle {
     Phase(Phase),
     Description(Description),
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+impl Validate for Lifecycle {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        let mut context = ValidationContext::new();
+
+        match self {
+            Lifecycle::Phase(phase) => {
+                context.add_enum("phase", phase, validate_phase);
+            }
+            Lifecycle::Description(description) => {
+                context.add_struct("description", description, version);
+            }
+        }
+
+        context.into()
+    }
+}
+
+pub fn validate_phase(phase: &Phase) -> Result<(), ValidationError> {
+    if let Phase::Unknown(unknown) = phase {
+        return Err(format!("Unknown lifecycle phase '{}'", unknown).into());
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Phase {
     Design,
     PreBuild,
@@ -71,9 +122,97 @@ impl Phase {
     }
 }
 
+from_str_via_new_unchecked!(Phase, Unknown, "phase");
+
 /// A description of a `Lifecycle`.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Description {
     pub name: NormalizedString,
     pub description: Option<NormalizedString>,
 }
+
+impl Validate for Description {
+    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_field("name", &self.name, validate_normalized_string)
+            .add_field_option(
+                "description",
+                self.description.as_ref(),
+                validate_normalized_string,
+            )
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use crate::validation;
+
+    use super::*;
+
+    #[test]
+    fn valid_lifecycles_should_pass_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(Phase::Build),
+            Lifecycle::Description(Description {
+                name: "platform-integration-testing".into(),
+                description: Some("Integration testing of the platform".into()),
+            }),
+        ])
+        .validate_version(SpecVersion::V1_5);
+
+        assert!(validation_result.passed());
+    }
+
+    #[test]
+    fn invalid_lifecycles_should_fail_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(Phase::Unknown("testing".to_string())),
+            Lifecycle::Description(Description {
+                name: NormalizedString("invalid\tname".to_string()),
+                description: None,
+            }),
+        ])
+        .validate_version(SpecVersion::V1_5);
+
+        assert_eq!(
+            validation_result,
+            validation::list(
+                "inner",
+                [
+                    (
+                        0,
+                        validation::r#enum("phase", "Unknown lifecycle phase 'testing'")
+                    ),
+                    (
+                        1,
+                        validation::r#struct(
+                            "description",
+                            validation::field(
+                                "name",
+                                "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                            )
+                        )
+                    )
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn lifecycles_should_fail_validation_in_older_versions() {
+        let validation_result =
+            Lifecycles(vec![Lifecycle::Phase(Phase::Design)]).validate_version(SpecVersion::V1_4);
+
+        assert_eq!(
+            validation_result,
+            validation::custom(
+                "lifecycles",
+                ["Lifecycles are not supported in this format version"]
+            )
+        );
+    }
+}
@@ -18,14 +18,14 @@
 
 use thiserror::Error;
 
-use crate::external_models::date_time::{DateTime, DateTimeError};
+use crate::external_models::date_time::{DateTime, DateTimeError, TimestampSource};
 use crate::external_models::validate_date_time;
 use crate::models::component::Component;
 use crate::models::license::Licenses;
 use crate::models::lifecycle::Lifecycles;
 use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::models::property::Properties;
-use crate::models::tool::Tools;
+use crate::models::tool::{Tool, Tools};
 use crate::validation::{Validate, ValidationContext, ValidationResult};
 
 use super::bom::SpecVersion;
@@ -33,13 +33,17 @@ use super::bom::SpecVersion;
 /// Represents additional information about a BOM
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_metadata)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Metadata {
     pub timestamp: Option<DateTime>,
     pub tools: Option<Tools>,
     pub authors: Option<Vec<OrganizationalContact>>,
     pub component: Option<Component>,
+    /// Deprecated in 1.6 in favor of `manufacturer`
     pub manufacture: Option<OrganizationalEntity>,
+    /// Added in 1.6
+    pub manufacturer: Option<OrganizationalEntity>,
     pub supplier: Option<OrganizationalEntity>,
     pub licenses: Option<Licenses>,
     pub properties: Option<Properties>,
@@ -59,7 +63,23 @@ impl Metadata {
     ///
     /// Returns an error variant if unable to generate a valid timestamp
     pub fn new() -> Result<Self, MetadataError> {
-        match DateTime::now() {
+        Self::new_with_timestamp(&TimestampSource::Utc)
+    }
+
+    /// Constructs a new `Metadata` with a timestamp created from the given source
+    /// ```
+    /// use cyclonedx_bom::external_models::date_time::TimestampSource;
+    /// use cyclonedx_bom::models::metadata::{Metadata, MetadataError};
+    ///
+    /// let source = TimestampSource::FixedOffset { hours: 2, minutes: 0 };
+    /// let metadata = Metadata::new_with_timestamp(&source)?;
+    /// # Ok::<(), MetadataError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error variant if unable to generate a valid timestamp
+    pub fn new_with_timestamp(source: &TimestampSource) -> Result<Self, MetadataError> {
+        match source.timestamp() {
             Ok(timestamp) => Ok(Self {
                 timestamp: Some(timestamp),
                 ..Default::default()
@@ -67,11 +87,39 @@ impl Metadata {
             Err(e) => Err(MetadataError::InvalidTimestamp(e)),
         }
     }
+
+    /// Constructs a new `Metadata` with a timestamp based on the current time and this library
+    /// registered as the tool that created the BOM
+    /// ```
+    /// use cyclonedx_bom::models::metadata::{Metadata, MetadataError};
+    /// use cyclonedx_bom::models::tool::{Tool, Tools};
+    ///
+    /// let metadata = Metadata::now()?;
+    /// assert_eq!(metadata.tools, Some(Tools::List(vec![Tool::cyclonedx_bom()])));
+    /// # Ok::<(), MetadataError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error variant if unable to generate a valid timestamp
+    pub fn now() -> Result<Self, MetadataError> {
+        Ok(Self {
+            tools: Some(Tools::List(vec![Tool::cyclonedx_bom()])),
+            ..Self::new()?
+        })
+    }
+
+    /// Returns the organization that manufactured the component the BOM describes.
+    ///
+    /// This is the `manufacturer` added in 1.6, falling back to the deprecated `manufacture`.
+    pub fn effective_manufacturer(&self) -> Option<&OrganizationalEntity> {
+        self.manufacturer.as_ref().or(self.manufacture.as_ref())
+    }
 }
 
 impl Validate for Metadata {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
-        ValidationContext::new()
+        let mut context = ValidationContext::new();
+        context
             .add_field_option("timestamp", self.timestamp.as_ref(), validate_date_time)
             .add_list("tools", self.tools.as_ref(), |tools| {
                 tools.validate_version(version)
@@ -88,7 +136,20 @@ impl Validate for Metadata {
             .add_list("properties", self.properties.as_ref(), |property| {
                 property.validate_version(version)
             })
-            .into()
+            .add_struct_option("lifecycles", self.lifecycles.as_ref(), version);
+
+        if version <= SpecVersion::V1_5 {
+            if self.manufacturer.is_some() {
+                context.add_custom(
+                    "manufacturer",
+                    "Field 'manufacturer' not supported in this format version",
+                );
+            }
+        } else {
+            context.add_struct_option("manufacturer", self.manufacturer.as_ref(), version);
+        }
+
+        context.into()
     }
 }
 
@@ -138,6 +199,7 @@ mod test {
                 mime_type: None,
                 bom_ref: None,
                 supplier: None,
+                authors: None,
                 author: None,
                 publisher: None,
                 group: None,
@@ -157,9 +219,11 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
                 signature: None,
                 model_card: None,
                 data: None,
+                crypto_properties: None,
             }),
             manufacture: Some(OrganizationalEntity {
                 bom_ref: Some(BomReference::new("Manufacturer")),
@@ -167,6 +231,7 @@ mod test {
                 url: None,
                 contact: None,
             }),
+            manufacturer: None,
             supplier: Some(OrganizationalEntity {
                 bom_ref: Some(BomReference::new("Supplier")),
                 name: Some(NormalizedString::new("name")),
@@ -182,7 +247,7 @@ mod test {
             }])),
             lifecycles: Some(Lifecycles(vec![Lifecycle::Phase(Phase::Build)])),
         }
-        .validate();
+        .validate_version(SpecVersion::V1_5);
 
         assert!(validation_result.passed());
     }
@@ -209,6 +274,7 @@ mod test {
                 mime_type: None,
                 bom_ref: None,
                 supplier: None,
+                authors: None,
                 author: None,
                 publisher: None,
                 group: None,
@@ -228,9 +294,11 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
                 signature: None,
                 model_card: None,
                 data: None,
+                crypto_properties: None,
             }),
             manufacture: Some(OrganizationalEntity {
                 bom_ref: Some(BomReference::new("Manufacturer")),
@@ -238,6 +306,7 @@ mod test {
                 url: None,
                 contact: None,
             }),
+            manufacturer: None,
             supplier: Some(OrganizationalEntity {
                 bom_ref: Some(BomReference::new("Supplier")),
                 name: Some(NormalizedString("invalid\tname".to_string())),
@@ -256,7 +325,7 @@ mod test {
                 description: Some(NormalizedString("invalid\tvalue".to_string())),
             })])),
         }
-        .validate();
+        .validate_version(SpecVersion::V1_5);
 
         assert_eq!(
             validation_result,
@@ -334,9 +403,76 @@ mod test {
                             )]
                         )
                     )]
+                ),
+                validation::r#struct(
+                    "lifecycles",
+                    validation::list(
+                        "inner",
+                        [(
+                            0,
+                            validation::r#struct(
+                                "description",
+                                validation::field(
+                                    "description",
+                                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                )
+                            )
+                        )]
+                    )
                 )
             ]
             .into()
         );
     }
+
+    #[test]
+    fn it_should_register_this_library_as_tool() {
+        let metadata = Metadata::now().expect("Failed to create metadata");
+
+        assert!(metadata.timestamp.is_some());
+        assert_eq!(
+            metadata.tools,
+            Some(Tools::List(vec![Tool::new(
+                "CycloneDX",
+                "cyclonedx-bom",
+                env!("CARGO_PKG_VERSION")
+            )]))
+        );
+        assert!(metadata.validate_version(SpecVersion::V1_5).passed());
+    }
+
+    #[test]
+    fn manufacturer_should_fail_validation_in_older_versions() {
+        let metadata = Metadata {
+            manufacturer: Some(OrganizationalEntity::new("name")),
+            ..Default::default()
+        };
+
+        assert!(metadata.validate_version(SpecVersion::V1_6).passed());
+        assert_eq!(
+            metadata.validate_version(SpecVersion::V1_5),
+            validation::custom(
+                "manufacturer",
+                ["Field 'manufacturer' not supported in this format version"]
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_fall_back_to_manufacture() {
+        let mut metadata = Metadata {
+            manufacture: Some(OrganizationalEntity::new("manufacture")),
+            ..Default::default()
+        };
+        assert_eq!(
+            metadata.effective_manufacturer(),
+            Some(&OrganizationalEntity::new("manufacture"))
+        );
+
+        metadata.manufacturer = Some(OrganizationalEntity::new("manufacturer"));
+        assert_eq!(
+            metadata.effective_manufacturer(),
+            Some(&OrganizationalEntity::new("manufacturer"))
+        );
+    }
 }
@@ -16,16 +16,42 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+/// Implements `FromStr` for an enum with a `new_unchecked` constructor, rejecting the values
+/// that end up in its catch-all variant instead of returning them as unknown.
+macro_rules! from_str_via_new_unchecked {
+    ($type:ident, $unknown:ident, $kind:literal) => {
+        impl std::str::FromStr for $type {
+            type Err = crate::errors::UnknownValueError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match Self::new_unchecked(s) {
+                    Self::$unknown(_) => Err(crate::errors::UnknownValueError {
+                        kind: $kind,
+                        value: s.to_string(),
+                    }),
+                    value => Ok(value),
+                }
+            }
+        }
+    };
+}
+
 pub mod advisory;
 pub mod annotation;
 pub mod attached_text;
 pub mod attachment;
 pub mod bom;
+pub mod bom_index;
+pub mod bom_link;
+pub mod bom_template;
 pub mod code;
 pub mod component;
 pub mod component_data;
 pub mod composition;
+pub mod crypto_properties;
 pub mod data_governance;
+pub mod declarations;
+pub mod definitions;
 pub mod dependency;
 pub mod external_reference;
 pub mod formulation;
@@ -34,11 +60,19 @@ pub mod license;
 pub mod lifecycle;
 pub mod metadata;
 pub mod modelcard;
+pub mod normalization;
 pub mod organization;
+pub mod osv;
 pub mod property;
+pub mod redaction;
+pub mod release_notes;
 pub mod service;
 pub mod signature;
+#[cfg(feature = "spdx-interop")]
+pub mod spdx_interop;
 pub mod tool;
+pub mod vers;
+pub mod vex;
 pub mod vulnerability;
 pub mod vulnerability_analysis;
 pub mod vulnerability_credits;
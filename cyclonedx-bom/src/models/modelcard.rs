@@ -16,6 +16,8 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use ordered_float::OrderedFloat;
+
 use crate::{
     prelude::{Validate, ValidationResult},
     validation::{ValidationContext, ValidationError},
@@ -24,13 +26,16 @@ use crate::{
 use super::{
     bom::{BomReference, SpecVersion},
     component_data::{ComponentData, GraphicsCollection},
+    external_reference::ExternalReferences,
+    organization::OrganizationalEntity,
     property::Properties,
 };
 
 /// This model was added in spec version 1.5
 ///
 /// For more details see: https://cyclonedx.org/docs/1.5/json/#metadata_component_modelCard
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModelCard {
     pub bom_ref: Option<BomReference>,
     pub model_parameters: Option<ModelParameters>,
@@ -57,7 +62,8 @@ impl Validate for ModelCard {
 /// This model was added in spec version 1.5.
 ///
 /// For more details see: https://cyclonedx.org/docs/1.5/json/#metadata_component_modelCard_modelParameters
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModelParameters {
     pub approach: Option<ModelParametersApproach>,
     pub task: Option<String>,
@@ -79,7 +85,8 @@ impl Validate for ModelParameters {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModelParametersApproach {
     pub approach_type: Option<ApproachType>,
 }
@@ -108,7 +115,8 @@ pub fn validate_approach_type(approach_type: &ApproachType) -> Result<(), Valida
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ApproachType {
     Supervised,
     Unsupervised,
@@ -132,6 +140,8 @@ impl ApproachType {
     }
 }
 
+from_str_via_new_unchecked!(ApproachType, Unknown, "approach type");
+
 impl std::fmt::Display for ApproachType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -146,7 +156,8 @@ impl std::fmt::Display for ApproachType {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Datasets(pub Vec<Dataset>);
 
 impl Validate for Datasets {
@@ -160,7 +171,8 @@ impl Validate for Datasets {
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Dataset {
     Component(ComponentData),
     Reference(String),
@@ -175,7 +187,8 @@ impl Validate for Dataset {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Inputs(pub Vec<MLParameter>);
 
 impl Validate for Inputs {
@@ -186,7 +199,8 @@ impl Validate for Inputs {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Outputs(pub Vec<MLParameter>);
 
 impl Validate for Outputs {
@@ -201,7 +215,8 @@ pub fn validate_mlparameter(_parameter: &MLParameter) -> Result<(), ValidationEr
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MLParameter {
     pub format: Option<String>,
 }
@@ -214,7 +229,8 @@ impl MLParameter {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QuantitativeAnalysis {
     pub performance_metrics: Option<PerformanceMetrics>,
     pub graphics: Option<GraphicsCollection>,
@@ -233,7 +249,8 @@ impl Validate for QuantitativeAnalysis {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerformanceMetrics(pub Vec<PerformanceMetric>);
 
 impl Validate for PerformanceMetrics {
@@ -244,7 +261,8 @@ impl Validate for PerformanceMetrics {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerformanceMetric {
     pub metric_type: Option<String>,
     pub value: Option<String>,
@@ -258,19 +276,310 @@ impl Validate for PerformanceMetric {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfidenceInterval {
     pub lower_bound: Option<String>,
     pub upper_bound: Option<String>,
 }
 
-/// TODO: implement struct
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Considerations {}
+/// This model was added in spec version 1.5.
+///
+/// For more details see: https://cyclonedx.org/docs/1.6/json/#metadata_component_modelCard_considerations
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Considerations {
+    pub users: Option<Vec<String>>,
+    pub use_cases: Option<Vec<String>>,
+    pub technical_limitations: Option<Vec<String>>,
+    pub performance_tradeoffs: Option<Vec<String>>,
+    pub ethical_considerations: Option<Vec<EthicalConsideration>>,
+    /// Added in version 1.6
+    pub environmental_considerations: Option<EnvironmentalConsiderations>,
+    pub fairness_assessments: Option<Vec<FairnessAssessment>>,
+}
 
 impl Validate for Considerations {
-    fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
-        ValidationContext::new().into()
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        let mut context = ValidationContext::new();
+        if version < SpecVersion::V1_6 {
+            if self.environmental_considerations.is_some() {
+                context.add_custom(
+                    "environmental_considerations",
+                    "Environmental considerations are not supported in this format version",
+                );
+            }
+        } else {
+            context.add_struct_option(
+                "environmental_considerations",
+                self.environmental_considerations.as_ref(),
+                version,
+            );
+        }
+        context.into()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EthicalConsideration {
+    pub name: Option<String>,
+    pub mitigation_strategy: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FairnessAssessment {
+    pub group_at_risk: Option<String>,
+    pub benefits: Option<String>,
+    pub harms: Option<String>,
+    pub mitigation_strategy: Option<String>,
+}
+
+/// The environmental impact of a model, e.g. the energy consumed while training it.
+///
+/// This model was added in spec version 1.6.
+///
+/// For more details see: https://cyclonedx.org/docs/1.6/json/#metadata_component_modelCard_considerations_environmentalConsiderations
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvironmentalConsiderations {
+    pub energy_consumptions: Option<Vec<EnergyConsumption>>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for EnvironmentalConsiderations {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_list_option(
+                "energy_consumptions",
+                self.energy_consumptions.as_ref(),
+                |consumption| consumption.validate_version(version),
+            )
+            .add_struct_option("properties", self.properties.as_ref(), version)
+            .into()
+    }
+}
+
+/// The energy consumed by a single activity in the lifecycle of a model.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnergyConsumption {
+    pub activity: EnergyActivity,
+    pub energy_providers: Vec<EnergyProvider>,
+    pub activity_energy_cost: EnergyMeasure,
+    pub co2_cost_equivalent: Option<Co2Measure>,
+    pub co2_cost_offset: Option<Co2Measure>,
+    pub properties: Option<Properties>,
+}
+
+impl EnergyConsumption {
+    pub fn new(activity: EnergyActivity, activity_energy_cost: EnergyMeasure) -> Self {
+        Self {
+            activity,
+            energy_providers: Vec::new(),
+            activity_energy_cost,
+            co2_cost_equivalent: None,
+            co2_cost_offset: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for EnergyConsumption {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_enum("activity", &self.activity, validate_energy_activity)
+            .add_list("energy_providers", &self.energy_providers, |provider| {
+                provider.validate_version(version)
+            })
+            .add_struct_option("properties", self.properties.as_ref(), version)
+            .into()
+    }
+}
+
+/// Checks the given [`EnergyActivity`] is valid.
+pub fn validate_energy_activity(activity: &EnergyActivity) -> Result<(), ValidationError> {
+    if let EnergyActivity::Unknown(unknown) = activity {
+        return Err(format!("Unknown energy activity '{unknown}'").into());
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EnergyActivity {
+    Design,
+    DataCollection,
+    DataPreparation,
+    Training,
+    FineTuning,
+    Validation,
+    Deployment,
+    Inference,
+    Other,
+    #[doc(hidden)]
+    Unknown(String),
+}
+
+impl EnergyActivity {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "design" => Self::Design,
+            "data-collection" => Self::DataCollection,
+            "data-preparation" => Self::DataPreparation,
+            "training" => Self::Training,
+            "fine-tuning" => Self::FineTuning,
+            "validation" => Self::Validation,
+            "deployment" => Self::Deployment,
+            "inference" => Self::Inference,
+            "other" => Self::Other,
+            unknown => Self::Unknown(unknown.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(EnergyActivity, Unknown, "energy activity");
+
+impl std::fmt::Display for EnergyActivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            EnergyActivity::Design => "design",
+            EnergyActivity::DataCollection => "data-collection",
+            EnergyActivity::DataPreparation => "data-preparation",
+            EnergyActivity::Training => "training",
+            EnergyActivity::FineTuning => "fine-tuning",
+            EnergyActivity::Validation => "validation",
+            EnergyActivity::Deployment => "deployment",
+            EnergyActivity::Inference => "inference",
+            EnergyActivity::Other => "other",
+            EnergyActivity::Unknown(unknown) => unknown,
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// The provider of the energy consumed by an activity.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnergyProvider {
+    pub bom_ref: Option<BomReference>,
+    pub description: Option<String>,
+    pub organization: OrganizationalEntity,
+    pub energy_source: EnergySource,
+    pub energy_provided: EnergyMeasure,
+    pub external_references: Option<ExternalReferences>,
+}
+
+impl Validate for EnergyProvider {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_struct("organization", &self.organization, version)
+            .add_enum("energy_source", &self.energy_source, validate_energy_source)
+            .add_struct_option(
+                "external_references",
+                self.external_references.as_ref(),
+                version,
+            )
+            .into()
+    }
+}
+
+/// Checks the given [`EnergySource`] is valid.
+pub fn validate_energy_source(source: &EnergySource) -> Result<(), ValidationError> {
+    if let EnergySource::Unknown(unknown) = source {
+        return Err(format!("Unknown energy source '{unknown}'").into());
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EnergySource {
+    Coal,
+    Oil,
+    NaturalGas,
+    Nuclear,
+    Wind,
+    Solar,
+    Geothermal,
+    Hydropower,
+    Biofuel,
+    /// The energy source is not known
+    UnknownSource,
+    Other,
+    #[doc(hidden)]
+    Unknown(String),
+}
+
+impl EnergySource {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "coal" => Self::Coal,
+            "oil" => Self::Oil,
+            "natural-gas" => Self::NaturalGas,
+            "nuclear" => Self::Nuclear,
+            "wind" => Self::Wind,
+            "solar" => Self::Solar,
+            "geothermal" => Self::Geothermal,
+            "hydropower" => Self::Hydropower,
+            "biofuel" => Self::Biofuel,
+            "unknown" => Self::UnknownSource,
+            "other" => Self::Other,
+            unknown => Self::Unknown(unknown.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(EnergySource, Unknown, "energy source");
+
+impl std::fmt::Display for EnergySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            EnergySource::Coal => "coal",
+            EnergySource::Oil => "oil",
+            EnergySource::NaturalGas => "natural-gas",
+            EnergySource::Nuclear => "nuclear",
+            EnergySource::Wind => "wind",
+            EnergySource::Solar => "solar",
+            EnergySource::Geothermal => "geothermal",
+            EnergySource::Hydropower => "hydropower",
+            EnergySource::Biofuel => "biofuel",
+            EnergySource::UnknownSource => "unknown",
+            EnergySource::Other => "other",
+            EnergySource::Unknown(unknown) => unknown,
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// An amount of energy in kilowatt-hours (kWh).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnergyMeasure(pub OrderedFloat<f32>);
+
+impl EnergyMeasure {
+    pub fn kilowatt_hours(value: f32) -> Self {
+        Self(OrderedFloat(value))
+    }
+
+    pub fn get(&self) -> f32 {
+        self.0 .0
+    }
+}
+
+/// An amount of carbon dioxide in tonnes of CO2 equivalent (tCO2eq).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Co2Measure(pub OrderedFloat<f32>);
+
+impl Co2Measure {
+    pub fn tonnes_co2_equivalent(value: f32) -> Self {
+        Self(OrderedFloat(value))
+    }
+
+    pub fn get(&self) -> f32 {
+        self.0 .0
     }
 }
 
@@ -285,15 +594,101 @@ mod test {
             },
             data_governance::{DataGovernance, DataGovernanceResponsibleParty},
             modelcard::{
-                ApproachType, ConfidenceInterval, Considerations, Dataset, Datasets, Inputs,
-                MLParameter, ModelCard, ModelParameters, ModelParametersApproach, Outputs,
-                PerformanceMetric, PerformanceMetrics, QuantitativeAnalysis,
+                ApproachType, ConfidenceInterval, Considerations, Dataset, Datasets,
+                EnergyActivity, EnergyConsumption, EnergyMeasure, EnergyProvider, EnergySource,
+                EnvironmentalConsiderations, Inputs, MLParameter, ModelCard, ModelParameters,
+                ModelParametersApproach, Outputs, PerformanceMetric, PerformanceMetrics,
+                QuantitativeAnalysis,
             },
-            organization::OrganizationalContact,
+            organization::{OrganizationalContact, OrganizationalEntity},
             property::{Properties, Property},
         },
         prelude::{NormalizedString, SpecVersion, Uri, Validate},
+        validation,
     };
+    use pretty_assertions::assert_eq;
+
+    fn considerations(activity: EnergyActivity, energy_source: EnergySource) -> Considerations {
+        Considerations {
+            environmental_considerations: Some(EnvironmentalConsiderations {
+                energy_consumptions: Some(vec![EnergyConsumption {
+                    energy_providers: vec![EnergyProvider {
+                        bom_ref: None,
+                        description: None,
+                        organization: OrganizationalEntity {
+                            bom_ref: None,
+                            name: Some(NormalizedString::new("Provider")),
+                            url: None,
+                            contact: None,
+                        },
+                        energy_source,
+                        energy_provided: EnergyMeasure::kilowatt_hours(1000.0),
+                        external_references: None,
+                    }],
+                    ..EnergyConsumption::new(activity, EnergyMeasure::kilowatt_hours(800.0))
+                }]),
+                properties: None,
+            }),
+            ..Considerations::default()
+        }
+    }
+
+    #[test]
+    fn valid_considerations_should_pass_validation() {
+        let validation_result = considerations(EnergyActivity::Training, EnergySource::Wind)
+            .validate_version(SpecVersion::V1_6);
+
+        assert!(validation_result.passed());
+    }
+
+    #[test]
+    fn invalid_considerations_should_fail_validation() {
+        let validation_result = considerations(
+            EnergyActivity::new_unchecked("sleeping"),
+            EnergySource::new_unchecked("hamster wheel"),
+        )
+        .validate_version(SpecVersion::V1_6);
+
+        assert_eq!(
+            validation_result,
+            validation::r#struct(
+                "environmental_considerations",
+                validation::list(
+                    "energy_consumptions",
+                    [(
+                        0,
+                        vec![
+                            validation::r#enum("activity", "Unknown energy activity 'sleeping'"),
+                            validation::list(
+                                "energy_providers",
+                                [(
+                                    0,
+                                    validation::r#enum(
+                                        "energy_source",
+                                        "Unknown energy source 'hamster wheel'"
+                                    )
+                                )]
+                            ),
+                        ]
+                    )]
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn environmental_considerations_should_fail_validation_in_older_versions() {
+        let validation_result = considerations(EnergyActivity::Training, EnergySource::Wind)
+            .validate_version(SpecVersion::V1_5);
+
+        assert_eq!(
+            validation_result,
+            validation::custom(
+                "environmental_considerations",
+                ["Environmental considerations are not supported in this format version"]
+            )
+        );
+    }
 
     #[test]
     fn valid_modelcard_should_pass_validation() {
@@ -312,7 +707,7 @@ mod test {
                     name: Some("dataset".to_string()),
                     contents: Some(DataContents {
                         attachment: Some(Attachment {
-                            content: "data content".to_string(),
+                            content: "ZGF0YSBjb250ZW50".to_string(),
                             content_type: Some("text/plain".to_string()),
                             encoding: Some("base64".to_string()),
                         }),
@@ -328,7 +723,7 @@ mod test {
                             image: Some(Attachment {
                                 content_type: Some("image/jpeg".to_string()),
                                 encoding: Some("base64".to_string()),
-                                content: "imagebytes".to_string(),
+                                content: "aW1hZ2VieXRlcw==".to_string(),
                             }),
                         }]),
                     }),
@@ -364,7 +759,7 @@ mod test {
                     collection: None,
                 }),
             }),
-            considerations: Some(Considerations {}),
+            considerations: Some(Considerations::default()),
             properties: Some(Properties(vec![Property {
                 name: "property-a".to_string(),
                 value: NormalizedString::new("value"),
@@ -374,4 +769,84 @@ mod test {
         let validation_result = modelcard.validate_version(SpecVersion::V1_5);
         assert!(validation_result.passed());
     }
+
+    #[test]
+    fn invalid_modelcard_should_fail_validation() {
+        let modelcard = ModelCard {
+            bom_ref: None,
+            model_parameters: Some(ModelParameters {
+                approach: Some(ModelParametersApproach::new("guesswork")),
+                task: None,
+                architecture_family: None,
+                model_architecture: None,
+                datasets: Some(Datasets(vec![Dataset::Component(ComponentData {
+                    bom_ref: None,
+                    data_type: ComponentDataType::new_unchecked("spreadsheet"),
+                    name: None,
+                    contents: None,
+                    classification: None,
+                    sensitive_data: None,
+                    graphics: None,
+                    description: None,
+                    governance: Some(DataGovernance {
+                        custodians: None,
+                        stewards: None,
+                        owners: Some(vec![DataGovernanceResponsibleParty::Contact(
+                            OrganizationalContact {
+                                bom_ref: None,
+                                name: Some(NormalizedString("owner\n".to_string())),
+                                email: None,
+                                phone: None,
+                            },
+                        )]),
+                    }),
+                })])),
+                inputs: None,
+                outputs: None,
+            }),
+            quantitative_analysis: None,
+            considerations: None,
+            properties: None,
+        };
+
+        let validation_result = modelcard.validate_version(SpecVersion::V1_5);
+
+        assert_eq!(
+            validation_result,
+            validation::r#struct(
+                "model_parameters",
+                vec![
+                    validation::r#struct(
+                        "approach",
+                        validation::field("type", "Unknown approach type 'guesswork'")
+                    ),
+                    validation::r#struct(
+                        "datasets",
+                        validation::list(
+                            "inner",
+                            [(
+                                0,
+                                vec![
+                                    validation::field("type", "Unknown component data type found"),
+                                    validation::r#struct(
+                                        "governance",
+                                        validation::list(
+                                            "owners",
+                                            [(
+                                                0,
+                                                validation::field(
+                                                    "name",
+                                                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                                )
+                                            )]
+                                        )
+                                    ),
+                                ]
+                            )]
+                        )
+                    ),
+                ]
+            )
+        );
+    }
 }
@@ -0,0 +1,445 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::models::bom::Bom;
+use crate::models::component::{Component, Components};
+use crate::models::composition::Composition;
+use crate::models::hash::Hashes;
+use crate::models::metadata::Metadata;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::service::{Service, Services};
+use crate::models::tool::Tools;
+use crate::models::vulnerability::Vulnerability;
+
+/// Brings the BOM into the canonical form of [`Bom::normalized`].
+///
+/// Nested lists are normalized before the lists containing them, so that sorting by the derived
+/// `Ord` of the models compares elements that are already in canonical form.
+pub(crate) fn normalize(bom: &mut Bom) {
+    if let Some(metadata) = &mut bom.metadata {
+        normalize_metadata(metadata);
+    }
+    if let Some(components) = &mut bom.components {
+        normalize_components(components);
+    }
+    if let Some(services) = &mut bom.services {
+        normalize_services(services);
+    }
+    if let Some(references) = &mut bom.external_references {
+        references.normalize();
+    }
+    if let Some(dependencies) = &mut bom.dependencies {
+        for dependency in &mut dependencies.0 {
+            dependency.dependencies.sort();
+            dependency.dependencies.dedup();
+        }
+        dependencies.0.sort();
+    }
+    if let Some(compositions) = &mut bom.compositions {
+        compositions.0.iter_mut().for_each(normalize_composition);
+        compositions.0.sort();
+    }
+    if let Some(properties) = &mut bom.properties {
+        properties.0.sort();
+    }
+    if let Some(vulnerabilities) = &mut bom.vulnerabilities {
+        vulnerabilities
+            .0
+            .iter_mut()
+            .for_each(normalize_vulnerability);
+        vulnerabilities.0.sort();
+    }
+}
+
+fn normalize_metadata(metadata: &mut Metadata) {
+    if let Some(tools) = &mut metadata.tools {
+        normalize_tools(tools);
+    }
+    if let Some(authors) = &mut metadata.authors {
+        normalize_contacts(authors);
+    }
+    if let Some(component) = &mut metadata.component {
+        normalize_component(component);
+    }
+    for entity in [
+        &mut metadata.manufacture,
+        &mut metadata.manufacturer,
+        &mut metadata.supplier,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        normalize_entity(entity);
+    }
+    if let Some(licenses) = &mut metadata.licenses {
+        licenses.0.sort();
+    }
+    if let Some(properties) = &mut metadata.properties {
+        properties.0.sort();
+    }
+}
+
+fn normalize_tools(tools: &mut Tools) {
+    match tools {
+        Tools::List(tools) => {
+            for tool in tools.iter_mut() {
+                trim_all([&mut tool.vendor, &mut tool.name, &mut tool.version]);
+                if let Some(hashes) = &mut tool.hashes {
+                    normalize_hashes(hashes);
+                }
+                if let Some(references) = &mut tool.external_references {
+                    references.normalize();
+                }
+            }
+            tools.sort();
+        }
+        Tools::Object {
+            services,
+            components,
+        } => {
+            if let Some(services) = services {
+                normalize_services(services);
+            }
+            if let Some(components) = components {
+                normalize_components(components);
+            }
+        }
+    }
+}
+
+fn normalize_components(components: &mut Components) {
+    components.0.iter_mut().for_each(normalize_component);
+    components.0.sort();
+}
+
+fn normalize_component(component: &mut Component) {
+    trim(&mut component.name);
+    trim_all([
+        &mut component.author,
+        &mut component.publisher,
+        &mut component.group,
+        &mut component.version,
+        &mut component.description,
+        &mut component.copyright,
+    ]);
+    if let Some(supplier) = &mut component.supplier {
+        normalize_entity(supplier);
+    }
+    if let Some(authors) = &mut component.authors {
+        normalize_contacts(authors);
+    }
+    if let Some(hashes) = &mut component.hashes {
+        normalize_hashes(hashes);
+    }
+    if let Some(licenses) = &mut component.licenses {
+        licenses.0.sort();
+    }
+    if let Some(pedigree) = &mut component.pedigree {
+        for components in [
+            &mut pedigree.ancestors,
+            &mut pedigree.descendants,
+            &mut pedigree.variants,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            normalize_components(components);
+        }
+    }
+    if let Some(references) = &mut component.external_references {
+        references.normalize();
+    }
+    if let Some(properties) = &mut component.properties {
+        properties.0.sort();
+    }
+    if let Some(components) = &mut component.components {
+        normalize_components(components);
+    }
+}
+
+fn normalize_services(services: &mut Services) {
+    services.0.iter_mut().for_each(normalize_service);
+    services.0.sort();
+}
+
+fn normalize_service(service: &mut Service) {
+    trim(&mut service.name);
+    trim_all([
+        &mut service.group,
+        &mut service.version,
+        &mut service.description,
+        &mut service.trust_zone,
+    ]);
+    if let Some(provider) = &mut service.provider {
+        normalize_entity(provider);
+    }
+    if let Some(endpoints) = &mut service.endpoints {
+        endpoints.sort();
+    }
+    if let Some(licenses) = &mut service.licenses {
+        licenses.0.sort();
+    }
+    if let Some(references) = &mut service.external_references {
+        references.normalize();
+    }
+    if let Some(properties) = &mut service.properties {
+        properties.0.sort();
+    }
+    if let Some(services) = &mut service.services {
+        normalize_services(services);
+    }
+}
+
+fn normalize_composition(composition: &mut Composition) {
+    for references in [
+        &mut composition.assemblies,
+        &mut composition.dependencies,
+        &mut composition.vulnerabilities,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        references.sort();
+    }
+}
+
+fn normalize_vulnerability(vulnerability: &mut Vulnerability) {
+    trim_all([&mut vulnerability.id]);
+    for text in [
+        &mut vulnerability.description,
+        &mut vulnerability.detail,
+        &mut vulnerability.recommendation,
+        &mut vulnerability.workaround,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        *text = text.trim().to_string();
+    }
+    if let Some(references) = &mut vulnerability.vulnerability_references {
+        references.0.sort();
+    }
+    if let Some(ratings) = &mut vulnerability.vulnerability_ratings {
+        ratings.0.sort();
+    }
+    if let Some(cwes) = &mut vulnerability.cwes {
+        cwes.sort();
+    }
+    if let Some(advisories) = &mut vulnerability.advisories {
+        advisories.0.sort();
+    }
+    if let Some(credits) = &mut vulnerability.vulnerability_credits {
+        if let Some(organizations) = &mut credits.organizations {
+            organizations.iter_mut().for_each(normalize_entity);
+            organizations.sort();
+        }
+        if let Some(individuals) = &mut credits.individuals {
+            normalize_contacts(individuals);
+        }
+    }
+    if let Some(tools) = &mut vulnerability.tools {
+        normalize_tools(tools);
+    }
+    if let Some(targets) = &mut vulnerability.vulnerability_targets {
+        for target in &mut targets.0 {
+            if let Some(versions) = &mut target.versions {
+                versions.0.sort();
+            }
+        }
+        targets.0.sort();
+    }
+    if let Some(properties) = &mut vulnerability.properties {
+        properties.0.sort();
+    }
+}
+
+fn normalize_entity(entity: &mut OrganizationalEntity) {
+    trim_all([&mut entity.name]);
+    if let Some(urls) = &mut entity.url {
+        urls.sort();
+    }
+    if let Some(contacts) = &mut entity.contact {
+        normalize_contacts(contacts);
+    }
+}
+
+fn normalize_contacts(contacts: &mut [OrganizationalContact]) {
+    for contact in contacts.iter_mut() {
+        trim_all([&mut contact.name, &mut contact.email, &mut contact.phone]);
+    }
+    contacts.sort();
+}
+
+/// Hash values are hexadecimal, so their case carries no meaning.
+fn normalize_hashes(hashes: &mut Hashes) {
+    for hash in &mut hashes.0 {
+        hash.content.0.make_ascii_lowercase();
+    }
+    hashes.0.sort();
+}
+
+fn trim(value: &mut NormalizedString) {
+    let trimmed = value.0.trim();
+    if trimmed.len() != value.0.len() {
+        value.0 = trimmed.to_string();
+    }
+}
+
+fn trim_all<const N: usize>(values: [&mut Option<NormalizedString>; N]) {
+    values.into_iter().flatten().for_each(trim);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::component::Classification;
+    use crate::models::dependency::{Dependencies, Dependency};
+    use crate::models::hash::{Hash, HashAlgorithm, HashValue};
+    use pretty_assertions::assert_eq;
+
+    fn component(name: &str) -> Component {
+        Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(name.to_string()),
+        )
+    }
+
+    #[test]
+    fn it_should_sort_components_and_dependencies() {
+        let mut bom = Bom {
+            components: Some(Components(vec![component("b"), component("a")])),
+            dependencies: Some(Dependencies(vec![
+                Dependency {
+                    dependency_ref: "b".to_string(),
+                    dependencies: vec!["c".to_string(), "a".to_string(), "c".to_string()],
+                },
+                Dependency {
+                    dependency_ref: "a".to_string(),
+                    dependencies: vec![],
+                },
+            ])),
+            ..Bom::default()
+        };
+
+        normalize(&mut bom);
+
+        let names: Vec<_> = bom
+            .components
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|c| c.name.0)
+            .collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(
+            bom.dependencies.unwrap().0,
+            vec![
+                Dependency {
+                    dependency_ref: "a".to_string(),
+                    dependencies: vec![],
+                },
+                Dependency {
+                    dependency_ref: "b".to_string(),
+                    dependencies: vec!["a".to_string(), "c".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_trim_strings_and_lowercase_hashes() {
+        let mut library = component(" library ");
+        library.description = Some(NormalizedString::new("  A library "));
+        library.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA_256,
+            content: HashValue("A3F2D8B1".to_string()),
+        }]));
+        let mut bom = Bom {
+            components: Some(Components(vec![library])),
+            ..Bom::default()
+        };
+
+        normalize(&mut bom);
+
+        let library = &bom.components.unwrap().0[0];
+        assert_eq!(library.name, NormalizedString::new("library"));
+        assert_eq!(
+            library.description,
+            Some(NormalizedString::new("A library"))
+        );
+        assert_eq!(library.hashes.as_ref().unwrap().0[0].content.0, "a3f2d8b1");
+    }
+
+    #[test]
+    fn it_should_sort_nested_components_before_their_parents() {
+        let mut first = component("parent");
+        first.components = Some(Components(vec![component("y"), component("x")]));
+        let mut second = component("parent");
+        second.components = Some(Components(vec![component("x"), component("y")]));
+
+        let mut bom = Bom {
+            components: Some(Components(vec![first])),
+            ..Bom::default()
+        };
+        let mut other = Bom {
+            components: Some(Components(vec![second])),
+            ..bom.clone()
+        };
+        normalize(&mut bom);
+        normalize(&mut other);
+
+        assert_eq!(bom, other);
+    }
+
+    #[test]
+    fn it_should_treat_reordered_boms_as_semantically_equal() {
+        let mut library = component("library");
+        library.hashes = Some(Hashes(vec![
+            Hash {
+                alg: HashAlgorithm::SHA_256,
+                content: HashValue("ABCD".to_string()),
+            },
+            Hash {
+                alg: HashAlgorithm::SHA1,
+                content: HashValue("1234".to_string()),
+            },
+        ]));
+        let bom = Bom {
+            components: Some(Components(vec![library.clone(), component("other")])),
+            ..Bom::default()
+        };
+
+        library.name = NormalizedString::new("library ");
+        library.hashes.as_mut().unwrap().0.reverse();
+        library.hashes.as_mut().unwrap().0[1].content = HashValue("abcd".to_string());
+        let reordered = Bom {
+            components: Some(Components(vec![component("other"), library])),
+            ..bom.clone()
+        };
+
+        assert_ne!(bom, reordered);
+        assert!(bom.semantically_equals(&reordered));
+        assert!(!bom.semantically_equals(&Bom {
+            components: Some(Components(vec![component("other")])),
+            ..bom.clone()
+        }));
+    }
+}
@@ -16,6 +16,8 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::collections::HashMap;
+
 use crate::{
     external_models::{
         normalized_string::{validate_normalized_string, NormalizedString},
@@ -29,7 +31,8 @@ use super::bom::{validate_bom_ref, BomReference, SpecVersion};
 /// Represents the contact information for an organization
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_organizationalContact)
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrganizationalContact {
     pub bom_ref: Option<BomReference>,
     pub name: Option<NormalizedString>,
@@ -52,6 +55,30 @@ impl OrganizationalContact {
             phone: None,
         }
     }
+
+    /// Returns the contact with the given `bom-ref`.
+    pub fn with_bom_ref(mut self, bom_ref: impl ToString) -> Self {
+        self.bom_ref = Some(BomReference::new(bom_ref));
+        self
+    }
+
+    /// Returns the contact with the given email address.
+    pub fn with_email(mut self, email: &str) -> Self {
+        self.email = Some(NormalizedString::new(email));
+        self
+    }
+
+    /// Returns the contact with the given phone number.
+    pub fn with_phone(mut self, phone: &str) -> Self {
+        self.phone = Some(NormalizedString::new(phone));
+        self
+    }
+
+    /// Two contacts are equivalent if they have the same name and email, regardless of their
+    /// `bom-ref` and phone number.
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.name == other.name && self.email == other.email
+    }
 }
 
 impl Validate for OrganizationalContact {
@@ -70,7 +97,8 @@ impl Validate for OrganizationalContact {
 /// Represents an organization with name, url, and contact information
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_organizationalEntity)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrganizationalEntity {
     pub bom_ref: Option<BomReference>,
     pub name: Option<NormalizedString>,
@@ -87,6 +115,103 @@ impl OrganizationalEntity {
             contact: None,
         }
     }
+
+    /// Returns the entity with the given `bom-ref`.
+    pub fn with_bom_ref(mut self, bom_ref: impl ToString) -> Self {
+        self.bom_ref = Some(BomReference::new(bom_ref));
+        self
+    }
+
+    /// Returns the entity with the url added to its urls.
+    pub fn with_url(mut self, url: Uri) -> Self {
+        self.url.get_or_insert_with(Vec::new).push(url);
+        self
+    }
+
+    /// Returns the entity with the contact added to its contacts.
+    pub fn with_contact(mut self, contact: OrganizationalContact) -> Self {
+        self.contact.get_or_insert_with(Vec::new).push(contact);
+        self
+    }
+
+    /// Two entities are equivalent if they have the same name and urls, regardless of their
+    /// `bom-ref` and contacts.
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.name == other.name && self.url == other.url
+    }
+}
+
+/// Unifies equivalent entities, see [`OrganizationalEntity::is_equivalent`].
+///
+/// The first entity of a group of equivalent entities is kept and receives the contacts of the
+/// others, equivalent contacts are unified as well. The returned map contains the `bom-ref`s that
+/// were removed together with the `bom-ref` that replaces them, so references to them can be
+/// rewritten consistently.
+pub fn dedup_entities(
+    entities: &mut Vec<OrganizationalEntity>,
+) -> HashMap<BomReference, BomReference> {
+    let mut replaced = HashMap::new();
+    let mut unique: Vec<OrganizationalEntity> = Vec::with_capacity(entities.len());
+
+    for entity in entities.drain(..) {
+        let Some(existing) = unique.iter_mut().find(|e| e.is_equivalent(&entity)) else {
+            unique.push(entity);
+            continue;
+        };
+
+        match (&existing.bom_ref, entity.bom_ref) {
+            (None, bom_ref) => existing.bom_ref = bom_ref,
+            (Some(kept), Some(bom_ref)) if *kept != bom_ref => {
+                replaced.insert(bom_ref, kept.clone());
+            }
+            _ => (),
+        }
+
+        for contact in entity.contact.into_iter().flatten() {
+            existing.contact.get_or_insert_with(Vec::new).push(contact);
+        }
+    }
+
+    for entity in &mut unique {
+        if let Some(contacts) = &mut entity.contact {
+            replaced.extend(dedup_contacts(contacts));
+        }
+    }
+
+    *entities = unique;
+    replaced
+}
+
+/// Unifies equivalent contacts, see [`OrganizationalContact::is_equivalent`].
+///
+/// Works like [`dedup_entities`], missing phone numbers are taken from the removed contacts.
+pub fn dedup_contacts(
+    contacts: &mut Vec<OrganizationalContact>,
+) -> HashMap<BomReference, BomReference> {
+    let mut replaced = HashMap::new();
+    let mut unique: Vec<OrganizationalContact> = Vec::with_capacity(contacts.len());
+
+    for contact in contacts.drain(..) {
+        let Some(existing) = unique.iter_mut().find(|c| c.is_equivalent(&contact)) else {
+            unique.push(contact);
+            continue;
+        };
+
+        match (&existing.bom_ref, contact.bom_ref) {
+            (None, bom_ref) => existing.bom_ref = bom_ref,
+            (Some(kept), Some(bom_ref)) if *kept != bom_ref => {
+                replaced.insert(bom_ref, kept.clone());
+            }
+            _ => (),
+        }
+
+        if existing.phone.is_none() {
+            existing.phone = contact.phone;
+        }
+    }
+
+    *contacts = unique;
+    replaced
 }
 
 impl Validate for OrganizationalEntity {
@@ -108,7 +233,7 @@ mod test {
     use crate::{
         models::{
             bom::BomReference,
-            organization::{OrganizationalContact, OrganizationalEntity},
+            organization::{dedup_entities, OrganizationalContact, OrganizationalEntity},
         },
         prelude::{NormalizedString, Uri, Validate},
         validation,
@@ -256,4 +381,79 @@ mod test {
             .into()
         );
     }
+
+    #[test]
+    fn it_should_build_entities() {
+        let entity = OrganizationalEntity::new("Acme")
+            .with_bom_ref("acme")
+            .with_url(Uri::new("https://example.com"))
+            .with_contact(
+                OrganizationalContact::new("Support", None)
+                    .with_email("support@example.com")
+                    .with_phone("0123456789"),
+            );
+
+        assert_eq!(
+            entity,
+            OrganizationalEntity {
+                bom_ref: Some(BomReference::new("acme")),
+                name: Some(NormalizedString::new("Acme")),
+                url: Some(vec![Uri::new("https://example.com")]),
+                contact: Some(vec![OrganizationalContact {
+                    bom_ref: None,
+                    name: Some(NormalizedString::new("Support")),
+                    email: Some(NormalizedString::new("support@example.com")),
+                    phone: Some(NormalizedString::new("0123456789")),
+                }]),
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_dedup_equivalent_entities() {
+        let mut entities = vec![
+            OrganizationalEntity::new("Acme")
+                .with_bom_ref("acme-1")
+                .with_contact(
+                    OrganizationalContact::new("Support", None).with_bom_ref("support-1"),
+                ),
+            OrganizationalEntity::new("Other"),
+            OrganizationalEntity::new("Acme")
+                .with_bom_ref("acme-2")
+                .with_contact(
+                    OrganizationalContact::new("Support", None)
+                        .with_bom_ref("support-2")
+                        .with_phone("0123456789"),
+                )
+                .with_contact(OrganizationalContact::new("Sales", None)),
+        ];
+
+        let replaced = dedup_entities(&mut entities);
+
+        assert_eq!(
+            entities,
+            vec![
+                OrganizationalEntity::new("Acme")
+                    .with_bom_ref("acme-1")
+                    .with_contact(
+                        OrganizationalContact::new("Support", None)
+                            .with_bom_ref("support-1")
+                            .with_phone("0123456789")
+                    )
+                    .with_contact(OrganizationalContact::new("Sales", None)),
+                OrganizationalEntity::new("Other"),
+            ]
+        );
+        assert_eq!(
+            replaced,
+            [
+                (BomReference::new("acme-2"), BomReference::new("acme-1")),
+                (
+                    BomReference::new("support-2"),
+                    BomReference::new("support-1")
+                ),
+            ]
+            .into()
+        );
+    }
 }
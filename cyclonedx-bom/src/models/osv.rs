@@ -0,0 +1,629 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Conversion of [OSV](https://ossf.github.io/osv-schema/) advisories into [`Vulnerability`] entries.
+//!
+//! The id, aliases, summary, details, dates, references of type `ADVISORY`, credits and CWE ids
+//! (`database_specific.cwe_ids`) of an advisory are mapped to the corresponding fields of the
+//! vulnerability. Each CVSS vector becomes a rating, with the base score and severity calculated
+//! for CVSS v3 vectors.
+//!
+//! The affected packages become the targets of the vulnerability. Since an advisory does not
+//! know the BOM it is applied to, the `bom-ref` of a target is the purl of the affected package,
+//! either taken from the advisory or derived from its ecosystem and name, and may have to be
+//! mapped to the `bom-ref`s of the BOM, e.g. with a
+//! [`BomIndex`](crate::models::bom_index::BomIndex). The affected versions and the `SEMVER` and
+//! `ECOSYSTEM` ranges are added as versions of the target, with the ranges written as
+//! [vers](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
+//! ranges. `GIT` ranges cannot be expressed as versions and are ignored.
+
+use std::convert::TryFrom;
+
+use serde::Deserialize;
+
+use crate::errors::OsvReadError;
+use crate::external_models::date_time::DateTime;
+use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::uri::Uri;
+use crate::models::advisory::{Advisories, Advisory};
+use crate::models::organization::OrganizationalContact;
+use crate::models::vulnerability::{Vulnerabilities, Vulnerability};
+use crate::models::vulnerability_credits::VulnerabilityCredits;
+use crate::models::vulnerability_rating::{
+    Score, ScoreMethod, Severity, VulnerabilityRating, VulnerabilityRatings,
+};
+use crate::models::vulnerability_reference::{VulnerabilityReference, VulnerabilityReferences};
+use crate::models::vulnerability_source::VulnerabilitySource;
+use crate::models::vulnerability_target::{
+    Status, Version, VersionRange, Versions, VulnerabilityTarget, VulnerabilityTargets,
+};
+
+impl Vulnerability {
+    /// Parses a single OSV advisory in JSON format.
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::Vulnerability;
+    ///
+    /// let advisory = r#"{
+    ///     "id": "RUSTSEC-2021-0001",
+    ///     "modified": "2021-01-01T00:00:00Z",
+    ///     "affected": [{
+    ///         "package": { "ecosystem": "crates.io", "name": "example" },
+    ///         "ranges": [{ "type": "SEMVER", "events": [{ "introduced": "0" }, { "fixed": "1.2.3" }] }]
+    ///     }]
+    /// }"#;
+    /// let vulnerability = Vulnerability::from_osv_json(advisory.as_bytes()).unwrap();
+    ///
+    /// let target = &vulnerability.vulnerability_targets.unwrap().0[0];
+    /// assert_eq!(target.bom_ref, "pkg:cargo/example");
+    /// assert_eq!(
+    ///     target.versions.as_ref().unwrap().0[0].version_range.to_string(),
+    ///     "vers:semver/<1.2.3"
+    /// );
+    /// ```
+    pub fn from_osv_json<R: std::io::Read>(reader: R) -> Result<Self, OsvReadError> {
+        let advisory: OsvAdvisory = serde_json::from_reader(reader)?;
+        Ok(advisory.into())
+    }
+}
+
+impl Vulnerabilities {
+    /// Parses a list of OSV advisories in JSON format, either as an array of advisories or
+    /// as the response of the OSV API with the advisories in the `vulns` field.
+    pub fn from_osv_json<R: std::io::Read>(reader: R) -> Result<Self, OsvReadError> {
+        let advisories: OsvAdvisories = serde_json::from_reader(reader)?;
+        let advisories = match advisories {
+            OsvAdvisories::List(advisories) => advisories,
+            OsvAdvisories::Response { vulns } => vulns,
+        };
+        Ok(Self(advisories.into_iter().map(Into::into).collect()))
+    }
+}
+
+impl From<OsvAdvisory> for Vulnerability {
+    fn from(advisory: OsvAdvisory) -> Self {
+        let mut vulnerability = Vulnerability::new(None);
+
+        vulnerability.vulnerability_source = Some(VulnerabilitySource::new(
+            Some("OSV".to_string()),
+            Some(Uri::new(&format!(
+                "https://osv.dev/vulnerability/{}",
+                advisory.id
+            ))),
+        ));
+        vulnerability.id = Some(NormalizedString::new(&advisory.id));
+
+        let references = advisory
+            .aliases
+            .iter()
+            .map(|alias| VulnerabilityReference::new(alias, alias_source(alias)))
+            .collect::<Vec<_>>();
+        if !references.is_empty() {
+            vulnerability.vulnerability_references = Some(VulnerabilityReferences(references));
+        }
+
+        let ratings = advisory
+            .severity
+            .iter()
+            .filter_map(rating)
+            .collect::<Vec<_>>();
+        if !ratings.is_empty() {
+            vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(ratings));
+        }
+
+        let cwes = advisory
+            .database_specific
+            .as_ref()
+            .and_then(|database_specific| database_specific.get("cwe_ids"))
+            .and_then(serde_json::Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|cwe| cwe.as_str()?.strip_prefix("CWE-")?.parse().ok())
+            .collect::<Vec<u32>>();
+        if !cwes.is_empty() {
+            vulnerability.cwes = Some(cwes);
+        }
+
+        vulnerability.description = advisory.summary;
+        vulnerability.detail = advisory.details;
+        vulnerability.published = advisory.published.and_then(|d| DateTime::try_from(d).ok());
+        vulnerability.updated = advisory.modified.and_then(|d| DateTime::try_from(d).ok());
+        vulnerability.rejected = advisory.withdrawn.and_then(|d| DateTime::try_from(d).ok());
+
+        let advisories = advisory
+            .references
+            .iter()
+            .filter(|reference| reference.reference_type == "ADVISORY")
+            .map(|reference| Advisory::new(Uri::new(&reference.url)))
+            .collect::<Vec<_>>();
+        if !advisories.is_empty() {
+            vulnerability.advisories = Some(Advisories(advisories));
+        }
+
+        let individuals = advisory
+            .credits
+            .iter()
+            .map(|credit| OrganizationalContact::new(&credit.name, None))
+            .collect::<Vec<_>>();
+        if !individuals.is_empty() {
+            vulnerability.vulnerability_credits = Some(VulnerabilityCredits {
+                organizations: None,
+                individuals: Some(individuals),
+            });
+        }
+
+        let targets = advisory
+            .affected
+            .iter()
+            .filter_map(target)
+            .collect::<Vec<_>>();
+        if !targets.is_empty() {
+            vulnerability.vulnerability_targets = Some(VulnerabilityTargets(targets));
+        }
+
+        vulnerability
+    }
+}
+
+/// Returns the source of an alias based on the well-known prefixes of vulnerability ids.
+fn alias_source(alias: &str) -> VulnerabilitySource {
+    let (name, url) = match alias.split('-').next() {
+        Some("CVE") => ("NVD", format!("https://nvd.nist.gov/vuln/detail/{alias}")),
+        Some("GHSA") => ("GitHub", format!("https://github.com/advisories/{alias}")),
+        Some("RUSTSEC") => ("RustSec", format!("https://rustsec.org/advisories/{alias}")),
+        _ => ("OSV", format!("https://osv.dev/vulnerability/{alias}")),
+    };
+    VulnerabilitySource::new(Some(name.to_string()), Some(Uri::new(&url)))
+}
+
+fn rating(severity: &OsvSeverity) -> Option<VulnerabilityRating> {
+    let vector = severity.score.as_str();
+    let (score_method, score) = match severity.severity_type.as_str() {
+        "CVSS_V2" => (ScoreMethod::CVSSv2, None),
+        "CVSS_V3" if vector.starts_with("CVSS:3.1/") => {
+            (ScoreMethod::CVSSv31, cvss_v3_base_score(vector))
+        }
+        "CVSS_V3" => (ScoreMethod::CVSSv3, cvss_v3_base_score(vector)),
+        "CVSS_V4" => (ScoreMethod::CVSSv4, None),
+        _ => return None,
+    };
+
+    let mut rating = VulnerabilityRating::new(
+        score.and_then(Score::from_f32),
+        score.map(cvss_severity),
+        Some(score_method),
+    );
+    rating.vector = Some(NormalizedString::new(vector));
+    Some(rating)
+}
+
+/// Calculates the base score of a CVSS v3.0 or v3.1 vector.
+///
+/// See the [CVSS v3.1 specification](https://www.first.org/cvss/v3.1/specification-document#7-1-Base-Metrics-Equations).
+fn cvss_v3_base_score(vector: &str) -> Option<f32> {
+    let mut metrics = std::collections::HashMap::new();
+    for metric in vector.split('/').skip(1) {
+        let (name, value) = metric.split_once(':')?;
+        metrics.insert(name, value);
+    }
+    let scope_changed = match *metrics.get("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+
+    let attack_vector = match *metrics.get("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let attack_complexity = match *metrics.get("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let privileges_required = match (*metrics.get("PR")?, scope_changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let user_interaction = match *metrics.get("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+    let impact = |name| match *metrics.get(name)? {
+        "H" => Some(0.56),
+        "L" => Some(0.22),
+        "N" => Some(0.0),
+        _ => None,
+    };
+
+    let impact_sub_score = 1.0 - (1.0 - impact("C")?) * (1.0 - impact("I")?) * (1.0 - impact("A")?);
+    let impact = match scope_changed {
+        false => 6.42 * impact_sub_score,
+        true => 7.52 * (impact_sub_score - 0.029) - 3.25 * f64::powi(impact_sub_score - 0.02, 15),
+    };
+    let exploitability =
+        8.22 * attack_vector * attack_complexity * privileges_required * user_interaction;
+
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let score = match scope_changed {
+        false => f64::min(impact + exploitability, 10.0),
+        true => f64::min(1.08 * (impact + exploitability), 10.0),
+    };
+    Some(round_up(score) as f32)
+}
+
+/// Rounds up to one decimal place as defined in Appendix A of the CVSS v3.1 specification.
+fn round_up(value: f64) -> f64 {
+    let value = (value * 100_000.0).round() as i64;
+    match value % 10_000 {
+        0 => value as f64 / 100_000.0,
+        _ => ((value / 10_000) + 1) as f64 / 10.0,
+    }
+}
+
+fn cvss_severity(score: f32) -> Severity {
+    match score {
+        score if score >= 9.0 => Severity::Critical,
+        score if score >= 7.0 => Severity::High,
+        score if score >= 4.0 => Severity::Medium,
+        score if score > 0.0 => Severity::Low,
+        _ => Severity::None,
+    }
+}
+
+fn target(affected: &OsvAffected) -> Option<VulnerabilityTarget> {
+    let package = affected.package.as_ref()?;
+    let purl_type = purl_type(&package.ecosystem);
+    let bom_ref = match (&package.purl, purl_type) {
+        (Some(purl), _) => purl.clone(),
+        (None, Some(purl_type)) => format!("pkg:{purl_type}/{}", package.name),
+        (None, None) => format!("{}/{}", package.ecosystem, package.name),
+    };
+
+    let mut versions = affected
+        .versions
+        .iter()
+        .map(|version| Version {
+            version_range: VersionRange::Version(NormalizedString::new(version)),
+            status: Status::Affected,
+        })
+        .collect::<Vec<_>>();
+    for range in &affected.ranges {
+        let scheme = match range.range_type.as_str() {
+            "SEMVER" => "semver",
+            "ECOSYSTEM" => match purl_type {
+                Some(purl_type) => purl_type,
+                None => continue,
+            },
+            _ => continue,
+        };
+        versions.push(Version {
+            version_range: VersionRange::Range(NormalizedString::new(&vers_range(
+                scheme,
+                &range.events,
+            ))),
+            status: Status::Affected,
+        });
+    }
+
+    let mut target = VulnerabilityTarget::new(bom_ref);
+    if !versions.is_empty() {
+        target.versions = Some(Versions(versions));
+    }
+    Some(target)
+}
+
+/// Builds a vers range from the events of an OSV range, each `introduced` event starts an
+/// affected interval which ends with the following `fixed` or `last_affected` event.
+fn vers_range(scheme: &str, events: &[OsvEvent]) -> String {
+    let constraints = events
+        .iter()
+        .filter_map(|event| match event {
+            OsvEvent::Introduced(version) if version == "0" => None,
+            OsvEvent::Introduced(version) => Some(format!(">={version}")),
+            OsvEvent::Fixed(version) => Some(format!("<{version}")),
+            OsvEvent::LastAffected(version) => Some(format!("<={version}")),
+            OsvEvent::Limit(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    match constraints.is_empty() {
+        true => format!("vers:{scheme}/*"),
+        false => format!("vers:{scheme}/{}", constraints.join("|")),
+    }
+}
+
+/// Returns the purl type of an [OSV ecosystem](https://ossf.github.io/osv-schema/#affectedpackage-field).
+fn purl_type(ecosystem: &str) -> Option<&'static str> {
+    // Ecosystems may have a suffix, e.g. the release of a Linux distribution
+    match ecosystem.split(':').next() {
+        Some("crates.io") => Some("cargo"),
+        Some("npm") => Some("npm"),
+        Some("PyPI") => Some("pypi"),
+        Some("Go") => Some("golang"),
+        Some("Maven") => Some("maven"),
+        Some("RubyGems") => Some("gem"),
+        Some("NuGet") => Some("nuget"),
+        Some("Packagist") => Some("composer"),
+        Some("Hex") => Some("hex"),
+        Some("Pub") => Some("pub"),
+        Some("Hackage") => Some("hackage"),
+        Some("SwiftURL") => Some("swift"),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OsvAdvisories {
+    List(Vec<OsvAdvisory>),
+    Response { vulns: Vec<OsvAdvisory> },
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvAdvisory {
+    id: String,
+    modified: Option<String>,
+    published: Option<String>,
+    withdrawn: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    summary: Option<String>,
+    details: Option<String>,
+    #[serde(default)]
+    severity: Vec<OsvSeverity>,
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+    #[serde(default)]
+    references: Vec<OsvReference>,
+    #[serde(default)]
+    credits: Vec<OsvCredit>,
+    database_specific: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvSeverity {
+    #[serde(rename = "type")]
+    severity_type: String,
+    score: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvAffected {
+    package: Option<OsvPackage>,
+    #[serde(default)]
+    ranges: Vec<OsvRange>,
+    #[serde(default)]
+    versions: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvPackage {
+    ecosystem: String,
+    name: String,
+    purl: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvRange {
+    #[serde(rename = "type")]
+    range_type: String,
+    #[serde(default)]
+    events: Vec<OsvEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum OsvEvent {
+    Introduced(String),
+    Fixed(String),
+    LastAffected(String),
+    Limit(serde::de::IgnoredAny),
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvReference {
+    #[serde(rename = "type")]
+    reference_type: String,
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvCredit {
+    name: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    const ADVISORY: &str = r#"{
+        "id": "GHSA-aaaa-bbbb-cccc",
+        "modified": "2023-05-06T07:08:09Z",
+        "published": "2023-01-02T03:04:05Z",
+        "aliases": ["CVE-2023-0001"],
+        "summary": "Example vulnerability",
+        "details": "More details",
+        "severity": [{ "type": "CVSS_V3", "score": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H" }],
+        "affected": [
+            {
+                "package": { "ecosystem": "crates.io", "name": "example" },
+                "ranges": [
+                    {
+                        "type": "SEMVER",
+                        "events": [
+                            { "introduced": "0" },
+                            { "fixed": "1.0.1" },
+                            { "introduced": "2.0.0" },
+                            { "last_affected": "2.1.0" }
+                        ]
+                    },
+                    { "type": "GIT", "repo": "https://github.com/example/example", "events": [{ "introduced": "abc" }] }
+                ],
+                "versions": ["1.0.0"]
+            },
+            {
+                "package": { "ecosystem": "npm", "name": "other", "purl": "pkg:npm/%40scope/other" },
+                "ranges": [{ "type": "ECOSYSTEM", "events": [{ "introduced": "1.2.0" }] }]
+            }
+        ],
+        "references": [
+            { "type": "ADVISORY", "url": "https://github.com/advisories/GHSA-aaaa-bbbb-cccc" },
+            { "type": "WEB", "url": "https://example.com" }
+        ],
+        "credits": [{ "name": "Jane Doe" }],
+        "database_specific": { "cwe_ids": ["CWE-79", "CWE-89"] }
+    }"#;
+
+    #[test]
+    fn it_should_convert_an_osv_advisory() {
+        let vulnerability = Vulnerability::from_osv_json(ADVISORY.as_bytes()).unwrap();
+
+        assert_eq!(
+            vulnerability.id,
+            Some(NormalizedString::new("GHSA-aaaa-bbbb-cccc"))
+        );
+        assert_eq!(
+            vulnerability.vulnerability_references,
+            Some(VulnerabilityReferences(vec![VulnerabilityReference::new(
+                "CVE-2023-0001",
+                VulnerabilitySource::new(
+                    Some("NVD".to_string()),
+                    Some(Uri::new("https://nvd.nist.gov/vuln/detail/CVE-2023-0001"))
+                )
+            )]))
+        );
+        assert_eq!(
+            vulnerability.description,
+            Some("Example vulnerability".to_string())
+        );
+        assert_eq!(vulnerability.cwes, Some(vec![79, 89]));
+        assert_eq!(
+            vulnerability.published,
+            Some(DateTime::try_from("2023-01-02T03:04:05Z".to_string()).unwrap())
+        );
+        assert_eq!(
+            vulnerability.advisories,
+            Some(Advisories(vec![Advisory::new(Uri::new(
+                "https://github.com/advisories/GHSA-aaaa-bbbb-cccc"
+            ))]))
+        );
+        assert_eq!(
+            vulnerability.vulnerability_credits,
+            Some(VulnerabilityCredits {
+                organizations: None,
+                individuals: Some(vec![OrganizationalContact::new("Jane Doe", None)]),
+            })
+        );
+
+        let mut rating = VulnerabilityRating::new(
+            Score::from_f32(9.8),
+            Some(Severity::Critical),
+            Some(ScoreMethod::CVSSv31),
+        );
+        rating.vector = Some(NormalizedString::new(
+            "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        ));
+        assert_eq!(
+            vulnerability.vulnerability_ratings,
+            Some(VulnerabilityRatings(vec![rating]))
+        );
+
+        let targets = vulnerability
+            .vulnerability_targets
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|target| {
+                let versions = target
+                    .versions
+                    .unwrap()
+                    .0
+                    .into_iter()
+                    .map(|version| version.version_range.to_string())
+                    .collect::<Vec<_>>();
+                (target.bom_ref, versions)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            targets,
+            vec![
+                (
+                    "pkg:cargo/example".to_string(),
+                    vec![
+                        "1.0.0".to_string(),
+                        "vers:semver/<1.0.1|>=2.0.0|<=2.1.0".to_string()
+                    ]
+                ),
+                (
+                    "pkg:npm/%40scope/other".to_string(),
+                    vec!["vers:npm/>=1.2.0".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_convert_a_list_of_osv_advisories() {
+        let list = format!("[{ADVISORY}]");
+        let response = format!(r#"{{ "vulns": [{ADVISORY}, {ADVISORY}] }}"#);
+
+        assert_eq!(
+            Vulnerabilities::from_osv_json(list.as_bytes())
+                .unwrap()
+                .0
+                .len(),
+            1
+        );
+        assert_eq!(
+            Vulnerabilities::from_osv_json(response.as_bytes())
+                .unwrap()
+                .0
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn it_should_calculate_cvss_v3_base_scores() {
+        assert_eq!(
+            cvss_v3_base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"),
+            Some(9.8)
+        );
+        assert_eq!(
+            cvss_v3_base_score("CVSS:3.0/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"),
+            Some(6.1)
+        );
+        assert_eq!(
+            cvss_v3_base_score("CVSS:3.1/AV:L/AC:H/PR:H/UI:R/S:U/C:N/I:N/A:N"),
+            Some(0.0)
+        );
+        assert_eq!(cvss_v3_base_score("CVSS:3.1/AV:X"), None);
+    }
+}
@@ -28,9 +28,73 @@ use super::bom::SpecVersion;
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_propertyType). Please see the
 /// [CycloneDX use case](https://cyclonedx.org/use-cases/#properties--name-value-store) for more information and examples.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Properties(pub Vec<Property>);
 
+impl Properties {
+    /// Returns the value of the first property with the given name.
+    /// ```
+    /// use cyclonedx_bom::models::property::{Properties, Property};
+    ///
+    /// let properties = Properties(vec![Property::new("cdx:npm:package:private", "true")]);
+    /// assert_eq!(properties.get("cdx:npm:package:private").map(|v| v.to_string()), Some("true".to_string()));
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&NormalizedString> {
+        self.0
+            .iter()
+            .find(|property| property.name == name)
+            .map(|property| &property.value)
+    }
+
+    /// Returns all properties whose name starts with the given prefix.
+    pub fn get_all<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a Property> + 'a {
+        self.0
+            .iter()
+            .filter(move |property| property.name.starts_with(prefix))
+    }
+
+    /// Sets the value of the first property with the given name, or appends a new property.
+    ///
+    /// Returns the previous value, if there was one.
+    pub fn insert(&mut self, name: impl ToString, value: &str) -> Option<NormalizedString> {
+        let name = name.to_string();
+        match self.0.iter_mut().find(|property| property.name == name) {
+            Some(property) => Some(std::mem::replace(
+                &mut property.value,
+                NormalizedString::new(value),
+            )),
+            None => {
+                self.0.push(Property::new(name, value));
+                None
+            }
+        }
+    }
+
+    /// Returns the properties that use the official `cdx:` taxonomy.
+    ///
+    /// See the [CycloneDX Property Taxonomy](https://github.com/CycloneDX/cyclonedx-property-taxonomy).
+    pub fn taxonomy(&self) -> impl Iterator<Item = TaxonomyProperty<'_>> {
+        self.0.iter().filter_map(TaxonomyProperty::parse)
+    }
+
+    /// Returns the names that are used by more than one property.
+    ///
+    /// Duplicate names are allowed by the specification, so they are not reported by validation,
+    /// but they are usually unintended.
+    pub fn duplicate_names(&self) -> Vec<&str> {
+        let mut seen = std::collections::HashSet::new();
+        let mut duplicates = Vec::new();
+        for property in &self.0 {
+            if !seen.insert(property.name.as_str()) && !duplicates.contains(&property.name.as_str())
+            {
+                duplicates.push(property.name.as_str());
+            }
+        }
+        duplicates
+    }
+}
+
 impl Validate for Properties {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -44,7 +108,8 @@ impl Validate for Properties {
 /// Represents an individual property with a name and value
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_propertyType)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Property {
     pub name: String,
     pub value: NormalizedString,
@@ -73,10 +138,73 @@ impl Validate for Property {
     }
 }
 
+/// A property whose name is in one of the namespaces of the official `cdx:` taxonomy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TaxonomyProperty<'a> {
+    pub namespace: TaxonomyNamespace,
+    /// The remainder of the name after the namespace, e.g. `package:private` for `cdx:npm:package:private`
+    pub name: &'a str,
+    pub value: &'a NormalizedString,
+}
+
+impl<'a> TaxonomyProperty<'a> {
+    fn parse(property: &'a Property) -> Option<Self> {
+        let name = property.name.strip_prefix("cdx:")?;
+        let (namespace, name) = name.split_once(':').unwrap_or((name, ""));
+        Some(Self {
+            namespace: TaxonomyNamespace::new_unchecked(namespace),
+            name,
+            value: &property.value,
+        })
+    }
+}
+
+/// The namespaces registered in the [CycloneDX Property Taxonomy](https://github.com/CycloneDX/cyclonedx-property-taxonomy).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "kebab-case")]
+pub enum TaxonomyNamespace {
+    Composer,
+    Device,
+    Gomod,
+    Maven,
+    Npm,
+    Pipenv,
+    Poetry,
+    Python,
+    Reproducible,
+    Rush,
+    Swift,
+    #[doc(hidden)]
+    #[strum(default)]
+    UnknownNamespace(String),
+}
+
+impl TaxonomyNamespace {
+    pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "composer" => Self::Composer,
+            "device" => Self::Device,
+            "gomod" => Self::Gomod,
+            "maven" => Self::Maven,
+            "npm" => Self::Npm,
+            "pipenv" => Self::Pipenv,
+            "poetry" => Self::Poetry,
+            "python" => Self::Python,
+            "reproducible" => Self::Reproducible,
+            "rush" => Self::Rush,
+            "swift" => Self::Swift,
+            unknown => Self::UnknownNamespace(unknown.to_string()),
+        }
+    }
+}
+
+from_str_via_new_unchecked!(TaxonomyNamespace, UnknownNamespace, "taxonomy namespace");
+
 #[cfg(test)]
 mod test {
     use crate::{
-        models::property::{Properties, Property},
+        models::property::{Properties, Property, TaxonomyNamespace, TaxonomyProperty},
         prelude::NormalizedString,
         validation,
     };
@@ -116,4 +244,75 @@ mod test {
             ),
         );
     }
+
+    #[test]
+    fn it_should_look_up_properties() {
+        let mut properties = Properties(vec![
+            Property::new("cdx:npm:package:private", "true"),
+            Property::new("cdx:npm:package:development", "false"),
+            Property::new("internal:team", "a"),
+        ]);
+
+        assert_eq!(
+            properties.get("internal:team"),
+            Some(&NormalizedString::new("a"))
+        );
+        assert_eq!(properties.get("missing"), None);
+        assert_eq!(
+            properties
+                .get_all("cdx:npm:")
+                .map(|property| property.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["cdx:npm:package:private", "cdx:npm:package:development"]
+        );
+
+        assert_eq!(
+            properties.insert("internal:team", "b"),
+            Some(NormalizedString::new("a"))
+        );
+        assert_eq!(properties.insert("internal:owner", "c"), None);
+        assert_eq!(
+            properties.get("internal:team"),
+            Some(&NormalizedString::new("b"))
+        );
+        assert_eq!(properties.0.len(), 4);
+    }
+
+    #[test]
+    fn it_should_provide_taxonomy_properties() {
+        let properties = Properties(vec![
+            Property::new("cdx:npm:package:private", "true"),
+            Property::new("cdx:unregistered:name", "value"),
+            Property::new("internal:team", "a"),
+        ]);
+
+        assert_eq!(
+            properties.taxonomy().collect::<Vec<_>>(),
+            vec![
+                TaxonomyProperty {
+                    namespace: TaxonomyNamespace::Npm,
+                    name: "package:private",
+                    value: &NormalizedString::new("true"),
+                },
+                TaxonomyProperty {
+                    namespace: TaxonomyNamespace::UnknownNamespace("unregistered".to_string()),
+                    name: "name",
+                    value: &NormalizedString::new("value"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_find_duplicate_names() {
+        let properties = Properties(vec![
+            Property::new("a", "1"),
+            Property::new("b", "2"),
+            Property::new("a", "3"),
+            Property::new("a", "4"),
+        ]);
+
+        assert_eq!(properties.duplicate_names(), vec!["a"]);
+        assert!(properties.validate().passed());
+    }
 }
@@ -0,0 +1,374 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::HashSet;
+
+use crate::models::bom::{Bom, BomReference};
+use crate::models::component::{Component, Components};
+use crate::models::dependency::Dependency;
+use crate::models::property::Properties;
+use crate::models::service::Services;
+
+/// Removes all components, including their nested components, for which the predicate returns
+/// `false` and returns the bom-refs of the removed components.
+///
+/// The dependencies and compositions are rewritten, so that they don't reference the removed
+/// components anymore. Dependencies of a removed component are passed on to its dependents, so
+/// the remaining graph stays connected.
+pub(crate) fn filter_components<P>(bom: &mut Bom, mut predicate: P) -> HashSet<String>
+where
+    P: FnMut(&Component) -> bool,
+{
+    let mut removed = HashSet::new();
+    if let Some(components) = &mut bom.components {
+        retain_components(components, &mut predicate, &mut removed);
+    }
+    if !removed.is_empty() {
+        remove_references(bom, &removed);
+    }
+    removed
+}
+
+fn retain_components<P>(
+    components: &mut Components,
+    predicate: &mut P,
+    removed: &mut HashSet<String>,
+) where
+    P: FnMut(&Component) -> bool,
+{
+    components.0.retain_mut(|component| {
+        if predicate(component) {
+            if let Some(components) = &mut component.components {
+                retain_components(components, predicate, removed);
+            }
+            true
+        } else {
+            collect_bom_refs(component, removed);
+            false
+        }
+    });
+}
+
+fn collect_bom_refs(component: &Component, bom_refs: &mut HashSet<String>) {
+    if let Some(bom_ref) = &component.bom_ref {
+        bom_refs.insert(bom_ref.clone());
+    }
+    for component in component.components.iter().flat_map(|c| &c.0) {
+        collect_bom_refs(component, bom_refs);
+    }
+}
+
+fn remove_references(bom: &mut Bom, removed: &HashSet<String>) {
+    if let Some(dependencies) = &mut bom.dependencies {
+        let removed_dependencies: Vec<Dependency> = dependencies
+            .0
+            .iter()
+            .filter(|d| removed.contains(&d.dependency_ref))
+            .cloned()
+            .collect();
+        dependencies
+            .0
+            .retain(|d| !removed.contains(&d.dependency_ref));
+
+        for dependency in &mut dependencies.0 {
+            let mut rewritten = Vec::new();
+            let mut pending: Vec<String> = dependency.dependencies.drain(..).rev().collect();
+            let mut visited = HashSet::new();
+            while let Some(dependency_ref) = pending.pop() {
+                if !visited.insert(dependency_ref.clone()) {
+                    continue;
+                }
+                if removed.contains(&dependency_ref) {
+                    let bridged = removed_dependencies
+                        .iter()
+                        .filter(|d| d.dependency_ref == dependency_ref)
+                        .flat_map(|d| d.dependencies.iter().rev().cloned());
+                    pending.extend(bridged);
+                } else {
+                    rewritten.push(dependency_ref);
+                }
+            }
+            dependency.dependencies = rewritten;
+        }
+    }
+
+    if let Some(compositions) = &mut bom.compositions {
+        let retain = |references: &mut Option<Vec<BomReference>>| {
+            if let Some(references) = references {
+                references.retain(|BomReference(r)| !removed.contains(r));
+            }
+        };
+        for composition in &mut compositions.0 {
+            retain(&mut composition.assemblies);
+            retain(&mut composition.dependencies);
+        }
+    }
+}
+
+type ComponentPredicate = Box<dyn Fn(&Component) -> bool>;
+
+/// Removes sensitive information from a BOM before sharing it.
+///
+/// ```
+/// use cyclonedx_bom::models::redaction::Redactor;
+/// use cyclonedx_bom::prelude::*;
+///
+/// let redactor = Redactor::new()
+///     .remove_components(|component| component.name.to_string().starts_with("internal-"))
+///     .remove_property_namespace("acme:internal")
+///     .remove_hashes();
+///
+/// let mut bom = Bom::default();
+/// redactor.redact(&mut bom);
+/// ```
+#[derive(Default)]
+pub struct Redactor {
+    component_predicate: Option<ComponentPredicate>,
+    property_namespaces: Vec<String>,
+    remove_hashes: bool,
+    remove_licenses: bool,
+}
+
+impl Redactor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all components for which the predicate returns `true`, see
+    /// [`Bom::filter_components`].
+    pub fn remove_components<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&Component) -> bool + 'static,
+    {
+        self.component_predicate = Some(Box::new(predicate));
+        self
+    }
+
+    /// Removes all properties in the given namespace, e.g. `acme:internal` removes
+    /// `acme:internal` and `acme:internal:owner`, but not `acme:internals`.
+    pub fn remove_property_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.property_namespaces.push(namespace.into());
+        self
+    }
+
+    /// Removes the hashes of all components.
+    pub fn remove_hashes(mut self) -> Self {
+        self.remove_hashes = true;
+        self
+    }
+
+    /// Removes the licenses of all components.
+    pub fn remove_licenses(mut self) -> Self {
+        self.remove_licenses = true;
+        self
+    }
+
+    /// Applies the redaction to the BOM and returns the bom-refs of the removed components.
+    pub fn redact(&self, bom: &mut Bom) -> HashSet<String> {
+        let removed = match &self.component_predicate {
+            Some(predicate) => filter_components(bom, |component| !predicate(component)),
+            None => HashSet::new(),
+        };
+
+        self.redact_properties(&mut bom.properties);
+        if let Some(metadata) = &mut bom.metadata {
+            self.redact_properties(&mut metadata.properties);
+            if let Some(component) = &mut metadata.component {
+                self.redact_component(component);
+            }
+        }
+        for component in bom.components.iter_mut().flat_map(|c| &mut c.0) {
+            self.redact_component(component);
+        }
+        if let Some(services) = &mut bom.services {
+            self.redact_services(services);
+        }
+
+        removed
+    }
+
+    fn redact_component(&self, component: &mut Component) {
+        if self.remove_hashes {
+            component.hashes = None;
+        }
+        if self.remove_licenses {
+            component.licenses = None;
+        }
+        self.redact_properties(&mut component.properties);
+        for component in component.components.iter_mut().flat_map(|c| &mut c.0) {
+            self.redact_component(component);
+        }
+    }
+
+    fn redact_services(&self, services: &mut Services) {
+        for service in &mut services.0 {
+            self.redact_properties(&mut service.properties);
+            if let Some(services) = &mut service.services {
+                self.redact_services(services);
+            }
+        }
+    }
+
+    fn redact_properties(&self, properties: &mut Option<Properties>) {
+        if self.property_namespaces.is_empty() {
+            return;
+        }
+        if let Some(inner) = properties {
+            inner.0.retain(|property| {
+                !self.property_namespaces.iter().any(|namespace| {
+                    property
+                        .name
+                        .strip_prefix(namespace.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with(':'))
+                })
+            });
+            if inner.0.is_empty() {
+                *properties = None;
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Redactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Redactor")
+            .field("remove_components", &self.component_predicate.is_some())
+            .field("property_namespaces", &self.property_namespaces)
+            .field("remove_hashes", &self.remove_hashes)
+            .field("remove_licenses", &self.remove_licenses)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::component::Classification;
+    use crate::models::composition::{AggregateType, Composition, Compositions};
+    use crate::models::dependency::Dependencies;
+    use crate::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+    use crate::models::property::Property;
+    use pretty_assertions::assert_eq;
+
+    fn component(name: &str) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(name.to_string()),
+        );
+        component.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA_256,
+            content: HashValue("a".repeat(64)),
+        }]));
+        component.properties = Some(Properties(vec![
+            Property::new("acme:internal:owner", "team-a"),
+            Property::new("acme:internals", "kept"),
+        ]));
+        component
+    }
+
+    fn dependency(from: &str, to: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: from.to_string(),
+            dependencies: to.iter().map(|to| to.to_string()).collect(),
+        }
+    }
+
+    fn example_bom() -> Bom {
+        let mut internal = component("internal-core");
+        internal.components = Some(Components(vec![component("internal-nested")]));
+
+        Bom {
+            components: Some(Components(vec![
+                component("app"),
+                internal,
+                component("serde"),
+            ])),
+            dependencies: Some(Dependencies(vec![
+                dependency("app", &["internal-core"]),
+                dependency("internal-core", &["serde", "internal-nested"]),
+                dependency("internal-nested", &["serde"]),
+                dependency("serde", &[]),
+            ])),
+            compositions: Some(Compositions(vec![Composition {
+                bom_ref: None,
+                aggregate: AggregateType::Complete,
+                assemblies: Some(vec![
+                    BomReference::new("app"),
+                    BomReference::new("internal-core"),
+                ]),
+                dependencies: Some(vec![BomReference::new("internal-nested")]),
+                vulnerabilities: None,
+                signature: None,
+            }])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_filter_components_and_rewrite_references() {
+        let mut bom = example_bom();
+
+        bom.filter_components(|component| !component.name.to_string().starts_with("internal-"));
+
+        assert_eq!(
+            bom.components
+                .as_ref()
+                .unwrap()
+                .0
+                .iter()
+                .map(|c| c.name.to_string())
+                .collect::<Vec<_>>(),
+            vec!["app", "serde"]
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                dependency("app", &["serde"]),
+                dependency("serde", &[]),
+            ]))
+        );
+        let composition = &bom.compositions.as_ref().unwrap().0[0];
+        assert_eq!(composition.assemblies, Some(vec![BomReference::new("app")]));
+        assert_eq!(composition.dependencies, Some(vec![]));
+    }
+
+    #[test]
+    fn it_should_redact_components_properties_and_hashes() {
+        let mut bom = example_bom();
+
+        let removed = Redactor::new()
+            .remove_components(|component| component.name.to_string() == "internal-core")
+            .remove_property_namespace("acme:internal")
+            .remove_hashes()
+            .redact(&mut bom);
+
+        assert_eq!(
+            removed,
+            HashSet::from(["internal-core".to_string(), "internal-nested".to_string()])
+        );
+        for component in &bom.components.as_ref().unwrap().0 {
+            assert_eq!(component.hashes, None);
+            assert_eq!(
+                component.properties,
+                Some(Properties(vec![Property::new("acme:internals", "kept")]))
+            );
+        }
+    }
+}
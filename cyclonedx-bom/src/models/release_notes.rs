@@ -0,0 +1,244 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    external_models::{
+        date_time::{validate_date_time, DateTime},
+        normalized_string::{validate_normalized_string, NormalizedString},
+        uri::{validate_uri, Uri},
+    },
+    validation::{Validate, ValidationContext, ValidationError, ValidationResult},
+};
+
+use super::{attached_text::AttachedText, bom::SpecVersion, code::Issue, property::Properties};
+
+/// Release notes of a component or service, added in version 1.4.
+///
+/// Please see the
+/// [CycloneDX use case](https://cyclonedx.org/use-cases/#release-notes) for details.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReleaseNotes {
+    /// The software versioning type, e.g. `major`, `minor`, `patch`, `pre-release` or `internal`.
+    pub release_type: NormalizedString,
+    pub title: Option<NormalizedString>,
+    pub featured_image: Option<Uri>,
+    pub social_image: Option<Uri>,
+    pub description: Option<NormalizedString>,
+    pub timestamp: Option<DateTime>,
+    pub aliases: Option<Vec<NormalizedString>>,
+    pub tags: Option<Vec<NormalizedString>>,
+    pub resolves: Option<Vec<Issue>>,
+    pub notes: Option<Vec<Note>>,
+    pub properties: Option<Properties>,
+}
+
+impl ReleaseNotes {
+    /// Construct `ReleaseNotes` of the given release type
+    /// ```
+    /// use cyclonedx_bom::models::release_notes::ReleaseNotes;
+    ///
+    /// let release_notes = ReleaseNotes::new("major");
+    /// ```
+    pub fn new(release_type: &str) -> Self {
+        Self {
+            release_type: NormalizedString::new(release_type),
+            title: None,
+            featured_image: None,
+            social_image: None,
+            description: None,
+            timestamp: None,
+            aliases: None,
+            tags: None,
+            resolves: None,
+            notes: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for ReleaseNotes {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_field(
+                "release_type",
+                &self.release_type,
+                validate_normalized_string,
+            )
+            .add_field_option("title", self.title.as_ref(), validate_normalized_string)
+            .add_field_option("featured_image", self.featured_image.as_ref(), validate_uri)
+            .add_field_option("social_image", self.social_image.as_ref(), validate_uri)
+            .add_field_option(
+                "description",
+                self.description.as_ref(),
+                validate_normalized_string,
+            )
+            .add_field_option("timestamp", self.timestamp.as_ref(), validate_date_time)
+            .add_list_option("aliases", self.aliases.as_ref(), validate_normalized_string)
+            .add_list_option("tags", self.tags.as_ref(), validate_normalized_string)
+            .add_list_option("resolves", self.resolves.as_ref(), |issue| {
+                issue.validate_version(version)
+            })
+            .add_list_option("notes", self.notes.as_ref(), |note| {
+                note.validate_version(version)
+            })
+            .add_struct_option("properties", self.properties.as_ref(), version)
+            .into()
+    }
+}
+
+/// A note of the release, optionally in a specific language.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Note {
+    pub locale: Option<Locale>,
+    pub text: AttachedText,
+}
+
+impl Validate for Note {
+    fn validate_version(&self, version: SpecVersion) -> ValidationResult {
+        ValidationContext::new()
+            .add_field_option("locale", self.locale.as_ref(), validate_locale)
+            .add_struct("text", &self.text, version)
+            .into()
+    }
+}
+
+/// A ISO-639 language code, optionally followed by a ISO-3166 country code, e.g. `en-US`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Locale(pub String);
+
+/// Checks if the given [`Locale`] is valid.
+pub fn validate_locale(locale: &Locale) -> Result<(), ValidationError> {
+    static LOCALE_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^([a-z]{2})(-[A-Z]{2})?$").expect("Failed to compile regex."));
+
+    if !LOCALE_REGEX.is_match(&locale.0) {
+        return Err(ValidationError::new(
+            "Locale does not match regular expression",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        models::{
+            attached_text::Encoding,
+            code::{IssueClassification, Source},
+            property::Property,
+        },
+        validation,
+    };
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn note(locale: &str, content_type: &str) -> Note {
+        Note {
+            locale: Some(Locale(locale.to_string())),
+            text: AttachedText {
+                content_type: Some(NormalizedString(content_type.to_string())),
+                encoding: Some(Encoding::Base64),
+                content: "PGgxPk15IG5ldyByZWxlYXNlPGgxPg==".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = ReleaseNotes {
+            release_type: NormalizedString::new("major"),
+            title: Some(NormalizedString::new("My new release")),
+            featured_image: Some(Uri("https://example.com/featured_image.png".to_string())),
+            social_image: Some(Uri("https://example.com/social_image.png".to_string())),
+            description: Some(NormalizedString::new("The main description")),
+            timestamp: Some(DateTime("2021-09-17T00:51:18+00:00".to_string())),
+            aliases: Some(vec![NormalizedString::new("Project Orion")]),
+            tags: Some(vec![NormalizedString::new("CMS")]),
+            resolves: Some(vec![Issue {
+                issue_type: IssueClassification::Security,
+                id: Some(NormalizedString::new("CVE-2019-9997")),
+                name: None,
+                description: None,
+                source: Some(Source {
+                    name: Some(NormalizedString::new("NVD")),
+                    url: Some(Uri(
+                        "https://nvd.nist.gov/vuln/detail/CVE-2019-9997".to_string()
+                    )),
+                }),
+                references: None,
+            }]),
+            notes: Some(vec![note("en-US", "text/html"), note("es", "text/html")]),
+            properties: Some(Properties(vec![Property::new("name", "value")])),
+        }
+        .validate();
+
+        assert!(validation_result.passed());
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = ReleaseNotes {
+            release_type: NormalizedString("major\tminor".to_string()),
+            featured_image: Some(Uri("invalid uri".to_string())),
+            timestamp: Some(DateTime("Thursday".to_string())),
+            notes: Some(vec![note("en_US", "text/html"), note("en", "text/\thtml")]),
+            ..ReleaseNotes::new("major")
+        }
+        .validate();
+
+        assert_eq!(
+            validation_result,
+            vec![
+                validation::field(
+                    "release_type",
+                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                ),
+                validation::field("featured_image", "Uri does not conform to RFC 3986"),
+                validation::field("timestamp", "DateTime does not conform to ISO 8601"),
+                validation::list(
+                    "notes",
+                    [
+                        (
+                            0,
+                            validation::field("locale", "Locale does not match regular expression")
+                        ),
+                        (
+                            1,
+                            validation::r#struct(
+                                "text",
+                                validation::field(
+                                    "content_type",
+                                    "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                )
+                            )
+                        )
+                    ]
+                )
+            ]
+            .into()
+        );
+    }
+}
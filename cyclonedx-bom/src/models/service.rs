@@ -23,6 +23,7 @@ use crate::models::external_reference::ExternalReferences;
 use crate::models::license::Licenses;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
+use crate::models::release_notes::ReleaseNotes;
 use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
 
 use super::bom::SpecVersion;
@@ -32,7 +33,8 @@ use super::signature::Signature;
 /// Represents a service as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#service-definition)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_service)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Service {
     pub bom_ref: Option<String>,
     pub provider: Option<OrganizationalEntity>,
@@ -49,6 +51,8 @@ pub struct Service {
     pub properties: Option<Properties>,
     pub services: Option<Services>,
     /// Added in version 1.4
+    pub release_notes: Option<ReleaseNotes>,
+    /// Added in version 1.4
     pub signature: Option<Signature>,
     /// Added in version 1.5
     pub trust_zone: Option<NormalizedString>,
@@ -77,6 +81,7 @@ impl Service {
             external_references: None,
             properties: None,
             services: None,
+            release_notes: None,
             signature: None,
             trust_zone: None,
         }
@@ -85,7 +90,8 @@ impl Service {
 
 impl Validate for Service {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
-        ValidationContext::new()
+        let mut ctx = ValidationContext::new();
+        ctx.set_bom_ref(self.bom_ref.as_deref())
             .add_struct_option("provider", self.provider.as_ref(), version)
             .add_field_option("group", self.group.as_ref(), validate_normalized_string)
             .add_field("name", &self.name, validate_normalized_string)
@@ -104,17 +110,37 @@ impl Validate for Service {
                 version,
             )
             .add_struct_option("properties", self.properties.as_ref(), version)
-            .add_struct_option("services", self.services.as_ref(), version)
-            .add_field_option(
-                "trust_zone",
-                self.trust_zone.as_ref(),
-                validate_normalized_string,
-            )
-            .into()
+            .add_struct_option("services", self.services.as_ref(), version);
+        if version <= SpecVersion::V1_3 {
+            if self.release_notes.is_some() {
+                ctx.add_custom(
+                    "release_notes",
+                    "Field 'release_notes' not supported in this format version",
+                );
+            }
+        } else {
+            ctx.add_struct_option("release_notes", self.release_notes.as_ref(), version);
+        }
+        ctx.add_field_option("trust_zone", self.trust_zone.as_ref(), |trust_zone| {
+            validate_trust_zone(trust_zone, version)
+        });
+        ctx.into()
+    }
+}
+
+/// Validates the trust zone of a service, which was added in 1.5.
+fn validate_trust_zone(
+    trust_zone: &NormalizedString,
+    version: SpecVersion,
+) -> Result<(), ValidationError> {
+    if version <= SpecVersion::V1_4 {
+        return Err("Field 'trustZone' not supported in this format version".into());
     }
+    validate_normalized_string(trust_zone)
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Services(pub Vec<Service>);
 
 impl Validate for Services {
@@ -127,7 +153,8 @@ impl Validate for Services {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Data {
     ServiceData(Vec<ServiceData>),
     Classification(Vec<DataClassification>),
@@ -146,7 +173,8 @@ impl Validate for Data {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ServiceData {
     pub name: Option<NormalizedString>,
     pub description: Option<NormalizedString>,
@@ -183,7 +211,8 @@ pub fn validate_data_flow_type(data_flow_type: &DataFlowType) -> Result<(), Vali
 /// Represents the data classification and data flow
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_dataClassificationType)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataClassification {
     pub flow: DataFlowType,
     pub classification: NormalizedString,
@@ -205,7 +234,8 @@ impl Validate for DataClassification {
 /// Represents the flow direction of the data
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_dataFlowType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum DataFlowType {
     Inbound,
@@ -229,6 +259,8 @@ impl DataFlowType {
     }
 }
 
+from_str_via_new_unchecked!(DataFlowType, UnknownDataFlow, "data flow type");
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -245,6 +277,40 @@ mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn trust_zone_should_fail_validation_before_1_5() {
+        let service = Service {
+            trust_zone: Some(NormalizedString::new("trust zone")),
+            ..Service::new("name", None)
+        };
+
+        assert!(service.validate_version(SpecVersion::V1_5).passed());
+        assert_eq!(
+            service.validate_version(SpecVersion::V1_4),
+            validation::field(
+                "trust_zone",
+                "Field 'trustZone' not supported in this format version"
+            )
+        );
+    }
+
+    #[test]
+    fn release_notes_should_fail_validation_before_1_4() {
+        let service = Service {
+            release_notes: Some(ReleaseNotes::new("major")),
+            ..Service::new("name", None)
+        };
+
+        assert!(service.validate_version(SpecVersion::V1_4).passed());
+        assert_eq!(
+            service.validate_version(SpecVersion::V1_3),
+            validation::custom(
+                "release_notes",
+                ["Field 'release_notes' not supported in this format version"]
+            )
+        );
+    }
+
     #[test]
     fn valid_services_should_pass_validation() {
         let validation_result = Services(vec![Service {
@@ -275,10 +341,11 @@ mod test {
                 value: NormalizedString::new("value"),
             }])),
             services: Some(Services(vec![])),
+            release_notes: None,
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
             trust_zone: Some("Trust Zone".into()),
         }])
-        .validate();
+        .validate_version(SpecVersion::V1_5);
 
         assert!(validation_result.passed());
     }
@@ -320,10 +387,11 @@ mod test {
                 value: NormalizedString("invalid\tvalue".to_string()),
             }])),
             services: Some(Services(vec![Service::new("invalid\tname", None)])),
+            release_notes: None,
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
             trust_zone: Some("Trust Zone".into()),
         }])
-        .validate();
+        .validate_version(SpecVersion::V1_5);
 
         assert_eq!(
             validation_result,
@@ -22,7 +22,8 @@ use crate::{
 };
 
 /// Enveloped signature in [JSON Signature Format (JSF)](https://cyberphone.github.io/doc/security/jsf.html)
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Signature {
     /// Multiple signatures
     Signers(Vec<Signer>),
@@ -50,7 +51,8 @@ impl Validate for Signature {
 }
 
 /// For now the [`Signer`] struct only holds algorithm and value
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Signer {
     /// Signature algorithm.
     pub algorithm: Algorithm,
@@ -103,7 +105,8 @@ impl Signature {
 }
 
 /// Supported signature algorithms.
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, strum::Display, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Algorithm {
     RS256,
     RS384,
@@ -130,7 +133,6 @@ pub fn validate_algorithm(algorithm: &Algorithm) -> Result<(), ValidationError>
 }
 
 impl Algorithm {
-    #[allow(unused)]
     pub fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
         match value.as_ref() {
             "RS256" => Algorithm::RS256,
@@ -151,3 +153,5 @@ impl Algorithm {
         }
     }
 }
+
+from_str_via_new_unchecked!(Algorithm, Unknown, "algorithm");
@@ -0,0 +1,1290 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Conversion between a [`Bom`] and an [SPDX 2.3](https://spdx.github.io/spdx-spec/v2.3/) JSON document.
+//!
+//! The conversion maps the following elements in both directions:
+//!
+//! - components and the SPDX packages with their name, version, supplier, author (originator),
+//!   description, copyright, declared license, hashes (checksums), purl and CPE
+//! - the `distribution` and `website` external references of a component and the download
+//!   location and homepage of a package
+//! - dependencies and `DEPENDS_ON` / `DEPENDENCY_OF` relationships
+//! - nested components and `CONTAINS` relationships
+//! - the metadata component and the packages the document `DESCRIBES`
+//! - the timestamp, tools, authors and supplier of the metadata and the creation info
+//!
+//! Everything else cannot be represented in the other format and is reported in a
+//! [`LossReport`], e.g. services, vulnerabilities, properties or the scope of a component
+//! when converting to SPDX, and files, snippets, annotations or concluded licenses when
+//! converting from SPDX.
+
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use time::{format_description::well_known::Iso8601, macros::format_description, OffsetDateTime};
+
+use crate::errors::SpdxInteropError;
+use crate::external_models::date_time::DateTime;
+use crate::external_models::normalized_string::NormalizedString;
+use crate::external_models::spdx::SpdxExpression;
+use crate::external_models::uri::{Purl, Uri as Url};
+use crate::models::attached_text::{AttachedText, Encoding};
+use crate::models::bom::{Bom, SpecVersion, UrnUuid};
+use crate::models::component::{Classification, Component, Components, Cpe};
+use crate::models::dependency::{Dependencies, Dependency};
+use crate::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences, Uri,
+};
+use crate::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+use crate::models::license::{License, LicenseChoice, LicenseIdentifier, Licenses};
+use crate::models::metadata::Metadata;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::tool::{Tool, Tools};
+
+const DOCUMENT_ID: &str = "SPDXRef-DOCUMENT";
+const NOASSERTION: &str = "NOASSERTION";
+const NONE: &str = "NONE";
+
+/// The information that was lost while converting between CycloneDX and SPDX
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LossReport {
+    pub losses: Vec<Loss>,
+}
+
+impl LossReport {
+    pub fn is_empty(&self) -> bool {
+        self.losses.is_empty()
+    }
+
+    fn add(&mut self, element: impl Into<String>, field: impl Into<String>) {
+        self.losses.push(Loss {
+            element: element.into(),
+            field: field.into(),
+        });
+    }
+}
+
+/// A field of an element that has no equivalent in the target format
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Loss {
+    /// The element of the source document, e.g. a bom-ref or an SPDX identifier
+    pub element: String,
+    /// The name of the field in the source document
+    pub field: String,
+}
+
+impl std::fmt::Display for Loss {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' of '{}' was not converted",
+            self.field, self.element
+        )
+    }
+}
+
+impl Bom {
+    /// Parses an SPDX 2.x JSON document and converts it into a BOM.
+    ///
+    /// See the [module documentation](crate::models::spdx_interop) for the mapped fields,
+    /// everything else is listed in the returned [`LossReport`].
+    pub fn from_spdx_json<R: std::io::Read>(
+        reader: R,
+    ) -> Result<(Self, LossReport), SpdxInteropError> {
+        let document: SpdxDocument = serde_json::from_reader(reader)?;
+        if !document.spdx_version.starts_with("SPDX-2.") {
+            return Err(SpdxInteropError::UnsupportedSpdxVersion(
+                document.spdx_version,
+            ));
+        }
+        Ok(FromSpdx::default().convert(document))
+    }
+
+    /// Converts the BOM into an SPDX 2.3 JSON document.
+    ///
+    /// See the [module documentation](crate::models::spdx_interop) for the mapped fields,
+    /// everything else is listed in the returned [`LossReport`].
+    pub fn to_spdx_json<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> Result<LossReport, SpdxInteropError> {
+        let (document, report) = ToSpdx::default().convert(self);
+        serde_json::to_writer_pretty(writer, &document)?;
+        Ok(report)
+    }
+}
+
+#[derive(Default)]
+struct ToSpdx {
+    report: LossReport,
+    ids: HashMap<String, String>,
+    used_ids: HashSet<String>,
+    packages: Vec<SpdxPackage>,
+    relationships: Vec<SpdxRelationship>,
+    extracted_licenses: Vec<SpdxExtractedLicense>,
+}
+
+impl ToSpdx {
+    fn convert(mut self, bom: &Bom) -> (SpdxDocument, LossReport) {
+        let metadata = bom.metadata.clone().unwrap_or_default();
+
+        if let Some(component) = &metadata.component {
+            let id = self.add_package(component);
+            self.relate(DOCUMENT_ID, "DESCRIBES", &id);
+        }
+        for component in bom.components.iter().flat_map(|c| c.0.iter()) {
+            let id = self.add_package(component);
+            if metadata.component.is_none() {
+                self.relate(DOCUMENT_ID, "DESCRIBES", &id);
+            }
+        }
+
+        for dependency in bom.dependencies.iter().flat_map(|d| d.0.iter()) {
+            let Some(from) = self.ids.get(&dependency.dependency_ref).cloned() else {
+                self.report.add(&dependency.dependency_ref, "dependencies");
+                continue;
+            };
+            for dependency_ref in &dependency.dependencies {
+                match self.ids.get(dependency_ref).cloned() {
+                    Some(to) => self.relate(&from, "DEPENDS_ON", &to),
+                    None => self.report.add(&dependency.dependency_ref, "dependsOn"),
+                }
+            }
+        }
+
+        self.report_bom_losses(bom, &metadata);
+
+        let name = metadata
+            .component
+            .as_ref()
+            .map(|component| component.name.to_string())
+            .unwrap_or_else(|| "SBOM".to_string());
+        let uuid = bom
+            .serial_number
+            .as_ref()
+            .and_then(|serial_number| serial_number.0.strip_prefix("urn:uuid:"))
+            .map(str::to_string)
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        let document = SpdxDocument {
+            spdx_version: "SPDX-2.3".to_string(),
+            data_license: "CC0-1.0".to_string(),
+            spdx_id: DOCUMENT_ID.to_string(),
+            document_namespace: format!("https://spdx.org/spdxdocs/{name}-{uuid}"),
+            name,
+            creation_info: SpdxCreationInfo {
+                created: spdx_timestamp(metadata.timestamp.as_ref()),
+                creators: creators(&metadata),
+            },
+            document_describes: Vec::new(),
+            packages: self.packages,
+            files: Vec::new(),
+            snippets: Vec::new(),
+            annotations: Vec::new(),
+            relationships: self.relationships,
+            has_extracted_licensing_infos: self.extracted_licenses,
+        };
+
+        (document, self.report)
+    }
+
+    fn add_package(&mut self, component: &Component) -> String {
+        let element = component
+            .bom_ref
+            .clone()
+            .unwrap_or_else(|| component.name.to_string());
+        let id = self.spdx_id(component.bom_ref.as_deref());
+        if let Some(bom_ref) = &component.bom_ref {
+            self.ids.insert(bom_ref.clone(), id.clone());
+        }
+
+        let external_references = component
+            .external_references
+            .iter()
+            .flat_map(|references| references.0.iter());
+        let mut download_location = None;
+        let mut homepage = None;
+        for reference in external_references {
+            match reference.external_reference_type {
+                ExternalReferenceType::Distribution if download_location.is_none() => {
+                    download_location = Some(reference.url.to_string())
+                }
+                ExternalReferenceType::Website if homepage.is_none() => {
+                    homepage = Some(reference.url.to_string())
+                }
+                _ => self.report.add(
+                    &element,
+                    format!("externalReferences[{}]", reference.external_reference_type),
+                ),
+            }
+        }
+
+        let mut checksums = Vec::new();
+        for hash in component.hashes.iter().flat_map(|hashes| hashes.0.iter()) {
+            match spdx_checksum_algorithm(&hash.alg) {
+                Some(algorithm) => checksums.push(SpdxChecksum {
+                    algorithm: algorithm.to_string(),
+                    checksum_value: hash.content.0.clone(),
+                }),
+                None => self.report.add(&element, format!("hashes[{}]", hash.alg)),
+            }
+        }
+
+        let mut external_refs = Vec::new();
+        if let Some(purl) = &component.purl {
+            external_refs.push(SpdxExternalRef {
+                reference_category: "PACKAGE-MANAGER".to_string(),
+                reference_type: "purl".to_string(),
+                reference_locator: purl.to_string(),
+            });
+        }
+        if let Some(cpe) = &component.cpe {
+            let cpe: &str = cpe.as_ref();
+            let reference_type = match cpe.starts_with("cpe:2.3:") {
+                true => "cpe23Type",
+                false => "cpe22Type",
+            };
+            external_refs.push(SpdxExternalRef {
+                reference_category: "SECURITY".to_string(),
+                reference_type: reference_type.to_string(),
+                reference_locator: cpe.to_string(),
+            });
+        }
+
+        let license_declared = component
+            .licenses
+            .as_ref()
+            .map(|licenses| self.license_expression(licenses));
+
+        self.report_component_losses(component, &element);
+
+        self.packages.push(SpdxPackage {
+            spdx_id: id.clone(),
+            name: component.name.to_string(),
+            version_info: component.version.as_ref().map(ToString::to_string),
+            supplier: component
+                .supplier
+                .as_ref()
+                .and_then(|supplier| supplier.name.as_ref())
+                .map(|name| format!("Organization: {name}")),
+            originator: component
+                .author
+                .as_ref()
+                .map(|author| format!("Person: {author}")),
+            download_location: download_location.unwrap_or_else(|| NOASSERTION.to_string()),
+            files_analyzed: Some(false),
+            homepage,
+            license_concluded: Some(NOASSERTION.to_string()),
+            license_declared: Some(license_declared.unwrap_or_else(|| NOASSERTION.to_string())),
+            copyright_text: Some(
+                component
+                    .copyright
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| NOASSERTION.to_string()),
+            ),
+            description: component.description.as_ref().map(ToString::to_string),
+            summary: None,
+            checksums,
+            external_refs,
+            primary_package_purpose: spdx_package_purpose(&component.component_type)
+                .map(str::to_string),
+        });
+
+        for child in component.components.iter().flat_map(|c| c.0.iter()) {
+            let child_id = self.add_package(child);
+            self.relate(&id, "CONTAINS", &child_id);
+        }
+
+        id
+    }
+
+    /// Creates a unique SPDX identifier, which may only contain letters, numbers, `.` and `-`.
+    fn spdx_id(&mut self, bom_ref: Option<&str>) -> String {
+        let base = match bom_ref {
+            Some(bom_ref) => format!("SPDXRef-{}", sanitize(bom_ref)),
+            None => format!("SPDXRef-Package-{}", self.packages.len() + 1),
+        };
+        let mut id = base.clone();
+        let mut counter = 1;
+        while !self.used_ids.insert(id.clone()) {
+            counter += 1;
+            id = format!("{base}-{counter}");
+        }
+        id
+    }
+
+    fn license_expression(&mut self, licenses: &Licenses) -> String {
+        let terms = licenses
+            .0
+            .iter()
+            .map(|license| match license {
+                LicenseChoice::Expression(expression) => expression.to_string(),
+                LicenseChoice::License(license) => match &license.license_identifier {
+                    LicenseIdentifier::SpdxId(id) => id.to_string(),
+                    LicenseIdentifier::Name(name) => self.extracted_license(name, license),
+                },
+            })
+            .collect::<Vec<_>>();
+
+        match terms.as_slice() {
+            [] => NOASSERTION.to_string(),
+            [term] => term.clone(),
+            terms => terms
+                .iter()
+                .map(|term| match term.contains(' ') {
+                    true => format!("({term})"),
+                    false => term.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(" AND "),
+        }
+    }
+
+    fn extracted_license(&mut self, name: &NormalizedString, license: &License) -> String {
+        let license_id = format!("LicenseRef-{}", sanitize(name.as_ref()));
+        if !self
+            .extracted_licenses
+            .iter()
+            .any(|extracted| extracted.license_id == license_id)
+        {
+            let extracted_text = license
+                .text
+                .as_ref()
+                .and_then(|text| match text.encoding {
+                    Some(Encoding::Base64) => STANDARD
+                        .decode(&text.content)
+                        .ok()
+                        .and_then(|content| String::from_utf8(content).ok()),
+                    _ => Some(text.content.clone()),
+                })
+                .unwrap_or_else(|| name.to_string());
+            self.extracted_licenses.push(SpdxExtractedLicense {
+                license_id: license_id.clone(),
+                extracted_text,
+                name: Some(name.to_string()),
+            });
+        }
+        license_id
+    }
+
+    fn relate(&mut self, from: &str, relationship_type: &str, to: &str) {
+        self.relationships.push(SpdxRelationship {
+            spdx_element_id: from.to_string(),
+            relationship_type: relationship_type.to_string(),
+            related_spdx_element: to.to_string(),
+        });
+    }
+
+    fn report_component_losses(&mut self, component: &Component, element: &str) {
+        let unsupported = [
+            ("mime-type", component.mime_type.is_some()),
+            ("publisher", component.publisher.is_some()),
+            ("group", component.group.is_some()),
+            ("scope", component.scope.is_some()),
+            ("swid", component.swid.is_some()),
+            ("modified", component.modified.is_some()),
+            ("pedigree", component.pedigree.is_some()),
+            ("properties", component.properties.is_some()),
+            ("evidence", component.evidence.is_some()),
+            ("releaseNotes", component.release_notes.is_some()),
+            ("signature", component.signature.is_some()),
+            ("modelCard", component.model_card.is_some()),
+            ("data", component.data.is_some()),
+        ];
+        for (field, present) in unsupported {
+            if present {
+                self.report.add(element, field);
+            }
+        }
+        if spdx_package_purpose(&component.component_type).is_none() {
+            self.report.add(element, "type");
+        }
+    }
+
+    fn report_bom_losses(&mut self, bom: &Bom, metadata: &Metadata) {
+        let unsupported = [
+            ("services", bom.services.is_some()),
+            ("externalReferences", bom.external_references.is_some()),
+            ("compositions", bom.compositions.is_some()),
+            ("properties", bom.properties.is_some()),
+            ("vulnerabilities", bom.vulnerabilities.is_some()),
+            ("signature", bom.signature.is_some()),
+            ("annotations", bom.annotations.is_some()),
+            ("formulation", bom.formulation.is_some()),
+            ("metadata.manufacture", metadata.manufacture.is_some()),
+            ("metadata.licenses", metadata.licenses.is_some()),
+            ("metadata.properties", metadata.properties.is_some()),
+            ("metadata.lifecycles", metadata.lifecycles.is_some()),
+        ];
+        for (field, present) in unsupported {
+            if present {
+                self.report.add("bom", field);
+            }
+        }
+        if let Some(Tools::Object {
+            services: Some(_), ..
+        }) = &metadata.tools
+        {
+            self.report.add("bom", "metadata.tools.services");
+        }
+    }
+}
+
+fn creators(metadata: &Metadata) -> Vec<String> {
+    let mut creators = Vec::new();
+    for author in metadata.authors.iter().flatten() {
+        if let Some(name) = &author.name {
+            creators.push(match &author.email {
+                Some(email) => format!("Person: {name} ({email})"),
+                None => format!("Person: {name}"),
+            });
+        }
+    }
+    if let Some(name) = metadata
+        .supplier
+        .as_ref()
+        .and_then(|supplier| supplier.name.as_ref())
+    {
+        creators.push(format!("Organization: {name}"));
+    }
+    match &metadata.tools {
+        Some(Tools::List(tools)) => {
+            for tool in tools {
+                if let Some(name) = &tool.name {
+                    creators.push(match &tool.version {
+                        Some(version) => format!("Tool: {name}-{version}"),
+                        None => format!("Tool: {name}"),
+                    });
+                }
+            }
+        }
+        Some(Tools::Object {
+            components: Some(components),
+            ..
+        }) => {
+            for component in &components.0 {
+                creators.push(match &component.version {
+                    Some(version) => format!("Tool: {}-{}", component.name, version),
+                    None => format!("Tool: {}", component.name),
+                });
+            }
+        }
+        _ => {}
+    }
+    creators.push(format!("Tool: cyclonedx-bom-{}", env!("CARGO_PKG_VERSION")));
+    creators
+}
+
+/// SPDX requires the creation time in UTC without fractions of a second.
+fn spdx_timestamp(timestamp: Option<&DateTime>) -> String {
+    let timestamp = timestamp
+        .and_then(|timestamp| OffsetDateTime::parse(timestamp.as_ref(), &Iso8601::DEFAULT).ok())
+        .unwrap_or_else(OffsetDateTime::now_utc)
+        .to_offset(time::UtcOffset::UTC);
+    timestamp
+        .format(format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second]Z"
+        ))
+        .unwrap_or_default()
+}
+
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' => c,
+            _ => '-',
+        })
+        .collect()
+}
+
+fn spdx_checksum_algorithm(algorithm: &HashAlgorithm) -> Option<&'static str> {
+    match algorithm {
+        HashAlgorithm::MD5 => Some("MD5"),
+        HashAlgorithm::SHA1 => Some("SHA1"),
+        HashAlgorithm::SHA_256 => Some("SHA256"),
+        HashAlgorithm::SHA_384 => Some("SHA384"),
+        HashAlgorithm::SHA_512 => Some("SHA512"),
+        HashAlgorithm::SHA3_256 => Some("SHA3-256"),
+        HashAlgorithm::SHA3_384 => Some("SHA3-384"),
+        HashAlgorithm::SHA3_512 => Some("SHA3-512"),
+        HashAlgorithm::BLAKE2b_256 => Some("BLAKE2b-256"),
+        HashAlgorithm::BLAKE2b_384 => Some("BLAKE2b-384"),
+        HashAlgorithm::BLAKE2b_512 => Some("BLAKE2b-512"),
+        HashAlgorithm::BLAKE3 => Some("BLAKE3"),
+        HashAlgorithm::UnknownHashAlgorithm(_) => None,
+    }
+}
+
+fn hash_algorithm(algorithm: &str) -> Option<HashAlgorithm> {
+    match algorithm {
+        "MD5" => Some(HashAlgorithm::MD5),
+        "SHA1" => Some(HashAlgorithm::SHA1),
+        "SHA256" => Some(HashAlgorithm::SHA_256),
+        "SHA384" => Some(HashAlgorithm::SHA_384),
+        "SHA512" => Some(HashAlgorithm::SHA_512),
+        "SHA3-256" => Some(HashAlgorithm::SHA3_256),
+        "SHA3-384" => Some(HashAlgorithm::SHA3_384),
+        "SHA3-512" => Some(HashAlgorithm::SHA3_512),
+        "BLAKE2b-256" => Some(HashAlgorithm::BLAKE2b_256),
+        "BLAKE2b-384" => Some(HashAlgorithm::BLAKE2b_384),
+        "BLAKE2b-512" => Some(HashAlgorithm::BLAKE2b_512),
+        "BLAKE3" => Some(HashAlgorithm::BLAKE3),
+        _ => None,
+    }
+}
+
+fn spdx_package_purpose(classification: &Classification) -> Option<&'static str> {
+    match classification {
+        Classification::Application => Some("APPLICATION"),
+        Classification::Framework => Some("FRAMEWORK"),
+        Classification::Library => Some("LIBRARY"),
+        Classification::Container => Some("CONTAINER"),
+        Classification::OperatingSystem => Some("OPERATING-SYSTEM"),
+        Classification::Device => Some("DEVICE"),
+        Classification::Firmware => Some("FIRMWARE"),
+        Classification::File => Some("FILE"),
+        _ => None,
+    }
+}
+
+fn classification(purpose: &str) -> Option<Classification> {
+    match purpose {
+        "APPLICATION" => Some(Classification::Application),
+        "FRAMEWORK" => Some(Classification::Framework),
+        "LIBRARY" => Some(Classification::Library),
+        "CONTAINER" => Some(Classification::Container),
+        "OPERATING-SYSTEM" => Some(Classification::OperatingSystem),
+        "DEVICE" => Some(Classification::Device),
+        "FIRMWARE" => Some(Classification::Firmware),
+        "FILE" => Some(Classification::File),
+        _ => None,
+    }
+}
+
+/// Returns the value unless it is one of the special SPDX values `NOASSERTION` or `NONE`.
+fn assertion(value: Option<&String>) -> Option<&str> {
+    value
+        .map(String::as_str)
+        .filter(|value| *value != NOASSERTION && *value != NONE)
+}
+
+/// Removes the `Person: ` or `Organization: ` prefix of an SPDX actor.
+fn actor_name(actor: &str) -> &str {
+    actor
+        .strip_prefix("Person:")
+        .or_else(|| actor.strip_prefix("Organization:"))
+        .or_else(|| actor.strip_prefix("Tool:"))
+        .unwrap_or(actor)
+        .trim()
+}
+
+#[derive(Default)]
+struct FromSpdx {
+    report: LossReport,
+}
+
+impl FromSpdx {
+    fn convert(mut self, document: SpdxDocument) -> (Bom, LossReport) {
+        let package_ids: HashSet<String> = document
+            .packages
+            .iter()
+            .map(|package| package.spdx_id.clone())
+            .collect();
+
+        let mut described: Vec<String> = document.document_describes.clone();
+        let mut parents: HashMap<String, String> = HashMap::new();
+        let mut dependencies: HashMap<String, Vec<String>> = HashMap::new();
+        for relationship in &document.relationships {
+            let (from, to) = (
+                relationship.spdx_element_id.as_str(),
+                relationship.related_spdx_element.as_str(),
+            );
+            let relationship_type = relationship.relationship_type.as_str();
+            match relationship_type {
+                "DESCRIBES" if from == DOCUMENT_ID && package_ids.contains(to) => {
+                    described.push(to.to_string())
+                }
+                "DESCRIBED_BY" if to == DOCUMENT_ID && package_ids.contains(from) => {
+                    described.push(from.to_string())
+                }
+                "DEPENDS_ON" if package_ids.contains(from) && package_ids.contains(to) => {
+                    dependencies
+                        .entry(from.to_string())
+                        .or_default()
+                        .push(to.to_string())
+                }
+                "DEPENDENCY_OF" if package_ids.contains(from) && package_ids.contains(to) => {
+                    dependencies
+                        .entry(to.to_string())
+                        .or_default()
+                        .push(from.to_string())
+                }
+                "CONTAINS"
+                    if package_ids.contains(from)
+                        && package_ids.contains(to)
+                        && from != to
+                        && !parents.contains_key(to) =>
+                {
+                    parents.insert(to.to_string(), from.to_string());
+                }
+                _ => self
+                    .report
+                    .add(from, format!("relationships[{relationship_type}]")),
+            }
+        }
+        let mut seen = HashSet::new();
+        described.retain(|id| seen.insert(id.clone()));
+
+        for (field, present) in [
+            ("files", !document.files.is_empty()),
+            ("snippets", !document.snippets.is_empty()),
+            ("annotations", !document.annotations.is_empty()),
+        ] {
+            if present {
+                self.report.add(DOCUMENT_ID, field);
+            }
+        }
+
+        let extracted_licenses: HashMap<&str, &SpdxExtractedLicense> = document
+            .has_extracted_licensing_infos
+            .iter()
+            .map(|license| (license.license_id.as_str(), license))
+            .collect();
+
+        let mut components: HashMap<String, Component> = HashMap::new();
+        let mut order = Vec::new();
+        for package in &document.packages {
+            order.push(package.spdx_id.clone());
+            components.insert(
+                package.spdx_id.clone(),
+                self.component(package, &extracted_licenses),
+            );
+        }
+
+        // Packages which would be nested into themselves stay at the top level.
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        let mut roots = Vec::new();
+        for id in &order {
+            match parents.get(id) {
+                Some(parent) if !is_ancestor(id, parent, &parents) => {
+                    children.entry(parent.clone()).or_default().push(id.clone())
+                }
+                Some(_) => {
+                    self.report.add(id.as_str(), "relationships[CONTAINS]");
+                    roots.push(id.clone());
+                }
+                None => roots.push(id.clone()),
+            }
+        }
+
+        let mut metadata = self.metadata(&document.creation_info);
+        let mut top_level = Vec::new();
+        for id in &roots {
+            let Some(component) = nest(id, &mut components, &children) else {
+                continue;
+            };
+            match described.as_slice() {
+                [described] if described == id => metadata.component = Some(component),
+                _ => top_level.push(component),
+            }
+        }
+
+        let dependencies = order
+            .iter()
+            .filter_map(|id| {
+                dependencies.get(id).map(|depends_on| Dependency {
+                    dependency_ref: id.clone(),
+                    dependencies: depends_on.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let bom = Bom {
+            serial_number: Some(UrnUuid::generate()),
+            metadata: Some(metadata),
+            components: Some(Components(top_level)),
+            dependencies: (!dependencies.is_empty()).then_some(Dependencies(dependencies)),
+            spec_version: SpecVersion::V1_5,
+            ..Bom::default()
+        };
+
+        (bom, self.report)
+    }
+
+    fn metadata(&mut self, creation_info: &SpdxCreationInfo) -> Metadata {
+        let mut metadata = Metadata {
+            timestamp: DateTime::try_from(creation_info.created.clone()).ok(),
+            ..Metadata::default()
+        };
+
+        let mut authors = Vec::new();
+        let mut tools = Vec::new();
+        for creator in &creation_info.creators {
+            if let Some(person) = creator.strip_prefix("Person:") {
+                let (name, email) = match person.trim().split_once(" (") {
+                    Some((name, email)) => (name, email.strip_suffix(')')),
+                    None => (person.trim(), None),
+                };
+                authors.push(OrganizationalContact::new(name, email));
+            } else if creator.starts_with("Organization:") && metadata.supplier.is_none() {
+                metadata.supplier = Some(OrganizationalEntity {
+                    bom_ref: None,
+                    name: Some(NormalizedString::new(actor_name(creator))),
+                    url: None,
+                    contact: None,
+                });
+            } else if creator.starts_with("Tool:") {
+                tools.push(Tool {
+                    name: Some(NormalizedString::new(actor_name(creator))),
+                    ..Tool::default()
+                });
+            } else {
+                self.report.add(DOCUMENT_ID, "creationInfo.creators");
+            }
+        }
+        if !authors.is_empty() {
+            metadata.authors = Some(authors);
+        }
+        if !tools.is_empty() {
+            metadata.tools = Some(Tools::List(tools));
+        }
+        metadata
+    }
+
+    fn component(
+        &mut self,
+        package: &SpdxPackage,
+        extracted_licenses: &HashMap<&str, &SpdxExtractedLicense>,
+    ) -> Component {
+        let element = package.spdx_id.as_str();
+        let component_type = match package.primary_package_purpose.as_deref() {
+            Some(purpose) => classification(purpose).unwrap_or_else(|| {
+                self.report.add(element, "primaryPackagePurpose");
+                Classification::Library
+            }),
+            None => Classification::Library,
+        };
+
+        let mut component = Component::new(
+            component_type,
+            &package.name,
+            "",
+            Some(package.spdx_id.clone()),
+        );
+        component.version = package.version_info.as_deref().map(NormalizedString::new);
+        component.supplier =
+            assertion(package.supplier.as_ref()).map(|supplier| OrganizationalEntity {
+                bom_ref: None,
+                name: Some(NormalizedString::new(actor_name(supplier))),
+                url: None,
+                contact: None,
+            });
+        component.author = assertion(package.originator.as_ref())
+            .map(|originator| NormalizedString::new(actor_name(originator)));
+        component.description = package
+            .description
+            .as_ref()
+            .or(package.summary.as_ref())
+            .map(|description| NormalizedString::new(description));
+        component.copyright = assertion(package.copyright_text.as_ref()).map(NormalizedString::new);
+
+        let declared = assertion(package.license_declared.as_ref());
+        let concluded = assertion(package.license_concluded.as_ref());
+        if concluded.is_some() && declared.is_some() && concluded != declared {
+            self.report.add(element, "licenseConcluded");
+        }
+        component.licenses = declared
+            .or(concluded)
+            .map(|license| Licenses(vec![license_choice(license, extracted_licenses)]));
+
+        let hashes = package
+            .checksums
+            .iter()
+            .filter_map(|checksum| match hash_algorithm(&checksum.algorithm) {
+                Some(alg) => Some(Hash {
+                    alg,
+                    content: HashValue(checksum.checksum_value.clone()),
+                }),
+                None => {
+                    self.report
+                        .add(element, format!("checksums[{}]", checksum.algorithm));
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        if !hashes.is_empty() {
+            component.hashes = Some(Hashes(hashes));
+        }
+
+        for external_ref in &package.external_refs {
+            match external_ref.reference_type.as_str() {
+                "purl" if component.purl.is_none() => {
+                    component.purl = Purl::from_str(&external_ref.reference_locator).ok()
+                }
+                "cpe23Type" | "cpe22Type" if component.cpe.is_none() => {
+                    component.cpe = Some(Cpe::new(&external_ref.reference_locator))
+                }
+                reference_type => self
+                    .report
+                    .add(element, format!("externalRefs[{reference_type}]")),
+            }
+        }
+
+        let mut external_references = Vec::new();
+        if let Some(download_location) = assertion(Some(&package.download_location)) {
+            external_references.push(ExternalReference::new(
+                ExternalReferenceType::Distribution,
+                Uri::Url(Url::new(download_location)),
+            ));
+        }
+        if let Some(homepage) = assertion(package.homepage.as_ref()) {
+            external_references.push(ExternalReference::new(
+                ExternalReferenceType::Website,
+                Uri::Url(Url::new(homepage)),
+            ));
+        }
+        if !external_references.is_empty() {
+            component.external_references = Some(ExternalReferences(external_references));
+        }
+
+        if package.files_analyzed == Some(true) {
+            self.report.add(element, "filesAnalyzed");
+        }
+
+        component
+    }
+}
+
+fn license_choice(
+    license: &str,
+    extracted_licenses: &HashMap<&str, &SpdxExtractedLicense>,
+) -> LicenseChoice {
+    if let Some(extracted) = extracted_licenses.get(license) {
+        let mut named =
+            License::named_license(extracted.name.as_deref().unwrap_or(&extracted.license_id));
+        named.text = Some(AttachedText::new(None, &extracted.extracted_text));
+        return LicenseChoice::License(named);
+    }
+    match SpdxExpression::try_from(license.to_string()) {
+        Ok(expression) => LicenseChoice::Expression(expression),
+        Err(_) => LicenseChoice::License(License::named_license(license)),
+    }
+}
+
+/// Removes the component and its contained components from `components` and nests them.
+fn nest(
+    id: &str,
+    components: &mut HashMap<String, Component>,
+    children: &HashMap<String, Vec<String>>,
+) -> Option<Component> {
+    let mut component = components.remove(id)?;
+    let nested = children
+        .get(id)
+        .into_iter()
+        .flatten()
+        .filter_map(|child| nest(child, components, children))
+        .collect::<Vec<_>>();
+    if !nested.is_empty() {
+        component.components = Some(Components(nested));
+    }
+    Some(component)
+}
+
+/// Returns `true` if `id` is an ancestor of `parent`, i.e. if nesting would create a cycle.
+fn is_ancestor(id: &str, parent: &str, parents: &HashMap<String, String>) -> bool {
+    let mut current = parent;
+    let mut visited = HashSet::new();
+    while let Some(next) = parents.get(current) {
+        if next == id || !visited.insert(next) {
+            return true;
+        }
+        current = next;
+    }
+    false
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxDocument {
+    spdx_version: String,
+    data_license: String,
+    #[serde(rename = "SPDXID")]
+    spdx_id: String,
+    name: String,
+    document_namespace: String,
+    creation_info: SpdxCreationInfo,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    document_describes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    packages: Vec<SpdxPackage>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    files: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    snippets: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    annotations: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    relationships: Vec<SpdxRelationship>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    has_extracted_licensing_infos: Vec<SpdxExtractedLicense>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxCreationInfo {
+    created: String,
+    #[serde(default)]
+    creators: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxPackage {
+    #[serde(rename = "SPDXID")]
+    spdx_id: String,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version_info: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supplier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    originator: Option<String>,
+    download_location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files_analyzed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license_concluded: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license_declared: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copyright_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing)]
+    summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    checksums: Vec<SpdxChecksum>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    external_refs: Vec<SpdxExternalRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primary_package_purpose: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxChecksum {
+    algorithm: String,
+    checksum_value: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxExternalRef {
+    reference_category: String,
+    reference_type: String,
+    reference_locator: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxRelationship {
+    spdx_element_id: String,
+    relationship_type: String,
+    related_spdx_element: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxExtractedLicense {
+    license_id: String,
+    extracted_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::models::component::Scope;
+    use crate::models::service::{Service, Services};
+    use pretty_assertions::assert_eq;
+
+    fn example_bom() -> Bom {
+        let mut application = Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("app".into()),
+        );
+        application.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+            SpdxExpression::new("MIT OR Apache-2.0"),
+        )]));
+
+        let mut library = Component::new(
+            Classification::Library,
+            "lib",
+            "0.2.0",
+            Some("pkg:cargo/lib@0.2.0".into()),
+        );
+        library.purl = Some(Purl::new("cargo", "lib", "0.2.0").unwrap());
+        library.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA_256,
+            content: HashValue("abc123".to_string()),
+        }]));
+        library.licenses = Some(Licenses(vec![LicenseChoice::License(
+            License::named_license("Custom License"),
+        )]));
+        library.scope = Some(Scope::Required);
+        library.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "nested",
+            "0.1.0",
+            Some("nested".into()),
+        )]));
+
+        Bom {
+            metadata: Some(Metadata {
+                timestamp: Some(
+                    DateTime::try_from("2024-01-02T03:04:05.678+01:00".to_string()).unwrap(),
+                ),
+                component: Some(application),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![library])),
+            dependencies: Some(Dependencies(vec![Dependency {
+                dependency_ref: "app".to_string(),
+                dependencies: vec!["pkg:cargo/lib@0.2.0".to_string()],
+            }])),
+            services: Some(Services(vec![Service::new("service", None)])),
+            ..Bom::default()
+        }
+    }
+
+    fn to_spdx(bom: &Bom) -> (serde_json::Value, LossReport) {
+        let mut output = Vec::new();
+        let report = bom
+            .to_spdx_json(&mut output)
+            .expect("Failed to export SPDX");
+        (serde_json::from_slice(&output).unwrap(), report)
+    }
+
+    fn losses(report: &LossReport) -> Vec<String> {
+        report.losses.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn it_should_export_spdx_packages_and_relationships() {
+        let (document, report) = to_spdx(&example_bom());
+
+        assert_eq!(document["spdxVersion"], "SPDX-2.3");
+        assert_eq!(document["creationInfo"]["created"], "2024-01-02T02:04:05Z");
+
+        let packages = document["packages"].as_array().unwrap();
+        let ids = packages
+            .iter()
+            .map(|package| package["SPDXID"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![
+                "SPDXRef-app",
+                "SPDXRef-pkg-cargo-lib-0.2.0",
+                "SPDXRef-nested"
+            ]
+        );
+        assert_eq!(packages[0]["licenseDeclared"], "MIT OR Apache-2.0");
+        assert_eq!(packages[1]["licenseDeclared"], "LicenseRef-Custom-License");
+        assert_eq!(packages[1]["checksums"][0]["algorithm"], "SHA256");
+        assert_eq!(
+            packages[1]["externalRefs"][0]["referenceLocator"],
+            "pkg:cargo/lib@0.2.0"
+        );
+        assert_eq!(
+            document["hasExtractedLicensingInfos"][0]["licenseId"],
+            "LicenseRef-Custom-License"
+        );
+
+        let relationships = document["relationships"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|relationship| {
+                format!(
+                    "{} {} {}",
+                    relationship["spdxElementId"].as_str().unwrap(),
+                    relationship["relationshipType"].as_str().unwrap(),
+                    relationship["relatedSpdxElement"].as_str().unwrap()
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            relationships,
+            vec![
+                "SPDXRef-DOCUMENT DESCRIBES SPDXRef-app",
+                "SPDXRef-pkg-cargo-lib-0.2.0 CONTAINS SPDXRef-nested",
+                "SPDXRef-app DEPENDS_ON SPDXRef-pkg-cargo-lib-0.2.0",
+            ]
+        );
+
+        assert_eq!(
+            losses(&report),
+            vec![
+                "'scope' of 'pkg:cargo/lib@0.2.0' was not converted",
+                "'services' of 'bom' was not converted",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_through_spdx() {
+        let mut output = Vec::new();
+        example_bom().to_spdx_json(&mut output).unwrap();
+        let (bom, report) = Bom::from_spdx_json(output.as_slice()).unwrap();
+        assert!(report.is_empty(), "{:?}", report);
+
+        let metadata = bom.metadata.unwrap();
+        let application = metadata.component.unwrap();
+        assert_eq!(application.bom_ref, Some("SPDXRef-app".to_string()));
+        assert_eq!(application.component_type, Classification::Application);
+        assert_eq!(
+            application.licenses,
+            Some(Licenses(vec![LicenseChoice::Expression(
+                SpdxExpression::new("MIT OR Apache-2.0")
+            )]))
+        );
+
+        let components = bom.components.unwrap();
+        let library = &components.0[0];
+        assert_eq!(components.0.len(), 1);
+        assert_eq!(library.version, Some(NormalizedString::new("0.2.0")));
+        assert_eq!(
+            library.purl,
+            Some(Purl::new("cargo", "lib", "0.2.0").unwrap())
+        );
+        assert_eq!(
+            library.hashes,
+            Some(Hashes(vec![Hash {
+                alg: HashAlgorithm::SHA_256,
+                content: HashValue("abc123".to_string()),
+            }]))
+        );
+        match &library.licenses.as_ref().unwrap().0[0] {
+            LicenseChoice::License(license) => assert_eq!(
+                license.license_identifier,
+                LicenseIdentifier::Name(NormalizedString::new("Custom License"))
+            ),
+            license => panic!("Expected a named license, got {license:?}"),
+        }
+        assert_eq!(
+            library.components.as_ref().unwrap().0[0].name,
+            NormalizedString::new("nested")
+        );
+
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![Dependency {
+                dependency_ref: "SPDXRef-app".to_string(),
+                dependencies: vec!["SPDXRef-pkg-cargo-lib-0.2.0".to_string()],
+            }]))
+        );
+    }
+
+    #[test]
+    fn it_should_report_losses_when_importing_spdx() {
+        let input = r#"{
+            "spdxVersion": "SPDX-2.3",
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": "example",
+            "documentNamespace": "https://example.com/example",
+            "creationInfo": {
+                "created": "2024-01-02T03:04:05Z",
+                "creators": ["Tool: example-1.0", "Person: Jane Doe (jane@example.com)"]
+            },
+            "packages": [
+                {
+                    "SPDXID": "SPDXRef-a",
+                    "name": "a",
+                    "downloadLocation": "NOASSERTION",
+                    "licenseConcluded": "MIT",
+                    "licenseDeclared": "Apache-2.0"
+                },
+                {
+                    "SPDXID": "SPDXRef-b",
+                    "name": "b",
+                    "downloadLocation": "https://example.com/b.tar.gz"
+                }
+            ],
+            "files": [{ "SPDXID": "SPDXRef-file", "fileName": "a.rs" }],
+            "relationships": [
+                { "spdxElementId": "SPDXRef-a", "relationshipType": "CONTAINS", "relatedSpdxElement": "SPDXRef-b" },
+                { "spdxElementId": "SPDXRef-b", "relationshipType": "CONTAINS", "relatedSpdxElement": "SPDXRef-a" },
+                { "spdxElementId": "SPDXRef-a", "relationshipType": "GENERATES", "relatedSpdxElement": "SPDXRef-file" }
+            ]
+        }"#;
+
+        let (bom, report) = Bom::from_spdx_json(input.as_bytes()).unwrap();
+
+        assert_eq!(
+            losses(&report),
+            vec![
+                "'relationships[GENERATES]' of 'SPDXRef-a' was not converted",
+                "'files' of 'SPDXRef-DOCUMENT' was not converted",
+                "'licenseConcluded' of 'SPDXRef-a' was not converted",
+                "'relationships[CONTAINS]' of 'SPDXRef-a' was not converted",
+                "'relationships[CONTAINS]' of 'SPDXRef-b' was not converted",
+            ]
+        );
+
+        let metadata = bom.metadata.unwrap();
+        assert_eq!(
+            metadata.authors,
+            Some(vec![OrganizationalContact::new(
+                "Jane Doe",
+                Some("jane@example.com")
+            )])
+        );
+        assert_eq!(bom.components.unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn it_should_reject_unsupported_spdx_versions() {
+        let input = r#"{
+            "spdxVersion": "SPDX-3.0",
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": "example",
+            "documentNamespace": "https://example.com/example",
+            "creationInfo": { "created": "2024-01-02T03:04:05Z" }
+        }"#;
+
+        assert!(matches!(
+            Bom::from_spdx_json(input.as_bytes()),
+            Err(SpdxInteropError::UnsupportedSpdxVersion(version)) if version == "SPDX-3.0"
+        ));
+    }
+}
@@ -29,7 +29,8 @@ use super::service::Services;
 ///
 /// In version 1.5 the type of this property changed to
 /// https://cyclonedx.org/docs/1.5/json/#metadata_tools_oneOf_i0_services .
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tools {
     /// Legacy https://cyclonedx.org/docs/1.4/json/#metadata_tools
     List(Vec<Tool>),
@@ -71,7 +72,8 @@ impl Validate for Tools {
 /// Represents the tool used to create the BOM
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_toolType)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tool {
     pub vendor: Option<NormalizedString>,
     pub name: Option<NormalizedString>,
@@ -97,6 +99,46 @@ impl Tool {
             external_references: None,
         }
     }
+
+    /// Construct a `Tool` describing this library, e.g. to register it in the metadata of
+    /// generated BOMs
+    /// ```
+    /// use cyclonedx_bom::models::tool::Tool;
+    ///
+    /// let tool = Tool::cyclonedx_bom();
+    /// assert_eq!(tool.name.unwrap().to_string(), "cyclonedx-bom");
+    /// ```
+    pub fn cyclonedx_bom() -> Self {
+        Self::new(
+            "CycloneDX",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+        )
+    }
+}
+
+/// Construct a [`Tool`] with the name and version of the crate that invokes the macro, and an
+/// optional vendor
+/// ```
+/// use cyclonedx_bom::tool_from_env;
+///
+/// let tool = tool_from_env!();
+/// assert_eq!(tool.name.unwrap().to_string(), env!("CARGO_PKG_NAME"));
+///
+/// let tool = tool_from_env!("ACME");
+/// assert_eq!(tool.vendor.unwrap().to_string(), "ACME");
+/// ```
+#[macro_export]
+macro_rules! tool_from_env {
+    () => {
+        $crate::models::tool::Tool {
+            vendor: None,
+            ..$crate::tool_from_env!("")
+        }
+    };
+    ($vendor:expr) => {
+        $crate::models::tool::Tool::new($vendor, env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+    };
 }
 
 impl Validate for Tool {
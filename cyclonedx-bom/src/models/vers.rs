@@ -0,0 +1,415 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! The [`vers`](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
+//! syntax of version ranges, e.g. `vers:cargo/>=1.0.0|<1.2.3`, used by the affected versions of
+//! vulnerabilities.
+//!
+//! ```
+//! use cyclonedx_bom::models::vers::{Vers, VersionScheme};
+//!
+//! let range: Vers = "vers:cargo/>=1.0.0|<1.2.3".parse()?;
+//! assert!(range.contains("1.1.0", VersionScheme::Cargo)?);
+//! assert!(!range.contains("1.2.3", VersionScheme::Cargo)?);
+//! # Ok::<(), cyclonedx_bom::models::vers::VersError>(())
+//! ```
+
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+/// A version scheme that versions can be compared in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VersionScheme {
+    /// [Semantic versioning](https://semver.org/)
+    Semver,
+    /// The versions of Rust crates, which follow semantic versioning
+    Cargo,
+}
+
+impl VersionScheme {
+    fn compare(&self, left: &str, right: &str) -> Result<Ordering, VersError> {
+        match self {
+            Self::Semver | Self::Cargo => {
+                let parse = |version: &str| {
+                    semver::Version::parse(version)
+                        .map_err(|_| VersError::InvalidVersion(version.to_string()))
+                };
+                Ok(parse(left)?.cmp_precedence(&parse(right)?))
+            }
+        }
+    }
+}
+
+impl Display for VersionScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Semver => write!(f, "semver"),
+            Self::Cargo => write!(f, "cargo"),
+        }
+    }
+}
+
+impl FromStr for VersionScheme {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "semver" => Ok(Self::Semver),
+            "cargo" => Ok(Self::Cargo),
+            scheme => Err(VersError::UnsupportedScheme(scheme.to_string())),
+        }
+    }
+}
+
+/// The comparator of a [`VersionConstraint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Comparator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparator {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Equal => "",
+            Self::NotEqual => "!=",
+            Self::Less => "<",
+            Self::LessOrEqual => "<=",
+            Self::Greater => ">",
+            Self::GreaterOrEqual => ">=",
+        }
+    }
+
+    /// Returns `true` if a version with the given ordering relative to the constraint matches
+    fn accepts(&self, ordering: Ordering) -> bool {
+        match self {
+            Self::Equal => ordering.is_eq(),
+            Self::NotEqual => ordering.is_ne(),
+            Self::Less => ordering.is_lt(),
+            Self::LessOrEqual => ordering.is_le(),
+            Self::Greater => ordering.is_gt(),
+            Self::GreaterOrEqual => ordering.is_ge(),
+        }
+    }
+}
+
+/// A single constraint of a [`Vers`] range.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VersionConstraint {
+    /// `*`, which matches every version
+    Any,
+    Compare {
+        comparator: Comparator,
+        version: String,
+    },
+}
+
+impl Display for VersionConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "*"),
+            Self::Compare {
+                comparator,
+                version,
+            } => write!(f, "{}{version}", comparator.symbol()),
+        }
+    }
+}
+
+impl FromStr for VersionConstraint {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "*" {
+            return Ok(Self::Any);
+        }
+
+        let (comparator, version) = [
+            (">=", Comparator::GreaterOrEqual),
+            ("<=", Comparator::LessOrEqual),
+            ("!=", Comparator::NotEqual),
+            ("<", Comparator::Less),
+            (">", Comparator::Greater),
+            ("=", Comparator::Equal),
+        ]
+        .into_iter()
+        .find_map(|(symbol, comparator)| Some((comparator, s.strip_prefix(symbol)?)))
+        .unwrap_or((Comparator::Equal, s));
+
+        if version.is_empty() {
+            return Err(VersError::InvalidConstraint(s.to_string()));
+        }
+        Ok(Self::Compare {
+            comparator,
+            version: version.to_string(),
+        })
+    }
+}
+
+/// A version range in the `vers` syntax.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vers {
+    /// The versioning scheme, e.g. `cargo` or `npm`
+    pub scheme: String,
+    pub constraints: Vec<VersionConstraint>,
+}
+
+impl Vers {
+    /// Returns `true` if the version is in the range, comparing versions in the given scheme.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the range declares a different scheme, or if a version is not valid
+    /// in the scheme.
+    pub fn contains(&self, version: &str, scheme: VersionScheme) -> Result<bool, VersError> {
+        if self.scheme != scheme.to_string() {
+            return Err(VersError::SchemeMismatch {
+                range: self.scheme.clone(),
+                expected: scheme,
+            });
+        }
+
+        let mut ranges: Vec<(&Comparator, &str, Ordering)> = Vec::new();
+        for constraint in &self.constraints {
+            let VersionConstraint::Compare {
+                comparator,
+                version: bound,
+            } = constraint
+            else {
+                return Ok(true);
+            };
+            let ordering = scheme.compare(version, bound)?;
+            match comparator {
+                Comparator::Equal if ordering.is_eq() => return Ok(true),
+                Comparator::NotEqual if ordering.is_eq() => return Ok(false),
+                Comparator::Equal | Comparator::NotEqual => {}
+                _ => ranges.push((comparator, bound.as_str(), ordering)),
+            }
+        }
+
+        // The remaining bounds form intervals in the order of their versions, each lower bound is
+        // followed by the upper bound of its interval, only the first interval may be open below
+        let mut sorted: Vec<(&Comparator, &str, Ordering)> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            let mut index = sorted.len();
+            for (i, (_, bound, _)) in sorted.iter().enumerate() {
+                if scheme.compare(range.1, bound)?.is_lt() {
+                    index = i;
+                    break;
+                }
+            }
+            sorted.insert(index, range);
+        }
+
+        for (index, (comparator, _, ordering)) in sorted.iter().enumerate() {
+            match comparator {
+                Comparator::Less | Comparator::LessOrEqual => {
+                    if index == 0 && comparator.accepts(*ordering) {
+                        return Ok(true);
+                    }
+                }
+                _ => match sorted.get(index + 1) {
+                    None => return Ok(comparator.accepts(*ordering)),
+                    Some((
+                        upper @ (Comparator::Less | Comparator::LessOrEqual),
+                        _,
+                        upper_ordering,
+                    )) => {
+                        if comparator.accepts(*ordering) && upper.accepts(*upper_ordering) {
+                            return Ok(true);
+                        }
+                    }
+                    Some(_) => {}
+                },
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl Display for Vers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let constraints: Vec<String> = self.constraints.iter().map(ToString::to_string).collect();
+        write!(f, "vers:{}/{}", self.scheme, constraints.join("|"))
+    }
+}
+
+impl FromStr for Vers {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let (scheme, constraints) = value
+            .strip_prefix("vers:")
+            .and_then(|range| range.split_once('/'))
+            .ok_or_else(|| VersError::InvalidRange(s.to_string()))?;
+        if scheme.is_empty() || constraints.is_empty() {
+            return Err(VersError::InvalidRange(s.to_string()));
+        }
+
+        let constraints = constraints
+            .split('|')
+            .map(VersionConstraint::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        if constraints.len() > 1 && constraints.contains(&VersionConstraint::Any) {
+            return Err(VersError::InvalidRange(s.to_string()));
+        }
+
+        Ok(Self {
+            scheme: scheme.to_lowercase(),
+            constraints,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum VersError {
+    #[error("Invalid vers range: {}", .0)]
+    InvalidRange(String),
+
+    #[error("Invalid version constraint: {}", .0)]
+    InvalidConstraint(String),
+
+    #[error("Invalid version: {}", .0)]
+    InvalidVersion(String),
+
+    #[error("Unsupported version scheme: {}", .0)]
+    UnsupportedScheme(String),
+
+    #[error("The range has the version scheme {range} instead of {expected}")]
+    SchemeMismatch {
+        range: String,
+        expected: VersionScheme,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn contains(range: &str, version: &str) -> bool {
+        range
+            .parse::<Vers>()
+            .expect("Failed to parse range")
+            .contains(version, VersionScheme::Cargo)
+            .expect("Failed to match version")
+    }
+
+    #[test]
+    fn it_should_parse_and_render_ranges() {
+        let range: Vers = "vers:Cargo/ 1.0.0 | >=2.0.0 | <3.0.0-alpha "
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            range,
+            Vers {
+                scheme: "cargo".to_string(),
+                constraints: vec![
+                    VersionConstraint::Compare {
+                        comparator: Comparator::Equal,
+                        version: "1.0.0".to_string()
+                    },
+                    VersionConstraint::Compare {
+                        comparator: Comparator::GreaterOrEqual,
+                        version: "2.0.0".to_string()
+                    },
+                    VersionConstraint::Compare {
+                        comparator: Comparator::Less,
+                        version: "3.0.0-alpha".to_string()
+                    },
+                ],
+            }
+        );
+        assert_eq!(range.to_string(), "vers:cargo/1.0.0|>=2.0.0|<3.0.0-alpha");
+    }
+
+    #[test]
+    fn it_should_reject_invalid_ranges() {
+        for range in [
+            "cargo/1.0.0",
+            "vers:cargo",
+            "vers:/1.0.0",
+            "vers:cargo/",
+            "vers:cargo/*|1.0.0",
+        ] {
+            assert_eq!(
+                range.parse::<Vers>(),
+                Err(VersError::InvalidRange(range.to_string())),
+                "{range}"
+            );
+        }
+        assert_eq!(
+            "vers:cargo/1.0.0|>=".parse::<Vers>(),
+            Err(VersError::InvalidConstraint(">=".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_match_versions_in_ranges() {
+        assert!(contains("vers:cargo/*", "0.1.0"));
+        assert!(contains("vers:cargo/1.2.3", "1.2.3"));
+        assert!(contains("vers:cargo/1.2.3", "1.2.3+build"));
+        assert!(!contains("vers:cargo/1.2.3", "1.2.4"));
+
+        let range = "vers:cargo/<1.0.0|>=2.0.0|<2.5.0|>3.0.0|!=3.1.0";
+        assert!(contains(range, "0.9.0"));
+        assert!(!contains(range, "1.0.0"));
+        assert!(contains(range, "2.0.0"));
+        assert!(contains(range, "2.4.9"));
+        assert!(!contains(range, "2.5.0"));
+        assert!(!contains(range, "3.0.0"));
+        assert!(contains(range, "3.0.1"));
+        assert!(!contains(range, "3.1.0"));
+
+        assert!(contains("vers:cargo/>=1.0.0-alpha|<=1.0.0", "1.0.0-beta"));
+        assert!(!contains("vers:cargo/>=1.0.0|<=1.5.0", "1.0.0-beta"));
+    }
+
+    #[test]
+    fn it_should_fail_to_match_other_schemes_and_invalid_versions() {
+        let range: Vers = "vers:npm/>=1.0.0".parse().unwrap();
+        assert_eq!(
+            range.contains("1.0.0", VersionScheme::Cargo),
+            Err(VersError::SchemeMismatch {
+                range: "npm".to_string(),
+                expected: VersionScheme::Cargo
+            })
+        );
+
+        let range: Vers = "vers:semver/>=1.0.0".parse().unwrap();
+        assert_eq!(
+            range.contains("1.0", VersionScheme::Semver),
+            Err(VersError::InvalidVersion("1.0".to_string()))
+        );
+        assert_eq!(
+            "npm".parse::<VersionScheme>(),
+            Err(VersError::UnsupportedScheme("npm".to_string()))
+        );
+    }
+}
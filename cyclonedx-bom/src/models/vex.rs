@@ -0,0 +1,325 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Standalone [VEX](https://cyclonedx.org/capabilities/vex/) documents.
+//!
+//! A VEX document is a [`Bom`] which only contains metadata and vulnerabilities with their
+//! analysis. The affected components are not part of the document, instead the targets of the
+//! vulnerabilities reference the components of an external SBOM with
+//! [BOM-Links](https://cyclonedx.org/capabilities/bomlink/), which is why a VEX document
+//! requires at least spec version 1.5.
+
+use thiserror::Error;
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::models::bom::{Bom, SpecVersion, UrnUuid};
+use crate::models::bom_link::{BomLink, BomLinkError, BomLinkUrn};
+use crate::models::metadata::{Metadata, MetadataError};
+use crate::models::vulnerability::{Vulnerabilities, Vulnerability};
+use crate::models::vulnerability_analysis::VulnerabilityAnalysis;
+use crate::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
+
+/// Builds a VEX document for the components of an SBOM.
+/// ```
+/// use cyclonedx_bom::models::bom::Bom;
+/// use cyclonedx_bom::models::vex::Vex;
+/// use cyclonedx_bom::models::vulnerability_analysis::{
+///     ImpactAnalysisJustification, ImpactAnalysisState, VulnerabilityAnalysis,
+/// };
+///
+/// let sbom = Bom::default();
+/// let mut vex = Vex::for_bom(&sbom)?;
+/// vex.add_analysis(
+///     "CVE-2023-0001",
+///     "pkg:cargo/example@1.0.0",
+///     VulnerabilityAnalysis::new(
+///         Some(ImpactAnalysisState::NotAffected),
+///         Some(ImpactAnalysisJustification::CodeNotReachable),
+///         None,
+///     ),
+/// );
+/// let vex = vex.into_bom()?;
+///
+/// let target = &vex.vulnerabilities.unwrap().0[0].vulnerability_targets.clone().unwrap().0[0];
+/// assert!(target.bom_ref.starts_with("urn:cdx:"));
+/// # Ok::<(), cyclonedx_bom::models::vex::VexError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vex {
+    sbom: BomLinkUrn,
+    vulnerabilities: Vec<Vulnerability>,
+}
+
+impl Vex {
+    /// Starts a VEX document for the given SBOM, which needs a serial number to be linked.
+    pub fn for_bom(sbom: &Bom) -> Result<Self, VexError> {
+        let sbom = BomLinkUrn::for_bom(sbom).map_err(|error| match error {
+            BomLinkError::MissingSerialNumber => VexError::MissingSerialNumber,
+            error => VexError::InvalidBomLink(error),
+        })?;
+
+        Ok(Self {
+            sbom,
+            vulnerabilities: Vec::new(),
+        })
+    }
+
+    /// Returns the BOM-Link to an element of the SBOM, the `bom-ref` is URL encoded.
+    pub fn bom_link(&self, bom_ref: &str) -> BomLink {
+        self.sbom.clone().with_bom_ref(bom_ref).into()
+    }
+
+    /// Adds the analysis of a vulnerability for the component of the SBOM with the `bom-ref`.
+    ///
+    /// Components which share the same analysis of a vulnerability are added as targets of
+    /// the same vulnerability.
+    pub fn add_analysis(
+        &mut self,
+        vulnerability_id: &str,
+        bom_ref: &str,
+        analysis: VulnerabilityAnalysis,
+    ) -> &mut Self {
+        let target = VulnerabilityTarget::new(self.bom_link(bom_ref).0);
+        let id = NormalizedString::new(vulnerability_id);
+
+        let existing = self.vulnerabilities.iter_mut().find(|vulnerability| {
+            vulnerability.id.as_ref() == Some(&id)
+                && vulnerability.vulnerability_analysis.as_ref() == Some(&analysis)
+        });
+        match existing {
+            Some(vulnerability) => add_target(vulnerability, target),
+            None => {
+                let mut vulnerability = Vulnerability::new(None);
+                vulnerability.id = Some(id);
+                vulnerability.vulnerability_analysis = Some(analysis);
+                vulnerability.vulnerability_targets = Some(VulnerabilityTargets(vec![target]));
+                self.vulnerabilities.push(vulnerability);
+            }
+        }
+        self
+    }
+
+    /// Adds a vulnerability, the targets which are not BOM-Links yet are linked to the SBOM.
+    pub fn add_vulnerability(&mut self, mut vulnerability: Vulnerability) -> &mut Self {
+        for target in vulnerability
+            .vulnerability_targets
+            .iter_mut()
+            .flat_map(|targets| targets.0.iter_mut())
+        {
+            if !target.bom_ref.starts_with("urn:cdx:") {
+                target.bom_ref = self.bom_link(&target.bom_ref).0;
+            }
+        }
+        self.vulnerabilities.push(vulnerability);
+        self
+    }
+
+    /// Creates the VEX document with a timestamp in UTC and a new serial number.
+    pub fn into_bom(self) -> Result<Bom, VexError> {
+        Ok(Bom {
+            serial_number: Some(UrnUuid::generate()),
+            metadata: Some(Metadata::new()?),
+            vulnerabilities: Some(Vulnerabilities(self.vulnerabilities)),
+            spec_version: SpecVersion::V1_5,
+            ..Bom::default()
+        })
+    }
+
+    /// Returns the statements of a VEX document about the components of the SBOM.
+    ///
+    /// Targets are matched if they are a BOM-Link to the SBOM, ignoring its version, or a
+    /// plain `bom-ref`, which refers to the components of the VEX document itself.
+    pub fn statements<'a>(vex: &'a Bom, sbom: &Bom) -> Vec<VexStatement<'a>> {
+        vex.vulnerabilities
+            .iter()
+            .flat_map(|vulnerabilities| vulnerabilities.0.iter())
+            .flat_map(|vulnerability| {
+                vulnerability
+                    .vulnerability_targets
+                    .iter()
+                    .flat_map(|targets| targets.0.iter())
+                    .filter_map(move |target| {
+                        let bom_ref = match target.bom_ref.starts_with("urn:cdx:") {
+                            true => {
+                                let link = BomLink(target.bom_ref.clone()).parse().ok()?;
+                                let serial_number = sbom.serial_number.as_ref()?;
+                                if !link.links_to_serial_number(serial_number) {
+                                    return None;
+                                }
+                                link.bom_ref()?.to_string()
+                            }
+                            false => target.bom_ref.clone(),
+                        };
+                        Some(VexStatement {
+                            bom_ref,
+                            vulnerability,
+                        })
+                    })
+            })
+            .collect()
+    }
+}
+
+/// A vulnerability of a VEX document that applies to a component of an SBOM
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VexStatement<'a> {
+    /// The `bom-ref` of the component in the SBOM
+    pub bom_ref: String,
+    pub vulnerability: &'a Vulnerability,
+}
+
+impl VexStatement<'_> {
+    pub fn analysis(&self) -> Option<&VulnerabilityAnalysis> {
+        self.vulnerability.vulnerability_analysis.as_ref()
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum VexError {
+    #[error("The SBOM has no serial number which a VEX document can link to")]
+    MissingSerialNumber,
+
+    #[error("The SBOM cannot be linked to: {0}")]
+    InvalidBomLink(#[from] BomLinkError),
+
+    #[error("Failed to create the metadata of the VEX document: {0}")]
+    InvalidMetadata(#[from] MetadataError),
+}
+
+fn add_target(vulnerability: &mut Vulnerability, target: VulnerabilityTarget) {
+    let targets = vulnerability
+        .vulnerability_targets
+        .get_or_insert_with(|| VulnerabilityTargets(Vec::new()));
+    if !targets.0.contains(&target) {
+        targets.0.push(target);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::models::vulnerability_analysis::{
+        ImpactAnalysisJustification, ImpactAnalysisResponse, ImpactAnalysisState,
+    };
+    use crate::validation::Validate;
+    use pretty_assertions::assert_eq;
+
+    fn example_sbom() -> Bom {
+        Bom {
+            serial_number: Some(UrnUuid(
+                "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string(),
+            )),
+            version: 2,
+            ..Bom::default()
+        }
+    }
+
+    fn not_affected() -> VulnerabilityAnalysis {
+        VulnerabilityAnalysis::new(
+            Some(ImpactAnalysisState::NotAffected),
+            Some(ImpactAnalysisJustification::CodeNotReachable),
+            None,
+        )
+    }
+
+    #[test]
+    fn it_should_link_analyses_to_the_sbom() {
+        let mut vex = Vex::for_bom(&example_sbom()).unwrap();
+        vex.add_analysis("CVE-2023-0001", "pkg:cargo/a@1.0.0", not_affected())
+            .add_analysis("CVE-2023-0001", "pkg:cargo/b@1.0.0", not_affected())
+            .add_analysis(
+                "CVE-2023-0001",
+                "pkg:cargo/c@1.0.0?x=a b",
+                VulnerabilityAnalysis::new(
+                    Some(ImpactAnalysisState::Exploitable),
+                    None,
+                    Some(vec![ImpactAnalysisResponse::Update]),
+                ),
+            );
+        let vex = vex.into_bom().unwrap();
+
+        let targets = vex
+            .vulnerabilities
+            .as_ref()
+            .unwrap()
+            .0
+            .iter()
+            .map(|vulnerability| {
+                vulnerability
+                    .vulnerability_targets
+                    .as_ref()
+                    .unwrap()
+                    .0
+                    .iter()
+                    .map(|target| target.bom_ref.as_str())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            targets,
+            vec![
+                vec![
+                    "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/2#pkg:cargo/a@1.0.0",
+                    "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/2#pkg:cargo/b@1.0.0",
+                ],
+                vec!["urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/2#pkg:cargo/c@1.0.0?x=a%20b"],
+            ]
+        );
+        assert!(vex.components.is_none());
+        assert!(vex.validate_version(SpecVersion::V1_5).passed());
+    }
+
+    #[test]
+    fn it_should_require_a_serial_number() {
+        let sbom = Bom {
+            serial_number: None,
+            ..Bom::default()
+        };
+        assert_eq!(Vex::for_bom(&sbom), Err(VexError::MissingSerialNumber));
+    }
+
+    #[test]
+    fn it_should_parse_the_statements_of_a_vex_document() {
+        let sbom = example_sbom();
+        let mut vex = Vex::for_bom(&sbom).unwrap();
+        vex.add_analysis("CVE-2023-0001", "pkg:cargo/c@1.0.0?x=a b", not_affected());
+        let mut unrelated = Vulnerability::new(None);
+        unrelated.vulnerability_targets =
+            Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                "urn:cdx:00000000-0000-0000-0000-000000000000/1#pkg:cargo/a@1.0.0".to_string(),
+            )]));
+        vex.add_vulnerability(unrelated);
+
+        let mut output = Vec::new();
+        vex.into_bom()
+            .unwrap()
+            .output_as_json_v1_5(&mut output)
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert!(json.get("components").is_none());
+
+        let vex = Bom::parse_from_json_v1_5(output.as_slice()).unwrap();
+        let statements = Vex::statements(&vex, &sbom);
+
+        assert_eq!(statements.len(), 1);
+        assert_eq!(statements[0].bom_ref, "pkg:cargo/c@1.0.0?x=a b");
+        assert_eq!(statements[0].analysis(), Some(&not_affected()));
+    }
+}
@@ -16,27 +16,37 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::collections::HashSet;
+
+use thiserror::Error;
+
 use crate::external_models::normalized_string::validate_normalized_string;
 use crate::external_models::validate_date_time;
-use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString};
+use crate::external_models::{
+    date_time::{DateTime, DateTimeError, TimestampSource},
+    normalized_string::NormalizedString,
+};
 use crate::models::advisory::Advisories;
 use crate::models::property::Properties;
 use crate::models::tool::Tools;
-use crate::models::vulnerability_analysis::VulnerabilityAnalysis;
+use crate::models::vulnerability_analysis::{
+    ImpactAnalysisJustification, ImpactAnalysisState, VulnerabilityAnalysis,
+};
 use crate::models::vulnerability_credits::VulnerabilityCredits;
 use crate::models::vulnerability_rating::VulnerabilityRatings;
-use crate::models::vulnerability_reference::VulnerabilityReferences;
+use crate::models::vulnerability_reference::{VulnerabilityReference, VulnerabilityReferences};
 use crate::models::vulnerability_source::VulnerabilitySource;
-use crate::models::vulnerability_target::VulnerabilityTargets;
+use crate::models::vulnerability_target::{Versions, VulnerabilityTargets};
 use crate::validation::{Validate, ValidationContext, ValidationResult};
 
 use super::attachment::Attachment;
-use super::bom::SpecVersion;
+use super::bom::{Bom, SpecVersion, UrnUuid};
 
 /// Represents a vulnerability as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilitiesType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vulnerability {
     pub bom_ref: Option<String>,
     pub id: Option<NormalizedString>,
@@ -93,11 +103,279 @@ impl Vulnerability {
             properties: None,
         }
     }
+
+    /// Sets the state, justification and detail of the analysis, keeping its responses.
+    ///
+    /// A new analysis records the current time as `first_issued`, and any change of the analysis
+    /// records it as `last_updated`.
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::Vulnerability;
+    /// use cyclonedx_bom::models::vulnerability_analysis::ImpactAnalysisState;
+    ///
+    /// let mut vulnerability = Vulnerability::new(None);
+    /// vulnerability.set_analysis(ImpactAnalysisState::InTriage, None, None)?;
+    ///
+    /// let analysis = vulnerability.vulnerability_analysis.unwrap();
+    /// assert!(analysis.first_issued.is_some());
+    /// assert_eq!(analysis.first_issued, analysis.last_updated);
+    /// # Ok::<(), cyclonedx_bom::external_models::date_time::DateTimeError>(())
+    /// ```
+    pub fn set_analysis(
+        &mut self,
+        state: ImpactAnalysisState,
+        justification: Option<ImpactAnalysisJustification>,
+        detail: Option<String>,
+    ) -> Result<(), DateTimeError> {
+        self.set_analysis_with_timestamp(state, justification, detail, &TimestampSource::Utc)
+    }
+
+    /// Sets the analysis like [`set_analysis`](Self::set_analysis) with timestamps created from
+    /// the given source.
+    pub fn set_analysis_with_timestamp(
+        &mut self,
+        state: ImpactAnalysisState,
+        justification: Option<ImpactAnalysisJustification>,
+        detail: Option<String>,
+        source: &TimestampSource,
+    ) -> Result<(), DateTimeError> {
+        let analysis = self
+            .vulnerability_analysis
+            .get_or_insert_with(|| VulnerabilityAnalysis::new(None, None, None));
+        if analysis.state.as_ref() == Some(&state)
+            && analysis.justification == justification
+            && analysis.detail == detail
+        {
+            return Ok(());
+        }
+
+        let now = source.timestamp()?;
+        analysis.state = Some(state);
+        analysis.justification = justification;
+        analysis.detail = detail;
+        analysis.first_issued.get_or_insert_with(|| now.clone());
+        analysis.last_updated = Some(now);
+        Ok(())
+    }
+
+    /// The upper case id and ids of the references of the vulnerability
+    fn aliases(&self) -> HashSet<String> {
+        let references = self.vulnerability_references.iter().flat_map(|r| &r.0);
+        self.id
+            .iter()
+            .chain(references.map(|reference| &reference.id))
+            .map(|id| id.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Takes over the lists and the missing fields of a duplicate of this vulnerability
+    fn merge(&mut self, other: Vulnerability) {
+        fn extend<T: PartialEq>(list: &mut Vec<T>, other: Vec<T>) {
+            for item in other {
+                if !list.contains(&item) {
+                    list.push(item);
+                }
+            }
+        }
+
+        let mut references = self
+            .vulnerability_references
+            .take()
+            .map(|references| references.0)
+            .unwrap_or_default();
+        if let Some(id) = &other.id {
+            let source = other
+                .vulnerability_source
+                .clone()
+                .unwrap_or_else(|| VulnerabilitySource::new(None, None));
+            references.push(VulnerabilityReference::new(id.as_ref(), source));
+        }
+        references.extend(other.vulnerability_references.into_iter().flat_map(|r| r.0));
+        let own_id = self.id.as_ref().map(|id| id.to_ascii_uppercase());
+        let mut seen = HashSet::new();
+        references.retain(|reference| {
+            let id = reference.id.to_ascii_uppercase();
+            Some(&id) != own_id.as_ref() && seen.insert(id)
+        });
+        if !references.is_empty() {
+            self.vulnerability_references = Some(VulnerabilityReferences(references));
+        }
+
+        if let Some(ratings) = other.vulnerability_ratings {
+            let own = self
+                .vulnerability_ratings
+                .get_or_insert_with(|| VulnerabilityRatings(Vec::new()));
+            extend(&mut own.0, ratings.0);
+        }
+        if let Some(targets) = other.vulnerability_targets {
+            let own = self
+                .vulnerability_targets
+                .get_or_insert_with(|| VulnerabilityTargets(Vec::new()));
+            for target in targets.0 {
+                match own.0.iter_mut().find(|own| own.bom_ref == target.bom_ref) {
+                    Some(own) => {
+                        if let Some(versions) = target.versions {
+                            let own = own.versions.get_or_insert_with(|| Versions(Vec::new()));
+                            extend(&mut own.0, versions.0);
+                        }
+                    }
+                    None => own.0.push(target),
+                }
+            }
+        }
+        if let Some(advisories) = other.advisories {
+            let own = self
+                .advisories
+                .get_or_insert_with(|| Advisories(Vec::new()));
+            extend(&mut own.0, advisories.0);
+        }
+        if let Some(cwes) = other.cwes {
+            extend(self.cwes.get_or_insert_with(Vec::new), cwes);
+        }
+        if let Some(properties) = other.properties {
+            let own = self
+                .properties
+                .get_or_insert_with(|| Properties(Vec::new()));
+            extend(&mut own.0, properties.0);
+        }
+
+        self.bom_ref = self.bom_ref.take().or(other.bom_ref);
+        self.id = self.id.take().or(other.id);
+        self.vulnerability_source = self
+            .vulnerability_source
+            .take()
+            .or(other.vulnerability_source);
+        self.description = self.description.take().or(other.description);
+        self.detail = self.detail.take().or(other.detail);
+        self.recommendation = self.recommendation.take().or(other.recommendation);
+        self.workaround = self.workaround.take().or(other.workaround);
+        self.proof_of_concept = self.proof_of_concept.take().or(other.proof_of_concept);
+        self.created = self.created.take().or(other.created);
+        self.published = self.published.take().or(other.published);
+        self.updated = self.updated.take().or(other.updated);
+        self.rejected = self.rejected.take().or(other.rejected);
+        self.vulnerability_credits = self
+            .vulnerability_credits
+            .take()
+            .or(other.vulnerability_credits);
+        self.tools = self.tools.take().or(other.tools);
+        self.vulnerability_analysis = self
+            .vulnerability_analysis
+            .take()
+            .or(other.vulnerability_analysis);
+    }
+
+    /// The id and source name, or else the bom-ref, identifying the vulnerability across BOM versions
+    fn analysis_key(&self) -> Option<(Option<&str>, &str)> {
+        let source = self
+            .vulnerability_source
+            .as_ref()
+            .and_then(|source| source.name.as_deref());
+        match (&self.id, &self.bom_ref) {
+            (Some(id), _) => Some((source, id.as_ref())),
+            (None, Some(bom_ref)) => Some((None, bom_ref.as_str())),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Bom {
+    /// Carries the analysis of the vulnerabilities over from a previous version of this BOM.
+    ///
+    /// Vulnerabilities are matched by their id and source, or else their bom-ref. A vulnerability
+    /// without analysis takes the previous analysis as is. A vulnerability with an analysis keeps
+    /// it, but takes the previous `first_issued`, and also the previous `last_updated` if the
+    /// analysis did not change. Returns the number of vulnerabilities that took over an analysis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the previous BOM has a different serial number or is not an earlier
+    /// version.
+    pub fn merge_vulnerability_analysis(
+        &mut self,
+        previous: &Bom,
+    ) -> Result<usize, AnalysisMergeError> {
+        let serial_number = self
+            .serial_number
+            .as_ref()
+            .ok_or(AnalysisMergeError::MissingSerialNumber)?;
+        if previous.serial_number.as_ref() != Some(serial_number) {
+            return Err(AnalysisMergeError::SerialNumberMismatch {
+                current: serial_number.clone(),
+                previous: previous.serial_number.clone(),
+            });
+        }
+        if previous.version >= self.version {
+            return Err(AnalysisMergeError::NotAPreviousVersion {
+                current: self.version,
+                previous: previous.version,
+            });
+        }
+
+        let previous_analyses: Vec<_> = previous
+            .vulnerabilities
+            .iter()
+            .flat_map(|vulnerabilities| &vulnerabilities.0)
+            .filter_map(|vulnerability| {
+                Some((
+                    vulnerability.analysis_key()?,
+                    vulnerability.vulnerability_analysis.as_ref()?,
+                ))
+            })
+            .collect();
+
+        let mut merged = 0;
+        for vulnerability in self
+            .vulnerabilities
+            .iter_mut()
+            .flat_map(|vulnerabilities| &mut vulnerabilities.0)
+        {
+            let Some(key) = vulnerability.analysis_key() else {
+                continue;
+            };
+            let Some((_, previous)) = previous_analyses.iter().find(|(k, _)| *k == key) else {
+                continue;
+            };
+
+            match &mut vulnerability.vulnerability_analysis {
+                None => vulnerability.vulnerability_analysis = Some((*previous).clone()),
+                Some(current) => {
+                    let unchanged = current.state == previous.state
+                        && current.justification == previous.justification
+                        && current.responses == previous.responses
+                        && current.detail == previous.detail;
+                    if previous.first_issued.is_some() {
+                        current.first_issued = previous.first_issued.clone();
+                    }
+                    if unchanged && previous.last_updated.is_some() {
+                        current.last_updated = previous.last_updated.clone();
+                    }
+                }
+            }
+            merged += 1;
+        }
+        Ok(merged)
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum AnalysisMergeError {
+    #[error("The BOM has no serial number to identify its previous versions")]
+    MissingSerialNumber,
+
+    #[error("The previous BOM has the serial number {previous:?} instead of {current}")]
+    SerialNumberMismatch {
+        current: UrnUuid,
+        previous: Option<UrnUuid>,
+    },
+
+    #[error("Version {previous} is not a previous version of version {current}")]
+    NotAPreviousVersion { current: u32, previous: u32 },
 }
 
 impl Validate for Vulnerability {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
+            .set_bom_ref(self.bom_ref.as_deref())
             .add_field_option("id", self.id.as_ref(), validate_normalized_string)
             .add_struct_option(
                 "vulnerability_source",
@@ -120,6 +398,7 @@ impl Validate for Vulnerability {
             .add_field_option("created", self.created.as_ref(), validate_date_time)
             .add_field_option("published", self.published.as_ref(), validate_date_time)
             .add_field_option("updated", self.updated.as_ref(), validate_date_time)
+            .add_field_option("rejected", self.rejected.as_ref(), validate_date_time)
             .add_struct_option(
                 "vulnerability_credits",
                 self.vulnerability_credits.as_ref(),
@@ -141,9 +420,81 @@ impl Validate for Vulnerability {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vulnerabilities(pub Vec<Vulnerability>);
 
+impl Vulnerabilities {
+    /// Merges vulnerabilities which share an id or alias, e.g. a RUSTSEC advisory and the
+    /// GHSA advisory of the same CVE.
+    ///
+    /// The aliases of a vulnerability are its id and the ids of its references, compared
+    /// case-insensitively. The first vulnerability of each group is kept, it takes over the
+    /// ratings, targets, references, advisories, CWEs and properties of the others, with the
+    /// ids of the others as references, and all fields it does not have itself. Returns the
+    /// number of vulnerabilities merged into others.
+    /// ```
+    /// use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+    /// use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+    /// use cyclonedx_bom::models::vulnerability_reference::{VulnerabilityReference, VulnerabilityReferences};
+    /// use cyclonedx_bom::models::vulnerability_source::VulnerabilitySource;
+    ///
+    /// let mut rustsec = Vulnerability::new(None);
+    /// rustsec.id = Some(NormalizedString::new("RUSTSEC-2021-0001"));
+    /// rustsec.vulnerability_references = Some(VulnerabilityReferences(vec![
+    ///     VulnerabilityReference::new("CVE-2021-0001", VulnerabilitySource::new(None, None)),
+    /// ]));
+    /// let mut cve = Vulnerability::new(None);
+    /// cve.id = Some(NormalizedString::new("CVE-2021-0001"));
+    ///
+    /// let mut vulnerabilities = Vulnerabilities(vec![rustsec, cve]);
+    /// assert_eq!(vulnerabilities.dedupe(), 1);
+    /// assert_eq!(vulnerabilities.0.len(), 1);
+    /// ```
+    pub fn dedupe(&mut self) -> usize {
+        let mut deduped: Vec<(HashSet<String>, Vulnerability)> = Vec::new();
+        let mut merged = 0;
+
+        for vulnerability in std::mem::take(&mut self.0) {
+            let aliases = vulnerability.aliases();
+            let mut matching = deduped
+                .iter()
+                .enumerate()
+                .filter(|(_, (known, _))| !known.is_disjoint(&aliases))
+                .map(|(index, _)| index);
+
+            let Some(first) = matching.next() else {
+                deduped.push((aliases, vulnerability));
+                continue;
+            };
+            // A vulnerability may join groups that did not share an alias so far
+            let others: Vec<usize> = matching.collect();
+            merged += others.len();
+            for index in others.into_iter().rev() {
+                let (other_aliases, other) = deduped.remove(index);
+                deduped[first].0.extend(other_aliases);
+                deduped[first].1.merge(other);
+            }
+            deduped[first].0.extend(aliases);
+            deduped[first].1.merge(vulnerability);
+            merged += 1;
+        }
+
+        self.0 = deduped
+            .into_iter()
+            .map(|(_, vulnerability)| vulnerability)
+            .collect();
+        merged
+    }
+
+    /// Appends the vulnerabilities, e.g. of another BOM or imported advisories, and merges
+    /// duplicates like [`dedupe`](Self::dedupe).
+    pub fn merge(&mut self, other: Vulnerabilities) -> usize {
+        self.0.extend(other.0);
+        self.dedupe()
+    }
+}
+
 impl Validate for Vulnerabilities {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -154,7 +505,8 @@ impl Validate for Vulnerabilities {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VulnerabilityProofOfConcept {
     pub reproduction_steps: Option<String>,
     pub environment: Option<String>,
@@ -421,6 +773,7 @@ mod test {
                         validation::field("created", "DateTime does not conform to ISO 8601"),
                         validation::field("published", "DateTime does not conform to ISO 8601"),
                         validation::field("updated", "DateTime does not conform to ISO 8601"),
+                        validation::field("rejected", "DateTime does not conform to ISO 8601"),
                         validation::r#struct(
                             "vulnerability_analysis",
                             vec![
@@ -455,4 +808,311 @@ mod test {
             )
         );
     }
+
+    fn timestamp(value: &str) -> TimestampSource {
+        value.parse().expect("Failed to parse timestamp")
+    }
+
+    #[test]
+    fn it_should_record_the_timestamps_of_analysis_changes() {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability
+            .set_analysis_with_timestamp(
+                ImpactAnalysisState::InTriage,
+                None,
+                None,
+                &timestamp("2024-01-01T00:00:00Z"),
+            )
+            .expect("Failed to set analysis");
+        vulnerability
+            .set_analysis_with_timestamp(
+                ImpactAnalysisState::InTriage,
+                None,
+                None,
+                &timestamp("2024-01-02T00:00:00Z"),
+            )
+            .expect("Failed to set analysis");
+        vulnerability
+            .vulnerability_analysis
+            .as_mut()
+            .unwrap()
+            .responses = Some(vec![ImpactAnalysisResponse::Update]);
+        vulnerability
+            .set_analysis_with_timestamp(
+                ImpactAnalysisState::NotAffected,
+                Some(ImpactAnalysisJustification::CodeNotReachable),
+                Some("Not called".to_string()),
+                &timestamp("2024-01-03T00:00:00Z"),
+            )
+            .expect("Failed to set analysis");
+
+        let analysis = vulnerability.vulnerability_analysis.unwrap();
+        assert_eq!(analysis.state, Some(ImpactAnalysisState::NotAffected));
+        assert_eq!(
+            analysis.justification,
+            Some(ImpactAnalysisJustification::CodeNotReachable)
+        );
+        assert_eq!(analysis.detail, Some("Not called".to_string()));
+        assert_eq!(
+            analysis.responses,
+            Some(vec![ImpactAnalysisResponse::Update])
+        );
+        assert_eq!(
+            analysis.first_issued,
+            Some(DateTime("2024-01-01T00:00:00Z".to_string()))
+        );
+        assert_eq!(
+            analysis.last_updated,
+            Some(DateTime("2024-01-03T00:00:00Z".to_string()))
+        );
+    }
+
+    fn versioned_bom(version: u32, vulnerabilities: Vec<Vulnerability>) -> Bom {
+        Bom {
+            version,
+            serial_number: Some(
+                UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap(),
+            ),
+            vulnerabilities: Some(Vulnerabilities(vulnerabilities)),
+            ..Bom::default()
+        }
+    }
+
+    fn analyzed(id: &str, state: ImpactAnalysisState, date: &str) -> Vulnerability {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.id = Some(NormalizedString::new(id));
+        vulnerability
+            .set_analysis_with_timestamp(state, None, None, &timestamp(date))
+            .expect("Failed to set analysis");
+        vulnerability
+    }
+
+    #[test]
+    fn it_should_merge_the_analysis_of_a_previous_version() {
+        let previous = versioned_bom(
+            1,
+            vec![
+                analyzed(
+                    "CVE-1",
+                    ImpactAnalysisState::NotAffected,
+                    "2024-01-01T00:00:00Z",
+                ),
+                analyzed(
+                    "CVE-2",
+                    ImpactAnalysisState::InTriage,
+                    "2024-01-01T00:00:00Z",
+                ),
+                analyzed(
+                    "CVE-3",
+                    ImpactAnalysisState::Exploitable,
+                    "2024-01-01T00:00:00Z",
+                ),
+            ],
+        );
+        let mut unanalyzed = Vulnerability::new(None);
+        unanalyzed.id = Some(NormalizedString::new("CVE-1"));
+        let mut current = versioned_bom(
+            2,
+            vec![
+                unanalyzed,
+                analyzed(
+                    "CVE-2",
+                    ImpactAnalysisState::Resolved,
+                    "2024-02-01T00:00:00Z",
+                ),
+                analyzed(
+                    "CVE-3",
+                    ImpactAnalysisState::Exploitable,
+                    "2024-02-01T00:00:00Z",
+                ),
+                analyzed(
+                    "CVE-4",
+                    ImpactAnalysisState::InTriage,
+                    "2024-02-01T00:00:00Z",
+                ),
+            ],
+        );
+
+        let merged = current
+            .merge_vulnerability_analysis(&previous)
+            .expect("Failed to merge analysis");
+
+        assert_eq!(merged, 3);
+        let analyses: Vec<_> = current
+            .vulnerabilities
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|vulnerability| {
+                let analysis = vulnerability.vulnerability_analysis.unwrap();
+                (
+                    analysis.state.unwrap(),
+                    analysis.first_issued.unwrap().to_string(),
+                    analysis.last_updated.unwrap().to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            analyses,
+            [
+                (
+                    ImpactAnalysisState::NotAffected,
+                    "2024-01-01T00:00:00Z".to_string(),
+                    "2024-01-01T00:00:00Z".to_string()
+                ),
+                (
+                    ImpactAnalysisState::Resolved,
+                    "2024-01-01T00:00:00Z".to_string(),
+                    "2024-02-01T00:00:00Z".to_string()
+                ),
+                (
+                    ImpactAnalysisState::Exploitable,
+                    "2024-01-01T00:00:00Z".to_string(),
+                    "2024-01-01T00:00:00Z".to_string()
+                ),
+                (
+                    ImpactAnalysisState::InTriage,
+                    "2024-02-01T00:00:00Z".to_string(),
+                    "2024-02-01T00:00:00Z".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_only_merge_the_analysis_of_previous_versions_of_the_same_bom() {
+        let mut current = versioned_bom(2, vec![]);
+
+        let mut other = versioned_bom(1, vec![]);
+        other.serial_number = None;
+        assert_eq!(
+            current.merge_vulnerability_analysis(&other),
+            Err(AnalysisMergeError::SerialNumberMismatch {
+                current: current.serial_number.clone().unwrap(),
+                previous: None,
+            })
+        );
+        assert_eq!(
+            current.merge_vulnerability_analysis(&versioned_bom(2, vec![])),
+            Err(AnalysisMergeError::NotAPreviousVersion {
+                current: 2,
+                previous: 2
+            })
+        );
+
+        current.serial_number = None;
+        assert_eq!(
+            current.merge_vulnerability_analysis(&versioned_bom(1, vec![])),
+            Err(AnalysisMergeError::MissingSerialNumber)
+        );
+    }
+
+    fn advisory(id: &str, aliases: &[&str]) -> Vulnerability {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.id = Some(NormalizedString::new(id));
+        if !aliases.is_empty() {
+            vulnerability.vulnerability_references = Some(VulnerabilityReferences(
+                aliases
+                    .iter()
+                    .map(|alias| {
+                        VulnerabilityReference::new(alias, VulnerabilitySource::new(None, None))
+                    })
+                    .collect(),
+            ));
+        }
+        vulnerability
+    }
+
+    fn reference_ids(vulnerability: &Vulnerability) -> Vec<String> {
+        vulnerability
+            .vulnerability_references
+            .iter()
+            .flat_map(|references| &references.0)
+            .map(|reference| reference.id.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn it_should_dedupe_vulnerabilities_by_their_aliases() {
+        let rating = |severity| VulnerabilityRating::new(None, Some(severity), None);
+        let mut rustsec = advisory("RUSTSEC-2021-0001", &["CVE-2021-0001"]);
+        rustsec.vulnerability_ratings = Some(VulnerabilityRatings(vec![rating(Severity::High)]));
+        rustsec.vulnerability_targets = Some(VulnerabilityTargets(vec![VulnerabilityTarget {
+            bom_ref: "pkg:cargo/example".to_string(),
+            versions: Some(Versions(vec![Version::new(
+                "vers:cargo/<1.0.0",
+                "affected",
+            )])),
+        }]));
+        let mut ghsa = advisory("GHSA-aaaa-bbbb-cccc", &["cve-2021-0001"]);
+        ghsa.description = Some("An issue".to_string());
+        ghsa.vulnerability_ratings = Some(VulnerabilityRatings(vec![
+            rating(Severity::High),
+            rating(Severity::Critical),
+        ]));
+        ghsa.vulnerability_targets = Some(VulnerabilityTargets(vec![
+            VulnerabilityTarget {
+                bom_ref: "pkg:cargo/example".to_string(),
+                versions: Some(Versions(vec![
+                    Version::new("vers:cargo/<1.0.0", "affected"),
+                    Version::new("1.0.0", "unaffected"),
+                ])),
+            },
+            VulnerabilityTarget::new("pkg:cargo/other".to_string()),
+        ]));
+        let unrelated = advisory("RUSTSEC-2022-0002", &[]);
+        let mut vulnerabilities = Vulnerabilities(vec![rustsec, unrelated.clone(), ghsa]);
+
+        assert_eq!(vulnerabilities.dedupe(), 1);
+
+        assert_eq!(vulnerabilities.0.len(), 2);
+        assert_eq!(vulnerabilities.0[1], unrelated);
+        let merged = &vulnerabilities.0[0];
+        assert_eq!(merged.id, Some(NormalizedString::new("RUSTSEC-2021-0001")));
+        assert_eq!(merged.description, Some("An issue".to_string()));
+        assert_eq!(
+            reference_ids(merged),
+            ["CVE-2021-0001", "GHSA-aaaa-bbbb-cccc"]
+        );
+        assert_eq!(
+            merged.vulnerability_ratings,
+            Some(VulnerabilityRatings(vec![
+                rating(Severity::High),
+                rating(Severity::Critical),
+            ]))
+        );
+        assert_eq!(
+            merged.vulnerability_targets,
+            Some(VulnerabilityTargets(vec![
+                VulnerabilityTarget {
+                    bom_ref: "pkg:cargo/example".to_string(),
+                    versions: Some(Versions(vec![
+                        Version::new("vers:cargo/<1.0.0", "affected"),
+                        Version::new("1.0.0", "unaffected"),
+                    ])),
+                },
+                VulnerabilityTarget::new("pkg:cargo/other".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_join_groups_of_vulnerabilities_by_a_shared_alias() {
+        let mut vulnerabilities = Vulnerabilities(vec![
+            advisory("CVE-2021-0001", &[]),
+            advisory("GHSA-aaaa-bbbb-cccc", &[]),
+        ]);
+
+        let merged = vulnerabilities.merge(Vulnerabilities(vec![advisory(
+            "RUSTSEC-2021-0001",
+            &["CVE-2021-0001", "GHSA-aaaa-bbbb-cccc"],
+        )]));
+
+        assert_eq!(merged, 2);
+        assert_eq!(vulnerabilities.0.len(), 1);
+        assert_eq!(
+            reference_ids(&vulnerabilities.0[0]),
+            ["GHSA-aaaa-bbbb-cccc", "RUSTSEC-2021-0001"]
+        );
+    }
 }
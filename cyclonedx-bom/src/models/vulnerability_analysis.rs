@@ -27,7 +27,8 @@ use super::bom::SpecVersion;
 /// Represents a vulnerability's analysis as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VulnerabilityAnalysis {
     pub state: Option<ImpactAnalysisState>,
     pub justification: Option<ImpactAnalysisJustification>,
@@ -102,7 +103,8 @@ pub fn validate_impact_analysis_state(state: &ImpactAnalysisState) -> Result<(),
 /// Specifies a vulnerability's state according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisStateType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "snake_case")]
 pub enum ImpactAnalysisState {
     Resolved,
@@ -130,6 +132,12 @@ impl ImpactAnalysisState {
     }
 }
 
+from_str_via_new_unchecked!(
+    ImpactAnalysisState,
+    UndefinedImpactAnalysisState,
+    "impact analysis state"
+);
+
 pub fn validate_impact_analysis_justification(
     justification: &ImpactAnalysisJustification,
 ) -> Result<(), ValidationError> {
@@ -145,7 +153,8 @@ pub fn validate_impact_analysis_justification(
 /// Justifies the vulnerability's state according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisJustificationType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "snake_case")]
 pub enum ImpactAnalysisJustification {
     CodeNotPresent,
@@ -179,6 +188,12 @@ impl ImpactAnalysisJustification {
     }
 }
 
+from_str_via_new_unchecked!(
+    ImpactAnalysisJustification,
+    UndefinedImpactAnalysisJustification,
+    "impact analysis justification"
+);
+
 pub fn validate_impact_analysis_response(
     response: &ImpactAnalysisResponse,
 ) -> Result<(), ValidationError> {
@@ -191,7 +206,8 @@ pub fn validate_impact_analysis_response(
 /// Provides a response to the vulnerability according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisResponsesType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "snake_case")]
 pub enum ImpactAnalysisResponse {
     CanNotFix,
@@ -217,6 +233,12 @@ impl ImpactAnalysisResponse {
     }
 }
 
+from_str_via_new_unchecked!(
+    ImpactAnalysisResponse,
+    UndefinedResponse,
+    "impact analysis response"
+);
+
 #[cfg(test)]
 mod test {
     use crate::validation;
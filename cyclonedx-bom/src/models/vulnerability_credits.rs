@@ -22,7 +22,8 @@ use crate::validation::{Validate, ValidationContext, ValidationResult};
 use super::bom::SpecVersion;
 
 /// Provides credits to organizations or individuals who contributed to a vulnerability.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VulnerabilityCredits {
     pub organizations: Option<Vec<OrganizationalEntity>>,
     pub individuals: Option<Vec<OrganizationalContact>>,
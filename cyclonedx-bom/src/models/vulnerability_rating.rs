@@ -27,7 +27,8 @@ use super::bom::SpecVersion;
 /// Represents a vulnerability's rating as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_ratingType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VulnerabilityRating {
     pub vulnerability_source: Option<VulnerabilitySource>,
     pub score: Option<Score>,
@@ -78,7 +79,8 @@ impl Validate for VulnerabilityRating {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VulnerabilityRatings(pub Vec<VulnerabilityRating>);
 
 impl Validate for VulnerabilityRatings {
@@ -96,7 +98,8 @@ impl Validate for VulnerabilityRatings {
 /// convert a f32 into i32 because OWASP's scoring method uses up to three decimal places.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_ratingType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Score(OrderedFloat<f32>);
 
 impl Score {
@@ -135,7 +138,8 @@ pub fn validate_severity(severity: &Severity) -> Result<(), ValidationError> {
 /// Specifies a vulnerability's severity adopted by the analysis method.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_severityType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Severity {
     Critical,
@@ -165,6 +169,8 @@ impl Severity {
     }
 }
 
+from_str_via_new_unchecked!(Severity, UndefinedSeverity, "severity");
+
 pub fn validate_score_method(
     method: &ScoreMethod,
     version: SpecVersion,
@@ -173,10 +179,8 @@ pub fn validate_score_method(
         if ScoreMethod::OWASP < *method {
             return Err(format!("Unknown score method '{method}'").into());
         }
-    } else if version <= SpecVersion::V1_5 {
-        if let ScoreMethod::Unknown(unknown) = method {
-            return Err(format!("Unknown score method '{unknown}'").into());
-        }
+    } else if let ScoreMethod::Unknown(unknown) = method {
+        return Err(format!("Unknown score method '{unknown}'").into());
     }
     Ok(())
 }
@@ -184,7 +188,8 @@ pub fn validate_score_method(
 /// Specifies the risk scoring method or standard used.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_scoreSourceType)
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum ScoreMethod {
     CVSSv2 = 1,
@@ -213,6 +218,8 @@ impl ScoreMethod {
     }
 }
 
+from_str_via_new_unchecked!(ScoreMethod, Unknown, "score method");
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -20,6 +20,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::external_models::normalized_string::NormalizedString;
+use crate::models::vers::{Comparator, Vers, VersError, VersionConstraint, VersionScheme};
 use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
 
 use super::bom::SpecVersion;
@@ -27,7 +28,8 @@ use super::bom::SpecVersion;
 /// Defines how a component or service is affected by a vulnerability as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VulnerabilityTarget {
     pub bom_ref: String,
     pub versions: Option<Versions>,
@@ -56,7 +58,8 @@ impl Validate for VulnerabilityTarget {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VulnerabilityTargets(pub Vec<VulnerabilityTarget>);
 
 impl Validate for VulnerabilityTargets {
@@ -67,9 +70,26 @@ impl Validate for VulnerabilityTargets {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Versions(pub Vec<Version>);
 
+impl Versions {
+    /// Returns the status of the first entry whose version or range contains the version.
+    pub fn status_of(
+        &self,
+        version: &str,
+        scheme: VersionScheme,
+    ) -> Result<Option<&Status>, VersError> {
+        for entry in &self.0 {
+            if entry.version_range.contains(version, scheme)? {
+                return Ok(Some(&entry.status));
+            }
+        }
+        Ok(None)
+    }
+}
+
 impl Validate for Versions {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -78,7 +98,8 @@ impl Validate for Versions {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version {
     pub version_range: VersionRange,
     pub status: Status,
@@ -109,17 +130,24 @@ impl Validate for Version {
 }
 
 pub fn validate_version_range(range: &VersionRange) -> Result<(), ValidationError> {
-    if matches!(range, VersionRange::UndefinedVersionRange(_)) {
-        return Err(ValidationError::new("Undefined version range"));
+    match range {
+        VersionRange::UndefinedVersionRange(_) => {
+            Err(ValidationError::new("Undefined version range"))
+        }
+        VersionRange::Range(range) => match range.parse::<Vers>() {
+            Ok(_) => Ok(()),
+            Err(error) => Err(ValidationError::new(error)),
+        },
+        VersionRange::Version(_) => Ok(()),
     }
-    Ok(())
 }
 
 /// Specifies a single version or a version range.
 ///
 /// Defined via the [PURL specification](https://github.com/package-url/purl-spec/blob/master/PURL-SPECIFICATION.rst)
 /// Spec for version ranges still work in progress [PURL version-range-spec](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VersionRange {
     #[strum(default)]
     Version(NormalizedString),
@@ -137,6 +165,36 @@ impl VersionRange {
             false => VersionRange::Version(NormalizedString::new(value)),
         }
     }
+
+    /// Returns `true` if the version equals the single version or is in the `vers` range.
+    /// ```
+    /// use cyclonedx_bom::models::vers::VersionScheme;
+    /// use cyclonedx_bom::models::vulnerability_target::VersionRange;
+    ///
+    /// let range = VersionRange::new("vers:cargo/>=1.0.0|<1.2.3");
+    /// assert!(range.contains("1.2.0", VersionScheme::Cargo)?);
+    /// # Ok::<(), cyclonedx_bom::models::vers::VersError>(())
+    /// ```
+    /// # Errors
+    ///
+    /// Returns an error if the range is invalid or has another scheme, or if a version is not
+    /// valid in the scheme.
+    pub fn contains(&self, version: &str, scheme: VersionScheme) -> Result<bool, VersError> {
+        match self {
+            VersionRange::Version(single) => Vers {
+                scheme: scheme.to_string(),
+                constraints: vec![VersionConstraint::Compare {
+                    comparator: Comparator::Equal,
+                    version: single.to_string(),
+                }],
+            }
+            .contains(version, scheme),
+            VersionRange::Range(range) => range.parse::<Vers>()?.contains(version, scheme),
+            VersionRange::UndefinedVersionRange(range) => {
+                Err(VersError::InvalidRange(range.clone()))
+            }
+        }
+    }
 }
 
 fn matches_purl_version_range_regex(value: &str) -> bool {
@@ -156,7 +214,8 @@ pub fn validate_status(status: &Status) -> Result<(), ValidationError> {
 /// Specifies if a vulnerability affects a component or service.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisAffectedStatusType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Status {
     Affected,
@@ -178,6 +237,8 @@ impl Status {
     }
 }
 
+from_str_via_new_unchecked!(Status, UndefinedStatus, "status");
+
 #[cfg(test)]
 mod test {
     use crate::validation;
@@ -241,4 +302,27 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn it_should_look_up_the_status_of_a_version() {
+        let versions = Versions(vec![
+            Version::new("vers:cargo/>=1.0.0|<1.2.3", "affected"),
+            Version::new("1.2.3", "unaffected"),
+        ]);
+
+        let status = |version| versions.status_of(version, VersionScheme::Cargo).unwrap();
+        assert_eq!(status("1.1.0"), Some(&Status::Affected));
+        assert_eq!(status("1.2.3"), Some(&Status::Unaffected));
+        assert_eq!(status("0.9.0"), None);
+    }
+
+    #[test]
+    fn invalid_vers_ranges_should_fail_validation() {
+        let validation_result = Version::new("vers:cargo/", "affected").validate();
+
+        assert_eq!(
+            validation_result,
+            validation::r#enum("version_range", "Invalid vers range: vers:cargo/"),
+        );
+    }
 }
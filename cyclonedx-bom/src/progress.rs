@@ -0,0 +1,268 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Progress reporting for long running operations on large documents.
+//!
+//! Operations that support progress reporting accept a [`ProgressHandler`], which is implemented
+//! for all closures taking a [`Progress`]:
+//!
+//! ```
+//! use std::cell::Cell;
+//! use cyclonedx_bom::prelude::*;
+//! use cyclonedx_bom::progress::{Phase, Progress};
+//!
+//! let reports = Cell::new(0);
+//! let bom = Bom::default();
+//! let result = bom.validate_with_progress(&|progress: Progress| {
+//!     assert_eq!(progress.phase, Phase::Validation);
+//!     reports.set(reports.get() + 1);
+//! });
+//!
+//! assert!(result.passed());
+//! assert!(reports.get() > 0);
+//! ```
+
+use std::io::Read;
+
+/// The phase of an operation that progress is reported for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+#[non_exhaustive]
+pub enum Phase {
+    /// Reading a document, items are bytes
+    Parse,
+    /// Validating a BOM, items are top level components
+    Validation,
+    /// Merging BOMs, items are components
+    Merge,
+    /// Generating BOMs, items are the packages a BOM is generated for
+    Generation,
+}
+
+/// A progress report
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub phase: Phase,
+    /// The number of items processed so far
+    pub processed: u64,
+    /// The total number of items, if known in advance
+    pub total: Option<u64>,
+}
+
+impl Progress {
+    pub fn new(phase: Phase, processed: u64, total: Option<u64>) -> Self {
+        Self {
+            phase,
+            processed,
+            total,
+        }
+    }
+
+    /// Returns `true` if all items are processed, which is never the case if the total is
+    /// unknown
+    pub fn is_finished(&self) -> bool {
+        self.total.is_some_and(|total| self.processed >= total)
+    }
+}
+
+/// Receives progress reports
+pub trait ProgressHandler {
+    fn on_progress(&self, progress: Progress);
+}
+
+impl<F> ProgressHandler for F
+where
+    F: Fn(Progress),
+{
+    fn on_progress(&self, progress: Progress) {
+        self(progress)
+    }
+}
+
+/// A [`ProgressHandler`] that ignores all reports
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoProgress;
+
+impl ProgressHandler for NoProgress {
+    fn on_progress(&self, _progress: Progress) {}
+}
+
+/// Wraps a reader and reports the bytes read in the [`Phase::Parse`] phase.
+///
+/// Any of the parse functions of [`Bom`](crate::models::bom::Bom) can be used with the wrapped
+/// reader. Reports are sent at most once per [`report_interval`](Self::with_report_interval)
+/// bytes, and a final report is sent at the end of the input.
+pub struct ProgressReader<'a, R> {
+    inner: R,
+    handler: &'a dyn ProgressHandler,
+    processed: u64,
+    reported: u64,
+    total: Option<u64>,
+    report_interval: u64,
+}
+
+impl<'a, R: Read> ProgressReader<'a, R> {
+    const DEFAULT_REPORT_INTERVAL: u64 = 64 * 1024;
+
+    pub fn new(inner: R, handler: &'a dyn ProgressHandler) -> Self {
+        Self {
+            inner,
+            handler,
+            processed: 0,
+            reported: 0,
+            total: None,
+            report_interval: Self::DEFAULT_REPORT_INTERVAL,
+        }
+    }
+
+    /// Sets the total number of bytes of the input, e.g. the size of the file
+    pub fn with_total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    /// Sets the number of bytes between two reports
+    pub fn with_report_interval(mut self, report_interval: u64) -> Self {
+        self.report_interval = report_interval.max(1);
+        self
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn report(&mut self) {
+        self.reported = self.processed;
+        self.handler
+            .on_progress(Progress::new(Phase::Parse, self.processed, self.total));
+    }
+}
+
+impl<R: Read> Read for ProgressReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.processed += read as u64;
+        let at_end = read == 0 && !buf.is_empty() && self.reported != self.processed;
+        if at_end || self.processed - self.reported >= self.report_interval {
+            self.report();
+        }
+        Ok(read)
+    }
+}
+
+/// Counts the processed items of an operation and reports them to a handler.
+pub(crate) struct ProgressTracker<'a> {
+    handler: &'a dyn ProgressHandler,
+    phase: Phase,
+    processed: std::cell::Cell<u64>,
+    total: Option<u64>,
+}
+
+impl<'a> ProgressTracker<'a> {
+    /// Creates the tracker and sends an initial report
+    pub(crate) fn start(
+        handler: &'a dyn ProgressHandler,
+        phase: Phase,
+        total: Option<u64>,
+    ) -> Self {
+        let tracker = Self {
+            handler,
+            phase,
+            processed: std::cell::Cell::new(0),
+            total,
+        };
+        tracker.report();
+        tracker
+    }
+
+    pub(crate) fn advance(&self, items: u64) {
+        self.processed.set(self.processed.get() + items);
+        self.report();
+    }
+
+    /// Sends the final report, marking all items as processed
+    pub(crate) fn finish(self) {
+        if let Some(total) = self.total {
+            if self.processed.get() < total {
+                self.processed.set(total);
+                self.report();
+            }
+        }
+    }
+
+    fn report(&self) {
+        self.handler
+            .on_progress(Progress::new(self.phase, self.processed.get(), self.total));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_report_bytes_read() {
+        let reports = RefCell::new(Vec::new());
+        let handler = |progress: Progress| reports.borrow_mut().push(progress);
+        let input = vec![b'a'; 10];
+
+        let mut reader = ProgressReader::new(input.as_slice(), &handler)
+            .with_total(10)
+            .with_report_interval(4);
+        let mut output = Vec::new();
+        let mut buffer = [0; 3];
+        loop {
+            let read = reader.read(&mut buffer).unwrap();
+            if read == 0 {
+                break;
+            }
+            output.extend_from_slice(&buffer[..read]);
+        }
+
+        assert_eq!(output, input);
+        assert_eq!(
+            reports.into_inner(),
+            vec![
+                Progress::new(Phase::Parse, 6, Some(10)),
+                Progress::new(Phase::Parse, 10, Some(10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_track_processed_items() {
+        let reports = RefCell::new(Vec::new());
+        let handler = |progress: Progress| reports.borrow_mut().push(progress);
+
+        let tracker = ProgressTracker::start(&handler, Phase::Merge, Some(3));
+        tracker.advance(1);
+        tracker.finish();
+
+        assert_eq!(
+            reports.into_inner(),
+            vec![
+                Progress::new(Phase::Merge, 0, Some(3)),
+                Progress::new(Phase::Merge, 1, Some(3)),
+                Progress::new(Phase::Merge, 3, Some(3)),
+            ]
+        );
+    }
+}
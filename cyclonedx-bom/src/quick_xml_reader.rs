@@ -0,0 +1,392 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Reads XML documents with [`quick_xml`] and translates its events into the events of `xml-rs`,
+//! so the readers of the elements work with either parser.
+//!
+//! The events are the same as those of an `xml-rs` reader with [`crate::xml::parser_config`]:
+//! text is trimmed and coalesced with CDATA sections, whitespace and comments are dropped, empty
+//! elements are expanded into a start and an end event, and names are resolved against the
+//! namespaces in scope.
+
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::io::{BufReader, Read};
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    name::{PrefixDeclaration, QName},
+    Reader,
+};
+use xml::{
+    attribute::OwnedAttribute,
+    common::{TextPosition, XmlVersion},
+    name::OwnedName,
+    namespace::{NamespaceStack, NS_NO_PREFIX},
+    reader::{Error, XmlEvent},
+};
+
+pub(crate) struct QuickXmlReader<R: Read> {
+    reader: Reader<BufReader<R>>,
+    buffer: Vec<u8>,
+    namespaces: NamespaceStack,
+    /// The names of the open elements, to check that the root element is closed
+    elements: Vec<OwnedName>,
+    /// The text read since the last element, to coalesce it with CDATA sections and the text
+    /// after comments
+    text: String,
+    events: VecDeque<XmlEvent>,
+    started: bool,
+    has_root: bool,
+    ended: bool,
+}
+
+impl<R: Read> QuickXmlReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        let mut reader = Reader::from_reader(BufReader::new(reader));
+        reader.expand_empty_elements(true).check_end_names(true);
+        Self {
+            reader,
+            buffer: Vec::new(),
+            namespaces: NamespaceStack::default(),
+            elements: Vec::new(),
+            text: String::new(),
+            events: VecDeque::new(),
+            started: false,
+            has_root: false,
+            ended: false,
+        }
+    }
+
+    /// Returns the next event, [`XmlEvent::EndDocument`] again after the end of the document.
+    pub(crate) fn next(&mut self) -> Result<XmlEvent, Error> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Ok(event);
+            }
+            if self.ended {
+                return Ok(XmlEvent::EndDocument);
+            }
+            self.read_event()?;
+        }
+    }
+
+    fn read_event(&mut self) -> Result<(), Error> {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        let result = match self.reader.read_event_into(&mut buffer) {
+            Ok(event) => self.handle_event(event),
+            Err(error) => Err(self.error(error)),
+        };
+        self.buffer = buffer;
+        result
+    }
+
+    fn handle_event(&mut self, event: Event) -> Result<(), Error> {
+        if !self.started {
+            self.started = true;
+            if let Event::Decl(declaration) = &event {
+                let version = match declaration.version().map_err(|e| self.error(e))?.as_ref() {
+                    b"1.1" => XmlVersion::Version11,
+                    _ => XmlVersion::Version10,
+                };
+                let encoding = match declaration.encoding() {
+                    Some(encoding) => utf8(encoding.map_err(|e| self.error(e))?)?,
+                    None => "UTF-8".to_string(),
+                };
+                let standalone = match declaration.standalone() {
+                    Some(standalone) => {
+                        Some(standalone.map_err(|e| self.error(e))?.as_ref() == b"yes")
+                    }
+                    None => None,
+                };
+                self.events.push_back(XmlEvent::StartDocument {
+                    version,
+                    encoding,
+                    standalone,
+                });
+                return Ok(());
+            }
+            self.events.push_back(XmlEvent::StartDocument {
+                version: XmlVersion::Version10,
+                encoding: "UTF-8".to_string(),
+                standalone: None,
+            });
+        }
+
+        match event {
+            Event::Text(text) => {
+                let text = text.unescape().map_err(|e| self.error(e))?;
+                self.text.push_str(&text);
+            }
+            Event::CData(cdata) => self.text.push_str(&utf8(cdata.into_inner())?),
+            Event::Comment(_) | Event::DocType(_) => (),
+            Event::Decl(_) => {
+                return Err(self.error("Unexpected XML declaration after the start of the document"))
+            }
+            Event::PI(instruction) => {
+                self.flush_text()?;
+                let instruction = utf8(instruction.into_inner())?;
+                let (name, data) = match instruction.split_once(char::is_whitespace) {
+                    Some((name, data)) => (name.to_string(), Some(data.trim().to_string())),
+                    None => (instruction.clone(), None),
+                };
+                self.events
+                    .push_back(XmlEvent::ProcessingInstruction { name, data });
+            }
+            Event::Start(start) => {
+                self.flush_text()?;
+                self.start_element(&start)?;
+            }
+            Event::Empty(start) => {
+                self.flush_text()?;
+                self.start_element(&start)?;
+                self.end_element(start.name())?;
+            }
+            Event::End(end) => {
+                self.flush_text()?;
+                self.end_element(end.name())?;
+            }
+            Event::Eof => {
+                self.flush_text()?;
+                if !self.has_root {
+                    return Err(self.error("Unexpected end of stream: no root element found"));
+                }
+                if !self.elements.is_empty() {
+                    return Err(
+                        self.error("Unexpected end of stream: still inside the root element")
+                    );
+                }
+                self.events.push_back(XmlEvent::EndDocument);
+                self.ended = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn start_element(&mut self, start: &BytesStart) -> Result<(), Error> {
+        if self.elements.is_empty() {
+            if self.has_root {
+                return Err(self.error("Unexpected element outside of the root element"));
+            }
+            self.has_root = true;
+        }
+        self.namespaces.push_empty();
+
+        let mut attributes = Vec::new();
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| self.error(e))?;
+            let value = attribute
+                .unescape_value()
+                .map_err(|e| self.error(e))?
+                .into_owned();
+            match attribute.key.as_namespace_binding() {
+                Some(PrefixDeclaration::Default) => {
+                    self.namespaces.put(NS_NO_PREFIX, value);
+                }
+                Some(PrefixDeclaration::Named(prefix)) => {
+                    self.namespaces.put(utf8(prefix)?, value);
+                }
+                None => attributes.push((attribute.key, value)),
+            }
+        }
+
+        let attributes = attributes
+            .into_iter()
+            .map(|(key, value)| {
+                let mut name = self.owned_name(key)?;
+                if name.prefix.is_none() {
+                    name.namespace = None;
+                }
+                Ok(OwnedAttribute { name, value })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let name = self.owned_name(start.name())?;
+        self.elements.push(name.clone());
+        self.events.push_back(XmlEvent::StartElement {
+            name,
+            attributes,
+            namespace: self.namespaces.squash(),
+        });
+        Ok(())
+    }
+
+    fn end_element(&mut self, name: QName) -> Result<(), Error> {
+        let name = self.owned_name(name)?;
+        if self.elements.pop().as_ref() != Some(&name) {
+            return Err(self.error(format!("Unexpected closing tag: {name}")));
+        }
+        self.namespaces.pop();
+        self.events.push_back(XmlEvent::EndElement { name });
+        Ok(())
+    }
+
+    fn flush_text(&mut self) -> Result<(), Error> {
+        let text = self.text.trim();
+        if !text.is_empty() {
+            if self.elements.is_empty() {
+                return Err(self.error("Unexpected text outside of the root element"));
+            }
+            self.events
+                .push_back(XmlEvent::Characters(text.to_string()));
+        }
+        self.text.clear();
+        Ok(())
+    }
+
+    /// Resolves the namespace of a name as `xml-rs` does, with `None` for the empty namespace.
+    fn owned_name(&self, name: QName) -> Result<OwnedName, Error> {
+        let prefix = name
+            .prefix()
+            .map(|prefix| utf8(prefix.into_inner()))
+            .transpose()?;
+        let local_name = utf8(name.local_name().into_inner())?;
+        let namespace = match self
+            .namespaces
+            .get(prefix.as_deref().unwrap_or(NS_NO_PREFIX))
+        {
+            Some("") => None,
+            Some(namespace) => Some(namespace.to_string()),
+            None => {
+                return Err(self.error(format!(
+                    "Element {}:{local_name} prefix is unbound",
+                    prefix.unwrap_or_default()
+                )))
+            }
+        };
+        Ok(OwnedName {
+            local_name,
+            namespace,
+            prefix,
+        })
+    }
+
+    fn error(&self, error: impl ToString) -> Error {
+        let message = format!(
+            "{} at byte {}",
+            error.to_string(),
+            self.reader.buffer_position()
+        );
+        Error::from((&TextPosition::new(), message))
+    }
+}
+
+fn utf8<'a>(bytes: impl Into<Cow<'a, [u8]>>) -> Result<String, Error> {
+    match bytes.into() {
+        Cow::Borrowed(bytes) => std::str::from_utf8(bytes).map(str::to_string),
+        Cow::Owned(bytes) => String::from_utf8(bytes).map_err(|e| e.utf8_error()),
+    }
+    .map_err(|e| Error::from((&TextPosition::new(), e.to_string())))
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use xml::EventReader;
+
+    use super::*;
+    use crate::xml::parser_config;
+
+    fn xml_rs_events(input: &[u8]) -> Vec<XmlEvent> {
+        let mut reader = EventReader::new_with_config(input, parser_config());
+        let mut events = Vec::new();
+        loop {
+            let event = reader.next().expect("Failed to read with xml-rs");
+            let end = event == XmlEvent::EndDocument;
+            events.push(event);
+            if end {
+                return events;
+            }
+        }
+    }
+
+    fn quick_xml_events(input: &[u8]) -> Vec<XmlEvent> {
+        let mut reader = QuickXmlReader::new(input);
+        let mut events = Vec::new();
+        loop {
+            let event = reader.next().expect("Failed to read with quick-xml");
+            let end = event == XmlEvent::EndDocument;
+            events.push(event);
+            if end {
+                return events;
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_read_the_same_events_as_xml_rs() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+<?xml-stylesheet href="bom.xsl"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" xmlns:ext="https://example.com/ext" version="1">
+  <!-- a comment -->
+  <components>
+    <component type="library" ext:scope="build">
+      <name>serde</name>
+      <description>Escapes &lt;b&gt; &amp; <![CDATA[<script> & more]]> in<!-- a comment --> HTML</description>
+      <ext:note lang="en" />
+    </component>
+  </components>
+  <properties/>
+</bom>
+"#;
+        assert_eq!(
+            quick_xml_events(input.as_bytes()),
+            xml_rs_events(input.as_bytes())
+        );
+    }
+
+    #[test]
+    fn it_should_read_the_specification_examples_like_xml_rs() {
+        let examples = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/spec");
+        let mut count = 0;
+        for version in ["1.3", "1.4", "1.5", "1.6"] {
+            let entries = std::fs::read_dir(examples.join(version)).expect("Failed to list");
+            for entry in entries {
+                let path = entry.expect("Failed to read the directory").path();
+                if path
+                    .extension()
+                    .map_or(true, |extension| extension != "xml")
+                {
+                    continue;
+                }
+                let input = std::fs::read(&path).expect("Failed to read the example");
+                assert_eq!(
+                    quick_xml_events(&input),
+                    xml_rs_events(&input),
+                    "Different events for {path:?}"
+                );
+                count += 1;
+            }
+        }
+        assert!(count > 0, "No examples found");
+    }
+
+    #[test]
+    fn it_should_fail_on_malformed_documents() {
+        for input in ["<bom><components></bom>", "<bom>", "<ext:bom />", "not xml"] {
+            let mut reader = QuickXmlReader::new(input.as_bytes());
+            let result = std::iter::from_fn(|| match reader.next() {
+                Ok(XmlEvent::EndDocument) => None,
+                result => Some(result),
+            })
+            .collect::<Result<Vec<_>, _>>();
+            assert!(result.is_err(), "Should have failed to read {input}");
+        }
+    }
+}
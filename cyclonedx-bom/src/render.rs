@@ -0,0 +1,429 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Human-readable summaries of a BOM, e.g. for pull request comments and quick reviews.
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::render::{summary, RenderFormat};
+//!
+//! let markdown = summary(&Bom::default(), RenderFormat::Markdown);
+//! assert!(markdown.starts_with("# BOM\n"));
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::errors::UnknownValueError;
+use crate::models::{
+    bom::Bom,
+    component::Component,
+    license::{LicenseChoice, LicenseIdentifier},
+    vulnerability::Vulnerability,
+    vulnerability_rating::Severity,
+};
+
+/// The format of a summary, see [`summary`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum RenderFormat {
+    /// Markdown headings and tables
+    #[default]
+    Markdown,
+    /// Plain text with aligned columns
+    Text,
+}
+
+impl FromStr for RenderFormat {
+    type Err = UnknownValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" => Ok(Self::Markdown),
+            "text" => Ok(Self::Text),
+            unknown => Err(UnknownValueError {
+                kind: "render format",
+                value: unknown.to_string(),
+            }),
+        }
+    }
+}
+
+/// Renders a summary of the BOM with a table of its components, an inventory of their licenses
+/// and a list of the vulnerabilities, ordered by severity.
+pub fn summary(bom: &Bom, format: RenderFormat) -> String {
+    let mut output = String::new();
+    let root = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref());
+    let title = root.map_or_else(|| "BOM".to_string(), component_label);
+    heading(&mut output, format, 1, &title);
+
+    // The component the BOM describes is already named in the title
+    let components: Vec<_> = bom
+        .iter_components_recursive()
+        .filter(|component| !root.is_some_and(|root| std::ptr::eq(root, *component)))
+        .collect();
+    heading(
+        &mut output,
+        format,
+        2,
+        &format!("Components ({})", components.len()),
+    );
+    let mut table = Table::new(["Name", "Version", "Type", "Licenses", "Purl"]);
+    for component in &components {
+        table.push([
+            component_name(component),
+            optional(component.version.as_ref()),
+            component.component_type.to_string(),
+            licenses(component).unwrap_or_default(),
+            optional(component.purl.as_ref()),
+        ]);
+    }
+    table.write(&mut output, format);
+
+    let mut inventory = BTreeMap::<String, usize>::new();
+    for component in &components {
+        let license = licenses(component).unwrap_or_else(|| "(none)".to_string());
+        *inventory.entry(license).or_default() += 1;
+    }
+    let mut inventory: Vec<_> = inventory.into_iter().collect();
+    inventory.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+    heading(&mut output, format, 2, "Licenses");
+    let mut table = Table::new(["License", "Components"]);
+    for (license, count) in inventory {
+        table.push([license, count.to_string()]);
+    }
+    table.write(&mut output, format);
+
+    let mut vulnerabilities: Vec<_> = bom.vulnerabilities.iter().flat_map(|v| &v.0).collect();
+    vulnerabilities.sort_by_key(|vulnerability| {
+        (
+            severity(vulnerability)
+                .unwrap_or(&Severity::Unknown)
+                .clone(),
+            vulnerability.id.as_ref().map(ToString::to_string),
+        )
+    });
+    heading(
+        &mut output,
+        format,
+        2,
+        &format!("Vulnerabilities ({})", vulnerabilities.len()),
+    );
+    let mut table = Table::new(["Id", "Severity", "Affects", "State"]);
+    for vulnerability in vulnerabilities {
+        let affects = vulnerability
+            .vulnerability_targets
+            .iter()
+            .flat_map(|targets| &targets.0)
+            .map(|target| match bom.component_by_ref(&target.bom_ref) {
+                Some(component) => component_label(component),
+                None => target.bom_ref.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        table.push([
+            optional(vulnerability.id.as_ref()),
+            optional(severity(vulnerability)),
+            affects,
+            optional(
+                vulnerability
+                    .vulnerability_analysis
+                    .as_ref()
+                    .and_then(|analysis| analysis.state.as_ref()),
+            ),
+        ]);
+    }
+    table.write(&mut output, format);
+
+    output
+}
+
+/// The most severe of the ratings of the vulnerability.
+fn severity(vulnerability: &Vulnerability) -> Option<&Severity> {
+    vulnerability
+        .vulnerability_ratings
+        .iter()
+        .flat_map(|ratings| &ratings.0)
+        .filter_map(|rating| rating.severity.as_ref())
+        .filter(|severity| !matches!(severity, Severity::UndefinedSeverity(_)))
+        .min()
+}
+
+fn licenses(component: &Component) -> Option<String> {
+    let licenses: Vec<_> = component
+        .licenses
+        .iter()
+        .flat_map(|licenses| &licenses.0)
+        .map(|choice| match choice {
+            LicenseChoice::Expression(expression) => expression.to_string(),
+            LicenseChoice::License(license) => match &license.license_identifier {
+                LicenseIdentifier::SpdxId(id) => id.to_string(),
+                LicenseIdentifier::Name(name) => name.to_string(),
+            },
+        })
+        .collect();
+    (!licenses.is_empty()).then(|| licenses.join(", "))
+}
+
+fn component_name(component: &Component) -> String {
+    match &component.group {
+        Some(group) => format!("{group}/{}", component.name),
+        None => component.name.to_string(),
+    }
+}
+
+fn component_label(component: &Component) -> String {
+    match &component.version {
+        Some(version) => format!("{} {version}", component_name(component)),
+        None => component_name(component),
+    }
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn heading(output: &mut String, format: RenderFormat, level: usize, title: &str) {
+    if !output.is_empty() && !output.ends_with("\n\n") {
+        output.push('\n');
+    }
+    match format {
+        RenderFormat::Markdown => {
+            let _ = writeln!(output, "{} {title}", "#".repeat(level));
+        }
+        RenderFormat::Text => {
+            let underline = if level == 1 { "=" } else { "-" };
+            let _ = writeln!(
+                output,
+                "{title}\n{}",
+                underline.repeat(title.chars().count())
+            );
+        }
+    }
+    output.push('\n');
+}
+
+/// A table that is written as a Markdown table or with columns padded to the same width.
+struct Table<const N: usize> {
+    headers: [&'static str; N],
+    rows: Vec<[String; N]>,
+}
+
+impl<const N: usize> Table<N> {
+    fn new(headers: [&'static str; N]) -> Self {
+        Self {
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, row: [String; N]) {
+        self.rows
+            .push(row.map(|cell| cell.replace(['\r', '\n'], " ")));
+    }
+
+    fn write(&self, output: &mut String, format: RenderFormat) {
+        if self.rows.is_empty() {
+            output.push_str("None\n");
+            return;
+        }
+        match format {
+            RenderFormat::Markdown => {
+                let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+                output.push_str(&line(self.headers.map(String::from).to_vec()));
+                output.push_str(&line(vec!["---".to_string(); N]));
+                for row in &self.rows {
+                    output.push_str(&line(
+                        row.iter().map(|cell| cell.replace('|', "\\|")).collect(),
+                    ));
+                }
+            }
+            RenderFormat::Text => {
+                let mut widths = self.headers.map(|header| header.chars().count());
+                for row in &self.rows {
+                    for (width, cell) in widths.iter_mut().zip(row) {
+                        *width = (*width).max(cell.chars().count());
+                    }
+                }
+                let line = |cells: Vec<String>| {
+                    let padded: Vec<_> = cells
+                        .iter()
+                        .zip(widths)
+                        .map(|(cell, width)| format!("{cell:width$}"))
+                        .collect();
+                    format!("{}\n", padded.join("  ").trim_end())
+                };
+                output.push_str(&line(self.headers.map(String::from).to_vec()));
+                output.push_str(&line(widths.map(|width| "-".repeat(width)).to_vec()));
+                for row in &self.rows {
+                    output.push_str(&line(row.to_vec()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::external_models::{normalized_string::NormalizedString, spdx::SpdxExpression};
+    use crate::models::{
+        component::{Classification, Components},
+        license::Licenses,
+        metadata::Metadata,
+        vulnerability::Vulnerabilities,
+        vulnerability_rating::{VulnerabilityRating, VulnerabilityRatings},
+        vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets},
+    };
+
+    fn example_bom() -> Bom {
+        let component = |name: &str, license: Option<&str>| {
+            let mut component = Component::new(
+                Classification::Library,
+                name,
+                "1.0.0",
+                Some(format!("{name}@1.0.0")),
+            );
+            component.licenses = license.map(|license| {
+                Licenses(vec![LicenseChoice::Expression(SpdxExpression::new(
+                    license,
+                ))])
+            });
+            component
+        };
+        let vulnerability = |id: &str, severity: Severity, target: &str| {
+            let mut vulnerability = Vulnerability::new(None);
+            vulnerability.id = Some(NormalizedString::new(id));
+            vulnerability.vulnerability_ratings =
+                Some(VulnerabilityRatings(vec![VulnerabilityRating::new(
+                    None,
+                    Some(severity),
+                    None,
+                )]));
+            vulnerability.vulnerability_targets =
+                Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                    target.to_string(),
+                )]));
+            vulnerability
+        };
+
+        Bom {
+            metadata: Some(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "0.1.0",
+                    None,
+                )),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![
+                component("first", Some("MIT")),
+                component("second", Some("MIT OR Apache-2.0")),
+                component("third|piped", Some("MIT")),
+                component("fourth", None),
+            ])),
+            vulnerabilities: Some(Vulnerabilities(vec![
+                vulnerability("RUSTSEC-2024-0002", Severity::Medium, "second@1.0.0"),
+                vulnerability("RUSTSEC-2024-0001", Severity::Critical, "missing"),
+            ])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_render_a_markdown_summary() {
+        assert_eq!(
+            summary(&example_bom(), RenderFormat::Markdown),
+            r"# app 0.1.0
+
+## Components (4)
+
+| Name | Version | Type | Licenses | Purl |
+| --- | --- | --- | --- | --- |
+| first | 1.0.0 | library | MIT |  |
+| second | 1.0.0 | library | MIT OR Apache-2.0 |  |
+| third\|piped | 1.0.0 | library | MIT |  |
+| fourth | 1.0.0 | library |  |  |
+
+## Licenses
+
+| License | Components |
+| --- | --- |
+| MIT | 2 |
+| (none) | 1 |
+| MIT OR Apache-2.0 | 1 |
+
+## Vulnerabilities (2)
+
+| Id | Severity | Affects | State |
+| --- | --- | --- | --- |
+| RUSTSEC-2024-0001 | critical | missing |  |
+| RUSTSEC-2024-0002 | medium | second 1.0.0 |  |
+"
+        );
+    }
+
+    #[test]
+    fn it_should_render_a_text_summary() {
+        assert_eq!(
+            summary(&Bom::default(), RenderFormat::Text),
+            "BOM
+===
+
+Components (0)
+--------------
+
+None
+
+Licenses
+--------
+
+None
+
+Vulnerabilities (0)
+-------------------
+
+None
+"
+        );
+
+        let text = summary(&example_bom(), RenderFormat::Text);
+        assert!(text.contains(
+            "Name         Version  Type     Licenses           Purl
+-----------  -------  -------  -----------------  ----
+first        1.0.0    library  MIT
+"
+        ));
+    }
+
+    #[test]
+    fn it_should_parse_render_formats() {
+        assert_eq!(RenderFormat::from_str("text"), Ok(RenderFormat::Text));
+        assert_eq!(
+            RenderFormat::from_str(&RenderFormat::Markdown.to_string()),
+            Ok(RenderFormat::Markdown)
+        );
+        assert!(RenderFormat::from_str("html").is_err());
+    }
+}
@@ -17,9 +17,10 @@
  */
 
 use crate::{
+    errors::XmlReadError,
     errors::XmlWriteError,
     external_models::normalized_string::NormalizedString,
-    xml::{closing_tag_or_error, inner_text_or_error, to_xml_read_error, FromXml, ToInnerXml},
+    xml::{read_text_content, FromXml, ToInnerXml},
 };
 use crate::{models, xml::to_xml_write_error};
 use serde::{Deserialize, Serialize};
@@ -38,7 +39,7 @@ pub(crate) struct AttachedText {
 impl From<models::attached_text::AttachedText> for AttachedText {
     fn from(other: models::attached_text::AttachedText) -> Self {
         Self {
-            content_type: other.content_type.map(|n| n.0),
+            content_type: other.content_type.map(String::from),
             encoding: other.encoding.map(|e| e.to_string()),
             content: other.content,
         }
@@ -92,10 +93,10 @@ impl ToInnerXml for AttachedText {
 
 impl FromXml for AttachedText {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
-    ) -> Result<Self, crate::errors::XmlReadError>
+    ) -> Result<Self, XmlReadError>
     where
         Self: Sized,
     {
@@ -110,15 +111,8 @@ impl FromXml for AttachedText {
             }
         }
 
-        let content = event_reader
-            .next()
-            .map_err(to_xml_read_error(&element_name.local_name))
-            .and_then(inner_text_or_error(&element_name.local_name))?;
-
-        event_reader
-            .next()
-            .map_err(to_xml_read_error(&element_name.local_name))
-            .and_then(closing_tag_or_error(element_name))?;
+        let content = read_text_content(event_reader, element_name)?
+            .ok_or_else(|| XmlReadError::required_data_missing("content", element_name))?;
 
         Ok(Self {
             content_type,
@@ -193,4 +187,18 @@ pub(crate) mod test {
         };
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_read_xml_cdata() {
+        let input = r#"
+<text content-type="text/plain"><![CDATA[Permission is granted <free of charge>]]>
+<!-- the full text follows -->
+<![CDATA[to any person]]></text>
+"#;
+        let actual: AttachedText = read_element_from_string(input);
+        assert_eq!(
+            actual.content,
+            "Permission is granted <free of charge>\n\nto any person"
+        );
+    }
 }
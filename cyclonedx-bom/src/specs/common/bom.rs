@@ -17,7 +17,7 @@
  */
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.3", "1.4", "1.5")]
+#[versioned("1.3", "1.4", "1.5", "1.6")]
 pub(crate) mod base {
     #[versioned("1.3")]
     use crate::specs::v1_3::{
@@ -39,8 +39,8 @@ pub(crate) mod base {
         utilities::{convert_optional, try_convert_optional},
         xml::{
             expected_namespace_or_error, optional_attribute, read_lax_validation_tag,
-            to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml,
-            FromXmlDocument, FromXmlType,
+            skip_unexpected_element, to_xml_read_error, to_xml_write_error,
+            unexpected_element_error, FromXml, FromXmlDocument, FromXmlType,
         },
     };
     #[versioned("1.5")]
@@ -50,8 +50,23 @@ pub(crate) mod base {
             common::signature::Signature,
             v1_5::{
                 annotation::Annotations, component::Components, composition::Compositions,
-                external_reference::ExternalReferences, formulation::Formula, metadata::Metadata,
-                service::Services, vulnerability::Vulnerabilities,
+                external_reference::ExternalReferences, formulation::v1_5::Formula,
+                metadata::Metadata, service::Services, vulnerability::Vulnerabilities,
+            },
+        },
+        utilities::convert_optional_vec,
+        xml::write_list_tag,
+    };
+    #[versioned("1.6")]
+    use crate::{
+        specs::{
+            common::property::Properties,
+            common::signature::Signature,
+            v1_6::{
+                annotation::Annotations, component::Components, composition::Compositions,
+                declarations::Declarations, definitions::Definitions,
+                external_reference::ExternalReferences, formulation::v1_6::Formula,
+                metadata::Metadata, service::Services, vulnerability::Vulnerabilities,
             },
         },
         utilities::convert_optional_vec,
@@ -68,6 +83,8 @@ pub(crate) mod base {
     const SPEC_VERSION: SpecVersion = SpecVersion::V1_4;
     #[versioned("1.5")]
     const SPEC_VERSION: SpecVersion = SpecVersion::V1_5;
+    #[versioned("1.6")]
+    const SPEC_VERSION: SpecVersion = SpecVersion::V1_6;
 
     #[versioned("1.3")]
     const NS: &str = "http://cyclonedx.org/schema/bom/1.3";
@@ -75,6 +92,8 @@ pub(crate) mod base {
     const NS: &str = "http://cyclonedx.org/schema/bom/1.4";
     #[versioned("1.5")]
     const NS: &str = "http://cyclonedx.org/schema/bom/1.5";
+    #[versioned("1.6")]
+    const NS: &str = "http://cyclonedx.org/schema/bom/1.6";
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
     #[serde(rename_all = "camelCase")]
@@ -95,21 +114,27 @@ pub(crate) mod base {
         dependencies: Option<Dependencies>,
         #[serde(skip_serializing_if = "Option::is_none")]
         compositions: Option<Compositions>,
-        #[versioned("1.4", "1.5")]
+        #[versioned("1.4", "1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         vulnerabilities: Option<Vulnerabilities>,
-        #[versioned("1.4", "1.5")]
+        #[versioned("1.4", "1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         signature: Option<Signature>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         annotations: Option<Annotations>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         properties: Option<Properties>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         formulation: Option<Vec<Formula>>,
+        #[versioned("1.6")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        declarations: Option<Declarations>,
+        #[versioned("1.6")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        definitions: Option<Definitions>,
     }
 
     impl TryFrom<models::bom::Bom> for Bom {
@@ -127,15 +152,15 @@ pub(crate) mod base {
                 external_references: try_convert_optional(other.external_references)?,
                 dependencies: convert_optional(other.dependencies),
                 compositions: convert_optional(other.compositions),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 vulnerabilities: try_convert_optional(other.vulnerabilities)?,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: convert_optional(other.signature),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 annotations: try_convert_optional(other.annotations)?,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties: convert_optional(other.properties),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 formulation: other
                     .formulation
                     .map(|formulation| {
@@ -145,6 +170,10 @@ pub(crate) mod base {
                             .collect::<Result<Vec<_>, _>>()
                     })
                     .transpose()?,
+                #[versioned("1.6")]
+                declarations: try_convert_optional(other.declarations)?,
+                #[versioned("1.6")]
+                definitions: convert_optional(other.definitions),
             })
         }
     }
@@ -162,24 +191,32 @@ pub(crate) mod base {
                 compositions: convert_optional(other.compositions),
                 #[versioned("1.3")]
                 vulnerabilities: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 vulnerabilities: convert_optional(other.vulnerabilities),
                 #[versioned("1.3")]
                 signature: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: convert_optional(other.signature),
                 #[versioned("1.3", "1.4")]
                 annotations: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 annotations: convert_optional(other.annotations),
                 #[versioned("1.3", "1.4")]
                 properties: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties: convert_optional(other.properties),
                 #[versioned("1.3", "1.4")]
                 formulation: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 formulation: convert_optional_vec(other.formulation),
+                #[versioned("1.3", "1.4", "1.5")]
+                declarations: None,
+                #[versioned("1.6")]
+                declarations: convert_optional(other.declarations),
+                #[versioned("1.3", "1.4", "1.5")]
+                definitions: None,
+                #[versioned("1.6")]
+                definitions: convert_optional(other.definitions),
                 spec_version: other.spec_version,
             }
         }
@@ -231,21 +268,41 @@ pub(crate) mod base {
                 compositions.write_xml_element(writer)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             if let Some(properties) = &self.properties {
                 properties.write_xml_element(writer)?;
             }
 
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
             if let Some(vulnerabilities) = &self.vulnerabilities {
                 vulnerabilities.write_xml_element(writer)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
+            if let Some(annotations) = &self.annotations {
+                annotations.write_xml_element(writer)?;
+            }
+
+            #[versioned("1.5", "1.6")]
             if let Some(formulation) = &self.formulation {
                 write_list_tag(writer, FORMULATION_TAG, formulation)?;
             }
 
+            #[versioned("1.6")]
+            if let Some(declarations) = &self.declarations {
+                declarations.write_xml_element(writer)?;
+            }
+
+            #[versioned("1.6")]
+            if let Some(definitions) = &self.definitions {
+                definitions.write_xml_element(writer)?;
+            }
+
+            #[versioned("1.4", "1.5", "1.6")]
+            if let Some(signature) = &self.signature {
+                signature.write_xml_element(writer)?;
+            }
+
             writer
                 .write(XmlEvent::end_element())
                 .map_err(to_xml_write_error(BOM_TAG))?;
@@ -260,22 +317,26 @@ pub(crate) mod base {
     const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
     const DEPENDENCIES_TAG: &str = "dependencies";
     const COMPOSITIONS_TAG: &str = "compositions";
-    #[versioned("1.4", "1.5")]
+    #[versioned("1.4", "1.5", "1.6")]
     const VULNERABILITIES_TAG: &str = "vulnerabilities";
-    #[versioned("1.4", "1.5")]
+    #[versioned("1.4", "1.5", "1.6")]
     const SIGNATURE_TAG: &str = "signature";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const ANNOTATIONS_TAG: &str = "annotations";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const PROPERTIES_TAG: &str = "properties";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const FORMULATION_TAG: &str = "formulation";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const FORMULA_TAG: &str = "formula";
+    #[versioned("1.6")]
+    const DECLARATIONS_TAG: &str = "declarations";
+    #[versioned("1.6")]
+    const DEFINITIONS_TAG: &str = "definitions";
 
     impl FromXmlDocument for Bom {
         fn read_xml_document<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
         ) -> Result<Self, crate::errors::XmlReadError>
         where
             Self: Sized,
@@ -303,6 +364,8 @@ pub(crate) mod base {
                         expected_namespace_or_error("1.4", &namespace)?;
                         #[versioned("1.5")]
                         expected_namespace_or_error("1.5", &namespace)?;
+                        #[versioned("1.6")]
+                        expected_namespace_or_error("1.6", &namespace)?;
                         let version =
                             if let Some(version) = optional_attribute(&attributes, VERSION_ATTR) {
                                 u32::from_xml_value(VERSION_ATTR, version)?
@@ -322,16 +385,20 @@ pub(crate) mod base {
             let mut external_references: Option<ExternalReferences> = None;
             let mut dependencies: Option<Dependencies> = None;
             let mut compositions: Option<Compositions> = None;
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
             let mut vulnerabilities: Option<Vulnerabilities> = None;
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
             let mut signature: Option<Signature> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut annotations: Option<Annotations> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut properties: Option<Properties> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut formulation: Option<Vec<Formula>> = None;
+            #[versioned("1.6")]
+            let mut declarations: Option<Declarations> = None;
+            #[versioned("1.6")]
+            let mut definitions: Option<Definitions> = None;
 
             let mut got_end_tag = false;
             while !got_end_tag {
@@ -391,7 +458,7 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.4", "1.5")]
+                    #[versioned("1.4", "1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == VULNERABILITIES_TAG => {
@@ -401,7 +468,7 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.4", "1.5")]
+                    #[versioned("1.4", "1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == SIGNATURE_TAG => {
@@ -411,7 +478,7 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == ANNOTATIONS_TAG => {
@@ -421,7 +488,7 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == PROPERTIES_TAG => {
@@ -431,13 +498,33 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement { name, .. }
                         if name.local_name == FORMULATION_TAG =>
                     {
                         formulation =
                             Some(crate::xml::read_list_tag(event_reader, &name, FORMULA_TAG)?)
                     }
+                    #[versioned("1.6")]
+                    reader::XmlEvent::StartElement {
+                        name, attributes, ..
+                    } if name.local_name == DECLARATIONS_TAG => {
+                        declarations = Some(Declarations::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?)
+                    }
+                    #[versioned("1.6")]
+                    reader::XmlEvent::StartElement {
+                        name, attributes, ..
+                    } if name.local_name == DEFINITIONS_TAG => {
+                        definitions = Some(Definitions::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?)
+                    }
 
                     // lax validation of any elements from a different schema
                     reader::XmlEvent::StartElement { name, .. } => {
@@ -446,7 +533,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if name.local_name == BOM_TAG => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(BOM_TAG, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, BOM_TAG, unexpected)?,
                 }
             }
 
@@ -469,16 +556,20 @@ pub(crate) mod base {
                 external_references,
                 dependencies,
                 compositions,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 vulnerabilities,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 annotations,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 formulation,
+                #[versioned("1.6")]
+                declarations,
+                #[versioned("1.6")]
+                definitions,
             })
         }
     }
@@ -526,7 +617,26 @@ pub(crate) mod base {
                 external_reference::test::{
                     corresponding_external_references, example_external_references,
                 },
-                formulation::test::{corresponding_formula, example_formula},
+                formulation::v1_5::test::{corresponding_formula, example_formula},
+                metadata::test::{corresponding_metadata, example_metadata},
+                service::test::{corresponding_services, example_services},
+                vulnerability::test::{corresponding_vulnerabilities, example_vulnerabilities},
+            },
+        };
+        #[versioned("1.6")]
+        use crate::specs::{
+            common::property::test::{corresponding_properties, example_properties},
+            common::signature::test::{corresponding_signature, example_signature},
+            v1_6::{
+                annotation::test::{corresponding_annotations, example_annotations},
+                component::test::{corresponding_components, example_components},
+                composition::test::{corresponding_compositions, example_compositions},
+                declarations::test::{corresponding_declarations, example_declarations},
+                definitions::test::{corresponding_definitions, example_definitions},
+                external_reference::test::{
+                    corresponding_external_references, example_external_references,
+                },
+                formulation::v1_6::test::{corresponding_formula, example_formula},
                 metadata::test::{corresponding_metadata, example_metadata},
                 service::test::{corresponding_services, example_services},
                 vulnerability::test::{corresponding_vulnerabilities, example_vulnerabilities},
@@ -566,16 +676,20 @@ pub(crate) mod base {
                 external_references: None,
                 dependencies: None,
                 compositions: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 vulnerabilities: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 annotations: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 formulation: None,
+                #[versioned("1.6")]
+                declarations: None,
+                #[versioned("1.6")]
+                definitions: None,
             }
         }
 
@@ -591,16 +705,20 @@ pub(crate) mod base {
                 external_references: Some(example_external_references()),
                 dependencies: Some(example_dependencies()),
                 compositions: Some(example_compositions()),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 vulnerabilities: Some(example_vulnerabilities()),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: Some(example_signature()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 annotations: Some(example_annotations()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties: Some(example_properties()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 formulation: Some(vec![example_formula()]),
+                #[versioned("1.6")]
+                declarations: Some(example_declarations()),
+                #[versioned("1.6")]
+                definitions: Some(example_definitions()),
             }
         }
 
@@ -617,24 +735,32 @@ pub(crate) mod base {
                 compositions: Some(corresponding_compositions()),
                 #[versioned("1.3")]
                 vulnerabilities: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 vulnerabilities: Some(corresponding_vulnerabilities()),
                 #[versioned("1.3")]
                 signature: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: Some(corresponding_signature()),
                 #[versioned("1.3", "1.4")]
                 annotations: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 annotations: Some(corresponding_annotations()),
                 #[versioned("1.3", "1.4")]
                 properties: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties: Some(corresponding_properties()),
                 #[versioned("1.3", "1.4")]
                 formulation: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 formulation: Some(vec![corresponding_formula()]),
+                #[versioned("1.3", "1.4", "1.5")]
+                declarations: None,
+                #[versioned("1.6")]
+                declarations: Some(corresponding_declarations()),
+                #[versioned("1.3", "1.4", "1.5")]
+                definitions: None,
+                #[versioned("1.6")]
+                definitions: Some(corresponding_definitions()),
             }
         }
 
@@ -1140,6 +1266,43 @@ pub(crate) mod base {
           <text><![CDATA[copyright]]></text>
         </copyright>
       </evidence>
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
       <signature>
         <algorithm>HS512</algorithm>
         <value>1234567890</value>
@@ -1268,6 +1431,43 @@ pub(crate) mod base {
           <text><![CDATA[copyright]]></text>
         </copyright>
       </evidence>
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
       <signature>
         <algorithm>HS512</algorithm>
         <value>1234567890</value>
@@ -1313,6 +1513,43 @@ pub(crate) mod base {
         <property name="name">value</property>
       </properties>
       <services />
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
       <signature>
         <algorithm>HS512</algorithm>
         <value>1234567890</value>
@@ -1607,7 +1844,7 @@ pub(crate) mod base {
           </occurrence>
         </occurrences>
         <callstack>
-          <frame>
+          <frames>
             <frame>
               <package>package-1</package>
               <module>module-1</module>
@@ -1616,7 +1853,7 @@ pub(crate) mod base {
               <column>20</column>
               <fullFilename>full-filename</fullFilename>
             </frame>
-          </frame>
+          </frames>
         </callstack>
         <identity>
           <field>group</field>
@@ -1633,6 +1870,43 @@ pub(crate) mod base {
           </tools>
         </identity>
       </evidence>
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
       <signature>
         <algorithm>HS512</algorithm>
         <value>1234567890</value>
@@ -1839,7 +2113,7 @@ pub(crate) mod base {
           </occurrence>
         </occurrences>
         <callstack>
-          <frame>
+          <frames>
             <frame>
               <package>package-1</package>
               <module>module-1</module>
@@ -1848,7 +2122,7 @@ pub(crate) mod base {
               <column>20</column>
               <fullFilename>full-filename</fullFilename>
             </frame>
-          </frame>
+          </frames>
         </callstack>
         <identity>
           <field>group</field>
@@ -1865,6 +2139,43 @@ pub(crate) mod base {
           </tools>
         </identity>
       </evidence>
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
       <signature>
         <algorithm>HS512</algorithm>
         <value>1234567890</value>
@@ -1995,6 +2306,43 @@ pub(crate) mod base {
         <property name="name">value</property>
       </properties>
       <services />
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
       <signature>
         <algorithm>HS512</algorithm>
         <value>1234567890</value>
@@ -2203,6 +2551,1184 @@ pub(crate) mod base {
     </formula>
   </formulation>
 </bom>
+"#.trim_start();
+            #[versioned("1.6")]
+            let input = r#"
+<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.6" xmlns:example="https://example.com" serialNumber="fake-uuid" version="1">
+  <metadata>
+    <timestamp>timestamp</timestamp>
+    <tools>
+      <tool>
+        <vendor>vendor</vendor>
+        <name>name</name>
+        <version>version</version>
+        <hashes>
+          <hash alg="algorithm">hash value</hash>
+        </hashes>
+        <externalReferences>
+          <reference type="external reference type">
+            <url>url</url>
+            <comment>comment</comment>
+            <hashes>
+              <hash alg="algorithm">hash value</hash>
+            </hashes>
+          </reference>
+        </externalReferences>
+      </tool>
+    </tools>
+    <authors>
+      <author>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </author>
+    </authors>
+    <component type="component type" mime-type="mime type" bom-ref="bom ref">
+      <supplier>
+        <name>name</name>
+        <url>url</url>
+        <contact>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </contact>
+      </supplier>
+      <authors>
+        <author>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </author>
+      </authors>
+      <author>author</author>
+      <publisher>publisher</publisher>
+      <group>group</group>
+      <name>name</name>
+      <version>version</version>
+      <description>description</description>
+      <scope>scope</scope>
+      <hashes>
+        <hash alg="algorithm">hash value</hash>
+      </hashes>
+      <licenses>
+        <expression>expression</expression>
+      </licenses>
+      <copyright>copyright</copyright>
+      <cpe>cpe</cpe>
+      <purl>purl</purl>
+      <swid tagId="tag id" name="name" version="version" tagVersion="1" patch="true">
+        <text content-type="content type" encoding="encoding">content</text>
+        <url>url</url>
+      </swid>
+      <modified>true</modified>
+      <pedigree>
+        <ancestors />
+        <descendants />
+        <variants />
+        <commits>
+          <commit>
+            <uid>uid</uid>
+            <url>url</url>
+            <author>
+              <timestamp>timestamp</timestamp>
+              <name>name</name>
+              <email>email</email>
+            </author>
+            <committer>
+              <timestamp>timestamp</timestamp>
+              <name>name</name>
+              <email>email</email>
+            </committer>
+            <message>message</message>
+          </commit>
+        </commits>
+        <patches>
+          <patch type="patch type">
+            <diff>
+              <text content-type="content type" encoding="encoding">content</text>
+              <url>url</url>
+            </diff>
+            <resolves>
+              <issue type="issue type">
+                <id>id</id>
+                <name>name</name>
+                <description>description</description>
+                <source>
+                  <name>name</name>
+                  <url>url</url>
+                </source>
+                <references>
+                  <url>reference</url>
+                </references>
+              </issue>
+            </resolves>
+          </patch>
+        </patches>
+        <notes>notes</notes>
+      </pedigree>
+      <externalReferences>
+        <reference type="external reference type">
+          <url>url</url>
+          <comment>comment</comment>
+          <hashes>
+            <hash alg="algorithm">hash value</hash>
+          </hashes>
+        </reference>
+      </externalReferences>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+      <components />
+      <evidence>
+        <licenses>
+          <expression>expression</expression>
+        </licenses>
+        <copyright>
+          <text><![CDATA[copyright]]></text>
+        </copyright>
+        <occurrences>
+          <occurrence bom-ref="occurrence-1">
+            <location>location-1</location>
+          </occurrence>
+        </occurrences>
+        <callstack>
+          <frames>
+            <frame>
+              <package>package-1</package>
+              <module>module-1</module>
+              <function>function</function>
+              <line>10</line>
+              <column>20</column>
+              <fullFilename>full-filename</fullFilename>
+            </frame>
+          </frames>
+        </callstack>
+        <identity>
+          <field>group</field>
+          <confidence>0.5</confidence>
+          <methods>
+            <method>
+              <technique>technique-1</technique>
+              <confidence>0.8</confidence>
+              <value>identity-value</value>
+            </method>
+          </methods>
+          <tools>
+            <tool ref="tool-ref-1" />
+          </tools>
+        </identity>
+      </evidence>
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
+      <signature>
+        <algorithm>HS512</algorithm>
+        <value>1234567890</value>
+      </signature>
+      <modelCard bom-ref="modelcard-1">
+        <modelParameters>
+          <approach>
+            <type>supervised</type>
+          </approach>
+          <task>Task</task>
+          <architectureFamily>Architecture</architectureFamily>
+          <modelArchitecture>Model</modelArchitecture>
+          <datasets>
+            <dataset bom-ref="dataset-1">
+              <type>dataset</type>
+              <name>Training Data</name>
+              <contents>
+                <url>https://example.com/path/to/dataset</url>
+              </contents>
+              <classification>public</classification>
+              <governance>
+                <owners>
+                  <owner>
+                    <contact bom-ref="contact-1">
+                      <name>Contact</name>
+                      <email>contact@example.com</email>
+                    </contact>
+                  </owner>
+                </owners>
+              </governance>
+            </dataset>
+          </datasets>
+          <inputs>
+            <input>
+              <format>string</format>
+            </input>
+          </inputs>
+          <outputs>
+            <output>
+              <format>image</format>
+            </output>
+          </outputs>
+        </modelParameters>
+        <quantitativeAnalysis>
+          <performanceMetrics>
+            <performanceMetric>
+              <type>metric-1</type>
+              <value>metric value</value>
+              <confidenceInterval>
+                <lowerBound>low</lowerBound>
+                <upperBound>high</upperBound>
+              </confidenceInterval>
+            </performanceMetric>
+          </performanceMetrics>
+          <graphics>
+            <description>Graphic Desc</description>
+            <collection>
+              <graphic>
+                <name>Graphic A</name>
+                <image>1234</image>
+              </graphic>
+            </collection>
+          </graphics>
+        </quantitativeAnalysis>
+      </modelCard>
+      <data>
+        <type>configuration</type>
+        <name>config</name>
+        <contents>
+          <attachment>foo: bar</attachment>
+        </contents>
+      </data>
+      <cryptoProperties>
+        <assetType>algorithm</assetType>
+        <algorithmProperties>
+          <primitive>kem</primitive>
+          <parameterSetIdentifier>768</parameterSetIdentifier>
+          <curve>brainpoolP160r1</curve>
+          <executionEnvironment>software-plain-ram</executionEnvironment>
+          <implementationPlatform>x86_64</implementationPlatform>
+          <certificationLevel>none</certificationLevel>
+          <mode>cbc</mode>
+          <padding>pkcs7</padding>
+          <cryptoFunctions>
+            <cryptoFunction>keygen</cryptoFunction>
+            <cryptoFunction>encapsulate</cryptoFunction>
+          </cryptoFunctions>
+          <classicalSecurityLevel>128</classicalSecurityLevel>
+          <nistQuantumSecurityLevel>3</nistQuantumSecurityLevel>
+        </algorithmProperties>
+        <certificateProperties>
+          <subjectName>CN=example.com</subjectName>
+          <issuerName>CN=Example CA</issuerName>
+          <notValidBefore>2024-01-01T00:00:00Z</notValidBefore>
+          <notValidAfter>2025-01-01T00:00:00Z</notValidAfter>
+          <signatureAlgorithmRef>crypto/algorithm/sha-512-rsa</signatureAlgorithmRef>
+          <subjectPublicKeyRef>crypto/key/rsa-2048</subjectPublicKeyRef>
+          <certificateFormat>X.509</certificateFormat>
+          <certificateExtension>crt</certificateExtension>
+        </certificateProperties>
+        <relatedCryptoMaterialProperties>
+          <type>public-key</type>
+          <id>2e9ef09e-dfac-4526-96b4-d02f31af1b22</id>
+          <state>active</state>
+          <algorithmRef>crypto/algorithm/rsa-2048</algorithmRef>
+          <creationDate>2024-01-01T00:00:00Z</creationDate>
+          <activationDate>2024-01-02T00:00:00Z</activationDate>
+          <updateDate>2024-01-03T00:00:00Z</updateDate>
+          <expirationDate>2025-01-01T00:00:00Z</expirationDate>
+          <value>value</value>
+          <size>2048</size>
+          <format>PEM</format>
+          <securedBy>
+            <mechanism>Software</mechanism>
+            <algorithmRef>crypto/algorithm/aes-128-gcm</algorithmRef>
+          </securedBy>
+        </relatedCryptoMaterialProperties>
+        <protocolProperties>
+          <type>ike</type>
+          <version>2.0</version>
+          <cipherSuites>
+            <cipherSuite>
+              <name>TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256</name>
+              <algorithms>
+                <algorithm>crypto/algorithm/ecdh-curve25519</algorithm>
+              </algorithms>
+              <identifiers>
+                <identifier>0xC0</identifier>
+                <identifier>0x2F</identifier>
+              </identifiers>
+            </cipherSuite>
+          </cipherSuites>
+          <ikev2TransformTypes>
+            <encr>crypto/algorithm/aes-128-gcm</encr>
+            <prf>crypto/algorithm/sha-256</prf>
+            <integ>crypto/algorithm/hmac-sha-256</integ>
+            <ke>crypto/algorithm/ecdh-curve25519</ke>
+            <esn>true</esn>
+            <auth>crypto/algorithm/rsa-2048</auth>
+          </ikev2TransformTypes>
+          <cryptoRefArray>
+            <cryptoRef>crypto/certificate/example.com</cryptoRef>
+          </cryptoRefArray>
+        </protocolProperties>
+        <oid>2.16.840.1.101.3.4.4.2</oid>
+      </cryptoProperties>
+    </component>
+    <manufacture>
+      <name>name</name>
+      <url>url</url>
+      <contact>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </contact>
+    </manufacture>
+    <supplier>
+      <name>name</name>
+      <url>url</url>
+      <contact>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </contact>
+    </supplier>
+    <licenses>
+      <expression>expression</expression>
+    </licenses>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+    <lifecycles>
+      <lifecycle>
+        <phase>design</phase>
+      </lifecycle>
+    </lifecycles>
+  </metadata>
+  <components>
+    <component type="component type" mime-type="mime type" bom-ref="bom ref">
+      <supplier>
+        <name>name</name>
+        <url>url</url>
+        <contact>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </contact>
+      </supplier>
+      <authors>
+        <author>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </author>
+      </authors>
+      <author>author</author>
+      <publisher>publisher</publisher>
+      <group>group</group>
+      <name>name</name>
+      <version>version</version>
+      <description>description</description>
+      <scope>scope</scope>
+      <hashes>
+        <hash alg="algorithm">hash value</hash>
+      </hashes>
+      <licenses>
+        <expression>expression</expression>
+      </licenses>
+      <copyright>copyright</copyright>
+      <cpe>cpe</cpe>
+      <purl>purl</purl>
+      <swid tagId="tag id" name="name" version="version" tagVersion="1" patch="true">
+        <text content-type="content type" encoding="encoding">content</text>
+        <url>url</url>
+      </swid>
+      <modified>true</modified>
+      <pedigree>
+        <ancestors />
+        <descendants />
+        <variants />
+        <commits>
+          <commit>
+            <uid>uid</uid>
+            <url>url</url>
+            <author>
+              <timestamp>timestamp</timestamp>
+              <name>name</name>
+              <email>email</email>
+            </author>
+            <committer>
+              <timestamp>timestamp</timestamp>
+              <name>name</name>
+              <email>email</email>
+            </committer>
+            <message>message</message>
+          </commit>
+        </commits>
+        <patches>
+          <patch type="patch type">
+            <diff>
+              <text content-type="content type" encoding="encoding">content</text>
+              <url>url</url>
+            </diff>
+            <resolves>
+              <issue type="issue type">
+                <id>id</id>
+                <name>name</name>
+                <description>description</description>
+                <source>
+                  <name>name</name>
+                  <url>url</url>
+                </source>
+                <references>
+                  <url>reference</url>
+                </references>
+              </issue>
+            </resolves>
+          </patch>
+        </patches>
+        <notes>notes</notes>
+      </pedigree>
+      <externalReferences>
+        <reference type="external reference type">
+          <url>url</url>
+          <comment>comment</comment>
+          <hashes>
+            <hash alg="algorithm">hash value</hash>
+          </hashes>
+        </reference>
+      </externalReferences>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+      <components />
+      <evidence>
+        <licenses>
+          <expression>expression</expression>
+        </licenses>
+        <copyright>
+          <text><![CDATA[copyright]]></text>
+        </copyright>
+        <occurrences>
+          <occurrence bom-ref="occurrence-1">
+            <location>location-1</location>
+          </occurrence>
+        </occurrences>
+        <callstack>
+          <frames>
+            <frame>
+              <package>package-1</package>
+              <module>module-1</module>
+              <function>function</function>
+              <line>10</line>
+              <column>20</column>
+              <fullFilename>full-filename</fullFilename>
+            </frame>
+          </frames>
+        </callstack>
+        <identity>
+          <field>group</field>
+          <confidence>0.5</confidence>
+          <methods>
+            <method>
+              <technique>technique-1</technique>
+              <confidence>0.8</confidence>
+              <value>identity-value</value>
+            </method>
+          </methods>
+          <tools>
+            <tool ref="tool-ref-1" />
+          </tools>
+        </identity>
+      </evidence>
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
+      <signature>
+        <algorithm>HS512</algorithm>
+        <value>1234567890</value>
+      </signature>
+      <modelCard bom-ref="modelcard-1">
+        <modelParameters>
+          <approach>
+            <type>supervised</type>
+          </approach>
+          <task>Task</task>
+          <architectureFamily>Architecture</architectureFamily>
+          <modelArchitecture>Model</modelArchitecture>
+          <datasets>
+            <dataset bom-ref="dataset-1">
+              <type>dataset</type>
+              <name>Training Data</name>
+              <contents>
+                <url>https://example.com/path/to/dataset</url>
+              </contents>
+              <classification>public</classification>
+              <governance>
+                <owners>
+                  <owner>
+                    <contact bom-ref="contact-1">
+                      <name>Contact</name>
+                      <email>contact@example.com</email>
+                    </contact>
+                  </owner>
+                </owners>
+              </governance>
+            </dataset>
+          </datasets>
+          <inputs>
+            <input>
+              <format>string</format>
+            </input>
+          </inputs>
+          <outputs>
+            <output>
+              <format>image</format>
+            </output>
+          </outputs>
+        </modelParameters>
+        <quantitativeAnalysis>
+          <performanceMetrics>
+            <performanceMetric>
+              <type>metric-1</type>
+              <value>metric value</value>
+              <confidenceInterval>
+                <lowerBound>low</lowerBound>
+                <upperBound>high</upperBound>
+              </confidenceInterval>
+            </performanceMetric>
+          </performanceMetrics>
+          <graphics>
+            <description>Graphic Desc</description>
+            <collection>
+              <graphic>
+                <name>Graphic A</name>
+                <image>1234</image>
+              </graphic>
+            </collection>
+          </graphics>
+        </quantitativeAnalysis>
+      </modelCard>
+      <data>
+        <type>configuration</type>
+        <name>config</name>
+        <contents>
+          <attachment>foo: bar</attachment>
+        </contents>
+      </data>
+      <cryptoProperties>
+        <assetType>algorithm</assetType>
+        <algorithmProperties>
+          <primitive>kem</primitive>
+          <parameterSetIdentifier>768</parameterSetIdentifier>
+          <curve>brainpoolP160r1</curve>
+          <executionEnvironment>software-plain-ram</executionEnvironment>
+          <implementationPlatform>x86_64</implementationPlatform>
+          <certificationLevel>none</certificationLevel>
+          <mode>cbc</mode>
+          <padding>pkcs7</padding>
+          <cryptoFunctions>
+            <cryptoFunction>keygen</cryptoFunction>
+            <cryptoFunction>encapsulate</cryptoFunction>
+          </cryptoFunctions>
+          <classicalSecurityLevel>128</classicalSecurityLevel>
+          <nistQuantumSecurityLevel>3</nistQuantumSecurityLevel>
+        </algorithmProperties>
+        <certificateProperties>
+          <subjectName>CN=example.com</subjectName>
+          <issuerName>CN=Example CA</issuerName>
+          <notValidBefore>2024-01-01T00:00:00Z</notValidBefore>
+          <notValidAfter>2025-01-01T00:00:00Z</notValidAfter>
+          <signatureAlgorithmRef>crypto/algorithm/sha-512-rsa</signatureAlgorithmRef>
+          <subjectPublicKeyRef>crypto/key/rsa-2048</subjectPublicKeyRef>
+          <certificateFormat>X.509</certificateFormat>
+          <certificateExtension>crt</certificateExtension>
+        </certificateProperties>
+        <relatedCryptoMaterialProperties>
+          <type>public-key</type>
+          <id>2e9ef09e-dfac-4526-96b4-d02f31af1b22</id>
+          <state>active</state>
+          <algorithmRef>crypto/algorithm/rsa-2048</algorithmRef>
+          <creationDate>2024-01-01T00:00:00Z</creationDate>
+          <activationDate>2024-01-02T00:00:00Z</activationDate>
+          <updateDate>2024-01-03T00:00:00Z</updateDate>
+          <expirationDate>2025-01-01T00:00:00Z</expirationDate>
+          <value>value</value>
+          <size>2048</size>
+          <format>PEM</format>
+          <securedBy>
+            <mechanism>Software</mechanism>
+            <algorithmRef>crypto/algorithm/aes-128-gcm</algorithmRef>
+          </securedBy>
+        </relatedCryptoMaterialProperties>
+        <protocolProperties>
+          <type>ike</type>
+          <version>2.0</version>
+          <cipherSuites>
+            <cipherSuite>
+              <name>TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256</name>
+              <algorithms>
+                <algorithm>crypto/algorithm/ecdh-curve25519</algorithm>
+              </algorithms>
+              <identifiers>
+                <identifier>0xC0</identifier>
+                <identifier>0x2F</identifier>
+              </identifiers>
+            </cipherSuite>
+          </cipherSuites>
+          <ikev2TransformTypes>
+            <encr>crypto/algorithm/aes-128-gcm</encr>
+            <prf>crypto/algorithm/sha-256</prf>
+            <integ>crypto/algorithm/hmac-sha-256</integ>
+            <ke>crypto/algorithm/ecdh-curve25519</ke>
+            <esn>true</esn>
+            <auth>crypto/algorithm/rsa-2048</auth>
+          </ikev2TransformTypes>
+          <cryptoRefArray>
+            <cryptoRef>crypto/certificate/example.com</cryptoRef>
+          </cryptoRefArray>
+        </protocolProperties>
+        <oid>2.16.840.1.101.3.4.4.2</oid>
+      </cryptoProperties>
+    </component>
+  </components>
+  <services>
+    <service bom-ref="bom-ref">
+      <provider>
+        <name>name</name>
+        <url>url</url>
+        <contact>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </contact>
+      </provider>
+      <group>group</group>
+      <name>name</name>
+      <version>version</version>
+      <description>description</description>
+      <endpoints>
+        <endpoint>endpoint</endpoint>
+      </endpoints>
+      <authenticated>true</authenticated>
+      <x-trust-boundary>true</x-trust-boundary>
+      <data>
+        <dataflow name="Consumer to Stock Service" description="Traffic to/from consumer to service">
+          <classification flow="flow">classification</classification>
+          <governance>
+            <owners>
+              <owner>
+                <organization>
+                  <name>Organization 1</name>
+                </organization>
+              </owner>
+            </owners>
+          </governance>
+          <source>
+            <url>https://0.0.0.0</url>
+          </source>
+          <destination>
+            <url>https://0.0.0.0</url>
+          </destination>
+        </dataflow>
+      </data>
+      <licenses>
+        <expression>expression</expression>
+      </licenses>
+      <externalReferences>
+        <reference type="external reference type">
+          <url>url</url>
+          <comment>comment</comment>
+          <hashes>
+            <hash alg="algorithm">hash value</hash>
+          </hashes>
+        </reference>
+      </externalReferences>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+      <services />
+      <releaseNotes>
+        <type>type</type>
+        <title>title</title>
+        <featuredImage>featured image</featuredImage>
+        <socialImage>social image</socialImage>
+        <description>description</description>
+        <timestamp>timestamp</timestamp>
+        <aliases>
+          <alias>alias</alias>
+        </aliases>
+        <tags>
+          <tag>tag</tag>
+        </tags>
+        <resolves>
+          <issue type="issue type">
+            <id>id</id>
+            <name>name</name>
+            <description>description</description>
+            <source>
+              <name>name</name>
+              <url>url</url>
+            </source>
+            <references>
+              <url>reference</url>
+            </references>
+          </issue>
+        </resolves>
+        <notes>
+          <note>
+            <locale>locale</locale>
+            <text content-type="content type" encoding="encoding">content</text>
+          </note>
+        </notes>
+        <properties>
+          <property name="name">value</property>
+        </properties>
+      </releaseNotes>
+      <signature>
+        <algorithm>HS512</algorithm>
+        <value>1234567890</value>
+      </signature>
+      <trustZone>trust zone</trustZone>
+    </service>
+  </services>
+  <externalReferences>
+    <reference type="external reference type">
+      <url>url</url>
+      <comment>comment</comment>
+      <hashes>
+        <hash alg="algorithm">hash value</hash>
+      </hashes>
+    </reference>
+  </externalReferences>
+  <dependencies>
+    <dependency ref="ref">
+      <dependency ref="depends on" />
+    </dependency>
+  </dependencies>
+  <compositions>
+    <composition bom-ref="composition-ref">
+      <aggregate>aggregate</aggregate>
+      <assemblies>
+        <assembly ref="assembly-ref" />
+      </assemblies>
+      <dependencies>
+        <dependency ref="dependency-ref" />
+      </dependencies>
+      <vulnerabilities>
+        <vulnerability ref="vulnerability-ref" />
+      </vulnerabilities>
+      <signature>
+        <algorithm>HS512</algorithm>
+        <value>1234567890</value>
+      </signature>
+    </composition>
+  </compositions>
+  <properties>
+    <property name="name">value</property>
+  </properties>
+  <vulnerabilities>
+    <vulnerability bom-ref="bom-ref">
+      <id>id</id>
+      <source>
+        <name>name</name>
+        <url>url</url>
+      </source>
+      <references>
+        <reference>
+          <id>id</id>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+        </reference>
+      </references>
+      <ratings>
+        <rating>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <score>9.8</score>
+          <severity>info</severity>
+          <method>CVSSv3</method>
+          <vector>vector</vector>
+          <justification>justification</justification>
+        </rating>
+      </ratings>
+      <cwes>
+        <cwe>1</cwe>
+        <cwe>2</cwe>
+        <cwe>3</cwe>
+      </cwes>
+      <description>description</description>
+      <detail>detail</detail>
+      <recommendation>recommendation</recommendation>
+      <workaround>workaround</workaround>
+      <proofOfConcept>
+        <reproductionSteps>reproduction steps</reproductionSteps>
+        <environment>production</environment>
+        <supportingMaterial>
+          <attachment content-type="image/jpeg" encoding="base64">abcdefgh</attachment>
+        </supportingMaterial>
+      </proofOfConcept>
+      <advisories>
+        <advisory>
+          <title>title</title>
+          <url>url</url>
+        </advisory>
+      </advisories>
+      <created>created</created>
+      <published>published</published>
+      <updated>updated</updated>
+      <rejected>rejected</rejected>
+      <credits>
+        <organizations>
+          <organization>
+            <name>name</name>
+            <url>url</url>
+            <contact>
+              <name>name</name>
+              <email>email</email>
+              <phone>phone</phone>
+            </contact>
+          </organization>
+        </organizations>
+        <individuals>
+          <individual>
+            <name>name</name>
+            <email>email</email>
+            <phone>phone</phone>
+          </individual>
+        </individuals>
+      </credits>
+      <tools>
+        <tool>
+          <vendor>vendor</vendor>
+          <name>name</name>
+          <version>version</version>
+          <hashes>
+            <hash alg="algorithm">hash value</hash>
+          </hashes>
+          <externalReferences>
+            <reference type="external reference type">
+              <url>url</url>
+              <comment>comment</comment>
+              <hashes>
+                <hash alg="algorithm">hash value</hash>
+              </hashes>
+            </reference>
+          </externalReferences>
+        </tool>
+      </tools>
+      <analysis>
+        <state>not_affected</state>
+        <justification>code_not_reachable</justification>
+        <responses>
+          <response>update</response>
+        </responses>
+        <detail>detail</detail>
+        <firstIssued>2024-01-02</firstIssued>
+        <lastUpdated>2024-01-10</lastUpdated>
+      </analysis>
+      <affects>
+        <target>
+          <ref>ref</ref>
+          <versions>
+            <version>
+              <version>5.0.0</version>
+              <status>unaffected</status>
+            </version>
+            <version>
+              <range>vers:npm/1.2.3|>=2.0.0|&lt;5.0.0</range>
+              <status>affected</status>
+            </version>
+          </versions>
+        </target>
+      </affects>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </vulnerability>
+  </vulnerabilities>
+  <signature>
+    <algorithm>HS512</algorithm>
+    <value>1234567890</value>
+  </signature>
+  <annotations>
+    <annotation bom-ref="annotation-1">
+      <subjects>
+        <subject ref="subject1" />
+      </subjects>
+      <annotator>
+        <organization>
+          <name>name</name>
+          <url>url</url>
+          <contact>
+            <name>name</name>
+            <email>email</email>
+            <phone>phone</phone>
+          </contact>
+        </organization>
+      </annotator>
+      <timestamp>timestamp</timestamp>
+      <text>Annotation text</text>
+      <signature>
+        <algorithm>HS512</algorithm>
+        <value>1234567890</value>
+      </signature>
+    </annotation>
+  </annotations>
+  <example:laxValidation>
+    <example:innerElement id="test" />
+  </example:laxValidation>
+  <formulation>
+    <formula bom-ref="formula-1">
+      <components>
+        <component type="platform" bom-ref="component-1">
+          <name>Pipeline controller image</name>
+          <version>v0.47.0</version>
+        </component>
+      </components>
+    </formula>
+  </formulation>
+  <declarations>
+    <assessors>
+      <assessor bom-ref="assessor-1">
+        <thirdParty>true</thirdParty>
+        <organization>
+          <name>name</name>
+          <url>url</url>
+          <contact>
+            <name>name</name>
+            <email>email</email>
+            <phone>phone</phone>
+          </contact>
+        </organization>
+      </assessor>
+    </assessors>
+    <attestations>
+      <attestation>
+        <summary>Attestation summary</summary>
+        <assessor>assessor-1</assessor>
+        <map>
+          <requirement>requirement-1</requirement>
+          <claims>
+            <claim>claim-1</claim>
+          </claims>
+          <counterClaims>
+            <counterClaim>claim-2</counterClaim>
+          </counterClaims>
+          <conformance>
+            <score>0.8</score>
+            <rationale>Conformance rationale</rationale>
+            <mitigationStrategies>
+              <mitigationStrategy>mitigation-1</mitigationStrategy>
+            </mitigationStrategies>
+          </conformance>
+          <confidence>
+            <score>1</score>
+            <rationale>Confidence rationale</rationale>
+          </confidence>
+        </map>
+      </attestation>
+    </attestations>
+    <claims>
+      <claim bom-ref="claim-1">
+        <target>target-1</target>
+        <predicate>The predicate</predicate>
+        <mitigationStrategies>
+          <mitigationStrategy>mitigation-1</mitigationStrategy>
+        </mitigationStrategies>
+        <reasoning>The reasoning</reasoning>
+        <evidence>evidence-1</evidence>
+        <evidence>evidence-2</evidence>
+        <counterEvidence>evidence-3</counterEvidence>
+        <externalReferences>
+          <reference type="external reference type">
+            <url>url</url>
+            <comment>comment</comment>
+            <hashes>
+              <hash alg="algorithm">hash value</hash>
+            </hashes>
+          </reference>
+        </externalReferences>
+      </claim>
+    </claims>
+    <evidence>
+      <evidence bom-ref="evidence-1">
+        <propertyName>internal.com:property</propertyName>
+        <description>The description</description>
+        <data>
+          <name>Data name</name>
+          <contents>
+            <attachment encoding="base64">abcdefgh</attachment>
+            <url>https://example.com</url>
+          </contents>
+          <classification>PII</classification>
+          <sensitiveData>Name</sensitiveData>
+          <sensitiveData>Email</sensitiveData>
+        </data>
+        <created>2022-01-01T00:00:00Z</created>
+        <expires>2023-01-01T00:00:00Z</expires>
+        <author>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </author>
+      </evidence>
+    </evidence>
+    <targets>
+      <organizations>
+        <organization>
+          <name>name</name>
+          <url>url</url>
+          <contact>
+            <name>name</name>
+            <email>email</email>
+            <phone>phone</phone>
+          </contact>
+        </organization>
+      </organizations>
+    </targets>
+    <affirmation>
+      <statement>The statement</statement>
+      <signatories>
+        <signatory>
+          <name>Tom</name>
+          <role>CEO</role>
+          <signature>
+            <algorithm>HS512</algorithm>
+            <value>1234567890</value>
+          </signature>
+        </signatory>
+      </signatories>
+    </affirmation>
+  </declarations>
+  <definitions>
+    <standards>
+      <standard bom-ref="standard-1">
+        <name>Example Security Standard</name>
+        <version>1.0.0</version>
+        <description>The description of the standard</description>
+        <owner>Example Organization</owner>
+        <requirements>
+          <requirement bom-ref="requirement-1">
+            <identifier>V1</identifier>
+            <title>Architecture</title>
+            <text>The text of the requirement</text>
+            <descriptions>
+              <description>The description</description>
+            </descriptions>
+            <openCre>CRE:764-507</openCre>
+            <properties>
+              <property name="name">value</property>
+            </properties>
+            <externalReferences>
+              <reference type="external reference type">
+                <url>url</url>
+                <comment>comment</comment>
+                <hashes>
+                  <hash alg="algorithm">hash value</hash>
+                </hashes>
+              </reference>
+            </externalReferences>
+          </requirement>
+          <requirement bom-ref="requirement-2">
+            <identifier>V1.1</identifier>
+            <parent>requirement-1</parent>
+          </requirement>
+        </requirements>
+        <levels>
+          <level bom-ref="level-1">
+            <identifier>L1</identifier>
+            <title>Level 1</title>
+            <description>The description of the level</description>
+            <requirements>
+              <requirement>requirement-1</requirement>
+              <requirement>requirement-2</requirement>
+            </requirements>
+          </level>
+        </levels>
+        <externalReferences>
+          <reference type="external reference type">
+            <url>url</url>
+            <comment>comment</comment>
+            <hashes>
+              <hash alg="algorithm">hash value</hash>
+            </hashes>
+          </reference>
+        </externalReferences>
+        <signature>
+          <algorithm>HS512</algorithm>
+          <value>1234567890</value>
+        </signature>
+      </standard>
+    </standards>
+  </definitions>
+</bom>
 "#.trim_start();
             let actual: Bom = read_document_from_string(input);
             let expected = full_bom_example();
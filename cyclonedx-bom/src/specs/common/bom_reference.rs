@@ -79,7 +79,7 @@ impl ToInnerXml for BomReference {
 
 impl FromXml for BomReference {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
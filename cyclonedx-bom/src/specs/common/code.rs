@@ -24,7 +24,7 @@ use crate::{
     utilities::{convert_optional, convert_optional_vec, convert_vec},
     xml::{
         attribute_or_error, read_lax_validation_list_tag, read_lax_validation_tag, read_list_tag,
-        read_simple_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
+        read_simple_tag, skip_unexpected_element, to_xml_read_error, to_xml_write_error,
         write_simple_tag, FromXml, ToInnerXml, ToXml,
     },
 };
@@ -71,7 +71,7 @@ impl ToXml for Commits {
 
 impl FromXml for Commits {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -167,7 +167,7 @@ impl ToXml for Commit {
 
 impl FromXml for Commit {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -217,7 +217,7 @@ impl FromXml for Commit {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -298,7 +298,7 @@ impl ToInnerXml for IdentifiableAction {
 
 impl FromXml for IdentifiableAction {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -331,7 +331,7 @@ impl FromXml for IdentifiableAction {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -383,7 +383,7 @@ impl ToXml for Patches {
 
 impl FromXml for Patches {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -467,7 +467,7 @@ impl ToXml for Patch {
 
 impl FromXml for Patch {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -497,7 +497,7 @@ impl FromXml for Patch {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -570,7 +570,7 @@ impl ToXml for Diff {
 
 impl FromXml for Diff {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -603,7 +603,7 @@ impl FromXml for Diff {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -613,7 +613,7 @@ impl FromXml for Diff {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-struct Issue {
+pub(crate) struct Issue {
     #[serde(rename = "type")]
     issue_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -714,7 +714,7 @@ impl ToXml for Issue {
 
 impl FromXml for Issue {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -760,7 +760,7 @@ impl FromXml for Issue {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -835,7 +835,7 @@ impl ToXml for Source {
 
 impl FromXml for Source {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -858,7 +858,7 @@ impl FromXml for Source {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -962,7 +962,7 @@ pub(crate) mod test {
         }
     }
 
-    fn example_issue() -> Issue {
+    pub(crate) fn example_issue() -> Issue {
         Issue {
             issue_type: "issue type".to_string(),
             id: Some("id".to_string()),
@@ -973,7 +973,7 @@ pub(crate) mod test {
         }
     }
 
-    fn corresponding_issue() -> models::code::Issue {
+    pub(crate) fn corresponding_issue() -> models::code::Issue {
         models::code::Issue {
             issue_type: models::code::IssueClassification::UnknownIssueClassification(
                 "issue type".to_string(),
@@ -18,11 +18,15 @@
 
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.3", "1.4", "1.5")]
+#[versioned("1.3", "1.4", "1.5", "1.6")]
 pub(crate) mod base {
-    #[versioned("1.4", "1.5")]
-    use crate::specs::common::signature::Signature;
+    #[versioned("1.4", "1.5", "1.6")]
+    use crate::specs::common::{release_notes::ReleaseNotes, signature::Signature};
+    #[versioned("1.5", "1.6")]
+    use crate::utilities::convert_optional_vec;
 
+    #[versioned("1.6")]
+    use crate::specs::common::organization::OrganizationalContact;
     #[versioned("1.4")]
     use crate::specs::v1_4::{external_reference::ExternalReferences, license::Licenses};
     #[versioned("1.5")]
@@ -32,6 +36,13 @@ pub(crate) mod base {
         license::Licenses,
         modelcard::ModelCard,
     };
+    #[versioned("1.6")]
+    use crate::specs::v1_6::{
+        evidence::{Callstack, Identity, Occurrences},
+        external_reference::ExternalReferences,
+        license::Licenses,
+        modelcard::ModelCard,
+    };
     #[versioned("1.3")]
     use crate::{
         models::bom::SpecVersion,
@@ -55,8 +66,8 @@ pub(crate) mod base {
         utilities::{convert_optional, convert_vec, try_convert_optional, try_convert_vec},
         xml::{
             attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_list_tag,
-            read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-            to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
+            read_lax_validation_tag, read_list_tag, read_simple_tag, skip_unexpected_element,
+            to_xml_read_error, to_xml_write_error, write_close_tag, write_simple_tag,
             write_start_tag, FromXml, FromXmlType, ToInnerXml, ToXml,
         },
     };
@@ -112,7 +123,7 @@ pub(crate) mod base {
 
     impl FromXml for Components {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, crate::errors::XmlReadError>
@@ -134,6 +145,9 @@ pub(crate) mod base {
         pub(crate) bom_ref: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) supplier: Option<OrganizationalEntity>,
+        #[versioned("1.6")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(crate) authors: Option<Vec<OrganizationalContact>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) author: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -143,7 +157,7 @@ pub(crate) mod base {
         pub(crate) name: String,
         #[versioned("1.3")]
         version: String,
-        #[versioned("1.4", "1.5")]
+        #[versioned("1.4", "1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) version: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -174,15 +188,22 @@ pub(crate) mod base {
         pub(crate) components: Option<Components>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) evidence: Option<ComponentEvidence>,
-        #[versioned("1.4", "1.5")]
+        #[versioned("1.4", "1.5", "1.6")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(crate) release_notes: Option<ReleaseNotes>,
+        #[versioned("1.4", "1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) signature: Option<Signature>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) model_card: Option<ModelCard>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(crate) data: Option<Vec<crate::specs::v1_5::component_data::ComponentData>>,
+        #[versioned("1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub(crate) data: Option<crate::specs::v1_5::component_data::ComponentData>,
+        pub(crate) crypto_properties:
+            Option<crate::specs::v1_6::crypto_properties::CryptoProperties>,
     }
 
     impl TryFrom<models::component::Component> for Component {
@@ -193,13 +214,15 @@ pub(crate) mod base {
             let version = other.version.map(|v| v.to_string()).ok_or_else(|| {
                 BomError::BomSerializationError(SpecVersion::V1_3, "version missing".to_string())
             })?;
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
             let version = other.version.map(|v| v.to_string());
             Ok(Self {
                 component_type: other.component_type.to_string(),
                 mime_type: other.mime_type.map(|m| MimeType(m.0)),
                 bom_ref: other.bom_ref,
                 supplier: convert_optional(other.supplier),
+                #[versioned("1.6")]
+                authors: convert_optional_vec(other.authors),
                 author: other.author.map(|a| a.to_string()),
                 publisher: other.publisher.map(|p| p.to_string()),
                 group: other.group.map(|g| g.to_string()),
@@ -217,17 +240,21 @@ pub(crate) mod base {
                 pedigree: try_convert_optional(other.pedigree)?,
                 #[versioned("1.3", "1.4")]
                 external_references: try_convert_optional(other.external_references)?,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 external_references: convert_optional(other.external_references),
                 properties: convert_optional(other.properties),
                 components: try_convert_optional(other.components)?,
                 evidence: convert_optional(other.evidence),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: convert_optional(other.release_notes),
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: convert_optional(other.signature),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 model_card: convert_optional(other.model_card),
-                #[versioned("1.5")]
-                data: convert_optional(other.data),
+                #[versioned("1.5", "1.6")]
+                data: convert_optional_vec(other.data),
+                #[versioned("1.6")]
+                crypto_properties: convert_optional(other.crypto_properties),
             })
         }
     }
@@ -241,13 +268,17 @@ pub(crate) mod base {
                 mime_type: other.mime_type.map(|m| models::component::MimeType(m.0)),
                 bom_ref: other.bom_ref,
                 supplier: convert_optional(other.supplier),
+                #[versioned("1.3", "1.4", "1.5")]
+                authors: None,
+                #[versioned("1.6")]
+                authors: convert_optional_vec(other.authors),
                 author: other.author.map(NormalizedString::new_unchecked),
                 publisher: other.publisher.map(NormalizedString::new_unchecked),
                 group: other.group.map(NormalizedString::new_unchecked),
                 name: NormalizedString::new_unchecked(other.name),
                 #[versioned("1.3")]
                 version: Some(NormalizedString::new_unchecked(other.version)),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 version: other.version.map(NormalizedString::new_unchecked),
                 description: other.description.map(NormalizedString::new_unchecked),
                 scope: other.scope.map(models::component::Scope::new_unchecked),
@@ -264,17 +295,25 @@ pub(crate) mod base {
                 components: convert_optional(other.components),
                 evidence: convert_optional(other.evidence),
                 #[versioned("1.3")]
+                release_notes: None,
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: convert_optional(other.release_notes),
+                #[versioned("1.3")]
                 signature: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: convert_optional(other.signature),
                 #[versioned("1.3", "1.4")]
                 model_card: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 model_card: convert_optional(other.model_card),
                 #[versioned("1.3", "1.4")]
                 data: None,
-                #[versioned("1.5")]
-                data: convert_optional(other.data),
+                #[versioned("1.5", "1.6")]
+                data: convert_optional_vec(other.data),
+                #[versioned("1.3", "1.4", "1.5")]
+                crypto_properties: None,
+                #[versioned("1.6")]
+                crypto_properties: convert_optional(other.crypto_properties),
             }
         }
     }
@@ -284,6 +323,8 @@ pub(crate) mod base {
     const MIME_TYPE_ATTR: &str = "mime-type";
     const BOM_REF_ATTR: &str = "bom-ref";
     const SUPPLIER_TAG: &str = "supplier";
+    #[versioned("1.6")]
+    const AUTHORS_TAG: &str = "authors";
     const AUTHOR_TAG: &str = "author";
     const PUBLISHER_TAG: &str = "publisher";
     const GROUP_TAG: &str = "group";
@@ -294,9 +335,9 @@ pub(crate) mod base {
     const COPYRIGHT_TAG: &str = "copyright";
     const PURL_TAG: &str = "purl";
     const MODIFIED_TAG: &str = "modified";
-    #[versioned("1.4", "1.5")]
+    #[versioned("1.4", "1.5", "1.6")]
     const SIGNATURE_TAG: &str = "signature";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const COMPONENT_DATA_TAG: &str = "data";
 
     impl ToXml for Component {
@@ -325,6 +366,19 @@ pub(crate) mod base {
                 }
             }
 
+            #[versioned("1.6")]
+            if let Some(authors) = &self.authors {
+                write_start_tag(writer, AUTHORS_TAG)?;
+
+                for author in authors {
+                    if author.will_write() {
+                        author.write_xml_named_element(writer, AUTHOR_TAG)?;
+                    }
+                }
+
+                write_close_tag(writer, AUTHORS_TAG)?;
+            }
+
             if let Some(author) = &self.author {
                 write_simple_tag(writer, AUTHOR_TAG, author)?;
             }
@@ -341,7 +395,7 @@ pub(crate) mod base {
 
             #[versioned("1.3")]
             write_simple_tag(writer, VERSION_TAG, &self.version)?;
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
             if let Some(version) = &self.version {
                 write_simple_tag(writer, VERSION_TAG, version)?;
             }
@@ -404,21 +458,31 @@ pub(crate) mod base {
                 }
             }
 
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
+            if let Some(release_notes) = &self.release_notes {
+                release_notes.write_xml_element(writer)?;
+            }
+
+            #[versioned("1.4", "1.5", "1.6")]
             if let Some(signature) = &self.signature {
                 signature.write_xml_element(writer)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             if let Some(model_card) = &self.model_card {
                 model_card.write_xml_element(writer)?;
             }
 
-            #[versioned("1.5")]
-            if let Some(data) = &self.data {
+            #[versioned("1.5", "1.6")]
+            for data in self.data.iter().flatten() {
                 data.write_xml_named_element(writer, COMPONENT_DATA_TAG)?;
             }
 
+            #[versioned("1.6")]
+            if let Some(crypto_properties) = &self.crypto_properties {
+                crypto_properties.write_xml_element(writer)?;
+            }
+
             writer
                 .write(XmlEvent::end_element())
                 .map_err(to_xml_write_error(COMPONENT_TAG))?;
@@ -431,12 +495,16 @@ pub(crate) mod base {
     const LICENSES_TAG: &str = "licenses";
     const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
     const PROPERTIES_TAG: &str = "properties";
-    #[versioned("1.5")]
+    #[versioned("1.4", "1.5", "1.6")]
+    const RELEASE_NOTES_TAG: &str = "releaseNotes";
+    #[versioned("1.5", "1.6")]
     const MODEL_CARD_TAG: &str = "modelCard";
+    #[versioned("1.6")]
+    const CRYPTO_PROPERTIES_TAG: &str = "cryptoProperties";
 
     impl FromXml for Component {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, crate::errors::XmlReadError>
@@ -448,6 +516,8 @@ pub(crate) mod base {
             let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
 
             let mut supplier: Option<OrganizationalEntity> = None;
+            #[versioned("1.6")]
+            let mut authors: Option<Vec<OrganizationalContact>> = None;
             let mut author: Option<String> = None;
             let mut publisher: Option<String> = None;
             let mut group: Option<String> = None;
@@ -467,12 +537,20 @@ pub(crate) mod base {
             let mut properties: Option<Properties> = None;
             let mut components: Option<Components> = None;
             let mut evidence: Option<ComponentEvidence> = None;
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
+            let mut release_notes: Option<ReleaseNotes> = None;
+            #[versioned("1.4", "1.5", "1.6")]
             let mut signature: Option<Signature> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut model_card: Option<ModelCard> = None;
-            #[versioned("1.5")]
-            let mut data: Option<crate::specs::v1_5::component_data::ComponentData> = None;
+            #[versioned("1.5", "1.6")]
+            let mut data: Option<
+                Vec<crate::specs::v1_5::component_data::ComponentData>,
+            > = None;
+            #[versioned("1.6")]
+            let mut crypto_properties: Option<
+                crate::specs::v1_6::crypto_properties::CryptoProperties,
+            > = None;
 
             let mut got_end_tag = false;
             while !got_end_tag {
@@ -489,6 +567,12 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
+                    #[versioned("1.6")]
+                    reader::XmlEvent::StartElement { name, .. }
+                        if name.local_name == AUTHORS_TAG =>
+                    {
+                        authors = Some(read_list_tag(event_reader, &name, AUTHOR_TAG)?)
+                    }
                     reader::XmlEvent::StartElement { name, .. }
                         if name.local_name == AUTHOR_TAG =>
                     {
@@ -600,7 +684,17 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.4", "1.5")]
+                    #[versioned("1.4", "1.5", "1.6")]
+                    reader::XmlEvent::StartElement {
+                        name, attributes, ..
+                    } if name.local_name == RELEASE_NOTES_TAG => {
+                        release_notes = Some(ReleaseNotes::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?)
+                    }
+                    #[versioned("1.4", "1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == SIGNATURE_TAG => {
@@ -610,7 +704,7 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == MODEL_CARD_TAG => {
@@ -621,11 +715,11 @@ pub(crate) mod base {
                         )?)
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == COMPONENT_DATA_TAG => {
-                        data = Some(
+                        data.get_or_insert_with(Vec::new).push(
                             crate::specs::v1_5::component_data::ComponentData::read_xml_element(
                                 event_reader,
                                 &name,
@@ -634,6 +728,17 @@ pub(crate) mod base {
                         )
                     }
 
+                    #[versioned("1.6")]
+                    reader::XmlEvent::StartElement {
+                        name, attributes, ..
+                    } if name.local_name == CRYPTO_PROPERTIES_TAG => crypto_properties = Some(
+                        crate::specs::v1_6::crypto_properties::CryptoProperties::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?,
+                    ),
+
                     // lax validation of any elements from a different schema
                     reader::XmlEvent::StartElement { name, .. } => {
                         read_lax_validation_tag(event_reader, &name)?
@@ -641,7 +746,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
@@ -662,6 +767,8 @@ pub(crate) mod base {
                 mime_type,
                 bom_ref,
                 supplier,
+                #[versioned("1.6")]
+                authors,
                 author,
                 publisher,
                 group,
@@ -681,12 +788,16 @@ pub(crate) mod base {
                 properties,
                 components,
                 evidence,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes,
+                #[versioned("1.4", "1.5", "1.6")]
                 signature,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 model_card,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 data,
+                #[versioned("1.6")]
+                crypto_properties,
             })
         }
     }
@@ -791,7 +902,7 @@ pub(crate) mod base {
 
     impl FromXml for Swid {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -840,7 +951,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
@@ -884,7 +995,7 @@ pub(crate) mod base {
 
     impl FromXml for Cpe {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -902,13 +1013,13 @@ pub(crate) mod base {
         licenses: Option<Licenses>,
         #[serde(skip_serializing_if = "Option::is_none")]
         copyright: Option<CopyrightTexts>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         occurrences: Option<Occurrences>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         callstack: Option<Callstack>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         identity: Option<Identity>,
     }
@@ -918,11 +1029,11 @@ pub(crate) mod base {
             Self {
                 licenses: convert_optional(other.licenses),
                 copyright: convert_optional(other.copyright),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 occurrences: convert_optional(other.occurrences),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 callstack: convert_optional(other.callstack),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 identity: convert_optional(other.identity),
             }
         }
@@ -935,15 +1046,15 @@ pub(crate) mod base {
                 copyright: convert_optional(other.copyright),
                 #[versioned("1.3", "1.4")]
                 occurrences: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 occurrences: convert_optional(other.occurrences),
                 #[versioned("1.3", "1.4")]
                 callstack: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 callstack: convert_optional(other.callstack),
                 #[versioned("1.3", "1.4")]
                 identity: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 identity: convert_optional(other.identity),
             }
         }
@@ -958,6 +1069,21 @@ pub(crate) mod base {
         ) -> Result<(), crate::errors::XmlWriteError> {
             write_start_tag(writer, EVIDENCE_TAG)?;
 
+            #[versioned("1.5", "1.6")]
+            if let Some(identity) = &self.identity {
+                identity.write_xml_element(writer)?;
+            }
+
+            #[versioned("1.5", "1.6")]
+            if let Some(occurrences) = &self.occurrences {
+                occurrences.write_xml_element(writer)?;
+            }
+
+            #[versioned("1.5", "1.6")]
+            if let Some(callstack) = &self.callstack {
+                callstack.write_xml_element(writer)?;
+            }
+
             if let Some(licenses) = &self.licenses {
                 licenses.write_xml_element(writer)?;
             }
@@ -972,20 +1098,25 @@ pub(crate) mod base {
         }
 
         fn will_write(&self) -> bool {
+            #[versioned("1.5", "1.6")]
+            if self.identity.is_some() || self.occurrences.is_some() || self.callstack.is_some() {
+                return true;
+            }
+
             self.licenses.is_some() || self.copyright.is_some()
         }
     }
 
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const OCCURRENCES_TAG: &str = "occurrences";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const CALLSTACK_TAG: &str = "callstack";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const IDENTITY_TAG: &str = "identity";
 
     impl FromXml for ComponentEvidence {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -994,11 +1125,11 @@ pub(crate) mod base {
         {
             let mut licenses: Option<Licenses> = None;
             let mut copyright: Option<CopyrightTexts> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut occurrences: Option<Occurrences> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut callstack: Option<Callstack> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut identity: Option<Identity> = None;
 
             let mut got_end_tag = false;
@@ -1025,7 +1156,7 @@ pub(crate) mod base {
                             &attributes,
                         )?);
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == OCCURRENCES_TAG => {
@@ -1036,7 +1167,7 @@ pub(crate) mod base {
                         )?);
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == CALLSTACK_TAG => {
@@ -1047,7 +1178,7 @@ pub(crate) mod base {
                         )?);
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == IDENTITY_TAG => {
@@ -1061,7 +1192,9 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    // unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    reader::XmlEvent::StartElement { name, .. } => {
+                        read_lax_validation_tag(event_reader, &name)?
+                    }
                     _ => (),
                 }
             }
@@ -1069,11 +1202,11 @@ pub(crate) mod base {
             Ok(Self {
                 licenses,
                 copyright,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 occurrences,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 callstack,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 identity,
             })
         }
@@ -1181,7 +1314,7 @@ pub(crate) mod base {
 
     impl FromXml for Pedigree {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -1248,7 +1381,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
@@ -1299,7 +1432,7 @@ pub(crate) mod base {
 
     impl FromXml for Copyright {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -1345,7 +1478,7 @@ pub(crate) mod base {
 
     impl FromXml for CopyrightTexts {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -1373,8 +1506,11 @@ pub(crate) mod base {
 
     #[cfg(test)]
     pub(crate) mod test {
-        #[versioned("1.4", "1.5")]
-        use crate::specs::common::signature::test::{corresponding_signature, example_signature};
+        #[versioned("1.4", "1.5", "1.6")]
+        use crate::specs::common::{
+            release_notes::test::{corresponding_release_notes, example_release_notes},
+            signature::test::{corresponding_signature, example_signature},
+        };
 
         #[versioned("1.4")]
         use crate::specs::v1_4::{
@@ -1384,6 +1520,12 @@ pub(crate) mod base {
             license::test::{corresponding_licenses, example_licenses},
         };
 
+        #[versioned("1.6")]
+        use crate::specs::common::organization::test::{corresponding_contact, example_contact};
+        #[versioned("1.6")]
+        use crate::specs::v1_5::component_data::tests::{
+            corresponding_component_data, example_component_data,
+        };
         #[versioned("1.5")]
         use crate::specs::v1_5::{
             component_data::tests::{corresponding_component_data, example_component_data},
@@ -1397,6 +1539,22 @@ pub(crate) mod base {
             license::test::{corresponding_licenses, example_licenses},
             modelcard::test::{corresponding_modelcard, example_modelcard},
         };
+        #[versioned("1.6")]
+        use crate::specs::v1_6::crypto_properties::test::{
+            corresponding_crypto_properties, example_crypto_properties,
+        };
+        #[versioned("1.6")]
+        use crate::specs::v1_6::{
+            evidence::test::{
+                corresponding_callstack, corresponding_identity, corresponding_occurrences,
+                example_callstack, example_identity, example_occurrences,
+            },
+            external_reference::test::{
+                corresponding_external_references, example_external_references,
+            },
+            license::test::{corresponding_licenses, example_licenses},
+            modelcard::test::{corresponding_modelcard, example_modelcard},
+        };
 
         #[versioned("1.3")]
         use crate::{
@@ -1438,13 +1596,15 @@ pub(crate) mod base {
                 mime_type: Some(MimeType("mime type".to_string())),
                 bom_ref: Some("bom ref".to_string()),
                 supplier: Some(example_entity()),
+                #[versioned("1.6")]
+                authors: Some(vec![example_contact()]),
                 author: Some("author".to_string()),
                 publisher: Some("publisher".to_string()),
                 group: Some("group".to_string()),
                 name: "name".to_string(),
                 #[versioned("1.3")]
                 version: "version".to_string(),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 version: Some("version".to_string()),
                 description: Some("description".to_string()),
                 scope: Some("scope".to_string()),
@@ -1460,12 +1620,16 @@ pub(crate) mod base {
                 properties: Some(example_properties()),
                 components: Some(example_empty_components()),
                 evidence: Some(example_evidence()),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: Some(example_release_notes()),
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: Some(example_signature()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 model_card: Some(example_modelcard()),
-                #[versioned("1.5")]
-                data: Some(example_component_data()),
+                #[versioned("1.5", "1.6")]
+                data: Some(vec![example_component_data()]),
+                #[versioned("1.6")]
+                crypto_properties: Some(example_crypto_properties()),
             }
         }
 
@@ -1477,6 +1641,10 @@ pub(crate) mod base {
                 mime_type: Some(models::component::MimeType("mime type".to_string())),
                 bom_ref: Some("bom ref".to_string()),
                 supplier: Some(corresponding_entity()),
+                #[versioned("1.3", "1.4", "1.5")]
+                authors: None,
+                #[versioned("1.6")]
+                authors: Some(vec![corresponding_contact()]),
                 author: Some(NormalizedString::new_unchecked("author".to_string())),
                 publisher: Some(NormalizedString::new_unchecked("publisher".to_string())),
                 group: Some(NormalizedString::new_unchecked("group".to_string())),
@@ -1497,17 +1665,25 @@ pub(crate) mod base {
                 components: Some(corresponding_empty_components()),
                 evidence: Some(corresponding_evidence()),
                 #[versioned("1.3")]
+                release_notes: None,
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: Some(corresponding_release_notes()),
+                #[versioned("1.3")]
                 signature: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: Some(corresponding_signature()),
                 #[versioned("1.3", "1.4")]
                 model_card: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 model_card: Some(corresponding_modelcard()),
                 #[versioned("1.3", "1.4")]
                 data: None,
-                #[versioned("1.5")]
-                data: Some(corresponding_component_data()),
+                #[versioned("1.5", "1.6")]
+                data: Some(vec![corresponding_component_data()]),
+                #[versioned("1.3", "1.4", "1.5")]
+                crypto_properties: None,
+                #[versioned("1.6")]
+                crypto_properties: Some(corresponding_crypto_properties()),
             }
         }
 
@@ -1581,7 +1757,7 @@ pub(crate) mod base {
             }
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         fn example_evidence() -> ComponentEvidence {
             ComponentEvidence {
                 licenses: Some(example_licenses()),
@@ -1603,7 +1779,7 @@ pub(crate) mod base {
             }
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         fn corresponding_evidence() -> models::component::ComponentEvidence {
             models::component::ComponentEvidence {
                 licenses: Some(corresponding_licenses()),
@@ -1843,6 +2019,43 @@ pub(crate) mod base {
         <text><![CDATA[copyright]]></text>
       </copyright>
     </evidence>
+    <releaseNotes>
+      <type>type</type>
+      <title>title</title>
+      <featuredImage>featured image</featuredImage>
+      <socialImage>social image</socialImage>
+      <description>description</description>
+      <timestamp>timestamp</timestamp>
+      <aliases>
+        <alias>alias</alias>
+      </aliases>
+      <tags>
+        <tag>tag</tag>
+      </tags>
+      <resolves>
+        <issue type="issue type">
+          <id>id</id>
+          <name>name</name>
+          <description>description</description>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <references>
+            <url>reference</url>
+          </references>
+        </issue>
+      </resolves>
+      <notes>
+        <note>
+          <locale>locale</locale>
+          <text content-type="content type" encoding="encoding">content</text>
+        </note>
+      </notes>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </releaseNotes>
     <signature>
       <algorithm>HS512</algorithm>
       <value>1234567890</value>
@@ -1955,7 +2168,7 @@ pub(crate) mod base {
         </occurrence>
       </occurrences>
       <callstack>
-        <frame>
+        <frames>
           <frame>
             <package>package-1</package>
             <module>module-1</module>
@@ -1964,7 +2177,7 @@ pub(crate) mod base {
             <column>20</column>
             <fullFilename>full-filename</fullFilename>
           </frame>
-        </frame>
+        </frames>
       </callstack>
       <identity>
         <field>group</field>
@@ -1981,6 +2194,367 @@ pub(crate) mod base {
         </tools>
       </identity>
     </evidence>
+    <releaseNotes>
+      <type>type</type>
+      <title>title</title>
+      <featuredImage>featured image</featuredImage>
+      <socialImage>social image</socialImage>
+      <description>description</description>
+      <timestamp>timestamp</timestamp>
+      <aliases>
+        <alias>alias</alias>
+      </aliases>
+      <tags>
+        <tag>tag</tag>
+      </tags>
+      <resolves>
+        <issue type="issue type">
+          <id>id</id>
+          <name>name</name>
+          <description>description</description>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <references>
+            <url>reference</url>
+          </references>
+        </issue>
+      </resolves>
+      <notes>
+        <note>
+          <locale>locale</locale>
+          <text content-type="content type" encoding="encoding">content</text>
+        </note>
+      </notes>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </releaseNotes>
+    <signature>
+      <algorithm>HS512</algorithm>
+      <value>1234567890</value>
+    </signature>
+    <modelCard bom-ref="modelcard-1">
+      <modelParameters>
+        <approach>
+          <type>supervised</type>
+        </approach>
+        <task>Task</task>
+        <architectureFamily>Architecture</architectureFamily>
+        <modelArchitecture>Model</modelArchitecture>
+        <datasets>
+          <dataset bom-ref="dataset-1">
+            <type>dataset</type>
+            <name>Training Data</name>
+            <contents>
+              <url>https://example.com/path/to/dataset</url>
+            </contents>
+            <classification>public</classification>
+            <governance>
+              <owners>
+                <owner>
+                  <contact bom-ref="contact-1">
+                    <name>Contact</name>
+                    <email>contact@example.com</email>
+                  </contact>
+                </owner>
+              </owners>
+            </governance>
+          </dataset>
+        </datasets>
+        <inputs>
+          <input>
+            <format>string</format>
+          </input>
+        </inputs>
+        <outputs>
+          <output>
+            <format>image</format>
+          </output>
+        </outputs>
+      </modelParameters>
+      <quantitativeAnalysis>
+        <performanceMetrics>
+          <performanceMetric>
+            <type>metric-1</type>
+            <value>metric value</value>
+            <confidenceInterval>
+              <lowerBound>low</lowerBound>
+              <upperBound>high</upperBound>
+            </confidenceInterval>
+          </performanceMetric>
+        </performanceMetrics>
+        <graphics>
+          <description>Graphic Desc</description>
+          <collection>
+            <graphic>
+              <name>Graphic A</name>
+              <image>1234</image>
+            </graphic>
+          </collection>
+        </graphics>
+      </quantitativeAnalysis>
+    </modelCard>
+    <data>
+      <type>configuration</type>
+      <name>config</name>
+      <contents>
+        <attachment>foo: bar</attachment>
+      </contents>
+    </data>
+    <cryptoProperties>
+      <assetType>algorithm</assetType>
+      <algorithmProperties>
+        <primitive>kem</primitive>
+        <parameterSetIdentifier>768</parameterSetIdentifier>
+        <curve>brainpoolP160r1</curve>
+        <executionEnvironment>software-plain-ram</executionEnvironment>
+        <implementationPlatform>x86_64</implementationPlatform>
+        <certificationLevel>none</certificationLevel>
+        <mode>cbc</mode>
+        <padding>pkcs7</padding>
+        <cryptoFunctions>
+          <cryptoFunction>keygen</cryptoFunction>
+          <cryptoFunction>encapsulate</cryptoFunction>
+        </cryptoFunctions>
+        <classicalSecurityLevel>128</classicalSecurityLevel>
+        <nistQuantumSecurityLevel>3</nistQuantumSecurityLevel>
+      </algorithmProperties>
+      <certificateProperties>
+        <subjectName>CN=example.com</subjectName>
+        <issuerName>CN=Example CA</issuerName>
+        <notValidBefore>2024-01-01T00:00:00Z</notValidBefore>
+        <notValidAfter>2025-01-01T00:00:00Z</notValidAfter>
+        <signatureAlgorithmRef>crypto/algorithm/sha-512-rsa</signatureAlgorithmRef>
+        <subjectPublicKeyRef>crypto/key/rsa-2048</subjectPublicKeyRef>
+        <certificateFormat>X.509</certificateFormat>
+        <certificateExtension>crt</certificateExtension>
+      </certificateProperties>
+      <relatedCryptoMaterialProperties>
+        <type>public-key</type>
+        <id>2e9ef09e-dfac-4526-96b4-d02f31af1b22</id>
+        <state>active</state>
+        <algorithmRef>crypto/algorithm/rsa-2048</algorithmRef>
+        <creationDate>2024-01-01T00:00:00Z</creationDate>
+        <activationDate>2024-01-02T00:00:00Z</activationDate>
+        <updateDate>2024-01-03T00:00:00Z</updateDate>
+        <expirationDate>2025-01-01T00:00:00Z</expirationDate>
+        <value>value</value>
+        <size>2048</size>
+        <format>PEM</format>
+        <securedBy>
+          <mechanism>Software</mechanism>
+          <algorithmRef>crypto/algorithm/aes-128-gcm</algorithmRef>
+        </securedBy>
+      </relatedCryptoMaterialProperties>
+      <protocolProperties>
+        <type>ike</type>
+        <version>2.0</version>
+        <cipherSuites>
+          <cipherSuite>
+            <name>TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256</name>
+            <algorithms>
+              <algorithm>crypto/algorithm/ecdh-curve25519</algorithm>
+            </algorithms>
+            <identifiers>
+              <identifier>0xC0</identifier>
+              <identifier>0x2F</identifier>
+            </identifiers>
+          </cipherSuite>
+        </cipherSuites>
+        <ikev2TransformTypes>
+          <encr>crypto/algorithm/aes-128-gcm</encr>
+          <prf>crypto/algorithm/sha-256</prf>
+          <integ>crypto/algorithm/hmac-sha-256</integ>
+          <ke>crypto/algorithm/ecdh-curve25519</ke>
+          <esn>true</esn>
+          <auth>crypto/algorithm/rsa-2048</auth>
+        </ikev2TransformTypes>
+        <cryptoRefArray>
+          <cryptoRef>crypto/certificate/example.com</cryptoRef>
+        </cryptoRefArray>
+      </protocolProperties>
+      <oid>2.16.840.1.101.3.4.4.2</oid>
+    </cryptoProperties>
+  </component>
+</components>
+"#;
+            #[versioned("1.6")]
+            let input = r#"
+<components>
+  <component type="component type" mime-type="mime type" bom-ref="bom ref">
+    <supplier>
+      <name>name</name>
+      <url>url</url>
+      <contact>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </contact>
+    </supplier>
+    <authors>
+      <author>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </author>
+    </authors>
+    <author>author</author>
+    <publisher>publisher</publisher>
+    <group>group</group>
+    <name>name</name>
+    <version>version</version>
+    <description>description</description>
+    <scope>scope</scope>
+    <hashes>
+      <hash alg="algorithm">hash value</hash>
+    </hashes>
+    <licenses>
+      <expression>expression</expression>
+    </licenses>
+    <copyright>copyright</copyright>
+    <cpe>cpe</cpe>
+    <purl>purl</purl>
+    <swid tagId="tag id" name="name" version="version" tagVersion="1" patch="true">
+      <text content-type="content type" encoding="encoding">content</text>
+      <url>url</url>
+    </swid>
+    <modified>true</modified>
+    <pedigree>
+      <ancestors />
+      <descendants />
+      <variants />
+      <commits>
+        <commit>
+          <uid>uid</uid>
+          <url>url</url>
+          <author>
+            <timestamp>timestamp</timestamp>
+            <name>name</name>
+            <email>email</email>
+          </author>
+          <committer>
+            <timestamp>timestamp</timestamp>
+            <name>name</name>
+            <email>email</email>
+          </committer>
+          <message>message</message>
+        </commit>
+      </commits>
+      <patches>
+        <patch type="patch type">
+          <diff>
+            <text content-type="content type" encoding="encoding">content</text>
+            <url>url</url>
+          </diff>
+          <resolves>
+            <issue type="issue type">
+              <id>id</id>
+              <name>name</name>
+              <description>description</description>
+              <source>
+                <name>name</name>
+                <url>url</url>
+              </source>
+              <references>
+                <url>reference</url>
+              </references>
+            </issue>
+          </resolves>
+        </patch>
+      </patches>
+      <notes>notes</notes>
+    </pedigree>
+    <externalReferences>
+      <reference type="external reference type">
+        <url>url</url>
+        <comment>comment</comment>
+        <hashes>
+          <hash alg="algorithm">hash value</hash>
+        </hashes>
+      </reference>
+    </externalReferences>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+    <components />
+    <evidence>
+      <licenses>
+        <expression>expression</expression>
+      </licenses>
+      <copyright>
+        <text><![CDATA[copyright]]></text>
+      </copyright>
+      <occurrences>
+        <occurrence bom-ref="occurrence-1">
+          <location>location-1</location>
+        </occurrence>
+      </occurrences>
+      <callstack>
+        <frames>
+          <frame>
+            <package>package-1</package>
+            <module>module-1</module>
+            <function>function</function>
+            <line>10</line>
+            <column>20</column>
+            <fullFilename>full-filename</fullFilename>
+          </frame>
+        </frames>
+      </callstack>
+      <identity>
+        <field>group</field>
+        <confidence>0.5</confidence>
+        <methods>
+          <method>
+            <technique>technique-1</technique>
+            <confidence>0.8</confidence>
+            <value>identity-value</value>
+          </method>
+        </methods>
+        <tools>
+          <tool ref="tool-ref-1" />
+        </tools>
+      </identity>
+    </evidence>
+    <releaseNotes>
+      <type>type</type>
+      <title>title</title>
+      <featuredImage>featured image</featuredImage>
+      <socialImage>social image</socialImage>
+      <description>description</description>
+      <timestamp>timestamp</timestamp>
+      <aliases>
+        <alias>alias</alias>
+      </aliases>
+      <tags>
+        <tag>tag</tag>
+      </tags>
+      <resolves>
+        <issue type="issue type">
+          <id>id</id>
+          <name>name</name>
+          <description>description</description>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <references>
+            <url>reference</url>
+          </references>
+        </issue>
+      </resolves>
+      <notes>
+        <note>
+          <locale>locale</locale>
+          <text content-type="content type" encoding="encoding">content</text>
+        </note>
+      </notes>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </releaseNotes>
     <signature>
       <algorithm>HS512</algorithm>
       <value>1234567890</value>
@@ -2053,6 +2627,80 @@ pub(crate) mod base {
         <attachment>foo: bar</attachment>
       </contents>
     </data>
+    <cryptoProperties>
+      <assetType>algorithm</assetType>
+      <algorithmProperties>
+        <primitive>kem</primitive>
+        <parameterSetIdentifier>768</parameterSetIdentifier>
+        <curve>brainpoolP160r1</curve>
+        <executionEnvironment>software-plain-ram</executionEnvironment>
+        <implementationPlatform>x86_64</implementationPlatform>
+        <certificationLevel>none</certificationLevel>
+        <mode>cbc</mode>
+        <padding>pkcs7</padding>
+        <cryptoFunctions>
+          <cryptoFunction>keygen</cryptoFunction>
+          <cryptoFunction>encapsulate</cryptoFunction>
+        </cryptoFunctions>
+        <classicalSecurityLevel>128</classicalSecurityLevel>
+        <nistQuantumSecurityLevel>3</nistQuantumSecurityLevel>
+      </algorithmProperties>
+      <certificateProperties>
+        <subjectName>CN=example.com</subjectName>
+        <issuerName>CN=Example CA</issuerName>
+        <notValidBefore>2024-01-01T00:00:00Z</notValidBefore>
+        <notValidAfter>2025-01-01T00:00:00Z</notValidAfter>
+        <signatureAlgorithmRef>crypto/algorithm/sha-512-rsa</signatureAlgorithmRef>
+        <subjectPublicKeyRef>crypto/key/rsa-2048</subjectPublicKeyRef>
+        <certificateFormat>X.509</certificateFormat>
+        <certificateExtension>crt</certificateExtension>
+      </certificateProperties>
+      <relatedCryptoMaterialProperties>
+        <type>public-key</type>
+        <id>2e9ef09e-dfac-4526-96b4-d02f31af1b22</id>
+        <state>active</state>
+        <algorithmRef>crypto/algorithm/rsa-2048</algorithmRef>
+        <creationDate>2024-01-01T00:00:00Z</creationDate>
+        <activationDate>2024-01-02T00:00:00Z</activationDate>
+        <updateDate>2024-01-03T00:00:00Z</updateDate>
+        <expirationDate>2025-01-01T00:00:00Z</expirationDate>
+        <value>value</value>
+        <size>2048</size>
+        <format>PEM</format>
+        <securedBy>
+          <mechanism>Software</mechanism>
+          <algorithmRef>crypto/algorithm/aes-128-gcm</algorithmRef>
+        </securedBy>
+      </relatedCryptoMaterialProperties>
+      <protocolProperties>
+        <type>ike</type>
+        <version>2.0</version>
+        <cipherSuites>
+          <cipherSuite>
+            <name>TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256</name>
+            <algorithms>
+              <algorithm>crypto/algorithm/ecdh-curve25519</algorithm>
+            </algorithms>
+            <identifiers>
+              <identifier>0xC0</identifier>
+              <identifier>0x2F</identifier>
+            </identifiers>
+          </cipherSuite>
+        </cipherSuites>
+        <ikev2TransformTypes>
+          <encr>crypto/algorithm/aes-128-gcm</encr>
+          <prf>crypto/algorithm/sha-256</prf>
+          <integ>crypto/algorithm/hmac-sha-256</integ>
+          <ke>crypto/algorithm/ecdh-curve25519</ke>
+          <esn>true</esn>
+          <auth>crypto/algorithm/rsa-2048</auth>
+        </ikev2TransformTypes>
+        <cryptoRefArray>
+          <cryptoRef>crypto/certificate/example.com</cryptoRef>
+        </cryptoRefArray>
+      </protocolProperties>
+      <oid>2.16.840.1.101.3.4.4.2</oid>
+    </cryptoProperties>
   </component>
 </components>
 "#;
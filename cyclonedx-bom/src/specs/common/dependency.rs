@@ -20,9 +20,8 @@ use crate::{
     errors::{XmlReadError, XmlWriteError},
     models,
     xml::{
-        attribute_or_error, closing_tag_or_error, read_list_tag, to_xml_read_error,
-        to_xml_write_error, unexpected_element_error, write_close_tag, write_start_tag, FromXml,
-        ToXml,
+        attribute_or_error, closing_tag_or_error, read_list_tag, skip_unexpected_element,
+        to_xml_read_error, to_xml_write_error, write_close_tag, write_start_tag, FromXml, ToXml,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -64,7 +63,7 @@ impl ToXml for Dependencies {
 
 impl FromXml for Dependencies {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -134,7 +133,7 @@ impl ToXml for Dependency {
 
 impl FromXml for Dependency {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -163,7 +162,7 @@ impl FromXml for Dependency {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -18,7 +18,7 @@
 
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.3", "1.4", "1.5")]
+#[versioned("1.3", "1.4", "1.5", "1.6")]
 pub(crate) mod base {
     use crate::{
         errors::XmlReadError,
@@ -26,9 +26,9 @@ pub(crate) mod base {
         specs::common::hash::Hashes,
         utilities::{convert_optional, convert_vec},
         xml::{
-            attribute_or_error, read_list_tag, read_simple_tag, to_xml_read_error,
-            to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
-            write_start_tag, FromXml, ToXml,
+            attribute_or_error, read_list_tag, read_simple_tag, skip_unexpected_element,
+            to_xml_read_error, to_xml_write_error, write_close_tag, write_simple_tag,
+            write_start_tag, FromXml, ToInnerXml, ToXml,
         },
     };
     use serde::{Deserialize, Serialize};
@@ -71,7 +71,7 @@ pub(crate) mod base {
 
     impl FromXml for ExternalReferences {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, crate::errors::XmlReadError>
@@ -128,13 +128,20 @@ pub(crate) mod base {
         fn write_xml_element<W: std::io::Write>(
             &self,
             writer: &mut xml::EventWriter<W>,
+        ) -> Result<(), crate::errors::XmlWriteError> {
+            self.write_xml_named_element(writer, REFERENCE_TAG)
+        }
+    }
+
+    impl ToInnerXml for ExternalReference {
+        fn write_xml_named_element<W: std::io::Write>(
+            &self,
+            writer: &mut xml::EventWriter<W>,
+            tag: &str,
         ) -> Result<(), crate::errors::XmlWriteError> {
             writer
-                .write(
-                    XmlEvent::start_element(REFERENCE_TAG)
-                        .attr(TYPE_ATTR, &self.external_reference_type),
-                )
-                .map_err(to_xml_write_error(REFERENCE_TAG))?;
+                .write(XmlEvent::start_element(tag).attr(TYPE_ATTR, &self.external_reference_type))
+                .map_err(to_xml_write_error(tag))?;
 
             write_simple_tag(writer, URL_TAG, &self.url)?;
 
@@ -148,7 +155,7 @@ pub(crate) mod base {
 
             writer
                 .write(XmlEvent::end_element())
-                .map_err(to_xml_write_error(REFERENCE_TAG))?;
+                .map_err(to_xml_write_error(tag))?;
 
             Ok(())
         }
@@ -158,7 +165,7 @@ pub(crate) mod base {
 
     impl FromXml for ExternalReference {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -192,7 +199,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
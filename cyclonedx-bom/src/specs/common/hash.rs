@@ -65,7 +65,7 @@ impl ToXml for Hashes {
 
 impl FromXml for Hashes {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -126,7 +126,7 @@ impl ToXml for Hash {
 
 impl FromXml for Hash {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -18,12 +18,14 @@
 
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.3", "1.4", "1.5")]
+#[versioned("1.3", "1.4", "1.5", "1.6")]
 pub(crate) mod base {
     use crate::models;
     use crate::models::bom::BomReference;
     #[versioned("1.5")]
     use crate::specs::{common::property::Properties, v1_5::licensing::Licensing};
+    #[versioned("1.6")]
+    use crate::specs::{common::property::Properties, v1_6::licensing::Licensing};
     use crate::xml::{optional_attribute, write_close_tag, write_simple_tag};
     use crate::{
         errors::XmlReadError,
@@ -34,9 +36,8 @@ pub(crate) mod base {
         },
         utilities::convert_vec,
         xml::{
-            closing_tag_or_error, inner_text_or_error, read_lax_validation_tag, read_simple_tag,
-            to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, ToInnerXml,
-            ToXml,
+            read_lax_validation_tag, read_simple_tag, read_text_content, skip_unexpected_element,
+            to_xml_read_error, to_xml_write_error, FromXml, ToInnerXml, ToXml,
         },
     };
     use crate::{specs::common::attached_text::AttachedText, utilities::convert_optional};
@@ -83,7 +84,7 @@ pub(crate) mod base {
 
     impl FromXml for Licenses {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -110,7 +111,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
@@ -168,7 +169,7 @@ pub(crate) mod base {
 
     impl FromXml for LicenseChoice {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &OwnedName,
             attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -197,8 +198,8 @@ pub(crate) mod base {
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
     #[serde(rename_all = "camelCase")]
     pub(crate) struct License {
-        #[versioned("1.5")]
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[versioned("1.5", "1.6")]
+        #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
         bom_ref: Option<String>,
         #[serde(flatten)]
         license_identifier: LicenseIdentifier,
@@ -206,10 +207,10 @@ pub(crate) mod base {
         text: Option<AttachedText>,
         #[serde(skip_serializing_if = "Option::is_none")]
         url: Option<String>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         licensing: Option<Licensing>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         properties: Option<Properties>,
     }
@@ -217,14 +218,14 @@ pub(crate) mod base {
     impl From<models::license::License> for License {
         fn from(other: models::license::License) -> Self {
             Self {
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 bom_ref: other.bom_ref.map(|b| b.0),
                 license_identifier: other.license_identifier.into(),
                 text: convert_optional(other.text),
                 url: other.url.map(|u| u.to_string()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 licensing: convert_optional(other.licensing),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties: convert_optional(other.properties),
             }
         }
@@ -235,18 +236,18 @@ pub(crate) mod base {
             Self {
                 #[versioned("1.3", "1.4")]
                 bom_ref: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 bom_ref: other.bom_ref.map(models::bom::BomReference::new),
                 license_identifier: other.license_identifier.into(),
                 text: convert_optional(other.text),
                 url: other.url.map(Uri),
                 #[versioned("1.3", "1.4")]
                 licensing: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 licensing: convert_optional(other.licensing),
                 #[versioned("1.3", "1.4")]
                 properties: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties: convert_optional(other.properties),
             }
         }
@@ -255,9 +256,9 @@ pub(crate) mod base {
     const LICENSE_TAG: &str = "license";
     const TEXT_TAG: &str = "text";
     const URL_TAG: &str = "url";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const LICENSING_TAG: &str = "licensing";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const PROPERTIES_TAG: &str = "properties";
 
     impl ToXml for License {
@@ -268,9 +269,9 @@ pub(crate) mod base {
             #[versioned("1.3", "1.4")]
             let start_tag = xml::writer::XmlEvent::start_element(LICENSE_TAG);
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut start_tag = xml::writer::XmlEvent::start_element(LICENSE_TAG);
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             if let Some(bom_ref) = &self.bom_ref {
                 start_tag = start_tag.attr(BOM_REF_ATTR, bom_ref);
             }
@@ -289,7 +290,12 @@ pub(crate) mod base {
                 write_simple_tag(writer, URL_TAG, url)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
+            if let Some(licensing) = &self.licensing {
+                licensing.write_xml_element(writer)?;
+            }
+
+            #[versioned("1.5", "1.6")]
             if let Some(properties) = &self.properties {
                 properties.write_xml_element(writer)?;
             }
@@ -304,21 +310,21 @@ pub(crate) mod base {
 
     impl FromXml for License {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &OwnedName,
             #[allow(unused)] attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
         where
             Self: Sized,
         {
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
             let mut license_identifier: Option<LicenseIdentifier> = None;
             let mut text: Option<AttachedText> = None;
             let mut url: Option<String> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut licensing: Option<Licensing> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut properties: Option<Properties> = None;
 
             let mut got_end_tag = false;
@@ -359,7 +365,7 @@ pub(crate) mod base {
                     reader::XmlEvent::StartElement { name, .. } if name.local_name == URL_TAG => {
                         url = Some(read_simple_tag(event_reader, &name)?)
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == LICENSING_TAG => {
@@ -369,7 +375,7 @@ pub(crate) mod base {
                             &attributes,
                         )?);
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == PROPERTIES_TAG => {
@@ -387,7 +393,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
             let license_identifier =
@@ -397,14 +403,14 @@ pub(crate) mod base {
                 })?;
 
             Ok(Self {
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 bom_ref,
                 license_identifier,
                 text,
                 url,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 licensing,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 properties,
             })
         }
@@ -459,7 +465,7 @@ pub(crate) mod base {
 
     impl FromXml for LicenseIdentifier {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             name: &OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -468,28 +474,14 @@ pub(crate) mod base {
         {
             match name.local_name.as_str() {
                 ID_TAG => {
-                    let id = event_reader
-                        .next()
-                        .map_err(to_xml_read_error(ID_TAG))
-                        .and_then(inner_text_or_error(ID_TAG))?;
-
-                    event_reader
-                        .next()
-                        .map_err(to_xml_read_error(ID_TAG))
-                        .and_then(closing_tag_or_error(name))?;
+                    let id = read_text_content(event_reader, name)?
+                        .ok_or_else(|| XmlReadError::required_data_missing("content", name))?;
 
                     Ok(Self::SpdxId(id))
                 }
                 NAME_TAG => {
-                    let license_name = event_reader
-                        .next()
-                        .map_err(to_xml_read_error(NAME_TAG))
-                        .and_then(inner_text_or_error(NAME_TAG))?;
-
-                    event_reader
-                        .next()
-                        .map_err(to_xml_read_error(NAME_TAG))
-                        .and_then(closing_tag_or_error(name))?;
+                    let license_name = read_text_content(event_reader, name)?
+                        .ok_or_else(|| XmlReadError::required_data_missing("content", name))?;
 
                     Ok(Self::Name(license_name))
                 }
@@ -504,7 +496,7 @@ pub(crate) mod base {
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
     #[serde(rename_all = "camelCase")]
     pub(crate) struct Expression {
-        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
         bom_ref: Option<String>,
         expression: String,
     }
@@ -564,7 +556,7 @@ pub(crate) mod base {
 
     impl FromXml for Expression {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &OwnedName,
             attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -591,6 +583,11 @@ pub(crate) mod base {
             common::property::test::{corresponding_properties, example_properties},
             v1_5::licensing::test::{corresponding_licensing, example_licensing},
         };
+        #[versioned("1.6")]
+        use crate::specs::{
+            common::property::test::{corresponding_properties, example_properties},
+            v1_6::licensing::test::{corresponding_licensing, example_licensing},
+        };
 
         use crate::{
             external_models::spdx::SpdxExpression,
@@ -617,7 +614,7 @@ pub(crate) mod base {
             })
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         pub(crate) fn example_spdx_license() -> LicenseChoice {
             LicenseChoice::License(License {
                 bom_ref: Some("license-id".to_string()),
@@ -645,7 +642,7 @@ pub(crate) mod base {
         }
 
         #[allow(unused)]
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         pub(crate) fn corresponding_spdx_license() -> models::license::LicenseChoice {
             models::license::LicenseChoice::License(models::license::License {
                 bom_ref: Some(models::bom::BomReference::new("license-id")),
@@ -668,7 +665,7 @@ pub(crate) mod base {
             })
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         pub(crate) fn example_named_license() -> LicenseChoice {
             LicenseChoice::License(License {
                 bom_ref: Some("license-1".to_string()),
@@ -696,7 +693,7 @@ pub(crate) mod base {
         }
 
         #[allow(unused)]
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         pub(crate) fn corresponding_named_license() -> models::license::LicenseChoice {
             models::license::LicenseChoice::License(models::license::License {
                 bom_ref: Some(models::bom::BomReference::new("license-1".to_string())),
@@ -794,7 +791,7 @@ pub(crate) mod base {
             assert_eq!(actual, expected);
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[test]
         fn it_should_read_xml_full_license_choice_licenses() {
             let input = r#"
@@ -18,7 +18,7 @@
 
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.3", "1.4", "1.5")]
+#[versioned("1.3", "1.4", "1.5", "1.6")]
 pub(crate) mod base {
     #[versioned("1.3")]
     use crate::specs::v1_3::{component::Component, license::Licenses, tool::Tools};
@@ -28,6 +28,10 @@ pub(crate) mod base {
     use crate::specs::v1_5::{
         component::Component, license::Licenses, lifecycles::Lifecycles, tool::Tools,
     };
+    #[versioned("1.6")]
+    use crate::specs::v1_6::{
+        component::Component, license::Licenses, lifecycles::Lifecycles, tool::Tools,
+    };
 
     use crate::errors::BomError;
     use crate::xml::{write_close_tag, write_start_tag};
@@ -40,8 +44,8 @@ pub(crate) mod base {
         },
         utilities::{convert_optional, convert_optional_vec, try_convert_optional},
         xml::{
-            read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-            unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
+            read_lax_validation_tag, read_list_tag, read_simple_tag, skip_unexpected_element,
+            to_xml_read_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
         },
     };
     use serde::{Deserialize, Serialize};
@@ -56,6 +60,9 @@ pub(crate) mod base {
         #[serde(skip_serializing_if = "Option::is_none")]
         tools: Option<Tools>,
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[versioned("1.6")]
+        manufacturer: Option<OrganizationalEntity>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         authors: Option<Vec<OrganizationalContact>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         component: Option<Component>,
@@ -68,7 +75,7 @@ pub(crate) mod base {
         #[serde(skip_serializing_if = "Option::is_none")]
         properties: Option<Properties>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         lifecycles: Option<Lifecycles>,
     }
 
@@ -79,13 +86,15 @@ pub(crate) mod base {
             Ok(Self {
                 timestamp: other.timestamp.map(|t| t.to_string()),
                 tools: try_convert_optional(other.tools)?,
+                #[versioned("1.6")]
+                manufacturer: convert_optional(other.manufacturer),
                 authors: convert_optional_vec(other.authors),
                 component: try_convert_optional(other.component)?,
                 manufacture: convert_optional(other.manufacture),
                 supplier: convert_optional(other.supplier),
                 licenses: convert_optional(other.licenses),
                 properties: convert_optional(other.properties),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 lifecycles: convert_optional(other.lifecycles),
             })
         }
@@ -99,12 +108,16 @@ pub(crate) mod base {
                 authors: convert_optional_vec(other.authors),
                 component: convert_optional(other.component),
                 manufacture: convert_optional(other.manufacture),
+                #[versioned("1.3", "1.4", "1.5")]
+                manufacturer: None,
+                #[versioned("1.6")]
+                manufacturer: convert_optional(other.manufacturer),
                 supplier: convert_optional(other.supplier),
                 licenses: convert_optional(other.licenses),
                 properties: convert_optional(other.properties),
                 #[versioned("1.3", "1.4")]
                 lifecycles: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 lifecycles: convert_optional(other.lifecycles),
             }
         }
@@ -115,6 +128,8 @@ pub(crate) mod base {
     const AUTHORS_TAG: &str = "authors";
     const AUTHOR_TAG: &str = "author";
     const MANUFACTURE_TAG: &str = "manufacture";
+    #[versioned("1.6")]
+    const MANUFACTURER_TAG: &str = "manufacturer";
     const SUPPLIER_TAG: &str = "supplier";
 
     impl ToXml for Metadata {
@@ -132,6 +147,11 @@ pub(crate) mod base {
                 tools.write_xml_element(writer)?;
             }
 
+            #[versioned("1.6")]
+            if let Some(manufacturer) = &self.manufacturer {
+                manufacturer.write_xml_named_element(writer, MANUFACTURER_TAG)?;
+            }
+
             if let Some(authors) = &self.authors {
                 write_start_tag(writer, AUTHORS_TAG)?;
 
@@ -164,7 +184,7 @@ pub(crate) mod base {
                 properties.write_xml_element(writer)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             if let Some(lifecycles) = &self.lifecycles {
                 lifecycles.write_xml_element(writer)?;
             }
@@ -175,6 +195,11 @@ pub(crate) mod base {
         }
 
         fn will_write(&self) -> bool {
+            #[versioned("1.6")]
+            if self.manufacturer.is_some() {
+                return true;
+            }
+
             self.timestamp.is_some()
                 || self.tools.is_some()
                 || self.authors.is_some()
@@ -190,12 +215,12 @@ pub(crate) mod base {
     const COMPONENT_TAG: &str = "component";
     const LICENSES_TAG: &str = "licenses";
     const PROPERTIES_TAG: &str = "properties";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const LIFECYCLES_TAG: &str = "lifecycles";
 
     impl FromXml for Metadata {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, crate::errors::XmlReadError>
@@ -207,10 +232,12 @@ pub(crate) mod base {
             let mut authors: Option<Vec<OrganizationalContact>> = None;
             let mut component: Option<Component> = None;
             let mut manufacture: Option<OrganizationalEntity> = None;
+            #[versioned("1.6")]
+            let mut manufacturer: Option<OrganizationalEntity> = None;
             let mut supplier: Option<OrganizationalEntity> = None;
             let mut licenses: Option<Licenses> = None;
             let mut properties: Option<Properties> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut lifecycles: Option<Lifecycles> = None;
 
             let mut got_end_tag = false;
@@ -252,6 +279,16 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
+                    #[versioned("1.6")]
+                    reader::XmlEvent::StartElement {
+                        name, attributes, ..
+                    } if name.local_name == MANUFACTURER_TAG => {
+                        manufacturer = Some(OrganizationalEntity::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?)
+                    }
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == SUPPLIER_TAG => {
@@ -279,7 +316,7 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == LIFECYCLES_TAG => {
@@ -296,20 +333,22 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
             Ok(Self {
                 timestamp,
                 tools,
+                #[versioned("1.6")]
+                manufacturer,
                 authors,
                 component,
                 manufacture,
                 supplier,
                 licenses,
                 properties,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 lifecycles,
             })
         }
@@ -339,6 +378,13 @@ pub(crate) mod base {
             lifecycles::test::{corresponding_lifecycles, example_lifecycles},
             tool::test::{corresponding_tools, example_tools},
         };
+        #[versioned("1.6")]
+        use crate::specs::v1_6::{
+            component::test::{corresponding_component, example_component},
+            license::test::{corresponding_licenses, example_licenses},
+            lifecycles::test::{corresponding_lifecycles, example_lifecycles},
+            tool::test::{corresponding_tools, example_tools},
+        };
         use crate::{
             specs::common::{
                 organization::test::{
@@ -353,13 +399,15 @@ pub(crate) mod base {
             Metadata {
                 timestamp: Some("timestamp".to_string()),
                 tools: Some(example_tools()),
+                #[versioned("1.6")]
+                manufacturer: None,
                 authors: Some(vec![example_contact()]),
                 component: Some(example_component()),
                 manufacture: Some(example_entity()),
                 supplier: Some(example_entity()),
                 licenses: Some(example_licenses()),
                 properties: Some(example_properties()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 lifecycles: Some(example_lifecycles()),
             }
         }
@@ -371,12 +419,13 @@ pub(crate) mod base {
                 authors: Some(vec![corresponding_contact()]),
                 component: Some(corresponding_component()),
                 manufacture: Some(corresponding_entity()),
+                manufacturer: None,
                 supplier: Some(corresponding_entity()),
                 licenses: Some(corresponding_licenses()),
                 properties: Some(corresponding_properties()),
                 #[versioned("1.3", "1.4")]
                 lifecycles: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 lifecycles: Some(corresponding_lifecycles()),
             }
         }
@@ -661,6 +710,43 @@ pub(crate) mod base {
         <text><![CDATA[copyright]]></text>
       </copyright>
     </evidence>
+    <releaseNotes>
+      <type>type</type>
+      <title>title</title>
+      <featuredImage>featured image</featuredImage>
+      <socialImage>social image</socialImage>
+      <description>description</description>
+      <timestamp>timestamp</timestamp>
+      <aliases>
+        <alias>alias</alias>
+      </aliases>
+      <tags>
+        <tag>tag</tag>
+      </tags>
+      <resolves>
+        <issue type="issue type">
+          <id>id</id>
+          <name>name</name>
+          <description>description</description>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <references>
+            <url>reference</url>
+          </references>
+        </issue>
+      </resolves>
+      <notes>
+        <note>
+          <locale>locale</locale>
+          <text content-type="content type" encoding="encoding">content</text>
+        </note>
+      </notes>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </releaseNotes>
     <signature>
       <algorithm>HS512</algorithm>
       <value>1234567890</value>
@@ -824,7 +910,387 @@ pub(crate) mod base {
         </occurrence>
       </occurrences>
       <callstack>
-        <frame>
+        <frames>
+          <frame>
+            <package>package-1</package>
+            <module>module-1</module>
+            <function>function</function>
+            <line>10</line>
+            <column>20</column>
+            <fullFilename>full-filename</fullFilename>
+          </frame>
+        </frames>
+      </callstack>
+      <identity>
+        <field>group</field>
+        <confidence>0.5</confidence>
+        <methods>
+          <method>
+            <technique>technique-1</technique>
+            <confidence>0.8</confidence>
+            <value>identity-value</value>
+          </method>
+        </methods>
+        <tools>
+          <tool ref="tool-ref-1" />
+        </tools>
+      </identity>
+    </evidence>
+    <releaseNotes>
+      <type>type</type>
+      <title>title</title>
+      <featuredImage>featured image</featuredImage>
+      <socialImage>social image</socialImage>
+      <description>description</description>
+      <timestamp>timestamp</timestamp>
+      <aliases>
+        <alias>alias</alias>
+      </aliases>
+      <tags>
+        <tag>tag</tag>
+      </tags>
+      <resolves>
+        <issue type="issue type">
+          <id>id</id>
+          <name>name</name>
+          <description>description</description>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <references>
+            <url>reference</url>
+          </references>
+        </issue>
+      </resolves>
+      <notes>
+        <note>
+          <locale>locale</locale>
+          <text content-type="content type" encoding="encoding">content</text>
+        </note>
+      </notes>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </releaseNotes>
+    <signature>
+      <algorithm>HS512</algorithm>
+      <value>1234567890</value>
+    </signature>
+    <modelCard bom-ref="modelcard-1">
+      <modelParameters>
+        <approach>
+          <type>supervised</type>
+        </approach>
+        <task>Task</task>
+        <architectureFamily>Architecture</architectureFamily>
+        <modelArchitecture>Model</modelArchitecture>
+        <datasets>
+          <dataset bom-ref="dataset-1">
+            <type>dataset</type>
+            <name>Training Data</name>
+            <contents>
+              <url>https://example.com/path/to/dataset</url>
+            </contents>
+            <classification>public</classification>
+            <governance>
+              <owners>
+                <owner>
+                  <contact bom-ref="contact-1">
+                    <name>Contact</name>
+                    <email>contact@example.com</email>
+                  </contact>
+                </owner>
+              </owners>
+            </governance>
+          </dataset>
+        </datasets>
+        <inputs>
+          <input>
+            <format>string</format>
+          </input>
+        </inputs>
+        <outputs>
+          <output>
+            <format>image</format>
+          </output>
+        </outputs>
+      </modelParameters>
+      <quantitativeAnalysis>
+        <performanceMetrics>
+          <performanceMetric>
+            <type>metric-1</type>
+            <value>metric value</value>
+            <confidenceInterval>
+              <lowerBound>low</lowerBound>
+              <upperBound>high</upperBound>
+            </confidenceInterval>
+          </performanceMetric>
+        </performanceMetrics>
+        <graphics>
+          <description>Graphic Desc</description>
+          <collection>
+            <graphic>
+              <name>Graphic A</name>
+              <image>1234</image>
+            </graphic>
+          </collection>
+        </graphics>
+      </quantitativeAnalysis>
+    </modelCard>
+    <data>
+      <type>configuration</type>
+      <name>config</name>
+      <contents>
+        <attachment>foo: bar</attachment>
+      </contents>
+    </data>
+    <cryptoProperties>
+      <assetType>algorithm</assetType>
+      <algorithmProperties>
+        <primitive>kem</primitive>
+        <parameterSetIdentifier>768</parameterSetIdentifier>
+        <curve>brainpoolP160r1</curve>
+        <executionEnvironment>software-plain-ram</executionEnvironment>
+        <implementationPlatform>x86_64</implementationPlatform>
+        <certificationLevel>none</certificationLevel>
+        <mode>cbc</mode>
+        <padding>pkcs7</padding>
+        <cryptoFunctions>
+          <cryptoFunction>keygen</cryptoFunction>
+          <cryptoFunction>encapsulate</cryptoFunction>
+        </cryptoFunctions>
+        <classicalSecurityLevel>128</classicalSecurityLevel>
+        <nistQuantumSecurityLevel>3</nistQuantumSecurityLevel>
+      </algorithmProperties>
+      <certificateProperties>
+        <subjectName>CN=example.com</subjectName>
+        <issuerName>CN=Example CA</issuerName>
+        <notValidBefore>2024-01-01T00:00:00Z</notValidBefore>
+        <notValidAfter>2025-01-01T00:00:00Z</notValidAfter>
+        <signatureAlgorithmRef>crypto/algorithm/sha-512-rsa</signatureAlgorithmRef>
+        <subjectPublicKeyRef>crypto/key/rsa-2048</subjectPublicKeyRef>
+        <certificateFormat>X.509</certificateFormat>
+        <certificateExtension>crt</certificateExtension>
+      </certificateProperties>
+      <relatedCryptoMaterialProperties>
+        <type>public-key</type>
+        <id>2e9ef09e-dfac-4526-96b4-d02f31af1b22</id>
+        <state>active</state>
+        <algorithmRef>crypto/algorithm/rsa-2048</algorithmRef>
+        <creationDate>2024-01-01T00:00:00Z</creationDate>
+        <activationDate>2024-01-02T00:00:00Z</activationDate>
+        <updateDate>2024-01-03T00:00:00Z</updateDate>
+        <expirationDate>2025-01-01T00:00:00Z</expirationDate>
+        <value>value</value>
+        <size>2048</size>
+        <format>PEM</format>
+        <securedBy>
+          <mechanism>Software</mechanism>
+          <algorithmRef>crypto/algorithm/aes-128-gcm</algorithmRef>
+        </securedBy>
+      </relatedCryptoMaterialProperties>
+      <protocolProperties>
+        <type>ike</type>
+        <version>2.0</version>
+        <cipherSuites>
+          <cipherSuite>
+            <name>TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256</name>
+            <algorithms>
+              <algorithm>crypto/algorithm/ecdh-curve25519</algorithm>
+            </algorithms>
+            <identifiers>
+              <identifier>0xC0</identifier>
+              <identifier>0x2F</identifier>
+            </identifiers>
+          </cipherSuite>
+        </cipherSuites>
+        <ikev2TransformTypes>
+          <encr>crypto/algorithm/aes-128-gcm</encr>
+          <prf>crypto/algorithm/sha-256</prf>
+          <integ>crypto/algorithm/hmac-sha-256</integ>
+          <ke>crypto/algorithm/ecdh-curve25519</ke>
+          <esn>true</esn>
+          <auth>crypto/algorithm/rsa-2048</auth>
+        </ikev2TransformTypes>
+        <cryptoRefArray>
+          <cryptoRef>crypto/certificate/example.com</cryptoRef>
+        </cryptoRefArray>
+      </protocolProperties>
+      <oid>2.16.840.1.101.3.4.4.2</oid>
+    </cryptoProperties>
+  </component>
+  <manufacture>
+    <name>name</name>
+    <url>url</url>
+    <contact>
+      <name>name</name>
+      <email>email</email>
+      <phone>phone</phone>
+    </contact>
+  </manufacture>
+  <supplier>
+    <name>name</name>
+    <url>url</url>
+    <contact>
+      <name>name</name>
+      <email>email</email>
+      <phone>phone</phone>
+    </contact>
+  </supplier>
+  <licenses>
+    <expression>expression</expression>
+  </licenses>
+  <properties>
+    <property name="name">value</property>
+  </properties>
+  <lifecycles>
+    <lifecycle>
+      <phase>design</phase>
+    </lifecycle>
+  </lifecycles>
+</metadata>
+"#;
+            #[versioned("1.6")]
+            let input = r#"
+<metadata>
+  <timestamp>timestamp</timestamp>
+  <tools>
+    <tool>
+      <vendor>vendor</vendor>
+      <name>name</name>
+      <version>version</version>
+      <hashes>
+        <hash alg="algorithm">hash value</hash>
+      </hashes>
+      <externalReferences>
+        <reference type="external reference type">
+          <url>url</url>
+          <comment>comment</comment>
+          <hashes>
+            <hash alg="algorithm">hash value</hash>
+          </hashes>
+        </reference>
+      </externalReferences>
+    </tool>
+  </tools>
+  <authors>
+    <author>
+      <name>name</name>
+      <email>email</email>
+      <phone>phone</phone>
+    </author>
+  </authors>
+  <component type="component type" mime-type="mime type" bom-ref="bom ref">
+    <supplier>
+      <name>name</name>
+      <url>url</url>
+      <contact>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </contact>
+    </supplier>
+    <authors>
+      <author>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </author>
+    </authors>
+    <author>author</author>
+    <publisher>publisher</publisher>
+    <group>group</group>
+    <name>name</name>
+    <version>version</version>
+    <description>description</description>
+    <scope>scope</scope>
+    <hashes>
+      <hash alg="algorithm">hash value</hash>
+    </hashes>
+    <licenses>
+      <expression>expression</expression>
+    </licenses>
+    <copyright>copyright</copyright>
+    <cpe>cpe</cpe>
+    <purl>purl</purl>
+    <swid tagId="tag id" name="name" version="version" tagVersion="1" patch="true">
+      <text content-type="content type" encoding="encoding">content</text>
+      <url>url</url>
+    </swid>
+    <modified>true</modified>
+    <pedigree>
+      <ancestors />
+      <descendants />
+      <variants />
+      <commits>
+        <commit>
+          <uid>uid</uid>
+          <url>url</url>
+          <author>
+            <timestamp>timestamp</timestamp>
+            <name>name</name>
+            <email>email</email>
+          </author>
+          <committer>
+            <timestamp>timestamp</timestamp>
+            <name>name</name>
+            <email>email</email>
+          </committer>
+          <message>message</message>
+        </commit>
+      </commits>
+      <patches>
+        <patch type="patch type">
+          <diff>
+            <text content-type="content type" encoding="encoding">content</text>
+            <url>url</url>
+          </diff>
+          <resolves>
+            <issue type="issue type">
+              <id>id</id>
+              <name>name</name>
+              <description>description</description>
+              <source>
+                <name>name</name>
+                <url>url</url>
+              </source>
+              <references>
+                <url>reference</url>
+              </references>
+            </issue>
+          </resolves>
+        </patch>
+      </patches>
+      <notes>notes</notes>
+    </pedigree>
+    <externalReferences>
+      <reference type="external reference type">
+        <url>url</url>
+        <comment>comment</comment>
+        <hashes>
+          <hash alg="algorithm">hash value</hash>
+        </hashes>
+      </reference>
+    </externalReferences>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+    <components />
+    <evidence>
+      <licenses>
+        <expression>expression</expression>
+      </licenses>
+      <copyright>
+        <text><![CDATA[copyright]]></text>
+      </copyright>
+      <occurrences>
+        <occurrence bom-ref="occurrence-1">
+          <location>location-1</location>
+        </occurrence>
+      </occurrences>
+      <callstack>
+        <frames>
           <frame>
             <package>package-1</package>
             <module>module-1</module>
@@ -833,7 +1299,7 @@ pub(crate) mod base {
             <column>20</column>
             <fullFilename>full-filename</fullFilename>
           </frame>
-        </frame>
+        </frames>
       </callstack>
       <identity>
         <field>group</field>
@@ -850,6 +1316,43 @@ pub(crate) mod base {
         </tools>
       </identity>
     </evidence>
+    <releaseNotes>
+      <type>type</type>
+      <title>title</title>
+      <featuredImage>featured image</featuredImage>
+      <socialImage>social image</socialImage>
+      <description>description</description>
+      <timestamp>timestamp</timestamp>
+      <aliases>
+        <alias>alias</alias>
+      </aliases>
+      <tags>
+        <tag>tag</tag>
+      </tags>
+      <resolves>
+        <issue type="issue type">
+          <id>id</id>
+          <name>name</name>
+          <description>description</description>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <references>
+            <url>reference</url>
+          </references>
+        </issue>
+      </resolves>
+      <notes>
+        <note>
+          <locale>locale</locale>
+          <text content-type="content type" encoding="encoding">content</text>
+        </note>
+      </notes>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </releaseNotes>
     <signature>
       <algorithm>HS512</algorithm>
       <value>1234567890</value>
@@ -922,6 +1425,80 @@ pub(crate) mod base {
         <attachment>foo: bar</attachment>
       </contents>
     </data>
+    <cryptoProperties>
+      <assetType>algorithm</assetType>
+      <algorithmProperties>
+        <primitive>kem</primitive>
+        <parameterSetIdentifier>768</parameterSetIdentifier>
+        <curve>brainpoolP160r1</curve>
+        <executionEnvironment>software-plain-ram</executionEnvironment>
+        <implementationPlatform>x86_64</implementationPlatform>
+        <certificationLevel>none</certificationLevel>
+        <mode>cbc</mode>
+        <padding>pkcs7</padding>
+        <cryptoFunctions>
+          <cryptoFunction>keygen</cryptoFunction>
+          <cryptoFunction>encapsulate</cryptoFunction>
+        </cryptoFunctions>
+        <classicalSecurityLevel>128</classicalSecurityLevel>
+        <nistQuantumSecurityLevel>3</nistQuantumSecurityLevel>
+      </algorithmProperties>
+      <certificateProperties>
+        <subjectName>CN=example.com</subjectName>
+        <issuerName>CN=Example CA</issuerName>
+        <notValidBefore>2024-01-01T00:00:00Z</notValidBefore>
+        <notValidAfter>2025-01-01T00:00:00Z</notValidAfter>
+        <signatureAlgorithmRef>crypto/algorithm/sha-512-rsa</signatureAlgorithmRef>
+        <subjectPublicKeyRef>crypto/key/rsa-2048</subjectPublicKeyRef>
+        <certificateFormat>X.509</certificateFormat>
+        <certificateExtension>crt</certificateExtension>
+      </certificateProperties>
+      <relatedCryptoMaterialProperties>
+        <type>public-key</type>
+        <id>2e9ef09e-dfac-4526-96b4-d02f31af1b22</id>
+        <state>active</state>
+        <algorithmRef>crypto/algorithm/rsa-2048</algorithmRef>
+        <creationDate>2024-01-01T00:00:00Z</creationDate>
+        <activationDate>2024-01-02T00:00:00Z</activationDate>
+        <updateDate>2024-01-03T00:00:00Z</updateDate>
+        <expirationDate>2025-01-01T00:00:00Z</expirationDate>
+        <value>value</value>
+        <size>2048</size>
+        <format>PEM</format>
+        <securedBy>
+          <mechanism>Software</mechanism>
+          <algorithmRef>crypto/algorithm/aes-128-gcm</algorithmRef>
+        </securedBy>
+      </relatedCryptoMaterialProperties>
+      <protocolProperties>
+        <type>ike</type>
+        <version>2.0</version>
+        <cipherSuites>
+          <cipherSuite>
+            <name>TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256</name>
+            <algorithms>
+              <algorithm>crypto/algorithm/ecdh-curve25519</algorithm>
+            </algorithms>
+            <identifiers>
+              <identifier>0xC0</identifier>
+              <identifier>0x2F</identifier>
+            </identifiers>
+          </cipherSuite>
+        </cipherSuites>
+        <ikev2TransformTypes>
+          <encr>crypto/algorithm/aes-128-gcm</encr>
+          <prf>crypto/algorithm/sha-256</prf>
+          <integ>crypto/algorithm/hmac-sha-256</integ>
+          <ke>crypto/algorithm/ecdh-curve25519</ke>
+          <esn>true</esn>
+          <auth>crypto/algorithm/rsa-2048</auth>
+        </ikev2TransformTypes>
+        <cryptoRefArray>
+          <cryptoRef>crypto/certificate/example.com</cryptoRef>
+        </cryptoRefArray>
+      </protocolProperties>
+      <oid>2.16.840.1.101.3.4.4.2</oid>
+    </cryptoProperties>
   </component>
   <manufacture>
     <name>name</name>
@@ -959,5 +1536,50 @@ pub(crate) mod base {
             let expected = example_metadata();
             assert_eq!(actual, expected);
         }
+
+        #[versioned("1.6")]
+        #[test]
+        fn it_should_read_and_write_manufacturer() {
+            let input = r#"
+<metadata>
+  <manufacturer>
+    <name>name</name>
+    <url>url</url>
+    <contact>
+      <name>name</name>
+      <email>email</email>
+      <phone>phone</phone>
+    </contact>
+  </manufacturer>
+</metadata>
+"#;
+            let actual: Metadata = read_element_from_string(input);
+            let expected = Metadata {
+                timestamp: None,
+                tools: None,
+                manufacturer: Some(example_entity()),
+                authors: None,
+                component: None,
+                manufacture: None,
+                supplier: None,
+                licenses: None,
+                properties: None,
+                lifecycles: None,
+            };
+            assert_eq!(actual, expected);
+            assert!(write_element_to_string(expected)
+                .trim()
+                .ends_with(input.trim()));
+
+            let json = serde_json::to_value(&actual).expect("Failed to serialize metadata");
+            assert!(json.get("manufacturer").is_some());
+
+            let model: models::metadata::Metadata = actual.into();
+            assert_eq!(model.manufacturer, Some(corresponding_entity()));
+            assert_eq!(
+                model.effective_manufacturer(),
+                Some(&corresponding_entity())
+            );
+        }
     }
 }
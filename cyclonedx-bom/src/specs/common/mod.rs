@@ -17,6 +17,7 @@
  */
 
 pub(crate) mod advisory;
+pub(crate) mod annotation;
 pub(crate) mod attached_text;
 pub(crate) mod bom;
 pub(crate) mod bom_reference;
@@ -30,6 +31,7 @@ pub(crate) mod license;
 pub(crate) mod metadata;
 pub(crate) mod organization;
 pub(crate) mod property;
+pub(crate) mod release_notes;
 pub(crate) mod service;
 pub(crate) mod signature;
 pub(crate) mod tool;
@@ -22,8 +22,8 @@ use crate::{
     models::{self, bom::BomReference},
     utilities::convert_optional_vec,
     xml::{
-        optional_attribute, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
-        to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag, FromXml,
+        optional_attribute, read_lax_validation_tag, read_simple_tag, skip_unexpected_element,
+        to_xml_read_error, to_xml_write_error, write_close_tag, write_simple_tag, FromXml,
         ToInnerXml,
     },
 };
@@ -33,7 +33,7 @@ use xml::{reader, writer::XmlEvent};
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct OrganizationalContact {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
     pub(crate) bom_ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) name: Option<String>,
@@ -102,13 +102,16 @@ impl ToInnerXml for OrganizationalContact {
     }
 
     fn will_write(&self) -> bool {
-        self.name.is_some() || self.email.is_some() || self.phone.is_some()
+        self.bom_ref.is_some()
+            || self.name.is_some()
+            || self.email.is_some()
+            || self.phone.is_some()
     }
 }
 
 impl FromXml for OrganizationalContact {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -143,7 +146,7 @@ impl FromXml for OrganizationalContact {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -159,7 +162,7 @@ impl FromXml for OrganizationalContact {
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct OrganizationalEntity {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
     pub(crate) bom_ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) name: Option<String>,
@@ -248,13 +251,16 @@ impl ToInnerXml for OrganizationalEntity {
     }
 
     fn will_write(&self) -> bool {
-        self.name.is_some() || self.url.is_some() || self.contact.is_some()
+        self.bom_ref.is_some()
+            || self.name.is_some()
+            || self.url.is_some()
+            || self.contact.is_some()
     }
 }
 
 impl FromXml for OrganizationalEntity {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -298,7 +304,7 @@ impl FromXml for OrganizationalEntity {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -65,7 +65,7 @@ impl ToXml for Properties {
 
 impl FromXml for Properties {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -87,7 +87,7 @@ impl From<models::property::Property> for Property {
     fn from(other: models::property::Property) -> Self {
         Self {
             name: other.name,
-            value: other.value.0,
+            value: other.value.into(),
         }
     }
 }
@@ -125,7 +125,7 @@ impl ToXml for Property {
 
 impl FromXml for Property {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
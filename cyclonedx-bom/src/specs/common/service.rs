@@ -18,7 +18,7 @@
 
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.3", "1.4", "1.5")]
+#[versioned("1.3", "1.4", "1.5", "1.6")]
 pub(crate) mod base {
     #[versioned("1.3", "1.4")]
     use crate::{
@@ -32,17 +32,17 @@ pub(crate) mod base {
         utilities::{convert_optional, convert_vec},
         xml::{
             attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_list_tag,
-            read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-            to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
+            read_lax_validation_tag, read_list_tag, read_simple_tag, skip_unexpected_element,
+            to_xml_read_error, to_xml_write_error, write_close_tag, write_simple_tag,
             write_start_tag, FromXml, ToInnerXml, ToXml,
         },
     };
     use serde::{Deserialize, Serialize};
     use xml::{reader, writer::XmlEvent};
 
-    #[versioned("1.4", "1.5")]
-    use crate::specs::common::signature::Signature;
     use crate::specs::common::{organization::OrganizationalEntity, property::Properties};
+    #[versioned("1.4", "1.5", "1.6")]
+    use crate::specs::common::{release_notes::ReleaseNotes, signature::Signature};
     #[versioned("1.3")]
     use crate::specs::v1_3::{external_reference::ExternalReferences, license::Licenses};
     #[versioned("1.4")]
@@ -51,6 +51,10 @@ pub(crate) mod base {
     use crate::specs::v1_5::{
         external_reference::ExternalReferences, license::Licenses, service_data::ServiceData,
     };
+    #[versioned("1.6")]
+    use crate::specs::v1_6::{
+        external_reference::ExternalReferences, license::Licenses, service_data::ServiceData,
+    };
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
     #[serde(transparent)]
@@ -65,7 +69,7 @@ pub(crate) mod base {
         }
     }
 
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     impl From<models::service::Services> for Services {
         fn from(other: models::service::Services) -> Self {
             Services(convert_vec(other.0))
@@ -99,7 +103,7 @@ pub(crate) mod base {
 
     impl FromXml for Services {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -140,10 +144,13 @@ pub(crate) mod base {
         pub(crate) properties: Option<Properties>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) services: Option<Services>,
-        #[versioned("1.4", "1.5")]
+        #[versioned("1.4", "1.5", "1.6")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(crate) release_notes: Option<ReleaseNotes>,
+        #[versioned("1.4", "1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) signature: Option<Signature>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         pub(crate) trust_zone: Option<String>,
     }
@@ -170,15 +177,17 @@ pub(crate) mod base {
                 external_references: try_convert_optional(other.external_references)?,
                 properties: convert_optional(other.properties),
                 services: try_convert_optional(other.services)?,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: convert_optional(other.release_notes),
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: convert_optional(other.signature),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 trust_zone: other.trust_zone.map(|tz| tz.to_string()),
             })
         }
     }
 
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     impl From<models::service::Service> for Service {
         fn from(other: models::service::Service) -> Self {
             Self {
@@ -198,9 +207,11 @@ pub(crate) mod base {
                 external_references: convert_optional(other.external_references),
                 properties: convert_optional(other.properties),
                 services: convert_optional(other.services),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: convert_optional(other.release_notes),
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: convert_optional(other.signature),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 trust_zone: other.trust_zone.map(|tz| tz.to_string()),
             }
         }
@@ -226,12 +237,16 @@ pub(crate) mod base {
                 properties: convert_optional(other.properties),
                 services: convert_optional(other.services),
                 #[versioned("1.3")]
+                release_notes: None,
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: convert_optional(other.release_notes),
+                #[versioned("1.3")]
                 signature: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: convert_optional(other.signature),
                 #[versioned("1.3", "1.4")]
                 trust_zone: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 trust_zone: other.trust_zone.map(NormalizedString::new_unchecked),
             }
         }
@@ -249,9 +264,11 @@ pub(crate) mod base {
     const AUTHENTICATED_TAG: &str = "authenticated";
     const X_TRUST_BOUNDARY_TAG: &str = "x-trust-boundary";
     const DATA_TAG: &str = "data";
-    #[versioned("1.4", "1.5")]
+    #[versioned("1.4", "1.5", "1.6")]
+    const RELEASE_NOTES_TAG: &str = "releaseNotes";
+    #[versioned("1.4", "1.5", "1.6")]
     const SIGNATURE_TAG: &str = "signature";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const TRUST_ZONE_TAG: &str = "trustZone";
 
     impl ToXml for Service {
@@ -331,12 +348,17 @@ pub(crate) mod base {
                 services.write_xml_element(writer)?;
             }
 
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
+            if let Some(release_notes) = &self.release_notes {
+                release_notes.write_xml_element(writer)?;
+            }
+
+            #[versioned("1.4", "1.5", "1.6")]
             if let Some(signature) = &self.signature {
                 signature.write_xml_element(writer)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             if let Some(trust_zone) = &self.trust_zone {
                 write_simple_tag(writer, TRUST_ZONE_TAG, trust_zone)?;
             }
@@ -355,7 +377,7 @@ pub(crate) mod base {
 
     impl FromXml for Service {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -377,9 +399,11 @@ pub(crate) mod base {
             let mut external_references: Option<ExternalReferences> = None;
             let mut properties: Option<Properties> = None;
             let mut services: Option<Services> = None;
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
+            let mut release_notes: Option<ReleaseNotes> = None;
+            #[versioned("1.4", "1.5", "1.6")]
             let mut signature: Option<Signature> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut trust_zone: Option<String> = None;
 
             let mut got_end_tag = false;
@@ -481,7 +505,17 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.4", "1.5")]
+                    #[versioned("1.4", "1.5", "1.6")]
+                    reader::XmlEvent::StartElement {
+                        name, attributes, ..
+                    } if name.local_name == RELEASE_NOTES_TAG => {
+                        release_notes = Some(ReleaseNotes::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?)
+                    }
+                    #[versioned("1.4", "1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == SIGNATURE_TAG => {
@@ -491,7 +525,7 @@ pub(crate) mod base {
                             &attributes,
                         )?)
                     }
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement { name, .. }
                         if name.local_name == TRUST_ZONE_TAG =>
                     {
@@ -505,7 +539,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
@@ -529,24 +563,58 @@ pub(crate) mod base {
                 external_references,
                 properties,
                 services,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes,
+                #[versioned("1.4", "1.5", "1.6")]
                 signature,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 trust_zone,
             })
         }
     }
 
-    #[versioned("1.3", "1.4", "1.5")]
+    #[versioned("1.3", "1.4", "1.5", "1.6")]
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
     #[serde(rename_all = "camelCase", untagged)]
     pub(crate) enum Data {
         /// Legacy entry type until version 1.4
+        #[versioned("1.3", "1.4")]
         Classification(Vec<DataClassification>),
-        #[versioned("1.5")]
+        /// Legacy entry type until version 1.4, only read from JSON when the entries have no
+        /// other fields than the ones of [`DataClassification`]
+        #[versioned("1.5", "1.6")]
+        #[serde(deserialize_with = "deserialize_legacy_classifications")]
+        Classification(Vec<DataClassification>),
+        #[versioned("1.5", "1.6")]
         ServiceData(Vec<ServiceData>),
     }
 
+    /// The fields of [`DataClassification`] are part of [`ServiceData`] as well, so reading all
+    /// entries that have them as legacy entries drops the other fields of service data.
+    #[versioned("1.5", "1.6")]
+    fn deserialize_legacy_classifications<'de, D>(
+        deserializer: D,
+    ) -> Result<Vec<DataClassification>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct LegacyDataClassification {
+            flow: String,
+            classification: String,
+        }
+
+        let classifications = Vec::<LegacyDataClassification>::deserialize(deserializer)?;
+        Ok(classifications
+            .into_iter()
+            .map(|legacy| DataClassification {
+                flow: legacy.flow,
+                classification: legacy.classification,
+            })
+            .collect())
+    }
+
     impl From<models::service::Data> for Data {
         fn from(other: models::service::Data) -> Self {
             match other {
@@ -559,7 +627,7 @@ pub(crate) mod base {
                         data.into_iter().map(|d| d.classification.into()).collect();
                     Self::Classification(classifications)
                 }
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 models::service::Data::ServiceData(data) => Self::ServiceData(convert_vec(data)),
             }
         }
@@ -571,19 +639,19 @@ pub(crate) mod base {
                 Data::Classification(classification) => {
                     Self::Classification(convert_vec(classification))
                 }
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 Data::ServiceData(data) => Self::ServiceData(convert_vec(data)),
             }
         }
     }
 
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const DATAFLOW_TAG: &str = "dataflow";
 
     #[versioned("1.3", "1.4")]
     impl FromXml for Data {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -612,6 +680,9 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
+                    reader::XmlEvent::StartElement { name, .. } => {
+                        read_lax_validation_tag(event_reader, &name)?
+                    }
                     _ => (),
                 }
             }
@@ -620,10 +691,10 @@ pub(crate) mod base {
         }
     }
 
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     impl FromXml for Data {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -663,6 +734,9 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
+                    reader::XmlEvent::StartElement { name, .. } => {
+                        read_lax_validation_tag(event_reader, &name)?
+                    }
                     _ => (),
                 }
             }
@@ -687,7 +761,7 @@ pub(crate) mod base {
                     }
                     Ok(())
                 }
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 Self::ServiceData(services) => {
                     for service in services {
                         service.write_xml_element(writer)?;
@@ -757,7 +831,7 @@ pub(crate) mod base {
 
     impl FromXml for DataClassification {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -779,8 +853,11 @@ pub(crate) mod base {
         use super::*;
         use pretty_assertions::assert_eq;
 
-        #[versioned("1.4", "1.5")]
-        use crate::specs::common::signature::test::{corresponding_signature, example_signature};
+        #[versioned("1.4", "1.5", "1.6")]
+        use crate::specs::common::{
+            release_notes::test::{corresponding_release_notes, example_release_notes},
+            signature::test::{corresponding_signature, example_signature},
+        };
         #[versioned("1.3")]
         use crate::specs::v1_3::{
             external_reference::test::{
@@ -795,6 +872,8 @@ pub(crate) mod base {
             },
             license::test::{corresponding_licenses, example_licenses},
         };
+        #[versioned("1.6")]
+        use crate::specs::v1_5::data_governance::{DataGovernance, DataGovernanceResponsibleParty};
         #[versioned("1.5")]
         use crate::specs::v1_5::{
             data_governance::{DataGovernance, DataGovernanceResponsibleParty},
@@ -803,6 +882,13 @@ pub(crate) mod base {
             },
             license::test::{corresponding_licenses, example_licenses},
         };
+        #[versioned("1.6")]
+        use crate::specs::v1_6::{
+            external_reference::test::{
+                corresponding_external_references, example_external_references,
+            },
+            license::test::{corresponding_licenses, example_licenses},
+        };
         use crate::{
             specs::common::{
                 organization::test::{corresponding_entity, example_entity},
@@ -835,9 +921,11 @@ pub(crate) mod base {
                 external_references: Some(example_external_references()),
                 properties: Some(example_properties()),
                 services: Some(Services(vec![])),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: Some(example_release_notes()),
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: Some(example_signature()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 trust_zone: Some("trust zone".to_string()),
             }
         }
@@ -859,12 +947,16 @@ pub(crate) mod base {
                 properties: Some(corresponding_properties()),
                 services: Some(models::service::Services(vec![])),
                 #[versioned("1.3")]
+                release_notes: None,
+                #[versioned("1.4", "1.5", "1.6")]
+                release_notes: Some(corresponding_release_notes()),
+                #[versioned("1.3")]
                 signature: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 signature: Some(corresponding_signature()),
                 #[versioned("1.3", "1.4")]
                 trust_zone: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 trust_zone: Some("trust zone".into()),
             }
         }
@@ -877,12 +969,12 @@ pub(crate) mod base {
             }])
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         fn example_data_classification() -> Data {
             Data::ServiceData(vec![ServiceData {
                 name: Some("Consumer to Stock Service".to_string()),
                 description: Some("Traffic to/from consumer to service".to_string()),
-                classification: DataClassification {
+                classification: crate::specs::v1_5::service::DataClassification {
                     flow: "flow".to_string(),
                     classification: "classification".to_string(),
                 },
@@ -911,7 +1003,7 @@ pub(crate) mod base {
             }])
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         fn corresponding_data_classification() -> models::service::Data {
             models::service::Data::ServiceData(vec![models::service::ServiceData {
                 name: Some(NormalizedString::new_unchecked(
@@ -945,6 +1037,36 @@ pub(crate) mod base {
             }])
         }
 
+        #[versioned("1.5", "1.6")]
+        #[test]
+        fn it_should_read_json_service_data_apart_from_legacy_classifications() {
+            let legacy: Data =
+                serde_json::from_str(r#"[{"flow": "inbound", "classification": "PII"}]"#)
+                    .expect("Failed to read legacy classifications");
+            let service_data: Data = serde_json::from_str(
+                r#"[{"name": "Customer data", "flow": "inbound", "classification": "PII"}]"#,
+            )
+            .expect("Failed to read service data");
+
+            assert_eq!(
+                legacy,
+                Data::Classification(vec![DataClassification::new("inbound", "PII")])
+            );
+            assert_eq!(
+                service_data,
+                Data::ServiceData(vec![ServiceData {
+                    name: Some("Customer data".to_string()),
+                    description: None,
+                    classification: crate::specs::v1_5::service::DataClassification::new(
+                        "inbound", "PII",
+                    ),
+                    governance: None,
+                    source: None,
+                    destination: None,
+                }])
+            );
+        }
+
         #[test]
         fn it_should_write_xml_full() {
             // NOTE: this only tests version 1.3 currently
@@ -1060,6 +1182,43 @@ pub(crate) mod base {
       <property name="name">value</property>
     </properties>
     <services />
+    <releaseNotes>
+      <type>type</type>
+      <title>title</title>
+      <featuredImage>featured image</featuredImage>
+      <socialImage>social image</socialImage>
+      <description>description</description>
+      <timestamp>timestamp</timestamp>
+      <aliases>
+        <alias>alias</alias>
+      </aliases>
+      <tags>
+        <tag>tag</tag>
+      </tags>
+      <resolves>
+        <issue type="issue type">
+          <id>id</id>
+          <name>name</name>
+          <description>description</description>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <references>
+            <url>reference</url>
+          </references>
+        </issue>
+      </resolves>
+      <notes>
+        <note>
+          <locale>locale</locale>
+          <text content-type="content type" encoding="encoding">content</text>
+        </note>
+      </notes>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </releaseNotes>
     <signature>
       <algorithm>HS512</algorithm>
      <value>1234567890</value>
@@ -1068,7 +1227,7 @@ pub(crate) mod base {
   </service>
 </services>
 "#;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let input = r#"
 <services>
   <service bom-ref="bom-ref">
@@ -1126,6 +1285,43 @@ pub(crate) mod base {
       <property name="name">value</property>
     </properties>
     <services />
+    <releaseNotes>
+      <type>type</type>
+      <title>title</title>
+      <featuredImage>featured image</featuredImage>
+      <socialImage>social image</socialImage>
+      <description>description</description>
+      <timestamp>timestamp</timestamp>
+      <aliases>
+        <alias>alias</alias>
+      </aliases>
+      <tags>
+        <tag>tag</tag>
+      </tags>
+      <resolves>
+        <issue type="issue type">
+          <id>id</id>
+          <name>name</name>
+          <description>description</description>
+          <source>
+            <name>name</name>
+            <url>url</url>
+          </source>
+          <references>
+            <url>reference</url>
+          </references>
+        </issue>
+      </resolves>
+      <notes>
+        <note>
+          <locale>locale</locale>
+          <text content-type="content type" encoding="encoding">content</text>
+        </note>
+      </notes>
+      <properties>
+        <property name="name">value</property>
+      </properties>
+    </releaseNotes>
     <signature>
       <algorithm>HS512</algorithm>
      <value>1234567890</value>
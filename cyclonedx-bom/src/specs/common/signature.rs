@@ -23,7 +23,7 @@ use crate::{
     errors::XmlReadError,
     models,
     xml::{
-        read_list_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
+        read_list_tag, read_simple_tag, skip_unexpected_element, to_xml_read_error,
         write_close_tag, write_simple_tag, write_start_tag, FromXml, ToXml,
     },
 };
@@ -60,7 +60,7 @@ impl ToXml for Signer {
 
 impl FromXml for Signer {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -86,9 +86,7 @@ impl FromXml for Signer {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => {
-                    return Err(unexpected_element_error(element_name, unexpected));
-                }
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -110,13 +108,14 @@ impl FromXml for Signer {
 
 /// Enveloped signature in [JSON Signature Format (JSF)](https://cyberphone.github.io/doc/security/jsf.html)
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-#[serde(rename_all = "camelCase", untagged)]
+#[serde(rename_all = "camelCase")]
 pub enum Signature {
     /// Multiple signatures
     Signers(Vec<Signer>),
     /// A single signature chain
     Chain(Vec<Signer>),
     /// A single signature
+    #[serde(untagged)]
     Single(Signer),
 }
 
@@ -292,7 +291,7 @@ const VALUE_TAG: &str = "value";
 
 impl FromXml for Signature {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -360,8 +359,8 @@ pub(crate) mod test {
     use xml::{name::OwnedName, EmitterConfig, EventReader, EventWriter, ParserConfig};
 
     use crate::{
-        models,
-        xml::{test::read_element_from_string, FromXml, ToXml},
+        models::{self, bom::XmlReadOptions},
+        xml::{test::read_element_from_string, FromXml, ToXml, XmlReader},
     };
 
     use super::Signature;
@@ -384,7 +383,8 @@ pub(crate) mod test {
     fn assert_invalid_signature(input: &str) {
         let reader = input.to_string();
         let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader.as_bytes(), config);
+        let events = EventReader::new_with_config(reader.as_bytes(), config);
+        let mut event_reader = XmlReader::new(events, XmlReadOptions::default());
 
         let element_name = OwnedName::local("signature");
         let actual = Signature::read_xml_element(&mut event_reader, &element_name, &[]);
@@ -563,4 +563,35 @@ pub(crate) mod test {
         let actual: Signature = read_element_from_string(input);
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_write_jsf_members_for_signers_and_chains_in_json() {
+        let signers = Signature::signers(&[("ES256", "abcdefgh")]);
+        let chain = Signature::chain(&[("ES256", "abcdefgh")]);
+
+        assert_eq!(
+            serde_json::to_string(&signers).expect("Failed to serialize signers"),
+            r#"{"signers":[{"algorithm":"ES256","value":"abcdefgh"}]}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&chain).expect("Failed to serialize chain"),
+            r#"{"chain":[{"algorithm":"ES256","value":"abcdefgh"}]}"#
+        );
+    }
+
+    #[test]
+    fn it_should_read_signers_chains_and_single_signatures_from_json() {
+        let signers: Signature =
+            serde_json::from_str(r#"{"signers":[{"algorithm":"ES256","value":"abcdefgh"}]}"#)
+                .expect("Failed to deserialize signers");
+        let chain: Signature =
+            serde_json::from_str(r#"{"chain":[{"algorithm":"ES256","value":"abcdefgh"}]}"#)
+                .expect("Failed to deserialize chain");
+        let single: Signature = serde_json::from_str(r#"{"algorithm":"ES256","value":"abcdefgh"}"#)
+            .expect("Failed to deserialize single signature");
+
+        assert_eq!(signers, Signature::signers(&[("ES256", "abcdefgh")]));
+        assert_eq!(chain, Signature::chain(&[("ES256", "abcdefgh")]));
+        assert_eq!(single, Signature::single("ES256", "abcdefgh"));
+    }
 }
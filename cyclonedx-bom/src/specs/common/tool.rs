@@ -18,7 +18,7 @@
 
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.3", "1.4", "1.5")]
+#[versioned("1.3", "1.4", "1.5", "1.6")]
 pub(crate) mod base {
     #[versioned("1.4")]
     use crate::specs::v1_4::external_reference::ExternalReferences;
@@ -26,6 +26,10 @@ pub(crate) mod base {
     use crate::specs::v1_5::{
         component::Components, external_reference::ExternalReferences, service::Services,
     };
+    #[versioned("1.6")]
+    use crate::specs::v1_6::{
+        component::Components, external_reference::ExternalReferences, service::Services,
+    };
 
     use crate::models;
     use crate::{
@@ -34,8 +38,8 @@ pub(crate) mod base {
         specs::common::hash::Hashes,
         utilities::{convert_optional, convert_vec},
         xml::{
-            read_lax_validation_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
-            unexpected_element_error, write_simple_tag, FromXml, ToXml,
+            read_lax_validation_tag, read_simple_tag, skip_unexpected_element, to_xml_read_error,
+            to_xml_write_error, write_simple_tag, FromXml, ToXml,
         },
     };
     use serde::{Deserialize, Serialize};
@@ -47,7 +51,7 @@ pub(crate) mod base {
         /// Legacy version: https://cyclonedx.org/docs/1.4/json/#metadata_tools
         List(Vec<Tool>),
         /// Added in 1.5, see https://cyclonedx.org/docs/1.5/json/#metadata_tools
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         Object {
             #[serde(skip_serializing_if = "Option::is_none")]
             services: Option<Services>,
@@ -64,7 +68,7 @@ pub(crate) mod base {
                 models::tool::Tools::List(tools) => Ok(Self::List(convert_vec(tools))),
                 #[versioned("1.3", "1.4")]
                 models::tool::Tools::Object { .. } => Ok(Self::List(vec![])),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 models::tool::Tools::Object {
                     services,
                     components,
@@ -80,7 +84,7 @@ pub(crate) mod base {
         fn from(other: Tools) -> Self {
             match other {
                 Tools::List(tools) => models::tool::Tools::List(convert_vec(tools)),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 Tools::Object {
                     services,
                     components,
@@ -93,9 +97,9 @@ pub(crate) mod base {
     }
 
     const TOOLS_TAG: &str = "tools";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const COMPONENTS_TAG: &str = "components";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const SERVICES_TAG: &str = "services";
 
     impl ToXml for Tools {
@@ -113,7 +117,7 @@ pub(crate) mod base {
                         tool.write_xml_element(writer)?;
                     }
                 }
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 Tools::Object {
                     services,
                     components,
@@ -132,7 +136,7 @@ pub(crate) mod base {
 
     impl FromXml for Tools {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -141,9 +145,9 @@ pub(crate) mod base {
         {
             let mut tools: Option<Vec<Tool>> = None;
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut components: Option<Components> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut services: Option<Services> = None;
 
             let mut got_end_tag = false;
@@ -161,7 +165,7 @@ pub(crate) mod base {
                         )?);
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == COMPONENTS_TAG => {
@@ -172,7 +176,7 @@ pub(crate) mod base {
                         )?);
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == SERVICES_TAG => {
@@ -186,15 +190,13 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => {
-                        return Err(unexpected_element_error(element_name, unexpected));
-                    }
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
             match tools {
                 Some(tools) => {
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     if components.is_some() || services.is_some() {
                         return Err(XmlReadError::RequiredDataMissing {
                             required_field: "tool array or services & components".to_string(),
@@ -206,7 +208,7 @@ pub(crate) mod base {
                 }
                 #[versioned("1.3", "1.4")]
                 None => Ok(Self::List(vec![])),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 None => Ok(Self::Object {
                     services,
                     components,
@@ -226,7 +228,7 @@ pub(crate) mod base {
         version: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         hashes: Option<Hashes>,
-        #[versioned("1.4", "1.5")]
+        #[versioned("1.4", "1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         external_references: Option<ExternalReferences>,
     }
@@ -238,7 +240,7 @@ pub(crate) mod base {
                 name: other.name.map(|n| n.to_string()),
                 version: other.version.map(|v| v.to_string()),
                 hashes: convert_optional(other.hashes),
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 external_references: convert_optional(other.external_references),
             }
         }
@@ -253,7 +255,7 @@ pub(crate) mod base {
                 hashes: convert_optional(other.hashes),
                 #[versioned("1.3")]
                 external_references: None,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 external_references: convert_optional(other.external_references),
             }
         }
@@ -291,7 +293,7 @@ pub(crate) mod base {
                 }
             }
 
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
             if let Some(external_references) = &self.external_references {
                 external_references.write_xml_element(writer)?;
             }
@@ -304,6 +306,11 @@ pub(crate) mod base {
         }
 
         fn will_write(&self) -> bool {
+            #[versioned("1.4", "1.5", "1.6")]
+            if self.external_references.is_some() {
+                return true;
+            }
+
             self.vendor.is_some()
                 || self.name.is_some()
                 || self.version.is_some()
@@ -312,12 +319,12 @@ pub(crate) mod base {
     }
 
     const HASHES_TAG: &str = "hashes";
-    #[versioned("1.4", "1.5")]
+    #[versioned("1.4", "1.5", "1.6")]
     const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
 
     impl FromXml for Tool {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -328,7 +335,7 @@ pub(crate) mod base {
             let mut tool_name: Option<String> = None;
             let mut version: Option<String> = None;
             let mut hashes: Option<Hashes> = None;
-            #[versioned("1.4", "1.5")]
+            #[versioned("1.4", "1.5", "1.6")]
             let mut external_references: Option<ExternalReferences> = None;
 
             let mut got_end_tag = false;
@@ -353,7 +360,7 @@ pub(crate) mod base {
                     } if name.local_name == HASHES_TAG => {
                         hashes = Some(Hashes::read_xml_element(event_reader, &name, &attributes)?)
                     }
-                    #[versioned("1.4", "1.5")]
+                    #[versioned("1.4", "1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == EXTERNAL_REFERENCES_TAG => {
@@ -370,7 +377,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
@@ -379,7 +386,7 @@ pub(crate) mod base {
                 name: tool_name,
                 version,
                 hashes,
-                #[versioned("1.4", "1.5")]
+                #[versioned("1.4", "1.5", "1.6")]
                 external_references,
             })
         }
@@ -405,6 +412,20 @@ pub(crate) mod base {
                 service::{Service, Services},
             },
         };
+        #[versioned("1.6")]
+        use crate::specs::{
+            common::{
+                external_reference::v1_6::test::{
+                    corresponding_external_references, example_external_references,
+                },
+                hash::{Hash, HashValue},
+                organization::OrganizationalEntity,
+            },
+            v1_6::{
+                component::{Component, Components},
+                service::{Service, Services},
+            },
+        };
 
         use crate::{
             specs::common::hash::test::{corresponding_hashes, example_hashes},
@@ -443,7 +464,7 @@ pub(crate) mod base {
             }
         }
 
-        #[versioned("1.4", "1.5")]
+        #[versioned("1.4", "1.5", "1.6")]
         pub(crate) fn example_tool() -> Tool {
             Tool {
                 vendor: Some("vendor".to_string()),
@@ -454,7 +475,7 @@ pub(crate) mod base {
             }
         }
 
-        #[versioned("1.4", "1.5")]
+        #[versioned("1.4", "1.5", "1.6")]
         pub(crate) fn corresponding_tool() -> models::tool::Tool {
             models::tool::Tool {
                 vendor: Some(NormalizedString::new_unchecked("vendor".to_string())),
@@ -500,7 +521,7 @@ pub(crate) mod base {
         }
 
         #[test]
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         fn it_should_read_xml_with_services_and_components() {
             let input = r#"
 <tools>
@@ -548,6 +569,7 @@ pub(crate) mod base {
                 external_references: None,
                 properties: None,
                 services: None,
+                release_notes: None,
                 signature: None,
                 trust_zone: None,
             };
@@ -556,6 +578,8 @@ pub(crate) mod base {
                 mime_type: None,
                 bom_ref: None,
                 supplier: None,
+                #[versioned("1.6")]
+                authors: None,
                 author: None,
                 publisher: None,
                 group: Some("Awesome Vendor".to_string()),
@@ -578,9 +602,12 @@ pub(crate) mod base {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
                 signature: None,
                 model_card: None,
                 data: None,
+                #[versioned("1.6")]
+                crypto_properties: None,
             };
             let expected = Tools::Object {
                 services: Some(Services(vec![service])),
@@ -18,7 +18,7 @@
 
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.4", "1.5")]
+#[versioned("1.4", "1.5", "1.6")]
 pub(crate) mod base {
 
     use crate::{
@@ -31,8 +31,8 @@ pub(crate) mod base {
         },
         xml::{
             optional_attribute, read_lax_validation_list_tag, read_lax_validation_tag,
-            read_list_tag, read_optional_tag, read_simple_tag, to_xml_read_error,
-            to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
+            read_list_tag, read_optional_tag, read_simple_tag, skip_unexpected_element,
+            to_xml_read_error, to_xml_write_error, write_close_tag, write_simple_tag,
             write_start_tag, FromXml, ToXml,
         },
     };
@@ -52,6 +52,11 @@ pub(crate) mod base {
         proof_of_concept::ProofOfConcept, tool::Tools,
         vulnerability_analysis::VulnerabilityAnalysis,
     };
+    #[versioned("1.6")]
+    use crate::specs::v1_6::{
+        proof_of_concept::ProofOfConcept, tool::Tools,
+        vulnerability_analysis::VulnerabilityAnalysis,
+    };
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
     #[serde(transparent)]
@@ -92,7 +97,7 @@ pub(crate) mod base {
 
     impl FromXml for Vulnerabilities {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -125,10 +130,10 @@ pub(crate) mod base {
         detail: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         recommendation: Option<String>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         workaround: Option<String>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         proof_of_concept: Option<ProofOfConcept>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -139,7 +144,7 @@ pub(crate) mod base {
         published: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         updated: Option<String>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         rejected: Option<String>,
         #[serde(rename = "credits", skip_serializing_if = "Option::is_none")]
@@ -168,15 +173,15 @@ pub(crate) mod base {
                 description: other.description,
                 detail: other.detail,
                 recommendation: other.recommendation,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 workaround: convert_optional(other.workaround),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 proof_of_concept: convert_optional(other.proof_of_concept),
                 advisories: convert_optional(other.advisories),
                 created: other.created.map(|c| c.to_string()),
                 published: other.published.map(|p| p.to_string()),
                 updated: other.updated.map(|u| u.to_string()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 rejected: other.rejected.map(|r| r.to_string()),
                 vulnerability_credits: convert_optional(other.vulnerability_credits),
                 tools: try_convert_optional(other.tools)?,
@@ -201,11 +206,11 @@ pub(crate) mod base {
                 recommendation: other.recommendation,
                 #[versioned("1.4")]
                 workaround: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 workaround: convert_optional(other.workaround),
                 #[versioned("1.4")]
                 proof_of_concept: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 proof_of_concept: convert_optional(other.proof_of_concept),
                 advisories: convert_optional(other.advisories),
                 created: other.created.map(DateTime),
@@ -213,7 +218,7 @@ pub(crate) mod base {
                 updated: other.updated.map(DateTime),
                 #[versioned("1.4")]
                 rejected: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 rejected: other.rejected.map(DateTime),
                 vulnerability_credits: convert_optional(other.vulnerability_credits),
                 tools: convert_optional(other.tools),
@@ -239,16 +244,16 @@ pub(crate) mod base {
     const CREATED_TAG: &str = "created";
     const PUBLISHED_TAG: &str = "published";
     const UPDATED_TAG: &str = "updated";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const REJECTED_TAG: &str = "rejected";
     const VULNERABILITY_CREDITS_TAG: &str = "credits";
     const TOOLS_TAG: &str = "tools";
     const VULNERABILITY_ANALYSIS_TAG: &str = "analysis";
     const VULNERABILITY_TARGETS_TAG: &str = "affects";
     const PROPERTIES_TAG: &str = "properties";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const WORKAROUND_TAG: &str = "workaround";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const PROOF_OF_CONCEPT_TAG: &str = "proofOfConcept";
 
     impl ToXml for Vulnerability {
@@ -306,6 +311,16 @@ pub(crate) mod base {
                 write_simple_tag(writer, RECOMMENDATION_TAG, recommendation)?;
             }
 
+            #[versioned("1.5", "1.6")]
+            if let Some(workaround) = &self.workaround {
+                write_simple_tag(writer, WORKAROUND_TAG, workaround)?;
+            }
+
+            #[versioned("1.5", "1.6")]
+            if let Some(proof_of_concept) = &self.proof_of_concept {
+                proof_of_concept.write_xml_element(writer)?;
+            }
+
             if let Some(advisories) = &self.advisories {
                 advisories.write_xml_element(writer)?;
             }
@@ -322,7 +337,7 @@ pub(crate) mod base {
                 write_simple_tag(writer, UPDATED_TAG, updated)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             if let Some(rejected) = &self.rejected {
                 write_simple_tag(writer, REJECTED_TAG, rejected)?;
             }
@@ -347,11 +362,6 @@ pub(crate) mod base {
                 properties.write_xml_element(writer)?;
             }
 
-            #[versioned("1.5")]
-            if let Some(workaround) = &self.workaround {
-                write_simple_tag(writer, WORKAROUND_TAG, workaround)?;
-            }
-
             writer
                 .write(XmlEvent::end_element())
                 .map_err(to_xml_write_error(VULNERABILITY_TAG))?;
@@ -362,7 +372,7 @@ pub(crate) mod base {
 
     impl FromXml for Vulnerability {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -383,16 +393,16 @@ pub(crate) mod base {
             let mut created: Option<String> = None;
             let mut published: Option<String> = None;
             let mut updated: Option<String> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut rejected: Option<String> = None;
             let mut vulnerability_credits: Option<VulnerabilityCredits> = None;
             let mut tools: Option<Tools> = None;
             let mut vulnerability_analysis: Option<VulnerabilityAnalysis> = None;
             let mut vulnerability_targets: Option<VulnerabilityTargets> = None;
             let mut properties: Option<Properties> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut workaround: Option<String> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut proof_of_concept: Option<ProofOfConcept> = None;
 
             let mut got_end_tag = false;
@@ -485,7 +495,7 @@ pub(crate) mod base {
                         updated = Some(read_simple_tag(event_reader, &name)?);
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement { name, .. }
                         if name.local_name == REJECTED_TAG =>
                     {
@@ -538,14 +548,14 @@ pub(crate) mod base {
                         )?)
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement { name, .. }
                         if name.local_name == WORKAROUND_TAG =>
                     {
                         workaround = Some(read_simple_tag(event_reader, &name)?);
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement {
                         name, attributes, ..
                     } if name.local_name == PROOF_OF_CONCEPT_TAG => {
@@ -563,7 +573,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
@@ -577,15 +587,15 @@ pub(crate) mod base {
                 description,
                 detail,
                 recommendation,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 workaround,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 proof_of_concept,
                 advisories,
                 created,
                 published,
                 updated,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 rejected,
                 vulnerability_credits,
                 tools,
@@ -616,6 +626,14 @@ pub(crate) mod base {
                 corresponding_vulnerability_analysis, example_vulnerability_analysis,
             },
         };
+        #[versioned("1.6")]
+        use crate::specs::v1_6::{
+            proof_of_concept::test::{corresponding_proof_of_concept, example_proof_of_concept},
+            tool::test::{corresponding_tools, example_tools},
+            vulnerability_analysis::test::{
+                corresponding_vulnerability_analysis, example_vulnerability_analysis,
+            },
+        };
         use crate::{
             specs::common::{
                 advisory::test::{corresponding_advisories, example_advisories},
@@ -671,7 +689,7 @@ pub(crate) mod base {
             }
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         pub(crate) fn example_vulnerability() -> Vulnerability {
             Vulnerability {
                 bom_ref: Some("bom-ref".to_string()),
@@ -725,7 +743,7 @@ pub(crate) mod base {
             }
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         pub(crate) fn corresponding_vulnerability() -> models::vulnerability::Vulnerability {
             models::vulnerability::Vulnerability {
                 bom_ref: Some("bom-ref".to_string()),
@@ -881,7 +899,7 @@ pub(crate) mod base {
             assert_eq!(actual, expected);
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[test]
         fn it_should_read_xml_full() {
             let input = r#"
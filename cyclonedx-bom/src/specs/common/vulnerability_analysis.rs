@@ -18,9 +18,9 @@
 
 use cyclonedx_bom_macros::versioned;
 
-#[versioned("1.4", "1.5")]
+#[versioned("1.4", "1.5", "1.6")]
 pub(crate) mod base {
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     use crate::external_models::date_time::DateTime;
     use crate::utilities::convert_optional_vec;
     use crate::xml::{write_close_tag, write_start_tag};
@@ -29,8 +29,8 @@ pub(crate) mod base {
         models,
         utilities::convert_optional,
         xml::{
-            read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-            unexpected_element_error, write_simple_tag, FromXml, ToXml,
+            read_lax_validation_tag, read_list_tag, read_simple_tag, skip_unexpected_element,
+            to_xml_read_error, write_simple_tag, FromXml, ToXml,
         },
     };
     use serde::{Deserialize, Serialize};
@@ -47,10 +47,10 @@ pub(crate) mod base {
         responses: Option<Vec<ImpactAnalysisResponse>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         detail: Option<String>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         first_issued: Option<String>,
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         #[serde(skip_serializing_if = "Option::is_none")]
         last_updated: Option<String>,
     }
@@ -62,9 +62,9 @@ pub(crate) mod base {
                 justification: convert_optional(other.justification),
                 responses: convert_optional_vec(other.responses),
                 detail: other.detail,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 first_issued: other.first_issued.map(|d| d.to_string()),
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 last_updated: other.last_updated.map(|d| d.to_string()),
             }
         }
@@ -79,11 +79,11 @@ pub(crate) mod base {
                 detail: other.detail,
                 #[versioned("1.4")]
                 first_issued: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 first_issued: other.first_issued.map(DateTime),
                 #[versioned("1.4")]
                 last_updated: None,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 last_updated: other.last_updated.map(DateTime),
             }
         }
@@ -95,9 +95,9 @@ pub(crate) mod base {
     const RESPONSES_TAG: &str = "responses";
     const RESPONSE_TAG: &str = "response";
     const DETAIL_TAG: &str = "detail";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const FIRST_ISSUED_TAG: &str = "firstIssued";
-    #[versioned("1.5")]
+    #[versioned("1.5", "1.6")]
     const LAST_UPDATED_TAG: &str = "lastUpdated";
 
     impl ToXml for VulnerabilityAnalysis {
@@ -129,12 +129,12 @@ pub(crate) mod base {
                 write_simple_tag(writer, DETAIL_TAG, detail)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             if let Some(first_issued) = &self.first_issued {
                 write_simple_tag(writer, FIRST_ISSUED_TAG, first_issued)?;
             }
 
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             if let Some(last_updated) = &self.last_updated {
                 write_simple_tag(writer, LAST_UPDATED_TAG, last_updated)?;
             }
@@ -147,7 +147,7 @@ pub(crate) mod base {
 
     impl FromXml for VulnerabilityAnalysis {
         fn read_xml_element<R: std::io::Read>(
-            event_reader: &mut xml::EventReader<R>,
+            event_reader: &mut crate::xml::XmlReader<R>,
             element_name: &xml::name::OwnedName,
             _attributes: &[xml::attribute::OwnedAttribute],
         ) -> Result<Self, XmlReadError>
@@ -158,9 +158,9 @@ pub(crate) mod base {
             let mut justification: Option<ImpactAnalysisJustification> = None;
             let mut responses: Option<Vec<ImpactAnalysisResponse>> = None;
             let mut detail: Option<String> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut first_issued: Option<String> = None;
-            #[versioned("1.5")]
+            #[versioned("1.5", "1.6")]
             let mut last_updated: Option<String> = None;
 
             let mut got_end_tag = false;
@@ -199,14 +199,14 @@ pub(crate) mod base {
                         detail = Some(read_simple_tag(event_reader, &name)?)
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement { name, .. }
                         if name.local_name == FIRST_ISSUED_TAG =>
                     {
                         first_issued = Some(read_simple_tag(event_reader, &name)?)
                     }
 
-                    #[versioned("1.5")]
+                    #[versioned("1.5", "1.6")]
                     reader::XmlEvent::StartElement { name, .. }
                         if name.local_name == LAST_UPDATED_TAG =>
                     {
@@ -220,7 +220,7 @@ pub(crate) mod base {
                     reader::XmlEvent::EndElement { name } if &name == element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                    unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
                 }
             }
 
@@ -229,9 +229,9 @@ pub(crate) mod base {
                 justification,
                 responses,
                 detail,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 first_issued,
-                #[versioned("1.5")]
+                #[versioned("1.5", "1.6")]
                 last_updated,
             })
         }
@@ -291,7 +291,7 @@ pub(crate) mod base {
         use super::*;
         use pretty_assertions::assert_eq;
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         use crate::external_models::date_time::DateTime;
         use crate::xml::test::{read_element_from_string, write_element_to_string};
 
@@ -307,7 +307,7 @@ pub(crate) mod base {
             }
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         pub(crate) fn example_vulnerability_analysis() -> VulnerabilityAnalysis {
             VulnerabilityAnalysis {
                 state: Some(ImpactAnalysisState("not_affected".to_string())),
@@ -338,7 +338,7 @@ pub(crate) mod base {
             }
         }
 
-        #[versioned("1.5")]
+        #[versioned("1.5", "1.6")]
         pub(crate) fn corresponding_vulnerability_analysis(
         ) -> models::vulnerability_analysis::VulnerabilityAnalysis {
             models::vulnerability_analysis::VulnerabilityAnalysis {
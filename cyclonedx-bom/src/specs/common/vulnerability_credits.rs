@@ -21,7 +21,7 @@ use crate::{
     models,
     utilities::convert_optional_vec,
     xml::{
-        read_lax_validation_tag, read_list_tag, to_xml_read_error, unexpected_element_error,
+        read_lax_validation_tag, read_list_tag, skip_unexpected_element, to_xml_read_error,
         write_close_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
@@ -102,7 +102,7 @@ impl ToXml for VulnerabilityCredits {
 
 impl FromXml for VulnerabilityCredits {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -137,7 +137,7 @@ impl FromXml for VulnerabilityCredits {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -23,9 +23,9 @@ use crate::{
     specs::common::vulnerability_source::VulnerabilitySource,
     utilities::convert_vec,
     xml::{
-        read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
-        to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
-        write_start_tag, FromXml, ToXml,
+        read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag,
+        skip_unexpected_element, to_xml_read_error, to_xml_write_error, write_close_tag,
+        write_simple_tag, write_start_tag, FromXml, ToXml,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -68,7 +68,7 @@ impl ToXml for VulnerabilityReferences {
 
 impl FromXml for VulnerabilityReferences {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -139,7 +139,7 @@ impl ToXml for VulnerabilityReference {
 
 impl FromXml for VulnerabilityReference {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -176,7 +176,7 @@ impl FromXml for VulnerabilityReference {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
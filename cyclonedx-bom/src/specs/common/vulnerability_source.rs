@@ -21,8 +21,8 @@ use crate::{
     external_models::{normalized_string::NormalizedString, uri::Uri},
     models,
     xml::{
-        read_lax_validation_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
-        unexpected_element_error, write_simple_tag, FromXml, ToXml,
+        read_lax_validation_tag, read_simple_tag, skip_unexpected_element, to_xml_read_error,
+        to_xml_write_error, write_simple_tag, FromXml, ToXml,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -88,7 +88,7 @@ impl ToXml for VulnerabilitySource {
 
 impl FromXml for VulnerabilitySource {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -119,7 +119,7 @@ impl FromXml for VulnerabilitySource {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -22,9 +22,9 @@ use crate::{
     models,
     utilities::{convert_optional, convert_vec},
     xml::{
-        read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
-        unexpected_element_error, write_close_tag, write_simple_tag, write_start_tag, FromXml,
-        ToXml,
+        read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag,
+        skip_unexpected_element, to_xml_read_error, write_close_tag, write_simple_tag,
+        write_start_tag, FromXml, ToXml,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -67,7 +67,7 @@ impl ToXml for VulnerabilityTargets {
 
 impl FromXml for VulnerabilityTargets {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -138,7 +138,7 @@ impl ToXml for VulnerabilityTarget {
 
 impl FromXml for VulnerabilityTarget {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -175,7 +175,7 @@ impl FromXml for VulnerabilityTarget {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -225,7 +225,7 @@ const VERSION_TAG: &str = "version";
 
 impl FromXml for Versions {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -282,7 +282,7 @@ const RANGE_TAG: &str = "range";
 
 impl FromXml for Version {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -321,7 +321,7 @@ impl FromXml for Version {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -391,7 +391,7 @@ impl ToXml for VersionRange {
 
 impl FromXml for VersionRange {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -447,7 +447,7 @@ impl ToXml for Status {
 
 impl FromXml for Status {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -20,3 +20,4 @@ pub(crate) mod common;
 pub(crate) mod v1_3;
 pub(crate) mod v1_4;
 pub(crate) mod v1_5;
+pub(crate) mod v1_6;
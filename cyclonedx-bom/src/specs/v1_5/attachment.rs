@@ -28,8 +28,8 @@ use crate::{
     models,
     utilities::convert_optional,
     xml::{
-        optional_attribute, to_xml_read_error, to_xml_write_error, write_close_tag, FromXml,
-        ToInnerXml,
+        optional_attribute, skip_unexpected_element, to_xml_read_error, to_xml_write_error,
+        write_close_tag, FromXml, ToInnerXml,
     },
 };
 
@@ -78,7 +78,7 @@ impl ToInnerXml for Attachment {
             start_tag = start_tag.attr(ENCODING_ATTR, encoding);
         }
         if let Some(content_type) = &self.content_type {
-            start_tag = start_tag.attr(ENCODING_ATTR, content_type);
+            start_tag = start_tag.attr(CONTENT_TYPE_ATTR, content_type);
         }
         writer.write(start_tag).map_err(to_xml_write_error(tag))?;
 
@@ -94,7 +94,7 @@ impl ToInnerXml for Attachment {
 
 impl FromXml for Attachment {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -120,7 +120,7 @@ impl FromXml for Attachment {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -33,8 +33,9 @@ use crate::{
     },
     utilities::{convert_optional, convert_vec},
     xml::{
-        optional_attribute, read_simple_tag, to_xml_read_error, to_xml_write_error,
-        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
+        optional_attribute, read_simple_tag, skip_unexpected_element, to_xml_read_error,
+        to_xml_write_error, write_close_tag, write_simple_tag, write_start_tag, FromXml,
+        ToInnerXml, ToXml,
     },
 };
 
@@ -46,7 +47,7 @@ use super::attachment::Attachment;
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ComponentData {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
     pub(crate) bom_ref: Option<BomReference>,
     #[serde(rename = "type")]
     pub(crate) data_type: String,
@@ -172,7 +173,7 @@ const TYPE_TAG: &str = "type";
 
 impl FromXml for ComponentData {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -256,7 +257,7 @@ impl FromXml for ComponentData {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -342,7 +343,7 @@ const PROPERTIES_TAG: &str = "properties";
 
 impl FromXml for DataContents {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -388,7 +389,7 @@ impl FromXml for DataContents {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -455,7 +456,7 @@ const OUTPUT_TAG: &str = "output";
 
 impl FromXml for GraphicsCollection {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -489,7 +490,7 @@ impl FromXml for GraphicsCollection {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -543,7 +544,7 @@ impl ToXml for Collection {
 
 impl FromXml for Collection {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -567,7 +568,7 @@ impl FromXml for Collection {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -626,7 +627,7 @@ impl ToXml for Graphic {
 
 impl FromXml for Graphic {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -659,7 +660,7 @@ impl FromXml for Graphic {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -673,6 +674,7 @@ impl FromXml for Graphic {
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
+    use crate::xml::test::read_element_from_string;
 
     pub(crate) fn example_component_data() -> ComponentData {
         ComponentData {
@@ -717,4 +719,53 @@ pub(crate) mod tests {
             governance: None,
         }
     }
+
+    #[test]
+    fn it_should_read_json_component_data() {
+        let input = r#"{
+  "bom-ref": "data-1",
+  "type": "configuration",
+  "name": "config",
+  "contents": {
+    "attachment": {
+      "content": "foo: bar"
+    }
+  }
+}"#;
+        let actual: ComponentData = serde_json::from_str(input).expect("Failed to parse JSON");
+        let expected = ComponentData {
+            bom_ref: Some(BomReference::new("data-1")),
+            ..example_component_data()
+        };
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn it_should_write_json_bom_ref_of_component_data() {
+        let data = ComponentData {
+            bom_ref: Some(BomReference::new("data-1")),
+            ..example_component_data()
+        };
+        let actual = serde_json::to_value(data).expect("Failed to write JSON");
+        assert_eq!(actual["bom-ref"], "data-1");
+    }
+
+    #[test]
+    fn it_should_read_xml_component_data() {
+        let input = r#"
+<data bom-ref="data-1">
+  <type>configuration</type>
+  <name>config</name>
+  <contents>
+    <attachment>foo: bar</attachment>
+  </contents>
+</data>
+"#;
+        let actual: ComponentData = read_element_from_string(input);
+        let expected = ComponentData {
+            bom_ref: Some(BomReference::new("data-1")),
+            ..example_component_data()
+        };
+        assert_eq!(expected, actual);
+    }
 }
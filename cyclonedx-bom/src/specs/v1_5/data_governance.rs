@@ -25,7 +25,7 @@ use crate::{
     specs::common::organization::{OrganizationalContact, OrganizationalEntity},
     utilities::convert_vec,
     xml::{
-        read_list_tag, to_xml_read_error, unexpected_element_error, write_close_tag,
+        read_list_tag, skip_unexpected_element, to_xml_read_error, write_close_tag,
         write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
@@ -114,7 +114,7 @@ impl ToInnerXml for DataGovernance {
 
 impl FromXml for DataGovernance {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -150,7 +150,7 @@ impl FromXml for DataGovernance {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -163,6 +163,7 @@ impl FromXml for DataGovernance {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub(crate) enum DataGovernanceResponsibleParty {
     Organization(OrganizationalEntity),
     Contact(OrganizationalContact),
@@ -219,7 +220,7 @@ impl ToXml for DataGovernanceResponsibleParty {
 
 impl FromXml for DataGovernanceResponsibleParty {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -255,7 +256,7 @@ impl FromXml for DataGovernanceResponsibleParty {
                     got_end_tag = true;
                 }
 
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -26,7 +26,7 @@ use crate::{
     utilities::{convert_optional, convert_vec},
     xml::{
         attribute_or_error, optional_attribute, read_f32_tag, read_list_tag, read_simple_tag,
-        read_u32_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
+        read_u32_tag, skip_unexpected_element, to_xml_read_error, to_xml_write_error,
         write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
@@ -67,7 +67,7 @@ impl ToInnerXml for Occurrences {
 
 impl FromXml for Occurrences {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -93,7 +93,7 @@ impl From<models::component::Occurrences> for Occurrences {
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct Occurrence {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
     pub bom_ref: Option<String>,
     pub location: String,
 }
@@ -144,7 +144,7 @@ impl ToXml for Occurrence {
 
 impl FromXml for Occurrence {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -166,7 +166,7 @@ impl FromXml for Occurrence {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -242,7 +242,7 @@ impl ToXml for Callstack {
 
 impl FromXml for Callstack {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -258,16 +258,14 @@ impl FromXml for Callstack {
                 .map_err(to_xml_read_error(&element_name.local_name))?;
 
             match next_element {
-                reader::XmlEvent::StartElement {
-                    name, attributes, ..
-                } if name.local_name == FRAME_TAG => {
-                    frames.push(Frame::read_xml_element(event_reader, &name, &attributes)?);
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == FRAMES_TAG => {
+                    frames = read_list_tag(event_reader, &name, FRAME_TAG)?;
                 }
 
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -398,7 +396,7 @@ impl ToXml for Frame {
 
 impl FromXml for Frame {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -455,7 +453,7 @@ impl FromXml for Frame {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -551,7 +549,7 @@ impl ToXml for Identity {
 
 impl FromXml for Identity {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -599,7 +597,7 @@ impl FromXml for Identity {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -660,7 +658,7 @@ impl ToXml for Methods {
 
 impl FromXml for Methods {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -685,7 +683,7 @@ impl FromXml for Methods {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -745,7 +743,7 @@ impl ToXml for Method {
 
 impl FromXml for Method {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -780,7 +778,7 @@ impl FromXml for Method {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -839,7 +837,7 @@ impl ToXml for ToolsReferences {
 
 impl FromXml for ToolsReferences {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -867,7 +865,7 @@ impl FromXml for ToolsReferences {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -7,8 +7,9 @@ use crate::{
     specs::{common::property::Properties, v1_5::attachment::Attachment},
     utilities::{convert_optional, convert_vec},
     xml::{
-        read_simple_tag, to_xml_read_error, unexpected_element_error, write_close_tag,
-        write_list_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
+        read_list_tag, read_simple_tag, skip_unexpected_child, skip_unexpected_element,
+        to_xml_read_error, write_close_tag, write_list_tag, write_simple_tag, write_start_tag,
+        FromXml, ToInnerXml, ToXml,
     },
 };
 
@@ -134,7 +135,7 @@ impl ToXml for Input {
 
 impl FromXml for Input {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -164,6 +165,11 @@ impl FromXml for Input {
                             )?,
                         })
                     }
+                    PARAMETERS_TAG => {
+                        required = Some(RequiredInputField::Parameters {
+                            parameters: read_list_tag(event_reader, name, PARAMETER_TAG)?,
+                        });
+                    }
                     ENVIRONMENT_VARS_TAG => {
                         required = Some(RequiredInputField::EnvironmentVars {
                             environment_vars: EnvironmentVars::read_xml_element(
@@ -199,17 +205,12 @@ impl FromXml for Input {
                             attributes,
                         )?)
                     }
-                    _ => {
-                        return Err(unexpected_element_error(
-                            name.local_name.clone(),
-                            next_element,
-                        ))
-                    }
+                    _ => skip_unexpected_child(event_reader, element_name, name)?,
                 },
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -295,7 +296,7 @@ impl ToXml for Parameter {
 
 impl FromXml for Parameter {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -320,17 +321,12 @@ impl FromXml for Parameter {
                     NAME_TAG => name = Some(read_simple_tag(event_reader, elem_name)?),
                     VALUE_TAG => value = Some(read_simple_tag(event_reader, elem_name)?),
                     DATA_TYPE_TAG => data_type = Some(read_simple_tag(event_reader, elem_name)?),
-                    _ => {
-                        return Err(unexpected_element_error(
-                            elem_name.to_string(),
-                            next_element,
-                        ))
-                    }
+                    _ => skip_unexpected_child(event_reader, element_name, elem_name)?,
                 },
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -390,4 +386,33 @@ mod tests {
         let expected = example_input();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_read_xml_parameters() {
+        let input = r#"
+<input>
+    <parameters>
+        <parameter>
+            <name>level</name>
+            <value>3</value>
+            <dataType>number</dataType>
+        </parameter>
+    </parameters>
+</input>
+"#;
+        let actual: Input = read_element_from_string(input);
+        let expected = Input {
+            required: RequiredInputField::Parameters {
+                parameters: vec![Parameter {
+                    name: Some("level".into()),
+                    value: Some("3".into()),
+                    data_type: Some("number".into()),
+                }],
+            },
+            source: None,
+            target: None,
+            properties: None,
+        };
+        assert_eq!(actual, expected);
+    }
 }
@@ -13,8 +13,8 @@ use crate::{
     specs::common::{dependency::Dependency, property::Properties},
     utilities::{convert_optional, convert_optional_vec},
     xml::{
-        attribute_or_error, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
-        to_xml_write_error, unexpected_element_error, write_close_tag, write_list_tag,
+        attribute_or_error, read_lax_validation_tag, read_simple_tag, skip_unexpected_element,
+        to_xml_read_error, to_xml_write_error, write_close_tag, write_list_tag,
         write_simple_option_tag, write_simple_tag, write_start_tag, FromXml, ToXml, VecElemTag,
         VecXmlReader,
     },
@@ -200,7 +200,7 @@ impl ToXml for Workflow {
 
 impl FromXml for Workflow {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -267,7 +267,7 @@ impl ToXml for TaskType {
 
 impl FromXml for TaskType {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -380,6 +380,7 @@ impl ToXml for Task {
         write_simple_option_tag(writer, NAME_TAG, &self.name)?;
         write_simple_option_tag(writer, DESCRIPTION_TAG, &self.description)?;
         self.resource_references.write_xml_element(writer)?;
+        write_list_tag(writer, TASK_TYPES_TAG, &self.task_types)?;
         self.trigger.write_xml_element(writer)?;
         if let Some(steps) = &self.steps {
             write_list_tag(writer, STEPS_TAG, steps)?;
@@ -400,13 +401,13 @@ impl ToXml for Task {
         }
         self.properties.write_xml_element(writer)?;
 
-        write_close_tag(writer, WORKFLOW_TAG)
+        write_close_tag(writer, TaskTag::VALUE)
     }
 }
 
 impl FromXml for Task {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -477,7 +478,7 @@ impl ToXml for EnvironmentVars {
 
 impl FromXml for EnvironmentVars {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -510,7 +511,7 @@ impl FromXml for EnvironmentVars {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -7,8 +7,8 @@ use crate::{
     specs::{common::property::Properties, v1_5::attachment::Attachment},
     utilities::{convert_optional, convert_vec},
     xml::{
-        read_simple_tag, to_xml_read_error, unexpected_element_error, write_close_tag,
-        write_simple_option_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
+        read_simple_tag, skip_unexpected_child, skip_unexpected_element, to_xml_read_error,
+        write_close_tag, write_simple_option_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
 
@@ -128,7 +128,7 @@ impl ToXml for Output {
 
 impl FromXml for Output {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -195,17 +195,12 @@ impl FromXml for Output {
                             attributes,
                         )?)
                     }
-                    _ => {
-                        return Err(unexpected_element_error(
-                            name.local_name.clone(),
-                            next_element,
-                        ))
-                    }
+                    _ => skip_unexpected_child(event_reader, element_name, name)?,
                 },
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
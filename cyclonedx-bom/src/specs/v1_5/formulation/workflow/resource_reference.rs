@@ -6,8 +6,9 @@ use crate::{
     models,
     specs::v1_5::external_reference::ExternalReference,
     xml::{
-        read_lax_validation_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
-        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
+        read_lax_validation_tag, read_simple_tag, skip_unexpected_child, skip_unexpected_element,
+        to_xml_read_error, write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml,
+        ToXml,
     },
 };
 
@@ -35,7 +36,7 @@ impl ToXml for ResourceReferences {
 
 impl FromXml for ResourceReferences {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -66,7 +67,7 @@ impl FromXml for ResourceReferences {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -126,7 +127,7 @@ impl ToInnerXml for ResourceReference {
         match self {
             Self::Ref { r#ref } => write_simple_tag(writer, REF_TAG, r#ref)?,
             Self::ExternalReference { external_reference } => {
-                external_reference.write_xml_element(writer)?
+                external_reference.write_xml_named_element(writer, EXTERNAL_REFERENCE_TAG)?
             }
         }
 
@@ -138,7 +139,7 @@ impl ToInnerXml for ResourceReference {
 
 impl FromXml for ResourceReference {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -173,17 +174,12 @@ impl FromXml for ResourceReference {
                             )?,
                         })
                     }
-                    _ => {
-                        return Err(unexpected_element_error(
-                            elem_name.to_string(),
-                            next_element,
-                        ))
-                    }
+                    _ => skip_unexpected_child(event_reader, element_name, elem_name)?,
                 },
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -196,7 +192,10 @@ impl FromXml for ResourceReference {
 
 #[cfg(test)]
 mod test {
-    use crate::xml::test::{read_element_from_string, write_element_to_string};
+    use crate::{
+        specs::v1_5::external_reference::test::example_external_reference,
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
 
     use super::*;
 
@@ -225,4 +224,16 @@ mod test {
         let expected = example_resource_references();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_read_written_external_references() {
+        let external_references = || {
+            ResourceReferences(vec![ResourceReference::ExternalReference {
+                external_reference: example_external_reference(),
+            }])
+        };
+        let xml_output = write_element_to_string(external_references());
+        let actual: ResourceReferences = read_element_from_string(xml_output);
+        assert_eq!(actual, external_references());
+    }
 }
@@ -6,9 +6,9 @@ use crate::{
     specs::common::property::Properties,
     utilities::{convert_optional, convert_optional_vec},
     xml::{
-        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-        unexpected_element_error, write_close_tag, write_list_tag, write_simple_option_tag,
-        write_start_tag, FromXml, ToXml,
+        read_lax_validation_tag, read_list_tag, read_simple_tag, skip_unexpected_child,
+        skip_unexpected_element, to_xml_read_error, write_close_tag, write_list_tag,
+        write_simple_option_tag, write_start_tag, FromXml, ToXml,
     },
 };
 
@@ -74,7 +74,7 @@ impl ToXml for Step {
 
 impl FromXml for Step {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -116,7 +116,7 @@ impl FromXml for Step {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -176,7 +176,7 @@ impl ToXml for Command {
 
 impl FromXml for Command {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -205,12 +205,12 @@ impl FromXml for Command {
                             attributes,
                         )?)
                     }
-                    _ => return Err(unexpected_element_error(element_name, next_element)),
+                    _ => skip_unexpected_child(event_reader, element_name, name)?,
                 },
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
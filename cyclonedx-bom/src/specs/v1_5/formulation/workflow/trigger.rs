@@ -8,9 +8,9 @@ use crate::{
     utilities::{convert_optional, convert_optional_vec},
     xml::{
         optional_attribute, read_lax_validation_tag, read_list_tag, read_simple_tag,
-        to_xml_read_error, to_xml_write_error, unexpected_element_error, write_close_tag,
-        write_list_tag, write_simple_option_tag, write_simple_tag, write_start_tag, FromXml,
-        ToInnerXml, ToXml,
+        skip_unexpected_child, skip_unexpected_element, to_xml_read_error, to_xml_write_error,
+        write_close_tag, write_list_tag, write_simple_option_tag, write_simple_tag,
+        write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
 
@@ -131,7 +131,7 @@ impl ToXml for Trigger {
 
 impl FromXml for Trigger {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -208,7 +208,7 @@ impl FromXml for Trigger {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -309,7 +309,7 @@ impl ToXml for Event {
 
 impl FromXml for Event {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -375,7 +375,7 @@ impl FromXml for Event {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -442,7 +442,7 @@ impl ToXml for Condition {
 
 impl FromXml for Condition {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -475,12 +475,12 @@ impl FromXml for Condition {
                             attributes,
                         )?)
                     }
-                    _ => return Err(unexpected_element_error(element_name, next_element)),
+                    _ => skip_unexpected_child(event_reader, element_name, elem_name)?,
                 },
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -27,7 +27,7 @@ use crate::{
     specs::common::organization::{OrganizationalContact, OrganizationalEntity},
     utilities::convert_optional,
     xml::{
-        read_list_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
+        read_list_tag, read_simple_tag, skip_unexpected_element, to_xml_read_error,
         write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
@@ -75,7 +75,7 @@ impl From<models::license::Licensing> for Licensing {
         Self {
             alt_ids: other
                 .alt_ids
-                .map(|ids| ids.into_iter().map(|id| id.0).collect()),
+                .map(|ids| ids.into_iter().map(String::from).collect()),
             licensor: other.licensor.map(From::from),
             licensee: other.licensee.map(From::from),
             purchaser: other.purchaser.map(From::from),
@@ -149,7 +149,7 @@ impl ToXml for LicenseContact {
 
 impl FromXml for LicenseContact {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -187,7 +187,7 @@ impl FromXml for LicenseContact {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -271,7 +271,7 @@ impl ToXml for Licensing {
 
 impl FromXml for Licensing {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -354,7 +354,7 @@ impl FromXml for Licensing {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
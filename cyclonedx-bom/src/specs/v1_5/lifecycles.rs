@@ -25,7 +25,7 @@ use crate::{
     prelude::NormalizedString,
     utilities::convert_vec,
     xml::{
-        read_list_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
+        read_list_tag, read_simple_tag, skip_unexpected_element, to_xml_read_error,
         write_close_tag, write_simple_tag, write_start_tag, FromXml, ToXml,
     },
 };
@@ -73,7 +73,7 @@ const NAME_TAG: &str = "name";
 
 impl FromXml for Lifecycles {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -138,7 +138,7 @@ impl ToXml for Lifecycle {
 
 impl FromXml for Lifecycle {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -173,7 +173,7 @@ impl FromXml for Lifecycle {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
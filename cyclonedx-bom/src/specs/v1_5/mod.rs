@@ -16,7 +16,6 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-pub(crate) mod annotation;
 pub(crate) mod attachment;
 pub(crate) mod component_data;
 pub(crate) mod data_governance;
@@ -28,6 +27,7 @@ pub(crate) mod modelcard;
 pub(crate) mod proof_of_concept;
 pub(crate) mod service_data;
 
+pub(crate) use crate::specs::common::annotation::v1_5 as annotation;
 pub(crate) use crate::specs::common::bom::v1_5 as bom;
 pub(crate) use crate::specs::common::component::v1_5 as component;
 pub(crate) use crate::specs::common::composition::v1_5 as composition;
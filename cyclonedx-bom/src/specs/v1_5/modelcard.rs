@@ -24,15 +24,20 @@ use xml::{
 };
 
 use crate::{
+    elem_tag,
     errors::XmlReadError,
-    models,
-    specs::common::property::Properties,
-    utilities::{convert_optional, convert_vec},
+    get_elements, models,
+    specs::{
+        common::{organization::OrganizationalEntity, property::Properties},
+        v1_5::external_reference::ExternalReferences,
+    },
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
     xml::{
-        optional_attribute, read_simple_tag, to_xml_read_error, to_xml_write_error,
-        unexpected_element_error, write_close_tag, write_simple_tag, write_start_tag, FromXml,
-        ToInnerXml, ToXml,
+        optional_attribute, read_simple_tag, skip_unexpected_element, to_xml_read_error,
+        to_xml_write_error, write_close_tag, write_list_tag, write_simple_option_tag,
+        write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml, VecXmlReader,
     },
+    xml_element,
 };
 
 use super::component_data::{ComponentData, GraphicsCollection};
@@ -40,7 +45,7 @@ use super::component_data::{ComponentData, GraphicsCollection};
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ModelCard {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
     pub(crate) bom_ref: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) model_parameters: Option<ModelParameters>,
@@ -117,7 +122,7 @@ impl ToXml for ModelCard {
 
 impl FromXml for ModelCard {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -127,6 +132,8 @@ impl FromXml for ModelCard {
         let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
         let mut model_parameters: Option<ModelParameters> = None;
         let mut quantitative_analysis: Option<QuantitativeAnalysis> = None;
+        let mut considerations: Option<Considerations> = None;
+        let mut properties: Option<Properties> = None;
 
         let mut got_end_tag = false;
         while !got_end_tag {
@@ -155,11 +162,31 @@ impl FromXml for ModelCard {
                     )?);
                 }
 
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CONSIDERATIONS_TAG => {
+                    considerations = Some(Considerations::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -167,8 +194,8 @@ impl FromXml for ModelCard {
             bom_ref,
             model_parameters,
             quantitative_analysis,
-            considerations: None,
-            properties: None,
+            considerations,
+            properties,
         })
     }
 }
@@ -273,7 +300,7 @@ impl ToXml for ModelParameters {
 
 impl FromXml for ModelParameters {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -347,7 +374,7 @@ impl FromXml for ModelParameters {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -408,7 +435,7 @@ impl ToXml for ModelParametersApproach {
 
 impl FromXml for ModelParametersApproach {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -432,7 +459,7 @@ impl FromXml for ModelParametersApproach {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -475,7 +502,7 @@ impl ToXml for Datasets {
 
 impl FromXml for Datasets {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -501,7 +528,7 @@ impl FromXml for Datasets {
                     got_end_tag = true;
                 }
 
-                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -564,7 +591,7 @@ impl ToXml for Dataset {
 
 impl FromXml for Dataset {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -633,7 +660,7 @@ impl ToXml for QuantitativeAnalysis {
 
 impl FromXml for QuantitativeAnalysis {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -674,7 +701,7 @@ impl FromXml for QuantitativeAnalysis {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -720,7 +747,7 @@ impl ToXml for PerformanceMetrics {
 
 impl FromXml for PerformanceMetrics {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -750,7 +777,7 @@ impl FromXml for PerformanceMetrics {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -827,7 +854,7 @@ impl ToXml for PerformanceMetric {
 
 impl FromXml for PerformanceMetric {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -872,7 +899,7 @@ impl FromXml for PerformanceMetric {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -938,7 +965,7 @@ impl ToXml for ConfidenceInterval {
 
 impl FromXml for ConfidenceInterval {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -971,7 +998,7 @@ impl FromXml for ConfidenceInterval {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -984,37 +1011,668 @@ impl FromXml for ConfidenceInterval {
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Considerations {}
+pub(crate) struct Considerations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) use_cases: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) technical_limitations: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) performance_tradeoffs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ethical_considerations: Option<Vec<EthicalConsideration>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) environmental_considerations: Option<EnvironmentalConsiderations>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fairness_assessments: Option<Vec<FairnessAssessment>>,
+}
 
 impl From<models::modelcard::Considerations> for Considerations {
-    fn from(_other: models::modelcard::Considerations) -> Self {
-        Self {}
+    fn from(other: models::modelcard::Considerations) -> Self {
+        Self {
+            users: other.users,
+            use_cases: other.use_cases,
+            technical_limitations: other.technical_limitations,
+            performance_tradeoffs: other.performance_tradeoffs,
+            ethical_considerations: convert_optional_vec(other.ethical_considerations),
+            environmental_considerations: convert_optional(other.environmental_considerations),
+            fairness_assessments: convert_optional_vec(other.fairness_assessments),
+        }
     }
 }
 
 impl From<Considerations> for models::modelcard::Considerations {
-    fn from(_other: Considerations) -> Self {
-        Self {}
+    fn from(other: Considerations) -> Self {
+        Self {
+            users: other.users,
+            use_cases: other.use_cases,
+            technical_limitations: other.technical_limitations,
+            performance_tradeoffs: other.performance_tradeoffs,
+            ethical_considerations: convert_optional_vec(other.ethical_considerations),
+            environmental_considerations: convert_optional(other.environmental_considerations),
+            fairness_assessments: convert_optional_vec(other.fairness_assessments),
+        }
     }
 }
 
 const CONSIDERATIONS_TAG: &str = "considerations";
+const USERS_TAG: &str = "users";
+const USE_CASES_TAG: &str = "useCases";
+const TECHNICAL_LIMITATIONS_TAG: &str = "technicalLimitations";
+const PERFORMANCE_TRADEOFFS_TAG: &str = "performanceTradeoffs";
+const ETHICAL_CONSIDERATIONS_TAG: &str = "ethicalConsiderations";
+const ENVIRONMENTAL_CONSIDERATIONS_TAG: &str = "environmentalConsiderations";
+const FAIRNESS_ASSESSMENTS_TAG: &str = "fairnessAssessments";
+
+elem_tag!(UserTag = "user");
+elem_tag!(UseCaseTag = "useCase");
+elem_tag!(TechnicalLimitationTag = "technicalLimitation");
+elem_tag!(PerformanceTradeoffTag = "performanceTradeoff");
+elem_tag!(EthicalConsiderationTag = "ethicalConsideration");
+elem_tag!(FairnessAssessmentTag = "fairnessAssessment");
+
+xml_element! {
+    Considerations = CONSIDERATIONS_TAG {
+        USERS_TAG => users: VecXmlReader<String, UserTag>,
+        USE_CASES_TAG => use_cases: VecXmlReader<String, UseCaseTag>,
+        TECHNICAL_LIMITATIONS_TAG => technical_limitations: VecXmlReader<String, TechnicalLimitationTag>,
+        PERFORMANCE_TRADEOFFS_TAG => performance_tradeoffs: VecXmlReader<String, PerformanceTradeoffTag>,
+        ETHICAL_CONSIDERATIONS_TAG => ethical_considerations: VecXmlReader<EthicalConsideration, EthicalConsiderationTag>,
+        ENVIRONMENTAL_CONSIDERATIONS_TAG => environmental_considerations: EnvironmentalConsiderations,
+        FAIRNESS_ASSESSMENTS_TAG => fairness_assessments: VecXmlReader<FairnessAssessment, FairnessAssessmentTag>,
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EthicalConsideration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) mitigation_strategy: Option<String>,
+}
+
+impl From<models::modelcard::EthicalConsideration> for EthicalConsideration {
+    fn from(other: models::modelcard::EthicalConsideration) -> Self {
+        Self {
+            name: other.name,
+            mitigation_strategy: other.mitigation_strategy,
+        }
+    }
+}
+
+impl From<EthicalConsideration> for models::modelcard::EthicalConsideration {
+    fn from(other: EthicalConsideration) -> Self {
+        Self {
+            name: other.name,
+            mitigation_strategy: other.mitigation_strategy,
+        }
+    }
+}
+
+const ETHICAL_CONSIDERATION_TAG: &str = "ethicalConsideration";
+const NAME_TAG: &str = "name";
+const MITIGATION_STRATEGY_TAG: &str = "mitigationStrategy";
+
+xml_element! {
+    EthicalConsideration = ETHICAL_CONSIDERATION_TAG {
+        NAME_TAG => name: String,
+        MITIGATION_STRATEGY_TAG => mitigation_strategy: String,
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FairnessAssessment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) group_at_risk: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) benefits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) harms: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) mitigation_strategy: Option<String>,
+}
+
+impl From<models::modelcard::FairnessAssessment> for FairnessAssessment {
+    fn from(other: models::modelcard::FairnessAssessment) -> Self {
+        Self {
+            group_at_risk: other.group_at_risk,
+            benefits: other.benefits,
+            harms: other.harms,
+            mitigation_strategy: other.mitigation_strategy,
+        }
+    }
+}
+
+impl From<FairnessAssessment> for models::modelcard::FairnessAssessment {
+    fn from(other: FairnessAssessment) -> Self {
+        Self {
+            group_at_risk: other.group_at_risk,
+            benefits: other.benefits,
+            harms: other.harms,
+            mitigation_strategy: other.mitigation_strategy,
+        }
+    }
+}
+
+const FAIRNESS_ASSESSMENT_TAG: &str = "fairnessAssessment";
+const GROUP_AT_RISK_TAG: &str = "groupAtRisk";
+const BENEFITS_TAG: &str = "benefits";
+const HARMS_TAG: &str = "harms";
+
+xml_element! {
+    FairnessAssessment = FAIRNESS_ASSESSMENT_TAG {
+        GROUP_AT_RISK_TAG => group_at_risk: String,
+        BENEFITS_TAG => benefits: String,
+        HARMS_TAG => harms: String,
+        MITIGATION_STRATEGY_TAG => mitigation_strategy: String,
+    }
+}
+
+/// Added in spec version 1.6.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EnvironmentalConsiderations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) energy_consumptions: Option<Vec<EnergyConsumption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<Properties>,
+}
+
+impl From<models::modelcard::EnvironmentalConsiderations> for EnvironmentalConsiderations {
+    fn from(other: models::modelcard::EnvironmentalConsiderations) -> Self {
+        Self {
+            energy_consumptions: convert_optional_vec(other.energy_consumptions),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<EnvironmentalConsiderations> for models::modelcard::EnvironmentalConsiderations {
+    fn from(other: EnvironmentalConsiderations) -> Self {
+        Self {
+            energy_consumptions: convert_optional_vec(other.energy_consumptions),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const ENERGY_CONSUMPTIONS_TAG: &str = "energyConsumptions";
+const PROPERTIES_TAG: &str = "properties";
+
+elem_tag!(EnergyConsumptionTag = "energyConsumption");
 
-impl ToXml for Considerations {
+impl ToInnerXml for EnvironmentalConsiderations {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        write_start_tag(writer, tag)?;
+
+        if let Some(energy_consumptions) = &self.energy_consumptions {
+            write_list_tag(writer, ENERGY_CONSUMPTIONS_TAG, energy_consumptions)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        write_close_tag(writer, tag)?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for EnvironmentalConsiderations {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        get_elements! {
+            event_reader, element_name,
+            ENERGY_CONSUMPTIONS_TAG => energy_consumptions: VecXmlReader<EnergyConsumption, EnergyConsumptionTag>,
+            PROPERTIES_TAG => properties: Properties,
+        };
+
+        Ok(Self {
+            energy_consumptions: energy_consumptions.map(Vec::from),
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EnergyConsumption {
+    pub(crate) activity: String,
+    pub(crate) energy_providers: Vec<EnergyProvider>,
+    pub(crate) activity_energy_cost: EnergyMeasure,
+    #[serde(rename = "co2CostEquivalent", skip_serializing_if = "Option::is_none")]
+    pub(crate) co2_cost_equivalent: Option<Co2Measure>,
+    #[serde(rename = "co2CostOffset", skip_serializing_if = "Option::is_none")]
+    pub(crate) co2_cost_offset: Option<Co2Measure>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) properties: Option<Properties>,
+}
+
+impl From<models::modelcard::EnergyConsumption> for EnergyConsumption {
+    fn from(other: models::modelcard::EnergyConsumption) -> Self {
+        Self {
+            activity: other.activity.to_string(),
+            energy_providers: convert_vec(other.energy_providers),
+            activity_energy_cost: other.activity_energy_cost.into(),
+            co2_cost_equivalent: convert_optional(other.co2_cost_equivalent),
+            co2_cost_offset: convert_optional(other.co2_cost_offset),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<EnergyConsumption> for models::modelcard::EnergyConsumption {
+    fn from(other: EnergyConsumption) -> Self {
+        Self {
+            activity: models::modelcard::EnergyActivity::new_unchecked(other.activity),
+            energy_providers: convert_vec(other.energy_providers),
+            activity_energy_cost: other.activity_energy_cost.into(),
+            co2_cost_equivalent: convert_optional(other.co2_cost_equivalent),
+            co2_cost_offset: convert_optional(other.co2_cost_offset),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const ENERGY_CONSUMPTION_TAG: &str = "energyConsumption";
+const ACTIVITY_TAG: &str = "activity";
+const ENERGY_PROVIDERS_TAG: &str = "energyProviders";
+const ACTIVITY_ENERGY_COST_TAG: &str = "activityEnergyCost";
+const CO2_COST_EQUIVALENT_TAG: &str = "co2CostEquivalent";
+const CO2_COST_OFFSET_TAG: &str = "co2CostOffset";
+
+impl ToXml for EnergyConsumption {
     fn write_xml_element<W: std::io::Write>(
         &self,
         writer: &mut xml::EventWriter<W>,
     ) -> Result<(), crate::errors::XmlWriteError> {
-        write_start_tag(writer, CONSIDERATIONS_TAG)?;
+        write_start_tag(writer, ENERGY_CONSUMPTION_TAG)?;
 
-        // TODO: implement
+        write_simple_tag(writer, ACTIVITY_TAG, &self.activity)?;
 
-        write_close_tag(writer, CONSIDERATIONS_TAG)?;
+        for energy_provider in &self.energy_providers {
+            energy_provider.write_xml_named_element(writer, ENERGY_PROVIDERS_TAG)?;
+        }
+
+        self.activity_energy_cost
+            .write_xml_named_element(writer, ACTIVITY_ENERGY_COST_TAG)?;
+
+        if let Some(co2_cost_equivalent) = &self.co2_cost_equivalent {
+            co2_cost_equivalent.write_xml_named_element(writer, CO2_COST_EQUIVALENT_TAG)?;
+        }
+
+        if let Some(co2_cost_offset) = &self.co2_cost_offset {
+            co2_cost_offset.write_xml_named_element(writer, CO2_COST_OFFSET_TAG)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        write_close_tag(writer, ENERGY_CONSUMPTION_TAG)?;
 
         Ok(())
     }
 }
 
+impl FromXml for EnergyConsumption {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut activity: Option<String> = None;
+        let mut energy_providers: Vec<EnergyProvider> = Vec::new();
+        let mut activity_energy_cost: Option<EnergyMeasure> = None;
+        let mut co2_cost_equivalent: Option<Co2Measure> = None;
+        let mut co2_cost_offset: Option<Co2Measure> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(&element_name.local_name))?;
+
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == ACTIVITY_TAG => {
+                    activity = Some(read_simple_tag(event_reader, &name)?);
+                }
+
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ENERGY_PROVIDERS_TAG => {
+                    energy_providers.push(EnergyProvider::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ACTIVITY_ENERGY_COST_TAG => {
+                    activity_energy_cost = Some(EnergyMeasure::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CO2_COST_EQUIVALENT_TAG => {
+                    co2_cost_equivalent = Some(Co2Measure::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CO2_COST_OFFSET_TAG => {
+                    co2_cost_offset = Some(Co2Measure::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
+            }
+        }
+
+        let activity = activity.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: ACTIVITY_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+        let activity_energy_cost =
+            activity_energy_cost.ok_or_else(|| XmlReadError::RequiredDataMissing {
+                required_field: ACTIVITY_ENERGY_COST_TAG.to_string(),
+                element: element_name.local_name.to_string(),
+            })?;
+
+        Ok(Self {
+            activity,
+            energy_providers,
+            activity_energy_cost,
+            co2_cost_equivalent,
+            co2_cost_offset,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EnergyProvider {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    pub(crate) bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    pub(crate) organization: OrganizationalEntity,
+    pub(crate) energy_source: String,
+    pub(crate) energy_provided: EnergyMeasure,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) external_references: Option<ExternalReferences>,
+}
+
+impl From<models::modelcard::EnergyProvider> for EnergyProvider {
+    fn from(other: models::modelcard::EnergyProvider) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(|r| r.0),
+            description: other.description,
+            organization: other.organization.into(),
+            energy_source: other.energy_source.to_string(),
+            energy_provided: other.energy_provided.into(),
+            external_references: convert_optional(other.external_references),
+        }
+    }
+}
+
+impl From<EnergyProvider> for models::modelcard::EnergyProvider {
+    fn from(other: EnergyProvider) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(models::bom::BomReference::new),
+            description: other.description,
+            organization: other.organization.into(),
+            energy_source: models::modelcard::EnergySource::new_unchecked(other.energy_source),
+            energy_provided: other.energy_provided.into(),
+            external_references: convert_optional(other.external_references),
+        }
+    }
+}
+
+const DESCRIPTION_TAG: &str = "description";
+const ORGANIZATION_TAG: &str = "organization";
+const ENERGY_SOURCE_TAG: &str = "energySource";
+const ENERGY_PROVIDED_TAG: &str = "energyProvided";
+const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
+
+impl ToInnerXml for EnergyProvider {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let mut start_tag = writer::XmlEvent::start_element(tag);
+        if let Some(bom_ref) = &self.bom_ref {
+            start_tag = start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+        writer.write(start_tag).map_err(to_xml_write_error(tag))?;
+
+        write_simple_option_tag(writer, DESCRIPTION_TAG, &self.description)?;
+        self.organization
+            .write_xml_named_element(writer, ORGANIZATION_TAG)?;
+        write_simple_tag(writer, ENERGY_SOURCE_TAG, &self.energy_source)?;
+        self.energy_provided
+            .write_xml_named_element(writer, ENERGY_PROVIDED_TAG)?;
+        if let Some(external_references) = &self.external_references {
+            external_references.write_xml_element(writer)?;
+        }
+
+        write_close_tag(writer, tag)?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for EnergyProvider {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+
+        get_elements! {
+            event_reader, element_name,
+            DESCRIPTION_TAG => description: String,
+            ORGANIZATION_TAG => organization: OrganizationalEntity,
+            ENERGY_SOURCE_TAG => energy_source: String,
+            ENERGY_PROVIDED_TAG => energy_provided: EnergyMeasure,
+            EXTERNAL_REFERENCES_TAG => external_references: ExternalReferences,
+        };
+
+        let required = |field: &str| XmlReadError::RequiredDataMissing {
+            required_field: field.to_string(),
+            element: element_name.local_name.to_string(),
+        };
+
+        Ok(Self {
+            bom_ref,
+            description,
+            organization: organization.ok_or_else(|| required(ORGANIZATION_TAG))?,
+            energy_source: energy_source.ok_or_else(|| required(ENERGY_SOURCE_TAG))?,
+            energy_provided: energy_provided.ok_or_else(|| required(ENERGY_PROVIDED_TAG))?,
+            external_references,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub(crate) struct EnergyMeasure {
+    pub(crate) value: f32,
+    pub(crate) unit: String,
+}
+
+const KILOWATT_HOURS: &str = "kWh";
+
+impl From<models::modelcard::EnergyMeasure> for EnergyMeasure {
+    fn from(other: models::modelcard::EnergyMeasure) -> Self {
+        Self {
+            value: other.get(),
+            unit: KILOWATT_HOURS.to_string(),
+        }
+    }
+}
+
+impl From<EnergyMeasure> for models::modelcard::EnergyMeasure {
+    fn from(other: EnergyMeasure) -> Self {
+        Self::kilowatt_hours(other.value)
+    }
+}
+
+const UNIT_TAG: &str = "unit";
+
+impl ToInnerXml for EnergyMeasure {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        write_start_tag(writer, tag)?;
+        write_simple_tag(writer, VALUE_TAG, &self.value.to_string())?;
+        write_simple_tag(writer, UNIT_TAG, &self.unit)?;
+        write_close_tag(writer, tag)?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for EnergyMeasure {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let (value, unit) = read_measure(event_reader, element_name)?;
+        Ok(Self { value, unit })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub(crate) struct Co2Measure {
+    pub(crate) value: f32,
+    pub(crate) unit: String,
+}
+
+const TONNES_CO2_EQUIVALENT: &str = "tCO2eq";
+
+impl From<models::modelcard::Co2Measure> for Co2Measure {
+    fn from(other: models::modelcard::Co2Measure) -> Self {
+        Self {
+            value: other.get(),
+            unit: TONNES_CO2_EQUIVALENT.to_string(),
+        }
+    }
+}
+
+impl From<Co2Measure> for models::modelcard::Co2Measure {
+    fn from(other: Co2Measure) -> Self {
+        Self::tonnes_co2_equivalent(other.value)
+    }
+}
+
+impl ToInnerXml for Co2Measure {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        write_start_tag(writer, tag)?;
+        write_simple_tag(writer, VALUE_TAG, &self.value.to_string())?;
+        write_simple_tag(writer, UNIT_TAG, &self.unit)?;
+        write_close_tag(writer, tag)?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Co2Measure {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let (value, unit) = read_measure(event_reader, element_name)?;
+        Ok(Self { value, unit })
+    }
+}
+
+/// Reads the `value` and `unit` of an energy or CO2 measure.
+fn read_measure<R: std::io::Read>(
+    event_reader: &mut crate::xml::XmlReader<R>,
+    element_name: &OwnedName,
+) -> Result<(f32, String), XmlReadError> {
+    get_elements! {
+        event_reader, element_name,
+        VALUE_TAG => value: f32,
+        UNIT_TAG => unit: String,
+    };
+
+    let required = |field: &str| XmlReadError::RequiredDataMissing {
+        required_field: field.to_string(),
+        element: element_name.local_name.to_string(),
+    };
+
+    Ok((
+        value.ok_or_else(|| required(VALUE_TAG))?,
+        unit.ok_or_else(|| required(UNIT_TAG))?,
+    ))
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub(crate) struct Inputs(pub Vec<MLParameter>);
 
@@ -1051,7 +1709,7 @@ impl ToXml for Inputs {
 
 impl FromXml for Inputs {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -1079,7 +1737,7 @@ impl FromXml for Inputs {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -1123,7 +1781,7 @@ impl ToXml for Outputs {
 
 impl FromXml for Outputs {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -1151,7 +1809,7 @@ impl FromXml for Outputs {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -1205,7 +1863,7 @@ impl ToXml for MLParameter {
 
 impl FromXml for MLParameter {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -1226,7 +1884,7 @@ impl FromXml for MLParameter {
                     got_end_tag = true;
                 }
 
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -1244,12 +1902,15 @@ pub(crate) mod test {
         specs::{
             common::bom_reference::BomReference,
             common::organization::{OrganizationalContact, OrganizationalEntity},
+            common::property::test::example_properties,
             v1_5::{
                 attachment::Attachment,
                 component_data::{ComponentData, DataContents, Graphic, GraphicsCollection},
                 data_governance::{DataGovernance, DataGovernanceResponsibleParty},
                 modelcard::{
-                    ConfidenceInterval, Dataset, Datasets, Inputs, MLParameter, ModelCard,
+                    Co2Measure, ConfidenceInterval, Considerations, Dataset, Datasets,
+                    EnergyConsumption, EnergyMeasure, EnergyProvider, EnvironmentalConsiderations,
+                    EthicalConsideration, FairnessAssessment, Inputs, MLParameter, ModelCard,
                     ModelParameters, ModelParametersApproach, Outputs, PerformanceMetric,
                     PerformanceMetrics, QuantitativeAnalysis,
                 },
@@ -1327,6 +1988,101 @@ pub(crate) mod test {
         }
     }
 
+    pub(crate) fn example_considerations() -> Considerations {
+        Considerations {
+            users: Some(vec!["Researchers".to_string()]),
+            use_cases: Some(vec!["Image classification".to_string()]),
+            technical_limitations: None,
+            performance_tradeoffs: None,
+            ethical_considerations: Some(vec![EthicalConsideration {
+                name: Some("Bias".to_string()),
+                mitigation_strategy: Some("Balanced training data".to_string()),
+            }]),
+            environmental_considerations: Some(EnvironmentalConsiderations {
+                energy_consumptions: Some(vec![EnergyConsumption {
+                    activity: "training".to_string(),
+                    energy_providers: vec![EnergyProvider {
+                        bom_ref: Some("energy-provider-1".to_string()),
+                        description: Some("Data center".to_string()),
+                        organization: OrganizationalEntity {
+                            bom_ref: None,
+                            name: Some("Provider".to_string()),
+                            url: None,
+                            contact: None,
+                        },
+                        energy_source: "wind".to_string(),
+                        energy_provided: EnergyMeasure {
+                            value: 1000.0,
+                            unit: "kWh".to_string(),
+                        },
+                        external_references: None,
+                    }],
+                    activity_energy_cost: EnergyMeasure {
+                        value: 800.0,
+                        unit: "kWh".to_string(),
+                    },
+                    co2_cost_equivalent: Some(Co2Measure {
+                        value: 0.5,
+                        unit: "tCO2eq".to_string(),
+                    }),
+                    co2_cost_offset: None,
+                    properties: None,
+                }]),
+                properties: None,
+            }),
+            fairness_assessments: Some(vec![FairnessAssessment {
+                group_at_risk: Some("Minorities".to_string()),
+                benefits: None,
+                harms: Some("Misclassification".to_string()),
+                mitigation_strategy: None,
+            }]),
+        }
+    }
+
+    pub(crate) fn corresponding_considerations() -> models::modelcard::Considerations {
+        models::modelcard::Considerations {
+            users: Some(vec!["Researchers".to_string()]),
+            use_cases: Some(vec!["Image classification".to_string()]),
+            technical_limitations: None,
+            performance_tradeoffs: None,
+            ethical_considerations: Some(vec![models::modelcard::EthicalConsideration {
+                name: Some("Bias".to_string()),
+                mitigation_strategy: Some("Balanced training data".to_string()),
+            }]),
+            environmental_considerations: Some(models::modelcard::EnvironmentalConsiderations {
+                energy_consumptions: Some(vec![models::modelcard::EnergyConsumption {
+                    activity: models::modelcard::EnergyActivity::Training,
+                    energy_providers: vec![models::modelcard::EnergyProvider {
+                        bom_ref: Some(models::bom::BomReference::new("energy-provider-1")),
+                        description: Some("Data center".to_string()),
+                        organization: models::organization::OrganizationalEntity {
+                            bom_ref: None,
+                            name: Some(NormalizedString::new("Provider")),
+                            url: None,
+                            contact: None,
+                        },
+                        energy_source: models::modelcard::EnergySource::Wind,
+                        energy_provided: models::modelcard::EnergyMeasure::kilowatt_hours(1000.0),
+                        external_references: None,
+                    }],
+                    activity_energy_cost: models::modelcard::EnergyMeasure::kilowatt_hours(800.0),
+                    co2_cost_equivalent: Some(
+                        models::modelcard::Co2Measure::tonnes_co2_equivalent(0.5),
+                    ),
+                    co2_cost_offset: None,
+                    properties: None,
+                }]),
+                properties: None,
+            }),
+            fairness_assessments: Some(vec![models::modelcard::FairnessAssessment {
+                group_at_risk: Some("Minorities".to_string()),
+                benefits: None,
+                harms: Some("Misclassification".to_string()),
+                mitigation_strategy: None,
+            }]),
+        }
+    }
+
     pub(crate) fn example_governance() -> DataGovernance {
         DataGovernance {
             custodians: None,
@@ -1434,6 +2190,75 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[test]
+    fn it_should_convert_considerations() {
+        let actual: models::modelcard::Considerations = example_considerations().into();
+        assert_eq!(corresponding_considerations(), actual);
+
+        let actual: Considerations = corresponding_considerations().into();
+        assert_eq!(example_considerations(), actual);
+    }
+
+    #[test]
+    fn it_should_write_xml_considerations() {
+        let xml_output = write_element_to_string(example_considerations());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_considerations() {
+        let input = r#"
+<considerations>
+  <users>
+    <user>Researchers</user>
+  </users>
+  <useCases>
+    <useCase>Image classification</useCase>
+  </useCases>
+  <ethicalConsiderations>
+    <ethicalConsideration>
+      <name>Bias</name>
+      <mitigationStrategy>Balanced training data</mitigationStrategy>
+    </ethicalConsideration>
+  </ethicalConsiderations>
+  <environmentalConsiderations>
+    <energyConsumptions>
+      <energyConsumption>
+        <activity>training</activity>
+        <energyProviders bom-ref="energy-provider-1">
+          <description>Data center</description>
+          <organization>
+            <name>Provider</name>
+          </organization>
+          <energySource>wind</energySource>
+          <energyProvided>
+            <value>1000</value>
+            <unit>kWh</unit>
+          </energyProvided>
+        </energyProviders>
+        <activityEnergyCost>
+          <value>800</value>
+          <unit>kWh</unit>
+        </activityEnergyCost>
+        <co2CostEquivalent>
+          <value>0.5</value>
+          <unit>tCO2eq</unit>
+        </co2CostEquivalent>
+      </energyConsumption>
+    </energyConsumptions>
+  </environmentalConsiderations>
+  <fairnessAssessments>
+    <fairnessAssessment>
+      <groupAtRisk>Minorities</groupAtRisk>
+      <harms>Misclassification</harms>
+    </fairnessAssessment>
+  </fairnessAssessments>
+</considerations>
+"#;
+        let actual: Considerations = read_element_from_string(input);
+        assert_eq!(example_considerations(), actual);
+    }
+
     #[test]
     fn it_should_write_xml_model_parameters() {
         let xml_output = write_element_to_string(example_model_parameters());
@@ -1851,4 +2676,24 @@ pub(crate) mod test {
         };
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn it_should_read_xml_properties_in_modelcard() {
+        let input = r#"
+<modelCard>
+  <properties>
+    <property name="name">value</property>
+  </properties>
+</modelCard>
+"#;
+        let actual: ModelCard = read_element_from_string(input);
+        let expected = ModelCard {
+            bom_ref: None,
+            model_parameters: None,
+            quantitative_analysis: None,
+            considerations: None,
+            properties: Some(example_properties()),
+        };
+        assert_eq!(expected, actual);
+    }
 }
@@ -23,8 +23,8 @@ use crate::{
     models,
     utilities::{convert_optional, convert_vec},
     xml::{
-        read_list_tag, read_simple_tag, to_xml_read_error, write_close_tag, write_simple_tag,
-        write_start_tag, FromXml, ToInnerXml, ToXml,
+        read_list_tag, read_simple_tag, skip_unexpected_element, to_xml_read_error,
+        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
 
@@ -100,7 +100,7 @@ impl ToXml for ProofOfConcept {
 
 impl FromXml for ProofOfConcept {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, crate::errors::XmlReadError>
@@ -138,7 +138,7 @@ impl FromXml for ProofOfConcept {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -25,8 +25,9 @@ use crate::{
     prelude::NormalizedString,
     utilities::convert_optional,
     xml::{
-        optional_attribute, read_list_tag, to_xml_read_error, to_xml_write_error, write_close_tag,
-        write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
+        optional_attribute, read_list_tag, skip_unexpected_element, to_xml_read_error,
+        to_xml_write_error, write_close_tag, write_simple_tag, write_start_tag, FromXml,
+        ToInnerXml, ToXml,
     },
 };
 
@@ -93,7 +94,7 @@ const URL_TAG: &str = "url";
 
 impl FromXml for ServiceData {
     fn read_xml_element<R: std::io::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut crate::xml::XmlReader<R>,
         element_name: &xml::name::OwnedName,
         attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -147,7 +148,7 @@ impl FromXml for ServiceData {
                 reader::XmlEvent::EndElement { name } if &name == element_name => {
                     got_end_tag = true;
                 }
-                _ => (),
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
             }
         }
 
@@ -179,7 +180,7 @@ impl ToXml for ServiceData {
         }
 
         if let Some(description) = &self.description {
-            start_tag = start_tag.attr(NAME_ATTR, description);
+            start_tag = start_tag.attr(DESCRIPTION_ATTR, description);
         }
 
         writer
@@ -305,4 +306,47 @@ pub(crate) mod test {
         };
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_read_json_service_data_governance() {
+        let input = r#"
+{
+  "name": "Stock Service to MS-1",
+  "classification": "PII",
+  "flow": "bi-directional",
+  "governance": {
+    "owners": [
+      {
+        "organization": {
+          "name": "Customer Name"
+        }
+      }
+    ]
+  }
+}"#;
+        let actual: ServiceData = serde_json::from_str(input).expect("Failed to read JSON");
+        let expected = ServiceData {
+            name: Some("Stock Service to MS-1".to_string()),
+            description: None,
+            classification: DataClassification {
+                flow: "bi-directional".to_string(),
+                classification: "PII".to_string(),
+            },
+            governance: Some(DataGovernance {
+                owners: Some(vec![DataGovernanceResponsibleParty::Organization(
+                    OrganizationalEntity {
+                        bom_ref: None,
+                        name: Some("Customer Name".to_string()),
+                        url: None,
+                        contact: None,
+                    },
+                )]),
+                custodians: None,
+                stewards: None,
+            }),
+            source: None,
+            destination: None,
+        };
+        assert_eq!(actual, expected);
+    }
 }
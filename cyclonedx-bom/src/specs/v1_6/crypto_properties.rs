@@ -0,0 +1,1074 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use serde::{Deserialize, Serialize};
+use xml::reader;
+
+use crate::{
+    elem_tag,
+    errors::{XmlReadError, XmlWriteError},
+    external_models::date_time::DateTime,
+    get_elements,
+    models::crypto_properties as models,
+    utilities::{convert_optional, convert_vec},
+    xml::{
+        read_list_tag, read_simple_tag, skip_unexpected_child, skip_unexpected_element,
+        to_xml_read_error, write_close_tag, write_list_string_tag, write_simple_option_tag,
+        write_simple_tag, write_start_tag, FromXml, FromXmlType, ToInnerXml, ToXml, VecXmlReader,
+    },
+    xml_element,
+};
+
+// #definitions/component/cryptoProperties
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CryptoProperties {
+    asset_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    algorithm_properties: Option<AlgorithmProperties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    certificate_properties: Option<CertificateProperties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    related_crypto_material_properties: Option<RelatedCryptoMaterialProperties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protocol_properties: Option<ProtocolProperties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    oid: Option<String>,
+}
+
+impl From<models::CryptoProperties> for CryptoProperties {
+    fn from(other: models::CryptoProperties) -> Self {
+        Self {
+            asset_type: other.asset_type.to_string(),
+            algorithm_properties: convert_optional(other.algorithm_properties),
+            certificate_properties: convert_optional(other.certificate_properties),
+            related_crypto_material_properties: convert_optional(
+                other.related_crypto_material_properties,
+            ),
+            protocol_properties: convert_optional(other.protocol_properties),
+            oid: other.oid,
+        }
+    }
+}
+
+impl From<CryptoProperties> for models::CryptoProperties {
+    fn from(other: CryptoProperties) -> Self {
+        Self {
+            asset_type: models::CryptoAssetType::new_unchecked(other.asset_type),
+            algorithm_properties: convert_optional(other.algorithm_properties),
+            certificate_properties: convert_optional(other.certificate_properties),
+            related_crypto_material_properties: convert_optional(
+                other.related_crypto_material_properties,
+            ),
+            protocol_properties: convert_optional(other.protocol_properties),
+            oid: other.oid,
+        }
+    }
+}
+
+const CRYPTO_PROPERTIES_TAG: &str = "cryptoProperties";
+const ASSET_TYPE_TAG: &str = "assetType";
+const ALGORITHM_PROPERTIES_TAG: &str = "algorithmProperties";
+const CERTIFICATE_PROPERTIES_TAG: &str = "certificateProperties";
+const RELATED_CRYPTO_MATERIAL_PROPERTIES_TAG: &str = "relatedCryptoMaterialProperties";
+const PROTOCOL_PROPERTIES_TAG: &str = "protocolProperties";
+const OID_TAG: &str = "oid";
+
+impl ToXml for CryptoProperties {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, CRYPTO_PROPERTIES_TAG)?;
+
+        write_simple_tag(writer, ASSET_TYPE_TAG, &self.asset_type)?;
+        self.algorithm_properties.write_xml_element(writer)?;
+        self.certificate_properties.write_xml_element(writer)?;
+        self.related_crypto_material_properties
+            .write_xml_element(writer)?;
+        self.protocol_properties.write_xml_element(writer)?;
+        write_simple_option_tag(writer, OID_TAG, &self.oid)?;
+
+        write_close_tag(writer, CRYPTO_PROPERTIES_TAG)
+    }
+}
+
+impl FromXml for CryptoProperties {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        get_elements! {
+            event_reader, element_name,
+            ASSET_TYPE_TAG => asset_type: String,
+            ALGORITHM_PROPERTIES_TAG => algorithm_properties: AlgorithmProperties,
+            CERTIFICATE_PROPERTIES_TAG => certificate_properties: CertificateProperties,
+            RELATED_CRYPTO_MATERIAL_PROPERTIES_TAG => related_crypto_material_properties: RelatedCryptoMaterialProperties,
+            PROTOCOL_PROPERTIES_TAG => protocol_properties: ProtocolProperties,
+            OID_TAG => oid: String,
+        };
+
+        let asset_type = asset_type.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: ASSET_TYPE_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            asset_type,
+            algorithm_properties,
+            certificate_properties,
+            related_crypto_material_properties,
+            protocol_properties,
+            oid,
+        })
+    }
+}
+
+// #definitions/component/cryptoProperties/algorithmProperties
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AlgorithmProperties {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primitive: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parameter_set_identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    curve: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execution_environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    implementation_platform: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    certification_level: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    padding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crypto_functions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    classical_security_level: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nist_quantum_security_level: Option<u32>,
+}
+
+impl From<models::AlgorithmProperties> for AlgorithmProperties {
+    fn from(other: models::AlgorithmProperties) -> Self {
+        Self {
+            primitive: other.primitive.map(|primitive| primitive.to_string()),
+            parameter_set_identifier: other.parameter_set_identifier,
+            curve: other.curve,
+            execution_environment: other
+                .execution_environment
+                .map(|environment| environment.to_string()),
+            implementation_platform: other
+                .implementation_platform
+                .map(|platform| platform.to_string()),
+            certification_level: other
+                .certification_level
+                .map(|levels| levels.iter().map(ToString::to_string).collect()),
+            mode: other.mode.map(|mode| mode.to_string()),
+            padding: other.padding.map(|padding| padding.to_string()),
+            crypto_functions: other
+                .crypto_functions
+                .map(|functions| functions.iter().map(ToString::to_string).collect()),
+            classical_security_level: other.classical_security_level,
+            nist_quantum_security_level: other.nist_quantum_security_level,
+        }
+    }
+}
+
+impl From<AlgorithmProperties> for models::AlgorithmProperties {
+    fn from(other: AlgorithmProperties) -> Self {
+        Self {
+            primitive: other.primitive.map(models::CryptoPrimitive::new_unchecked),
+            parameter_set_identifier: other.parameter_set_identifier,
+            curve: other.curve,
+            execution_environment: other
+                .execution_environment
+                .map(models::ExecutionEnvironment::new_unchecked),
+            implementation_platform: other
+                .implementation_platform
+                .map(models::ImplementationPlatform::new_unchecked),
+            certification_level: other.certification_level.map(|levels| {
+                levels
+                    .into_iter()
+                    .map(models::CertificationLevel::new_unchecked)
+                    .collect()
+            }),
+            mode: other.mode.map(models::CryptoMode::new_unchecked),
+            padding: other.padding.map(models::CryptoPadding::new_unchecked),
+            crypto_functions: other.crypto_functions.map(|functions| {
+                functions
+                    .into_iter()
+                    .map(models::CryptoFunction::new_unchecked)
+                    .collect()
+            }),
+            classical_security_level: other.classical_security_level,
+            nist_quantum_security_level: other.nist_quantum_security_level,
+        }
+    }
+}
+
+const PRIMITIVE_TAG: &str = "primitive";
+const PARAMETER_SET_IDENTIFIER_TAG: &str = "parameterSetIdentifier";
+const CURVE_TAG: &str = "curve";
+const EXECUTION_ENVIRONMENT_TAG: &str = "executionEnvironment";
+const IMPLEMENTATION_PLATFORM_TAG: &str = "implementationPlatform";
+const CERTIFICATION_LEVEL_TAG: &str = "certificationLevel";
+const MODE_TAG: &str = "mode";
+const PADDING_TAG: &str = "padding";
+const CRYPTO_FUNCTIONS_TAG: &str = "cryptoFunctions";
+const CRYPTO_FUNCTION_TAG: &str = "cryptoFunction";
+const CLASSICAL_SECURITY_LEVEL_TAG: &str = "classicalSecurityLevel";
+const NIST_QUANTUM_SECURITY_LEVEL_TAG: &str = "nistQuantumSecurityLevel";
+
+impl ToXml for AlgorithmProperties {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, ALGORITHM_PROPERTIES_TAG)?;
+
+        write_simple_option_tag(writer, PRIMITIVE_TAG, &self.primitive)?;
+        write_simple_option_tag(
+            writer,
+            PARAMETER_SET_IDENTIFIER_TAG,
+            &self.parameter_set_identifier,
+        )?;
+        write_simple_option_tag(writer, CURVE_TAG, &self.curve)?;
+        write_simple_option_tag(
+            writer,
+            EXECUTION_ENVIRONMENT_TAG,
+            &self.execution_environment,
+        )?;
+        write_simple_option_tag(
+            writer,
+            IMPLEMENTATION_PLATFORM_TAG,
+            &self.implementation_platform,
+        )?;
+        for certification_level in self.certification_level.iter().flatten() {
+            write_simple_tag(writer, CERTIFICATION_LEVEL_TAG, certification_level)?;
+        }
+        write_simple_option_tag(writer, MODE_TAG, &self.mode)?;
+        write_simple_option_tag(writer, PADDING_TAG, &self.padding)?;
+        if let Some(crypto_functions) = &self.crypto_functions {
+            write_list_string_tag(
+                writer,
+                CRYPTO_FUNCTIONS_TAG,
+                CRYPTO_FUNCTION_TAG,
+                crypto_functions,
+            )?;
+        }
+        if let Some(level) = &self.classical_security_level {
+            write_simple_tag(writer, CLASSICAL_SECURITY_LEVEL_TAG, &level.to_string())?;
+        }
+        if let Some(level) = &self.nist_quantum_security_level {
+            write_simple_tag(writer, NIST_QUANTUM_SECURITY_LEVEL_TAG, &level.to_string())?;
+        }
+
+        write_close_tag(writer, ALGORITHM_PROPERTIES_TAG)
+    }
+}
+
+impl FromXml for AlgorithmProperties {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut primitive: Option<String> = None;
+        let mut parameter_set_identifier: Option<String> = None;
+        let mut curve: Option<String> = None;
+        let mut execution_environment: Option<String> = None;
+        let mut implementation_platform: Option<String> = None;
+        let mut certification_level: Option<Vec<String>> = None;
+        let mut mode: Option<String> = None;
+        let mut padding: Option<String> = None;
+        let mut crypto_functions: Option<Vec<String>> = None;
+        let mut classical_security_level: Option<u32> = None;
+        let mut nist_quantum_security_level: Option<u32> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ALGORITHM_PROPERTIES_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } => match name.local_name.as_str() {
+                    PRIMITIVE_TAG => primitive = Some(read_simple_tag(event_reader, &name)?),
+                    PARAMETER_SET_IDENTIFIER_TAG => {
+                        parameter_set_identifier = Some(read_simple_tag(event_reader, &name)?)
+                    }
+                    CURVE_TAG => curve = Some(read_simple_tag(event_reader, &name)?),
+                    EXECUTION_ENVIRONMENT_TAG => {
+                        execution_environment = Some(read_simple_tag(event_reader, &name)?)
+                    }
+                    IMPLEMENTATION_PLATFORM_TAG => {
+                        implementation_platform = Some(read_simple_tag(event_reader, &name)?)
+                    }
+                    CERTIFICATION_LEVEL_TAG => certification_level
+                        .get_or_insert_with(Vec::new)
+                        .push(read_simple_tag(event_reader, &name)?),
+                    MODE_TAG => mode = Some(read_simple_tag(event_reader, &name)?),
+                    PADDING_TAG => padding = Some(read_simple_tag(event_reader, &name)?),
+                    CRYPTO_FUNCTIONS_TAG => {
+                        crypto_functions =
+                            Some(read_list_tag(event_reader, &name, CRYPTO_FUNCTION_TAG)?)
+                    }
+                    CLASSICAL_SECURITY_LEVEL_TAG => {
+                        classical_security_level = Some(u32::from_xml_value(
+                            CLASSICAL_SECURITY_LEVEL_TAG,
+                            read_simple_tag(event_reader, &name)?,
+                        )?)
+                    }
+                    NIST_QUANTUM_SECURITY_LEVEL_TAG => {
+                        nist_quantum_security_level = Some(u32::from_xml_value(
+                            NIST_QUANTUM_SECURITY_LEVEL_TAG,
+                            read_simple_tag(event_reader, &name)?,
+                        )?)
+                    }
+                    _ => skip_unexpected_child(event_reader, ALGORITHM_PROPERTIES_TAG, &name)?,
+                },
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
+            }
+        }
+
+        Ok(Self {
+            primitive,
+            parameter_set_identifier,
+            curve,
+            execution_environment,
+            implementation_platform,
+            certification_level,
+            mode,
+            padding,
+            crypto_functions,
+            classical_security_level,
+            nist_quantum_security_level,
+        })
+    }
+}
+
+// #definitions/component/cryptoProperties/certificateProperties
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CertificateProperties {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issuer_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not_valid_before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not_valid_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature_algorithm_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject_public_key_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    certificate_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    certificate_extension: Option<String>,
+}
+
+impl From<models::CertificateProperties> for CertificateProperties {
+    fn from(other: models::CertificateProperties) -> Self {
+        Self {
+            subject_name: other.subject_name,
+            issuer_name: other.issuer_name,
+            not_valid_before: other.not_valid_before.map(|date| date.to_string()),
+            not_valid_after: other.not_valid_after.map(|date| date.to_string()),
+            signature_algorithm_ref: other.signature_algorithm_ref,
+            subject_public_key_ref: other.subject_public_key_ref,
+            certificate_format: other.certificate_format,
+            certificate_extension: other.certificate_extension,
+        }
+    }
+}
+
+impl From<CertificateProperties> for models::CertificateProperties {
+    fn from(other: CertificateProperties) -> Self {
+        Self {
+            subject_name: other.subject_name,
+            issuer_name: other.issuer_name,
+            not_valid_before: other.not_valid_before.map(DateTime),
+            not_valid_after: other.not_valid_after.map(DateTime),
+            signature_algorithm_ref: other.signature_algorithm_ref,
+            subject_public_key_ref: other.subject_public_key_ref,
+            certificate_format: other.certificate_format,
+            certificate_extension: other.certificate_extension,
+        }
+    }
+}
+
+const SUBJECT_NAME_TAG: &str = "subjectName";
+const ISSUER_NAME_TAG: &str = "issuerName";
+const NOT_VALID_BEFORE_TAG: &str = "notValidBefore";
+const NOT_VALID_AFTER_TAG: &str = "notValidAfter";
+const SIGNATURE_ALGORITHM_REF_TAG: &str = "signatureAlgorithmRef";
+const SUBJECT_PUBLIC_KEY_REF_TAG: &str = "subjectPublicKeyRef";
+const CERTIFICATE_FORMAT_TAG: &str = "certificateFormat";
+const CERTIFICATE_EXTENSION_TAG: &str = "certificateExtension";
+
+xml_element! {
+    CertificateProperties = CERTIFICATE_PROPERTIES_TAG {
+        SUBJECT_NAME_TAG => subject_name: String,
+        ISSUER_NAME_TAG => issuer_name: String,
+        NOT_VALID_BEFORE_TAG => not_valid_before: String,
+        NOT_VALID_AFTER_TAG => not_valid_after: String,
+        SIGNATURE_ALGORITHM_REF_TAG => signature_algorithm_ref: String,
+        SUBJECT_PUBLIC_KEY_REF_TAG => subject_public_key_ref: String,
+        CERTIFICATE_FORMAT_TAG => certificate_format: String,
+        CERTIFICATE_EXTENSION_TAG => certificate_extension: String,
+    }
+}
+
+// #definitions/component/cryptoProperties/relatedCryptoMaterialProperties
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RelatedCryptoMaterialProperties {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    material_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    algorithm_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    creation_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    activation_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    update_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiration_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secured_by: Option<SecuredBy>,
+}
+
+impl From<models::RelatedCryptoMaterialProperties> for RelatedCryptoMaterialProperties {
+    fn from(other: models::RelatedCryptoMaterialProperties) -> Self {
+        Self {
+            material_type: other.material_type.map(|material| material.to_string()),
+            id: other.id,
+            state: other.state.map(|state| state.to_string()),
+            algorithm_ref: other.algorithm_ref,
+            creation_date: other.creation_date.map(|date| date.to_string()),
+            activation_date: other.activation_date.map(|date| date.to_string()),
+            update_date: other.update_date.map(|date| date.to_string()),
+            expiration_date: other.expiration_date.map(|date| date.to_string()),
+            value: other.value,
+            size: other.size,
+            format: other.format,
+            secured_by: convert_optional(other.secured_by),
+        }
+    }
+}
+
+impl From<RelatedCryptoMaterialProperties> for models::RelatedCryptoMaterialProperties {
+    fn from(other: RelatedCryptoMaterialProperties) -> Self {
+        Self {
+            material_type: other
+                .material_type
+                .map(models::RelatedCryptoMaterialType::new_unchecked),
+            id: other.id,
+            state: other
+                .state
+                .map(models::RelatedCryptoMaterialState::new_unchecked),
+            algorithm_ref: other.algorithm_ref,
+            creation_date: other.creation_date.map(DateTime),
+            activation_date: other.activation_date.map(DateTime),
+            update_date: other.update_date.map(DateTime),
+            expiration_date: other.expiration_date.map(DateTime),
+            value: other.value,
+            size: other.size,
+            format: other.format,
+            secured_by: convert_optional(other.secured_by),
+        }
+    }
+}
+
+const TYPE_TAG: &str = "type";
+const ID_TAG: &str = "id";
+const STATE_TAG: &str = "state";
+const ALGORITHM_REF_TAG: &str = "algorithmRef";
+const CREATION_DATE_TAG: &str = "creationDate";
+const ACTIVATION_DATE_TAG: &str = "activationDate";
+const UPDATE_DATE_TAG: &str = "updateDate";
+const EXPIRATION_DATE_TAG: &str = "expirationDate";
+const VALUE_TAG: &str = "value";
+const SIZE_TAG: &str = "size";
+const FORMAT_TAG: &str = "format";
+const SECURED_BY_TAG: &str = "securedBy";
+
+xml_element! {
+    RelatedCryptoMaterialProperties = RELATED_CRYPTO_MATERIAL_PROPERTIES_TAG {
+        TYPE_TAG => material_type: String,
+        ID_TAG => id: String,
+        STATE_TAG => state: String,
+        ALGORITHM_REF_TAG => algorithm_ref: String,
+        CREATION_DATE_TAG => creation_date: String,
+        ACTIVATION_DATE_TAG => activation_date: String,
+        UPDATE_DATE_TAG => update_date: String,
+        EXPIRATION_DATE_TAG => expiration_date: String,
+        VALUE_TAG => value: String,
+        SIZE_TAG => size: u32,
+        FORMAT_TAG => format: String,
+        SECURED_BY_TAG => secured_by: SecuredBy,
+    }
+}
+
+// #definitions/component/cryptoProperties/relatedCryptoMaterialProperties/securedBy
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SecuredBy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mechanism: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    algorithm_ref: Option<String>,
+}
+
+impl From<models::SecuredBy> for SecuredBy {
+    fn from(other: models::SecuredBy) -> Self {
+        Self {
+            mechanism: other.mechanism,
+            algorithm_ref: other.algorithm_ref,
+        }
+    }
+}
+
+impl From<SecuredBy> for models::SecuredBy {
+    fn from(other: SecuredBy) -> Self {
+        Self {
+            mechanism: other.mechanism,
+            algorithm_ref: other.algorithm_ref,
+        }
+    }
+}
+
+const MECHANISM_TAG: &str = "mechanism";
+
+xml_element! {
+    SecuredBy = SECURED_BY_TAG {
+        MECHANISM_TAG => mechanism: String,
+        ALGORITHM_REF_TAG => algorithm_ref: String,
+    }
+}
+
+// #definitions/component/cryptoProperties/protocolProperties
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProtocolProperties {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    protocol_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cipher_suites: Option<Vec<CipherSuite>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ikev2_transform_types: Option<Ikev2TransformTypes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crypto_ref_array: Option<Vec<String>>,
+}
+
+impl From<models::ProtocolProperties> for ProtocolProperties {
+    fn from(other: models::ProtocolProperties) -> Self {
+        Self {
+            protocol_type: other.protocol_type.map(|protocol| protocol.to_string()),
+            version: other.version,
+            cipher_suites: other.cipher_suites.map(convert_vec),
+            ikev2_transform_types: convert_optional(other.ikev2_transform_types),
+            crypto_ref_array: other.crypto_ref_array,
+        }
+    }
+}
+
+impl From<ProtocolProperties> for models::ProtocolProperties {
+    fn from(other: ProtocolProperties) -> Self {
+        Self {
+            protocol_type: other.protocol_type.map(models::ProtocolType::new_unchecked),
+            version: other.version,
+            cipher_suites: other.cipher_suites.map(convert_vec),
+            ikev2_transform_types: convert_optional(other.ikev2_transform_types),
+            crypto_ref_array: other.crypto_ref_array,
+        }
+    }
+}
+
+const VERSION_TAG: &str = "version";
+const CIPHER_SUITES_TAG: &str = "cipherSuites";
+const IKEV2_TRANSFORM_TYPES_TAG: &str = "ikev2TransformTypes";
+const CRYPTO_REF_ARRAY_TAG: &str = "cryptoRefArray";
+
+elem_tag!(CipherSuiteTag = "cipherSuite");
+elem_tag!(CryptoRefTag = "cryptoRef");
+
+xml_element! {
+    ProtocolProperties = PROTOCOL_PROPERTIES_TAG {
+        TYPE_TAG => protocol_type: String,
+        VERSION_TAG => version: String,
+        CIPHER_SUITES_TAG => cipher_suites: VecXmlReader<CipherSuite, CipherSuiteTag>,
+        IKEV2_TRANSFORM_TYPES_TAG => ikev2_transform_types: Ikev2TransformTypes,
+        CRYPTO_REF_ARRAY_TAG => crypto_ref_array: VecXmlReader<String, CryptoRefTag>,
+    }
+}
+
+// #definitions/component/cryptoProperties/protocolProperties/cipherSuites
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CipherSuite {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    algorithms: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identifiers: Option<Vec<String>>,
+}
+
+impl From<models::CipherSuite> for CipherSuite {
+    fn from(other: models::CipherSuite) -> Self {
+        Self {
+            name: other.name,
+            algorithms: other.algorithms,
+            identifiers: other.identifiers,
+        }
+    }
+}
+
+impl From<CipherSuite> for models::CipherSuite {
+    fn from(other: CipherSuite) -> Self {
+        Self {
+            name: other.name,
+            algorithms: other.algorithms,
+            identifiers: other.identifiers,
+        }
+    }
+}
+
+const CIPHER_SUITE_TAG: &str = "cipherSuite";
+const NAME_TAG: &str = "name";
+const ALGORITHMS_TAG: &str = "algorithms";
+const IDENTIFIERS_TAG: &str = "identifiers";
+
+elem_tag!(AlgorithmTag = "algorithm");
+elem_tag!(IdentifierTag = "identifier");
+
+xml_element! {
+    CipherSuite = CIPHER_SUITE_TAG {
+        NAME_TAG => name: String,
+        ALGORITHMS_TAG => algorithms: VecXmlReader<String, AlgorithmTag>,
+        IDENTIFIERS_TAG => identifiers: VecXmlReader<String, IdentifierTag>,
+    }
+}
+
+// #definitions/component/cryptoProperties/protocolProperties/ikev2TransformTypes
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Ikev2TransformTypes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encr: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prf: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    integ: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ke: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    esn: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auth: Option<Vec<String>>,
+}
+
+impl From<models::Ikev2TransformTypes> for Ikev2TransformTypes {
+    fn from(other: models::Ikev2TransformTypes) -> Self {
+        Self {
+            encr: other.encr,
+            prf: other.prf,
+            integ: other.integ,
+            ke: other.ke,
+            esn: other.esn,
+            auth: other.auth,
+        }
+    }
+}
+
+impl From<Ikev2TransformTypes> for models::Ikev2TransformTypes {
+    fn from(other: Ikev2TransformTypes) -> Self {
+        Self {
+            encr: other.encr,
+            prf: other.prf,
+            integ: other.integ,
+            ke: other.ke,
+            esn: other.esn,
+            auth: other.auth,
+        }
+    }
+}
+
+const ENCR_TAG: &str = "encr";
+const PRF_TAG: &str = "prf";
+const INTEG_TAG: &str = "integ";
+const KE_TAG: &str = "ke";
+const ESN_TAG: &str = "esn";
+const AUTH_TAG: &str = "auth";
+
+impl ToInnerXml for Ikev2TransformTypes {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, tag)?;
+
+        for (tag, algorithms) in [
+            (ENCR_TAG, &self.encr),
+            (PRF_TAG, &self.prf),
+            (INTEG_TAG, &self.integ),
+            (KE_TAG, &self.ke),
+        ] {
+            for algorithm in algorithms.iter().flatten() {
+                write_simple_tag(writer, tag, algorithm)?;
+            }
+        }
+        if let Some(esn) = &self.esn {
+            write_simple_tag(writer, ESN_TAG, &esn.to_string())?;
+        }
+        for algorithm in self.auth.iter().flatten() {
+            write_simple_tag(writer, AUTH_TAG, algorithm)?;
+        }
+
+        write_close_tag(writer, tag)
+    }
+}
+
+impl FromXml for Ikev2TransformTypes {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut encr: Option<Vec<String>> = None;
+        let mut prf: Option<Vec<String>> = None;
+        let mut integ: Option<Vec<String>> = None;
+        let mut ke: Option<Vec<String>> = None;
+        let mut esn: Option<bool> = None;
+        let mut auth: Option<Vec<String>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(IKEV2_TRANSFORM_TYPES_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } => {
+                    let algorithms = match name.local_name.as_str() {
+                        ENCR_TAG => &mut encr,
+                        PRF_TAG => &mut prf,
+                        INTEG_TAG => &mut integ,
+                        KE_TAG => &mut ke,
+                        AUTH_TAG => &mut auth,
+                        ESN_TAG => {
+                            esn = Some(bool::from_xml_value(
+                                ESN_TAG,
+                                read_simple_tag(event_reader, &name)?,
+                            )?);
+                            continue;
+                        }
+                        _ => {
+                            skip_unexpected_child(event_reader, IKEV2_TRANSFORM_TYPES_TAG, &name)?;
+                            continue;
+                        }
+                    };
+                    algorithms
+                        .get_or_insert_with(Vec::new)
+                        .push(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
+            }
+        }
+
+        Ok(Self {
+            encr,
+            prf,
+            integ,
+            ke,
+            esn,
+            auth,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    pub(crate) fn example_crypto_properties() -> CryptoProperties {
+        CryptoProperties {
+            asset_type: "algorithm".to_string(),
+            algorithm_properties: Some(AlgorithmProperties {
+                primitive: Some("kem".to_string()),
+                parameter_set_identifier: Some("768".to_string()),
+                curve: Some("brainpoolP160r1".to_string()),
+                execution_environment: Some("software-plain-ram".to_string()),
+                implementation_platform: Some("x86_64".to_string()),
+                certification_level: Some(vec!["none".to_string()]),
+                mode: Some("cbc".to_string()),
+                padding: Some("pkcs7".to_string()),
+                crypto_functions: Some(vec!["keygen".to_string(), "encapsulate".to_string()]),
+                classical_security_level: Some(128),
+                nist_quantum_security_level: Some(3),
+            }),
+            certificate_properties: Some(CertificateProperties {
+                subject_name: Some("CN=example.com".to_string()),
+                issuer_name: Some("CN=Example CA".to_string()),
+                not_valid_before: Some("2024-01-01T00:00:00Z".to_string()),
+                not_valid_after: Some("2025-01-01T00:00:00Z".to_string()),
+                signature_algorithm_ref: Some("crypto/algorithm/sha-512-rsa".to_string()),
+                subject_public_key_ref: Some("crypto/key/rsa-2048".to_string()),
+                certificate_format: Some("X.509".to_string()),
+                certificate_extension: Some("crt".to_string()),
+            }),
+            related_crypto_material_properties: Some(RelatedCryptoMaterialProperties {
+                material_type: Some("public-key".to_string()),
+                id: Some("2e9ef09e-dfac-4526-96b4-d02f31af1b22".to_string()),
+                state: Some("active".to_string()),
+                algorithm_ref: Some("crypto/algorithm/rsa-2048".to_string()),
+                creation_date: Some("2024-01-01T00:00:00Z".to_string()),
+                activation_date: Some("2024-01-02T00:00:00Z".to_string()),
+                update_date: Some("2024-01-03T00:00:00Z".to_string()),
+                expiration_date: Some("2025-01-01T00:00:00Z".to_string()),
+                value: Some("value".to_string()),
+                size: Some(2048),
+                format: Some("PEM".to_string()),
+                secured_by: Some(SecuredBy {
+                    mechanism: Some("Software".to_string()),
+                    algorithm_ref: Some("crypto/algorithm/aes-128-gcm".to_string()),
+                }),
+            }),
+            protocol_properties: Some(ProtocolProperties {
+                protocol_type: Some("ike".to_string()),
+                version: Some("2.0".to_string()),
+                cipher_suites: Some(vec![CipherSuite {
+                    name: Some("TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256".to_string()),
+                    algorithms: Some(vec!["crypto/algorithm/ecdh-curve25519".to_string()]),
+                    identifiers: Some(vec!["0xC0".to_string(), "0x2F".to_string()]),
+                }]),
+                ikev2_transform_types: Some(Ikev2TransformTypes {
+                    encr: Some(vec!["crypto/algorithm/aes-128-gcm".to_string()]),
+                    prf: Some(vec!["crypto/algorithm/sha-256".to_string()]),
+                    integ: Some(vec!["crypto/algorithm/hmac-sha-256".to_string()]),
+                    ke: Some(vec!["crypto/algorithm/ecdh-curve25519".to_string()]),
+                    esn: Some(true),
+                    auth: Some(vec!["crypto/algorithm/rsa-2048".to_string()]),
+                }),
+                crypto_ref_array: Some(vec!["crypto/certificate/example.com".to_string()]),
+            }),
+            oid: Some("2.16.840.1.101.3.4.4.2".to_string()),
+        }
+    }
+
+    pub(crate) fn corresponding_crypto_properties() -> models::CryptoProperties {
+        models::CryptoProperties {
+            asset_type: models::CryptoAssetType::Algorithm,
+            algorithm_properties: Some(models::AlgorithmProperties {
+                primitive: Some(models::CryptoPrimitive::Kem),
+                parameter_set_identifier: Some("768".to_string()),
+                curve: Some("brainpoolP160r1".to_string()),
+                execution_environment: Some(models::ExecutionEnvironment::SoftwarePlainRam),
+                implementation_platform: Some(models::ImplementationPlatform::X86_64),
+                certification_level: Some(vec![models::CertificationLevel::None]),
+                mode: Some(models::CryptoMode::Cbc),
+                padding: Some(models::CryptoPadding::Pkcs7),
+                crypto_functions: Some(vec![
+                    models::CryptoFunction::Keygen,
+                    models::CryptoFunction::Encapsulate,
+                ]),
+                classical_security_level: Some(128),
+                nist_quantum_security_level: Some(3),
+            }),
+            certificate_properties: Some(models::CertificateProperties {
+                subject_name: Some("CN=example.com".to_string()),
+                issuer_name: Some("CN=Example CA".to_string()),
+                not_valid_before: Some(DateTime("2024-01-01T00:00:00Z".to_string())),
+                not_valid_after: Some(DateTime("2025-01-01T00:00:00Z".to_string())),
+                signature_algorithm_ref: Some("crypto/algorithm/sha-512-rsa".to_string()),
+                subject_public_key_ref: Some("crypto/key/rsa-2048".to_string()),
+                certificate_format: Some("X.509".to_string()),
+                certificate_extension: Some("crt".to_string()),
+            }),
+            related_crypto_material_properties: Some(models::RelatedCryptoMaterialProperties {
+                material_type: Some(models::RelatedCryptoMaterialType::PublicKey),
+                id: Some("2e9ef09e-dfac-4526-96b4-d02f31af1b22".to_string()),
+                state: Some(models::RelatedCryptoMaterialState::Active),
+                algorithm_ref: Some("crypto/algorithm/rsa-2048".to_string()),
+                creation_date: Some(DateTime("2024-01-01T00:00:00Z".to_string())),
+                activation_date: Some(DateTime("2024-01-02T00:00:00Z".to_string())),
+                update_date: Some(DateTime("2024-01-03T00:00:00Z".to_string())),
+                expiration_date: Some(DateTime("2025-01-01T00:00:00Z".to_string())),
+                value: Some("value".to_string()),
+                size: Some(2048),
+                format: Some("PEM".to_string()),
+                secured_by: Some(models::SecuredBy {
+                    mechanism: Some("Software".to_string()),
+                    algorithm_ref: Some("crypto/algorithm/aes-128-gcm".to_string()),
+                }),
+            }),
+            protocol_properties: Some(models::ProtocolProperties {
+                protocol_type: Some(models::ProtocolType::Ike),
+                version: Some("2.0".to_string()),
+                cipher_suites: Some(vec![models::CipherSuite {
+                    name: Some("TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256".to_string()),
+                    algorithms: Some(vec!["crypto/algorithm/ecdh-curve25519".to_string()]),
+                    identifiers: Some(vec!["0xC0".to_string(), "0x2F".to_string()]),
+                }]),
+                ikev2_transform_types: Some(models::Ikev2TransformTypes {
+                    encr: Some(vec!["crypto/algorithm/aes-128-gcm".to_string()]),
+                    prf: Some(vec!["crypto/algorithm/sha-256".to_string()]),
+                    integ: Some(vec!["crypto/algorithm/hmac-sha-256".to_string()]),
+                    ke: Some(vec!["crypto/algorithm/ecdh-curve25519".to_string()]),
+                    esn: Some(true),
+                    auth: Some(vec!["crypto/algorithm/rsa-2048".to_string()]),
+                }),
+                crypto_ref_array: Some(vec!["crypto/certificate/example.com".to_string()]),
+            }),
+            oid: Some("2.16.840.1.101.3.4.4.2".to_string()),
+        }
+    }
+
+    #[test]
+    fn it_should_convert_to_the_model() {
+        let actual: models::CryptoProperties = example_crypto_properties().into();
+        assert_eq!(actual, corresponding_crypto_properties());
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_crypto_properties());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<cryptoProperties>
+  <assetType>algorithm</assetType>
+  <algorithmProperties>
+    <primitive>kem</primitive>
+    <parameterSetIdentifier>768</parameterSetIdentifier>
+    <curve>brainpoolP160r1</curve>
+    <executionEnvironment>software-plain-ram</executionEnvironment>
+    <implementationPlatform>x86_64</implementationPlatform>
+    <certificationLevel>none</certificationLevel>
+    <mode>cbc</mode>
+    <padding>pkcs7</padding>
+    <cryptoFunctions>
+      <cryptoFunction>keygen</cryptoFunction>
+      <cryptoFunction>encapsulate</cryptoFunction>
+    </cryptoFunctions>
+    <classicalSecurityLevel>128</classicalSecurityLevel>
+    <nistQuantumSecurityLevel>3</nistQuantumSecurityLevel>
+  </algorithmProperties>
+  <certificateProperties>
+    <subjectName>CN=example.com</subjectName>
+    <issuerName>CN=Example CA</issuerName>
+    <notValidBefore>2024-01-01T00:00:00Z</notValidBefore>
+    <notValidAfter>2025-01-01T00:00:00Z</notValidAfter>
+    <signatureAlgorithmRef>crypto/algorithm/sha-512-rsa</signatureAlgorithmRef>
+    <subjectPublicKeyRef>crypto/key/rsa-2048</subjectPublicKeyRef>
+    <certificateFormat>X.509</certificateFormat>
+    <certificateExtension>crt</certificateExtension>
+  </certificateProperties>
+  <relatedCryptoMaterialProperties>
+    <type>public-key</type>
+    <id>2e9ef09e-dfac-4526-96b4-d02f31af1b22</id>
+    <state>active</state>
+    <algorithmRef>crypto/algorithm/rsa-2048</algorithmRef>
+    <creationDate>2024-01-01T00:00:00Z</creationDate>
+    <activationDate>2024-01-02T00:00:00Z</activationDate>
+    <updateDate>2024-01-03T00:00:00Z</updateDate>
+    <expirationDate>2025-01-01T00:00:00Z</expirationDate>
+    <value>value</value>
+    <size>2048</size>
+    <format>PEM</format>
+    <securedBy>
+      <mechanism>Software</mechanism>
+      <algorithmRef>crypto/algorithm/aes-128-gcm</algorithmRef>
+    </securedBy>
+  </relatedCryptoMaterialProperties>
+  <protocolProperties>
+    <type>ike</type>
+    <version>2.0</version>
+    <cipherSuites>
+      <cipherSuite>
+        <name>TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256</name>
+        <algorithms>
+          <algorithm>crypto/algorithm/ecdh-curve25519</algorithm>
+        </algorithms>
+        <identifiers>
+          <identifier>0xC0</identifier>
+          <identifier>0x2F</identifier>
+        </identifiers>
+      </cipherSuite>
+    </cipherSuites>
+    <ikev2TransformTypes>
+      <encr>crypto/algorithm/aes-128-gcm</encr>
+      <prf>crypto/algorithm/sha-256</prf>
+      <integ>crypto/algorithm/hmac-sha-256</integ>
+      <ke>crypto/algorithm/ecdh-curve25519</ke>
+      <esn>true</esn>
+      <auth>crypto/algorithm/rsa-2048</auth>
+    </ikev2TransformTypes>
+    <cryptoRefArray>
+      <cryptoRef>crypto/certificate/example.com</cryptoRef>
+    </cryptoRefArray>
+  </protocolProperties>
+  <oid>2.16.840.1.101.3.4.4.2</oid>
+</cryptoProperties>
+"#;
+        let actual: CryptoProperties = read_element_from_string(input);
+        let expected = example_crypto_properties();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,1645 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer};
+
+use crate::{
+    elem_tag,
+    errors::{BomError, XmlReadError, XmlWriteError},
+    external_models::{date_time::DateTime, uri::Uri},
+    get_elements,
+    models::{self, declarations as models_declarations},
+    specs::common::{
+        organization::{OrganizationalContact, OrganizationalEntity},
+        signature::Signature,
+    },
+    utilities::{convert_optional, convert_optional_vec, try_convert_optional},
+    xml::{
+        attribute_or_error, read_list_tag, read_simple_tag, skip_unexpected_element,
+        to_xml_read_error, to_xml_write_error, write_close_tag, write_list_string_tag,
+        write_list_tag, write_simple_option_tag, write_simple_tag, write_start_tag, FromXml,
+        ToInnerXml, ToXml, VecXmlReader,
+    },
+    xml_element,
+};
+
+use super::{
+    attachment::Attachment,
+    component::Components,
+    data_governance::DataGovernance,
+    external_reference::{ExternalReference, ExternalReferences},
+    service::Services,
+};
+
+// #declarations
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Declarations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assessors: Option<Vec<Assessor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attestations: Option<Vec<Attestation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    claims: Option<Vec<Claim>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    evidence: Option<Vec<DeclarationEvidence>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    targets: Option<Targets>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    affirmation: Option<Affirmation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+}
+
+impl TryFrom<models_declarations::Declarations> for Declarations {
+    type Error = BomError;
+
+    fn try_from(other: models_declarations::Declarations) -> Result<Self, Self::Error> {
+        Ok(Self {
+            assessors: convert_optional_vec(other.assessors),
+            attestations: convert_optional_vec(other.attestations),
+            claims: convert_optional_vec(other.claims),
+            evidence: convert_optional_vec(other.evidence),
+            targets: try_convert_optional(other.targets)?,
+            affirmation: convert_optional(other.affirmation),
+            signature: convert_optional(other.signature),
+        })
+    }
+}
+
+impl From<Declarations> for models_declarations::Declarations {
+    fn from(other: Declarations) -> Self {
+        Self {
+            assessors: convert_optional_vec(other.assessors),
+            attestations: convert_optional_vec(other.attestations),
+            claims: convert_optional_vec(other.claims),
+            evidence: convert_optional_vec(other.evidence),
+            targets: convert_optional(other.targets),
+            affirmation: convert_optional(other.affirmation),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+const DECLARATIONS_TAG: &str = "declarations";
+const ASSESSORS_TAG: &str = "assessors";
+const ATTESTATIONS_TAG: &str = "attestations";
+const CLAIMS_TAG: &str = "claims";
+const EVIDENCE_TAG: &str = "evidence";
+const TARGETS_TAG: &str = "targets";
+const AFFIRMATION_TAG: &str = "affirmation";
+const SIGNATURE_TAG: &str = "signature";
+const BOM_REF_ATTR: &str = "bom-ref";
+
+elem_tag!(AssessorTag = "assessor");
+elem_tag!(AttestationTag = "attestation");
+elem_tag!(ClaimTag = "claim");
+elem_tag!(EvidenceTag = "evidence");
+
+impl ToXml for Declarations {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, DECLARATIONS_TAG)?;
+
+        if let Some(assessors) = &self.assessors {
+            write_list_tag(writer, ASSESSORS_TAG, assessors)?;
+        }
+        if let Some(attestations) = &self.attestations {
+            write_list_tag(writer, ATTESTATIONS_TAG, attestations)?;
+        }
+        if let Some(claims) = &self.claims {
+            write_list_tag(writer, CLAIMS_TAG, claims)?;
+        }
+        if let Some(evidence) = &self.evidence {
+            write_list_tag(writer, EVIDENCE_TAG, evidence)?;
+        }
+        self.targets.write_xml_element(writer)?;
+        self.affirmation.write_xml_element(writer)?;
+        self.signature.write_xml_element(writer)?;
+
+        write_close_tag(writer, DECLARATIONS_TAG)
+    }
+}
+
+impl FromXml for Declarations {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        get_elements! {
+            event_reader, element_name,
+            ASSESSORS_TAG => assessors: VecXmlReader<Assessor, AssessorTag>,
+            ATTESTATIONS_TAG => attestations: VecXmlReader<Attestation, AttestationTag>,
+            CLAIMS_TAG => claims: VecXmlReader<Claim, ClaimTag>,
+            EVIDENCE_TAG => evidence: VecXmlReader<DeclarationEvidence, EvidenceTag>,
+            TARGETS_TAG => targets: Targets,
+            AFFIRMATION_TAG => affirmation: Affirmation,
+            SIGNATURE_TAG => signature: Signature,
+        };
+
+        Ok(Self {
+            assessors: assessors.map(Vec::from),
+            attestations: attestations.map(Vec::from),
+            claims: claims.map(Vec::from),
+            evidence: evidence.map(Vec::from),
+            targets,
+            affirmation,
+            signature,
+        })
+    }
+}
+
+// #declarations/assessors
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Assessor {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    third_party: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<OrganizationalEntity>,
+}
+
+impl From<models_declarations::Assessor> for Assessor {
+    fn from(other: models_declarations::Assessor) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(|bom_ref| bom_ref.0),
+            third_party: other.third_party,
+            organization: convert_optional(other.organization),
+        }
+    }
+}
+
+impl From<Assessor> for models_declarations::Assessor {
+    fn from(other: Assessor) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(models::bom::BomReference),
+            third_party: other.third_party,
+            organization: convert_optional(other.organization),
+        }
+    }
+}
+
+const ASSESSOR_TAG: &str = "assessor";
+const THIRD_PARTY_TAG: &str = "thirdParty";
+const ORGANIZATION_TAG: &str = "organization";
+
+impl ToXml for Assessor {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let mut start_element = writer::XmlEvent::start_element(ASSESSOR_TAG);
+        if let Some(bom_ref) = &self.bom_ref {
+            start_element = start_element.attr(BOM_REF_ATTR, bom_ref);
+        }
+        writer
+            .write(start_element)
+            .map_err(to_xml_write_error(ASSESSOR_TAG))?;
+
+        if let Some(third_party) = &self.third_party {
+            write_simple_tag(writer, THIRD_PARTY_TAG, &third_party.to_string())?;
+        }
+        self.organization
+            .write_xml_named_element(writer, ORGANIZATION_TAG)?;
+
+        write_close_tag(writer, ASSESSOR_TAG)
+    }
+}
+
+impl FromXml for Assessor {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR).ok();
+
+        get_elements! {
+            event_reader, element_name,
+            THIRD_PARTY_TAG => third_party: bool,
+            ORGANIZATION_TAG => organization: OrganizationalEntity,
+        };
+
+        Ok(Self {
+            bom_ref,
+            third_party,
+            organization,
+        })
+    }
+}
+
+// #declarations/attestations
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Attestation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assessor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    map: Option<Vec<AttestationMap>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+}
+
+impl From<models_declarations::Attestation> for Attestation {
+    fn from(other: models_declarations::Attestation) -> Self {
+        Self {
+            summary: other.summary,
+            assessor: other.assessor,
+            map: convert_optional_vec(other.map),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+impl From<Attestation> for models_declarations::Attestation {
+    fn from(other: Attestation) -> Self {
+        Self {
+            summary: other.summary,
+            assessor: other.assessor,
+            map: convert_optional_vec(other.map),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+const ATTESTATION_TAG: &str = "attestation";
+const SUMMARY_TAG: &str = "summary";
+const MAP_TAG: &str = "map";
+
+impl ToXml for Attestation {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, ATTESTATION_TAG)?;
+
+        write_simple_option_tag(writer, SUMMARY_TAG, &self.summary)?;
+        write_simple_option_tag(writer, ASSESSOR_TAG, &self.assessor)?;
+        for map in self.map.iter().flatten() {
+            map.write_xml_element(writer)?;
+        }
+        self.signature.write_xml_element(writer)?;
+
+        write_close_tag(writer, ATTESTATION_TAG)
+    }
+}
+
+impl FromXml for Attestation {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut summary: Option<String> = None;
+        let mut assessor: Option<String> = None;
+        let mut map: Option<Vec<AttestationMap>> = None;
+        let mut signature: Option<Signature> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ATTESTATION_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == SUMMARY_TAG => {
+                    summary = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == ASSESSOR_TAG => {
+                    assessor = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == MAP_TAG => {
+                    map.get_or_insert_with(Vec::new)
+                        .push(AttestationMap::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SIGNATURE_TAG => {
+                    signature = Some(Signature::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
+            }
+        }
+
+        Ok(Self {
+            summary,
+            assessor,
+            map,
+            signature,
+        })
+    }
+}
+
+// #declarations/attestations/map
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AttestationMap {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requirement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    claims: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counter_claims: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conformance: Option<Conformance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confidence: Option<Confidence>,
+}
+
+impl From<models_declarations::AttestationMap> for AttestationMap {
+    fn from(other: models_declarations::AttestationMap) -> Self {
+        Self {
+            requirement: other.requirement,
+            claims: other.claims,
+            counter_claims: other.counter_claims,
+            conformance: convert_optional(other.conformance),
+            confidence: convert_optional(other.confidence),
+        }
+    }
+}
+
+impl From<AttestationMap> for models_declarations::AttestationMap {
+    fn from(other: AttestationMap) -> Self {
+        Self {
+            requirement: other.requirement,
+            claims: other.claims,
+            counter_claims: other.counter_claims,
+            conformance: convert_optional(other.conformance),
+            confidence: convert_optional(other.confidence),
+        }
+    }
+}
+
+const REQUIREMENT_TAG: &str = "requirement";
+const CLAIM_TAG: &str = "claim";
+const COUNTER_CLAIMS_TAG: &str = "counterClaims";
+const CONFORMANCE_TAG: &str = "conformance";
+const CONFIDENCE_TAG: &str = "confidence";
+
+elem_tag!(CounterClaimTag = "counterClaim");
+
+xml_element! {
+    AttestationMap = MAP_TAG {
+        REQUIREMENT_TAG => requirement: String,
+        CLAIMS_TAG => claims: VecXmlReader<String, ClaimTag>,
+        COUNTER_CLAIMS_TAG => counter_claims: VecXmlReader<String, CounterClaimTag>,
+        CONFORMANCE_TAG => conformance: Conformance,
+        CONFIDENCE_TAG => confidence: Confidence,
+    }
+}
+
+// #declarations/attestations/map/conformance
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Conformance {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rationale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mitigation_strategies: Option<Vec<String>>,
+}
+
+impl From<models_declarations::Conformance> for Conformance {
+    fn from(other: models_declarations::Conformance) -> Self {
+        Self {
+            score: convert_optional(other.score),
+            rationale: other.rationale,
+            mitigation_strategies: other.mitigation_strategies,
+        }
+    }
+}
+
+impl From<Conformance> for models_declarations::Conformance {
+    fn from(other: Conformance) -> Self {
+        Self {
+            score: convert_optional(other.score),
+            rationale: other.rationale,
+            mitigation_strategies: other.mitigation_strategies,
+        }
+    }
+}
+
+const SCORE_TAG: &str = "score";
+const RATIONALE_TAG: &str = "rationale";
+const MITIGATION_STRATEGIES_TAG: &str = "mitigationStrategies";
+const MITIGATION_STRATEGY_TAG: &str = "mitigationStrategy";
+
+elem_tag!(MitigationStrategyTag = "mitigationStrategy");
+
+xml_element! {
+    Conformance = CONFORMANCE_TAG {
+        SCORE_TAG => score: f32,
+        RATIONALE_TAG => rationale: String,
+        MITIGATION_STRATEGIES_TAG => mitigation_strategies: VecXmlReader<String, MitigationStrategyTag>,
+    }
+}
+
+// #declarations/attestations/map/confidence
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Confidence {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rationale: Option<String>,
+}
+
+impl From<models_declarations::Confidence> for Confidence {
+    fn from(other: models_declarations::Confidence) -> Self {
+        Self {
+            score: convert_optional(other.score),
+            rationale: other.rationale,
+        }
+    }
+}
+
+impl From<Confidence> for models_declarations::Confidence {
+    fn from(other: Confidence) -> Self {
+        Self {
+            score: convert_optional(other.score),
+            rationale: other.rationale,
+        }
+    }
+}
+
+xml_element! {
+    Confidence = CONFIDENCE_TAG {
+        SCORE_TAG => score: f32,
+        RATIONALE_TAG => rationale: String,
+    }
+}
+
+// #declarations/claims
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Claim {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    predicate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mitigation_strategies: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reasoning: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    evidence: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counter_evidence: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_references: Option<ExternalReferences>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+}
+
+impl From<models_declarations::Claim> for Claim {
+    fn from(other: models_declarations::Claim) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(|bom_ref| bom_ref.0),
+            target: other.target,
+            predicate: other.predicate,
+            mitigation_strategies: other.mitigation_strategies,
+            reasoning: other.reasoning,
+            evidence: other.evidence,
+            counter_evidence: other.counter_evidence,
+            external_references: convert_optional(other.external_references),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+impl From<Claim> for models_declarations::Claim {
+    fn from(other: Claim) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(models::bom::BomReference),
+            target: other.target,
+            predicate: other.predicate,
+            mitigation_strategies: other.mitigation_strategies,
+            reasoning: other.reasoning,
+            evidence: other.evidence,
+            counter_evidence: other.counter_evidence,
+            external_references: convert_optional(other.external_references),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+const TARGET_TAG: &str = "target";
+const PREDICATE_TAG: &str = "predicate";
+const REASONING_TAG: &str = "reasoning";
+const COUNTER_EVIDENCE_TAG: &str = "counterEvidence";
+const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
+
+impl ToXml for Claim {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let mut start_element = writer::XmlEvent::start_element(CLAIM_TAG);
+        if let Some(bom_ref) = &self.bom_ref {
+            start_element = start_element.attr(BOM_REF_ATTR, bom_ref);
+        }
+        writer
+            .write(start_element)
+            .map_err(to_xml_write_error(CLAIM_TAG))?;
+
+        write_simple_option_tag(writer, TARGET_TAG, &self.target)?;
+        write_simple_option_tag(writer, PREDICATE_TAG, &self.predicate)?;
+        if let Some(mitigation_strategies) = &self.mitigation_strategies {
+            write_list_string_tag(
+                writer,
+                MITIGATION_STRATEGIES_TAG,
+                MITIGATION_STRATEGY_TAG,
+                mitigation_strategies,
+            )?;
+        }
+        write_simple_option_tag(writer, REASONING_TAG, &self.reasoning)?;
+        for evidence in self.evidence.iter().flatten() {
+            write_simple_tag(writer, EVIDENCE_TAG, evidence)?;
+        }
+        for counter_evidence in self.counter_evidence.iter().flatten() {
+            write_simple_tag(writer, COUNTER_EVIDENCE_TAG, counter_evidence)?;
+        }
+        self.external_references.write_xml_element(writer)?;
+        self.signature.write_xml_element(writer)?;
+
+        write_close_tag(writer, CLAIM_TAG)
+    }
+}
+
+impl FromXml for Claim {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR).ok();
+        let mut target: Option<String> = None;
+        let mut predicate: Option<String> = None;
+        let mut mitigation_strategies: Option<Vec<String>> = None;
+        let mut reasoning: Option<String> = None;
+        let mut evidence: Option<Vec<String>> = None;
+        let mut counter_evidence: Option<Vec<String>> = None;
+        let mut external_references: Option<ExternalReferences> = None;
+        let mut signature: Option<Signature> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(CLAIM_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TARGET_TAG => {
+                    target = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == PREDICATE_TAG => {
+                    predicate = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == MITIGATION_STRATEGIES_TAG =>
+                {
+                    mitigation_strategies =
+                        Some(read_list_tag(event_reader, &name, MITIGATION_STRATEGY_TAG)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == REASONING_TAG => {
+                    reasoning = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == EVIDENCE_TAG => {
+                    evidence
+                        .get_or_insert_with(Vec::new)
+                        .push(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == COUNTER_EVIDENCE_TAG =>
+                {
+                    counter_evidence
+                        .get_or_insert_with(Vec::new)
+                        .push(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == EXTERNAL_REFERENCES_TAG => {
+                    external_references = Some(ExternalReferences::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SIGNATURE_TAG => {
+                    signature = Some(Signature::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
+            }
+        }
+
+        Ok(Self {
+            bom_ref,
+            target,
+            predicate,
+            mitigation_strategies,
+            reasoning,
+            evidence,
+            counter_evidence,
+            external_references,
+            signature,
+        })
+    }
+}
+
+// #declarations/evidence
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DeclarationEvidence {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    property_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<Vec<EvidenceData>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<OrganizationalContact>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reviewer: Option<OrganizationalContact>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+}
+
+impl From<models_declarations::DeclarationEvidence> for DeclarationEvidence {
+    fn from(other: models_declarations::DeclarationEvidence) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(|bom_ref| bom_ref.0),
+            property_name: other.property_name,
+            description: other.description,
+            data: convert_optional_vec(other.data),
+            created: other.created.map(|created| created.to_string()),
+            expires: other.expires.map(|expires| expires.to_string()),
+            author: convert_optional(other.author),
+            reviewer: convert_optional(other.reviewer),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+impl From<DeclarationEvidence> for models_declarations::DeclarationEvidence {
+    fn from(other: DeclarationEvidence) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(models::bom::BomReference),
+            property_name: other.property_name,
+            description: other.description,
+            data: convert_optional_vec(other.data),
+            created: other.created.map(DateTime),
+            expires: other.expires.map(DateTime),
+            author: convert_optional(other.author),
+            reviewer: convert_optional(other.reviewer),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+const PROPERTY_NAME_TAG: &str = "propertyName";
+const DESCRIPTION_TAG: &str = "description";
+const DATA_TAG: &str = "data";
+const CREATED_TAG: &str = "created";
+const EXPIRES_TAG: &str = "expires";
+const AUTHOR_TAG: &str = "author";
+const REVIEWER_TAG: &str = "reviewer";
+
+impl ToXml for DeclarationEvidence {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let mut start_element = writer::XmlEvent::start_element(EVIDENCE_TAG);
+        if let Some(bom_ref) = &self.bom_ref {
+            start_element = start_element.attr(BOM_REF_ATTR, bom_ref);
+        }
+        writer
+            .write(start_element)
+            .map_err(to_xml_write_error(EVIDENCE_TAG))?;
+
+        write_simple_option_tag(writer, PROPERTY_NAME_TAG, &self.property_name)?;
+        write_simple_option_tag(writer, DESCRIPTION_TAG, &self.description)?;
+        for data in self.data.iter().flatten() {
+            data.write_xml_element(writer)?;
+        }
+        write_simple_option_tag(writer, CREATED_TAG, &self.created)?;
+        write_simple_option_tag(writer, EXPIRES_TAG, &self.expires)?;
+        self.author.write_xml_named_element(writer, AUTHOR_TAG)?;
+        self.reviewer
+            .write_xml_named_element(writer, REVIEWER_TAG)?;
+        self.signature.write_xml_element(writer)?;
+
+        write_close_tag(writer, EVIDENCE_TAG)
+    }
+}
+
+impl FromXml for DeclarationEvidence {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR).ok();
+        let mut property_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut data: Option<Vec<EvidenceData>> = None;
+        let mut created: Option<String> = None;
+        let mut expires: Option<String> = None;
+        let mut author: Option<OrganizationalContact> = None;
+        let mut reviewer: Option<OrganizationalContact> = None;
+        let mut signature: Option<Signature> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(EVIDENCE_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == PROPERTY_NAME_TAG =>
+                {
+                    property_name = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == DATA_TAG => {
+                    data.get_or_insert_with(Vec::new)
+                        .push(EvidenceData::read_xml_element(
+                            event_reader,
+                            &name,
+                            &attributes,
+                        )?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == CREATED_TAG => {
+                    created = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == EXPIRES_TAG => {
+                    expires = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == AUTHOR_TAG => {
+                    author = Some(OrganizationalContact::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == REVIEWER_TAG => {
+                    reviewer = Some(OrganizationalContact::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SIGNATURE_TAG => {
+                    signature = Some(Signature::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
+            }
+        }
+
+        Ok(Self {
+            bom_ref,
+            property_name,
+            description,
+            data,
+            created,
+            expires,
+            author,
+            reviewer,
+            signature,
+        })
+    }
+}
+
+// #declarations/evidence/data
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EvidenceData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contents: Option<EvidenceContents>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sensitive_data: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    governance: Option<DataGovernance>,
+}
+
+impl From<models_declarations::EvidenceData> for EvidenceData {
+    fn from(other: models_declarations::EvidenceData) -> Self {
+        Self {
+            name: other.name,
+            contents: convert_optional(other.contents),
+            classification: other.classification,
+            sensitive_data: other.sensitive_data,
+            governance: convert_optional(other.governance),
+        }
+    }
+}
+
+impl From<EvidenceData> for models_declarations::EvidenceData {
+    fn from(other: EvidenceData) -> Self {
+        Self {
+            name: other.name,
+            contents: convert_optional(other.contents),
+            classification: other.classification,
+            sensitive_data: other.sensitive_data,
+            governance: convert_optional(other.governance),
+        }
+    }
+}
+
+const NAME_TAG: &str = "name";
+const CONTENTS_TAG: &str = "contents";
+const CLASSIFICATION_TAG: &str = "classification";
+const SENSITIVE_DATA_TAG: &str = "sensitiveData";
+const GOVERNANCE_TAG: &str = "governance";
+
+impl ToXml for EvidenceData {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, DATA_TAG)?;
+
+        write_simple_option_tag(writer, NAME_TAG, &self.name)?;
+        self.contents.write_xml_element(writer)?;
+        write_simple_option_tag(writer, CLASSIFICATION_TAG, &self.classification)?;
+        for sensitive_data in self.sensitive_data.iter().flatten() {
+            write_simple_tag(writer, SENSITIVE_DATA_TAG, sensitive_data)?;
+        }
+        self.governance
+            .write_xml_named_element(writer, GOVERNANCE_TAG)?;
+
+        write_close_tag(writer, DATA_TAG)
+    }
+}
+
+impl FromXml for EvidenceData {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut name: Option<String> = None;
+        let mut contents: Option<EvidenceContents> = None;
+        let mut classification: Option<String> = None;
+        let mut sensitive_data: Option<Vec<String>> = None;
+        let mut governance: Option<DataGovernance> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(DATA_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name: elem_name, ..
+                } if elem_name.local_name == NAME_TAG => {
+                    name = Some(read_simple_tag(event_reader, &elem_name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name: elem_name,
+                    attributes,
+                    ..
+                } if elem_name.local_name == CONTENTS_TAG => {
+                    contents = Some(EvidenceContents::read_xml_element(
+                        event_reader,
+                        &elem_name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement {
+                    name: elem_name, ..
+                } if elem_name.local_name == CLASSIFICATION_TAG => {
+                    classification = Some(read_simple_tag(event_reader, &elem_name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name: elem_name, ..
+                } if elem_name.local_name == SENSITIVE_DATA_TAG => {
+                    sensitive_data
+                        .get_or_insert_with(Vec::new)
+                        .push(read_simple_tag(event_reader, &elem_name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name: elem_name,
+                    attributes,
+                    ..
+                } if elem_name.local_name == GOVERNANCE_TAG => {
+                    governance = Some(DataGovernance::read_xml_element(
+                        event_reader,
+                        &elem_name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
+            }
+        }
+
+        Ok(Self {
+            name,
+            contents,
+            classification,
+            sensitive_data,
+            governance,
+        })
+    }
+}
+
+// #declarations/evidence/data/contents
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EvidenceContents {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachment: Option<Attachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+impl From<models_declarations::EvidenceContents> for EvidenceContents {
+    fn from(other: models_declarations::EvidenceContents) -> Self {
+        Self {
+            attachment: convert_optional(other.attachment),
+            url: other.url.map(|url| url.to_string()),
+        }
+    }
+}
+
+impl From<EvidenceContents> for models_declarations::EvidenceContents {
+    fn from(other: EvidenceContents) -> Self {
+        Self {
+            attachment: convert_optional(other.attachment),
+            url: other.url.map(Uri),
+        }
+    }
+}
+
+const ATTACHMENT_TAG: &str = "attachment";
+const URL_TAG: &str = "url";
+
+xml_element! {
+    EvidenceContents = CONTENTS_TAG {
+        ATTACHMENT_TAG => attachment: Attachment,
+        URL_TAG => url: String,
+    }
+}
+
+// #declarations/targets
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Targets {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organizations: Option<Vec<OrganizationalEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Components>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    services: Option<Services>,
+}
+
+impl TryFrom<models_declarations::Targets> for Targets {
+    type Error = BomError;
+
+    fn try_from(other: models_declarations::Targets) -> Result<Self, Self::Error> {
+        Ok(Self {
+            organizations: convert_optional_vec(other.organizations),
+            components: try_convert_optional(other.components)?,
+            services: convert_optional(other.services),
+        })
+    }
+}
+
+impl From<Targets> for models_declarations::Targets {
+    fn from(other: Targets) -> Self {
+        Self {
+            organizations: convert_optional_vec(other.organizations),
+            components: convert_optional(other.components),
+            services: convert_optional(other.services),
+        }
+    }
+}
+
+const ORGANIZATIONS_TAG: &str = "organizations";
+
+impl ToXml for Targets {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, TARGETS_TAG)?;
+
+        if let Some(organizations) = &self.organizations {
+            write_start_tag(writer, ORGANIZATIONS_TAG)?;
+            for organization in organizations {
+                organization.write_xml_named_element(writer, ORGANIZATION_TAG)?;
+            }
+            write_close_tag(writer, ORGANIZATIONS_TAG)?;
+        }
+        self.components.write_xml_element(writer)?;
+        self.services.write_xml_element(writer)?;
+
+        write_close_tag(writer, TARGETS_TAG)
+    }
+}
+
+const COMPONENTS_TAG: &str = "components";
+const SERVICES_TAG: &str = "services";
+
+elem_tag!(OrganizationTag = "organization");
+
+impl FromXml for Targets {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        get_elements! {
+            event_reader, element_name,
+            ORGANIZATIONS_TAG => organizations: VecXmlReader<OrganizationalEntity, OrganizationTag>,
+            COMPONENTS_TAG => components: Components,
+            SERVICES_TAG => services: Services,
+        };
+
+        Ok(Self {
+            organizations: organizations.map(Vec::from),
+            components,
+            services,
+        })
+    }
+}
+
+// #declarations/affirmation
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Affirmation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signatories: Option<Vec<Signatory>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+}
+
+impl From<models_declarations::Affirmation> for Affirmation {
+    fn from(other: models_declarations::Affirmation) -> Self {
+        Self {
+            statement: other.statement,
+            signatories: convert_optional_vec(other.signatories),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+impl From<Affirmation> for models_declarations::Affirmation {
+    fn from(other: Affirmation) -> Self {
+        Self {
+            statement: other.statement,
+            signatories: convert_optional_vec(other.signatories),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+const STATEMENT_TAG: &str = "statement";
+const SIGNATORIES_TAG: &str = "signatories";
+
+elem_tag!(SignatoryTag = "signatory");
+
+impl ToXml for Affirmation {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, AFFIRMATION_TAG)?;
+
+        write_simple_option_tag(writer, STATEMENT_TAG, &self.statement)?;
+        if let Some(signatories) = &self.signatories {
+            write_list_tag(writer, SIGNATORIES_TAG, signatories)?;
+        }
+        self.signature.write_xml_element(writer)?;
+
+        write_close_tag(writer, AFFIRMATION_TAG)
+    }
+}
+
+impl FromXml for Affirmation {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        get_elements! {
+            event_reader, element_name,
+            STATEMENT_TAG => statement: String,
+            SIGNATORIES_TAG => signatories: VecXmlReader<Signatory, SignatoryTag>,
+            SIGNATURE_TAG => signature: Signature,
+        };
+
+        Ok(Self {
+            statement,
+            signatories: signatories.map(Vec::from),
+            signature,
+        })
+    }
+}
+
+// #declarations/affirmation/signatories
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Signatory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<OrganizationalEntity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_reference: Option<ExternalReference>,
+}
+
+impl From<models_declarations::Signatory> for Signatory {
+    fn from(other: models_declarations::Signatory) -> Self {
+        Self {
+            name: other.name,
+            role: other.role,
+            signature: convert_optional(other.signature),
+            organization: convert_optional(other.organization),
+            external_reference: convert_optional(other.external_reference),
+        }
+    }
+}
+
+impl From<Signatory> for models_declarations::Signatory {
+    fn from(other: Signatory) -> Self {
+        Self {
+            name: other.name,
+            role: other.role,
+            signature: convert_optional(other.signature),
+            organization: convert_optional(other.organization),
+            external_reference: convert_optional(other.external_reference),
+        }
+    }
+}
+
+const SIGNATORY_TAG: &str = "signatory";
+const ROLE_TAG: &str = "role";
+const EXTERNAL_REFERENCE_TAG: &str = "externalReference";
+
+impl ToXml for Signatory {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, SIGNATORY_TAG)?;
+
+        write_simple_option_tag(writer, NAME_TAG, &self.name)?;
+        write_simple_option_tag(writer, ROLE_TAG, &self.role)?;
+        self.signature.write_xml_element(writer)?;
+        self.organization
+            .write_xml_named_element(writer, ORGANIZATION_TAG)?;
+        self.external_reference
+            .write_xml_named_element(writer, EXTERNAL_REFERENCE_TAG)?;
+
+        write_close_tag(writer, SIGNATORY_TAG)
+    }
+}
+
+impl FromXml for Signatory {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        get_elements! {
+            event_reader, element_name,
+            NAME_TAG => name: String,
+            ROLE_TAG => role: String,
+            SIGNATURE_TAG => signature: Signature,
+            ORGANIZATION_TAG => organization: OrganizationalEntity,
+            EXTERNAL_REFERENCE_TAG => external_reference: ExternalReference,
+        };
+
+        Ok(Self {
+            name,
+            role,
+            signature,
+            organization,
+            external_reference,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::{
+        models::vulnerability_rating::Score,
+        specs::common::{
+            external_reference::v1_6::test::{
+                corresponding_external_references, example_external_references,
+            },
+            organization::test::{
+                corresponding_contact, corresponding_entity, example_contact, example_entity,
+            },
+            signature::test::{corresponding_signature, example_signature},
+        },
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    pub(crate) fn example_declarations() -> Declarations {
+        Declarations {
+            assessors: Some(vec![Assessor {
+                bom_ref: Some("assessor-1".to_string()),
+                third_party: Some(true),
+                organization: Some(example_entity()),
+            }]),
+            attestations: Some(vec![Attestation {
+                summary: Some("Attestation summary".to_string()),
+                assessor: Some("assessor-1".to_string()),
+                map: Some(vec![AttestationMap {
+                    requirement: Some("requirement-1".to_string()),
+                    claims: Some(vec!["claim-1".to_string()]),
+                    counter_claims: Some(vec!["claim-2".to_string()]),
+                    conformance: Some(Conformance {
+                        score: Some(0.8),
+                        rationale: Some("Conformance rationale".to_string()),
+                        mitigation_strategies: Some(vec!["mitigation-1".to_string()]),
+                    }),
+                    confidence: Some(Confidence {
+                        score: Some(1.0),
+                        rationale: Some("Confidence rationale".to_string()),
+                    }),
+                }]),
+                signature: None,
+            }]),
+            claims: Some(vec![Claim {
+                bom_ref: Some("claim-1".to_string()),
+                target: Some("target-1".to_string()),
+                predicate: Some("The predicate".to_string()),
+                mitigation_strategies: Some(vec!["mitigation-1".to_string()]),
+                reasoning: Some("The reasoning".to_string()),
+                evidence: Some(vec!["evidence-1".to_string(), "evidence-2".to_string()]),
+                counter_evidence: Some(vec!["evidence-3".to_string()]),
+                external_references: Some(example_external_references()),
+                signature: None,
+            }]),
+            evidence: Some(vec![DeclarationEvidence {
+                bom_ref: Some("evidence-1".to_string()),
+                property_name: Some("internal.com:property".to_string()),
+                description: Some("The description".to_string()),
+                data: Some(vec![EvidenceData {
+                    name: Some("Data name".to_string()),
+                    contents: Some(EvidenceContents {
+                        attachment: Some(Attachment {
+                            content: "abcdefgh".to_string(),
+                            content_type: None,
+                            encoding: Some("base64".to_string()),
+                        }),
+                        url: Some("https://example.com".to_string()),
+                    }),
+                    classification: Some("PII".to_string()),
+                    sensitive_data: Some(vec!["Name".to_string(), "Email".to_string()]),
+                    governance: None,
+                }]),
+                created: Some("2022-01-01T00:00:00Z".to_string()),
+                expires: Some("2023-01-01T00:00:00Z".to_string()),
+                author: Some(example_contact()),
+                reviewer: None,
+                signature: None,
+            }]),
+            targets: Some(Targets {
+                organizations: Some(vec![example_entity()]),
+                components: None,
+                services: None,
+            }),
+            affirmation: Some(Affirmation {
+                statement: Some("The statement".to_string()),
+                signatories: Some(vec![Signatory {
+                    name: Some("Tom".to_string()),
+                    role: Some("CEO".to_string()),
+                    signature: Some(example_signature()),
+                    organization: None,
+                    external_reference: None,
+                }]),
+                signature: None,
+            }),
+            signature: None,
+        }
+    }
+
+    pub(crate) fn corresponding_declarations() -> models_declarations::Declarations {
+        models_declarations::Declarations {
+            assessors: Some(vec![models_declarations::Assessor {
+                bom_ref: Some(models::bom::BomReference::new("assessor-1")),
+                third_party: Some(true),
+                organization: Some(corresponding_entity()),
+            }]),
+            attestations: Some(vec![models_declarations::Attestation {
+                summary: Some("Attestation summary".to_string()),
+                assessor: Some("assessor-1".to_string()),
+                map: Some(vec![models_declarations::AttestationMap {
+                    requirement: Some("requirement-1".to_string()),
+                    claims: Some(vec!["claim-1".to_string()]),
+                    counter_claims: Some(vec!["claim-2".to_string()]),
+                    conformance: Some(models_declarations::Conformance {
+                        score: Some(Score::from(0.8)),
+                        rationale: Some("Conformance rationale".to_string()),
+                        mitigation_strategies: Some(vec!["mitigation-1".to_string()]),
+                    }),
+                    confidence: Some(models_declarations::Confidence {
+                        score: Some(Score::from(1.0)),
+                        rationale: Some("Confidence rationale".to_string()),
+                    }),
+                }]),
+                signature: None,
+            }]),
+            claims: Some(vec![models_declarations::Claim {
+                bom_ref: Some(models::bom::BomReference::new("claim-1")),
+                target: Some("target-1".to_string()),
+                predicate: Some("The predicate".to_string()),
+                mitigation_strategies: Some(vec!["mitigation-1".to_string()]),
+                reasoning: Some("The reasoning".to_string()),
+                evidence: Some(vec!["evidence-1".to_string(), "evidence-2".to_string()]),
+                counter_evidence: Some(vec!["evidence-3".to_string()]),
+                external_references: Some(corresponding_external_references()),
+                signature: None,
+            }]),
+            evidence: Some(vec![models_declarations::DeclarationEvidence {
+                bom_ref: Some(models::bom::BomReference::new("evidence-1")),
+                property_name: Some("internal.com:property".to_string()),
+                description: Some("The description".to_string()),
+                data: Some(vec![models_declarations::EvidenceData {
+                    name: Some("Data name".to_string()),
+                    contents: Some(models_declarations::EvidenceContents {
+                        attachment: Some(models::attachment::Attachment {
+                            content: "abcdefgh".to_string(),
+                            content_type: None,
+                            encoding: Some("base64".to_string()),
+                        }),
+                        url: Some(Uri("https://example.com".to_string())),
+                    }),
+                    classification: Some("PII".to_string()),
+                    sensitive_data: Some(vec!["Name".to_string(), "Email".to_string()]),
+                    governance: None,
+                }]),
+                created: Some(DateTime("2022-01-01T00:00:00Z".to_string())),
+                expires: Some(DateTime("2023-01-01T00:00:00Z".to_string())),
+                author: Some(corresponding_contact()),
+                reviewer: None,
+                signature: None,
+            }]),
+            targets: Some(models_declarations::Targets {
+                organizations: Some(vec![corresponding_entity()]),
+                components: None,
+                services: None,
+            }),
+            affirmation: Some(models_declarations::Affirmation {
+                statement: Some("The statement".to_string()),
+                signatories: Some(vec![models_declarations::Signatory {
+                    name: Some("Tom".to_string()),
+                    role: Some("CEO".to_string()),
+                    signature: Some(corresponding_signature()),
+                    organization: None,
+                    external_reference: None,
+                }]),
+                signature: None,
+            }),
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn it_should_convert_to_the_model() {
+        let actual: models_declarations::Declarations = example_declarations().into();
+        assert_eq!(actual, corresponding_declarations());
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_declarations());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<declarations>
+  <assessors>
+    <assessor bom-ref="assessor-1">
+      <thirdParty>true</thirdParty>
+      <organization>
+        <name>name</name>
+        <url>url</url>
+        <contact>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </contact>
+      </organization>
+    </assessor>
+  </assessors>
+  <attestations>
+    <attestation>
+      <summary>Attestation summary</summary>
+      <assessor>assessor-1</assessor>
+      <map>
+        <requirement>requirement-1</requirement>
+        <claims>
+          <claim>claim-1</claim>
+        </claims>
+        <counterClaims>
+          <counterClaim>claim-2</counterClaim>
+        </counterClaims>
+        <conformance>
+          <score>0.8</score>
+          <rationale>Conformance rationale</rationale>
+          <mitigationStrategies>
+            <mitigationStrategy>mitigation-1</mitigationStrategy>
+          </mitigationStrategies>
+        </conformance>
+        <confidence>
+          <score>1</score>
+          <rationale>Confidence rationale</rationale>
+        </confidence>
+      </map>
+    </attestation>
+  </attestations>
+  <claims>
+    <claim bom-ref="claim-1">
+      <target>target-1</target>
+      <predicate>The predicate</predicate>
+      <mitigationStrategies>
+        <mitigationStrategy>mitigation-1</mitigationStrategy>
+      </mitigationStrategies>
+      <reasoning>The reasoning</reasoning>
+      <evidence>evidence-1</evidence>
+      <evidence>evidence-2</evidence>
+      <counterEvidence>evidence-3</counterEvidence>
+      <externalReferences>
+        <reference type="external reference type">
+          <url>url</url>
+          <comment>comment</comment>
+          <hashes>
+            <hash alg="algorithm">hash value</hash>
+          </hashes>
+        </reference>
+      </externalReferences>
+    </claim>
+  </claims>
+  <evidence>
+    <evidence bom-ref="evidence-1">
+      <propertyName>internal.com:property</propertyName>
+      <description>The description</description>
+      <data>
+        <name>Data name</name>
+        <contents>
+          <attachment encoding="base64">abcdefgh</attachment>
+          <url>https://example.com</url>
+        </contents>
+        <classification>PII</classification>
+        <sensitiveData>Name</sensitiveData>
+        <sensitiveData>Email</sensitiveData>
+      </data>
+      <created>2022-01-01T00:00:00Z</created>
+      <expires>2023-01-01T00:00:00Z</expires>
+      <author>
+        <name>name</name>
+        <email>email</email>
+        <phone>phone</phone>
+      </author>
+    </evidence>
+  </evidence>
+  <targets>
+    <organizations>
+      <organization>
+        <name>name</name>
+        <url>url</url>
+        <contact>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </contact>
+      </organization>
+    </organizations>
+  </targets>
+  <affirmation>
+    <statement>The statement</statement>
+    <signatories>
+      <signatory>
+        <name>Tom</name>
+        <role>CEO</role>
+        <signature>
+          <algorithm>HS512</algorithm>
+          <value>1234567890</value>
+        </signature>
+      </signatory>
+    </signatories>
+  </affirmation>
+</declarations>
+"#;
+        let actual: Declarations = read_element_from_string(input);
+        let expected = example_declarations();
+        assert_eq!(actual, expected);
+    }
+}
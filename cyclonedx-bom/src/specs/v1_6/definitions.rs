@@ -0,0 +1,682 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer};
+
+use crate::{
+    elem_tag,
+    errors::{XmlReadError, XmlWriteError},
+    get_elements,
+    models::{self, definitions as models_definitions},
+    specs::common::{property::Properties, signature::Signature},
+    utilities::{convert_optional, convert_optional_vec},
+    xml::{
+        attribute_or_error, read_list_tag, read_simple_tag, skip_unexpected_element,
+        to_xml_read_error, to_xml_write_error, write_close_tag, write_list_string_tag,
+        write_list_tag, write_simple_option_tag, write_simple_tag, FromXml, ToInnerXml, ToXml,
+        VecXmlReader,
+    },
+    xml_element,
+};
+
+use super::external_reference::ExternalReferences;
+
+// #definitions
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Definitions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    standards: Option<Vec<Standard>>,
+}
+
+impl From<models_definitions::Definitions> for Definitions {
+    fn from(other: models_definitions::Definitions) -> Self {
+        Self {
+            standards: convert_optional_vec(other.standards),
+        }
+    }
+}
+
+impl From<Definitions> for models_definitions::Definitions {
+    fn from(other: Definitions) -> Self {
+        Self {
+            standards: convert_optional_vec(other.standards),
+        }
+    }
+}
+
+const DEFINITIONS_TAG: &str = "definitions";
+const STANDARDS_TAG: &str = "standards";
+const BOM_REF_ATTR: &str = "bom-ref";
+
+elem_tag!(StandardTag = "standard");
+elem_tag!(RequirementTag = "requirement");
+elem_tag!(LevelTag = "level");
+
+xml_element! {
+    Definitions = DEFINITIONS_TAG {
+        STANDARDS_TAG => standards: VecXmlReader<Standard, StandardTag>,
+    }
+}
+
+// #definitions/standards
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Standard {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requirements: Option<Vec<Requirement>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    levels: Option<Vec<Level>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_references: Option<ExternalReferences>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
+}
+
+impl From<models_definitions::Standard> for Standard {
+    fn from(other: models_definitions::Standard) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(|bom_ref| bom_ref.0),
+            name: other.name,
+            version: other.version,
+            description: other.description,
+            owner: other.owner,
+            requirements: convert_optional_vec(other.requirements),
+            levels: convert_optional_vec(other.levels),
+            external_references: convert_optional(other.external_references),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+impl From<Standard> for models_definitions::Standard {
+    fn from(other: Standard) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(models::bom::BomReference),
+            name: other.name,
+            version: other.version,
+            description: other.description,
+            owner: other.owner,
+            requirements: convert_optional_vec(other.requirements),
+            levels: convert_optional_vec(other.levels),
+            external_references: convert_optional(other.external_references),
+            signature: convert_optional(other.signature),
+        }
+    }
+}
+
+const NAME_TAG: &str = "name";
+const VERSION_TAG: &str = "version";
+const DESCRIPTION_TAG: &str = "description";
+const OWNER_TAG: &str = "owner";
+const REQUIREMENTS_TAG: &str = "requirements";
+const LEVELS_TAG: &str = "levels";
+const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
+const SIGNATURE_TAG: &str = "signature";
+
+impl ToInnerXml for Standard {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        let mut start_element = writer::XmlEvent::start_element(tag);
+        if let Some(bom_ref) = &self.bom_ref {
+            start_element = start_element.attr(BOM_REF_ATTR, bom_ref);
+        }
+        writer
+            .write(start_element)
+            .map_err(to_xml_write_error(tag))?;
+
+        write_simple_option_tag(writer, NAME_TAG, &self.name)?;
+        write_simple_option_tag(writer, VERSION_TAG, &self.version)?;
+        write_simple_option_tag(writer, DESCRIPTION_TAG, &self.description)?;
+        write_simple_option_tag(writer, OWNER_TAG, &self.owner)?;
+        if let Some(requirements) = &self.requirements {
+            write_list_tag(writer, REQUIREMENTS_TAG, requirements)?;
+        }
+        if let Some(levels) = &self.levels {
+            write_list_tag(writer, LEVELS_TAG, levels)?;
+        }
+        self.external_references.write_xml_element(writer)?;
+        self.signature.write_xml_element(writer)?;
+
+        write_close_tag(writer, tag)
+    }
+}
+
+impl FromXml for Standard {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR).ok();
+
+        get_elements! {
+            event_reader, element_name,
+            NAME_TAG => name: String,
+            VERSION_TAG => version: String,
+            DESCRIPTION_TAG => description: String,
+            OWNER_TAG => owner: String,
+            REQUIREMENTS_TAG => requirements: VecXmlReader<Requirement, RequirementTag>,
+            LEVELS_TAG => levels: VecXmlReader<Level, LevelTag>,
+            EXTERNAL_REFERENCES_TAG => external_references: ExternalReferences,
+            SIGNATURE_TAG => signature: Signature,
+        };
+
+        Ok(Self {
+            bom_ref,
+            name,
+            version,
+            description,
+            owner,
+            requirements: requirements.map(Vec::from),
+            levels: levels.map(Vec::from),
+            external_references,
+            signature,
+        })
+    }
+}
+
+// #definitions/standards/requirements
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Requirement {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    descriptions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    open_cre: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_references: Option<ExternalReferences>,
+}
+
+impl From<models_definitions::Requirement> for Requirement {
+    fn from(other: models_definitions::Requirement) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(|bom_ref| bom_ref.0),
+            identifier: other.identifier,
+            title: other.title,
+            text: other.text,
+            descriptions: other.descriptions,
+            open_cre: other.open_cre,
+            parent: other.parent,
+            properties: convert_optional(other.properties),
+            external_references: convert_optional(other.external_references),
+        }
+    }
+}
+
+impl From<Requirement> for models_definitions::Requirement {
+    fn from(other: Requirement) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(models::bom::BomReference),
+            identifier: other.identifier,
+            title: other.title,
+            text: other.text,
+            descriptions: other.descriptions,
+            open_cre: other.open_cre,
+            parent: other.parent,
+            properties: convert_optional(other.properties),
+            external_references: convert_optional(other.external_references),
+        }
+    }
+}
+
+const REQUIREMENT_TAG: &str = "requirement";
+const IDENTIFIER_TAG: &str = "identifier";
+const TITLE_TAG: &str = "title";
+const TEXT_TAG: &str = "text";
+const DESCRIPTIONS_TAG: &str = "descriptions";
+const OPEN_CRE_TAG: &str = "openCre";
+const PARENT_TAG: &str = "parent";
+const PROPERTIES_TAG: &str = "properties";
+
+impl ToXml for Requirement {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let mut start_element = writer::XmlEvent::start_element(REQUIREMENT_TAG);
+        if let Some(bom_ref) = &self.bom_ref {
+            start_element = start_element.attr(BOM_REF_ATTR, bom_ref);
+        }
+        writer
+            .write(start_element)
+            .map_err(to_xml_write_error(REQUIREMENT_TAG))?;
+
+        write_simple_option_tag(writer, IDENTIFIER_TAG, &self.identifier)?;
+        write_simple_option_tag(writer, TITLE_TAG, &self.title)?;
+        write_simple_option_tag(writer, TEXT_TAG, &self.text)?;
+        if let Some(descriptions) = &self.descriptions {
+            write_list_string_tag(writer, DESCRIPTIONS_TAG, DESCRIPTION_TAG, descriptions)?;
+        }
+        for open_cre in self.open_cre.iter().flatten() {
+            write_simple_tag(writer, OPEN_CRE_TAG, open_cre)?;
+        }
+        write_simple_option_tag(writer, PARENT_TAG, &self.parent)?;
+        self.properties.write_xml_element(writer)?;
+        self.external_references.write_xml_element(writer)?;
+
+        write_close_tag(writer, REQUIREMENT_TAG)
+    }
+}
+
+impl FromXml for Requirement {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR).ok();
+        let mut identifier: Option<String> = None;
+        let mut title: Option<String> = None;
+        let mut text: Option<String> = None;
+        let mut descriptions: Option<Vec<String>> = None;
+        let mut open_cre: Option<Vec<String>> = None;
+        let mut parent: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+        let mut external_references: Option<ExternalReferences> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(REQUIREMENT_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == IDENTIFIER_TAG =>
+                {
+                    identifier = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TITLE_TAG => {
+                    title = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TEXT_TAG => {
+                    text = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTIONS_TAG =>
+                {
+                    descriptions = Some(read_list_tag(event_reader, &name, DESCRIPTION_TAG)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == OPEN_CRE_TAG => {
+                    open_cre
+                        .get_or_insert_with(Vec::new)
+                        .push(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == PARENT_TAG => {
+                    parent = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == EXTERNAL_REFERENCES_TAG => {
+                    external_references = Some(ExternalReferences::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
+            }
+        }
+
+        Ok(Self {
+            bom_ref,
+            identifier,
+            title,
+            text,
+            descriptions,
+            open_cre,
+            parent,
+            properties,
+            external_references,
+        })
+    }
+}
+
+// #definitions/standards/levels
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Level {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requirements: Option<Vec<String>>,
+}
+
+impl From<models_definitions::Level> for Level {
+    fn from(other: models_definitions::Level) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(|bom_ref| bom_ref.0),
+            identifier: other.identifier,
+            title: other.title,
+            description: other.description,
+            requirements: other.requirements,
+        }
+    }
+}
+
+impl From<Level> for models_definitions::Level {
+    fn from(other: Level) -> Self {
+        Self {
+            bom_ref: other.bom_ref.map(models::bom::BomReference),
+            identifier: other.identifier,
+            title: other.title,
+            description: other.description,
+            requirements: other.requirements,
+        }
+    }
+}
+
+const LEVEL_TAG: &str = "level";
+
+impl ToXml for Level {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let mut start_element = writer::XmlEvent::start_element(LEVEL_TAG);
+        if let Some(bom_ref) = &self.bom_ref {
+            start_element = start_element.attr(BOM_REF_ATTR, bom_ref);
+        }
+        writer
+            .write(start_element)
+            .map_err(to_xml_write_error(LEVEL_TAG))?;
+
+        write_simple_option_tag(writer, IDENTIFIER_TAG, &self.identifier)?;
+        write_simple_option_tag(writer, TITLE_TAG, &self.title)?;
+        write_simple_option_tag(writer, DESCRIPTION_TAG, &self.description)?;
+        if let Some(requirements) = &self.requirements {
+            write_list_string_tag(writer, REQUIREMENTS_TAG, REQUIREMENT_TAG, requirements)?;
+        }
+
+        write_close_tag(writer, LEVEL_TAG)
+    }
+}
+
+impl FromXml for Level {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut crate::xml::XmlReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR).ok();
+
+        get_elements! {
+            event_reader, element_name,
+            IDENTIFIER_TAG => identifier: String,
+            TITLE_TAG => title: String,
+            DESCRIPTION_TAG => description: String,
+            REQUIREMENTS_TAG => requirements: VecXmlReader<String, RequirementTag>,
+        };
+
+        Ok(Self {
+            bom_ref,
+            identifier,
+            title,
+            description,
+            requirements: requirements.map(Vec::from),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::{
+        specs::common::{
+            external_reference::v1_6::test::{
+                corresponding_external_references, example_external_references,
+            },
+            property::test::{corresponding_properties, example_properties},
+            signature::test::{corresponding_signature, example_signature},
+        },
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    pub(crate) fn example_definitions() -> Definitions {
+        Definitions {
+            standards: Some(vec![Standard {
+                bom_ref: Some("standard-1".to_string()),
+                name: Some("Example Security Standard".to_string()),
+                version: Some("1.0.0".to_string()),
+                description: Some("The description of the standard".to_string()),
+                owner: Some("Example Organization".to_string()),
+                requirements: Some(vec![
+                    Requirement {
+                        bom_ref: Some("requirement-1".to_string()),
+                        identifier: Some("V1".to_string()),
+                        title: Some("Architecture".to_string()),
+                        text: Some("The text of the requirement".to_string()),
+                        descriptions: Some(vec!["The description".to_string()]),
+                        open_cre: Some(vec!["CRE:764-507".to_string()]),
+                        parent: None,
+                        properties: Some(example_properties()),
+                        external_references: Some(example_external_references()),
+                    },
+                    Requirement {
+                        bom_ref: Some("requirement-2".to_string()),
+                        identifier: Some("V1.1".to_string()),
+                        title: None,
+                        text: None,
+                        descriptions: None,
+                        open_cre: None,
+                        parent: Some("requirement-1".to_string()),
+                        properties: None,
+                        external_references: None,
+                    },
+                ]),
+                levels: Some(vec![Level {
+                    bom_ref: Some("level-1".to_string()),
+                    identifier: Some("L1".to_string()),
+                    title: Some("Level 1".to_string()),
+                    description: Some("The description of the level".to_string()),
+                    requirements: Some(vec![
+                        "requirement-1".to_string(),
+                        "requirement-2".to_string(),
+                    ]),
+                }]),
+                external_references: Some(example_external_references()),
+                signature: Some(example_signature()),
+            }]),
+        }
+    }
+
+    pub(crate) fn corresponding_definitions() -> models_definitions::Definitions {
+        models_definitions::Definitions {
+            standards: Some(vec![models_definitions::Standard {
+                bom_ref: Some(models::bom::BomReference::new("standard-1")),
+                name: Some("Example Security Standard".to_string()),
+                version: Some("1.0.0".to_string()),
+                description: Some("The description of the standard".to_string()),
+                owner: Some("Example Organization".to_string()),
+                requirements: Some(vec![
+                    models_definitions::Requirement {
+                        bom_ref: Some(models::bom::BomReference::new("requirement-1")),
+                        identifier: Some("V1".to_string()),
+                        title: Some("Architecture".to_string()),
+                        text: Some("The text of the requirement".to_string()),
+                        descriptions: Some(vec!["The description".to_string()]),
+                        open_cre: Some(vec!["CRE:764-507".to_string()]),
+                        parent: None,
+                        properties: Some(corresponding_properties()),
+                        external_references: Some(corresponding_external_references()),
+                    },
+                    models_definitions::Requirement {
+                        bom_ref: Some(models::bom::BomReference::new("requirement-2")),
+                        identifier: Some("V1.1".to_string()),
+                        title: None,
+                        text: None,
+                        descriptions: None,
+                        open_cre: None,
+                        parent: Some("requirement-1".to_string()),
+                        properties: None,
+                        external_references: None,
+                    },
+                ]),
+                levels: Some(vec![models_definitions::Level {
+                    bom_ref: Some(models::bom::BomReference::new("level-1")),
+                    identifier: Some("L1".to_string()),
+                    title: Some("Level 1".to_string()),
+                    description: Some("The description of the level".to_string()),
+                    requirements: Some(vec![
+                        "requirement-1".to_string(),
+                        "requirement-2".to_string(),
+                    ]),
+                }]),
+                external_references: Some(corresponding_external_references()),
+                signature: Some(corresponding_signature()),
+            }]),
+        }
+    }
+
+    #[test]
+    fn it_should_convert_to_the_model() {
+        let actual: models_definitions::Definitions = example_definitions().into();
+        assert_eq!(actual, corresponding_definitions());
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_definitions());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<definitions>
+  <standards>
+    <standard bom-ref="standard-1">
+      <name>Example Security Standard</name>
+      <version>1.0.0</version>
+      <description>The description of the standard</description>
+      <owner>Example Organization</owner>
+      <requirements>
+        <requirement bom-ref="requirement-1">
+          <identifier>V1</identifier>
+          <title>Architecture</title>
+          <text>The text of the requirement</text>
+          <descriptions>
+            <description>The description</description>
+          </descriptions>
+          <openCre>CRE:764-507</openCre>
+          <properties>
+            <property name="name">value</property>
+          </properties>
+          <externalReferences>
+            <reference type="external reference type">
+              <url>url</url>
+              <comment>comment</comment>
+              <hashes>
+                <hash alg="algorithm">hash value</hash>
+              </hashes>
+            </reference>
+          </externalReferences>
+        </requirement>
+        <requirement bom-ref="requirement-2">
+          <identifier>V1.1</identifier>
+          <parent>requirement-1</parent>
+        </requirement>
+      </requirements>
+      <levels>
+        <level bom-ref="level-1">
+          <identifier>L1</identifier>
+          <title>Level 1</title>
+          <description>The description of the level</description>
+          <requirements>
+            <requirement>requirement-1</requirement>
+            <requirement>requirement-2</requirement>
+          </requirements>
+        </level>
+      </levels>
+      <externalReferences>
+        <reference type="external reference type">
+          <url>url</url>
+          <comment>comment</comment>
+          <hashes>
+            <hash alg="algorithm">hash value</hash>
+          </hashes>
+        </reference>
+      </externalReferences>
+      <signature>
+        <algorithm>HS512</algorithm>
+        <value>1234567890</value>
+      </signature>
+    </standard>
+  </standards>
+</definitions>
+"#;
+        let actual: Definitions = read_element_from_string(input);
+        let expected = example_definitions();
+        assert_eq!(actual, expected);
+    }
+}
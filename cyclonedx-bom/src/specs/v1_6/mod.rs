@@ -0,0 +1,38 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+pub(crate) mod crypto_properties;
+pub(crate) mod declarations;
+pub(crate) mod definitions;
+
+pub(crate) use crate::specs::v1_5::{
+    attachment, data_governance, evidence, formulation, licensing, lifecycles, modelcard,
+    proof_of_concept, service_data,
+};
+
+pub(crate) use crate::specs::common::annotation::v1_6 as annotation;
+pub(crate) use crate::specs::common::bom::v1_6 as bom;
+pub(crate) use crate::specs::common::component::v1_6 as component;
+pub(crate) use crate::specs::common::composition::v1_6 as composition;
+pub(crate) use crate::specs::common::external_reference::v1_6 as external_reference;
+pub(crate) use crate::specs::common::license::v1_6 as license;
+pub(crate) use crate::specs::common::metadata::v1_6 as metadata;
+pub(crate) use crate::specs::common::service::v1_6 as service;
+pub(crate) use crate::specs::common::tool::v1_6 as tool;
+pub(crate) use crate::specs::common::vulnerability::v1_6 as vulnerability;
+pub(crate) use crate::specs::common::vulnerability_analysis::v1_6 as vulnerability_analysis;
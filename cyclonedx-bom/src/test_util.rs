@@ -0,0 +1,508 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Fixtures and helpers for the serialization tests of crates building on this library, enabled
+//! by the `test-util` feature.
+//!
+//! [`example_bom()`] returns a BOM of spec version 1.6 that passes validation and fills most
+//! sections, assembled from the per-section builders such as [`example_component()`] and
+//! [`example_modelcard()`]. [`write_element_to_string()`] and [`read_element_from_string()`]
+//! write and read a single element as XML of a given spec version, without the surrounding
+//! document.
+//!
+//! ```
+//! use cyclonedx_bom::models::{bom::SpecVersion, component::Component};
+//! use cyclonedx_bom::test_util::{example_component, read_element_from_string, write_element_to_string};
+//!
+//! let xml = write_element_to_string(example_component(), SpecVersion::V1_5);
+//! assert!(xml.contains("<name>library</name>"));
+//!
+//! let component: Component = read_element_from_string(xml, SpecVersion::V1_5);
+//! assert_eq!(component, example_component());
+//! ```
+//!
+//! The helpers panic instead of returning errors, so they are meant for tests only.
+
+use crate::external_models::{
+    date_time::DateTime,
+    normalized_string::NormalizedString,
+    spdx::SpdxExpression,
+    uri::{Purl, Uri},
+};
+use crate::models::{
+    bom::{Bom, BomReference, SpecVersion, UrnUuid},
+    component::{Classification, Component, Components, Scope},
+    composition::{AggregateType, Composition, Compositions},
+    dependency::{Dependencies, Dependency},
+    external_reference::{ExternalReference, ExternalReferenceType, ExternalReferences},
+    hash::{Hash, HashAlgorithm, HashValue, Hashes},
+    license::{License, LicenseChoice, Licenses},
+    metadata::Metadata,
+    modelcard::{
+        ConfidenceInterval, Considerations, EthicalConsideration, Inputs, MLParameter, ModelCard,
+        ModelParameters, ModelParametersApproach, Outputs, PerformanceMetric, PerformanceMetrics,
+        QuantitativeAnalysis,
+    },
+    organization::{OrganizationalContact, OrganizationalEntity},
+    property::{Properties, Property},
+    service::{Service, Services},
+    tool::{Tool, Tools},
+    vulnerability::{Vulnerabilities, Vulnerability},
+    vulnerability_analysis::{ImpactAnalysisState, VulnerabilityAnalysis},
+    vulnerability_rating::{
+        Score, ScoreMethod, Severity, VulnerabilityRating, VulnerabilityRatings,
+    },
+    vulnerability_source::VulnerabilitySource,
+    vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets},
+};
+use crate::xml::element;
+
+const APPLICATION_REF: &str = "pkg:cargo/application@1.0.0";
+const LIBRARY_REF: &str = "pkg:cargo/library@1.2.3";
+const MODEL_REF: &str = "model-1";
+const SERVICE_REF: &str = "service-api";
+
+/// A BOM of spec version 1.6 with metadata, components, a service, dependencies, compositions,
+/// a vulnerability and properties.
+pub fn example_bom() -> Bom {
+    Bom {
+        version: 1,
+        serial_number: Some(
+            UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string())
+                .expect("Failed to create the serial number"),
+        ),
+        metadata: Some(example_metadata()),
+        components: Some(Components(vec![
+            example_component(),
+            example_ml_component(),
+        ])),
+        services: Some(Services(vec![example_service()])),
+        external_references: Some(ExternalReferences(vec![ExternalReference::new(
+            ExternalReferenceType::Documentation,
+            Uri::new("https://docs.acme.example.com"),
+        )])),
+        dependencies: Some(example_dependencies()),
+        compositions: Some(example_compositions()),
+        properties: Some(Properties(vec![Property::new(
+            "acme:classification",
+            "internal",
+        )])),
+        vulnerabilities: Some(Vulnerabilities(vec![example_vulnerability()])),
+        signature: None,
+        annotations: None,
+        formulation: None,
+        declarations: None,
+        definitions: None,
+        spec_version: SpecVersion::V1_6,
+    }
+}
+
+/// The metadata of [`example_bom()`], describing the application with the bom-ref
+/// `pkg:cargo/application@1.0.0`.
+pub fn example_metadata() -> Metadata {
+    let mut application = Component::new(
+        Classification::Application,
+        "application",
+        "1.0.0",
+        Some(APPLICATION_REF.to_string()),
+    );
+    application.supplier = Some(OrganizationalEntity::new("ACME Corp"));
+    application.purl = Some(Purl::new("cargo", "application", "1.0.0").expect("Invalid purl"));
+
+    Metadata {
+        timestamp: Some(
+            DateTime::try_from("2024-01-01T00:00:00Z".to_string()).expect("Invalid timestamp"),
+        ),
+        tools: Some(Tools::List(vec![Tool::new("ACME", "generator", "1.0.0")])),
+        authors: Some(vec![OrganizationalContact::new(
+            "Jane Doe",
+            Some("jane@example.com"),
+        )]),
+        component: Some(application),
+        manufacture: None,
+        manufacturer: Some(OrganizationalEntity::new("ACME Manufacturing")),
+        supplier: Some(OrganizationalEntity::new("ACME Corp")),
+        licenses: Some(Licenses(vec![LicenseChoice::License(License::license_id(
+            "Apache-2.0",
+        ))])),
+        properties: Some(Properties(vec![Property::new("acme:build", "42")])),
+        lifecycles: None,
+    }
+}
+
+/// A library with the bom-ref `pkg:cargo/library@1.2.3`, with a hash, licenses, a purl,
+/// external references and a property.
+pub fn example_component() -> Component {
+    let mut library = Component::new(
+        Classification::Library,
+        "library",
+        "1.2.3",
+        Some(LIBRARY_REF.to_string()),
+    );
+    library.group = Some(NormalizedString::new("acme"));
+    library.description = Some(NormalizedString::new("A library"));
+    library.scope = Some(Scope::Required);
+    library.hashes = Some(Hashes(vec![Hash {
+        alg: HashAlgorithm::SHA_256,
+        content: HashValue(
+            "a3f2d8b1c4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f".to_string(),
+        ),
+    }]));
+    library.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+        SpdxExpression::new("MIT OR Apache-2.0"),
+    )]));
+    library.copyright = Some(NormalizedString::new("Copyright ACME"));
+    library.purl = Some(Purl::new("cargo", "library", "1.2.3").expect("Invalid purl"));
+    library.external_references = Some(ExternalReferences(vec![ExternalReference::new(
+        ExternalReferenceType::Vcs,
+        Uri::new("https://github.com/acme/library"),
+    )]));
+    library.properties = Some(Properties(vec![Property::new("acme:team", "platform")]));
+    library
+}
+
+/// A machine learning model with the bom-ref `model-1` and the model card of
+/// [`example_modelcard()`], which is supported since spec version 1.5.
+pub fn example_ml_component() -> Component {
+    let mut model = Component::new(
+        Classification::MachineLearningModel,
+        "classifier",
+        "2.0.0",
+        Some(MODEL_REF.to_string()),
+    );
+    model.model_card = Some(example_modelcard());
+    model
+}
+
+/// A model card with model parameters, a performance metric and considerations.
+pub fn example_modelcard() -> ModelCard {
+    ModelCard {
+        bom_ref: Some(BomReference::new("modelcard-1")),
+        model_parameters: Some(ModelParameters {
+            approach: Some(ModelParametersApproach::new("supervised")),
+            task: Some("classification".to_string()),
+            architecture_family: Some("convolutional neural network".to_string()),
+            model_architecture: Some("ResNet-50".to_string()),
+            datasets: None,
+            inputs: Some(Inputs(vec![MLParameter::new("image")])),
+            outputs: Some(Outputs(vec![MLParameter::new("label")])),
+        }),
+        quantitative_analysis: Some(QuantitativeAnalysis {
+            performance_metrics: Some(PerformanceMetrics(vec![PerformanceMetric {
+                metric_type: Some("accuracy".to_string()),
+                value: Some("0.92".to_string()),
+                slice: None,
+                confidence_interval: Some(ConfidenceInterval {
+                    lower_bound: Some("0.90".to_string()),
+                    upper_bound: Some("0.94".to_string()),
+                }),
+            }])),
+            graphics: None,
+        }),
+        considerations: Some(Considerations {
+            users: Some(vec!["Researchers".to_string()]),
+            use_cases: Some(vec!["Image classification".to_string()]),
+            technical_limitations: None,
+            performance_tradeoffs: None,
+            ethical_considerations: Some(vec![EthicalConsideration {
+                name: Some("Bias".to_string()),
+                mitigation_strategy: Some("Balanced training data".to_string()),
+            }]),
+            environmental_considerations: None,
+            fairness_assessments: None,
+        }),
+        properties: None,
+    }
+}
+
+/// An authenticated service with the bom-ref `service-api` and an endpoint.
+pub fn example_service() -> Service {
+    let mut service = Service::new("api", Some(SERVICE_REF.to_string()));
+    service.version = Some(NormalizedString::new("1"));
+    service.endpoints = Some(vec![Uri::new("https://api.acme.example.com/v1")]);
+    service.authenticated = Some(true);
+    service.x_trust_boundary = Some(false);
+    service
+}
+
+/// A vulnerability of [`example_component()`] with a rating and an analysis, which is supported
+/// since spec version 1.4.
+pub fn example_vulnerability() -> Vulnerability {
+    let mut vulnerability = Vulnerability::new(Some("vulnerability-1".to_string()));
+    vulnerability.id = Some(NormalizedString::new("CVE-2024-0001"));
+    vulnerability.vulnerability_source = Some(VulnerabilitySource::new(
+        Some("NVD".to_string()),
+        Some(Uri::new("https://nvd.nist.gov/vuln/detail/CVE-2024-0001")),
+    ));
+    vulnerability.vulnerability_ratings =
+        Some(VulnerabilityRatings(vec![VulnerabilityRating::new(
+            Score::from_f32(7.5),
+            Some(Severity::High),
+            Some(ScoreMethod::CVSSv31),
+        )]));
+    vulnerability.cwes = Some(vec![79]);
+    vulnerability.description = Some("A vulnerability".to_string());
+    vulnerability.recommendation = Some("Upgrade to 1.2.4".to_string());
+    vulnerability.vulnerability_analysis = Some(VulnerabilityAnalysis::new(
+        Some(ImpactAnalysisState::Exploitable),
+        None,
+        None,
+    ));
+    vulnerability.vulnerability_targets =
+        Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+            LIBRARY_REF.to_string(),
+        )]));
+    vulnerability
+}
+
+/// The dependencies of the application of [`example_metadata()`] on the components and the
+/// service of [`example_bom()`].
+pub fn example_dependencies() -> Dependencies {
+    Dependencies(vec![
+        Dependency {
+            dependency_ref: APPLICATION_REF.to_string(),
+            dependencies: vec![
+                LIBRARY_REF.to_string(),
+                MODEL_REF.to_string(),
+                SERVICE_REF.to_string(),
+            ],
+        },
+        Dependency {
+            dependency_ref: LIBRARY_REF.to_string(),
+            dependencies: vec![],
+        },
+    ])
+}
+
+/// A composition asserting that the dependencies of the application are complete.
+pub fn example_compositions() -> Compositions {
+    Compositions(vec![Composition {
+        bom_ref: None,
+        aggregate: AggregateType::Complete,
+        assemblies: None,
+        dependencies: Some(vec![BomReference::new(APPLICATION_REF)]),
+        vulnerabilities: None,
+        signature: None,
+    }])
+}
+
+/// Models that are written and read as a single XML element by [`write_element_to_string()`]
+/// and [`read_element_from_string()`].
+///
+/// This trait is sealed, it is implemented for [`Metadata`], [`Component`], [`ModelCard`],
+/// [`Service`] and [`Vulnerability`].
+pub trait XmlElement: private::Sealed {}
+
+mod private {
+    use crate::models::bom::SpecVersion;
+
+    pub trait Sealed: Sized {
+        fn write_xml(self, version: SpecVersion) -> String;
+        fn read_xml(input: &str, version: SpecVersion) -> Self;
+    }
+}
+
+/// Writes the element as XML of the spec version, with an XML declaration but without the
+/// surrounding document.
+///
+/// # Panics
+///
+/// If the element cannot be converted to or written as the spec version, e.g. a vulnerability
+/// as 1.3.
+pub fn write_element_to_string<E: XmlElement>(element: E, version: SpecVersion) -> String {
+    element.write_xml(version)
+}
+
+/// Reads a single element from XML of the spec version, the counterpart of
+/// [`write_element_to_string()`].
+///
+/// # Panics
+///
+/// If the input is not a single element that can be read as the spec version.
+pub fn read_element_from_string<E: XmlElement>(input: impl AsRef<str>, version: SpecVersion) -> E {
+    E::read_xml(input.as_ref(), version)
+}
+
+fn write_spec_element<S, M>(model: M) -> String
+where
+    S: TryFrom<M> + crate::xml::ToXml,
+    S::Error: std::fmt::Debug,
+{
+    let spec = S::try_from(model)
+        .unwrap_or_else(|error| panic!("Failed to convert the element: {error:?}"));
+    element::write_element_to_string(spec)
+}
+
+fn read_spec_element<S, M>(input: &str) -> M
+where
+    S: crate::xml::FromXml + Into<M>,
+{
+    element::read_element_from_string::<S>(input).into()
+}
+
+fn unsupported(element: &str, version: SpecVersion) -> ! {
+    panic!("The {element} element is not supported in spec version {version}")
+}
+
+macro_rules! impl_xml_element {
+    ($model:ty, $element:literal, $($version:ident => $spec:ty),+ $(,)?) => {
+        impl XmlElement for $model {}
+
+        impl private::Sealed for $model {
+            fn write_xml(self, version: SpecVersion) -> String {
+                match version {
+                    $(SpecVersion::$version => write_spec_element::<$spec, _>(self),)+
+                    #[allow(unreachable_patterns)]
+                    _ => unsupported($element, version),
+                }
+            }
+
+            fn read_xml(input: &str, version: SpecVersion) -> Self {
+                match version {
+                    $(SpecVersion::$version => read_spec_element::<$spec, _>(input),)+
+                    #[allow(unreachable_patterns)]
+                    _ => unsupported($element, version),
+                }
+            }
+        }
+    };
+}
+
+impl_xml_element!(
+    Metadata,
+    "metadata",
+    V1_3 => crate::specs::v1_3::metadata::Metadata,
+    V1_4 => crate::specs::v1_4::metadata::Metadata,
+    V1_5 => crate::specs::v1_5::metadata::Metadata,
+    V1_6 => crate::specs::v1_6::metadata::Metadata,
+);
+
+impl_xml_element!(
+    Component,
+    "component",
+    V1_3 => crate::specs::v1_3::component::Component,
+    V1_4 => crate::specs::v1_4::component::Component,
+    V1_5 => crate::specs::v1_5::component::Component,
+    V1_6 => crate::specs::v1_6::component::Component,
+);
+
+impl_xml_element!(
+    ModelCard,
+    "modelCard",
+    V1_5 => crate::specs::v1_5::modelcard::ModelCard,
+    V1_6 => crate::specs::v1_6::modelcard::ModelCard,
+);
+
+impl_xml_element!(
+    Service,
+    "service",
+    V1_3 => crate::specs::v1_3::service::Service,
+    V1_4 => crate::specs::v1_4::service::Service,
+    V1_5 => crate::specs::v1_5::service::Service,
+    V1_6 => crate::specs::v1_6::service::Service,
+);
+
+impl_xml_element!(
+    Vulnerability,
+    "vulnerability",
+    V1_4 => crate::specs::v1_4::vulnerability::Vulnerability,
+    V1_5 => crate::specs::v1_5::vulnerability::Vulnerability,
+    V1_6 => crate::specs::v1_6::vulnerability::Vulnerability,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::Validate;
+
+    #[test]
+    fn it_should_validate_the_example_bom() {
+        let validation_result = example_bom().validate();
+        assert!(
+            validation_result.passed(),
+            "Example BOM failed validation: {validation_result:#?}"
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_the_example_bom() {
+        let mut output = Vec::new();
+        example_bom()
+            .output_as_json_v1_6(&mut output)
+            .expect("Failed to write JSON");
+        let bom = Bom::parse_from_json_v1_6(output.as_slice()).expect("Failed to read JSON");
+        assert_eq!(bom, example_bom());
+
+        let mut output = Vec::new();
+        example_bom()
+            .output_as_xml_v1_6(&mut output)
+            .expect("Failed to write XML");
+        let bom = Bom::parse_from_xml_v1_6(output.as_slice()).expect("Failed to read XML");
+        assert_eq!(bom, example_bom());
+    }
+
+    #[test]
+    fn it_should_round_trip_elements() {
+        // The `manufacturer` of the metadata was added in 1.6
+        let xml = write_element_to_string(example_metadata(), SpecVersion::V1_6);
+        assert_eq!(
+            read_element_from_string::<Metadata>(xml, SpecVersion::V1_6),
+            example_metadata()
+        );
+
+        for version in [SpecVersion::V1_5, SpecVersion::V1_6] {
+            let xml = write_element_to_string(example_ml_component(), version);
+            assert_eq!(
+                read_element_from_string::<Component>(xml, version),
+                example_ml_component()
+            );
+
+            let xml = write_element_to_string(example_modelcard(), version);
+            assert_eq!(
+                read_element_from_string::<ModelCard>(xml, version),
+                example_modelcard()
+            );
+
+            let xml = write_element_to_string(example_service(), version);
+            assert_eq!(
+                read_element_from_string::<Service>(xml, version),
+                example_service()
+            );
+
+            let xml = write_element_to_string(example_vulnerability(), version);
+            assert_eq!(
+                read_element_from_string::<Vulnerability>(xml, version),
+                example_vulnerability()
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_write_elements_of_older_versions() {
+        let xml = write_element_to_string(example_component(), SpecVersion::V1_3);
+        assert!(xml.contains("<component type=\"library\""));
+
+        let xml = write_element_to_string(example_vulnerability(), SpecVersion::V1_4);
+        assert!(xml.contains("<id>CVE-2024-0001</id>"));
+    }
+
+    #[test]
+    #[should_panic(expected = "not supported in spec version 1.3")]
+    fn it_should_panic_on_unsupported_versions() {
+        write_element_to_string(example_vulnerability(), SpecVersion::V1_3);
+    }
+}
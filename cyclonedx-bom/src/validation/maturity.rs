@@ -0,0 +1,394 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Scores which level of the CycloneDX BOM maturity model a BOM achieves.
+//!
+//! Each level adds criteria to the previous one, a BOM achieves a level if it satisfies all
+//! criteria up to that level for the metadata and every component:
+//!
+//! 1. [`Minimum`](MaturityLevel::Minimum): the [NTIA minimum elements](super::ntia)
+//! 2. [`Licensed`](MaturityLevel::Licensed): licenses of all components
+//! 3. [`Verified`](MaturityLevel::Verified): hashes of all components
+//! 4. [`Traceable`](MaturityLevel::Traceable): the provenance of all components
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::validation::maturity::{score, MaturityLevel};
+//!
+//! let scorecard = score(&Bom::default());
+//! assert_eq!(scorecard.level, None);
+//! assert!(!scorecard.meets(MaturityLevel::Minimum));
+//! ```
+
+use std::fmt::Display;
+
+use crate::models::{bom::Bom, component::Component, external_reference::ExternalReferenceType};
+use crate::validation::ntia::{check_minimum_elements, NtiaElement};
+
+/// A level of the maturity model, ordered from the lowest to the highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MaturityLevel {
+    Minimum,
+    Licensed,
+    Verified,
+    Traceable,
+}
+
+impl MaturityLevel {
+    pub const ALL: [MaturityLevel; 4] = [
+        MaturityLevel::Minimum,
+        MaturityLevel::Licensed,
+        MaturityLevel::Verified,
+        MaturityLevel::Traceable,
+    ];
+}
+
+impl Display for MaturityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MaturityLevel::Minimum => "minimum",
+            MaturityLevel::Licensed => "licensed",
+            MaturityLevel::Verified => "verified",
+            MaturityLevel::Traceable => "traceable",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A criterion of the maturity model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaturityCriterion {
+    /// An NTIA minimum element
+    Ntia(NtiaElement),
+    /// At least one license of a component
+    Licenses,
+    /// At least one hash of a component
+    Hashes,
+    /// A pedigree, or a `vcs` or `distribution` external reference of a component
+    Provenance,
+}
+
+impl MaturityCriterion {
+    /// The level which requires the criterion
+    pub fn level(&self) -> MaturityLevel {
+        match self {
+            MaturityCriterion::Ntia(_) => MaturityLevel::Minimum,
+            MaturityCriterion::Licenses => MaturityLevel::Licensed,
+            MaturityCriterion::Hashes => MaturityLevel::Verified,
+            MaturityCriterion::Provenance => MaturityLevel::Traceable,
+        }
+    }
+}
+
+impl Display for MaturityCriterion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaturityCriterion::Ntia(element) => write!(f, "{element}"),
+            MaturityCriterion::Licenses => write!(f, "licenses"),
+            MaturityCriterion::Hashes => write!(f, "hashes"),
+            MaturityCriterion::Provenance => write!(f, "provenance"),
+        }
+    }
+}
+
+/// How many of the elements a criterion applies to satisfy it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CriterionScore {
+    pub criterion: MaturityCriterion,
+    /// The number of satisfying elements, i.e. components or the BOM metadata
+    pub satisfied: usize,
+    pub total: usize,
+}
+
+impl CriterionScore {
+    /// Returns `true` if all elements satisfy the criterion.
+    pub fn passed(&self) -> bool {
+        self.satisfied == self.total
+    }
+
+    /// Returns the share of satisfying elements between `0.0` and `1.0`, `1.0` without elements.
+    pub fn coverage(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.satisfied as f64 / self.total as f64
+        }
+    }
+}
+
+/// The maturity of a BOM, see [`score`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaturityScorecard {
+    /// The highest level the BOM achieves, `None` if it misses some NTIA minimum elements
+    pub level: Option<MaturityLevel>,
+    /// The scores of all criteria, ordered by their level
+    pub criteria: Vec<CriterionScore>,
+}
+
+impl MaturityScorecard {
+    /// Returns `true` if the BOM achieves at least the given level, e.g. to fail a CI quality gate.
+    pub fn meets(&self, level: MaturityLevel) -> bool {
+        self.level.is_some_and(|achieved| achieved >= level)
+    }
+
+    /// Returns the scores of the criteria which are not satisfied by all elements.
+    pub fn failed(&self) -> impl Iterator<Item = &CriterionScore> {
+        self.criteria.iter().filter(|score| !score.passed())
+    }
+}
+
+/// Renders one line per criterion, e.g. `licensed  licenses  12/14 (85.7%)`.
+impl Display for MaturityScorecard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.level {
+            Some(level) => writeln!(f, "Maturity level: {level}")?,
+            None => writeln!(f, "Maturity level: none")?,
+        }
+        for score in &self.criteria {
+            writeln!(
+                f,
+                "{:<10}{:<26}{}/{} ({:.1}%)",
+                score.criterion.level().to_string(),
+                score.criterion.to_string(),
+                score.satisfied,
+                score.total,
+                score.coverage() * 100.0
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Scores the criteria of all levels for the metadata and the components of the BOM, including
+/// the metadata component and nested components.
+pub fn score(bom: &Bom) -> MaturityScorecard {
+    let ntia = check_minimum_elements(bom);
+    let components: Vec<&Component> = bom.iter_components_recursive().collect();
+
+    let mut criteria = Vec::new();
+    for element in [NtiaElement::Author, NtiaElement::Timestamp] {
+        criteria.push(CriterionScore {
+            criterion: MaturityCriterion::Ntia(element),
+            satisfied: usize::from(!ntia.missing.contains(&element)),
+            total: 1,
+        });
+    }
+    for element in [
+        NtiaElement::Supplier,
+        NtiaElement::ComponentName,
+        NtiaElement::Version,
+        NtiaElement::UniqueIdentifier,
+        NtiaElement::DependencyRelationship,
+    ] {
+        criteria.push(CriterionScore {
+            criterion: MaturityCriterion::Ntia(element),
+            satisfied: ntia
+                .components
+                .iter()
+                .filter(|component| !component.missing.contains(&element))
+                .count(),
+            total: ntia.components.len(),
+        });
+    }
+
+    for criterion in [
+        MaturityCriterion::Licenses,
+        MaturityCriterion::Hashes,
+        MaturityCriterion::Provenance,
+    ] {
+        let satisfies = match criterion {
+            MaturityCriterion::Licenses => has_licenses,
+            MaturityCriterion::Hashes => has_hashes,
+            _ => has_provenance,
+        };
+        criteria.push(CriterionScore {
+            criterion,
+            satisfied: components
+                .iter()
+                .filter(|component| satisfies(component))
+                .count(),
+            total: components.len(),
+        });
+    }
+
+    let level = MaturityLevel::ALL
+        .into_iter()
+        .take_while(|level| {
+            criteria
+                .iter()
+                .filter(|score| score.criterion.level() == *level)
+                .all(CriterionScore::passed)
+        })
+        .last();
+
+    MaturityScorecard { level, criteria }
+}
+
+fn has_licenses(component: &Component) -> bool {
+    component
+        .licenses
+        .as_ref()
+        .is_some_and(|licenses| !licenses.0.is_empty())
+}
+
+fn has_hashes(component: &Component) -> bool {
+    component
+        .hashes
+        .as_ref()
+        .is_some_and(|hashes| !hashes.0.is_empty())
+}
+
+fn has_provenance(component: &Component) -> bool {
+    let has_reference = component.external_references.iter().any(|references| {
+        references.0.iter().any(|reference| {
+            matches!(
+                reference.external_reference_type,
+                ExternalReferenceType::Vcs | ExternalReferenceType::Distribution
+            )
+        })
+    });
+    has_reference || component.pedigree.is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        external_models::{date_time::DateTime, uri::Purl, uri::Uri},
+        models::{
+            component::{Classification, Components},
+            dependency::{Dependencies, Dependency},
+            external_reference::{ExternalReference, ExternalReferences},
+            hash::{Hash, HashAlgorithm, HashValue, Hashes},
+            license::{LicenseChoice, Licenses},
+            metadata::Metadata,
+            organization::{OrganizationalContact, OrganizationalEntity},
+        },
+        prelude::SpdxExpression,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn bom(components: Vec<Component>) -> Bom {
+        Bom {
+            metadata: Some(Metadata {
+                timestamp: Some(DateTime::now().expect("Failed to create timestamp")),
+                authors: Some(vec![OrganizationalContact::new("Jane", None)]),
+                ..Metadata::default()
+            }),
+            dependencies: Some(Dependencies(
+                components
+                    .iter()
+                    .filter_map(|component| component.bom_ref.clone())
+                    .map(|dependency_ref| Dependency {
+                        dependency_ref,
+                        dependencies: vec![],
+                    })
+                    .collect(),
+            )),
+            components: Some(Components(components)),
+            ..Bom::default()
+        }
+    }
+
+    fn minimum_component(name: &str) -> Component {
+        let mut component =
+            Component::new(Classification::Library, name, "1.0.0", Some(name.into()));
+        component.supplier = Some(OrganizationalEntity::new("Acme"));
+        component.purl = Purl::new("cargo", name, "1.0.0").ok();
+        component
+    }
+
+    fn licensed_component(name: &str) -> Component {
+        let mut component = minimum_component(name);
+        component.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+            SpdxExpression::new("MIT"),
+        )]));
+        component
+    }
+
+    #[test]
+    fn it_should_score_the_highest_level_with_all_criteria_satisfied() {
+        let mut verified = licensed_component("verified");
+        verified.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA_256,
+            content: HashValue("0".repeat(64)),
+        }]));
+        let mut traceable = verified.clone();
+        traceable.external_references = Some(ExternalReferences(vec![ExternalReference::new(
+            ExternalReferenceType::Vcs,
+            Uri::new("https://example.com/repository"),
+        )]));
+
+        assert_eq!(
+            score(&bom(vec![minimum_component("minimum")])).level,
+            Some(MaturityLevel::Minimum)
+        );
+        assert_eq!(
+            score(&bom(vec![licensed_component("licensed")])).level,
+            Some(MaturityLevel::Licensed)
+        );
+        assert_eq!(
+            score(&bom(vec![verified.clone()])).level,
+            Some(MaturityLevel::Verified)
+        );
+        assert_eq!(
+            score(&bom(vec![traceable.clone()])).level,
+            Some(MaturityLevel::Traceable)
+        );
+
+        let scorecard = score(&bom(vec![traceable, verified]));
+        assert_eq!(scorecard.level, Some(MaturityLevel::Verified));
+        assert!(scorecard.meets(MaturityLevel::Licensed));
+        assert!(!scorecard.meets(MaturityLevel::Traceable));
+        assert_eq!(
+            scorecard.failed().collect::<Vec<_>>(),
+            [&CriterionScore {
+                criterion: MaturityCriterion::Provenance,
+                satisfied: 1,
+                total: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_not_skip_levels() {
+        let mut component = minimum_component("hashed");
+        component.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA_256,
+            content: HashValue("0".repeat(64)),
+        }]));
+        component.supplier = None;
+
+        let scorecard = score(&bom(vec![component]));
+
+        assert_eq!(scorecard.level, None);
+        assert!(!scorecard.meets(MaturityLevel::Minimum));
+        let failed: Vec<_> = scorecard.failed().map(|score| score.criterion).collect();
+        assert_eq!(
+            failed,
+            [
+                MaturityCriterion::Ntia(NtiaElement::Supplier),
+                MaturityCriterion::Licenses,
+                MaturityCriterion::Provenance,
+            ]
+        );
+        assert!(scorecard
+            .to_string()
+            .contains("licensed  licenses                  0/1 (0.0%)"));
+    }
+}
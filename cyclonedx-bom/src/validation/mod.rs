@@ -16,6 +16,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 use std::{
+    cell::Cell,
     collections::{BTreeMap, HashSet},
     fmt::Display,
     hash::Hash,
@@ -28,11 +29,69 @@ use indexmap::{
 
 use crate::models::bom::SpecVersion;
 
+pub mod maturity;
+pub mod ntia;
+pub mod rules;
+
+/// Machine-readable codes of [`ValidationIssue`]s, to route or suppress specific findings.
+pub mod code {
+    /// A field has an invalid value, used when the check does not define a more specific code
+    pub const INVALID_FIELD: &str = "invalid-field";
+    /// An enum has an unknown or unsupported variant
+    pub const UNKNOWN_VARIANT: &str = "unknown-variant";
+    /// A custom check failed, used when the check does not define a more specific code
+    pub const INVALID: &str = "invalid";
+    /// A URI does not conform to the configured [`UriValidation`](super::UriValidation)
+    pub const INVALID_URI: &str = "invalid-uri";
+    /// A purl does not conform to the Package URL spec
+    pub const INVALID_PURL: &str = "invalid-purl";
+    /// A date time does not conform to ISO 8601
+    pub const INVALID_DATE_TIME: &str = "invalid-date-time";
+    /// A normalized string contains line breaks or tabs
+    pub const INVALID_NORMALIZED_STRING: &str = "invalid-normalized-string";
+    /// A license identifier is not a valid SPDX identifier
+    pub const INVALID_SPDX_IDENTIFIER: &str = "invalid-spdx-identifier";
+    /// A license expression is not a valid SPDX expression
+    pub const INVALID_SPDX_EXPRESSION: &str = "invalid-spdx-expression";
+    /// A serial number is not a valid URN UUID
+    pub const INVALID_URN_UUID: &str = "invalid-urn-uuid";
+    /// An element of a list that must be unique is repeated
+    pub const DUPLICATE_ELEMENT: &str = "duplicate-element";
+    /// A bom-ref is used by more than one element of the BOM
+    pub const DUPLICATE_BOM_REF: &str = "duplicate-bom-ref";
+    /// A reference does not point to an element of the BOM
+    pub const UNKNOWN_REFERENCE: &str = "unknown-reference";
+    /// An NTIA minimum element is missing, see [`ntia`](super::ntia)
+    pub const MISSING_NTIA_ELEMENT: &str = "missing-ntia-element";
+}
+
 /// Contains all collected validation errors.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct ValidationResult {
     /// Maps names to validation errors.
     pub(crate) inner: IndexMap<String, ValidationErrorsKind>,
+    /// The bom-ref of the validated element, to locate its errors in the [`ValidationPath`].
+    pub(crate) bom_ref: Option<String>,
+}
+
+/// Results are equal if they contain the same errors, the bom-ref only helps to locate them.
+impl PartialEq for ValidationResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+/// Renders one [`ValidationIssue`] per line.
+impl Display for ValidationResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, issue) in self.issues().iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{issue}")?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for ValidationResult {
@@ -49,6 +108,7 @@ impl From<Vec<ValidationResult>> for ValidationResult {
             for (key, value) in error.inner.into_iter() {
                 result.inner.insert(key, value);
             }
+            result.bom_ref = result.bom_ref.or(error.bom_ref);
         }
         result
     }
@@ -71,6 +131,7 @@ impl ValidationResult {
     pub fn new() -> Self {
         Self {
             inner: IndexMap::new(),
+            bom_ref: None,
         }
     }
 
@@ -98,6 +159,61 @@ impl ValidationResult {
         self.inner.into_iter()
     }
 
+    /// Returns all errors as a flat list of [`ValidationIssue`]s with their paths and codes.
+    pub fn issues(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        self.collect_issues(&mut Vec::new(), &mut issues);
+        issues
+    }
+
+    fn collect_issues(&self, path: &mut Vec<PathSegment>, issues: &mut Vec<ValidationIssue>) {
+        let issue = |path: &[PathSegment], error: &ValidationError, default_code| ValidationIssue {
+            path: ValidationPath(path.to_vec()),
+            code: error.code.unwrap_or(default_code),
+            message: error.message.clone(),
+        };
+
+        for (name, kind) in &self.inner {
+            let depth = path.len();
+            if !name.is_empty() {
+                path.push(PathSegment::Field(name.clone()));
+            }
+            match kind {
+                ValidationErrorsKind::Struct(result) => {
+                    if let Some(bom_ref) = &result.bom_ref {
+                        path.push(PathSegment::BomRef(bom_ref.clone()));
+                    }
+                    result.collect_issues(path, issues);
+                }
+                ValidationErrorsKind::List(results) => {
+                    for (index, result) in results {
+                        let list_depth = path.len();
+                        path.push(PathSegment::Index(*index));
+                        if let Some(bom_ref) = &result.bom_ref {
+                            path.push(PathSegment::BomRef(bom_ref.clone()));
+                        }
+                        result.collect_issues(path, issues);
+                        path.truncate(list_depth);
+                    }
+                }
+                ValidationErrorsKind::Field(errors) => {
+                    for error in errors {
+                        issues.push(issue(path, error, code::INVALID_FIELD));
+                    }
+                }
+                ValidationErrorsKind::Enum(error) => {
+                    issues.push(issue(path, error, code::UNKNOWN_VARIANT))
+                }
+                ValidationErrorsKind::Custom(errors) => {
+                    for error in errors {
+                        issues.push(issue(path, error, code::INVALID));
+                    }
+                }
+            }
+            path.truncate(depth);
+        }
+    }
+
     /// Adds a nested object kind
     fn add_nested(&mut self, nested_name: &str, errors_kind: ValidationErrorsKind) {
         if let Vacant(entry) = self.inner.entry(nested_name.to_string()) {
@@ -162,6 +278,12 @@ impl ValidationContext {
         }
     }
 
+    /// Records the bom-ref of the validated element, which then appears in the paths of its issues.
+    pub fn set_bom_ref(&mut self, bom_ref: Option<&str>) -> &mut Self {
+        self.state.bom_ref = bom_ref.map(ToString::to_string);
+        self
+    }
+
     pub fn add_field<T>(
         &mut self,
         field_name: &str,
@@ -256,7 +378,8 @@ impl ValidationContext {
 
         for (index, item) in list.into_iter().enumerate() {
             if !set.insert(item) {
-                child_errors.insert(index, Err(ValidationError::new("repeated element")).into());
+                child_errors.insert(index, Err(ValidationError::new("repeated element").with_code(code::DUPLICATE_ELEMENT))
+                        .into());
             } else {
                 let result = validation(item).into();
                 if result.has_errors() {
@@ -368,17 +491,88 @@ pub trait Validate {
     fn validate(&self) -> ValidationResult {
         self.validate_version(SpecVersion::default())
     }
+
+    /// Validates like [`validate`](Self::validate), with the given options instead of the defaults.
+    fn validate_with_options(&self, options: ValidationOptions) -> ValidationResult {
+        options.apply(|| self.validate())
+    }
+}
+
+/// How strictly URIs, such as the urls of external references, advisories and data contents, are validated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UriValidation {
+    /// URI references must conform to RFC 3986, this is the default
+    #[default]
+    Strict,
+    /// Also accept IRIs as of RFC 3987, which may contain unescaped non-ASCII characters
+    IriPermissive,
+    /// Do not validate URIs at all
+    Off,
+}
+
+/// Options for the checks of [`Validate`] which are not decided by the specification alone.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationOptions {
+    pub uri: UriValidation,
+}
+
+thread_local! {
+    static OPTIONS: Cell<ValidationOptions> = Cell::new(ValidationOptions::default());
+}
+
+impl ValidationOptions {
+    /// Runs `validation` with these options, which apply to all validations on the current thread
+    /// until it returns, e.g. to validate a [`Bom`](crate::models::bom::Bom) in a specific version:
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    /// use cyclonedx_bom::validation::{UriValidation, Validate, ValidationOptions};
+    ///
+    /// let options = ValidationOptions { uri: UriValidation::IriPermissive };
+    /// let result = options.apply(|| Bom::default().validate_version(SpecVersion::V1_5));
+    /// assert!(result.passed());
+    /// ```
+    pub fn apply<R>(self, validation: impl FnOnce() -> R) -> R {
+        /// Restores the previous options, also if the validation panics
+        struct Restore(ValidationOptions);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                OPTIONS.with(|options| options.set(self.0));
+            }
+        }
+
+        let _restore = Restore(OPTIONS.with(|options| options.replace(self)));
+        validation()
+    }
+
+    /// The options the current validation runs with
+    pub(crate) fn current() -> Self {
+        OPTIONS.with(Cell::get)
+    }
 }
 
 /// A single validation error with a message, useful to log / display for user.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct ValidationError {
     pub message: String,
+    /// The machine-readable code of the failed check, one of [`code`] or a custom one
+    pub code: Option<&'static str>,
+}
+
+/// Errors are equal if their messages are, the code only classifies the message.
+impl PartialEq for ValidationError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
 }
 
 impl From<String> for ValidationError {
     fn from(message: String) -> Self {
-        ValidationError { message }
+        ValidationError {
+            message,
+            code: None,
+        }
     }
 }
 
@@ -392,6 +586,85 @@ impl ValidationError {
     pub fn new<D: Display>(message: D) -> Self {
         Self {
             message: message.to_string(),
+            code: None,
+        }
+    }
+
+    /// Sets the machine-readable code of the error.
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// A segment of a [`ValidationPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// The name of a field, e.g. `components`
+    Field(String),
+    /// The index of an element in a list
+    Index(usize),
+    /// The bom-ref of the element the path leads through
+    BomRef(String),
+}
+
+/// The location of a [`ValidationIssue`] in the validated structure.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ValidationPath(pub Vec<PathSegment>);
+
+impl ValidationPath {
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    /// Returns the bom-ref of the innermost element with a bom-ref, if any
+    pub fn bom_ref(&self) -> Option<&str> {
+        self.0.iter().rev().find_map(|segment| match segment {
+            PathSegment::BomRef(bom_ref) => Some(bom_ref.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns the field names of the path, ignoring indices and bom-refs, e.g. to match every component
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().filter_map(|segment| match segment {
+            PathSegment::Field(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+}
+
+/// Renders paths like `components[0][bom-ref=a].licenses[1].license`.
+impl Display for ValidationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if index == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(list_index) => write!(f, "[{list_index}]")?,
+                PathSegment::BomRef(bom_ref) => write!(f, "[bom-ref={bom_ref}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A single finding of a validation, see [`ValidationResult::issues`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationIssue {
+    pub path: ValidationPath,
+    /// The machine-readable code of the failed check, see [`code`]
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// Renders the issue as `path: message`.
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.0.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
         }
     }
 }
@@ -477,7 +750,100 @@ mod tests {
         validation::{field, r#enum, r#struct, Validate, ValidationErrorsKind, ValidationResult},
     };
 
-    use super::{ValidationContext, ValidationError};
+    use super::{
+        code, PathSegment, UriValidation, ValidationContext, ValidationError, ValidationIssue,
+        ValidationOptions, ValidationPath,
+    };
+
+    #[test]
+    fn it_should_apply_the_options_while_validating() {
+        let options = ValidationOptions {
+            uri: UriValidation::Off,
+        };
+        assert_eq!(ValidationOptions::current(), ValidationOptions::default());
+        assert_eq!(options.apply(ValidationOptions::current), options);
+
+        let nested = ValidationOptions {
+            uri: UriValidation::IriPermissive,
+        };
+        options.apply(|| {
+            assert_eq!(nested.apply(ValidationOptions::current), nested);
+            assert_eq!(ValidationOptions::current(), options);
+        });
+        assert_eq!(ValidationOptions::current(), ValidationOptions::default());
+    }
+
+    #[test]
+    fn it_should_flatten_errors_into_issues_with_paths_and_codes() {
+        let mut component = ValidationContext::new();
+        component
+            .set_bom_ref(Some("library"))
+            .add_field("name", "", |_| Err("Name is empty".into()))
+            .add_field("purl", "", |_| {
+                Err(ValidationError::new("Purl is invalid").with_code(code::INVALID_PURL))
+            });
+        let component = ValidationResult::from(component);
+        let mut bom = ValidationContext::new();
+        bom.add_list("components", &[(), ()], |_| component.clone())
+            .add_enum("kind", &(), |_| Err("Unknown kind".into()));
+        let result = ValidationResult::from(bom);
+
+        let issues = result.issues();
+
+        let component_path = |index, field: &str| {
+            ValidationPath(vec![
+                PathSegment::Field("components".to_string()),
+                PathSegment::Index(index),
+                PathSegment::BomRef("library".to_string()),
+                PathSegment::Field(field.to_string()),
+            ])
+        };
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue {
+                    path: component_path(0, "name"),
+                    code: code::INVALID_FIELD,
+                    message: "Name is empty".to_string(),
+                },
+                ValidationIssue {
+                    path: component_path(0, "purl"),
+                    code: code::INVALID_PURL,
+                    message: "Purl is invalid".to_string(),
+                },
+                ValidationIssue {
+                    path: component_path(1, "name"),
+                    code: code::INVALID_FIELD,
+                    message: "Name is empty".to_string(),
+                },
+                ValidationIssue {
+                    path: component_path(1, "purl"),
+                    code: code::INVALID_PURL,
+                    message: "Purl is invalid".to_string(),
+                },
+                ValidationIssue {
+                    path: ValidationPath(vec![PathSegment::Field("kind".to_string())]),
+                    code: code::UNKNOWN_VARIANT,
+                    message: "Unknown kind".to_string(),
+                },
+            ]
+        );
+        assert_eq!(issues[1].path.bom_ref(), Some("library"));
+        assert_eq!(
+            issues[1].path.field_names().collect::<Vec<_>>(),
+            ["components", "purl"]
+        );
+        assert_eq!(
+            result.to_string().lines().collect::<Vec<_>>(),
+            [
+                "components[0][bom-ref=library].name: Name is empty",
+                "components[0][bom-ref=library].purl: Purl is invalid",
+                "components[1][bom-ref=library].name: Name is empty",
+                "components[1][bom-ref=library].purl: Purl is invalid",
+                "kind: Unknown kind",
+            ]
+        );
+    }
 
     #[test]
     fn has_error() {
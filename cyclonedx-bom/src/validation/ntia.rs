@@ -0,0 +1,362 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Checks the [NTIA minimum elements](https://www.ntia.gov/report/2021/minimum-elements-software-bill-materials-sbom)
+//! of a software bill of materials.
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::validation::ntia::{check_minimum_elements, NtiaElement};
+//!
+//! let report = check_minimum_elements(&Bom::default());
+//! assert_eq!(report.missing, [NtiaElement::Author, NtiaElement::Timestamp]);
+//! ```
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use crate::models::{
+    bom::{Bom, SpecVersion},
+    component::Component,
+    organization::OrganizationalEntity,
+};
+use crate::validation::{
+    code, rules::ValidationRule, ValidationContext, ValidationError, ValidationResult,
+};
+
+/// A minimum element as defined by the NTIA for the data fields of an SBOM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NtiaElement {
+    /// The `supplier` of a component with a name
+    Supplier,
+    /// The `name` of a component
+    ComponentName,
+    /// The `version` of a component
+    Version,
+    /// A purl, CPE or SWID tag of a component
+    UniqueIdentifier,
+    /// An entry of the component in the `dependencies` of the BOM
+    DependencyRelationship,
+    /// The `authors` or `tools` of the BOM metadata
+    Author,
+    /// The `timestamp` of the BOM metadata
+    Timestamp,
+}
+
+impl NtiaElement {
+    /// The name of the field the element is recorded in
+    fn field_name(&self) -> &'static str {
+        match self {
+            NtiaElement::Supplier => "supplier",
+            NtiaElement::ComponentName => "name",
+            NtiaElement::Version => "version",
+            NtiaElement::UniqueIdentifier => "purl",
+            NtiaElement::DependencyRelationship => "dependencies",
+            NtiaElement::Author => "authors",
+            NtiaElement::Timestamp => "timestamp",
+        }
+    }
+}
+
+impl Display for NtiaElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            NtiaElement::Supplier => "supplier name",
+            NtiaElement::ComponentName => "component name",
+            NtiaElement::Version => "version of the component",
+            NtiaElement::UniqueIdentifier => "other unique identifiers",
+            NtiaElement::DependencyRelationship => "dependency relationship",
+            NtiaElement::Author => "author of SBOM data",
+            NtiaElement::Timestamp => "timestamp",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The NTIA minimum elements missing in a BOM, see [`check_minimum_elements`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NtiaReport {
+    /// The elements missing in the BOM metadata, i.e. the author and the timestamp
+    pub missing: Vec<NtiaElement>,
+    /// The components in the order of [`Bom::iter_components_recursive`]
+    pub components: Vec<NtiaComponentReport>,
+}
+
+/// The NTIA minimum elements missing for a single component.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NtiaComponentReport {
+    pub bom_ref: Option<String>,
+    pub name: String,
+    pub missing: Vec<NtiaElement>,
+}
+
+impl NtiaReport {
+    /// Returns `true` if all minimum elements are present.
+    pub fn passed(&self) -> bool {
+        self.missing.is_empty()
+            && self
+                .components
+                .iter()
+                .all(|component| component.missing.is_empty())
+    }
+
+    /// Returns the reports of the components which miss any elements.
+    pub fn incomplete_components(&self) -> impl Iterator<Item = &NtiaComponentReport> {
+        self.components
+            .iter()
+            .filter(|component| !component.missing.is_empty())
+    }
+}
+
+/// Reports every missing element as a [`ValidationIssue`](crate::validation::ValidationIssue)
+/// with the code [`MISSING_NTIA_ELEMENT`](code::MISSING_NTIA_ELEMENT).
+///
+/// Components are listed by their index in the report and their bom-ref.
+impl From<&NtiaReport> for ValidationResult {
+    fn from(report: &NtiaReport) -> Self {
+        let mut context = ValidationContext::new();
+        for element in &report.missing {
+            context.add_custom(element.field_name(), missing(element));
+        }
+        context.add_list("components", &report.components, |component| {
+            let mut context = ValidationContext::new();
+            context.set_bom_ref(component.bom_ref.as_deref());
+            for element in &component.missing {
+                context.add_custom(element.field_name(), missing(element));
+            }
+            ValidationResult::from(context)
+        });
+        context.into()
+    }
+}
+
+/// A [`ValidationRule`] that reports the missing NTIA minimum elements under the name `ntia`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NtiaMinimumElements;
+
+impl ValidationRule for NtiaMinimumElements {
+    fn name(&self) -> &str {
+        "ntia"
+    }
+
+    fn validate(&self, bom: &Bom, _version: SpecVersion) -> ValidationResult {
+        ValidationResult::from(&check_minimum_elements(bom))
+    }
+}
+
+fn missing(element: &NtiaElement) -> ValidationError {
+    ValidationError::new(format!("Missing NTIA minimum element: {element}"))
+        .with_code(code::MISSING_NTIA_ELEMENT)
+}
+
+/// Checks which NTIA minimum elements are missing in the metadata and in each component of the BOM,
+/// including the metadata component and nested components.
+pub fn check_minimum_elements(bom: &Bom) -> NtiaReport {
+    let mut missing = Vec::new();
+    let metadata = bom.metadata.as_ref();
+    let has_authors = metadata
+        .and_then(|metadata| metadata.authors.as_ref())
+        .is_some_and(|authors| !authors.is_empty());
+    let has_tools = metadata.is_some_and(|metadata| metadata.tools.is_some());
+    if !has_authors && !has_tools {
+        missing.push(NtiaElement::Author);
+    }
+    if metadata
+        .and_then(|metadata| metadata.timestamp.as_ref())
+        .is_none()
+    {
+        missing.push(NtiaElement::Timestamp);
+    }
+
+    let related: HashSet<&str> = bom
+        .dependencies
+        .iter()
+        .flat_map(|dependencies| &dependencies.0)
+        .flat_map(|dependency| {
+            std::iter::once(&dependency.dependency_ref).chain(&dependency.dependencies)
+        })
+        .map(String::as_str)
+        .collect();
+
+    let components = bom
+        .iter_components_recursive()
+        .map(|component| check_component(component, &related))
+        .collect();
+
+    NtiaReport {
+        missing,
+        components,
+    }
+}
+
+fn check_component(component: &Component, related: &HashSet<&str>) -> NtiaComponentReport {
+    let mut missing = Vec::new();
+    if !component
+        .supplier
+        .as_ref()
+        .is_some_and(has_organization_name)
+    {
+        missing.push(NtiaElement::Supplier);
+    }
+    if component.name.is_empty() {
+        missing.push(NtiaElement::ComponentName);
+    }
+    if !component
+        .version
+        .as_ref()
+        .is_some_and(|version| !version.is_empty())
+    {
+        missing.push(NtiaElement::Version);
+    }
+    if component.purl.is_none() && component.cpe.is_none() && component.swid.is_none() {
+        missing.push(NtiaElement::UniqueIdentifier);
+    }
+    if !component
+        .bom_ref
+        .as_deref()
+        .is_some_and(|bom_ref| related.contains(bom_ref))
+    {
+        missing.push(NtiaElement::DependencyRelationship);
+    }
+
+    NtiaComponentReport {
+        bom_ref: component.bom_ref.clone(),
+        name: component.name.to_string(),
+        missing,
+    }
+}
+
+fn has_organization_name(organization: &OrganizationalEntity) -> bool {
+    organization
+        .name
+        .as_ref()
+        .is_some_and(|name| !name.trim().is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Purl},
+        models::{
+            component::{Classification, Components},
+            dependency::{Dependencies, Dependency},
+            metadata::Metadata,
+            organization::OrganizationalContact,
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    fn complete_component(bom_ref: &str) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            bom_ref,
+            "1.0.0",
+            Some(bom_ref.into()),
+        );
+        component.supplier = Some(OrganizationalEntity::new("Acme"));
+        component.purl = Purl::new("cargo", bom_ref, "1.0.0").ok();
+        component
+    }
+
+    #[test]
+    fn it_should_pass_a_complete_bom() {
+        let metadata = Metadata {
+            timestamp: Some(DateTime::now().expect("Failed to create timestamp")),
+            authors: Some(vec![OrganizationalContact::new("Jane", None)]),
+            component: Some(complete_component("root")),
+            ..Metadata::default()
+        };
+        let bom = Bom {
+            metadata: Some(metadata),
+            components: Some(Components(vec![complete_component("library")])),
+            dependencies: Some(Dependencies(vec![Dependency {
+                dependency_ref: "root".to_string(),
+                dependencies: vec!["library".to_string()],
+            }])),
+            ..Bom::default()
+        };
+
+        let report = check_minimum_elements(&bom);
+
+        assert!(report.passed(), "{report:?}");
+        assert!(ValidationResult::from(&report).passed());
+        assert!(NtiaMinimumElements
+            .validate(&bom, SpecVersion::V1_5)
+            .passed());
+    }
+
+    #[test]
+    fn it_should_report_the_missing_elements_per_component() {
+        let mut nested = Component::new(Classification::Library, "nested", "", None);
+        nested.supplier = Some(OrganizationalEntity {
+            name: Some(NormalizedString::new(" ")),
+            ..OrganizationalEntity::new("")
+        });
+        let mut library = complete_component("library");
+        library.components = Some(Components(vec![nested]));
+        let bom = Bom {
+            components: Some(Components(vec![library])),
+            ..Bom::default()
+        };
+
+        let report = check_minimum_elements(&bom);
+
+        assert_eq!(
+            report.components,
+            vec![
+                NtiaComponentReport {
+                    bom_ref: Some("library".to_string()),
+                    name: "library".to_string(),
+                    missing: vec![NtiaElement::DependencyRelationship],
+                },
+                NtiaComponentReport {
+                    bom_ref: None,
+                    name: "nested".to_string(),
+                    missing: vec![
+                        NtiaElement::Supplier,
+                        NtiaElement::Version,
+                        NtiaElement::UniqueIdentifier,
+                        NtiaElement::DependencyRelationship,
+                    ],
+                },
+            ]
+        );
+        assert_eq!(report.incomplete_components().count(), 2);
+
+        let issues = ValidationResult::from(&report).issues();
+        assert!(issues
+            .iter()
+            .all(|issue| issue.code == code::MISSING_NTIA_ELEMENT));
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| issue.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "authors: Missing NTIA minimum element: author of SBOM data",
+                "timestamp: Missing NTIA minimum element: timestamp",
+                "components[0][bom-ref=library].dependencies: Missing NTIA minimum element: dependency relationship",
+                "components[1].supplier: Missing NTIA minimum element: supplier name",
+                "components[1].version: Missing NTIA minimum element: version of the component",
+                "components[1].purl: Missing NTIA minimum element: other unique identifiers",
+                "components[1].dependencies: Missing NTIA minimum element: dependency relationship",
+            ]
+        );
+    }
+}
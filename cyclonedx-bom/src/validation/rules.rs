@@ -0,0 +1,259 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! User-defined [`ValidationRule`]s that run alongside the built-in validation of a [`Bom`].
+//!
+//! ```
+//! use cyclonedx_bom::models::bom::Bom;
+//! use cyclonedx_bom::validation::rules::{ComponentRule, Validator};
+//!
+//! let validator = Validator::new().with_rule(ComponentRule::new("owner", |component| {
+//!     match component.properties.as_ref().and_then(|p| p.get("acme:owner")) {
+//!         Some(_) => Ok(()),
+//!         None => Err("Internal components need an owner".into()),
+//!     }
+//! }));
+//! assert!(validator.validate(&Bom::default()).passed());
+//! ```
+
+use indexmap::map::Entry;
+
+use crate::models::{
+    bom::{Bom, SpecVersion},
+    component::Component,
+};
+use crate::validation::{
+    Validate, ValidationContext, ValidationError, ValidationErrorsKind, ValidationOptions,
+    ValidationResult,
+};
+
+/// A check of a whole [`Bom`] in addition to the built-in validation.
+pub trait ValidationRule {
+    /// The name of the rule, which is the first segment of the paths of its issues
+    fn name(&self) -> &str;
+
+    fn validate(&self, bom: &Bom, version: SpecVersion) -> ValidationResult;
+}
+
+/// A rule that checks every component of the BOM including the metadata component and nested
+/// components, listing them in the order of [`Bom::iter_components_recursive`].
+pub struct ComponentRule<F> {
+    name: String,
+    check: F,
+}
+
+impl<F> ComponentRule<F>
+where
+    F: Fn(&Component) -> Result<(), ValidationError>,
+{
+    pub fn new(name: &str, check: F) -> Self {
+        Self {
+            name: name.to_string(),
+            check,
+        }
+    }
+}
+
+impl<F> ValidationRule for ComponentRule<F>
+where
+    F: Fn(&Component) -> Result<(), ValidationError>,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn validate(&self, bom: &Bom, _version: SpecVersion) -> ValidationResult {
+        let components: Vec<&Component> = bom.iter_components_recursive().collect();
+        ValidationContext::new()
+            .add_list("components", components, |component| {
+                let mut context = ValidationContext::new();
+                context.set_bom_ref(component.bom_ref.as_deref());
+                if let Err(error) = (self.check)(component) {
+                    context.add_custom("", error);
+                }
+                ValidationResult::from(context)
+            })
+            .into()
+    }
+}
+
+/// Runs the built-in validation of a [`Bom`] together with user-defined rules.
+///
+/// The issues of each rule are nested under the name of the rule, rules with the same name
+/// share their results.
+pub struct Validator<'a> {
+    options: ValidationOptions,
+    builtin: bool,
+    rules: Vec<Box<dyn ValidationRule + 'a>>,
+}
+
+impl Default for Validator<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Validator<'a> {
+    /// A validator that runs only the built-in validation with the default options.
+    pub fn new() -> Self {
+        Self {
+            options: ValidationOptions::default(),
+            builtin: true,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Adds a rule that runs after the built-in validation.
+    pub fn with_rule(mut self, rule: impl ValidationRule + 'a) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Sets the options of the built-in validation and all rules.
+    pub fn with_options(mut self, options: ValidationOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Skips the built-in validation to run only the rules.
+    pub fn without_builtin(mut self) -> Self {
+        self.builtin = false;
+        self
+    }
+
+    /// Validates the BOM against its spec version.
+    pub fn validate(&self, bom: &Bom) -> ValidationResult {
+        self.validate_version(bom, bom.spec_version)
+    }
+
+    pub fn validate_version(&self, bom: &Bom, version: SpecVersion) -> ValidationResult {
+        self.options.apply(|| {
+            let mut result = if self.builtin {
+                bom.validate_version(version)
+            } else {
+                ValidationResult::default()
+            };
+
+            for rule in &self.rules {
+                let rule_result = rule.validate(bom, version);
+                if rule_result.passed() {
+                    continue;
+                }
+                match result.inner.entry(rule.name().to_string()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(ValidationErrorsKind::Struct(rule_result));
+                    }
+                    Entry::Occupied(mut entry) => match entry.get_mut() {
+                        ValidationErrorsKind::Struct(existing) => {
+                            existing.inner.extend(rule_result.inner)
+                        }
+                        _ => panic!("Rule '{}' replaces a built-in entry", rule.name()),
+                    },
+                }
+            }
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        external_models::uri::Uri,
+        models::{
+            component::{Classification, Components},
+            external_reference::{ExternalReference, ExternalReferenceType, ExternalReferences},
+            property::{Properties, Property},
+        },
+        validation::{code, UriValidation},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn owner_rule() -> impl ValidationRule {
+        ComponentRule::new("owner", |component: &Component| {
+            let internal = component.name.starts_with("acme-");
+            let owner = component
+                .properties
+                .as_ref()
+                .and_then(|properties| properties.get("acme:owner"));
+            if internal && owner.is_none() {
+                Err(ValidationError::new("Internal component without owner")
+                    .with_code("acme-owner"))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    #[test]
+    fn it_should_report_the_issues_of_rules_with_the_builtin_issues() {
+        let mut owned = Component::new(Classification::Library, "acme-owned", "1.0.0", None);
+        owned.properties = Some(Properties(vec![Property::new("acme:owner", "jane")]));
+        let mut unowned = Component::new(
+            Classification::Library,
+            "acme-unowned",
+            "1.0.0",
+            Some("unowned".to_string()),
+        );
+        unowned.external_references = Some(ExternalReferences(vec![ExternalReference::new(
+            ExternalReferenceType::Website,
+            Uri::new("https://例え.jp"),
+        )]));
+        let external = Component::new(Classification::Library, "external", "1.0.0", None);
+        let bom = Bom {
+            components: Some(Components(vec![owned, unowned, external])),
+            ..Bom::default()
+        };
+
+        let result = Validator::new().with_rule(owner_rule()).validate(&bom);
+
+        let issues = result.issues();
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| (issue.code, issue.path.to_string()))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    code::INVALID_URI,
+                    "components.inner[1][bom-ref=unowned].external_references.inner[0].url"
+                        .to_string()
+                ),
+                (
+                    "acme-owner",
+                    "owner.components[1][bom-ref=unowned]".to_string()
+                ),
+            ]
+        );
+
+        let permissive = ValidationOptions {
+            uri: UriValidation::IriPermissive,
+        };
+        let result = Validator::new()
+            .with_options(permissive)
+            .with_rule(owner_rule())
+            .validate(&bom);
+        assert_eq!(result.issues().len(), 1);
+        assert!(Validator::new()
+            .without_builtin()
+            .with_rule(owner_rule())
+            .validate(&Bom::default())
+            .passed());
+    }
+}
@@ -1,4 +1,7 @@
 use crate::errors::{XmlReadError, XmlWriteError};
+use crate::models::bom::{XmlReadOptions, XmlReadWarning};
+#[cfg(feature = "quick-xml")]
+use crate::quick_xml_reader::QuickXmlReader;
 use std::io::{Read, Write};
 use xml::{
     attribute::OwnedAttribute,
@@ -6,9 +9,133 @@ use xml::{
     namespace::{Namespace, NS_NO_PREFIX},
     reader::{self},
     writer::{self, EventWriter, XmlEvent},
-    EventReader,
+    EventReader, ParserConfig,
 };
 
+/// The configuration of the parser of XML documents.
+///
+/// CDATA sections are read as characters, so that they coalesce with the text around them, and
+/// comments are ignored, so `<text>a <!-- note --><![CDATA[<b>]]></text>` reads as `a <b>`.
+#[cfg_attr(feature = "quick-xml", allow(dead_code))]
+pub(crate) fn parser_config() -> ParserConfig {
+    ParserConfig::default()
+        .trim_whitespace(true)
+        .cdata_to_characters(true)
+        .ignore_comments(true)
+}
+
+/// Reads the events of an XML document with the options of the read and collects the warnings
+/// about the content that was skipped.
+pub(crate) struct XmlReader<R: Read> {
+    events: XmlEvents<R>,
+    options: XmlReadOptions,
+    warnings: Vec<XmlReadWarning>,
+}
+
+/// The parsers of XML documents, which all produce the events of `xml-rs`.
+enum XmlEvents<R: Read> {
+    #[cfg_attr(feature = "quick-xml", allow(dead_code))]
+    XmlRs(Box<EventReader<R>>),
+    #[cfg(feature = "quick-xml")]
+    QuickXml(Box<QuickXmlReader<R>>),
+}
+
+impl<R: Read> XmlReader<R> {
+    #[cfg(test)]
+    pub(crate) fn new(events: EventReader<R>, options: XmlReadOptions) -> Self {
+        Self::with_events(XmlEvents::XmlRs(Box::new(events)), options)
+    }
+
+    /// Reads the document with `quick-xml` if the `quick-xml` feature is enabled, and with
+    /// `xml-rs` and [`parser_config`] otherwise.
+    pub(crate) fn from_reader(reader: R, options: XmlReadOptions) -> Self {
+        #[cfg(feature = "quick-xml")]
+        let events = XmlEvents::QuickXml(Box::new(QuickXmlReader::new(reader)));
+        #[cfg(not(feature = "quick-xml"))]
+        let events = XmlEvents::XmlRs(Box::new(EventReader::new_with_config(
+            reader,
+            parser_config(),
+        )));
+        Self::with_events(events, options)
+    }
+
+    fn with_events(events: XmlEvents<R>, options: XmlReadOptions) -> Self {
+        Self {
+            events,
+            options,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub(crate) fn next(&mut self) -> Result<reader::XmlEvent, xml::reader::Error> {
+        match &mut self.events {
+            XmlEvents::XmlRs(events) => events.next(),
+            #[cfg(feature = "quick-xml")]
+            XmlEvents::QuickXml(events) => events.next(),
+        }
+    }
+
+    /// The warnings about the content that was skipped so far.
+    pub(crate) fn into_warnings(self) -> Vec<XmlReadWarning> {
+        self.warnings
+    }
+
+    fn warn_skipped(&mut self, element: String, skipped: String) {
+        // The display of a name includes its namespace and prefix, as in `{namespace}prefix:local`
+        let element = match element.rsplit_once(['}', ':']) {
+            Some((_, local_name)) => local_name.to_string(),
+            None => element,
+        };
+        self.warnings.push(XmlReadWarning { element, skipped });
+    }
+}
+
+/// Handles an event that the reader of `element` does not expect.
+///
+/// Unexpected child elements, including all of their content, and text are skipped with a
+/// warning, unless the read is strict, which makes them an error. The end of the document is
+/// always an error.
+pub(crate) fn skip_unexpected_element<R: Read>(
+    event_reader: &mut XmlReader<R>,
+    element: impl ToString,
+    unexpected: reader::XmlEvent,
+) -> Result<(), XmlReadError> {
+    if event_reader.options.strict {
+        return Err(unexpected_element_error(element, unexpected));
+    }
+    match unexpected {
+        reader::XmlEvent::StartElement { name, .. } => {
+            skip_unexpected_child(event_reader, element, &name)
+        }
+        reader::XmlEvent::Characters(_) | reader::XmlEvent::CData(_) => {
+            event_reader.warn_skipped(element.to_string(), "text".to_string());
+            Ok(())
+        }
+        reader::XmlEvent::Comment(_)
+        | reader::XmlEvent::ProcessingInstruction { .. }
+        | reader::XmlEvent::Whitespace(_) => Ok(()),
+        unexpected => Err(unexpected_element_error(element, unexpected)),
+    }
+}
+
+/// Skips the child element `child` of `element`, whose start was already read, with a warning,
+/// see [`skip_unexpected_element`].
+pub(crate) fn skip_unexpected_child<R: Read>(
+    event_reader: &mut XmlReader<R>,
+    element: impl ToString,
+    child: &OwnedName,
+) -> Result<(), XmlReadError> {
+    if event_reader.options.strict {
+        return Err(XmlReadError::UnexpectedElementReadError {
+            error: format!("Got unexpected element {:?}", child.local_name),
+            element: element.to_string(),
+        });
+    }
+    read_lax_validation_tag(event_reader, child)?;
+    event_reader.warn_skipped(element.to_string(), format!("<{}>", child.local_name));
+    Ok(())
+}
+
 pub(crate) trait ToXml {
     fn write_xml_element<W: Write>(&self, writer: &mut EventWriter<W>)
         -> Result<(), XmlWriteError>;
@@ -65,6 +192,83 @@ impl<T: ToInnerXml> ToInnerXml for Option<T> {
     }
 }
 
+impl ToInnerXml for String {
+    fn write_xml_named_element<W: Write>(
+        &self,
+        writer: &mut EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        write_simple_tag(writer, tag, self)
+    }
+}
+
+impl ToInnerXml for u32 {
+    fn write_xml_named_element<W: Write>(
+        &self,
+        writer: &mut EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        write_simple_tag(writer, tag, &self.to_string())
+    }
+}
+
+impl ToInnerXml for f32 {
+    fn write_xml_named_element<W: Write>(
+        &self,
+        writer: &mut EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        write_simple_tag(writer, tag, &self.to_string())
+    }
+}
+
+impl ToInnerXml for bool {
+    fn write_xml_named_element<W: Write>(
+        &self,
+        writer: &mut EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        write_simple_tag(writer, tag, &self.to_string())
+    }
+}
+
+/// Writes a field of type `F` as child element, the counterpart of reading a field with
+/// [`FromXml`].
+///
+/// The implementing type is the one given in the field descriptors of [`xml_element!`], so
+/// [`VecXmlReader`] is used to write a `Vec` as a wrapping tag with one element per item.
+pub(crate) trait ToXmlField<F> {
+    fn write_xml_field<W: Write>(
+        field: &F,
+        writer: &mut EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError>;
+}
+
+impl<E: ToInnerXml> ToXmlField<E> for E {
+    fn write_xml_field<W: Write>(
+        field: &E,
+        writer: &mut EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        field.write_xml_named_element(writer, tag)
+    }
+}
+
+impl<E: ToInnerXml + FromXml, T: VecElemTag> ToXmlField<Vec<E>> for VecXmlReader<E, T> {
+    fn write_xml_field<W: Write>(
+        field: &Vec<E>,
+        writer: &mut EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        write_start_tag(writer, tag)?;
+        for item in field {
+            item.write_xml_named_element(writer, T::VALUE)?;
+        }
+        write_close_tag(writer, tag)
+    }
+}
+
 /// Write a tag that is of the form `<tag>content</tag>`
 pub(crate) fn write_simple_tag<W: Write>(
     writer: &mut EventWriter<W>,
@@ -154,14 +358,14 @@ pub(crate) fn to_xml_write_error(
 }
 
 pub(crate) trait FromXmlDocument {
-    fn read_xml_document<R: Read>(event_reader: &mut EventReader<R>) -> Result<Self, XmlReadError>
+    fn read_xml_document<R: Read>(event_reader: &mut XmlReader<R>) -> Result<Self, XmlReadError>
     where
         Self: Sized;
 }
 
 pub(crate) trait FromXml {
     fn read_xml_element<R: Read>(
-        event_reader: &mut EventReader<R>,
+        event_reader: &mut XmlReader<R>,
         element_name: &OwnedName,
         attributes: &[OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -198,7 +402,7 @@ macro_rules! get_elements_lax {
                     xml::reader::XmlEvent::EndElement { name } if &name == $element_name => {
                         got_end_tag = true;
                     }
-                    unexpected => return Err($crate::xml::unexpected_element_error($element_name, unexpected)),
+                    unexpected => $crate::xml::skip_unexpected_element($event_reader, $element_name, unexpected)?,
                 }
             }
     };
@@ -227,13 +431,78 @@ macro_rules! get_elements {
                                 &attributes,
                             )?);
                         },)*
-                        unexpected => return Err($crate::xml::unexpected_element_error(unexpected.to_string(), next_element)),
+                        _ => $crate::xml::skip_unexpected_child($event_reader, $element_name, elem_name)?,
                     }
                 }
                 xml::reader::XmlEvent::EndElement { name } if &name == $element_name => {
                     got_end_tag = true;
                 }
-                unexpected => return Err($crate::xml::unexpected_element_error($element_name, unexpected)),
+                unexpected => $crate::xml::skip_unexpected_element($event_reader, $element_name, unexpected)?,
+            }
+        }
+    };
+}
+
+/// Implements [`FromXml`], [`ToInnerXml`] and [`ToXml`] for a struct whose fields are all
+/// optional child elements, from a table of field descriptors.
+///
+/// Every descriptor names the tag constant, the field and the type used to read and write it,
+/// e.g. `VecXmlReader<String, UserTag>` for a list of `user` elements. The elements are written
+/// in the order of the descriptors, fields that are `None` are skipped. [`ToXml`] writes the
+/// element with the given default tag.
+///
+/// ```ignore
+/// xml_element! {
+///     EthicalConsideration = ETHICAL_CONSIDERATION_TAG {
+///         NAME_TAG => name: String,
+///         MITIGATION_STRATEGY_TAG => mitigation_strategy: String,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! xml_element {
+    ($type:ident = $element_tag:ident { $($tag:ident => $name:ident: $field_type:ty,)+ }) => {
+        impl $crate::xml::ToInnerXml for $type {
+            fn write_xml_named_element<W: std::io::Write>(
+                &self,
+                writer: &mut xml::EventWriter<W>,
+                tag: &str,
+            ) -> Result<(), $crate::errors::XmlWriteError> {
+                $crate::xml::write_start_tag(writer, tag)?;
+                $(
+                    if let Some(value) = &self.$name {
+                        <$field_type as $crate::xml::ToXmlField<_>>::write_xml_field(
+                            value, writer, $tag,
+                        )?;
+                    }
+                )+
+                $crate::xml::write_close_tag(writer, tag)
+            }
+        }
+
+        impl $crate::xml::ToXml for $type {
+            fn write_xml_element<W: std::io::Write>(
+                &self,
+                writer: &mut xml::EventWriter<W>,
+            ) -> Result<(), $crate::errors::XmlWriteError> {
+                $crate::xml::ToInnerXml::write_xml_named_element(self, writer, $element_tag)
+            }
+        }
+
+        impl $crate::xml::FromXml for $type {
+            fn read_xml_element<R: std::io::Read>(
+                event_reader: &mut $crate::xml::XmlReader<R>,
+                element_name: &xml::name::OwnedName,
+                _attributes: &[xml::attribute::OwnedAttribute],
+            ) -> Result<Self, $crate::errors::XmlReadError> {
+                $crate::get_elements! {
+                    event_reader, element_name,
+                    $($tag => $name: $field_type,)+
+                };
+
+                Ok(Self {
+                    $($name: $name.map(::std::convert::Into::into),)+
+                })
             }
         }
     };
@@ -269,7 +538,7 @@ impl<E: FromXml, T: VecElemTag> From<VecXmlReader<E, T>> for Vec<E> {
 
 impl<E: FromXml, T: VecElemTag> FromXml for VecXmlReader<E, T> {
     fn read_xml_element<R: std::io::prelude::Read>(
-        event_reader: &mut xml::EventReader<R>,
+        event_reader: &mut XmlReader<R>,
         element_name: &xml::name::OwnedName,
         _attributes: &[xml::attribute::OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -312,27 +581,6 @@ pub(crate) fn expected_namespace_or_error(
     }
 }
 
-pub(crate) fn inner_text_or_error(
-    element_name: impl AsRef<str>,
-) -> impl FnOnce(xml::reader::XmlEvent) -> Result<String, XmlReadError> {
-    let element_name = element_name.as_ref().to_owned();
-    |event| match event {
-        reader::XmlEvent::Characters(s) | reader::XmlEvent::CData(s) => Ok(s),
-        unexpected => Err(unexpected_element_error(element_name, unexpected)),
-    }
-}
-
-pub(crate) fn inner_text_or_none(
-    element_name: impl AsRef<str>,
-) -> impl FnOnce(xml::reader::XmlEvent) -> Result<Option<String>, XmlReadError> {
-    let element_name = element_name.as_ref().to_owned();
-    |event| match event {
-        reader::XmlEvent::Characters(s) | reader::XmlEvent::CData(s) => Ok(Some(s)),
-        reader::XmlEvent::EndElement { name } if name.to_string() == element_name => Ok(None),
-        unexpected => Err(unexpected_element_error(element_name, unexpected)),
-    }
-}
-
 pub(crate) fn closing_tag_or_error(
     element: &OwnedName,
 ) -> impl FnOnce(xml::reader::XmlEvent) -> Result<(), XmlReadError> {
@@ -453,111 +701,100 @@ impl FromXmlType for f32 {
 /// ```
 ///
 /// are valid XML tags. The first returns the string "Content", the latter is an empty string.
+/// See [`read_text_content`] for CDATA, comments and mixed content.
 pub(crate) fn read_simple_tag<R: Read>(
-    event_reader: &mut EventReader<R>,
+    event_reader: &mut XmlReader<R>,
     element: &OwnedName,
 ) -> Result<String, XmlReadError> {
-    let element_display = element.to_string();
-    let content = event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))?;
-
-    let content = match content {
-        reader::XmlEvent::EndElement { .. } => String::new(),
-        reader::XmlEvent::Characters(content) | reader::XmlEvent::CData(content) => {
-            event_reader
-                .next()
-                .map_err(to_xml_read_error(&element_display))
-                .and_then(closing_tag_or_error(element))?;
-            content
-        }
-        unexpected => return Err(unexpected_element_error(element, unexpected)),
-    };
-
-    Ok(content)
+    Ok(read_text_content(event_reader, element)?.unwrap_or_default())
 }
 
 pub(crate) fn read_optional_tag<R: Read>(
-    event_reader: &mut EventReader<R>,
+    event_reader: &mut XmlReader<R>,
+    element: &OwnedName,
+) -> Result<Option<String>, XmlReadError> {
+    read_text_content(event_reader, element)
+}
+
+/// Reads the text up to the closing tag of the element, `None` if the element is empty.
+///
+/// Text and CDATA sections are concatenated and comments and processing instructions are
+/// skipped. Some producers embed markup in text fields, e.g. `<text>MIT <b>or</b> Apache</text>`,
+/// the text of such nested elements is kept and joined with a space, as the whitespace around it
+/// has been trimmed by the parser.
+pub(crate) fn read_text_content<R: Read>(
+    event_reader: &mut XmlReader<R>,
     element: &OwnedName,
 ) -> Result<Option<String>, XmlReadError> {
     let element_display = element.to_string();
-    let content = event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))
-        .and_then(inner_text_or_none(&element_display))?;
+    let mut content: Option<String> = None;
+    let mut depth = 0usize;
+    let mut separate = false;
 
-    // If XML tag has content, read next element
-    if content.is_some() {
-        event_reader
+    loop {
+        let event = event_reader
             .next()
-            .map_err(to_xml_read_error(&element_display))
-            .and_then(closing_tag_or_error(element))?;
+            .map_err(to_xml_read_error(&element_display))?;
+        match event {
+            reader::XmlEvent::Characters(text) | reader::XmlEvent::CData(text) => {
+                let content = content.get_or_insert_with(String::new);
+                if separate && !content.is_empty() {
+                    content.push(' ');
+                }
+                content.push_str(&text);
+                separate = false;
+            }
+            reader::XmlEvent::StartElement { .. } => {
+                depth += 1;
+                separate = true;
+            }
+            reader::XmlEvent::EndElement { name } if depth == 0 && &name == element => {
+                return Ok(content);
+            }
+            reader::XmlEvent::EndElement { .. } if depth > 0 => {
+                depth -= 1;
+                separate = true;
+            }
+            reader::XmlEvent::Comment(_)
+            | reader::XmlEvent::ProcessingInstruction { .. }
+            | reader::XmlEvent::Whitespace(_) => (),
+            unexpected => return Err(unexpected_element_error(element, unexpected)),
+        }
     }
-
-    Ok(content)
 }
 
 pub(crate) fn read_u32_tag<R: Read>(
-    event_reader: &mut EventReader<R>,
+    event_reader: &mut XmlReader<R>,
     element: &OwnedName,
 ) -> Result<u32, XmlReadError> {
-    let element_display = element.to_string();
-    let content = event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))
-        .and_then(inner_text_or_error(&element_display))?;
-
-    let number = match content.trim().parse::<u32>() {
-        Ok(n) => n,
-        Err(_) => {
-            return Err(XmlReadError::InvalidParseError {
-                value: content,
-                data_type: "u32".to_string(),
-                element: element_display,
-            })
-        }
-    };
-
-    event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))
-        .and_then(closing_tag_or_error(element))?;
-
-    Ok(number)
+    let content = read_simple_tag(event_reader, element)?;
+    content
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| XmlReadError::InvalidParseError {
+            value: content.clone(),
+            data_type: "u32".to_string(),
+            element: element.to_string(),
+        })
 }
 
 pub(crate) fn read_f32_tag<R: Read>(
-    event_reader: &mut EventReader<R>,
+    event_reader: &mut XmlReader<R>,
     element: &OwnedName,
 ) -> Result<f32, XmlReadError> {
-    let element_display = element.to_string();
-    let content = event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))
-        .and_then(inner_text_or_error(&element_display))?;
-
-    let number = match content.trim().parse::<f32>() {
-        Ok(n) => n,
-        Err(_) => {
-            return Err(XmlReadError::InvalidParseError {
-                value: content,
-                data_type: "f32".to_string(),
-                element: element_display,
-            })
-        }
-    };
-
-    event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))
-        .and_then(closing_tag_or_error(element))?;
-
-    Ok(number)
+    let content = read_simple_tag(event_reader, element)?;
+    content
+        .trim()
+        .parse::<f32>()
+        .map_err(|_| XmlReadError::InvalidParseError {
+            value: content.clone(),
+            data_type: "f32".to_string(),
+            element: element.to_string(),
+        })
 }
 
 pub(crate) fn read_boolean_tag<R: Read>(
-    event_reader: &mut EventReader<R>,
+    event_reader: &mut XmlReader<R>,
     element: &OwnedName,
 ) -> Result<bool, XmlReadError> {
     read_simple_tag(event_reader, element)
@@ -566,7 +803,7 @@ pub(crate) fn read_boolean_tag<R: Read>(
 
 impl FromXml for String {
     fn read_xml_element<R: Read>(
-        event_reader: &mut EventReader<R>,
+        event_reader: &mut XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -579,7 +816,7 @@ impl FromXml for String {
 
 impl FromXml for u32 {
     fn read_xml_element<R: Read>(
-        event_reader: &mut EventReader<R>,
+        event_reader: &mut XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -592,7 +829,7 @@ impl FromXml for u32 {
 
 impl FromXml for f32 {
     fn read_xml_element<R: Read>(
-        event_reader: &mut EventReader<R>,
+        event_reader: &mut XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -605,7 +842,7 @@ impl FromXml for f32 {
 
 impl FromXml for bool {
     fn read_xml_element<R: Read>(
-        event_reader: &mut EventReader<R>,
+        event_reader: &mut XmlReader<R>,
         element_name: &OwnedName,
         _attributes: &[OwnedAttribute],
     ) -> Result<Self, XmlReadError>
@@ -617,7 +854,7 @@ impl FromXml for bool {
 }
 
 pub(crate) fn read_list_tag<R: Read, X: FromXml>(
-    event_reader: &mut EventReader<R>,
+    event_reader: &mut XmlReader<R>,
     element_name: &OwnedName,
     inner_element_tag: &str,
 ) -> Result<Vec<X>, XmlReadError> {
@@ -637,7 +874,7 @@ pub(crate) fn read_list_tag<R: Read, X: FromXml>(
             reader::XmlEvent::EndElement { name } if &name == element_name => {
                 got_end_tag = true;
             }
-            unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
         }
     }
 
@@ -645,7 +882,7 @@ pub(crate) fn read_list_tag<R: Read, X: FromXml>(
 }
 
 pub(crate) fn read_lax_validation_tag<R: Read>(
-    event_reader: &mut EventReader<R>,
+    event_reader: &mut XmlReader<R>,
     element: &OwnedName,
 ) -> Result<(), XmlReadError> {
     let mut got_end_tag = false;
@@ -675,7 +912,7 @@ pub(crate) fn read_lax_validation_tag<R: Read>(
 }
 
 pub(crate) fn read_lax_validation_list_tag<R: Read, X: FromXml>(
-    event_reader: &mut EventReader<R>,
+    event_reader: &mut XmlReader<R>,
     element_name: &OwnedName,
     inner_element_tag: &str,
 ) -> Result<Vec<X>, XmlReadError> {
@@ -698,7 +935,7 @@ pub(crate) fn read_lax_validation_list_tag<R: Read, X: FromXml>(
             reader::XmlEvent::EndElement { name } if &name == element_name => {
                 got_end_tag = true;
             }
-            unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            unexpected => skip_unexpected_element(event_reader, element_name, unexpected)?,
         }
     }
 
@@ -715,13 +952,14 @@ pub(crate) fn unexpected_element_error(
     }
 }
 
-#[cfg(test)]
-pub(crate) mod test {
-    use xml::{EmitterConfig, ParserConfig};
+/// Writes and reads single elements, used by the tests and the `test-util` feature.
+#[cfg(any(test, feature = "test-util"))]
+pub(crate) mod element {
+    use xml::EmitterConfig;
 
     use super::*;
 
-    fn emitter_config() -> EmitterConfig {
+    pub(crate) fn emitter_config() -> EmitterConfig {
         EmitterConfig::default().perform_indent(true)
     }
 
@@ -734,41 +972,9 @@ pub(crate) mod test {
         String::from_utf8_lossy(&output).to_string()
     }
 
-    pub(crate) fn write_named_element_to_string<X: ToInnerXml>(element: X, tag: &str) -> String {
-        let mut output = Vec::new();
-        let mut event_writer = EventWriter::new_with_config(&mut output, emitter_config());
-        element
-            .write_xml_named_element(&mut event_writer, tag)
-            .expect("Should have written the element");
-        String::from_utf8_lossy(&output).to_string()
-    }
-
-    fn parser_config() -> ParserConfig {
-        ParserConfig::default().trim_whitespace(true)
-    }
-
-    pub(crate) fn read_document_from_string<X: FromXmlDocument>(string: impl AsRef<str>) -> X {
-        let mut event_reader =
-            EventReader::new_with_config(string.as_ref().as_bytes(), parser_config());
-        let output: X = X::read_xml_document(&mut event_reader)
-            .expect("Failed to read the document from the string");
-
-        // According to the documentation, an event reader that returns an
-        // EndDocument event will continue to return that event for subsequent
-        // requests
-        let end_document = event_reader.next().expect("Expected to end the document");
-
-        match end_document {
-            reader::XmlEvent::EndDocument { .. } => (),
-            other => panic!("Expected to end a document, but got {:?}", other),
-        }
-
-        output
-    }
-
     pub(crate) fn read_element_from_string<X: FromXml>(string: impl AsRef<str>) -> X {
         let mut event_reader =
-            EventReader::new_with_config(string.as_ref().as_bytes(), parser_config());
+            XmlReader::from_reader(string.as_ref().as_bytes(), XmlReadOptions::default());
 
         let start_document = event_reader.next().expect("Expected to start the document");
 
@@ -790,7 +996,43 @@ pub(crate) mod test {
         let end_document = event_reader.next().expect("Expected to end the document");
 
         match end_document {
-            reader::XmlEvent::EndDocument { .. } => (),
+            reader::XmlEvent::EndDocument => (),
+            other => panic!("Expected to end a document, but got {:?}", other),
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    pub(crate) use super::element::{read_element_from_string, write_element_to_string};
+
+    use super::element::emitter_config;
+    use super::*;
+
+    pub(crate) fn write_named_element_to_string<X: ToInnerXml>(element: X, tag: &str) -> String {
+        let mut output = Vec::new();
+        let mut event_writer = EventWriter::new_with_config(&mut output, emitter_config());
+        element
+            .write_xml_named_element(&mut event_writer, tag)
+            .expect("Should have written the element");
+        String::from_utf8_lossy(&output).to_string()
+    }
+
+    pub(crate) fn read_document_from_string<X: FromXmlDocument>(string: impl AsRef<str>) -> X {
+        let mut event_reader =
+            XmlReader::from_reader(string.as_ref().as_bytes(), XmlReadOptions::default());
+        let output: X = X::read_xml_document(&mut event_reader)
+            .expect("Failed to read the document from the string");
+
+        // According to the documentation, an event reader that returns an
+        // EndDocument event will continue to return that event for subsequent
+        // requests
+        let end_document = event_reader.next().expect("Expected to end the document");
+
+        match end_document {
+            reader::XmlEvent::EndDocument => (),
             other => panic!("Expected to end a document, but got {:?}", other),
         }
 
@@ -807,7 +1049,8 @@ pub(crate) mod test {
     </recursiveTag>
   </innerTag>
 "#;
-        let mut event_reader = EventReader::new_with_config(input.as_bytes(), parser_config());
+        let events = EventReader::new_with_config(input.as_bytes(), parser_config());
+        let mut event_reader = XmlReader::new(events, XmlReadOptions::default());
 
         let start_document = event_reader.next().expect("Expected to start the document");
 
@@ -828,4 +1071,160 @@ pub(crate) mod test {
 
         // no end document, because it returns an error during the read_lax_validation_tag call
     }
+
+    #[derive(Debug, PartialEq)]
+    struct Sample {
+        name: Option<String>,
+        count: Option<u32>,
+        items: Option<Vec<String>>,
+    }
+
+    const SAMPLE_TAG: &str = "sample";
+    const NAME_TAG: &str = "name";
+    const COUNT_TAG: &str = "count";
+    const ITEMS_TAG: &str = "items";
+
+    crate::elem_tag!(ItemTag = "item");
+
+    crate::xml_element! {
+        Sample = SAMPLE_TAG {
+            NAME_TAG => name: String,
+            COUNT_TAG => count: u32,
+            ITEMS_TAG => items: VecXmlReader<String, ItemTag>,
+        }
+    }
+
+    #[test]
+    fn it_should_read_and_write_elements_from_descriptors() {
+        let input = r#"
+<sample>
+  <count>2</count>
+  <items>
+    <item>a</item>
+    <item>b</item>
+  </items>
+</sample>
+"#;
+        let expected = Sample {
+            name: None,
+            count: Some(2),
+            items: Some(vec!["a".to_string(), "b".to_string()]),
+        };
+
+        let actual: Sample = read_element_from_string(input);
+        assert_eq!(actual, expected);
+        assert!(write_element_to_string(expected).ends_with(input.trim()));
+    }
+
+    #[test]
+    fn it_should_read_text_with_cdata_entities_and_comments() {
+        let input = r#"
+<description>Escapes DOM &lt;b&gt; &amp; <![CDATA[<script> & more]]> in<!-- a comment --> HTML</description>
+"#;
+        let actual: String = read_element_from_string(input);
+        assert_eq!(actual, "Escapes DOM <b> & <script> & more in HTML");
+    }
+
+    #[test]
+    fn it_should_keep_the_text_of_embedded_markup() {
+        let input = r#"
+<description>Licensed under <b>MIT</b> or <i>Apache-2.0</i></description>
+"#;
+        let actual: String = read_element_from_string(input);
+        assert_eq!(actual, "Licensed under MIT or Apache-2.0");
+    }
+
+    #[test]
+    fn it_should_read_a_number_in_cdata() {
+        let input = r#"
+<count><![CDATA[ 42 ]]></count>
+"#;
+        let actual: u32 = read_element_from_string(input);
+        assert_eq!(actual, 42);
+    }
+
+    fn read_items_with_options(
+        input: &str,
+        options: XmlReadOptions,
+    ) -> Result<(Vec<String>, Vec<XmlReadWarning>), XmlReadError> {
+        let events = EventReader::new_with_config(input.as_bytes(), parser_config());
+        let mut event_reader = XmlReader::new(events, options);
+        loop {
+            if let reader::XmlEvent::StartElement { name, .. } =
+                event_reader.next().map_err(to_xml_read_error(ITEMS_TAG))?
+            {
+                let items = read_list_tag(&mut event_reader, &name, "item")?;
+                return Ok((items, event_reader.into_warnings()));
+            }
+        }
+    }
+
+    const ITEMS_WITH_UNEXPECTED_CONTENT: &str = r#"
+<items>
+  <item>a</item>
+  <note><item>hidden</item></note>
+  loose text
+  <item>b</item>
+</items>
+"#;
+
+    #[test]
+    fn it_should_fail_on_unexpected_content_of_lists_when_strict() {
+        let error = read_items_with_options(
+            ITEMS_WITH_UNEXPECTED_CONTENT,
+            XmlReadOptions { strict: true },
+        )
+        .expect_err("Should have failed on the unexpected element");
+
+        assert!(matches!(
+            error,
+            XmlReadError::UnexpectedElementReadError { element, .. } if element == ITEMS_TAG
+        ));
+    }
+
+    #[test]
+    fn it_should_skip_unexpected_content_of_lists_with_warnings_by_default() {
+        let (items, warnings) =
+            read_items_with_options(ITEMS_WITH_UNEXPECTED_CONTENT, XmlReadOptions::default())
+                .expect("Failed to read the items");
+
+        assert_eq!(items, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(
+            warnings,
+            vec![
+                XmlReadWarning {
+                    element: ITEMS_TAG.to_string(),
+                    skipped: "<note>".to_string(),
+                },
+                XmlReadWarning {
+                    element: ITEMS_TAG.to_string(),
+                    skipped: "text".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_fail_on_unexpected_child_elements_when_strict() {
+        let input = r#"
+<sample>
+  <count>2</count>
+  <note>Added by hand</note>
+</sample>
+"#;
+        let events = EventReader::new_with_config(input.as_bytes(), parser_config());
+        let mut event_reader = XmlReader::new(events, XmlReadOptions { strict: true });
+        let name = loop {
+            if let reader::XmlEvent::StartElement { name, .. } = event_reader.next().unwrap() {
+                break name;
+            }
+        };
+
+        let error = Sample::read_xml_element(&mut event_reader, &name, &[])
+            .expect_err("Should have failed on the unexpected element");
+        assert!(matches!(
+            error,
+            XmlReadError::UnexpectedElementReadError { element, .. } if element == SAMPLE_TAG
+        ));
+    }
 }
@@ -0,0 +1,2318 @@
+//! Generators of arbitrary models for the property-based tests.
+//!
+//! Every model type implements [`Arbitrary`] with a `proptest` strategy, composed from the
+//! strategies of its fields like a struct literal, so a new field fails to compile here until it
+//! is generated as well. Since the values come from strategies, a failing case shrinks to a
+//! minimal model.
+//!
+//! Generated values only use what every format can represent without loss: text has no leading,
+//! trailing or repeated whitespace since XML readers trim text, lists are not empty, enums only
+//! use their known variants and numbers are exact binary fractions.
+
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+use cyclonedx_bom::external_models::{
+    date_time::DateTime,
+    normalized_string::NormalizedString,
+    spdx::{SpdxExpression, SpdxIdentifier},
+    uri::{Purl, Uri as Url},
+};
+use cyclonedx_bom::models::{
+    advisory::{Advisories, Advisory},
+    annotation::{Annotation, Annotations, Annotator},
+    attached_text::{AttachedText, Encoding},
+    attachment::Attachment,
+    bom::{Bom, BomReference, SpecVersion, UrnUuid},
+    bom_link::BomLink,
+    code::{
+        Commit, Commits, Diff, IdentifiableAction, Issue, IssueClassification, Patch,
+        PatchClassification, Patches, Source,
+    },
+    component::{
+        Callstack, Classification, Component, ComponentEvidence, Components, ConfidenceScore,
+        Copyright, CopyrightTexts, Cpe, Frame, Frames, Identity, IdentityField, Method, Methods,
+        MimeType, Occurrence, Occurrences, Pedigree, Scope, Swid, ToolsReferences,
+    },
+    component_data::{ComponentData, ComponentDataType, DataContents, Graphic, GraphicsCollection},
+    composition::{AggregateType, Composition, Compositions},
+    crypto_properties::{
+        AlgorithmProperties, CertificateProperties, CertificationLevel, CipherSuite,
+        CryptoAssetType, CryptoFunction, CryptoMode, CryptoPadding, CryptoPrimitive,
+        CryptoProperties, ExecutionEnvironment, Ikev2TransformTypes, ImplementationPlatform,
+        ProtocolProperties, ProtocolType, RelatedCryptoMaterialProperties,
+        RelatedCryptoMaterialState, RelatedCryptoMaterialType, SecuredBy,
+    },
+    data_governance::{DataGovernance, DataGovernanceResponsibleParty},
+    declarations::{
+        Affirmation, Assessor, Attestation, AttestationMap, Claim, Confidence, Conformance,
+        DeclarationEvidence, Declarations, EvidenceContents, EvidenceData, Signatory, Targets,
+    },
+    definitions::{Definitions, Level, Requirement, Standard},
+    dependency::{Dependencies, Dependency},
+    external_reference::{self, ExternalReference, ExternalReferenceType, ExternalReferences},
+    formulation::{
+        workflow::{
+            input::{Input, Parameter, RequiredInputField},
+            output::{self, Output, RequiredOutputField},
+            resource_reference::ResourceReference,
+            step::{Command, Step},
+            trigger::{self, Condition, Event, Trigger},
+            workspace::{AccessMode, Mode, Volume, Workspace},
+            EnvironmentVar, Task, TaskType, Workflow,
+        },
+        Formula,
+    },
+    hash::{Hash, HashAlgorithm, HashValue, Hashes},
+    license::{
+        License, LicenseChoice, LicenseContact, LicenseIdentifier, LicenseType, Licenses, Licensing,
+    },
+    lifecycle::{Description, Lifecycle, Lifecycles, Phase},
+    metadata::Metadata,
+    modelcard::{
+        ApproachType, Co2Measure, ConfidenceInterval, Considerations, Dataset, Datasets,
+        EnergyActivity, EnergyConsumption, EnergyMeasure, EnergyProvider, EnergySource,
+        EnvironmentalConsiderations, EthicalConsideration, FairnessAssessment, Inputs, MLParameter,
+        ModelCard, ModelParameters, ModelParametersApproach, Outputs, PerformanceMetric,
+        PerformanceMetrics, QuantitativeAnalysis,
+    },
+    organization::{OrganizationalContact, OrganizationalEntity},
+    property::{Properties, Property},
+    release_notes::{Locale, Note, ReleaseNotes},
+    service::{Data, DataClassification, DataFlowType, Service, ServiceData, Services},
+    signature::{Algorithm, Signature, Signer},
+    tool::{Tool, Tools},
+    vulnerability::{Vulnerabilities, Vulnerability, VulnerabilityProofOfConcept},
+    vulnerability_analysis::{
+        ImpactAnalysisJustification, ImpactAnalysisResponse, ImpactAnalysisState,
+        VulnerabilityAnalysis,
+    },
+    vulnerability_credits::VulnerabilityCredits,
+    vulnerability_rating::{
+        Score, ScoreMethod, Severity, VulnerabilityRating, VulnerabilityRatings,
+    },
+    vulnerability_reference::{VulnerabilityReference, VulnerabilityReferences},
+    vulnerability_source::VulnerabilitySource,
+    vulnerability_target::{
+        Status, Version, VersionRange, Versions, VulnerabilityTarget, VulnerabilityTargets,
+    },
+};
+use proptest::{
+    arbitrary::any,
+    collection, option, prop_oneof, sample,
+    strategy::{BoxedStrategy, Just, LazyJust, Strategy, Union},
+};
+
+/// How deep components, services and other recursive models nest at most
+const MAX_DEPTH: u32 = 2;
+
+/// Words of generated text, including characters that have to be escaped in JSON and XML
+const WORDS: [&str; 12] = [
+    "acme",
+    "library",
+    "Crate",
+    "1.0",
+    "a&b",
+    "<tag>",
+    "\"quoted\"",
+    "it's",
+    "ünïcödé",
+    "back\\slash",
+    "x-y_z",
+    "42",
+];
+
+/// Where a strategy generates its values, to end recursive models at [`MAX_DEPTH`]
+#[derive(Clone, Copy, Default)]
+pub struct Gen {
+    depth: u32,
+}
+
+impl Gen {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bool(self) -> BoxedStrategy<bool> {
+        any::<bool>().boxed()
+    }
+
+    pub fn u32(self, range: RangeInclusive<u32>) -> BoxedStrategy<u32> {
+        range.boxed()
+    }
+
+    /// A fraction with a short exact decimal representation, between `0.0` and `max`
+    pub fn fraction(self, max: u32) -> BoxedStrategy<f32> {
+        (0..=max * 4).prop_map(|n| n as f32 / 4.0).boxed()
+    }
+
+    /// Text of one to three words
+    pub fn text(self) -> BoxedStrategy<String> {
+        collection::vec(sample::select(WORDS.to_vec()), 1..=3)
+            .prop_map(|words| words.join(" "))
+            .boxed()
+    }
+
+    /// An identifier such as a bom-ref, `prefix-` and a number
+    pub fn ident(self, prefix: &'static str) -> BoxedStrategy<String> {
+        (0..1000u32)
+            .prop_map(move |n| format!("{prefix}-{n}"))
+            .boxed()
+    }
+
+    pub fn choose<T: Clone + Debug + 'static>(self, values: Vec<T>) -> BoxedStrategy<T> {
+        sample::select(values).boxed()
+    }
+
+    pub fn arbitrary<T: Arbitrary>(self) -> BoxedStrategy<T> {
+        T::arbitrary(self)
+    }
+
+    pub fn option_with<S: Strategy + 'static>(
+        self,
+        strategy: S,
+    ) -> BoxedStrategy<Option<S::Value>> {
+        option::of(strategy).boxed()
+    }
+
+    /// One to three values
+    pub fn vec_with<S: Strategy + 'static>(self, strategy: S) -> BoxedStrategy<Vec<S::Value>> {
+        collection::vec(strategy, 1..=3).boxed()
+    }
+
+    /// A value one level deeper in a recursive model, or `None` beyond [`MAX_DEPTH`]
+    pub fn nested<T: Arbitrary>(self) -> BoxedStrategy<Option<T>> {
+        match self.deeper() {
+            Some(g) => option::of(T::arbitrary(g)).boxed(),
+            None => LazyJust::new(|| None).boxed(),
+        }
+    }
+
+    /// Where the values one level deeper in a recursive model are generated, `None` beyond
+    /// [`MAX_DEPTH`]
+    pub fn deeper(self) -> Option<Self> {
+        (self.depth < MAX_DEPTH).then_some(Self {
+            depth: self.depth + 1,
+        })
+    }
+}
+
+/// A model with a strategy to generate it
+pub trait Arbitrary: Debug + Sized + 'static {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self>;
+}
+
+impl Arbitrary for String {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.text()
+    }
+}
+
+impl Arbitrary for bool {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.bool()
+    }
+}
+
+impl Arbitrary for u32 {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.u32(0..=10_000)
+    }
+}
+
+/// Every optional field is generated here, present or not with the same probability
+impl<T: Arbitrary> Arbitrary for Option<T> {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.option_with(T::arbitrary(g))
+    }
+}
+
+/// Every list is generated here, with one to three values
+impl<T: Arbitrary> Arbitrary for Vec<T> {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.vec_with(T::arbitrary(g))
+    }
+}
+
+/// Composes the strategies of the fields of a struct, written like a struct literal, into the
+/// strategy of the struct
+macro_rules! compose {
+    ($($type:ident)::+ { $($field:ident: $strategy:expr),+ $(,)? }) => {
+        compose!(@tuple $($strategy),+)
+            .prop_map(|compose!(@pattern $($field),+)| $($type)::+ { $($field),+ })
+            .boxed()
+    };
+    (@tuple $strategy:expr) => { $strategy };
+    (@tuple $strategy:expr, $($rest:expr),+) => { ($strategy, compose!(@tuple $($rest),+)) };
+    (@pattern $field:ident) => { $field };
+    (@pattern $field:ident, $($rest:ident),+) => { ($field, compose!(@pattern $($rest),+)) };
+}
+
+/// Implements [`Arbitrary`] for enums by choosing one of the listed values
+macro_rules! arbitrary_enum {
+    ($($type:ty => [$($value:expr),+ $(,)?];)+) => {
+        $(
+            impl Arbitrary for $type {
+                fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+                    g.choose(vec![$($value),+])
+                }
+            }
+        )+
+    };
+}
+
+/// Implements [`Arbitrary`] for wrappers of a list of models
+macro_rules! arbitrary_list {
+    ($($type:ident),+ $(,)?) => {
+        $(
+            impl Arbitrary for $type {
+                fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+                    g.arbitrary().prop_map(Self).boxed()
+                }
+            }
+        )+
+    };
+}
+
+arbitrary_list!(
+    Advisories,
+    Annotations,
+    Commits,
+    CopyrightTexts,
+    Datasets,
+    Dependencies,
+    ExternalReferences,
+    Frames,
+    Hashes,
+    Inputs,
+    Licenses,
+    Lifecycles,
+    Methods,
+    Occurrences,
+    Outputs,
+    Patches,
+    PerformanceMetrics,
+    Properties,
+    Versions,
+    Vulnerabilities,
+    VulnerabilityRatings,
+    VulnerabilityReferences,
+    VulnerabilityTargets,
+);
+
+// Nested lists are only generated up to [`MAX_DEPTH`], so they always end
+arbitrary_list!(Components, Services);
+
+impl Arbitrary for Compositions {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.arbitrary().prop_map(Self).boxed()
+    }
+}
+
+impl Arbitrary for NormalizedString {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.text().prop_map(|text| Self::new(&text)).boxed()
+    }
+}
+
+impl Arbitrary for BomReference {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.ident("ref").prop_map(Self::new).boxed()
+    }
+}
+
+impl Arbitrary for DateTime {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        (
+            g.u32(0..=30),
+            g.u32(1..=12),
+            g.u32(1..=28),
+            g.u32(0..=23),
+            g.u32(0..=59),
+            g.u32(0..=59),
+        )
+            .prop_map(|(year, month, day, hour, minute, second)| {
+                let value =
+                    format!("20{year:02}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z");
+                Self::try_from(value).expect("Failed to generate a date time")
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Url {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.ident("path")
+            .prop_map(|path| Self::new(&format!("https://example.com/{path}")))
+            .boxed()
+    }
+}
+
+impl Arbitrary for Purl {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        (g.ident("crate"), g.u32(0..=9), g.u32(0..=9), g.u32(0..=9))
+            .prop_map(|(name, major, minor, patch)| {
+                let version = format!("{major}.{minor}.{patch}");
+                Self::new("cargo", &name, &version).expect("Failed to generate a purl")
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for UrnUuid {
+    fn arbitrary(_: Gen) -> BoxedStrategy<Self> {
+        (
+            any::<u32>(),
+            any::<u16>(),
+            0..0x1000u16,
+            0..0x1000u16,
+            0..1u64 << 48,
+        )
+            .prop_map(|(a, b, c, d, e)| {
+                Self::new(format!(
+                    "urn:uuid:{a:08x}-{b:04x}-4{c:03x}-8{d:03x}-{e:012x}"
+                ))
+                .expect("Failed to generate a serial number")
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for SpdxExpression {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.choose(vec![
+            "MIT",
+            "MIT OR Apache-2.0",
+            "GPL-2.0-only WITH Classpath-exception-2.0",
+            "(MIT AND BSD-3-Clause) OR Apache-2.0",
+        ])
+        .prop_map(Self::new)
+        .boxed()
+    }
+}
+
+impl Arbitrary for SpdxIdentifier {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.choose(vec!["MIT", "Apache-2.0", "BSD-3-Clause", "MPL-2.0"])
+            .prop_map(|id| {
+                Self::try_from(id.to_string()).expect("Failed to generate an SPDX identifier")
+            })
+            .boxed()
+    }
+}
+
+arbitrary_enum! {
+    Encoding => [Encoding::Base64];
+    IssueClassification => [
+        IssueClassification::Defect,
+        IssueClassification::Enhancement,
+        IssueClassification::Security,
+    ];
+    PatchClassification => [
+        PatchClassification::Unofficial,
+        PatchClassification::Monkey,
+        PatchClassification::Backport,
+        PatchClassification::CherryPick,
+    ];
+    Classification => [
+        Classification::Application,
+        Classification::Framework,
+        Classification::Library,
+        Classification::Container,
+        Classification::OperatingSystem,
+        Classification::Device,
+        Classification::Firmware,
+        Classification::File,
+        Classification::Platform,
+        Classification::DeviceDriver,
+        Classification::MachineLearningModel,
+        Classification::Data,
+        Classification::CryptographicAsset,
+    ];
+    Scope => [Scope::Required, Scope::Optional, Scope::Excluded];
+    IdentityField => [
+        IdentityField::Group,
+        IdentityField::Name,
+        IdentityField::Version,
+        IdentityField::Purl,
+        IdentityField::Cpe,
+        IdentityField::Swid,
+        IdentityField::Hash,
+    ];
+    ComponentDataType => [
+        ComponentDataType::SourceCode,
+        ComponentDataType::Configuration,
+        ComponentDataType::Dataset,
+        ComponentDataType::Definition,
+        ComponentDataType::Other,
+    ];
+    AggregateType => [
+        AggregateType::Complete,
+        AggregateType::Incomplete,
+        AggregateType::IncompleteFirstPartyOnly,
+        AggregateType::IncompleteThirdPartyOnly,
+        AggregateType::Unknown,
+        AggregateType::NotSpecified,
+        AggregateType::IncompleteFirstPartyProprietaryOnly,
+        AggregateType::IncompleteFirstPartyOpensourceOnly,
+        AggregateType::IncompleteThirdPartyProprietaryOnly,
+        AggregateType::IncompleteThirdPartyOpensourceOnly,
+    ];
+    CryptoAssetType => [
+        CryptoAssetType::Algorithm,
+        CryptoAssetType::Certificate,
+        CryptoAssetType::Protocol,
+        CryptoAssetType::RelatedCryptoMaterial,
+    ];
+    CryptoPrimitive => [
+        CryptoPrimitive::Drbg,
+        CryptoPrimitive::Mac,
+        CryptoPrimitive::BlockCipher,
+        CryptoPrimitive::StreamCipher,
+        CryptoPrimitive::Signature,
+        CryptoPrimitive::Hash,
+        CryptoPrimitive::Pke,
+        CryptoPrimitive::Xof,
+        CryptoPrimitive::Kdf,
+        CryptoPrimitive::KeyAgree,
+        CryptoPrimitive::Kem,
+        CryptoPrimitive::Ae,
+        CryptoPrimitive::Combiner,
+        CryptoPrimitive::Other,
+        CryptoPrimitive::Unknown,
+    ];
+    ExecutionEnvironment => [
+        ExecutionEnvironment::SoftwarePlainRam,
+        ExecutionEnvironment::SoftwareEncryptedRam,
+        ExecutionEnvironment::SoftwareTee,
+        ExecutionEnvironment::Hardware,
+        ExecutionEnvironment::Other,
+        ExecutionEnvironment::Unknown,
+    ];
+    ImplementationPlatform => [
+        ImplementationPlatform::Generic,
+        ImplementationPlatform::X86_32,
+        ImplementationPlatform::X86_64,
+        ImplementationPlatform::Armv7A,
+        ImplementationPlatform::Armv7M,
+        ImplementationPlatform::Armv8A,
+        ImplementationPlatform::Armv8M,
+        ImplementationPlatform::Armv9A,
+        ImplementationPlatform::Armv9M,
+        ImplementationPlatform::S390x,
+        ImplementationPlatform::Ppc64,
+        ImplementationPlatform::Ppc64le,
+        ImplementationPlatform::Other,
+        ImplementationPlatform::Unknown,
+    ];
+    CertificationLevel => [
+        CertificationLevel::None,
+        CertificationLevel::Fips140_1L1,
+        CertificationLevel::Fips140_2L3,
+        CertificationLevel::Fips140_3L4,
+        CertificationLevel::CcEal1,
+        CertificationLevel::CcEal4Plus,
+        CertificationLevel::CcEal7Plus,
+        CertificationLevel::Other,
+        CertificationLevel::Unknown,
+    ];
+    CryptoMode => [
+        CryptoMode::Cbc,
+        CryptoMode::Ecb,
+        CryptoMode::Ccm,
+        CryptoMode::Gcm,
+        CryptoMode::Cfb,
+        CryptoMode::Ofb,
+        CryptoMode::Ctr,
+        CryptoMode::Other,
+        CryptoMode::Unknown,
+    ];
+    CryptoPadding => [
+        CryptoPadding::Pkcs5,
+        CryptoPadding::Pkcs7,
+        CryptoPadding::Pkcs1v15,
+        CryptoPadding::Oaep,
+        CryptoPadding::Raw,
+        CryptoPadding::Other,
+        CryptoPadding::Unknown,
+    ];
+    CryptoFunction => [
+        CryptoFunction::Generate,
+        CryptoFunction::Keygen,
+        CryptoFunction::Encrypt,
+        CryptoFunction::Decrypt,
+        CryptoFunction::Digest,
+        CryptoFunction::Tag,
+        CryptoFunction::Keyderive,
+        CryptoFunction::Sign,
+        CryptoFunction::Verify,
+        CryptoFunction::Encapsulate,
+        CryptoFunction::Decapsulate,
+        CryptoFunction::Other,
+        CryptoFunction::Unknown,
+    ];
+    RelatedCryptoMaterialType => [
+        RelatedCryptoMaterialType::PrivateKey,
+        RelatedCryptoMaterialType::PublicKey,
+        RelatedCryptoMaterialType::SecretKey,
+        RelatedCryptoMaterialType::Key,
+        RelatedCryptoMaterialType::Ciphertext,
+        RelatedCryptoMaterialType::Signature,
+        RelatedCryptoMaterialType::Digest,
+        RelatedCryptoMaterialType::InitializationVector,
+        RelatedCryptoMaterialType::Nonce,
+        RelatedCryptoMaterialType::Seed,
+        RelatedCryptoMaterialType::Salt,
+        RelatedCryptoMaterialType::SharedSecret,
+        RelatedCryptoMaterialType::Tag,
+        RelatedCryptoMaterialType::AdditionalData,
+        RelatedCryptoMaterialType::Password,
+        RelatedCryptoMaterialType::Credential,
+        RelatedCryptoMaterialType::Token,
+        RelatedCryptoMaterialType::Other,
+        RelatedCryptoMaterialType::Unknown,
+    ];
+    RelatedCryptoMaterialState => [
+        RelatedCryptoMaterialState::PreActivation,
+        RelatedCryptoMaterialState::Active,
+        RelatedCryptoMaterialState::Suspended,
+        RelatedCryptoMaterialState::Deactivated,
+        RelatedCryptoMaterialState::Compromised,
+        RelatedCryptoMaterialState::Destroyed,
+    ];
+    ProtocolType => [
+        ProtocolType::Tls,
+        ProtocolType::Ssh,
+        ProtocolType::Ipsec,
+        ProtocolType::Ike,
+        ProtocolType::Sstp,
+        ProtocolType::Wpa,
+        ProtocolType::Other,
+        ProtocolType::Unknown,
+    ];
+    ExternalReferenceType => [
+        ExternalReferenceType::Vcs,
+        ExternalReferenceType::IssueTracker,
+        ExternalReferenceType::Website,
+        ExternalReferenceType::Advisories,
+        ExternalReferenceType::Bom,
+        ExternalReferenceType::MailingList,
+        ExternalReferenceType::Social,
+        ExternalReferenceType::Chat,
+        ExternalReferenceType::Documentation,
+        ExternalReferenceType::Support,
+        ExternalReferenceType::Distribution,
+        ExternalReferenceType::DistributionIntake,
+        ExternalReferenceType::License,
+        ExternalReferenceType::BuildMeta,
+        ExternalReferenceType::BuildSystem,
+        ExternalReferenceType::Other,
+        ExternalReferenceType::ReleaseNotes,
+        ExternalReferenceType::SecurityContact,
+        ExternalReferenceType::ModelCard,
+        ExternalReferenceType::Log,
+        ExternalReferenceType::Configuration,
+        ExternalReferenceType::Evidence,
+        ExternalReferenceType::Formulation,
+        ExternalReferenceType::Attestation,
+        ExternalReferenceType::ThreatModel,
+        ExternalReferenceType::AdversaryModel,
+        ExternalReferenceType::RiskAssessment,
+        ExternalReferenceType::VulnerabilityAssertion,
+        ExternalReferenceType::ExploitabilityStatement,
+        ExternalReferenceType::PentestReport,
+        ExternalReferenceType::StaticAnalysisReport,
+        ExternalReferenceType::DynamicAnalysisReport,
+        ExternalReferenceType::RuntimeAnalysisReport,
+        ExternalReferenceType::ComponentAnalysisReport,
+        ExternalReferenceType::MaturityReport,
+        ExternalReferenceType::CertificationReport,
+        ExternalReferenceType::CondifiedInfrastructure,
+        ExternalReferenceType::QualityMetrics,
+        ExternalReferenceType::Poam,
+        ExternalReferenceType::SourceDistribution,
+        ExternalReferenceType::ElectronicSignature,
+        ExternalReferenceType::DigitalSignature,
+        ExternalReferenceType::Rfc9116,
+    ];
+    TaskType => [
+        TaskType::Copy,
+        TaskType::Clone,
+        TaskType::Lint,
+        TaskType::Scan,
+        TaskType::Merge,
+        TaskType::Build,
+        TaskType::Test,
+        TaskType::Deliver,
+        TaskType::Deploy,
+        TaskType::Release,
+        TaskType::Clean,
+        TaskType::Other,
+    ];
+    output::Type => [
+        output::Type::Artifact,
+        output::Type::Attestation,
+        output::Type::Log,
+        output::Type::Evidence,
+        output::Type::Metrics,
+        output::Type::Other,
+    ];
+    trigger::Type => [
+        trigger::Type::Manual,
+        trigger::Type::Api,
+        trigger::Type::Webhook,
+        trigger::Type::Scheduled,
+    ];
+    AccessMode => [
+        AccessMode::ReadOnly,
+        AccessMode::ReadWrite,
+        AccessMode::ReadWriteOnce,
+        AccessMode::WriteOnce,
+        AccessMode::WriteOnly,
+    ];
+    Mode => [Mode::Filesystem, Mode::Block];
+    HashAlgorithm => [
+        HashAlgorithm::MD5,
+        HashAlgorithm::SHA1,
+        HashAlgorithm::SHA_256,
+        HashAlgorithm::SHA_384,
+        HashAlgorithm::SHA_512,
+        HashAlgorithm::SHA3_256,
+        HashAlgorithm::SHA3_384,
+        HashAlgorithm::SHA3_512,
+        HashAlgorithm::BLAKE2b_256,
+        HashAlgorithm::BLAKE2b_384,
+        HashAlgorithm::BLAKE2b_512,
+        HashAlgorithm::BLAKE3,
+    ];
+    LicenseType => [
+        LicenseType::Academic,
+        LicenseType::Appliance,
+        LicenseType::ClientAccess,
+        LicenseType::ConcurrentUser,
+        LicenseType::CorePoints,
+        LicenseType::CustomMetric,
+        LicenseType::Device,
+        LicenseType::Evaluation,
+        LicenseType::NamedUser,
+        LicenseType::NodeLocked,
+        LicenseType::Oem,
+        LicenseType::Perpetual,
+        LicenseType::ProcessorPoints,
+        LicenseType::Subscription,
+        LicenseType::User,
+        LicenseType::Other,
+    ];
+    Phase => [
+        Phase::Design,
+        Phase::PreBuild,
+        Phase::Build,
+        Phase::PostBuild,
+        Phase::Operations,
+        Phase::Discovery,
+        Phase::Decommission,
+    ];
+    ApproachType => [
+        ApproachType::Supervised,
+        ApproachType::Unsupervised,
+        ApproachType::ReinforcementLearning,
+        ApproachType::SemiSupervised,
+        ApproachType::SelfSupervised,
+    ];
+    EnergyActivity => [
+        EnergyActivity::Design,
+        EnergyActivity::DataCollection,
+        EnergyActivity::DataPreparation,
+        EnergyActivity::Training,
+        EnergyActivity::FineTuning,
+        EnergyActivity::Validation,
+        EnergyActivity::Deployment,
+        EnergyActivity::Inference,
+        EnergyActivity::Other,
+    ];
+    EnergySource => [
+        EnergySource::Coal,
+        EnergySource::Oil,
+        EnergySource::NaturalGas,
+        EnergySource::Nuclear,
+        EnergySource::Wind,
+        EnergySource::Solar,
+        EnergySource::Geothermal,
+        EnergySource::Hydropower,
+        EnergySource::Biofuel,
+        EnergySource::UnknownSource,
+        EnergySource::Other,
+    ];
+    DataFlowType => [
+        DataFlowType::Inbound,
+        DataFlowType::Outbound,
+        DataFlowType::BiDirectional,
+        DataFlowType::Unknown,
+    ];
+    Algorithm => [
+        Algorithm::RS256,
+        Algorithm::RS384,
+        Algorithm::RS512,
+        Algorithm::PS256,
+        Algorithm::PS384,
+        Algorithm::PS512,
+        Algorithm::ES256,
+        Algorithm::ES384,
+        Algorithm::ES512,
+        Algorithm::Ed25519,
+        Algorithm::Ed448,
+        Algorithm::HS256,
+        Algorithm::HS384,
+        Algorithm::HS512,
+    ];
+    ImpactAnalysisState => [
+        ImpactAnalysisState::Resolved,
+        ImpactAnalysisState::ResolvedWithPedigree,
+        ImpactAnalysisState::Exploitable,
+        ImpactAnalysisState::InTriage,
+        ImpactAnalysisState::FalsePositive,
+        ImpactAnalysisState::NotAffected,
+    ];
+    ImpactAnalysisJustification => [
+        ImpactAnalysisJustification::CodeNotPresent,
+        ImpactAnalysisJustification::CodeNotReachable,
+        ImpactAnalysisJustification::RequiresConfiguration,
+        ImpactAnalysisJustification::RequiresDependency,
+        ImpactAnalysisJustification::RequiresEnvironment,
+        ImpactAnalysisJustification::ProtectedByCompiler,
+        ImpactAnalysisJustification::ProtectedAtRuntime,
+        ImpactAnalysisJustification::ProtectedAtPerimeter,
+        ImpactAnalysisJustification::ProtectedByMitigatingControl,
+    ];
+    ImpactAnalysisResponse => [
+        ImpactAnalysisResponse::CanNotFix,
+        ImpactAnalysisResponse::WillNotFix,
+        ImpactAnalysisResponse::Update,
+        ImpactAnalysisResponse::Rollback,
+        ImpactAnalysisResponse::WorkaroundAvailable,
+    ];
+    ScoreMethod => [
+        ScoreMethod::CVSSv2,
+        ScoreMethod::CVSSv3,
+        ScoreMethod::CVSSv31,
+        ScoreMethod::OWASP,
+        ScoreMethod::CVSSv4,
+        ScoreMethod::SSVC,
+    ];
+    Severity => [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+        Severity::Info,
+        Severity::None,
+        Severity::Unknown,
+    ];
+    Status => [Status::Affected, Status::Unaffected, Status::Unknown];
+}
+
+impl Arbitrary for Advisory {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            title: g.arbitrary(),
+            url: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Annotation {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.option_with(g.ident("annotation")),
+            subjects: g.vec_with(g.ident("ref")),
+            annotator: g.arbitrary(),
+            timestamp: g.arbitrary(),
+            text: g.text(),
+            signature: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Annotator {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        let mut annotators = vec![
+            g.arbitrary().prop_map(Self::Organization).boxed(),
+            g.arbitrary().prop_map(Self::Individual).boxed(),
+        ];
+        if let Some(nested) = g.deeper() {
+            annotators.push(nested.arbitrary().prop_map(Self::Component).boxed());
+            annotators.push(nested.arbitrary().prop_map(Self::Service).boxed());
+        }
+        Union::new(annotators).boxed()
+    }
+}
+
+impl Arbitrary for AttachedText {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            compose!(Self {
+                content_type: Just(Some(NormalizedString::new("application/octet-stream"))),
+                encoding: g.arbitrary().prop_map(Some),
+                content: Just("SGVsbG8sIFdvcmxkIQ==".to_string()),
+            }),
+            compose!(Self {
+                content_type: g.option_with(Just(NormalizedString::new("text/plain"))),
+                encoding: Just(None),
+                content: g.text(),
+            }),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Attachment {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            compose!(Self {
+                content: Just("SGVsbG8sIFdvcmxkIQ==".to_string()),
+                content_type: g.option_with(Just("image/png".to_string())),
+                encoding: Just(Some("base64".to_string())),
+            }),
+            compose!(Self {
+                content: g.text(),
+                content_type: g.option_with(Just("text/plain".to_string())),
+                encoding: Just(None),
+            }),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Commit {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            uid: g.arbitrary(),
+            url: g.arbitrary(),
+            author: g.arbitrary(),
+            committer: g.arbitrary(),
+            message: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Diff {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        // A diff without a url or text is an empty element that is not written at all
+        compose!(Self {
+            text: g.arbitrary(),
+            url: g.arbitrary().prop_map(Some),
+        })
+    }
+}
+
+impl Arbitrary for IdentifiableAction {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            timestamp: g.arbitrary(),
+            name: g.arbitrary(),
+            email: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Issue {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            issue_type: g.arbitrary(),
+            id: g.arbitrary(),
+            name: g.arbitrary(),
+            description: g.arbitrary(),
+            source: g.arbitrary(),
+            references: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Patch {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            patch_type: g.arbitrary(),
+            diff: g.arbitrary(),
+            resolves: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Source {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        // A source without a name or url is an empty element that is not written at all
+        compose!(Self {
+            name: g.arbitrary().prop_map(Some),
+            url: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Component {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            component_type: g.arbitrary(),
+            mime_type: g.option_with(Just(MimeType("application/x-rust".to_string()))),
+            bom_ref: g.option_with(g.ident("component")),
+            supplier: g.arbitrary(),
+            authors: g.arbitrary(),
+            author: g.arbitrary(),
+            publisher: g.arbitrary(),
+            group: g.arbitrary(),
+            name: g.arbitrary(),
+            // Spec version 1.3 requires the version of components
+            version: g.arbitrary().prop_map(Some),
+            description: g.arbitrary(),
+            scope: g.arbitrary(),
+            hashes: g.arbitrary(),
+            licenses: g.arbitrary(),
+            copyright: g.arbitrary(),
+            cpe: g.option_with(g.ident("product").prop_map(|product| {
+                Cpe::new(&format!("cpe:2.3:a:acme:{product}:1.0:*:*:*:*:*:*:*"))
+            })),
+            purl: g.arbitrary(),
+            swid: g.arbitrary(),
+            modified: g.arbitrary(),
+            pedigree: g.nested(),
+            external_references: g.arbitrary(),
+            properties: g.arbitrary(),
+            components: g.nested(),
+            evidence: g.arbitrary(),
+            release_notes: g.arbitrary(),
+            signature: g.arbitrary(),
+            model_card: g.arbitrary(),
+            data: g.arbitrary(),
+            crypto_properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Swid {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            tag_id: g.ident("swid"),
+            name: g.text(),
+            version: g.arbitrary(),
+            tag_version: g.arbitrary(),
+            patch: g.arbitrary(),
+            text: g.arbitrary(),
+            url: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for ComponentEvidence {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        // Always with licenses, because the XML writer drops evidence without content
+        compose!(Self {
+            licenses: g.arbitrary().prop_map(Some),
+            copyright: g.arbitrary(),
+            occurrences: g.arbitrary(),
+            callstack: g.arbitrary(),
+            identity: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Copyright {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.text().prop_map(Self).boxed()
+    }
+}
+
+impl Arbitrary for Occurrence {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            location: g.ident("file").prop_map(|file| format!("/src/{file}.rs")),
+        })
+    }
+}
+
+impl Arbitrary for Callstack {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.arbitrary().prop_map(Self::new).boxed()
+    }
+}
+
+impl Arbitrary for Frame {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            package: g.arbitrary(),
+            module: g.arbitrary(),
+            function: g.arbitrary(),
+            parameters: g.arbitrary(),
+            line: g.arbitrary(),
+            column: g.arbitrary(),
+            full_filename: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for ConfidenceScore {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.fraction(1).prop_map(Self::new).boxed()
+    }
+}
+
+impl Arbitrary for Identity {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            field: g.arbitrary(),
+            confidence: g.arbitrary(),
+            methods: g.arbitrary(),
+            tools: g.option_with(g.vec_with(g.ident("tool")).prop_map(ToolsReferences)),
+        })
+    }
+}
+
+impl Arbitrary for Method {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            technique: g
+                .choose(vec!["source-code-analysis", "hash-comparison"])
+                .prop_map(str::to_string),
+            confidence: g.arbitrary(),
+            value: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Pedigree {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            ancestors: g.nested(),
+            descendants: g.nested(),
+            variants: g.nested(),
+            commits: g.arbitrary(),
+            patches: g.arbitrary(),
+            notes: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for ComponentData {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            data_type: g.arbitrary(),
+            name: g.arbitrary(),
+            contents: g.arbitrary(),
+            classification: g.arbitrary(),
+            sensitive_data: g.arbitrary(),
+            graphics: g.arbitrary(),
+            description: g.arbitrary(),
+            governance: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for DataContents {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            attachment: g.arbitrary(),
+            url: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for GraphicsCollection {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            description: g.arbitrary(),
+            collection: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Graphic {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            name: g.arbitrary(),
+            image: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Composition {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            aggregate: g.arbitrary(),
+            assemblies: g.arbitrary(),
+            dependencies: g.arbitrary(),
+            vulnerabilities: g.arbitrary(),
+            signature: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for CryptoProperties {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            asset_type: g.arbitrary(),
+            algorithm_properties: g.arbitrary(),
+            certificate_properties: g.arbitrary(),
+            related_crypto_material_properties: g.arbitrary(),
+            protocol_properties: g.arbitrary(),
+            oid: g.option_with(g.u32(0..=999).prop_map(|n| format!("1.2.840.{n}"))),
+        })
+    }
+}
+
+impl Arbitrary for AlgorithmProperties {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            primitive: g.arbitrary(),
+            parameter_set_identifier: g.arbitrary(),
+            curve: g.arbitrary(),
+            execution_environment: g.arbitrary(),
+            implementation_platform: g.arbitrary(),
+            certification_level: g.arbitrary(),
+            mode: g.arbitrary(),
+            padding: g.arbitrary(),
+            crypto_functions: g.arbitrary(),
+            classical_security_level: g.arbitrary(),
+            nist_quantum_security_level: g.option_with(g.u32(0..=6)),
+        })
+    }
+}
+
+impl Arbitrary for CertificateProperties {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            subject_name: g.arbitrary(),
+            issuer_name: g.arbitrary(),
+            not_valid_before: g.arbitrary(),
+            not_valid_after: g.arbitrary(),
+            signature_algorithm_ref: g.option_with(g.ident("algorithm")),
+            subject_public_key_ref: g.option_with(g.ident("key")),
+            certificate_format: g.arbitrary(),
+            certificate_extension: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for RelatedCryptoMaterialProperties {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            material_type: g.arbitrary(),
+            id: g.arbitrary(),
+            state: g.arbitrary(),
+            algorithm_ref: g.option_with(g.ident("algorithm")),
+            creation_date: g.arbitrary(),
+            activation_date: g.arbitrary(),
+            update_date: g.arbitrary(),
+            expiration_date: g.arbitrary(),
+            value: g.arbitrary(),
+            size: g.arbitrary(),
+            format: g.arbitrary(),
+            secured_by: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for SecuredBy {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            mechanism: g.arbitrary(),
+            algorithm_ref: g.option_with(g.ident("algorithm")),
+        })
+    }
+}
+
+impl Arbitrary for ProtocolProperties {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            protocol_type: g.arbitrary(),
+            version: g.arbitrary(),
+            cipher_suites: g.arbitrary(),
+            ikev2_transform_types: g.arbitrary(),
+            crypto_ref_array: g.option_with(g.vec_with(g.ident("crypto"))),
+        })
+    }
+}
+
+impl Arbitrary for CipherSuite {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            name: g.arbitrary(),
+            algorithms: g.option_with(g.vec_with(g.ident("algorithm"))),
+            identifiers: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Ikev2TransformTypes {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            encr: g.arbitrary(),
+            prf: g.arbitrary(),
+            integ: g.arbitrary(),
+            ke: g.arbitrary(),
+            esn: g.arbitrary(),
+            auth: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for DataGovernance {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            custodians: g.arbitrary(),
+            stewards: g.arbitrary(),
+            owners: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for DataGovernanceResponsibleParty {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::Organization),
+            g.arbitrary().prop_map(Self::Contact),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Declarations {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            assessors: g.arbitrary(),
+            attestations: g.arbitrary(),
+            claims: g.arbitrary(),
+            evidence: g.arbitrary(),
+            targets: g.arbitrary(),
+            affirmation: g.arbitrary(),
+            signature: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Assessor {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            third_party: g.arbitrary(),
+            organization: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Attestation {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            summary: g.arbitrary(),
+            assessor: g.option_with(g.ident("assessor")),
+            map: g.arbitrary(),
+            signature: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for AttestationMap {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            requirement: g.option_with(g.ident("requirement")),
+            claims: g.option_with(g.vec_with(g.ident("claim"))),
+            counter_claims: g.option_with(g.vec_with(g.ident("claim"))),
+            conformance: g.arbitrary(),
+            confidence: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Conformance {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            score: g.option_with(g.fraction(1).prop_map(Score::from)),
+            rationale: g.arbitrary(),
+            mitigation_strategies: g.option_with(g.vec_with(g.ident("strategy"))),
+        })
+    }
+}
+
+impl Arbitrary for Confidence {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            score: g.option_with(g.fraction(1).prop_map(Score::from)),
+            rationale: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Claim {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            target: g.option_with(g.ident("target")),
+            predicate: g.arbitrary(),
+            mitigation_strategies: g.option_with(g.vec_with(g.ident("strategy"))),
+            reasoning: g.arbitrary(),
+            evidence: g.option_with(g.vec_with(g.ident("evidence"))),
+            counter_evidence: g.option_with(g.vec_with(g.ident("evidence"))),
+            external_references: g.arbitrary(),
+            signature: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for DeclarationEvidence {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            property_name: g.arbitrary(),
+            description: g.arbitrary(),
+            data: g.arbitrary(),
+            created: g.arbitrary(),
+            expires: g.arbitrary(),
+            author: g.arbitrary(),
+            reviewer: g.arbitrary(),
+            signature: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for EvidenceData {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            name: g.arbitrary(),
+            contents: g.arbitrary(),
+            classification: g.arbitrary(),
+            sensitive_data: g.arbitrary(),
+            governance: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for EvidenceContents {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            attachment: g.arbitrary(),
+            url: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Targets {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            organizations: g.arbitrary(),
+            components: g.nested(),
+            services: g.nested(),
+        })
+    }
+}
+
+impl Arbitrary for Affirmation {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            statement: g.arbitrary(),
+            signatories: g.arbitrary(),
+            signature: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Signatory {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            name: g.arbitrary(),
+            role: g.arbitrary(),
+            signature: g.arbitrary(),
+            organization: g.arbitrary(),
+            external_reference: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Definitions {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            standards: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Standard {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            name: g.arbitrary(),
+            version: g.arbitrary(),
+            description: g.arbitrary(),
+            owner: g.arbitrary(),
+            requirements: g.arbitrary(),
+            levels: g.arbitrary(),
+            external_references: g.arbitrary(),
+            signature: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Requirement {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            identifier: g.arbitrary(),
+            title: g.arbitrary(),
+            text: g.arbitrary(),
+            descriptions: g.arbitrary(),
+            open_cre: g.option_with(g.vec_with(
+                (g.u32(100..=999), g.u32(100..=999)).prop_map(|(a, b)| format!("CRE:{a}-{b}"))
+            )),
+            parent: g.option_with(g.ident("requirement")),
+            properties: g.arbitrary(),
+            external_references: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Level {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            identifier: g.arbitrary(),
+            title: g.arbitrary(),
+            description: g.arbitrary(),
+            requirements: g.option_with(g.vec_with(g.ident("requirement"))),
+        })
+    }
+}
+
+impl Arbitrary for Dependency {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            dependency_ref: g.ident("ref"),
+            dependencies: g.vec_with(g.ident("ref")),
+        })
+    }
+}
+
+impl Arbitrary for ExternalReference {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        // Only references of BOMs link to other BOMs
+        prop_oneof![
+            compose!(Self {
+                external_reference_type: Just(ExternalReferenceType::Bom),
+                url: g.u32(1..=9).prop_map(|version| {
+                    external_reference::Uri::BomLink(BomLink(format!(
+                        "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/{version}"
+                    )))
+                }),
+                comment: g.arbitrary(),
+                hashes: g.arbitrary(),
+            }),
+            compose!(Self {
+                external_reference_type: g.arbitrary(),
+                url: g.arbitrary().prop_map(external_reference::Uri::Url),
+                comment: g.arbitrary(),
+                hashes: g.arbitrary(),
+            }),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Formula {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            components: g.nested(),
+            services: g.nested(),
+            workflows: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Input {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            required: g.arbitrary(),
+            source: g.arbitrary(),
+            target: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for RequiredInputField {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::Resource),
+            g.arbitrary().prop_map(Self::Parameters),
+            g.arbitrary().prop_map(Self::EnvironmentVars),
+            g.arbitrary().prop_map(Self::Data),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Parameter {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            name: g.arbitrary(),
+            value: g.arbitrary(),
+            data_type: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Workflow {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            uid: g.ident("workflow"),
+            name: g.arbitrary(),
+            description: g.arbitrary(),
+            resource_references: g.arbitrary(),
+            tasks: g.arbitrary(),
+            task_dependencies: g.arbitrary(),
+            task_types: g.arbitrary(),
+            trigger: g.arbitrary(),
+            steps: g.arbitrary(),
+            inputs: g.arbitrary(),
+            outputs: g.arbitrary(),
+            time_start: g.arbitrary(),
+            time_end: g.arbitrary(),
+            workspaces: g.arbitrary(),
+            runtime_topology: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Task {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            uid: g.ident("task"),
+            name: g.arbitrary(),
+            description: g.arbitrary(),
+            resource_references: g.arbitrary(),
+            task_types: g.arbitrary(),
+            trigger: g.arbitrary(),
+            steps: g.arbitrary(),
+            inputs: g.arbitrary(),
+            outputs: g.arbitrary(),
+            time_start: g.arbitrary(),
+            time_end: g.arbitrary(),
+            workspaces: g.arbitrary(),
+            runtime_topology: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for EnvironmentVar {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            compose!(Self::Property {
+                name: g.ident("VARIABLE"),
+                value: g.text(),
+            }),
+            g.text().prop_map(Self::Value),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Output {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            required: g.arbitrary(),
+            r#type: g.arbitrary(),
+            source: g.arbitrary(),
+            target: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for RequiredOutputField {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::Resource),
+            g.arbitrary().prop_map(Self::EnvironmentVars),
+            g.arbitrary().prop_map(Self::Data),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for ResourceReference {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.ident("ref").prop_map(Self::Ref),
+            g.arbitrary().prop_map(Self::ExternalReference),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Step {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            commands: g.arbitrary(),
+            description: g.arbitrary(),
+            name: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Command {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            executed: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Trigger {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            uid: g.ident("trigger"),
+            name: g.arbitrary(),
+            description: g.arbitrary(),
+            resource_references: g.arbitrary(),
+            r#type: g.arbitrary(),
+            event: g.arbitrary(),
+            conditions: g.arbitrary(),
+            time_activated: g.arbitrary(),
+            inputs: g.arbitrary(),
+            outputs: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Event {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            uid: g.option_with(g.ident("event")),
+            description: g.arbitrary(),
+            time_received: g.arbitrary(),
+            data: g.arbitrary(),
+            source: g.arbitrary(),
+            target: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Condition {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            description: g.arbitrary(),
+            expression: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Workspace {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            uid: g.ident("workspace"),
+            name: g.arbitrary(),
+            aliases: g.arbitrary(),
+            description: g.arbitrary(),
+            resource_references: g.arbitrary(),
+            access_mode: g.arbitrary(),
+            mount_path: g.option_with(
+                g.ident("volume")
+                    .prop_map(|volume| format!("/mnt/{volume}"))
+            ),
+            managed_data_type: g.arbitrary(),
+            volume_request: g.arbitrary(),
+            volume: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Volume {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            uid: g.option_with(g.ident("volume")),
+            name: g.arbitrary(),
+            mode: g.arbitrary(),
+            path: g.option_with(
+                g.ident("volume")
+                    .prop_map(|volume| format!("/mnt/{volume}"))
+            ),
+            size_allocated: g.option_with(g.u32(1..=100).prop_map(|size| format!("{size}Gi"))),
+            persistent: g.arbitrary(),
+            remote: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Hash {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            alg: g.arbitrary(),
+            content: any::<u128>().prop_map(|n| HashValue(format!("{n:032x}"))),
+        })
+    }
+}
+
+impl Arbitrary for LicenseChoice {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::License),
+            g.arbitrary().prop_map(Self::Expression),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for License {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            license_identifier: g.arbitrary(),
+            text: g.arbitrary(),
+            url: g.arbitrary(),
+            licensing: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for LicenseIdentifier {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::SpdxId),
+            g.arbitrary().prop_map(Self::Name),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Licensing {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            alt_ids: g.arbitrary(),
+            licensor: g.arbitrary(),
+            licensee: g.arbitrary(),
+            purchaser: g.arbitrary(),
+            purchase_order: g.arbitrary(),
+            license_types: g.arbitrary(),
+            last_renewal: g.arbitrary(),
+            expiration: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for LicenseContact {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::Organization),
+            g.arbitrary().prop_map(Self::Contact),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Lifecycle {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::Phase),
+            compose!(Description {
+                name: g.arbitrary(),
+                description: g.arbitrary(),
+            })
+            .prop_map(Self::Description),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Metadata {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            timestamp: g.arbitrary(),
+            tools: g.arbitrary(),
+            authors: g.arbitrary(),
+            component: g.nested(),
+            manufacture: g.arbitrary(),
+            manufacturer: g.arbitrary(),
+            supplier: g.arbitrary(),
+            licenses: g.arbitrary(),
+            properties: g.arbitrary(),
+            lifecycles: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for ModelCard {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            model_parameters: g.arbitrary(),
+            quantitative_analysis: g.arbitrary(),
+            considerations: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for ModelParameters {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            approach: g.option_with(compose!(ModelParametersApproach {
+                approach_type: g.arbitrary(),
+            })),
+            task: g.arbitrary(),
+            architecture_family: g.arbitrary(),
+            model_architecture: g.arbitrary(),
+            datasets: g.arbitrary(),
+            inputs: g.arbitrary(),
+            outputs: g.arbitrary(),
+        })
+    }
+}
+
+/// References to datasets are deprecated and not read from XML, so only data components are
+/// generated
+impl Arbitrary for Dataset {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        g.arbitrary().prop_map(Self::Component).boxed()
+    }
+}
+
+impl Arbitrary for MLParameter {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            format: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for QuantitativeAnalysis {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            performance_metrics: g.arbitrary(),
+            graphics: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for PerformanceMetric {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            metric_type: g.arbitrary(),
+            value: g.arbitrary(),
+            slice: g.arbitrary(),
+            confidence_interval: g.option_with(compose!(ConfidenceInterval {
+                lower_bound: g.arbitrary(),
+                upper_bound: g.arbitrary(),
+            })),
+        })
+    }
+}
+
+impl Arbitrary for Considerations {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            users: g.arbitrary(),
+            use_cases: g.arbitrary(),
+            technical_limitations: g.arbitrary(),
+            performance_tradeoffs: g.arbitrary(),
+            ethical_considerations: g.arbitrary(),
+            environmental_considerations: g.arbitrary(),
+            fairness_assessments: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for EthicalConsideration {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            name: g.arbitrary(),
+            mitigation_strategy: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for FairnessAssessment {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            group_at_risk: g.arbitrary(),
+            benefits: g.arbitrary(),
+            harms: g.arbitrary(),
+            mitigation_strategy: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for EnvironmentalConsiderations {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            energy_consumptions: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for EnergyConsumption {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            activity: g.arbitrary(),
+            energy_providers: g.arbitrary(),
+            activity_energy_cost: g.fraction(1000).prop_map(|n| EnergyMeasure(n.into())),
+            co2_cost_equivalent: g.option_with(g.fraction(1000).prop_map(|n| Co2Measure(n.into()))),
+            co2_cost_offset: g.option_with(g.fraction(1000).prop_map(|n| Co2Measure(n.into()))),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for EnergyProvider {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            description: g.arbitrary(),
+            organization: g.arbitrary(),
+            energy_source: g.arbitrary(),
+            energy_provided: g.fraction(1000).prop_map(|n| EnergyMeasure(n.into())),
+            external_references: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for OrganizationalContact {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        // Always named, because the XML writers drop entirely empty elements
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            name: g.arbitrary().prop_map(Some),
+            email: g.option_with(
+                g.ident("user")
+                    .prop_map(|user| NormalizedString::new(&format!("{user}@example.com")))
+            ),
+            phone: g.option_with(
+                g.u32(0..=9999)
+                    .prop_map(|n| NormalizedString::new(&format!("+1 555 {n:04}")))
+            ),
+        })
+    }
+}
+
+impl Arbitrary for OrganizationalEntity {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        // Always named, because the XML writers drop entirely empty elements
+        compose!(Self {
+            bom_ref: g.arbitrary(),
+            name: g.arbitrary().prop_map(Some),
+            url: g.arbitrary(),
+            contact: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Property {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            name: g
+                .ident("property")
+                .prop_map(|property| format!("acme:{property}")),
+            value: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for ReleaseNotes {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            release_type: g
+                .choose(vec!["major", "minor", "patch"])
+                .prop_map(NormalizedString::new),
+            title: g.arbitrary(),
+            featured_image: g.arbitrary(),
+            social_image: g.arbitrary(),
+            description: g.arbitrary(),
+            timestamp: g.arbitrary(),
+            aliases: g.arbitrary(),
+            tags: g.arbitrary(),
+            resolves: g.arbitrary(),
+            notes: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Note {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            locale: g.option_with(
+                g.choose(vec!["en", "en-US", "de-DE"])
+                    .prop_map(|locale| Locale(locale.to_string()))
+            ),
+            text: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Service {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.option_with(g.ident("service")),
+            provider: g.arbitrary(),
+            group: g.arbitrary(),
+            name: g.arbitrary(),
+            version: g.arbitrary(),
+            description: g.arbitrary(),
+            endpoints: g.arbitrary(),
+            authenticated: g.arbitrary(),
+            x_trust_boundary: g.arbitrary(),
+            data: g.arbitrary(),
+            licenses: g.arbitrary(),
+            external_references: g.arbitrary(),
+            properties: g.arbitrary(),
+            services: g.nested(),
+            release_notes: g.arbitrary(),
+            signature: g.arbitrary(),
+            trust_zone: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Data {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::ServiceData),
+            g.arbitrary().prop_map(Self::Classification),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for ServiceData {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        // Always named, because service data with only a classification reads as the legacy
+        // data classification
+        compose!(Self {
+            name: g.arbitrary().prop_map(Some),
+            description: g.arbitrary(),
+            classification: g.arbitrary(),
+            governance: g.arbitrary(),
+            source: g.arbitrary(),
+            destination: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for DataClassification {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            flow: g.arbitrary(),
+            classification: g
+                .choose(vec!["PII", "PIFI", "public"])
+                .prop_map(NormalizedString::new),
+        })
+    }
+}
+
+impl Arbitrary for Signature {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::Signers),
+            g.arbitrary().prop_map(Self::Chain),
+            g.arbitrary().prop_map(Self::Single),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Signer {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            algorithm: g.arbitrary(),
+            value: any::<u128>().prop_map(|n| format!("{n:032x}")),
+        })
+    }
+}
+
+impl Arbitrary for Tools {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        prop_oneof![
+            g.arbitrary().prop_map(Self::List),
+            compose!(Self::Object {
+                services: g.nested(),
+                components: g.nested(),
+            }),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Tool {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            vendor: g.arbitrary(),
+            name: g.arbitrary(),
+            version: g.arbitrary(),
+            hashes: g.arbitrary(),
+            external_references: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Vulnerability {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.option_with(g.ident("vulnerability")),
+            id: g.option_with(
+                g.u32(0..=9999)
+                    .prop_map(|n| NormalizedString::new(&format!("CVE-2024-{n:04}")))
+            ),
+            vulnerability_source: g.arbitrary(),
+            vulnerability_references: g.arbitrary(),
+            vulnerability_ratings: g.arbitrary(),
+            cwes: g.option_with(g.vec_with(g.u32(1..=1000))),
+            description: g.arbitrary(),
+            detail: g.arbitrary(),
+            recommendation: g.arbitrary(),
+            workaround: g.arbitrary(),
+            proof_of_concept: g.arbitrary(),
+            advisories: g.arbitrary(),
+            created: g.arbitrary(),
+            published: g.arbitrary(),
+            updated: g.arbitrary(),
+            rejected: g.arbitrary(),
+            vulnerability_credits: g.arbitrary(),
+            tools: g.arbitrary(),
+            vulnerability_analysis: g.arbitrary(),
+            vulnerability_targets: g.arbitrary(),
+            properties: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for VulnerabilityProofOfConcept {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            reproduction_steps: g.arbitrary(),
+            environment: g.arbitrary(),
+            supporting_material: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for VulnerabilityAnalysis {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            state: g.arbitrary(),
+            justification: g.arbitrary(),
+            responses: g.arbitrary(),
+            detail: g.arbitrary(),
+            first_issued: g.arbitrary(),
+            last_updated: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for VulnerabilityCredits {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            organizations: g.arbitrary(),
+            individuals: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for VulnerabilityRating {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            vulnerability_source: g.arbitrary(),
+            score: g.option_with(g.fraction(10).prop_map(Score::from)),
+            severity: g.arbitrary(),
+            score_method: g.arbitrary(),
+            vector: g.option_with(Just(NormalizedString::new(
+                "AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+            ))),
+            justification: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for VulnerabilityReference {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            id: g
+                .ident("xxxx")
+                .prop_map(|id| NormalizedString::new(&format!("GHSA-{id}"))),
+            vulnerability_source: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for VulnerabilitySource {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            name: g.arbitrary(),
+            url: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for VulnerabilityTarget {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            bom_ref: g.ident("component"),
+            versions: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Version {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        let version_range = prop_oneof![
+            g.u32(0..=9).prop_map(|minor| {
+                VersionRange::Version(NormalizedString::new(&format!("1.{minor}.0")))
+            }),
+            g.u32(1..=9).prop_map(|minor| {
+                VersionRange::Range(NormalizedString::new(&format!(
+                    "vers:cargo/>=1.0.0|<1.{minor}.0"
+                )))
+            }),
+        ];
+        compose!(Self {
+            version_range: version_range,
+            status: g.arbitrary(),
+        })
+    }
+}
+
+impl Arbitrary for Bom {
+    fn arbitrary(g: Gen) -> BoxedStrategy<Self> {
+        compose!(Self {
+            version: g.u32(1..=10),
+            serial_number: g.arbitrary(),
+            metadata: g.arbitrary(),
+            components: g.arbitrary(),
+            services: g.arbitrary(),
+            external_references: g.arbitrary(),
+            dependencies: g.arbitrary(),
+            compositions: g.arbitrary(),
+            properties: g.arbitrary(),
+            vulnerabilities: g.arbitrary(),
+            signature: g.arbitrary(),
+            annotations: g.arbitrary(),
+            formulation: g.arbitrary(),
+            declarations: g.arbitrary(),
+            definitions: g.arbitrary(),
+            spec_version: Just(SpecVersion::V1_6),
+        })
+    }
+}
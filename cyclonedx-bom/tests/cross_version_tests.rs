@@ -0,0 +1,243 @@
+//! Serializes a single reference model to every supported spec version in JSON and XML and
+//! compares the outputs with snapshots. Whenever a spec module changes, the snapshots of all
+//! versions show the effect, e.g. a changed element order or a field that went missing.
+//!
+//! Extend [`reference_bom`] when adding support for new fields.
+
+use cyclonedx_bom::external_models::{
+    date_time::DateTime,
+    normalized_string::NormalizedString,
+    spdx::SpdxExpression,
+    uri::{Purl, Uri},
+};
+use cyclonedx_bom::models::{
+    bom::{Bom, SpecVersion, UrnUuid},
+    component::{Classification, Component, Components, Scope},
+    composition::{AggregateType, Composition, Compositions},
+    dependency::{Dependencies, Dependency},
+    external_reference::{ExternalReference, ExternalReferenceType, ExternalReferences},
+    hash::{Hash, HashAlgorithm, HashValue, Hashes},
+    license::{License, LicenseChoice, Licenses},
+    metadata::Metadata,
+    organization::{OrganizationalContact, OrganizationalEntity},
+    property::{Properties, Property},
+    service::{Service, Services},
+    tool::{Tool, Tools},
+    vulnerability::{Vulnerabilities, Vulnerability},
+    vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets},
+};
+use cyclonedx_bom::validation::Validate;
+use test_utils::validate_json_with_schema;
+
+const VERSIONS: [SpecVersion; 4] = [
+    SpecVersion::V1_3,
+    SpecVersion::V1_4,
+    SpecVersion::V1_5,
+    SpecVersion::V1_6,
+];
+
+fn reference_bom() -> Bom {
+    let mut nested = Component::new(
+        Classification::Library,
+        "nested",
+        "0.1.0",
+        Some("pkg:cargo/nested@0.1.0".to_string()),
+    );
+    nested.purl = Some(Purl::new("cargo", "nested", "0.1.0").unwrap());
+
+    let mut library = Component::new(
+        Classification::Library,
+        "library",
+        "1.2.3",
+        Some("pkg:cargo/library@1.2.3".to_string()),
+    );
+    library.group = Some(NormalizedString::new("acme"));
+    library.description = Some(NormalizedString::new("A library"));
+    library.scope = Some(Scope::Required);
+    library.hashes = Some(Hashes(vec![Hash {
+        alg: HashAlgorithm::SHA_256,
+        content: HashValue(
+            "a3f2d8b1c4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f".to_string(),
+        ),
+    }]));
+    library.licenses = Some(Licenses(vec![
+        LicenseChoice::License(License::license_id("MIT")),
+        LicenseChoice::License(License::named_license("ACME License")),
+    ]));
+    library.copyright = Some(NormalizedString::new("Copyright ACME"));
+    library.purl = Some(Purl::new("cargo", "library", "1.2.3").unwrap());
+    library.external_references = Some(ExternalReferences(vec![
+        ExternalReference::new(
+            ExternalReferenceType::Vcs,
+            Uri::new("https://github.com/acme/library"),
+        ),
+        ExternalReference::new(
+            ExternalReferenceType::Website,
+            Uri::new("https://acme.example.com"),
+        ),
+    ]));
+    library.properties = Some(Properties(vec![Property::new("acme:team", "platform")]));
+    library.components = Some(Components(vec![nested]));
+
+    let mut expression_licensed = Component::new(
+        Classification::Library,
+        "dual",
+        "2.0.0",
+        Some("pkg:cargo/dual@2.0.0".to_string()),
+    );
+    expression_licensed.licenses = Some(Licenses(vec![LicenseChoice::Expression(
+        SpdxExpression::new("MIT OR Apache-2.0"),
+    )]));
+
+    let mut application = Component::new(
+        Classification::Application,
+        "application",
+        "1.0.0",
+        Some("pkg:cargo/application@1.0.0".to_string()),
+    );
+    application.supplier = Some(OrganizationalEntity::new("ACME Corp"));
+    application.purl = Some(Purl::new("cargo", "application", "1.0.0").unwrap());
+
+    let mut service = Service::new("api", Some("service-api".to_string()));
+    service.version = Some(NormalizedString::new("1"));
+    service.endpoints = Some(vec![Uri::new("https://api.acme.example.com/v1")]);
+    service.authenticated = Some(true);
+    service.x_trust_boundary = Some(false);
+
+    let mut vulnerability = Vulnerability::new(Some("vulnerability-1".to_string()));
+    vulnerability.id = Some(NormalizedString::new("CVE-2024-0001"));
+    vulnerability.description = Some("A vulnerability".to_string());
+    vulnerability.vulnerability_targets = Some(VulnerabilityTargets(vec![VulnerabilityTarget {
+        bom_ref: "pkg:cargo/library@1.2.3".to_string(),
+        versions: None,
+    }]));
+
+    Bom {
+        version: 1,
+        serial_number: Some(
+            UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap(),
+        ),
+        metadata: Some(Metadata {
+            timestamp: Some(DateTime::try_from("2024-01-01T00:00:00Z".to_string()).unwrap()),
+            tools: Some(Tools::List(vec![Tool::new("ACME", "generator", "1.0.0")])),
+            authors: Some(vec![OrganizationalContact::new(
+                "Jane Doe",
+                Some("jane@example.com"),
+            )]),
+            component: Some(application),
+            manufacture: Some(OrganizationalEntity::new("ACME Manufacturing")),
+            manufacturer: None,
+            supplier: Some(OrganizationalEntity::new("ACME Corp")),
+            licenses: Some(Licenses(vec![LicenseChoice::License(License::license_id(
+                "Apache-2.0",
+            ))])),
+            properties: Some(Properties(vec![Property::new("acme:build", "42")])),
+            lifecycles: None,
+        }),
+        components: Some(Components(vec![library, expression_licensed])),
+        services: Some(Services(vec![service])),
+        external_references: Some(ExternalReferences(vec![ExternalReference::new(
+            ExternalReferenceType::Documentation,
+            Uri::new("https://docs.acme.example.com"),
+        )])),
+        dependencies: Some(Dependencies(vec![
+            Dependency {
+                dependency_ref: "pkg:cargo/application@1.0.0".to_string(),
+                dependencies: vec![
+                    "pkg:cargo/library@1.2.3".to_string(),
+                    "pkg:cargo/dual@2.0.0".to_string(),
+                ],
+            },
+            Dependency {
+                dependency_ref: "pkg:cargo/library@1.2.3".to_string(),
+                dependencies: vec!["pkg:cargo/nested@0.1.0".to_string()],
+            },
+        ])),
+        compositions: Some(Compositions(vec![Composition {
+            bom_ref: None,
+            aggregate: AggregateType::Complete,
+            assemblies: None,
+            dependencies: None,
+            vulnerabilities: None,
+            signature: None,
+        }])),
+        properties: Some(Properties(vec![Property::new(
+            "acme:classification",
+            "internal",
+        )])),
+        vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+        signature: None,
+        annotations: None,
+        formulation: None,
+        declarations: None,
+        definitions: None,
+        spec_version: SpecVersion::V1_5,
+    }
+}
+
+fn output_as_json(bom: Bom, version: SpecVersion) -> String {
+    let mut output = Vec::new();
+    bom.output_as_json(&mut output, version)
+        .unwrap_or_else(|e| panic!("Failed to output {version} JSON: {e:?}"));
+    String::from_utf8(output).expect("Output is not UTF-8")
+}
+
+fn output_as_xml(bom: Bom, version: SpecVersion) -> String {
+    let mut output = Vec::new();
+    bom.output_as_xml(&mut output, version)
+        .unwrap_or_else(|e| panic!("Failed to output {version} XML: {e:?}"));
+    String::from_utf8(output).expect("Output is not UTF-8")
+}
+
+#[test]
+fn it_should_validate_the_reference_model_for_all_versions() {
+    for version in VERSIONS {
+        let validation_result = reference_bom().validate_version(version);
+        assert!(
+            validation_result.passed(),
+            "Reference model failed validation for {version}: {validation_result:#?}"
+        );
+    }
+}
+
+#[test]
+fn it_should_output_the_reference_model_as_json_for_all_versions() {
+    insta::with_settings!({
+        snapshot_path => "spec/snapshots/cross-version",
+        prepend_module_to_snapshot => false,
+    }, {
+        for version in VERSIONS {
+            let output = output_as_json(reference_bom(), version);
+
+            let json = serde_json::from_str(&output).expect("Failed to parse JSON");
+            validate_json_with_schema(&json, version)
+                .unwrap_or_else(|errors| panic!("Failed to validate {version} output, errors: {errors:?}"));
+
+            // Parsing the output and writing it again has to be lossless.
+            let bom = Bom::parse_from_json_with_version(output.as_bytes(), version)
+                .unwrap_or_else(|e| panic!("Failed to parse {version} output: {e:?}"));
+            assert_eq!(output_as_json(bom, version), output);
+
+            insta::assert_snapshot!(format!("reference-{version}.json"), output);
+        }
+    });
+}
+
+#[test]
+fn it_should_output_the_reference_model_as_xml_for_all_versions() {
+    insta::with_settings!({
+        snapshot_path => "spec/snapshots/cross-version",
+        prepend_module_to_snapshot => false,
+    }, {
+        for version in VERSIONS {
+            let output = output_as_xml(reference_bom(), version);
+
+            // Parsing the output and writing it again has to be lossless.
+            let bom = Bom::parse_from_xml_with_version(output.as_bytes(), version)
+                .unwrap_or_else(|e| panic!("Failed to parse {version} output: {e:?}"));
+            assert_eq!(output_as_xml(bom, version), output);
+
+            insta::assert_snapshot!(format!("reference-{version}.xml"), output);
+        }
+    });
+}
@@ -0,0 +1,38 @@
+//! Normalizing strings on output is a process-wide setting, so it is tested in its own test
+//! binary to keep it from affecting the validation tests of the library.
+
+use cyclonedx_bom::external_models::normalized_string::set_normalize_on_output;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::validation::Validate;
+
+const INPUT: &str = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "version": 1,
+  "components": [
+    {
+      "type": "library",
+      "name": "multi-line",
+      "version": "1.0.0",
+      "description": "A description\nfrom Cargo.toml"
+    }
+  ]
+}"#;
+
+#[test]
+fn it_should_normalize_strings_on_output() {
+    let bom = Bom::parse_from_json(INPUT.as_bytes()).expect("Failed to parse BOM");
+    assert!(bom.validate_version(SpecVersion::V1_5).has_errors());
+
+    set_normalize_on_output(true);
+
+    assert!(bom.validate_version(SpecVersion::V1_5).passed());
+
+    let mut output = Vec::new();
+    bom.output_as_json_v1_5(&mut output)
+        .expect("Failed to write BOM");
+    let output = String::from_utf8(output).expect("Failed to read output");
+    assert!(output.contains(r#""description": "A description from Cargo.toml""#));
+
+    set_normalize_on_output(false);
+}
@@ -0,0 +1,116 @@
+//! Generates BOMs with the strategies in [`arbitrary`] and checks that they survive a round trip
+//! through JSON and XML for every supported spec version.
+//!
+//! A BOM of the latest version has to come back unchanged. Older versions lack fields of the
+//! models, so the first round trip drops them, but once dropped the BOM has to stay the same.
+//!
+//! `proptest` shrinks a failing BOM to a minimal one and saves its seed next to this file in
+//! `round_trip_property_tests.proptest-regressions`, so later runs check it first.
+//! `PROPTEST_CASES` sets the number of generated BOMs per format, 64 by default.
+
+mod arbitrary;
+
+use arbitrary::Gen;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion, XmlReadOptions};
+use proptest::{
+    prop_assert_eq, proptest,
+    test_runner::{Config, FileFailurePersistence, TestCaseError},
+};
+
+const VERSIONS: [SpecVersion; 4] = [
+    SpecVersion::V1_3,
+    SpecVersion::V1_4,
+    SpecVersion::V1_5,
+    SpecVersion::V1_6,
+];
+
+fn config() -> Config {
+    let config = Config::default();
+    Config {
+        // Fewer cases than the default of `proptest` unless `PROPTEST_CASES` is set, because
+        // every case writes and reads a large BOM eight times
+        cases: match std::env::var_os("PROPTEST_CASES") {
+            Some(_) => config.cases,
+            None => 64,
+        },
+        failure_persistence: Some(Box::new(FileFailurePersistence::WithSource(
+            "proptest-regressions",
+        ))),
+        ..config
+    }
+}
+
+fn write_json(bom: &Bom, version: SpecVersion) -> Result<Vec<u8>, TestCaseError> {
+    let mut output = Vec::new();
+    bom.clone()
+        .output_as_json(&mut output, version)
+        .map_err(|e| TestCaseError::fail(format!("Failed to write JSON: {e}")))?;
+    Ok(output)
+}
+
+fn write_xml(bom: &Bom, version: SpecVersion) -> Result<Vec<u8>, TestCaseError> {
+    let mut output = Vec::new();
+    bom.clone()
+        .output_as_xml(&mut output, version)
+        .map_err(|e| TestCaseError::fail(format!("Failed to write XML: {e}")))?;
+    Ok(output)
+}
+
+fn json_round_trip(bom: &Bom, version: SpecVersion) -> Result<Bom, TestCaseError> {
+    let output = write_json(bom, version)?;
+    Bom::parse_from_json_with_version(output.as_slice(), version).map_err(|e| {
+        TestCaseError::fail(format!(
+            "Failed to read JSON: {e}\n{}",
+            String::from_utf8_lossy(&output)
+        ))
+    })
+}
+
+/// Reads strictly, so that content the readers would skip fails the test instead
+fn xml_round_trip(bom: &Bom, version: SpecVersion) -> Result<Bom, TestCaseError> {
+    let output = write_xml(bom, version)?;
+    let (read, _) = Bom::parse_from_xml_with_options(
+        output.as_slice(),
+        version,
+        XmlReadOptions { strict: true },
+    )
+    .map_err(|e| {
+        TestCaseError::fail(format!(
+            "Failed to read XML: {e}\n{}",
+            String::from_utf8_lossy(&output)
+        ))
+    })?;
+    Ok(read)
+}
+
+fn check_round_trips(
+    bom: &Bom,
+    format: &str,
+    round_trip: fn(&Bom, SpecVersion) -> Result<Bom, TestCaseError>,
+) -> Result<(), TestCaseError> {
+    for version in VERSIONS {
+        let once = round_trip(bom, version)?;
+        let expected = match version {
+            SpecVersion::V1_6 => bom,
+            _ => &once,
+        };
+        let twice = round_trip(&once, version)?;
+
+        prop_assert_eq!(expected, &twice, "{} {} changed the BOM", format, version);
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn it_should_round_trip_arbitrary_boms_through_json(bom in Gen::new().arbitrary::<Bom>()) {
+        check_round_trips(&bom, "JSON", json_round_trip)?;
+    }
+
+    #[test]
+    fn it_should_round_trip_arbitrary_boms_through_xml(bom in Gen::new().arbitrary::<Bom>()) {
+        check_round_trips(&bom, "XML", xml_round_trip)?;
+    }
+}
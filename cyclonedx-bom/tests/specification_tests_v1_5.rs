@@ -63,6 +63,25 @@ mod v1_5 {
         });
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_should_round_trip_all_of_the_valid_json_specifications_with_serde() {
+        insta::glob!("spec/1.5/valid*.json", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {path:?}"));
+            let bom = Bom::parse_from_json_v1_5(file).unwrap_or_else(|e| {
+                panic!("Failed to parse the document as an BOM: {path:?} {:#?}", e)
+            });
+
+            let serialized = serde_json::to_vec(&bom)
+                .unwrap_or_else(|e| panic!("Failed to serialize the models: {path:?} {e}"));
+            let deserialized: Bom = serde_json::from_slice(&serialized)
+                .unwrap_or_else(|e| panic!("Failed to deserialize the models: {path:?} {e}"));
+
+            assert_eq!(bom, deserialized, "{path:?} changed in the round trip");
+        });
+    }
+
     #[test]
     fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
         insta::with_settings!({
@@ -0,0 +1,125 @@
+mod v1_6 {
+    use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    use cyclonedx_bom::validation::Validate;
+    use test_utils::validate_json_with_schema;
+
+    #[test]
+    fn it_should_parse_all_of_the_valid_xml_specifications() {
+        insta::with_settings!({
+            snapshot_path => "spec/snapshots/1.6",
+            prepend_module_to_snapshot => false,
+        }, {
+            insta::glob!("spec/1.6/valid*.xml", |path| {
+                let file = std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file: {path:?}"));
+                let bom = Bom::parse_from_xml_v1_6(file).unwrap_or_else(|e| panic!("Failed to parse the document as an BOM: {path:?} {:#?}", e));
+
+                let validation_result = bom.validate_version(SpecVersion::V1_6);
+                if !validation_result.passed() {
+                    dbg!(&validation_result);
+                }
+                assert!(
+                    validation_result.passed(),
+                    "{path:?} unexpectedly failed validation"
+                );
+
+                let mut output = Vec::new();
+                bom.output_as_xml_v1_6(&mut output)
+                    .unwrap_or_else(|_| panic!("Failed to output the file: {path:?}"));
+                let bom_output = String::from_utf8_lossy(&output).to_string();
+
+                insta::assert_snapshot!(bom_output);
+            });
+        });
+    }
+
+    #[test]
+    fn it_should_parse_all_of_the_valid_json_specifications() {
+        insta::with_settings!({
+            snapshot_path => "spec/snapshots/1.6",
+            prepend_module_to_snapshot => false,
+        }, {
+            insta::glob!("spec/1.6/valid*.json", |path| {
+                let file = std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file: {path:?}"));
+                let bom = Bom::parse_from_json_v1_6(file).unwrap_or_else(|e| panic!("Failed to parse the document as an BOM: {path:?} {:#?}", e));
+
+                let validation_result = bom.validate_version(SpecVersion::V1_6);
+                if !validation_result.passed() {
+                    dbg!(&validation_result);
+                }
+                assert!(
+                    validation_result.passed(),
+                    "{path:?} unexpectedly failed validation"
+                );
+
+                let mut output = Vec::new();
+                bom.output_as_json_v1_6(&mut output)
+                    .unwrap_or_else(|_| panic!("Failed to output the file: {path:?}"));
+                let bom_output = String::from_utf8_lossy(&output).to_string();
+
+                // Check that the written JSON file validates against its schema.
+                let json = serde_json::from_str(&bom_output).expect("Failed to parse JSON");
+                validate_json_with_schema(&json, SpecVersion::V1_6)
+                    .unwrap_or_else(|errors| panic!("Failed to validate output {path:?}, errors: {errors:?}"));
+
+                insta::assert_snapshot!(bom_output);
+            });
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_should_round_trip_all_of_the_valid_json_specifications_with_serde() {
+        insta::glob!("spec/1.6/valid*.json", |path| {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {path:?}"));
+            let bom = Bom::parse_from_json_v1_6(file).unwrap_or_else(|e| {
+                panic!("Failed to parse the document as an BOM: {path:?} {:#?}", e)
+            });
+
+            let serialized = serde_json::to_vec(&bom)
+                .unwrap_or_else(|e| panic!("Failed to serialize the models: {path:?} {e}"));
+            let deserialized: Bom = serde_json::from_slice(&serialized)
+                .unwrap_or_else(|e| panic!("Failed to deserialize the models: {path:?} {e}"));
+
+            assert_eq!(bom, deserialized, "{path:?} changed in the round trip");
+        });
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
+        insta::with_settings!({
+            snapshot_path => "spec/snapshots/1.6",
+            prepend_module_to_snapshot => false,
+        }, {
+            insta::glob!("spec/1.6/invalid*.xml", |path| {
+                let file = std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file: {path:?}"));
+                if let Ok(bom) = Bom::parse_from_xml_v1_6(file) {
+                    let validation_result = bom.validate_version(SpecVersion::V1_6);
+                    assert!(
+                        validation_result.has_errors(),
+                        "{path:?} unexpectedly passed validation"
+                    );
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_all_of_the_invalid_json_specifications() {
+        insta::with_settings!({
+            snapshot_path => "spec/snapshots/1.6",
+            prepend_module_to_snapshot => false,
+        }, {
+            insta::glob!("spec/1.6/invalid*.json", |path| {
+                let file = std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file: {path:?}"));
+                if let Ok(bom) = Bom::parse_from_json_v1_6(file) {
+                    let validation_result = bom.validate_version(SpecVersion::V1_6);
+                    assert!(
+                        validation_result.has_errors(),
+                        "{path:?} unexpectedly passed validation"
+                    );
+                }
+            });
+        });
+    }
+}